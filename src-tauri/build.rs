@@ -9,5 +9,25 @@ fn main() {
             "<!DOCTYPE html><html><body>Frontend not built. Run <code>pnpm build</code> first.</body></html>",
         );
     }
+    emit_build_info();
     tauri_build::build()
 }
+
+/// 编译期写入提交哈希和编译时间（UNIX 秒），供 maa_get_version 返回；获取失败时不写入
+fn emit_build_info() {
+    let commit = std::process::Command::new("git")
+        .args(["rev-parse", "--short=10", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+    if let Some(commit) = commit {
+        println!("cargo:rustc-env=MXU_GIT_COMMIT={}", commit);
+    }
+    if let Ok(now) = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+        println!("cargo:rustc-env=MXU_BUILD_TIMESTAMP={}", now.as_secs());
+    }
+    println!("cargo:rerun-if-changed=../.git/HEAD");
+}
@@ -0,0 +1,137 @@
+//! 点击/滑动轨迹事件
+//!
+//! 开启后，控制器每完成一次点击、长按或滑动，就以合成回调 `MXU.Action.Trace` 转发一次坐标
+//! （动作类型、起止坐标、所属节点），前端据此在截图预览或悬浮窗上画出点击点和滑动轨迹。
+//! 坐标为设备坐标，事件同时带上当前截图尺寸，前端按比例换算到预览坐标。
+//! 事件较密集，默认关闭。
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
+
+use log::info;
+use serde_json::Value;
+
+use super::types::{ActionTraceEvent, MaaState};
+use super::utils::png_size;
+
+/// 合成回调的消息名
+pub const ACTION_TRACE_MESSAGE: &str = "MXU.Action.Trace";
+
+/// 是否转发点击/滑动轨迹事件
+static ACTION_TRACE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// 各实例当前正在执行动作的节点（instance_id -> 节点名）
+static CURRENT_NODES: LazyLock<Mutex<HashMap<String, String>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 开启或关闭点击/滑动轨迹事件
+pub fn apply_action_trace_enabled(enabled: bool) {
+    ACTION_TRACE_ENABLED.store(enabled, Ordering::SeqCst);
+    if !enabled {
+        if let Ok(mut nodes) = CURRENT_NODES.lock() {
+            nodes.clear();
+        }
+    }
+    info!("Action trace enabled: {}", enabled);
+}
+
+/// 记录实例当前执行动作的节点，未开启时直接返回
+///
+/// 在 context sink 中调用
+pub fn record_node(instance_id: &str, message: &str, details: &str) {
+    if message != "Node.Action.Starting" || !ACTION_TRACE_ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let Some(name) = serde_json::from_str::<Value>(details)
+        .ok()
+        .and_then(|v| v.get("name").and_then(|n| n.as_str()).map(String::from))
+    else {
+        return;
+    };
+    if let Ok(mut nodes) = CURRENT_NODES.lock() {
+        nodes.insert(instance_id.to_string(), name);
+    }
+}
+
+/// 从控制器动作回调中提取坐标，生成轨迹事件；未开启或不是坐标类动作时返回 None
+///
+/// 在 controller sink 中调用，返回的详情以 `ACTION_TRACE_MESSAGE` 转发给前端
+pub fn trace_controller_action(
+    state: &MaaState,
+    instance_id: &str,
+    message: &str,
+    details: &str,
+) -> Option<String> {
+    if message != "Controller.Action.Succeeded" || !ACTION_TRACE_ENABLED.load(Ordering::Relaxed) {
+        return None;
+    }
+    let details: Value = serde_json::from_str(details).ok()?;
+    let param = details.get("param")?;
+
+    // 点击/长按/触摸只有 point，滑动有 begin/end
+    let (begin, end) = match (param.get("point"), param.get("begin"), param.get("end")) {
+        (Some(point), _, _) => {
+            let point = parse_point(point)?;
+            (point, None)
+        }
+        (None, Some(begin), Some(end)) => (parse_point(begin)?, Some(parse_point(end)?)),
+        _ => return None,
+    };
+
+    let resolution = {
+        let instances = state.instances.lock().ok()?;
+        instances.get(instance_id)?.controller.clone()
+    }
+    .and_then(|controller| controller.cached_image().ok())
+    .and_then(|buf| buf.to_vec())
+    .and_then(|png| png_size(&png));
+
+    let node = CURRENT_NODES
+        .lock()
+        .ok()
+        .and_then(|nodes| nodes.get(instance_id).cloned());
+
+    let event = ActionTraceEvent {
+        instance_id: instance_id.to_string(),
+        action: details
+            .get("action")
+            .and_then(|a| a.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        begin,
+        end,
+        node,
+        resolution,
+    };
+    serde_json::to_string(&event).ok()
+}
+
+/// 解析 `[x, y]` 或 `{ "x": .., "y": .. }` 形式的坐标
+fn parse_point(value: &Value) -> Option<(i32, i32)> {
+    let (x, y) = match value {
+        Value::Array(arr) if arr.len() >= 2 => (arr[0].as_i64()?, arr[1].as_i64()?),
+        Value::Object(obj) => (obj.get("x")?.as_i64()?, obj.get("y")?.as_i64()?),
+        _ => return None,
+    };
+    Some((x as i32, y as i32))
+}
+
+/// 移除实例当前的动作节点（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut entries) = CURRENT_NODES.lock() {
+        entries.remove(instance_id);
+    }
+}
+
+/// 开启或关闭点击/滑动轨迹事件（持久化由前端写入配置）
+#[tauri::command]
+pub fn set_action_trace_enabled(enabled: bool) {
+    apply_action_trace_enabled(enabled);
+}
+
+/// 查询点击/滑动轨迹事件是否开启
+#[tauri::command]
+pub fn get_action_trace_enabled() -> bool {
+    ACTION_TRACE_ENABLED.load(Ordering::SeqCst)
+}
@@ -0,0 +1,263 @@
+//! ADB 授权丢失检测
+//!
+//! 真机和部分模拟器的 adb 会变为 `unauthorized`（撤销了调试授权、重新安装了 adb key 等），
+//! 此时 MaaFramework 只报笼统的连接失败或操作失败。这里从 `adb devices` 的设备状态检测授权丢失，
+//! 发出 `maa-adb-auth` 事件引导用户在设备上点击「允许调试」：
+//! - 连接前：设备未授权时等待授权恢复再继续连接，超时则返回明确的错误
+//! - 连接后：任务运行期间后台线程轮询 ADB 控制器的设备状态，授权恢复后自动重连控制器。
+//!   没有任务运行的实例不轮询，避免空闲时反复执行 `adb devices`
+//!
+//! 等待授权超过 `AUTH_TIMEOUT` 后放弃并发出超时事件，之后需用户手动重新连接。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+use maa_framework::MaaStatus;
+use tauri::{AppHandle, Emitter};
+
+use super::emulator_launch::wait_with_stop_check;
+use super::types::{AdbAuthEvent, AdbAuthStage, ControllerConfig, MaaState};
+use super::usb_devices::{query_device_states, ADB_STATE_DEVICE, ADB_STATE_UNAUTHORIZED};
+use super::utils::emit_state_changed;
+
+/// 等待用户授权的时间上限
+const AUTH_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// 连接前等待授权时的轮询间隔
+const WAIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 后台检测已连接设备状态的间隔
+const MONITOR_INTERVAL: Duration = Duration::from_secs(3);
+
+fn emit_auth(
+    app: &AppHandle,
+    instance_id: &str,
+    address: &str,
+    stage: AdbAuthStage,
+    reconnected: bool,
+) {
+    let event = AdbAuthEvent {
+        instance_id: instance_id.to_string(),
+        address: address.to_string(),
+        stage,
+        reconnected,
+    };
+    if let Err(e) = app.emit("maa-adb-auth", event) {
+        warn!("[adb_auth] Failed to emit event: {}", e);
+    }
+}
+
+/// 在后台线程中查询单个设备的状态，adb 执行失败或设备未列出时返回 None
+async fn device_state(adb_path: &str, address: &str) -> Option<String> {
+    let adb_path = adb_path.to_string();
+    let address = address.to_string();
+    tokio::task::spawn_blocking(move || query_device_states(&adb_path)?.remove(&address))
+        .await
+        .ok()
+        .flatten()
+}
+
+/// 连接前检查 ADB 设备授权，未授权时发出事件并等待用户允许调试
+///
+/// 非 ADB 控制器、adb 执行失败或设备未列出时直接返回，交给后续连接处理
+pub async fn wait_for_authorization(
+    app: &AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    config: &ControllerConfig,
+) -> Result<(), String> {
+    let ControllerConfig::Adb {
+        adb_path, address, ..
+    } = config
+    else {
+        return Ok(());
+    };
+    if device_state(adb_path, address).await.as_deref() != Some(ADB_STATE_UNAUTHORIZED) {
+        return Ok(());
+    }
+
+    info!(
+        "[adb_auth] {}: device {} unauthorized, waiting for authorization",
+        instance_id, address
+    );
+    emit_auth(app, instance_id, address, AdbAuthStage::Unauthorized, false);
+    let started = Instant::now();
+    loop {
+        if !wait_with_stop_check(state, instance_id, WAIT_POLL_INTERVAL).await {
+            return Err("MXU_PRE_ACTION_CANCELLED".to_string());
+        }
+        let current = device_state(adb_path, address).await;
+        if current.as_deref() == Some(ADB_STATE_DEVICE) {
+            info!("[adb_auth] {}: device {} authorized", instance_id, address);
+            emit_auth(app, instance_id, address, AdbAuthStage::Authorized, false);
+            return Ok(());
+        }
+        if started.elapsed() >= AUTH_TIMEOUT {
+            warn!(
+                "[adb_auth] {}: device {} still unauthorized after {:?}",
+                instance_id, address, AUTH_TIMEOUT
+            );
+            emit_auth(app, instance_id, address, AdbAuthStage::Timeout, false);
+            return Err(format!(
+                "设备 {} 未授权调试，等待 {} 秒后仍未授权，请在设备上点击「允许」后重新连接",
+                address,
+                AUTH_TIMEOUT.as_secs()
+            ));
+        }
+    }
+}
+
+/// 需要检测授权的已连接 ADB 实例：(instance_id, adb 路径, 设备地址)
+///
+/// 只包括任务运行中的实例，以及仍在等待授权恢复的实例（授权丢失后任务通常已失败停止）
+fn adb_targets(
+    state: &MaaState,
+    waiting: &HashMap<String, Waiting>,
+) -> Vec<(String, String, String)> {
+    let Ok(instances) = state.instances.lock() else {
+        return Vec::new();
+    };
+    let mut targets = Vec::new();
+    for (id, instance) in instances.iter() {
+        if instance.controller.is_none() {
+            continue;
+        }
+        let running = instance.tasker.as_ref().is_some_and(|t| t.running());
+        let awaiting = waiting.get(id).is_some_and(|w| !w.gave_up);
+        if !running && !awaiting {
+            continue;
+        }
+        if let Some(ControllerConfig::Adb {
+            adb_path, address, ..
+        }) = &instance.controller_config
+        {
+            targets.push((id.clone(), adb_path.clone(), address.clone()));
+        }
+    }
+    targets
+}
+
+/// 授权恢复后重连实例的控制器，返回是否重连成功
+fn reconnect(state: &MaaState, instance_id: &str) -> bool {
+    let controller = state
+        .instances
+        .lock()
+        .ok()
+        .and_then(|instances| instances.get(instance_id)?.controller.clone());
+    let Some(controller) = controller else {
+        return false;
+    };
+    match controller.post_connection() {
+        Ok(id) => controller.wait(id) == MaaStatus::SUCCEEDED,
+        Err(e) => {
+            warn!("[adb_auth] Failed to post controller connection: {}", e);
+            false
+        }
+    }
+}
+
+/// 未授权设备的等待状态
+struct Waiting {
+    since: Instant,
+    /// 已超时放弃，授权恢复前不再提示
+    gave_up: bool,
+}
+
+/// 根据设备的最新状态更新等待状态，授权恢复时在新线程中重连控制器
+fn on_device_state(
+    app: &AppHandle,
+    state: &Arc<MaaState>,
+    waiting: &mut HashMap<String, Waiting>,
+    instance_id: String,
+    address: String,
+    current: &str,
+) {
+    if current == ADB_STATE_UNAUTHORIZED {
+        match waiting.get_mut(&instance_id) {
+            None => {
+                warn!(
+                    "[adb_auth] {}: device {} became unauthorized",
+                    instance_id, address
+                );
+                let w = Waiting {
+                    since: Instant::now(),
+                    gave_up: false,
+                };
+                emit_auth(
+                    app,
+                    &instance_id,
+                    &address,
+                    AdbAuthStage::Unauthorized,
+                    false,
+                );
+                waiting.insert(instance_id, w);
+            }
+            Some(w) if !w.gave_up && w.since.elapsed() >= AUTH_TIMEOUT => {
+                warn!(
+                    "[adb_auth] {}: giving up waiting for device {}",
+                    instance_id, address
+                );
+                w.gave_up = true;
+                emit_auth(app, &instance_id, &address, AdbAuthStage::Timeout, false);
+            }
+            Some(_) => {}
+        }
+        return;
+    }
+
+    if current != ADB_STATE_DEVICE {
+        return;
+    }
+    // 已放弃等待的由用户手动重新连接
+    match waiting.remove(&instance_id) {
+        Some(w) if !w.gave_up => {}
+        _ => return,
+    }
+    info!(
+        "[adb_auth] {}: device {} authorized, reconnecting",
+        instance_id, address
+    );
+    let app = app.clone();
+    let state = Arc::clone(state);
+    std::thread::spawn(move || {
+        let reconnected = reconnect(&state, &instance_id);
+        if !reconnected {
+            warn!("[adb_auth] {}: reconnection failed", instance_id);
+        }
+        emit_auth(
+            &app,
+            &instance_id,
+            &address,
+            AdbAuthStage::Authorized,
+            reconnected,
+        );
+        if reconnected {
+            emit_state_changed(&app, &instance_id, "connected");
+        }
+    });
+}
+
+/// 启动授权状态轮询线程（应用启动时调用一次），没有需要检测的实例时不执行 adb
+pub fn start_adb_auth_monitor(app: AppHandle, state: Arc<MaaState>) {
+    std::thread::spawn(move || {
+        let mut waiting: HashMap<String, Waiting> = HashMap::new();
+        loop {
+            std::thread::sleep(MONITOR_INTERVAL);
+            let targets = adb_targets(&state, &waiting);
+            waiting.retain(|id, _| targets.iter().any(|(t, _, _)| t == id));
+
+            // 同一 adb 只查询一次设备列表，设备未列出（已断开）时保持原等待状态
+            let mut states: HashMap<String, Option<HashMap<String, String>>> = HashMap::new();
+            for (instance_id, adb_path, address) in targets {
+                let devices = states
+                    .entry(adb_path.clone())
+                    .or_insert_with(|| query_device_states(&adb_path));
+                if let Some(current) = devices.as_ref().and_then(|d| d.get(&address)) {
+                    on_device_state(&app, &state, &mut waiting, instance_id, address, current);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,292 @@
+//! ADB 文件传输命令
+//!
+//! 通过实例已连接设备的 adb 在本机与模拟器之间推送/拉取文件，仅 ADB 控制器可用
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::{info, warn};
+use tauri::{Emitter, State};
+
+use super::types::{ControllerConfig, MaaState};
+
+/// 传输进度上报间隔
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// ADB 文件传输进度事件载荷
+#[derive(Clone, serde::Serialize)]
+pub struct AdbTransferProgressEvent {
+    pub instance_id: String,
+    /// push / pull
+    pub direction: String,
+    pub local: String,
+    pub remote: String,
+    pub transferred: u64,
+    pub total: u64,
+}
+
+/// 取出实例当前 ADB 控制器的 adb 路径与设备地址
+fn adb_target(state: &MaaState, instance_id: &str) -> Result<(String, String), String> {
+    let instances = state.instances.lock().map_err(|e| e.to_string())?;
+    let instance = instances.get(instance_id).ok_or("Instance not found")?;
+    match &instance.controller_config {
+        Some(ControllerConfig::Adb {
+            adb_path, address, ..
+        }) => Ok((adb_path.clone(), address.clone())),
+        Some(_) => Err("当前控制器不支持文件传输，仅 ADB 控制器可用".to_string()),
+        None => Err("控制器未连接".to_string()),
+    }
+}
+
+/// 校验设备端路径：必须为绝对路径，不允许 `..` 和控制字符
+fn validate_remote_path(remote: &str) -> Result<(), String> {
+    if !remote.starts_with('/') {
+        return Err(format!("设备路径必须是绝对路径: {}", remote));
+    }
+    if remote.split('/').any(|part| part == "..") {
+        return Err(format!("设备路径不允许包含 '..': {}", remote));
+    }
+    if remote.chars().any(|c| c.is_control()) {
+        return Err("设备路径包含非法字符".to_string());
+    }
+    Ok(())
+}
+
+fn adb_command(adb_path: &str, address: &str) -> Command {
+    let mut cmd = Command::new(adb_path);
+    cmd.args(["-s", address])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(super::system::CREATE_NO_WINDOW);
+    }
+
+    cmd
+}
+
+/// 查询设备端文件大小，文件不存在或不是普通文件时返回 None
+fn remote_file_size(adb_path: &str, address: &str, remote: &str) -> Option<u64> {
+    // shell 参数经设备端 sh 解析，使用单引号包裹并转义内部单引号
+    let quoted = format!("'{}'", remote.replace('\'', r"'\''"));
+    let output = adb_command(adb_path, address)
+        .args(["shell", "stat", "-c", "%F:%s", &quoted])
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (kind, size) = stdout.trim().rsplit_once(':')?;
+    if !kind.contains("regular") {
+        return None;
+    }
+    size.parse().ok()
+}
+
+/// 运行 adb push/pull，传输期间按间隔轮询已传输大小并上报进度
+///
+/// stdout 只有进度输出，直接丢弃；stderr 在后台线程持续读取，避免管道写满阻塞 adb
+fn run_transfer(
+    mut cmd: Command,
+    mut progress: impl FnMut() -> Option<u64>,
+    mut emit: impl FnMut(u64),
+) -> Result<(), String> {
+    let mut child = cmd
+        .stdout(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("启动 adb 失败: {}", e))?;
+    let stderr_reader = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut output = Vec::new();
+            let _ = stderr.read_to_end(&mut output);
+            String::from_utf8_lossy(&output).trim().to_string()
+        })
+    });
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => {
+                if let Some(transferred) = progress() {
+                    emit(transferred);
+                }
+                std::thread::sleep(PROGRESS_INTERVAL);
+            }
+            Err(e) => return Err(format!("等待 adb 退出失败: {}", e)),
+        }
+    };
+
+    let stderr = stderr_reader
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
+    if status.success() {
+        return Ok(());
+    }
+    let message = if stderr.is_empty() {
+        format!("退出码 {}", status.code().unwrap_or(-1))
+    } else {
+        stderr
+    };
+    Err(format!("adb 传输失败: {}", message))
+}
+
+/// 推送本地文件到设备的内部实现，返回设备端目标路径
+///
+/// remote 以 `/` 结尾时视为目录，文件名沿用本地文件名
+pub async fn adb_push_impl(
+    app: tauri::AppHandle,
+    state: Arc<MaaState>,
+    instance_id: String,
+    local: String,
+    remote: String,
+) -> Result<String, String> {
+    let (adb_path, address) = adb_target(&state, &instance_id)?;
+    validate_remote_path(&remote)?;
+
+    let local_path = PathBuf::from(&local);
+    let metadata =
+        std::fs::metadata(&local_path).map_err(|e| format!("本地文件不存在: {} ({})", local, e))?;
+    if !metadata.is_file() {
+        return Err(format!("只能推送文件: {}", local));
+    }
+    let total = metadata.len();
+
+    let remote_target = if remote.ends_with('/') {
+        let file_name = local_path
+            .file_name()
+            .ok_or_else(|| format!("无法获取文件名: {}", local))?;
+        format!("{}{}", remote, file_name.to_string_lossy())
+    } else {
+        remote
+    };
+    info!(
+        "adb push: {} -> {}:{} ({} bytes)",
+        local, address, remote_target, total
+    );
+
+    tokio::task::spawn_blocking(move || {
+        let event = |transferred: u64| AdbTransferProgressEvent {
+            instance_id: instance_id.clone(),
+            direction: "push".to_string(),
+            local: local.clone(),
+            remote: remote_target.clone(),
+            transferred: transferred.min(total),
+            total,
+        };
+
+        let mut cmd = adb_command(&adb_path, &address);
+        cmd.arg("push").arg(&local_path).arg(&remote_target);
+        run_transfer(
+            cmd,
+            || remote_file_size(&adb_path, &address, &remote_target),
+            |transferred| {
+                let _ = app.emit("maa-adb-transfer-progress", event(transferred));
+            },
+        )?;
+        let _ = app.emit("maa-adb-transfer-progress", event(total));
+        Ok(remote_target)
+    })
+    .await
+    .map_err(|e| format!("adb push 任务执行失败: {}", e))?
+}
+
+/// 从设备拉取文件到本地的内部实现，返回本地目标路径
+///
+/// local 为已存在的目录时，文件名沿用设备端文件名
+pub async fn adb_pull_impl(
+    app: tauri::AppHandle,
+    state: Arc<MaaState>,
+    instance_id: String,
+    remote: String,
+    local: String,
+) -> Result<String, String> {
+    let (adb_path, address) = adb_target(&state, &instance_id)?;
+    validate_remote_path(&remote)?;
+
+    let mut local_path = PathBuf::from(&local);
+    if local_path.is_dir() {
+        let file_name = Path::new(&remote)
+            .file_name()
+            .ok_or_else(|| format!("无法获取文件名: {}", remote))?;
+        local_path.push(file_name);
+    } else if let Some(parent) = local_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|e| format!("创建目录失败: {}", e))?;
+    }
+    let local_target = local_path.to_string_lossy().to_string();
+
+    tokio::task::spawn_blocking(move || {
+        let total = remote_file_size(&adb_path, &address, &remote)
+            .ok_or_else(|| format!("设备文件不存在: {}", remote))?;
+        info!(
+            "adb pull: {}:{} -> {} ({} bytes)",
+            address, remote, local_target, total
+        );
+
+        let event = |transferred: u64| AdbTransferProgressEvent {
+            instance_id: instance_id.clone(),
+            direction: "pull".to_string(),
+            local: local_target.clone(),
+            remote: remote.clone(),
+            transferred: transferred.min(total),
+            total,
+        };
+
+        // 先拉取到同目录的临时文件，成功后再替换目标，失败时只清理本次创建的临时文件，
+        // 不影响目标位置原有的文件
+        let mut partial_name = local_path.file_name().unwrap_or_default().to_os_string();
+        partial_name.push(".mxu-pull");
+        let partial_path = local_path.with_file_name(partial_name);
+
+        let mut cmd = adb_command(&adb_path, &address);
+        cmd.arg("pull").arg(&remote).arg(&partial_path);
+        let result = run_transfer(
+            cmd,
+            || std::fs::metadata(&partial_path).ok().map(|m| m.len()),
+            |transferred| {
+                let _ = app.emit("maa-adb-transfer-progress", event(transferred));
+            },
+        )
+        .and_then(|()| {
+            std::fs::rename(&partial_path, &local_path).map_err(|e| format!("保存文件失败: {}", e))
+        });
+        if let Err(e) = result {
+            if partial_path.exists() {
+                if let Err(remove_err) = std::fs::remove_file(&partial_path) {
+                    warn!("adb pull: failed to remove partial file: {}", remove_err);
+                }
+            }
+            return Err(e);
+        }
+        let _ = app.emit("maa-adb-transfer-progress", event(total));
+        Ok(local_target)
+    })
+    .await
+    .map_err(|e| format!("adb pull 任务执行失败: {}", e))?
+}
+
+/// 通过实例已连接设备的 adb 推送本地文件到设备，返回设备端目标路径
+#[tauri::command]
+pub async fn maa_adb_push(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    local: String,
+    remote: String,
+) -> Result<String, String> {
+    adb_push_impl(app, state.inner().clone(), instance_id, local, remote).await
+}
+
+/// 通过实例已连接设备的 adb 从设备拉取文件到本地，返回本地目标路径
+#[tauri::command]
+pub async fn maa_adb_pull(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    remote: String,
+    local: String,
+) -> Result<String, String> {
+    adb_pull_impl(app, state.inner().clone(), instance_id, remote, local).await
+}
@@ -0,0 +1,278 @@
+//! Agent 子进程资源限制（Windows Job Object）
+//!
+//! 启动 agent 时将其加入一个全局 Job Object：
+//! - 可配置单个 agent 进程的内存上限，超限后分配失败，由系统限制而非拖垮整机
+//!   （agent 随之崩溃时走已有的崩溃重启流程）
+//! - 可配置所有 agent 合计的 CPU 配额（硬上限，按整机 CPU 的百分比）
+//! - Job 设置了 KILL_ON_JOB_CLOSE，MXU 退出（包括崩溃）时系统自动结束所有关联进程，避免遗留孤儿进程
+//!
+//! 修改上限立即对已在 Job 中的进程生效。非 Windows 平台不做任何限制。
+
+use std::sync::{LazyLock, Mutex};
+
+use log::info;
+#[cfg(windows)]
+use log::warn;
+
+use super::types::AgentResourceLimits;
+
+/// 当前资源限制设置
+static LIMITS: LazyLock<Mutex<AgentResourceLimits>> =
+    LazyLock::new(|| Mutex::new(AgentResourceLimits::default()));
+
+/// 全局 Job Object 句柄（首次启动 agent 时创建，进程生命周期内不关闭）
+#[cfg(windows)]
+static AGENT_JOB: Mutex<Option<winsafe::guard::CloseHandleGuard<windows_impl::HJOB>>> =
+    Mutex::new(None);
+
+/// 应用资源限制设置（启动时从配置读取，之后由前端修改）
+pub fn apply_agent_resource_limits(limits: AgentResourceLimits) {
+    info!(
+        "Agent resource limits: memory {} MB, cpu {}% (0 = unlimited)",
+        limits.memory_limit_mb, limits.cpu_rate_percent
+    );
+    if let Ok(mut current) = LIMITS.lock() {
+        *current = limits.clone();
+    }
+    #[cfg(windows)]
+    if let Ok(job) = AGENT_JOB.lock() {
+        if let Some(job) = job.as_ref() {
+            if let Err(e) = windows_impl::set_limits(job, &limits) {
+                warn!("Failed to update agent job limits: {}", e);
+            }
+        }
+    }
+}
+
+/// 将 agent 子进程加入 Job Object（首次调用时创建 Job），失败只记录日志
+///
+/// 在 spawn 之后立即调用；agent 在加入前已派生的子进程不受限制
+#[cfg(windows)]
+pub fn assign_agent_process(child: &std::process::Child) {
+    use std::os::windows::io::AsRawHandle;
+
+    let Ok(mut job) = AGENT_JOB.lock() else {
+        return;
+    };
+    if job.is_none() {
+        let limits = LIMITS.lock().map(|l| l.clone()).unwrap_or_default();
+        match windows_impl::create_job(&limits) {
+            Ok(handle) => {
+                info!("Agent job object created");
+                *job = Some(handle);
+            }
+            Err(e) => {
+                warn!("Failed to create agent job object: {}", e);
+                return;
+            }
+        }
+    }
+    let Some(handle) = job.as_ref() else {
+        return;
+    };
+    // 借用 Child 持有的进程句柄，不转移所有权
+    let process = unsafe { winsafe::HPROCESS::from_ptr(child.as_raw_handle()) };
+    if let Err(e) = windows_impl::assign_process(handle, &process) {
+        warn!(
+            "Failed to assign agent process {} to job object: {}",
+            child.id(),
+            e
+        );
+    }
+}
+
+#[cfg(not(windows))]
+pub fn assign_agent_process(child: &std::process::Child) {
+    let _ = child;
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use std::ffi::c_void;
+    use std::fmt;
+
+    use winsafe::guard::CloseHandleGuard;
+    use winsafe::prelude::Handle;
+    use winsafe::{GetLastError, HPROCESS};
+
+    use super::AgentResourceLimits;
+
+    const JOB_OBJECT_LIMIT_PROCESS_MEMORY: u32 = 0x0000_0100;
+    const JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE: u32 = 0x0000_2000;
+    const JOB_OBJECT_EXTENDED_LIMIT_INFORMATION_CLASS: i32 = 9;
+    const JOB_OBJECT_CPU_RATE_CONTROL_INFORMATION_CLASS: i32 = 15;
+    const JOB_OBJECT_CPU_RATE_CONTROL_ENABLE: u32 = 0x1;
+    const JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP: u32 = 0x4;
+
+    /// Job Object 句柄（WinSafe 未封装 Job Object，按其句柄约定补充，以便交给 CloseHandleGuard 管理）
+    #[repr(transparent)]
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    pub struct HJOB(*mut c_void);
+
+    unsafe impl Send for HJOB {}
+
+    impl fmt::Display for HJOB {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:#010x}", self.0 as usize)
+        }
+    }
+
+    impl fmt::LowerHex for HJOB {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::LowerHex::fmt(&(self.0 as usize), f)
+        }
+    }
+
+    impl fmt::UpperHex for HJOB {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::UpperHex::fmt(&(self.0 as usize), f)
+        }
+    }
+
+    impl Handle for HJOB {
+        const NULL: Self = Self(std::ptr::null_mut());
+        const INVALID: Self = Self(-1 as _);
+
+        unsafe fn from_ptr(p: *mut c_void) -> Self {
+            Self(p)
+        }
+
+        unsafe fn as_mut(&mut self) -> &mut *mut c_void {
+            &mut self.0
+        }
+
+        fn ptr(&self) -> *mut c_void {
+            self.0
+        }
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct BasicLimitInformation {
+        per_process_user_time_limit: i64,
+        per_job_user_time_limit: i64,
+        limit_flags: u32,
+        minimum_working_set_size: usize,
+        maximum_working_set_size: usize,
+        active_process_limit: u32,
+        affinity: usize,
+        priority_class: u32,
+        scheduling_class: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct IoCounters {
+        read_operation_count: u64,
+        write_operation_count: u64,
+        other_operation_count: u64,
+        read_transfer_count: u64,
+        write_transfer_count: u64,
+        other_transfer_count: u64,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct ExtendedLimitInformation {
+        basic_limit_information: BasicLimitInformation,
+        io_info: IoCounters,
+        process_memory_limit: usize,
+        job_memory_limit: usize,
+        peak_process_memory_used: usize,
+        peak_job_memory_used: usize,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct CpuRateControlInformation {
+        control_flags: u32,
+        cpu_rate: u32,
+    }
+
+    /// WinSafe 未封装的 Job Object API，单独声明
+    mod ffi {
+        use std::ffi::c_void;
+
+        #[link(name = "kernel32")]
+        extern "system" {
+            pub fn CreateJobObjectW(attributes: *const c_void, name: *const u16) -> *mut c_void;
+            pub fn SetInformationJobObject(
+                job: *mut c_void,
+                class: i32,
+                info: *const c_void,
+                length: u32,
+            ) -> i32;
+            pub fn AssignProcessToJobObject(job: *mut c_void, process: *mut c_void) -> i32;
+        }
+    }
+
+    pub fn create_job(limits: &AgentResourceLimits) -> Result<CloseHandleGuard<HJOB>, String> {
+        let ptr = unsafe { ffi::CreateJobObjectW(std::ptr::null(), std::ptr::null()) };
+        if ptr.is_null() {
+            return Err(GetLastError().to_string());
+        }
+        // 设置失败时由 guard 关闭句柄
+        let job = unsafe { CloseHandleGuard::new(HJOB::from_ptr(ptr)) };
+        set_limits(&job, limits)?;
+        Ok(job)
+    }
+
+    fn set_information<T>(job: &HJOB, class: i32, info: &T) -> Result<(), String> {
+        let ok = unsafe {
+            ffi::SetInformationJobObject(
+                job.ptr(),
+                class,
+                info as *const T as *const c_void,
+                std::mem::size_of::<T>() as u32,
+            )
+        };
+        if ok == 0 {
+            return Err(GetLastError().to_string());
+        }
+        Ok(())
+    }
+
+    pub fn set_limits(job: &HJOB, limits: &AgentResourceLimits) -> Result<(), String> {
+        let mut extended = ExtendedLimitInformation::default();
+        extended.basic_limit_information.limit_flags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+        if limits.memory_limit_mb > 0 {
+            extended.basic_limit_information.limit_flags |= JOB_OBJECT_LIMIT_PROCESS_MEMORY;
+            extended.process_memory_limit =
+                (limits.memory_limit_mb as usize).saturating_mul(1024 * 1024);
+        }
+        set_information(job, JOB_OBJECT_EXTENDED_LIMIT_INFORMATION_CLASS, &extended)
+            .map_err(|e| format!("内存上限设置失败: {}", e))?;
+
+        // CpuRate 以 1/10000 为单位，0 表示关闭 CPU 配额
+        let cpu = match limits.cpu_rate_percent.min(100) {
+            0 => CpuRateControlInformation::default(),
+            percent => CpuRateControlInformation {
+                control_flags: JOB_OBJECT_CPU_RATE_CONTROL_ENABLE
+                    | JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP,
+                cpu_rate: percent * 100,
+            },
+        };
+        set_information(job, JOB_OBJECT_CPU_RATE_CONTROL_INFORMATION_CLASS, &cpu)
+            .map_err(|e| format!("CPU 配额设置失败: {}", e))
+    }
+
+    pub fn assign_process(job: &HJOB, process: &HPROCESS) -> Result<(), String> {
+        let ok = unsafe { ffi::AssignProcessToJobObject(job.ptr(), process.ptr()) };
+        if ok == 0 {
+            return Err(GetLastError().to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 设置 agent 资源限制（持久化由前端写入配置）
+#[tauri::command]
+pub fn set_agent_resource_limits(limits: AgentResourceLimits) {
+    apply_agent_resource_limits(limits);
+}
+
+/// 查询当前 agent 资源限制
+#[tauri::command]
+pub fn get_agent_resource_limits() -> AgentResourceLimits {
+    LIMITS.lock().map(|l| l.clone()).unwrap_or_default()
+}
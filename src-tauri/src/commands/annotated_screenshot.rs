@@ -0,0 +1,298 @@
+//! 带识别标注的截图
+//!
+//! 记录每个实例当前一轮 next 列表中各节点最近一次的识别结果（由 `reco_stats` 在 context sink
+//! 中取到识别详情后转交），保存截图时在当前画面上画出识别框与匹配分数：命中为绿色，未命中
+//! 为红色（取分数最高的候选框）。相比 save_draw 的底层调试图，更适合直接附在反馈里。
+//! 没有识别结果时只保存原图。
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use log::info;
+use serde_json::Value;
+use tauri::State;
+
+use super::reco_stats::best_score;
+use super::types::{AnnotatedRecognition, AnnotatedScreenshotResult, MaaState};
+use super::utils::{encode_png, get_logs_dir};
+
+const HIT_COLOR: [u8; 4] = [0x22, 0xc5, 0x5e, 0xff];
+const MISS_COLOR: [u8; 4] = [0xef, 0x44, 0x44, 0xff];
+const TEXT_COLOR: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
+
+/// 各实例当前一轮的识别结果及原始识别详情（instance_id -> 按识别顺序的结果，同一节点只保留最近一次）
+static LAST_RECOGNITIONS: LazyLock<Mutex<HashMap<String, Vec<(AnnotatedRecognition, Value)>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 记录一次识别结果，detail 为识别算法输出的 JSON（含 all / best）
+pub fn record_recognition(instance_id: &str, node: &str, hit: bool, detail: &Value) {
+    let score_of = |v: &Value| v.get("score").and_then(|s| s.as_f64());
+    // 命中时取 best，未命中时取分数最高的候选
+    let candidate = detail.get("best").filter(|b| !b.is_null()).or_else(|| {
+        detail
+            .get("all")
+            .and_then(|all| all.as_array())
+            .and_then(|all| {
+                all.iter().max_by(|a, b| {
+                    score_of(a)
+                        .unwrap_or(f64::MIN)
+                        .total_cmp(&score_of(b).unwrap_or(f64::MIN))
+                })
+            })
+    });
+    let recognition = AnnotatedRecognition {
+        node: node.to_string(),
+        hit,
+        rect: candidate.and_then(|c| c.get("box")).and_then(parse_box),
+        score: best_score(detail),
+    };
+
+    if let Ok(mut records) = LAST_RECOGNITIONS.lock() {
+        let list = records.entry(instance_id.to_string()).or_default();
+        list.retain(|(r, _)| r.node != recognition.node);
+        list.push((recognition, detail.clone()));
+    }
+}
+
+/// 实例当前一轮各节点的识别结果及原始识别详情（导出问题包用）
+pub fn last_recognition_details(instance_id: &str) -> Vec<Value> {
+    LAST_RECOGNITIONS
+        .lock()
+        .ok()
+        .and_then(|records| {
+            let list = records.get(instance_id)?;
+            Some(
+                list.iter()
+                    .map(|(r, detail)| {
+                        serde_json::json!({
+                            "node": r.node,
+                            "hit": r.hit,
+                            "score": r.score,
+                            "box": r.rect,
+                            "detail": detail,
+                        })
+                    })
+                    .collect(),
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// 实例当前一轮各节点的识别结果（悬浮窗画面预览叠加识别框用）
+pub fn last_recognitions(instance_id: &str) -> Vec<AnnotatedRecognition> {
+    LAST_RECOGNITIONS
+        .lock()
+        .ok()
+        .and_then(|records| {
+            let list = records.get(instance_id)?;
+            Some(list.iter().map(|(r, _)| r.clone()).collect())
+        })
+        .unwrap_or_default()
+}
+
+/// 清空实例当前一轮的识别结果（进入新的 next 列表时调用）
+pub fn clear_recognitions(instance_id: &str) {
+    if let Ok(mut records) = LAST_RECOGNITIONS.lock() {
+        records.remove(instance_id);
+    }
+}
+
+/// 解析 `[x, y, w, h]` 形式的识别框
+fn parse_box(value: &Value) -> Option<[i32; 4]> {
+    let arr = value.as_array().filter(|arr| arr.len() >= 4)?;
+    let mut rect = [0i32; 4];
+    for (dst, src) in rect.iter_mut().zip(arr) {
+        *dst = src.as_i64()? as i32;
+    }
+    Some(rect)
+}
+
+/// 保存带标注截图的内部实现，返回保存路径与画上的识别结果
+pub fn save_annotated_screenshot_impl(
+    state: &MaaState,
+    instance_id: &str,
+) -> Result<AnnotatedScreenshotResult, String> {
+    let controller = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        instance
+            .controller
+            .clone()
+            .ok_or("Controller not connected")?
+    };
+    let png = controller
+        .cached_image()
+        .ok()
+        .and_then(|buf| buf.to_vec())
+        .filter(|data| !data.is_empty())
+        .ok_or("暂无截图")?;
+    let image =
+        tauri::image::Image::from_bytes(&png).map_err(|e| format!("解码截图失败: {}", e))?;
+    let (width, height) = (image.width(), image.height());
+    let mut rgba = image.rgba().to_vec();
+
+    let recognitions = last_recognitions(instance_id);
+
+    let mut canvas = Canvas {
+        width,
+        height,
+        rgba: &mut rgba,
+    };
+    let thickness = (width.max(height) / 480).max(2) as i32;
+    let scale = (width.max(height) / 400).max(2) as i32;
+    for recognition in &recognitions {
+        let Some([x, y, w, h]) = recognition.rect else {
+            continue;
+        };
+        let color = if recognition.hit {
+            HIT_COLOR
+        } else {
+            MISS_COLOR
+        };
+        canvas.stroke_rect(x, y, w, h, thickness, color);
+        if let Some(score) = recognition.score {
+            let text = format!("{:.3}", score);
+            let text_height = GLYPH_HEIGHT * scale + 2 * scale;
+            // 分数标签放在框上方，贴顶时放到框内
+            let label_y = if y >= text_height { y - text_height } else { y };
+            canvas.fill_rect(
+                x,
+                label_y,
+                text_width(&text, scale) + 2 * scale,
+                text_height,
+                color,
+            );
+            canvas.draw_text(x + scale, label_y + scale, &text, scale, TEXT_COLOR);
+        }
+    }
+
+    let dir = get_logs_dir().join("screenshots");
+    std::fs::create_dir_all(&dir).map_err(|e| format!("创建截图目录失败: {}", e))?;
+    let path = dir.join(format!(
+        "annotated_{}.png",
+        chrono::Local::now().format("%Y%m%d_%H%M%S")
+    ));
+    std::fs::write(&path, encode_png(width, height, &rgba))
+        .map_err(|e| format!("写入截图失败: {}", e))?;
+
+    info!(
+        "Annotated screenshot saved: {} ({} recognitions)",
+        path.display(),
+        recognitions.len()
+    );
+    Ok(AnnotatedScreenshotResult {
+        path: path.to_string_lossy().to_string(),
+        width,
+        height,
+        recognitions,
+    })
+}
+
+/// 移除实例的识别结果（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut entries) = LAST_RECOGNITIONS.lock() {
+        entries.remove(instance_id);
+    }
+}
+
+/// 保存当前截图，并画出最近一轮的识别框与分数（PNG，保存到 debug/screenshots）
+#[tauri::command]
+pub async fn maa_save_annotated_screenshot(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+) -> Result<AnnotatedScreenshotResult, String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || save_annotated_screenshot_impl(&state, &instance_id))
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|r| r)
+}
+
+// ============================================================================
+// 绘制
+// ============================================================================
+
+const GLYPH_WIDTH: i32 = 3;
+const GLYPH_HEIGHT: i32 = 5;
+
+/// 3x5 点阵字形（每行低 3 位从左到右），只需覆盖分数用到的字符
+fn glyph(c: char) -> Option<[u8; 5]> {
+    Some(match c {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b001, 0b001, 0b001],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        _ => return None,
+    })
+}
+
+/// 文本宽度（字间距 1 个点）
+fn text_width(text: &str, scale: i32) -> i32 {
+    let count = text.chars().count() as i32;
+    (count * (GLYPH_WIDTH + 1) - 1).max(0) * scale
+}
+
+struct Canvas<'a> {
+    width: u32,
+    height: u32,
+    rgba: &'a mut [u8],
+}
+
+impl Canvas<'_> {
+    /// 填充矩形，超出画布的部分被裁掉
+    fn fill_rect(&mut self, x: i32, y: i32, w: i32, h: i32, color: [u8; 4]) {
+        let x0 = x.clamp(0, self.width as i32) as usize;
+        let x1 = (x + w).clamp(0, self.width as i32) as usize;
+        let y0 = y.clamp(0, self.height as i32) as usize;
+        let y1 = (y + h).clamp(0, self.height as i32) as usize;
+        if x1 <= x0 || y1 <= y0 {
+            return;
+        }
+        for row in y0..y1 {
+            let start = (row * self.width as usize + x0) * 4;
+            let end = (row * self.width as usize + x1) * 4;
+            for px in self.rgba[start..end].chunks_exact_mut(4) {
+                px.copy_from_slice(&color);
+            }
+        }
+    }
+
+    /// 描边矩形（线宽向框内延伸）
+    fn stroke_rect(&mut self, x: i32, y: i32, w: i32, h: i32, thickness: i32, color: [u8; 4]) {
+        let t = thickness.min(w).min(h).max(1);
+        self.fill_rect(x, y, w, t, color);
+        self.fill_rect(x, y + h - t, w, t, color);
+        self.fill_rect(x, y, t, h, color);
+        self.fill_rect(x + w - t, y, t, h, color);
+    }
+
+    fn draw_text(&mut self, x: i32, y: i32, text: &str, scale: i32, color: [u8; 4]) {
+        let mut cursor = x;
+        for c in text.chars() {
+            if let Some(rows) = glyph(c) {
+                for (row, bits) in rows.iter().enumerate() {
+                    for col in 0..GLYPH_WIDTH {
+                        if bits & (1 << (GLYPH_WIDTH - 1 - col)) != 0 {
+                            self.fill_rect(
+                                cursor + col * scale,
+                                y + row as i32 * scale,
+                                scale,
+                                scale,
+                                color,
+                            );
+                        }
+                    }
+                }
+            }
+            cursor += (GLYPH_WIDTH + 1) * scale;
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! 截图与识别性能测试
+//!
+//! 连续截图统计耗时与帧率，并用独立的临时 Tasker 跑一组不依赖资源图片的识别，
+//! 结果导出为 JSON 保存到日志目录，便于对比截图方式和上报性能问题
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{debug, info, warn};
+use maa_framework::tasker::Tasker;
+use maa_framework::MaaStatus;
+use tauri::State;
+
+use super::screenshot_preprocess::active_config as active_preprocess;
+use super::system::{apply_log_level, current_log_level};
+use super::types::{BenchmarkRecognition, BenchmarkResult, BenchmarkStat, MaaState};
+use super::utils::{get_logs_dir, png_size};
+
+/// 默认截图次数
+const DEFAULT_SCREENCAP_ROUNDS: u32 = 20;
+/// 截图次数上限
+const MAX_SCREENCAP_ROUNDS: u32 = 200;
+/// 每种识别的运行次数
+const RECOGNITION_ROUNDS: u32 = 5;
+/// 等待识别任务完成的轮询间隔
+const TASK_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// 参与测试的识别：(名称, 节点定义)，均不依赖资源中的模板图片
+fn recognition_cases() -> Vec<(&'static str, serde_json::Value)> {
+    let base = |recognition: serde_json::Value| {
+        let mut node = serde_json::json!({
+            "action": "DoNothing",
+            "next": [],
+            "timeout": 0,
+            "rate_limit": 0,
+            "pre_delay": 0,
+            "post_delay": 0,
+        });
+        if let (Some(node), serde_json::Value::Object(fields)) = (node.as_object_mut(), recognition)
+        {
+            node.extend(fields);
+        }
+        node
+    };
+    vec![
+        (
+            "ColorMatch",
+            base(serde_json::json!({
+                "recognition": "ColorMatch",
+                "lower": [0, 0, 0],
+                "upper": [255, 255, 255],
+                "count": 1,
+            })),
+        ),
+        // 需要资源中包含 OCR 模型，缺失时计为失败
+        ("OCR", base(serde_json::json!({ "recognition": "OCR" }))),
+    ]
+}
+
+/// 按耗时样本统计
+fn summarize(samples: &[f64], failures: u32) -> BenchmarkStat {
+    if samples.is_empty() {
+        return BenchmarkStat {
+            failures,
+            ..Default::default()
+        };
+    }
+    BenchmarkStat {
+        rounds: samples.len() as u32,
+        failures,
+        avg_ms: samples.iter().sum::<f64>() / samples.len() as f64,
+        min_ms: samples.iter().copied().fold(f64::INFINITY, f64::min),
+        max_ms: samples.iter().copied().fold(0.0, f64::max),
+    }
+}
+
+/// 性能测试的内部实现
+fn benchmark_impl(
+    state: &MaaState,
+    instance_id: &str,
+    rounds: u32,
+) -> Result<BenchmarkResult, String> {
+    let (controller, resource, controller_config) = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        if instance.tasker.as_ref().is_some_and(|t| t.running()) {
+            return Err("任务运行中，请停止任务后再进行性能测试".to_string());
+        }
+        let controller = instance
+            .controller
+            .as_ref()
+            .ok_or("Controller not connected")?
+            .clone();
+        (
+            controller,
+            instance.resource.clone(),
+            instance.controller_config.clone(),
+        )
+    };
+
+    info!(
+        "[benchmark] Start: instance={}, screencap rounds={}",
+        instance_id, rounds
+    );
+
+    // 截图
+    let mut samples = Vec::with_capacity(rounds as usize);
+    let mut failures = 0;
+    for round in 0..rounds {
+        let started = Instant::now();
+        let id = controller.post_screencap().map_err(|e| e.to_string())?;
+        let status = controller.wait(id);
+        let elapsed = started.elapsed().as_secs_f64() * 1000.0;
+        if status == MaaStatus::SUCCEEDED {
+            debug!("[benchmark] screencap #{}: {:.1} ms", round + 1, elapsed);
+            samples.push(elapsed);
+        } else {
+            warn!("[benchmark] screencap #{} failed", round + 1);
+            failures += 1;
+        }
+    }
+    let screencap = summarize(&samples, failures);
+    let resolution = controller
+        .cached_image()
+        .ok()
+        .and_then(|buf| buf.to_vec())
+        .and_then(|png| png_size(&png));
+    let fps = if screencap.avg_ms > 0.0 {
+        1000.0 / screencap.avg_ms
+    } else {
+        0.0
+    };
+    info!(
+        "[benchmark] screencap avg {:.1} ms ({:.1} fps), {} failure(s)",
+        screencap.avg_ms, fps, screencap.failures
+    );
+
+    // 识别：使用未挂载回调的临时 Tasker，避免影响实例的任务状态与日志
+    let mut recognitions = Vec::new();
+    match resource {
+        Some(resource) => {
+            let tasker = Tasker::new().map_err(|e| e.to_string())?;
+            tasker
+                .bind(&resource, &controller)
+                .map_err(|e| e.to_string())?;
+            for (name, node) in recognition_cases() {
+                let entry = format!("MXU_BENCHMARK_{}", name);
+                let mut pipeline_override = serde_json::Map::new();
+                pipeline_override.insert(entry.clone(), node);
+                let pipeline_override = serde_json::Value::Object(pipeline_override).to_string();
+                let mut samples = Vec::new();
+                let mut failures = 0;
+                for round in 0..RECOGNITION_ROUNDS {
+                    let started = Instant::now();
+                    let job = tasker
+                        .post_task(&entry, &pipeline_override)
+                        .map_err(|e| e.to_string())?;
+                    let status = loop {
+                        let status = tasker
+                            .get_task_detail(job.id)
+                            .map_err(|e| e.to_string())?
+                            .map(|d| d.status)
+                            .unwrap_or(MaaStatus::INVALID);
+                        if status != MaaStatus::PENDING && status != MaaStatus::RUNNING {
+                            break status;
+                        }
+                        std::thread::sleep(TASK_POLL_INTERVAL);
+                    };
+                    let elapsed = started.elapsed().as_secs_f64() * 1000.0;
+                    let succeeded = status == MaaStatus::SUCCEEDED;
+                    debug!(
+                        "[benchmark] {} #{}: {:.1} ms, succeeded={}",
+                        name,
+                        round + 1,
+                        elapsed,
+                        succeeded
+                    );
+                    if succeeded {
+                        samples.push(elapsed);
+                    } else {
+                        failures += 1;
+                    }
+                }
+                let task = summarize(&samples, failures);
+                let recognition_avg_ms = (task.avg_ms - screencap.avg_ms).max(0.0);
+                info!(
+                    "[benchmark] {} avg {:.1} ms (recognition ~{:.1} ms), {} failure(s)",
+                    name, task.avg_ms, recognition_avg_ms, task.failures
+                );
+                recognitions.push(BenchmarkRecognition {
+                    name: name.to_string(),
+                    task,
+                    recognition_avg_ms,
+                });
+            }
+        }
+        None => info!("[benchmark] Resource not loaded, recognition skipped"),
+    }
+
+    let now = chrono::Local::now();
+    let mut result = BenchmarkResult {
+        instance_id: instance_id.to_string(),
+        controller_config,
+        resolution,
+        screencap,
+        fps,
+        recognitions,
+        preprocess: active_preprocess(instance_id),
+        report_path: None,
+        timestamp: now.to_rfc3339(),
+    };
+
+    // 导出为 JSON
+    let dir = get_logs_dir();
+    let path = dir.join(format!("benchmark_{}.json", now.format("%Y%m%d_%H%M%S")));
+    let export = std::fs::create_dir_all(&dir)
+        .map_err(|e| e.to_string())
+        .and_then(|_| serde_json::to_string_pretty(&result).map_err(|e| e.to_string()))
+        .and_then(|json| std::fs::write(&path, json).map_err(|e| e.to_string()));
+    match export {
+        Ok(()) => result.report_path = Some(path.to_string_lossy().to_string()),
+        Err(e) => warn!("[benchmark] Failed to export result: {}", e),
+    }
+
+    Ok(result)
+}
+
+/// 测试实例的截图与识别性能：连续截图 rounds 次（默认 20），再运行一组识别，
+/// 返回平均耗时与帧率，结果同时导出到日志目录。测试期间临时将日志级别提高到 debug
+#[tauri::command]
+pub async fn maa_benchmark(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    rounds: Option<u32>,
+) -> Result<BenchmarkResult, String> {
+    let state = state.inner().clone();
+    let rounds = rounds
+        .unwrap_or(DEFAULT_SCREENCAP_ROUNDS)
+        .clamp(1, MAX_SCREENCAP_ROUNDS);
+
+    tokio::task::spawn_blocking(move || {
+        let maafw_loaded = state.lib_dir.lock().map(|l| l.is_some()).unwrap_or(false);
+        let previous_level = current_log_level().filter(|level| level != "debug");
+        if previous_level.is_some() {
+            if let Err(e) = apply_log_level("debug", maafw_loaded) {
+                warn!("[benchmark] Failed to raise log level: {}", e);
+            }
+        }
+
+        let result = benchmark_impl(&state, &instance_id, rounds);
+
+        if let Some(level) = previous_level {
+            if let Err(e) = apply_log_level(&level, maafw_loaded) {
+                warn!("[benchmark] Failed to restore log level: {}", e);
+            }
+        }
+        result
+    })
+    .await
+    .map_err(|e| format!("性能测试任务执行失败: {}", e))?
+}
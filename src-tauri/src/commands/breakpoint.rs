@@ -0,0 +1,240 @@
+//! Pipeline 断点调试
+//!
+//! 任务执行到断点节点时（识别命中后、执行动作前）在 context sink 回调中阻塞任务线程，
+//! 发送带当前截图和识别结果的 `maa-breakpoint-hit` 事件，前端确认后继续执行
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Condvar, LazyLock, Mutex};
+use std::time::Duration;
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use log::{info, warn};
+use tauri::{AppHandle, Emitter};
+
+use super::types::MaaState;
+
+/// 暂停期间检查停止请求的间隔
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// 单个实例的断点状态
+#[derive(Default)]
+struct InstanceBreakpoints {
+    nodes: HashSet<String>,
+    /// 当前暂停所在的节点
+    paused_node: Option<String>,
+    /// 最近一次识别回调的详情（断点命中时随事件发送）
+    last_recognition: Option<serde_json::Value>,
+}
+
+/// 各实例断点状态（instance_id -> 状态）及暂停/继续通知
+static BREAKPOINTS: LazyLock<Mutex<HashMap<String, InstanceBreakpoints>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static BREAKPOINT_RESUMED: Condvar = Condvar::new();
+
+/// 断点命中事件载荷
+#[derive(Clone, serde::Serialize)]
+pub struct BreakpointHitEvent {
+    pub instance_id: String,
+    pub node: String,
+    pub task_id: Option<i64>,
+    /// 命中时的截图（PNG data URL）
+    pub screenshot: Option<String>,
+    /// 命中节点的识别回调详情
+    pub recognition: Option<serde_json::Value>,
+}
+
+/// 断点继续事件载荷
+#[derive(Clone, serde::Serialize)]
+pub struct BreakpointResumedEvent {
+    pub instance_id: String,
+    pub node: String,
+}
+
+/// 处理 context sink 回调：记录识别结果，命中断点节点时阻塞直到继续或停止
+///
+/// 应在 context sink 中、`emit_callback_event` 之前调用；未设置断点时立即返回
+pub fn handle_breakpoint_callback(
+    maa_state: &Arc<MaaState>,
+    app: &AppHandle,
+    instance_id: &str,
+    message: &str,
+    details: &str,
+) {
+    let is_recognition =
+        message.starts_with("Node.Recognition.") && message != "Node.Recognition.Starting";
+    let is_node_starting = message == "Node.PipelineNode.Starting";
+    if !is_recognition && !is_node_starting {
+        return;
+    }
+
+    let Ok(mut breakpoints) = BREAKPOINTS.lock() else {
+        return;
+    };
+    let Some(state) = breakpoints
+        .get_mut(instance_id)
+        .filter(|s| !s.nodes.is_empty())
+    else {
+        return;
+    };
+
+    let details: Option<serde_json::Value> = serde_json::from_str(details).ok();
+    if is_recognition {
+        state.last_recognition = details;
+        return;
+    }
+
+    let Some(node) = details
+        .as_ref()
+        .and_then(|d| d.get("name"))
+        .and_then(|v| v.as_str())
+        .filter(|name| state.nodes.contains(*name))
+        .map(str::to_string)
+    else {
+        return;
+    };
+
+    state.paused_node = Some(node.clone());
+    let recognition = state
+        .last_recognition
+        .take()
+        .filter(|r| r.get("name").and_then(|v| v.as_str()) == Some(node.as_str()));
+    drop(breakpoints);
+
+    info!("[breakpoint] Paused at node '{}' ({})", node, instance_id);
+    let screenshot = maa_state.instances.lock().ok().and_then(|instances| {
+        instances
+            .get(instance_id)
+            .and_then(|i| i.controller.as_ref())
+            .and_then(|c| c.cached_image().ok())
+            .and_then(|buf| buf.to_vec())
+            .filter(|data| !data.is_empty())
+            .map(|png| format!("data:image/png;base64,{}", STANDARD.encode(png)))
+    });
+    let event = BreakpointHitEvent {
+        instance_id: instance_id.to_string(),
+        node: node.clone(),
+        task_id: details
+            .as_ref()
+            .and_then(|d| d.get("task_id"))
+            .and_then(|v| v.as_i64()),
+        screenshot,
+        recognition,
+    };
+    if let Err(e) = app.emit("maa-breakpoint-hit", event) {
+        warn!("[breakpoint] Failed to emit maa-breakpoint-hit: {}", e);
+    }
+
+    // 阻塞任务线程，直到继续、断点被清除或任务被停止
+    let Ok(mut breakpoints) = BREAKPOINTS.lock() else {
+        return;
+    };
+    loop {
+        let paused = breakpoints
+            .get(instance_id)
+            .is_some_and(|s| s.paused_node.is_some());
+        if !paused {
+            break;
+        }
+        let stopping = maa_state
+            .instances
+            .lock()
+            .ok()
+            .and_then(|instances| instances.get(instance_id).map(|i| i.stop_in_progress))
+            .unwrap_or(true);
+        if stopping {
+            info!("[breakpoint] Stop requested while paused at '{}'", node);
+            if let Some(state) = breakpoints.get_mut(instance_id) {
+                state.paused_node = None;
+            }
+            break;
+        }
+        breakpoints = match BREAKPOINT_RESUMED.wait_timeout(breakpoints, PAUSE_POLL_INTERVAL) {
+            Ok((guard, _)) => guard,
+            Err(_) => return,
+        };
+    }
+    drop(breakpoints);
+
+    info!(
+        "[breakpoint] Resumed from node '{}' ({})",
+        node, instance_id
+    );
+    let _ = app.emit(
+        "maa-breakpoint-resumed",
+        BreakpointResumedEvent {
+            instance_id: instance_id.to_string(),
+            node,
+        },
+    );
+}
+
+/// 实例当前是否停在断点上
+pub fn is_paused(instance_id: &str) -> bool {
+    BREAKPOINTS
+        .lock()
+        .ok()
+        .is_some_and(|b| b.get(instance_id).is_some_and(|s| s.paused_node.is_some()))
+}
+
+/// 移除实例的断点并放行暂停中的任务（销毁实例或重置运行时状态时调用）
+pub fn clear_breakpoints(instance_id: &str) {
+    if let Ok(mut breakpoints) = BREAKPOINTS.lock() {
+        if breakpoints.remove(instance_id).is_some() {
+            info!("[breakpoint] Cleared for instance {}", instance_id);
+        }
+    }
+    BREAKPOINT_RESUMED.notify_all();
+}
+
+/// 设置实例的断点节点列表（覆盖原有断点），传空列表移除所有断点并恢复正常运行
+#[tauri::command]
+pub fn set_breakpoints(instance_id: String, node_names: Vec<String>) -> Result<(), String> {
+    let nodes: HashSet<String> = node_names
+        .into_iter()
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+        .collect();
+    info!("set_breakpoints: {} -> {:?}", instance_id, nodes);
+
+    let mut breakpoints = BREAKPOINTS.lock().map_err(|e| e.to_string())?;
+    if nodes.is_empty() {
+        breakpoints.remove(&instance_id);
+    } else {
+        let state = breakpoints.entry(instance_id).or_default();
+        // 当前暂停节点不再是断点时直接放行
+        if state
+            .paused_node
+            .as_ref()
+            .is_some_and(|node| !nodes.contains(node))
+        {
+            state.paused_node = None;
+        }
+        state.nodes = nodes;
+    }
+    BREAKPOINT_RESUMED.notify_all();
+    Ok(())
+}
+
+/// 获取实例当前设置的断点节点列表
+#[tauri::command]
+pub fn get_breakpoints(instance_id: String) -> Result<Vec<String>, String> {
+    let breakpoints = BREAKPOINTS.lock().map_err(|e| e.to_string())?;
+    let mut nodes: Vec<String> = breakpoints
+        .get(&instance_id)
+        .map(|s| s.nodes.iter().cloned().collect())
+        .unwrap_or_default();
+    nodes.sort();
+    Ok(nodes)
+}
+
+/// 从当前断点继续执行，返回是否有处于暂停状态的任务
+#[tauri::command]
+pub fn continue_from_breakpoint(instance_id: String) -> Result<bool, String> {
+    let mut breakpoints = BREAKPOINTS.lock().map_err(|e| e.to_string())?;
+    let resumed = breakpoints
+        .get_mut(&instance_id)
+        .and_then(|s| s.paused_node.take())
+        .is_some();
+    BREAKPOINT_RESUMED.notify_all();
+    Ok(resumed)
+}
@@ -0,0 +1,228 @@
+//! Win32 截图防遮挡模式
+//!
+//! Win32 控制器的默认截图方式在目标窗口被其他窗口遮挡或最小化时会截到错误内容。
+//! 按实例提供两种处理：
+//! - `foreground`：任务开始及每轮识别（`Node.NextList.Starting`）前，把目标窗口恢复并提到前台
+//! - `print_window`：连接时改用 PrintWindow 截图绕过遮挡。部分硬件加速窗口用 PrintWindow
+//!   只能截到黑屏，因此连接前先试截一次，无效时保持原截图方式
+//!
+//! `print_window` 在下次连接时生效，`foreground` 立即生效
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use log::{info, warn};
+
+use super::types::{CaptureMode, ControllerConfig, MaaState};
+
+/// 各实例的截图防遮挡模式（未设置的为 Default）
+static CAPTURE_MODES: LazyLock<Mutex<HashMap<String, CaptureMode>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 获取实例的截图防遮挡模式
+pub fn capture_mode(instance_id: &str) -> CaptureMode {
+    CAPTURE_MODES
+        .lock()
+        .ok()
+        .and_then(|modes| modes.get(instance_id).copied())
+        .unwrap_or_default()
+}
+
+/// 连接 Win32 控制器前计算实际使用的截图方式
+///
+/// PrintWindow 模式下先对窗口试截一次，有效时改用 PrintWindow，否则保持配置的截图方式
+pub fn win32_screencap_method(instance_id: &str, handle: u64, configured: u64) -> u64 {
+    if capture_mode(instance_id) != CaptureMode::PrintWindow {
+        return configured;
+    }
+    if print_window_works(handle) {
+        info!(
+            "[capture_mode] {}: PrintWindow probe succeeded, using PrintWindow",
+            instance_id
+        );
+        maa_framework::common::Win32ScreencapMethod::PRINT_WINDOW.bits()
+    } else {
+        warn!(
+            "[capture_mode] {}: PrintWindow probe failed (window minimized or hardware accelerated), \
+             falling back to configured method {}",
+            instance_id, configured
+        );
+        configured
+    }
+}
+
+/// 前台模式下，在任务开始和每轮识别前把目标窗口提到前台
+///
+/// 在 tasker sink 和 context sink 中调用，其余消息直接返回
+pub fn before_capture(state: &MaaState, instance_id: &str, message: &str) {
+    if message != "Tasker.Task.Starting" && message != "Node.NextList.Starting" {
+        return;
+    }
+    if capture_mode(instance_id) != CaptureMode::Foreground {
+        return;
+    }
+    let handle = {
+        let Ok(instances) = state.instances.lock() else {
+            return;
+        };
+        match instances
+            .get(instance_id)
+            .and_then(|i| i.controller_config.as_ref())
+        {
+            Some(ControllerConfig::Win32 { handle, .. })
+            | Some(ControllerConfig::Gamepad { handle, .. }) => *handle,
+            _ => return,
+        }
+    };
+    bring_to_foreground(handle);
+}
+
+/// 恢复最小化的窗口并提到前台（已在前台时不做任何操作）
+#[cfg(windows)]
+fn bring_to_foreground(handle: u64) {
+    use winsafe::{co::SW, HWND};
+
+    if handle == 0 {
+        return;
+    }
+    let hwnd = unsafe { HWND::from_ptr(handle as *mut _) };
+    if !hwnd.IsWindow() {
+        return;
+    }
+    if hwnd.IsIconic() {
+        log::debug!("[capture_mode] restoring minimized window 0x{:X}", handle);
+        hwnd.ShowWindow(SW::RESTORE);
+    }
+    if HWND::GetForegroundWindow().as_ref() != Some(&hwnd) {
+        log::debug!(
+            "[capture_mode] bringing window 0x{:X} to foreground",
+            handle
+        );
+        // 后台进程抢前台可能被系统拒绝，此时至少提到 Z 序顶部
+        if !hwnd.SetForegroundWindow() {
+            let _ = hwnd.BringWindowToTop();
+        }
+    }
+}
+
+#[cfg(not(windows))]
+fn bring_to_foreground(handle: u64) {
+    let _ = handle;
+}
+
+/// 用 PrintWindow 对窗口客户区试截一次，截图成功且不是全黑时返回 true
+#[cfg(windows)]
+fn print_window_works(handle: u64) -> bool {
+    use winsafe::{co, BITMAPINFO, HWND};
+
+    if handle == 0 {
+        return false;
+    }
+    let hwnd = unsafe { HWND::from_ptr(handle as *mut _) };
+    if !hwnd.IsWindow() || hwnd.IsIconic() {
+        return false;
+    }
+    let Ok(rect) = hwnd.GetClientRect() else {
+        return false;
+    };
+    let (width, height) = (rect.right - rect.left, rect.bottom - rect.top);
+    if width <= 0 || height <= 0 {
+        return false;
+    }
+
+    let Ok(screen_dc) = HWND::DESKTOP.GetDC() else {
+        return false;
+    };
+    let (Ok(mem_dc), Ok(bitmap)) = (
+        screen_dc.CreateCompatibleDC(),
+        screen_dc.CreateCompatibleBitmap(width, height),
+    ) else {
+        return false;
+    };
+
+    let printed = {
+        // GetDIBits 要求位图未被选入 DC，guard 离开作用域时换回原对象
+        let Ok(_selected) = mem_dc.SelectObject(&*bitmap) else {
+            return false;
+        };
+        unsafe {
+            ffi::PrintWindow(
+                hwnd.ptr(),
+                mem_dc.ptr(),
+                ffi::PW_CLIENTONLY | ffi::PW_RENDERFULLCONTENT,
+            ) != 0
+        }
+    };
+    if !printed {
+        return false;
+    }
+
+    let mut bmi = BITMAPINFO::default();
+    bmi.bmiHeader.biWidth = width;
+    bmi.bmiHeader.biHeight = -height;
+    bmi.bmiHeader.biPlanes = 1;
+    bmi.bmiHeader.biBitCount = 32;
+    bmi.bmiHeader.biCompression = co::BI::RGB;
+    let mut pixels = vec![0u8; (width * height * 4) as usize];
+    let lines = unsafe {
+        mem_dc.GetDIBits(
+            &bitmap,
+            0,
+            height as u32,
+            Some(&mut pixels),
+            &mut bmi,
+            co::DIB::RGB_COLORS,
+        )
+    };
+    // 硬件加速窗口 PrintWindow 往往返回成功但内容全黑
+    matches!(lines, Ok(n) if n == height)
+        && pixels
+            .chunks_exact(4)
+            .any(|px| px[0] != 0 || px[1] != 0 || px[2] != 0)
+}
+
+#[cfg(not(windows))]
+fn print_window_works(handle: u64) -> bool {
+    let _ = handle;
+    false
+}
+
+/// WinSafe 未封装 PrintWindow，单独声明
+#[cfg(windows)]
+mod ffi {
+    use std::ffi::c_void;
+
+    pub const PW_CLIENTONLY: u32 = 0x1;
+    pub const PW_RENDERFULLCONTENT: u32 = 0x2;
+
+    #[link(name = "user32")]
+    extern "system" {
+        pub fn PrintWindow(hwnd: *mut c_void, hdc: *mut c_void, flags: u32) -> i32;
+    }
+}
+
+/// 移除实例的截图模式设置（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut entries) = CAPTURE_MODES.lock() {
+        entries.remove(instance_id);
+    }
+}
+
+/// 设置实例的截图防遮挡模式（前台模式立即生效，PrintWindow 模式下次连接时生效）
+#[tauri::command]
+pub fn set_capture_mode(instance_id: String, mode: CaptureMode) -> Result<(), String> {
+    info!("set_capture_mode: {} -> {:?}", instance_id, mode);
+    let mut modes = CAPTURE_MODES.lock().map_err(|e| e.to_string())?;
+    if mode == CaptureMode::Default {
+        modes.remove(&instance_id);
+    } else {
+        modes.insert(instance_id, mode);
+    }
+    Ok(())
+}
+
+/// 查询实例的截图防遮挡模式
+#[tauri::command]
+pub fn get_capture_mode(instance_id: String) -> CaptureMode {
+    capture_mode(&instance_id)
+}
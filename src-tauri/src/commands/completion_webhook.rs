@@ -0,0 +1,153 @@
+//! 任务队列完成 webhook
+//!
+//! 启用后，实例的任务队列全部结束时向用户配置的 URL POST 一份运行摘要 JSON
+//! （各任务结果、耗时、是否有错误），面向"整轮跑完通知我"的场景。
+//! 请求体结构见 `CompletionWebhookPayload`，`schema` 字段标识版本，便于用户对接。
+//! 在后台线程发送，失败按间隔重试几次，只记录日志，不影响任务结果。
+
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+
+use super::types::{
+    CompletionWebhookPayload, CompletionWebhookSettings, CompletionWebhookTask, InstanceRuntime,
+};
+
+/// 请求体结构版本
+const PAYLOAD_SCHEMA: u32 = 1;
+
+/// 单次请求超时
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 失败重试间隔（次数即数组长度）
+const RETRY_DELAYS: &[Duration] = &[
+    Duration::from_secs(2),
+    Duration::from_secs(5),
+    Duration::from_secs(15),
+];
+
+/// 当前 webhook 设置
+static SETTINGS: LazyLock<Mutex<CompletionWebhookSettings>> =
+    LazyLock::new(|| Mutex::new(CompletionWebhookSettings::default()));
+
+/// 应用 webhook 设置（启动时从配置读取，之后由前端修改）
+pub fn apply_completion_webhook(settings: CompletionWebhookSettings) {
+    info!(
+        "Completion webhook enabled: {}, url set: {}",
+        settings.enabled,
+        !settings.url.trim().is_empty()
+    );
+    if let Ok(mut current) = SETTINGS.lock() {
+        *current = settings;
+    }
+}
+
+/// 返回启用且已配置的 webhook URL
+fn webhook_url() -> Option<String> {
+    let settings = SETTINGS.lock().ok()?;
+    let url = settings.url.trim();
+    (settings.enabled && !url.is_empty()).then(|| url.to_string())
+}
+
+/// 根据实例本轮的任务记录生成运行摘要
+fn build_payload(instance_id: &str, instance: &InstanceRuntime) -> CompletionWebhookPayload {
+    let run_state = &instance.task_run_state;
+    let tasks: Vec<CompletionWebhookTask> = instance
+        .task_report
+        .iter()
+        .map(|record| CompletionWebhookTask {
+            task_id: record.task_id,
+            selected_task_id: run_state.mappings.get(&record.task_id).cloned(),
+            entry: record.entry.clone(),
+            status: record.status.clone(),
+            duration_ms: record.duration_ms,
+        })
+        .collect();
+    let has_error = tasks
+        .iter()
+        .any(|t| t.status.as_deref() != Some("succeeded"));
+    let duration_ms = instance
+        .task_report
+        .iter()
+        .map(|r| r.started_at)
+        .min()
+        .map(|start| Instant::now().duration_since(start).as_millis() as u64)
+        .unwrap_or(0);
+
+    CompletionWebhookPayload {
+        schema: PAYLOAD_SCHEMA,
+        event: "tasks_completed",
+        instance_id: instance_id.to_string(),
+        instance_name: instance.name.clone(),
+        status: run_state
+            .overall_status
+            .clone()
+            .unwrap_or_else(|| if has_error { "Failed" } else { "Succeeded" }.to_string()),
+        has_error,
+        finished_at: chrono::Local::now().to_rfc3339(),
+        duration_ms,
+        tasks,
+    }
+}
+
+/// 发送 webhook，失败时按 `RETRY_DELAYS` 重试
+fn send_with_retry(url: &str, payload: &CompletionWebhookPayload) {
+    let client = match reqwest::blocking::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+    {
+        Ok(c) => c,
+        Err(e) => {
+            warn!("[completion_webhook] Failed to build HTTP client: {}", e);
+            return;
+        }
+    };
+
+    for attempt in 0..=RETRY_DELAYS.len() {
+        if attempt > 0 {
+            std::thread::sleep(RETRY_DELAYS[attempt - 1]);
+        }
+        match client.post(url).json(payload).send() {
+            Ok(resp) if resp.status().is_success() => {
+                info!(
+                    "[completion_webhook] Sent for instance {} ({})",
+                    payload.instance_id,
+                    resp.status()
+                );
+                return;
+            }
+            Ok(resp) => warn!(
+                "[completion_webhook] Attempt {} got status {}",
+                attempt + 1,
+                resp.status()
+            ),
+            Err(e) => warn!("[completion_webhook] Attempt {} failed: {}", attempt + 1, e),
+        }
+    }
+    warn!(
+        "[completion_webhook] Giving up for instance {} after {} attempts",
+        payload.instance_id,
+        RETRY_DELAYS.len() + 1
+    );
+}
+
+/// 任务队列全部完成时调用：已启用则在后台线程发送运行摘要
+pub fn notify_tasks_completed(instance_id: &str, instance: &InstanceRuntime) {
+    let Some(url) = webhook_url() else {
+        return;
+    };
+    let payload = build_payload(instance_id, instance);
+    if let Err(e) = std::thread::Builder::new()
+        .name("completion-webhook".to_string())
+        .spawn(move || send_with_retry(&url, &payload))
+    {
+        warn!("[completion_webhook] Failed to spawn sender thread: {}", e);
+    }
+}
+
+/// 设置任务队列完成 webhook（持久化由前端写入配置）
+#[tauri::command]
+pub fn set_completion_webhook(settings: CompletionWebhookSettings) {
+    apply_completion_webhook(settings);
+}
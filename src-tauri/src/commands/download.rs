@@ -2,20 +2,60 @@
 //!
 //! 提供流式文件下载功能，支持进度回调和取消
 
-use log::{error, info, warn};
+use log::{info, warn};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
 
 use tauri::Emitter;
 
+use super::error::{MxuError, MxuErrorCode, WithErrorCode};
+use super::proxy::resolve_proxy;
 use super::types::GitHubRelease;
-use reqwest::header::{ACCEPT, AUTHORIZATION, USER_AGENT};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, AUTHORIZATION, USER_AGENT};
 
-use super::types::{DownloadProgressEvent, DownloadResult};
+use super::types::{DownloadProgressEvent, DownloadResult, NetworkProbeResult};
 use super::update::move_to_old_folder;
 use super::utils::build_user_agent;
 
+/// 由下载器自身管理、不允许自定义覆盖的请求头（分段/续传由下载器统一处理）
+const RESERVED_DOWNLOAD_HEADERS: &[&str] = &["range", "host", "content-length"];
+
+/// 判断请求头是否敏感（值不写入日志）
+fn is_sensitive_header(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    matches!(
+        lower.as_str(),
+        "authorization" | "proxy-authorization" | "cookie"
+    ) || ["token", "secret", "key", "auth"]
+        .iter()
+        .any(|k| lower.contains(k))
+}
+
+/// 将前端传入的自定义请求头转换为 HeaderMap，跳过保留头，日志中隐藏敏感值
+fn build_custom_headers(headers: &HashMap<String, String>) -> Result<HeaderMap, String> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        if RESERVED_DOWNLOAD_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+            warn!("[下载] 忽略保留请求头: {}", name);
+            continue;
+        }
+        let header_name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| format!("无效的请求头名称 {}: {}", name, e))?;
+        let mut header_value =
+            HeaderValue::from_str(value).map_err(|e| format!("请求头 {} 的值无效: {}", name, e))?;
+        if is_sensitive_header(name) {
+            header_value.set_sensitive(true);
+            info!("[下载] 自定义请求头: {}: ***", name);
+        } else {
+            info!("[下载] 自定义请求头: {}: {}", name, value);
+        }
+        map.insert(header_name, header_value);
+    }
+    Ok(map)
+}
+
 /// 进度上报任务的守卫，在函数任意返回路径上都能确保发送停止信号
 struct ProgressEmitterGuard(Option<tokio::sync::oneshot::Sender<()>>);
 
@@ -35,12 +75,19 @@ struct TempFileGuard {
 
 impl TempFileGuard {
     fn new(path: PathBuf) -> Self {
+        if let Ok(mut files) = ACTIVE_TEMP_FILES.lock() {
+            files.insert(path.clone());
+        }
         Self { path: Some(path) }
     }
 
     /// 成功重命名后调用，使 drop 时不再尝试删除（文件已移至目标路径）。
     fn disarm(&mut self) {
-        self.path = None;
+        if let Some(p) = self.path.take() {
+            if let Ok(mut files) = ACTIVE_TEMP_FILES.lock() {
+                files.remove(&p);
+            }
+        }
     }
 }
 
@@ -50,14 +97,62 @@ impl Drop for TempFileGuard {
             // 必须同步删除，避免竞态条件：
             // 如果异步删除，可能在下一次下载创建同名临时文件后才执行，导致误删。
             let _ = std::fs::remove_file(&p);
+            if let Ok(mut files) = ACTIVE_TEMP_FILES.lock() {
+                files.remove(&p);
+            }
         }
     }
 }
 
+/// 活跃下载计数守卫，download_file 任意返回路径上都会递减计数
+struct ActiveDownloadGuard;
+
+impl ActiveDownloadGuard {
+    fn new() -> Self {
+        ACTIVE_DOWNLOADS.fetch_add(1, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Drop for ActiveDownloadGuard {
+    fn drop(&mut self) {
+        ACTIVE_DOWNLOADS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 /// 全局下载取消标志
 static DOWNLOAD_CANCELLED: AtomicBool = AtomicBool::new(false);
 /// 当前下载的 session ID，用于区分不同的下载任务
 static CURRENT_DOWNLOAD_SESSION: AtomicU64 = AtomicU64::new(0);
+/// 正在进行的下载数量（用于退出前确认）
+static ACTIVE_DOWNLOADS: AtomicUsize = AtomicUsize::new(0);
+/// 正在写入的下载临时文件（强制退出时清理）
+static ACTIVE_TEMP_FILES: LazyLock<Mutex<HashSet<PathBuf>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// 是否有正在进行的下载
+pub fn has_active_downloads() -> bool {
+    ACTIVE_DOWNLOADS.load(Ordering::SeqCst) > 0
+}
+
+/// 退出前中止所有下载并删除未完成的临时文件
+pub fn abort_active_downloads() {
+    DOWNLOAD_CANCELLED.store(true, Ordering::SeqCst);
+    let files: Vec<PathBuf> = match ACTIVE_TEMP_FILES.lock() {
+        Ok(mut files) => files.drain().collect(),
+        Err(_) => return,
+    };
+    for path in files {
+        match std::fs::remove_file(&path) {
+            Ok(()) => info!("abort_active_downloads: removed {}", path.display()),
+            Err(e) => warn!(
+                "abort_active_downloads: failed to remove {}: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+}
 
 /// 根据版本号获取 GitHub Release URL
 ///
@@ -79,21 +174,12 @@ pub async fn get_github_release_by_version(
         .timeout(std::time::Duration::from_secs(10))
         .connect_timeout(std::time::Duration::from_secs(3));
 
-    // 添加代理配置（如果提供）
-    if let Some(ref proxy) = proxy_url {
-        if !proxy.is_empty() {
-            info!("[检查更新] 使用代理: {}", proxy);
-            info!("[检查更新] 目标: {}", url);
-            let reqwest_proxy = reqwest::Proxy::all(proxy).map_err(|e| {
-                error!("代理配置失败: {} (代理地址: {})", e, proxy);
-                format!(
-                    "代理配置失败: {}。请检查代理格式是否正确（支持 http:// 或 socks5://）",
-                    e
-                )
-            })?;
-            client_builder = client_builder.proxy(reqwest_proxy);
-        }
-    }
+    // 配置代理：手动代理优先，其次系统代理，都没有时直连
+    info!("[检查更新] 目标: {}", url);
+    client_builder = match resolve_proxy(proxy_url.as_deref(), "检查更新")? {
+        Some(proxy) => client_builder.proxy(proxy),
+        None => client_builder.no_proxy(),
+    };
 
     let client = client_builder
         .build()
@@ -144,6 +230,95 @@ pub async fn get_github_release_by_version(
     Ok(None)
 }
 
+/// 未指定目标时探测的更新服务器（Mirror酱主站/备用站与 GitHub）
+const DEFAULT_PROBE_TARGETS: &[&str] = &[
+    "https://mirrorchyan.com",
+    "https://mirrorchyan.net",
+    "https://github.com",
+    "https://api.github.com",
+];
+
+/// 单个目标的探测超时，保证网络不通时也能很快给出结果
+const PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// 探测更新服务器/CDN 的连通性
+///
+/// 并发向各目标发送短超时的 HEAD 请求（不跟随重定向），收到任何 HTTP 响应即视为可达。
+/// 结果按可达优先、延迟从低到高排序，可直接用于挑选最快的镜像。未指定目标时探测默认更新服务器
+#[tauri::command]
+pub async fn get_network_status(
+    targets: Option<Vec<String>>,
+    proxy_url: Option<String>,
+) -> Result<Vec<NetworkProbeResult>, String> {
+    let targets: Vec<String> = match targets {
+        Some(targets) if !targets.is_empty() => targets,
+        _ => DEFAULT_PROBE_TARGETS
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    };
+
+    let mut client_builder = reqwest::Client::builder()
+        .user_agent(build_user_agent())
+        .timeout(PROBE_TIMEOUT)
+        .connect_timeout(PROBE_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none());
+    client_builder = match resolve_proxy(proxy_url.as_deref(), "网络探测")? {
+        Some(proxy) => client_builder.proxy(proxy),
+        None => client_builder.no_proxy(),
+    };
+    let client = client_builder
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
+
+    let probes = targets.into_iter().map(|url| {
+        let client = client.clone();
+        async move {
+            let start = std::time::Instant::now();
+            match client.head(&url).send().await {
+                Ok(response) => NetworkProbeResult {
+                    url,
+                    reachable: true,
+                    latency_ms: Some(start.elapsed().as_millis() as u64),
+                    status: Some(response.status().as_u16()),
+                    error: None,
+                },
+                Err(e) => NetworkProbeResult {
+                    url,
+                    reachable: false,
+                    latency_ms: None,
+                    status: None,
+                    error: Some(if e.is_timeout() {
+                        "连接超时".to_string()
+                    } else {
+                        e.to_string()
+                    }),
+                },
+            }
+        }
+    });
+    let mut results = futures_util::future::join_all(probes).await;
+    results.sort_by_key(|r| (!r.reachable, r.latency_ms.unwrap_or(u64::MAX)));
+
+    info!(
+        "[网络检测] {}",
+        results
+            .iter()
+            .map(|r| match r.latency_ms {
+                Some(ms) => format!("{} {}ms", r.url, ms),
+                None => format!("{} 不可达", r.url),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Ok(results)
+}
+
+/// 下载被取消（前端据此区分取消与失败）
+fn download_cancelled() -> MxuError {
+    MxuError::new(MxuErrorCode::DownloadCancelled, "下载已取消")
+}
+
 /// 流式下载文件，支持进度回调和取消
 ///
 /// 使用 reqwest 进行流式下载，直接写入文件而不经过内存缓冲，
@@ -152,6 +327,9 @@ pub async fn get_github_release_by_version(
 /// 返回 DownloadResult，包含 session_id 和实际保存路径
 /// 如果检测到重定向后的 URL 或 Content-Disposition 包含正确的文件名，
 /// 会使用该文件名保存（替换原始 save_path 的文件名部分）
+///
+/// headers 为可选的自定义请求头（如私有仓库的 Authorization），
+/// Range 等由下载器管理的请求头会被忽略，敏感头的值不会写入日志
 #[tauri::command]
 pub async fn download_file(
     app: tauri::AppHandle,
@@ -159,12 +337,27 @@ pub async fn download_file(
     save_path: String,
     total_size: Option<u64>,
     proxy_url: Option<String>,
-) -> Result<DownloadResult, String> {
+    headers: Option<HashMap<String, String>>,
+) -> Result<DownloadResult, MxuError> {
+    download_file_impl(app, url.clone(), save_path, total_size, proxy_url, headers)
+        .await
+        .map_err(|e| e.with_detail(url))
+}
+
+async fn download_file_impl(
+    app: tauri::AppHandle,
+    url: String,
+    save_path: String,
+    total_size: Option<u64>,
+    proxy_url: Option<String>,
+    headers: Option<HashMap<String, String>>,
+) -> Result<DownloadResult, MxuError> {
     use futures_util::StreamExt;
     use std::io::Write;
     use tokio::time::{sleep, Duration};
 
     info!("download_file: {} -> {}", url, save_path);
+    let _active_guard = ActiveDownloadGuard::new();
 
     // 生成新的 session ID，使旧下载的进度事件无效
     let session_id = CURRENT_DOWNLOAD_SESSION.fetch_add(1, Ordering::SeqCst) + 1;
@@ -177,7 +370,9 @@ pub async fn download_file(
 
     // 确保目录存在
     if let Some(parent) = save_path_obj.parent() {
-        std::fs::create_dir_all(parent).map_err(|e| format!("无法创建目录: {}", e))?;
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("无法创建目录: {}", e))
+            .with_code(MxuErrorCode::DownloadFailed)?;
     }
 
     // 构建 HTTP 客户端和请求
@@ -186,38 +381,41 @@ pub async fn download_file(
         .timeout(std::time::Duration::from_secs(1800)) // 30 分钟超时，足够下载大文件但防止无限挂起
         .connect_timeout(std::time::Duration::from_secs(10));
 
-    // 配置代理（如果提供）
-    if let Some(ref proxy) = proxy_url {
-        if !proxy.is_empty() {
-            info!("[下载] 使用代理: {}", proxy);
-            info!("[下载] 目标: {}", url);
-            let reqwest_proxy = reqwest::Proxy::all(proxy).map_err(|e| {
-                error!("代理配置失败: {} (代理地址: {})", e, proxy);
-                format!(
-                    "代理配置失败: {}。请检查代理格式是否正确（支持 http:// 或 socks5://）",
-                    e
-                )
-            })?;
-            client_builder = client_builder.proxy(reqwest_proxy);
-        } else {
-            info!("[下载] 直连（无代理）: {}", url);
-        }
-    } else {
-        info!("[下载] 直连（无代理）: {}", url);
-    }
+    // 配置代理：手动代理优先，其次系统代理，都没有时直连
+    info!("[下载] 目标: {}", url);
+    client_builder = match resolve_proxy(proxy_url.as_deref(), "下载")
+        .with_code(MxuErrorCode::InvalidArgument)?
+    {
+        Some(proxy) => client_builder.proxy(proxy),
+        None => client_builder.no_proxy(),
+    };
 
     let client = client_builder
         .build()
-        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))
+        .with_code(MxuErrorCode::DownloadFailed)?;
+
+    // 自定义请求头（如鉴权 token、特定 User-Agent），同名时覆盖客户端默认值
+    let custom_headers = match headers {
+        Some(ref h) if !h.is_empty() => {
+            build_custom_headers(h).with_code(MxuErrorCode::InvalidArgument)?
+        }
+        _ => HeaderMap::new(),
+    };
 
     let response = client
         .get(&url)
+        .headers(custom_headers)
         .send()
         .await
-        .map_err(|e| format!("请求失败: {}", e))?;
+        .map_err(|e| format!("请求失败: {}", e))
+        .with_code(MxuErrorCode::DownloadFailed)?;
 
     if !response.status().is_success() {
-        return Err(format!("HTTP 错误: {}", response.status()));
+        return Err(MxuError::new(
+            MxuErrorCode::DownloadFailed,
+            format!("HTTP 错误: {}", response.status()),
+        ));
     }
 
     // 尝试从 Content-Disposition header 或最终 URL 提取文件名
@@ -343,28 +541,34 @@ pub async fn download_file(
     // 流式下载
     let mut stream = response.bytes_stream();
     let mut downloaded: u64 = 0;
-    let mut download_err: Option<String> = None;
+    let mut download_err: Option<MxuError> = None;
 
     while let Some(chunk) = stream.next().await {
         if DOWNLOAD_CANCELLED.load(Ordering::SeqCst)
             || CURRENT_DOWNLOAD_SESSION.load(Ordering::SeqCst) != session_id
         {
             info!("download_file cancelled (session {})", session_id);
-            download_err = Some("下载已取消".to_string());
+            download_err = Some(download_cancelled());
             break;
         }
 
         let chunk = match chunk {
             Ok(c) => c,
             Err(e) => {
-                download_err = Some(format!("下载数据失败: {}", e));
+                download_err = Some(MxuError::new(
+                    MxuErrorCode::DownloadFailed,
+                    format!("下载数据失败: {}", e),
+                ));
                 break;
             }
         };
 
         let len = chunk.len() as u64;
         if write_tx.send(chunk).await.is_err() {
-            download_err = Some("磁盘写入线程异常退出".to_string());
+            download_err = Some(MxuError::new(
+                MxuErrorCode::DownloadFailed,
+                "磁盘写入线程异常退出",
+            ));
             break;
         }
         downloaded += len;
@@ -380,7 +584,7 @@ pub async fn download_file(
             "download_file cancelled before finalization (session {})",
             session_id
         );
-        download_err = Some("下载已取消".to_string());
+        download_err = Some(download_cancelled());
     }
 
     // 关闭发送端，通知写入线程所有数据已发送完毕
@@ -389,12 +593,14 @@ pub async fn download_file(
     // 等待写入线程完成，确保文件句柄关闭后再进行重命名等后续操作
     let write_thread_result = write_handle
         .await
-        .map_err(|e| format!("写入任务异常: {}", e))?;
+        .map_err(|e| format!("写入任务异常: {}", e))
+        .with_code(MxuErrorCode::DownloadFailed)?
+        .with_code(MxuErrorCode::DownloadFailed);
 
     if let Some(err) = download_err {
-        // 写入线程通常持有更具体的 I/O 错误信息（如磁盘满），优先返回
-        if let Err(write_err) = write_thread_result {
-            return Err(write_err);
+        // 写入线程通常持有更具体的 I/O 错误信息（如磁盘满），优先返回；取消时仍按取消处理
+        if err.code != MxuErrorCode::DownloadCancelled {
+            write_thread_result?;
         }
         return Err(err);
     }
@@ -420,7 +626,8 @@ pub async fn download_file(
     // 重命名临时文件（使用异步版本避免阻塞 runtime 线程）
     tokio::fs::rename(&temp_path, &actual_save_path)
         .await
-        .map_err(|e| format!("重命名文件失败: {}", e))?;
+        .map_err(|e| format!("重命名文件失败: {}", e))
+        .with_code(MxuErrorCode::DownloadFailed)?;
     temp_guard.disarm();
 
     info!(
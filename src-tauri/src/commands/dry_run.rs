@@ -0,0 +1,153 @@
+//! 任务 dry-run 模式（只识别不操作）
+//!
+//! MaaFramework 没有只识别、跳过动作的运行选项，因此在 MXU 层模拟：提交任务时通过
+//! pipeline override 把所有会操作设备的节点动作替换为 `MXU_DRY_RUN_ACTION`，
+//! 截图、识别和节点跳转照常进行，原本要执行的动作只通过 `maa-dry-run-action` 事件上报
+
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex};
+
+use log::info;
+use maa_framework::resource::Resource;
+
+use super::utils::{merge_pipeline_override, parse_pipeline_override};
+use crate::mxu_actions::MXU_DRY_RUN_ACTION;
+
+/// 开启了 dry-run 的实例
+static DRY_RUN_INSTANCES: LazyLock<Mutex<HashSet<String>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// 不操作设备、无需替换的动作类型
+const PASSIVE_ACTIONS: &[&str] = &["DoNothing", "StopTask"];
+
+/// 实例是否开启了 dry-run
+pub fn is_dry_run(instance_id: &str) -> bool {
+    DRY_RUN_INSTANCES
+        .lock()
+        .map(|set| set.contains(instance_id))
+        .unwrap_or(false)
+}
+
+/// 取出节点定义中的动作类型与目标，兼容 `"action": "Click"` 与
+/// `"action": {"type": "Click", "param": {...}}` 两种写法
+fn node_action(node: &serde_json::Value) -> (String, serde_json::Value) {
+    let action = node.get("action");
+    let (kind, param) = match action {
+        Some(serde_json::Value::Object(obj)) => (
+            obj.get("type").and_then(|v| v.as_str()),
+            obj.get("param").unwrap_or(node),
+        ),
+        Some(serde_json::Value::String(kind)) => (Some(kind.as_str()), node),
+        _ => (None, node),
+    };
+
+    let mut detail = serde_json::Map::new();
+    for key in [
+        "target",
+        "target_offset",
+        "begin",
+        "end",
+        "key",
+        "input_text",
+        "custom_action",
+    ] {
+        if let Some(value) = param.get(key) {
+            detail.insert(key.to_string(), value.clone());
+        }
+    }
+    (
+        kind.unwrap_or("DoNothing").to_string(),
+        serde_json::Value::Object(detail),
+    )
+}
+
+/// 为任务生成 dry-run 用的 pipeline override：在原 override 基础上把所有节点的动作
+/// 替换为 `MXU_DRY_RUN_ACTION`，并把原动作信息放入 `custom_action_param`
+pub fn build_dry_run_override(
+    resource: &Resource,
+    pipeline_override: &str,
+) -> Result<String, String> {
+    let mut merged = serde_json::Map::new();
+    for layer in parse_pipeline_override(pipeline_override)? {
+        merge_pipeline_override(&mut merged, &layer);
+    }
+
+    let mut nodes: Vec<String> = resource
+        .node_list()
+        .map_err(|e| format!("获取节点列表失败: {}", e))?;
+    let extra: Vec<String> = merged
+        .keys()
+        .filter(|k| !nodes.contains(k))
+        .cloned()
+        .collect();
+    nodes.extend(extra);
+
+    let mut replacements = serde_json::Map::new();
+    for node in nodes {
+        // 节点最终定义 = 资源原始定义 + 任务 override
+        let mut effective = resource
+            .get_node_data(&node)
+            .ok()
+            .flatten()
+            .and_then(|data| serde_json::from_str::<serde_json::Value>(&data).ok())
+            .filter(|v| v.is_object())
+            .unwrap_or_else(|| serde_json::json!({}));
+        if let (Some(target), Some(serde_json::Value::Object(fields))) =
+            (effective.as_object_mut(), merged.get(&node))
+        {
+            for (key, value) in fields {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+
+        let (kind, detail) = node_action(&effective);
+        if PASSIVE_ACTIONS.contains(&kind.as_str()) {
+            continue;
+        }
+        replacements.insert(
+            node.clone(),
+            serde_json::json!({
+                "action": "Custom",
+                "custom_action": MXU_DRY_RUN_ACTION,
+                "custom_action_param": {
+                    "node": node,
+                    "action": kind,
+                    "detail": detail,
+                },
+            }),
+        );
+    }
+
+    info!(
+        "build_dry_run_override: {} node action(s) replaced",
+        replacements.len()
+    );
+    merge_pipeline_override(&mut merged, &replacements);
+    Ok(serde_json::Value::Object(merged).to_string())
+}
+
+/// 移除实例的模拟运行设置（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut entries) = DRY_RUN_INSTANCES.lock() {
+        entries.remove(instance_id);
+    }
+}
+
+/// 开启或关闭实例的 dry-run 模式，对之后提交的任务生效
+#[tauri::command]
+pub fn set_dry_run(instance_id: String, enabled: bool) -> Result<(), String> {
+    info!("set_dry_run: {} -> {}", instance_id, enabled);
+    let mut instances = DRY_RUN_INSTANCES.lock().map_err(|e| e.to_string())?;
+    if enabled {
+        instances.insert(instance_id);
+    } else {
+        instances.remove(&instance_id);
+    }
+    Ok(())
+}
+
+/// 查询实例是否开启了 dry-run 模式
+#[tauri::command]
+pub fn get_dry_run(instance_id: String) -> bool {
+    is_dry_run(&instance_id)
+}
@@ -0,0 +1,263 @@
+//! 连接前自动启动模拟器
+//!
+//! maa_connect_controller（及 HTTP `/connect`）传入启动配置时，先检查目标设备/窗口是否已就绪：
+//! 已就绪直接连接；否则在模拟器程序未运行时用配置的命令拉起（与 run_action 相同的启动方式，
+//! 同样经过外部程序信任校验），已运行则跳过启动，然后轮询等待设备/窗口出现再连接。
+//! 等待期间响应前置动作的停止请求（set_pre_action_stop）。
+//!
+//! 自动连接时保存的设备可能要等模拟器启动后才能被搜索到，前端先调用 maa_prepare_emulator
+//! 等待就绪，再按保存的设备名搜索连接。
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+use tauri::{AppHandle, Emitter, State};
+
+use super::error::{MxuError, MxuErrorCode, WithErrorCode};
+use super::maa_core::{find_adb_devices_impl, find_win32_windows_impl};
+use super::system::{build_action_command, check_process_running, check_program_trust};
+use super::types::{
+    ControllerConfig, EmulatorLaunchConfig, EmulatorLaunchEvent, EmulatorLaunchStage, MaaState,
+};
+use super::window_state::window_exists;
+
+/// 未配置超时时等待设备/窗口就绪的时间
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// 轮询设备/窗口的间隔
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 探测 ADB 端口的连接超时
+const ADB_PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+fn emit_stage(
+    app: &AppHandle,
+    instance_id: &str,
+    launch: &EmulatorLaunchConfig,
+    stage: EmulatorLaunchStage,
+) {
+    let event = EmulatorLaunchEvent {
+        instance_id: instance_id.to_string(),
+        stage,
+        program: launch.program.clone(),
+    };
+    if let Err(e) = app.emit("maa-emulator-launch", event) {
+        warn!("[emulator_launch] Failed to emit event: {}", e);
+    }
+}
+
+fn stop_requested(state: &MaaState, instance_id: &str) -> bool {
+    state
+        .pre_action_stop_requests
+        .lock()
+        .map(|requests| requests.contains(instance_id))
+        .unwrap_or(false)
+}
+
+/// ADB 设备是否就绪
+///
+/// `host:port` 形式的地址直接探测端口（避免每秒调用一次 adb），其余按 adb 设备列表匹配
+async fn adb_target_ready(state: &Arc<MaaState>, adb_path: &str, address: &str) -> bool {
+    let has_port = address
+        .rsplit_once(':')
+        .is_some_and(|(_, port)| port.parse::<u16>().is_ok());
+    if has_port {
+        return matches!(
+            tokio::time::timeout(ADB_PROBE_TIMEOUT, tokio::net::TcpStream::connect(address)).await,
+            Ok(Ok(_))
+        );
+    }
+    match find_adb_devices_impl(Arc::clone(state), Some(adb_path.to_string())).await {
+        Ok(devices) => devices.iter().any(|d| {
+            d.address == address
+                || d.address
+                    .strip_prefix(address)
+                    .is_some_and(|rest| rest.starts_with(':'))
+        }),
+        Err(e) => {
+            warn!("[emulator_launch] Failed to find adb devices: {}", e);
+            false
+        }
+    }
+}
+
+/// 连接目标是否就绪
+///
+/// Win32 / Gamepad 控制器原句柄失效但按正则找到新窗口时，同时更新 config 中的句柄
+async fn target_ready(
+    state: &Arc<MaaState>,
+    config: &mut ControllerConfig,
+    launch: &EmulatorLaunchConfig,
+) -> bool {
+    match config {
+        ControllerConfig::Adb {
+            adb_path, address, ..
+        } => adb_target_ready(state, adb_path, address).await,
+        ControllerConfig::Win32 { handle, .. } | ControllerConfig::Gamepad { handle, .. } => {
+            if *handle != 0 && window_exists(*handle) {
+                return true;
+            }
+            if launch.class_regex.is_none() && launch.window_regex.is_none() {
+                return false;
+            }
+            let windows = match find_win32_windows_impl(
+                Arc::clone(state),
+                launch.class_regex.clone(),
+                launch.window_regex.clone(),
+            )
+            .await
+            {
+                Ok(windows) => windows,
+                Err(e) => {
+                    warn!("[emulator_launch] Failed to find windows: {}", e);
+                    return false;
+                }
+            };
+            match windows.first() {
+                Some(window) => {
+                    info!(
+                        "[emulator_launch] Target window found: 0x{:X} {:?}",
+                        window.handle, window.window_name
+                    );
+                    *handle = window.handle;
+                    true
+                }
+                None => false,
+            }
+        }
+        // 其余控制器不依赖模拟器进程
+        _ => true,
+    }
+}
+
+/// 可中断的等待，期间收到停止请求时返回 false
+pub async fn wait_with_stop_check(state: &MaaState, instance_id: &str, duration: Duration) -> bool {
+    let deadline = Instant::now() + duration;
+    while Instant::now() < deadline {
+        if stop_requested(state, instance_id) {
+            return false;
+        }
+        tokio::time::sleep(POLL_INTERVAL.min(deadline.saturating_duration_since(Instant::now())))
+            .await;
+    }
+    true
+}
+
+/// 连接前确保模拟器已启动且设备/窗口已就绪
+pub async fn ensure_emulator_ready(
+    app: &AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    config: &mut ControllerConfig,
+    launch: &EmulatorLaunchConfig,
+) -> Result<(), String> {
+    if target_ready(state, config, launch).await {
+        info!(
+            "[emulator_launch] {}: target already available, skip launching",
+            instance_id
+        );
+        return Ok(());
+    }
+
+    let is_window = matches!(
+        config,
+        ControllerConfig::Win32 { .. } | ControllerConfig::Gamepad { .. }
+    );
+    if is_window && launch.class_regex.is_none() && launch.window_regex.is_none() {
+        return Err("目标窗口已失效且未配置窗口匹配规则，无法定位启动后的模拟器窗口".to_string());
+    }
+
+    let program = launch.program.trim();
+    if program.is_empty() {
+        return Err("未配置模拟器启动程序".to_string());
+    }
+
+    let launched = if check_process_running(program) {
+        info!(
+            "[emulator_launch] {}: {} already running, waiting for target",
+            instance_id, program
+        );
+        emit_stage(
+            app,
+            instance_id,
+            launch,
+            EmulatorLaunchStage::AlreadyRunning,
+        );
+        false
+    } else {
+        let cmd_args = launch.use_cmd.then_some(launch.args.as_str());
+        check_program_trust(app, instance_id, program, cmd_args, "emulator_launch").await?;
+        let mut cmd = build_action_command(
+            program,
+            &launch.args,
+            launch.cwd.as_deref(),
+            launch.use_cmd,
+            launch.hide_window,
+        )?;
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| format!("启动模拟器失败: {} - {}", program, e))?;
+        // 不等待模拟器退出，交给后台线程回收以免留下僵尸进程
+        std::thread::spawn(move || {
+            let _ = child.wait();
+        });
+        info!("[emulator_launch] {}: launched {}", instance_id, program);
+        emit_stage(app, instance_id, launch, EmulatorLaunchStage::Launching);
+        true
+    };
+
+    let timeout = launch
+        .timeout_secs
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_TIMEOUT);
+    let started = Instant::now();
+    while !target_ready(state, config, launch).await {
+        if started.elapsed() >= timeout {
+            warn!(
+                "[emulator_launch] {}: target not ready after {:?}",
+                instance_id, timeout
+            );
+            emit_stage(app, instance_id, launch, EmulatorLaunchStage::Timeout);
+            return Err(format!("等待模拟器就绪超时（{} 秒）", timeout.as_secs()));
+        }
+        if !wait_with_stop_check(state, instance_id, POLL_INTERVAL).await {
+            return Err("MXU_PRE_ACTION_CANCELLED".to_string());
+        }
+    }
+
+    info!(
+        "[emulator_launch] {}: target ready after {:?}",
+        instance_id,
+        started.elapsed()
+    );
+    emit_stage(app, instance_id, launch, EmulatorLaunchStage::Ready);
+
+    // 刚拉起的模拟器端口/窗口出现后系统往往还没启动完，按配置再等一会
+    let settle = launch.settle_secs.unwrap_or(0);
+    if launched
+        && settle > 0
+        && !wait_with_stop_check(state, instance_id, Duration::from_secs(settle)).await
+    {
+        return Err("MXU_PRE_ACTION_CANCELLED".to_string());
+    }
+    Ok(())
+}
+
+/// 确保模拟器已启动且设备/窗口已就绪，但不连接
+///
+/// 自动连接在按保存的设备名搜索之前调用；`config` 为等待的目标（ADB 地址或按窗口正则查找的空句柄）
+#[tauri::command]
+pub async fn maa_prepare_emulator(
+    app: AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    mut config: ControllerConfig,
+    launch: EmulatorLaunchConfig,
+) -> Result<(), MxuError> {
+    info!("maa_prepare_emulator called, instance_id: {}", instance_id);
+    ensure_emulator_ready(&app, state.inner(), &instance_id, &mut config, &launch)
+        .await
+        .with_code(MxuErrorCode::ControllerConnectFailed)
+        .map_err(|e| e.with_detail(&instance_id))
+}
@@ -0,0 +1,133 @@
+//! 结构化错误
+//!
+//! 关键命令（连接、加载资源、运行任务、下载、更新）返回 `MxuError`，序列化为
+//! `{ code, message, detail }`：前端按 `code` 做逻辑分支和本地化，`message` 保留原有的可读描述，
+//! `detail` 为可选的补充信息（如实例 ID、URL、路径）。
+//! 错误码在出错处显式指定（`MxuError::new` 或 `WithErrorCode::with_code`），不按错误文本归类；
+//! 仍返回字符串错误的内部函数经 `?` 转换时归入 `Internal`。
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+/// 错误码（序列化为 SCREAMING_SNAKE_CASE）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MxuErrorCode {
+    /// 实例不存在
+    InstanceNotFound,
+    /// 控制器未连接
+    ControllerNotConnected,
+    /// 控制器连接失败
+    ControllerConnectFailed,
+    /// 资源未加载
+    ResourceNotLoaded,
+    /// 资源加载失败
+    ResourceLoadFailed,
+    /// 任务提交失败
+    TaskRunFailed,
+    /// 正在抢占执行其他任务，暂不能提交
+    TaskPreempting,
+    /// 下载失败
+    DownloadFailed,
+    /// 下载被取消
+    DownloadCancelled,
+    /// 更新安装失败
+    UpdateFailed,
+    /// 参数不合法
+    InvalidArgument,
+    /// 其他内部错误
+    Internal,
+}
+
+/// 命令返回的结构化错误
+#[derive(Debug, Clone)]
+pub struct MxuError {
+    pub code: MxuErrorCode,
+    pub message: String,
+    pub detail: Option<String>,
+}
+
+impl MxuError {
+    pub fn new(code: MxuErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            detail: None,
+        }
+    }
+
+    /// 实例不存在
+    pub fn instance_not_found() -> Self {
+        Self::new(MxuErrorCode::InstanceNotFound, "Instance not found")
+    }
+
+    /// 控制器未连接（前置条件错误，前端按错误码显示本地化提示）
+    pub fn controller_not_connected() -> Self {
+        Self::new(
+            MxuErrorCode::ControllerNotConnected,
+            "Controller not connected",
+        )
+    }
+
+    /// 资源未加载（前置条件错误，前端按错误码显示本地化提示）
+    pub fn resource_not_loaded() -> Self {
+        Self::new(MxuErrorCode::ResourceNotLoaded, "Resource not loaded")
+    }
+
+    /// 附加补充信息
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+/// 在出错处为内部错误指定错误码
+pub trait WithErrorCode<T> {
+    fn with_code(self, code: MxuErrorCode) -> Result<T, MxuError>;
+}
+
+impl<T, E: std::fmt::Display> WithErrorCode<T> for Result<T, E> {
+    fn with_code(self, code: MxuErrorCode) -> Result<T, MxuError> {
+        self.map_err(|e| MxuError::new(code, e.to_string()))
+    }
+}
+
+impl std::fmt::Display for MxuError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.detail {
+            Some(detail) => write!(f, "{} ({})", self.message, detail),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for MxuError {}
+
+impl From<String> for MxuError {
+    fn from(message: String) -> Self {
+        Self::new(MxuErrorCode::Internal, message)
+    }
+}
+
+impl From<&str> for MxuError {
+    fn from(message: &str) -> Self {
+        Self::from(message.to_string())
+    }
+}
+
+/// 供仍返回字符串错误的内部调用方使用 `?` 透传
+impl From<MxuError> for String {
+    fn from(err: MxuError) -> Self {
+        err.to_string()
+    }
+}
+
+impl Serialize for MxuError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("MxuError", 3)?;
+        s.serialize_field("code", &self.code)?;
+        s.serialize_field("message", &self.message)?;
+        s.serialize_field("detail", &self.detail)?;
+        s.end()
+    }
+}
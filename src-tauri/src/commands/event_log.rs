@@ -0,0 +1,162 @@
+//! 结构化事件日志
+//!
+//! 在文本日志之外，把任务开始/结束、识别结果和错误等关键事件以 JSONL 形式
+//! （每行一个 JSON）实时写入日志目录下的 `events.jsonl`，便于脚本统计分析。
+//!
+//! 每条事件都带 `schema` 版本号，公共字段为 `schema`、`ts`、`event`、`instance_id`，
+//! 其余字段随事件类型不同。字段只增不改，不兼容的变更需要提升 `EVENT_SCHEMA_VERSION`。
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
+
+use log::{info, warn};
+
+use super::utils::get_logs_dir;
+
+/// 事件结构版本号
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// 事件日志文件名
+const EVENT_LOG_FILE: &str = "events.jsonl";
+
+/// 超过该大小时在下次开启时轮转为 `events.old.jsonl`
+const EVENT_LOG_MAX_SIZE: u64 = 16 * 1024 * 1024;
+
+/// 是否写入事件日志
+static EVENT_LOG_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// 事件日志文件（开启时打开，关闭时释放）
+static EVENT_LOG_WRITER: LazyLock<Mutex<Option<BufWriter<File>>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+/// 打开事件日志文件（追加写入），文件过大时先轮转
+fn open_event_log() -> Result<BufWriter<File>, String> {
+    let dir = get_logs_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| format!("无法创建日志目录: {}", e))?;
+    let path = dir.join(EVENT_LOG_FILE);
+    if std::fs::metadata(&path).is_ok_and(|m| m.len() > EVENT_LOG_MAX_SIZE) {
+        let _ = std::fs::rename(&path, dir.join("events.old.jsonl"));
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("无法打开事件日志 [{}]: {}", path.display(), e))?;
+    Ok(BufWriter::new(file))
+}
+
+/// 开启或关闭事件日志
+pub fn apply_event_log_enabled(enabled: bool) -> Result<(), String> {
+    let mut writer = EVENT_LOG_WRITER.lock().map_err(|e| e.to_string())?;
+    if enabled && writer.is_none() {
+        *writer = Some(open_event_log()?);
+    } else if !enabled {
+        if let Some(mut w) = writer.take() {
+            let _ = w.flush();
+        }
+    }
+    EVENT_LOG_ENABLED.store(enabled, Ordering::SeqCst);
+    info!("Event log enabled: {}", enabled);
+    Ok(())
+}
+
+/// 写入一条事件
+fn write_event(event: &str, instance_id: &str, fields: serde_json::Value) {
+    let mut record = serde_json::json!({
+        "schema": EVENT_SCHEMA_VERSION,
+        "ts": chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, false),
+        "event": event,
+        "instance_id": instance_id,
+    });
+    if let (Some(record), serde_json::Value::Object(fields)) = (record.as_object_mut(), fields) {
+        record.extend(fields);
+    }
+
+    let Ok(mut guard) = EVENT_LOG_WRITER.lock() else {
+        return;
+    };
+    if let Some(writer) = guard.as_mut() {
+        let result = serde_json::to_writer(&mut *writer, &record)
+            .map_err(|e| e.to_string())
+            .and_then(|_| writer.write_all(b"\n").map_err(|e| e.to_string()))
+            .and_then(|_| writer.flush().map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            warn!("Failed to write event log: {}", e);
+        }
+    }
+}
+
+/// 从 MaaFramework 回调中提取关键事件写入事件日志，未开启或非关键消息时直接返回
+///
+/// 在 tasker / context / controller / resource 的 sink 中调用
+pub fn record_callback(instance_id: &str, message: &str, details: &str) {
+    if !EVENT_LOG_ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let (event, keys, extra) = match message {
+        "Tasker.Task.Starting" => ("task_start", &["task_id", "entry"][..], None),
+        "Tasker.Task.Succeeded" => (
+            "task_end",
+            &["task_id", "entry"][..],
+            Some(("status", "succeeded")),
+        ),
+        "Tasker.Task.Failed" => (
+            "task_end",
+            &["task_id", "entry"][..],
+            Some(("status", "failed")),
+        ),
+        "Node.Recognition.Succeeded" => (
+            "recognition",
+            &["task_id", "reco_id", "name"][..],
+            Some(("result", "hit")),
+        ),
+        "Node.Recognition.Failed" => (
+            "recognition",
+            &["task_id", "reco_id", "name"][..],
+            Some(("result", "miss")),
+        ),
+        "Node.Action.Failed" => (
+            "error",
+            &["task_id", "action_id", "name"][..],
+            Some(("source", "action")),
+        ),
+        "Controller.Action.Failed" => (
+            "error",
+            &["ctrl_id", "action"][..],
+            Some(("source", "controller")),
+        ),
+        "Resource.Loading.Failed" => (
+            "error",
+            &["res_id", "path"][..],
+            Some(("source", "resource")),
+        ),
+        _ => return,
+    };
+
+    let details: serde_json::Value = serde_json::from_str(details).unwrap_or_default();
+    let mut fields = serde_json::Map::new();
+    for key in keys {
+        if let Some(value) = details.get(*key) {
+            fields.insert(key.to_string(), value.clone());
+        }
+    }
+    if let Some((key, value)) = extra {
+        fields.insert(key.to_string(), serde_json::Value::from(value));
+    }
+    write_event(event, instance_id, serde_json::Value::Object(fields));
+}
+
+/// 开启或关闭结构化事件日志（持久化由前端写入配置）
+#[tauri::command]
+pub fn set_event_log_enabled(enabled: bool) -> Result<(), String> {
+    apply_event_log_enabled(enabled)
+}
+
+/// 查询结构化事件日志是否开启
+#[tauri::command]
+pub fn get_event_log_enabled() -> bool {
+    EVENT_LOG_ENABLED.load(Ordering::SeqCst)
+}
@@ -0,0 +1,98 @@
+//! 任务失败自动截图
+//!
+//! 开启后，`Tasker.Task.Failed` 回调转发给前端前会附带失败时的截图（`screenshot` 字段，
+//! PNG data URL），前端可直接展示任务卡住的界面，用户上报问题时也能一并附上。
+//! 截图最长边超过 `MAX_SCREENSHOT_SIDE` 时先等比缩小再编码，避免每次失败都传输大图。
+
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use log::{info, warn};
+
+use super::types::MaaState;
+use super::utils::{downscale_rgba, encode_png};
+
+/// 附带截图的最长边（像素）
+const MAX_SCREENSHOT_SIDE: u32 = 960;
+
+/// 是否在任务失败事件中附带截图
+static FAILURE_SCREENSHOT_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// 开启或关闭任务失败自动截图
+pub fn apply_failure_screenshot_enabled(enabled: bool) {
+    FAILURE_SCREENSHOT_ENABLED.store(enabled, Ordering::SeqCst);
+    info!("Failure screenshot enabled: {}", enabled);
+}
+
+/// 任务失败时在回调详情中附加截图，未开启或非失败消息时原样返回
+///
+/// 在 tasker sink 中、`emit_callback_event` 之前调用
+pub fn attach_failure_screenshot<'a>(
+    state: &MaaState,
+    instance_id: &str,
+    message: &str,
+    details: &'a str,
+) -> Cow<'a, str> {
+    if message != "Tasker.Task.Failed" || !FAILURE_SCREENSHOT_ENABLED.load(Ordering::Relaxed) {
+        return Cow::Borrowed(details);
+    }
+    let Ok(serde_json::Value::Object(mut map)) = serde_json::from_str(details) else {
+        return Cow::Borrowed(details);
+    };
+    // 停止任务的失败不代表卡在某个界面
+    if map.get("entry").and_then(|v| v.as_str()) == Some("MaaTaskerPostStop") {
+        return Cow::Borrowed(details);
+    }
+    let Some(screenshot) = capture_screenshot(state, instance_id) else {
+        return Cow::Borrowed(details);
+    };
+    map.insert(
+        "screenshot".to_string(),
+        serde_json::Value::from(screenshot),
+    );
+    Cow::Owned(serde_json::Value::Object(map).to_string())
+}
+
+/// 获取实例当前的缓存截图，过大时缩小，返回 PNG data URL
+fn capture_screenshot(state: &MaaState, instance_id: &str) -> Option<String> {
+    let controller = {
+        let instances = state.instances.lock().ok()?;
+        instances.get(instance_id)?.controller.clone()?
+    };
+    let png = controller
+        .cached_image()
+        .ok()
+        .and_then(|buf| buf.to_vec())
+        .filter(|data| !data.is_empty())?;
+
+    let png = match tauri::image::Image::from_bytes(&png) {
+        Ok(image) if image.width().max(image.height()) > MAX_SCREENSHOT_SIDE => {
+            let (width, height, rgba) = downscale_rgba(
+                image.width(),
+                image.height(),
+                image.rgba(),
+                MAX_SCREENSHOT_SIDE,
+            );
+            encode_png(width, height, &rgba)
+        }
+        Ok(_) => png,
+        Err(e) => {
+            warn!("Failed to decode failure screenshot: {}", e);
+            return None;
+        }
+    };
+    Some(format!("data:image/png;base64,{}", STANDARD.encode(png)))
+}
+
+/// 开启或关闭任务失败自动截图（持久化由前端写入配置）
+#[tauri::command]
+pub fn set_failure_screenshot_enabled(enabled: bool) {
+    apply_failure_screenshot_enabled(enabled);
+}
+
+/// 查询任务失败自动截图是否开启
+#[tauri::command]
+pub fn get_failure_screenshot_enabled() -> bool {
+    FAILURE_SCREENSHOT_ENABLED.load(Ordering::SeqCst)
+}
@@ -8,7 +8,11 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
-use super::utils::{get_app_data_dir, get_exe_directory, normalize_path};
+use super::instance_log;
+use super::types::{AppPathEntry, AppPaths, GpuAdapterInfo};
+use super::utils::{
+    get_app_data_dir, get_exe_directory, get_logs_dir, get_maafw_dir, normalize_path,
+};
 
 /// 单个分卷 zip 的大小上限（字节）。
 const MAX_VOLUME_BYTES: u64 = 24_500_000;
@@ -346,6 +350,37 @@ pub fn get_cwd() -> Result<String, String> {
         .map_err(|e| format!("Failed to get current directory: {}", e))
 }
 
+fn app_path_entry(path: &Path) -> AppPathEntry {
+    AppPathEntry {
+        path: path.to_string_lossy().to_string(),
+        exists: path.exists(),
+    }
+}
+
+/// 一次获取所有关键目录（exe、数据、日志、缓存、maafw、工作目录、WebView2 运行时）及其是否存在
+#[tauri::command]
+pub fn get_app_paths() -> Result<AppPaths, String> {
+    let exe_dir = get_exe_directory()?;
+    let data_dir = get_app_data_dir()?;
+    let cwd =
+        std::env::current_dir().map_err(|e| format!("Failed to get current directory: {}", e))?;
+    // 启动时若使用了内置/共享的固定版本运行时，会通过该环境变量指定
+    let webview2_runtime_dir = std::env::var("WEBVIEW2_BROWSER_EXECUTABLE_FOLDER")
+        .ok()
+        .filter(|dir| !dir.is_empty())
+        .map(|dir| app_path_entry(Path::new(&dir)));
+
+    Ok(AppPaths {
+        exe_dir: app_path_entry(&exe_dir),
+        cache_dir: app_path_entry(&data_dir.join("cache")),
+        data_dir: app_path_entry(&data_dir),
+        logs_dir: app_path_entry(&get_logs_dir()),
+        maafw_dir: app_path_entry(&get_maafw_dir()?),
+        cwd: app_path_entry(&cwd),
+        webview2_runtime_dir,
+    })
+}
+
 /// 检查 exe 路径是否存在问题
 /// 返回: None 表示正常, Some("root") 表示在磁盘根目录, Some("temp") 表示在临时目录
 #[tauri::command]
@@ -512,19 +547,37 @@ fn collect_debug_images(dir: &Path, archive_prefix: &str) -> Vec<ExportEntry> {
 ///
 /// 注：`vision/` 是否有内容由 `maa_set_save_draw` 控制；导出时只要 `vision/`
 /// 下有文件就一并打包，因此本命令不接收 save_draw 参数。
+///
+/// 传入 instance_id 时只导出该实例的日志文件（附带配置与显卡信息）
 #[tauri::command]
 pub async fn export_logs(
     project_name: Option<String>,
     project_version: Option<String>,
+    instance_id: Option<String>,
 ) -> Result<String, String> {
-    tokio::task::spawn_blocking(move || export_logs_blocking(project_name, project_version))
-        .await
-        .map_err(|e| format!("导出任务执行失败: {}", e))?
+    // 显卡信息单独查询，不占用打包任务
+    let gpu_adapters = super::system::get_gpu_adapters().await.unwrap_or_default();
+    tokio::task::spawn_blocking(move || {
+        export_logs_blocking(project_name, project_version, instance_id, gpu_adapters)
+    })
+    .await
+    .map_err(|e| format!("导出任务执行失败: {}", e))?
+}
+
+/// 导出过程中生成的临时文件，任意返回路径上都会删除
+struct ExportTempFile(PathBuf);
+
+impl Drop for ExportTempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
 }
 
 fn export_logs_blocking(
     project_name: Option<String>,
     project_version: Option<String>,
+    instance_id: Option<String>,
+    gpu_adapters: Vec<GpuAdapterInfo>,
 ) -> Result<String, String> {
     use std::fs::File;
     use zip::write::SimpleFileOptions;
@@ -538,10 +591,25 @@ fn export_logs_blocking(
         return Err("日志目录不存在".to_string());
     }
 
+    let instance_logs = match instance_id.as_deref() {
+        Some(id) => {
+            let files = instance_log::instance_log_files(id);
+            if files.is_empty() {
+                return Err("该实例没有日志文件".to_string());
+            }
+            Some(files)
+        }
+        None => None,
+    };
+
     let now = chrono::Local::now();
     let date_str = now.format("%Y%m%d-%H%M%S");
     let name = project_name.unwrap_or_else(|| "mxu".to_string());
     let version = project_version.unwrap_or_default();
+    let name = match instance_id.as_deref() {
+        Some(id) => format!("{}-instance-{}", name, instance_log::sanitize_id(id)),
+        None => name,
+    };
     let dir_name = if version.is_empty() {
         format!("{}-logs-{}", name, date_str)
     } else {
@@ -558,8 +626,27 @@ fn export_logs_blocking(
     // ─── 1. 收集常规文件（log / config / 子目录下的 log/json） ───
     let mut regular_entries: Vec<ExportEntry> = Vec::new();
 
-    let entries = std::fs::read_dir(&debug_dir).map_err(|e| format!("读取日志目录失败: {}", e))?;
-    for entry in entries.flatten() {
+    // 只导出单个实例时不扫描 debug 目录，仅收集该实例的日志文件
+    let entries = match &instance_logs {
+        Some(files) => {
+            for path in files {
+                let Some(file_name) = path.file_name().map(|n| n.to_string_lossy().to_string())
+                else {
+                    continue;
+                };
+                regular_entries.push(ExportEntry {
+                    source_path: path.clone(),
+                    archive_name: format!("logs/{}", file_name),
+                });
+            }
+            Vec::new()
+        }
+        None => std::fs::read_dir(&debug_dir)
+            .map_err(|e| format!("读取日志目录失败: {}", e))?
+            .flatten()
+            .collect(),
+    };
+    for entry in entries {
         let path = entry.path();
         if !path.is_file() {
             continue;
@@ -577,13 +664,33 @@ fn export_logs_blocking(
     }
     regular_entries.sort_by(|a, b| a.archive_name.cmp(&b.archive_name));
 
+    // 附带显卡信息，便于远程诊断 WebView2 渲染问题（导出结束后删除临时文件）
+    let gpu_info = ExportTempFile(out_dir.join("gpu_adapters.json"));
+    match serde_json::to_vec_pretty(&gpu_adapters) {
+        Ok(bytes) if std::fs::write(&gpu_info.0, bytes).is_ok() => {
+            regular_entries.push(ExportEntry {
+                source_path: gpu_info.0.clone(),
+                archive_name: "gpu_adapters.json".to_string(),
+            });
+        }
+        _ => log::warn!("写入显卡信息失败，跳过"),
+    }
+
     let config_dir = data_dir.join("config");
     regular_entries.extend(collect_files_recursively(&config_dir, "config")?);
-    regular_entries.extend(collect_debug_subdir_files(&debug_dir, &["log", "json"])?);
+    if instance_logs.is_none() {
+        regular_entries.extend(collect_debug_subdir_files(&debug_dir, &["log", "json"])?);
+    }
 
-    // ─── 2. 收集图片（on_error + vision，按 mtime 新→旧） ───
-    let on_error_images = collect_debug_images(&debug_dir.join("on_error"), "on_error");
-    let vision_images = collect_debug_images(&debug_dir.join("vision"), "vision");
+    // ─── 2. 收集图片（on_error + vision，按 mtime 新→旧），只导出单个实例时跳过 ───
+    let (on_error_images, vision_images) = if instance_logs.is_none() {
+        (
+            collect_debug_images(&debug_dir.join("on_error"), "on_error"),
+            collect_debug_images(&debug_dir.join("vision"), "vision"),
+        )
+    } else {
+        (Vec::new(), Vec::new())
+    };
 
     // ─── 3. 合并入卷条目：先 regular，再 on_error，再 vision ───
     // 图片按 mtime 新→旧排在尾部，保证最近的崩溃图一定落在 part01.zip。
@@ -690,6 +797,8 @@ fn export_logs_blocking(
         volume_idx += 1;
     }
 
+    drop(gpu_info);
+
     log::info!(
         "日志导出完成：{} 个文件分为 {} 个分卷，输出目录 {}",
         total_files_written,
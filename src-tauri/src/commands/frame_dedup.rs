@@ -0,0 +1,224 @@
+//! 截图帧去重
+//!
+//! 静止画面下连续截图往往完全相同，每轮照常识别只是白白占用 CPU。开启后在每轮识别
+//! （`Node.NextList.Starting`）前对控制器缓存的最近一帧计算区域采样的差值哈希（dHash），
+//! 与上一轮比较：相似度达到阈值时视为画面未变化，按未变化的轮数指数退避，在本轮截图前
+//! 等待一段时间（不超过配置的上限）以降低识别频率；画面一旦变化立即恢复正常频率。
+//! 等待期间停止任务时立即退出。
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use log::{debug, info};
+
+use super::types::{FrameDedupConfig, MaaState};
+
+/// 哈希网格边长，哈希共 HASH_SIZE * HASH_SIZE 位
+const HASH_SIZE: usize = 16;
+
+/// 每个网格单元在每个方向上的采样点数
+const CELL_SAMPLES: u32 = 4;
+
+/// 画面首次判定为未变化时的等待时间，之后每轮翻倍
+const BASE_DELAY: Duration = Duration::from_millis(200);
+
+/// 等待期间检查停止请求的间隔
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 未设置时的默认相似度阈值
+const DEFAULT_SIMILARITY: f64 = 0.98;
+
+/// 未设置时的默认最长等待时间（毫秒）
+const DEFAULT_MAX_INTERVAL_MS: u64 = 2000;
+
+/// 画面的差值哈希（HASH_SIZE x HASH_SIZE 位）
+pub type FrameHash = [u64; HASH_SIZE * HASH_SIZE / 64];
+
+/// 各实例的去重配置（未设置的不去重）
+static CONFIGS: LazyLock<Mutex<HashMap<String, FrameDedupConfig>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 各实例上一轮的帧哈希与连续未变化的轮数
+static LAST_FRAMES: LazyLock<Mutex<HashMap<String, (FrameHash, u32)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 计算 RGBA 图像的差值哈希：把画面划分为 (HASH_SIZE + 1) x HASH_SIZE 个单元，
+/// 每个单元等距采样求平均亮度，相邻单元左亮于右时对应位为 1
+pub fn frame_hash(rgba: &[u8], width: u32, height: u32) -> Option<FrameHash> {
+    if width == 0 || height == 0 || rgba.len() < width as usize * height as usize * 4 {
+        return None;
+    }
+    let cols = HASH_SIZE as u32 + 1;
+    let rows = HASH_SIZE as u32;
+    let luma = |x: u32, y: u32| -> u32 {
+        let i = ((y * width + x) * 4) as usize;
+        (rgba[i] as u32 * 299 + rgba[i + 1] as u32 * 587 + rgba[i + 2] as u32 * 114) / 1000
+    };
+
+    let mut cells = [[0u32; HASH_SIZE + 1]; HASH_SIZE];
+    for (row, cells_row) in cells.iter_mut().enumerate() {
+        let y0 = row as u32 * height / rows;
+        let y1 = ((row as u32 + 1) * height / rows).max(y0 + 1);
+        for (col, cell) in cells_row.iter_mut().enumerate() {
+            let x0 = col as u32 * width / cols;
+            let x1 = ((col as u32 + 1) * width / cols).max(x0 + 1);
+            let mut sum = 0;
+            for sy in 0..CELL_SAMPLES {
+                let y = (y0 + (y1 - y0) * (2 * sy + 1) / (2 * CELL_SAMPLES)).min(height - 1);
+                for sx in 0..CELL_SAMPLES {
+                    let x = (x0 + (x1 - x0) * (2 * sx + 1) / (2 * CELL_SAMPLES)).min(width - 1);
+                    sum += luma(x, y);
+                }
+            }
+            *cell = sum;
+        }
+    }
+
+    let mut hash: FrameHash = [0; HASH_SIZE * HASH_SIZE / 64];
+    for (row, cells_row) in cells.iter().enumerate() {
+        for (col, pair) in cells_row.windows(2).enumerate() {
+            if pair[0] > pair[1] {
+                let bit = row * HASH_SIZE + col;
+                hash[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+    }
+    Some(hash)
+}
+
+/// 两个哈希的相似度（0~1，相同位的比例）
+pub fn similarity(a: &FrameHash, b: &FrameHash) -> f64 {
+    let distance: u32 = a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum();
+    1.0 - distance as f64 / (HASH_SIZE * HASH_SIZE) as f64
+}
+
+fn dedup_config(instance_id: &str) -> Option<FrameDedupConfig> {
+    CONFIGS
+        .lock()
+        .ok()
+        .and_then(|configs| configs.get(instance_id).cloned())
+}
+
+fn clear_last_frame(instance_id: &str) {
+    if let Ok(mut frames) = LAST_FRAMES.lock() {
+        frames.remove(instance_id);
+    }
+}
+
+/// 控制器缓存的最近一帧的哈希
+fn current_frame_hash(state: &MaaState, instance_id: &str) -> Option<FrameHash> {
+    let controller = {
+        let instances = state.instances.lock().ok()?;
+        instances.get(instance_id)?.controller.clone()?
+    };
+    let png = controller.cached_image().ok()?.to_vec()?;
+    let image = tauri::image::Image::from_bytes(&png).ok()?;
+    frame_hash(image.rgba(), image.width(), image.height())
+}
+
+/// 可中断的等待，期间停止任务时提前返回
+fn wait_unless_stopping(state: &MaaState, instance_id: &str, duration: Duration) {
+    let mut remaining = duration;
+    while !remaining.is_zero() {
+        let stopping = state
+            .instances
+            .lock()
+            .ok()
+            .and_then(|instances| instances.get(instance_id).map(|i| i.stop_in_progress))
+            .unwrap_or(true);
+        if stopping {
+            return;
+        }
+        let step = remaining.min(STOP_POLL_INTERVAL);
+        std::thread::sleep(step);
+        remaining -= step;
+    }
+}
+
+/// 每轮识别前比较画面，未变化时退避等待
+///
+/// 在 tasker sink 和 context sink 中调用：任务开始时重置记录，每轮识别前比较，其余消息直接返回
+pub fn before_capture(state: &MaaState, instance_id: &str, message: &str) {
+    if message == "Tasker.Task.Starting" {
+        clear_last_frame(instance_id);
+        return;
+    }
+    if message != "Node.NextList.Starting" {
+        return;
+    }
+    let Some(config) = dedup_config(instance_id) else {
+        return;
+    };
+    let Some(hash) = current_frame_hash(state, instance_id) else {
+        return;
+    };
+
+    let unchanged_rounds = {
+        let Ok(mut frames) = LAST_FRAMES.lock() else {
+            return;
+        };
+        let rounds = match frames.get(instance_id) {
+            Some((last, rounds)) if similarity(last, &hash) >= config.similarity => rounds + 1,
+            _ => 0,
+        };
+        frames.insert(instance_id.to_string(), (hash, rounds));
+        rounds
+    };
+    if unchanged_rounds == 0 {
+        return;
+    }
+
+    let delay = BASE_DELAY
+        .saturating_mul(1 << (unchanged_rounds - 1).min(16))
+        .min(Duration::from_millis(config.max_interval_ms));
+    debug!(
+        "[frame_dedup] {}: frame unchanged for {} rounds, waiting {:?}",
+        instance_id, unchanged_rounds, delay
+    );
+    wait_unless_stopping(state, instance_id, delay);
+}
+
+/// 移除实例的截图去重设置与帧记录（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut entries) = CONFIGS.lock() {
+        entries.remove(instance_id);
+    }
+    if let Ok(mut entries) = LAST_FRAMES.lock() {
+        entries.remove(instance_id);
+    }
+}
+
+/// 设置实例的截图帧去重（立即生效），关闭时清除记录
+#[tauri::command]
+pub fn set_frame_dedup(
+    instance_id: String,
+    enabled: bool,
+    similarity: Option<f64>,
+    max_interval_ms: Option<u64>,
+) -> Result<(), String> {
+    let mut configs = CONFIGS.lock().map_err(|e| e.to_string())?;
+    if !enabled {
+        info!("set_frame_dedup: {} disabled", instance_id);
+        configs.remove(&instance_id);
+        clear_last_frame(&instance_id);
+        return Ok(());
+    }
+    let similarity = similarity.unwrap_or(DEFAULT_SIMILARITY);
+    if !(0.5..=1.0).contains(&similarity) {
+        return Err("相似度阈值需在 0.5 到 1 之间".to_string());
+    }
+    let config = FrameDedupConfig {
+        similarity,
+        max_interval_ms: max_interval_ms.unwrap_or(DEFAULT_MAX_INTERVAL_MS),
+    };
+    info!("set_frame_dedup: {} -> {:?}", instance_id, config);
+    configs.insert(instance_id, config);
+    Ok(())
+}
+
+/// 获取实例的截图帧去重配置，未开启时返回 None
+#[tauri::command]
+pub fn get_frame_dedup(instance_id: String) -> Option<FrameDedupConfig> {
+    dedup_config(&instance_id)
+}
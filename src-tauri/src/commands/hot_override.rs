@@ -0,0 +1,83 @@
+//! 运行中热更新 Pipeline override
+//!
+//! `maa_override_pipeline` 对排队中和运行中的任务都会提交给 MaaFramework。节点定义在节点开始时读取，
+//! 新配置从下一个节点开始生效，正在执行的节点（包括其动作）不受影响，变更总是落在节点边界上。
+//! 这里按任务状态把本次修改的字段分为立即生效、待所在节点下次执行时生效、需重新开始任务三类返回给前端；
+//! 循环执行时同时把修改记入循环上下文，之后各轮重新提交时沿用。
+
+use serde_json::{Map, Value};
+
+use maa_framework::MaaStatus;
+
+use super::node_timing;
+use super::types::{InstanceRuntime, PipelineOverrideResult, TaskConfig};
+use super::utils::merge_pipeline_override;
+
+/// 按任务状态对本次修改的字段分类
+///
+/// 排队中的任务全部立即生效；运行中的任务入口节点已执行过，其字段需重新开始任务才生效，
+/// 正在执行的节点待其下次执行时生效，其余节点从下一个节点开始生效；任务已结束时全部需重新开始
+pub fn classify(
+    result: &mut PipelineOverrideResult,
+    layers: &[Map<String, Value>],
+    instance_id: &str,
+    status: MaaStatus,
+    entry: Option<&str>,
+) {
+    let mut merged = Map::new();
+    for layer in layers {
+        merge_pipeline_override(&mut merged, layer);
+    }
+    let running_nodes = node_timing::running_nodes(instance_id);
+
+    for (node, fields) in &merged {
+        let names: Vec<String> = match fields {
+            Value::Object(fields) => fields.keys().map(|k| format!("{}.{}", node, k)).collect(),
+            _ => vec![node.clone()],
+        };
+        let target = if status == MaaStatus::PENDING {
+            &mut result.hot_fields
+        } else if status != MaaStatus::RUNNING || entry == Some(node.as_str()) {
+            &mut result.restart_fields
+        } else if running_nodes.iter().any(|n| n == node) {
+            &mut result.deferred_fields
+        } else {
+            &mut result.hot_fields
+        };
+        target.extend(names);
+    }
+}
+
+/// 循环执行时把修改记入循环上下文，之后各轮重新提交该任务时一并应用（`maa_reset_pipeline` 时清除）
+///
+/// 返回是否已同步到后续轮次
+pub fn sync_to_loop(
+    instance: &mut InstanceRuntime,
+    task_id: i64,
+    layers: &[Map<String, Value>],
+) -> bool {
+    let Some(original) = instance
+        .submitted_tasks
+        .iter()
+        .find(|(id, _)| *id == task_id)
+        .map(|(_, task)| task)
+    else {
+        return false;
+    };
+    let Some(ctx) = instance.queue_loop.as_mut() else {
+        return false;
+    };
+    // 循环上下文中的任务没有 task_id，按前端任务 ID（没有时按入口）对应
+    let matches = |task: &TaskConfig| match &original.selected_task_id {
+        Some(id) => task.selected_task_id.as_deref() == Some(id),
+        None => task.entry == original.entry,
+    };
+    let Some(index) = ctx.tasks.iter().position(matches) else {
+        return false;
+    };
+    ctx.hot_layers
+        .entry(index)
+        .or_default()
+        .extend(layers.iter().cloned());
+    true
+}
@@ -0,0 +1,195 @@
+//! 按实例分文件保存的运行日志
+//!
+//! 所有日志仍写入 `mxu-tauri` 日志文件；带实例标签的运行日志（前端推送到后端缓冲区的日志）
+//! 额外写到日志目录下的 `instance-<id>.log`，多开时可按实例排查，导出日志时也可只导某个实例。
+//! 单个文件超过上限时轮转为 `instance-<id>.old.log`。实例销毁后文件保留供排查，
+//! 启动时清理超过保留期的文件，文件总数超出上限时从最旧的开始删除。
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, SystemTime};
+
+use log::{info, warn};
+
+use super::types::LogEntryDto;
+use super::utils::get_logs_dir;
+
+/// 实例日志文件名前缀
+const INSTANCE_LOG_PREFIX: &str = "instance-";
+
+/// 单个实例日志文件的大小上限，超过后轮转
+const INSTANCE_LOG_MAX_SIZE: u64 = 4 * 1024 * 1024;
+
+/// 实例日志文件的保留期（按最后修改时间）
+const INSTANCE_LOG_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// 实例日志文件总数上限（含轮转档）
+const MAX_INSTANCE_LOG_FILES: usize = 40;
+
+/// 已打开的实例日志文件
+struct InstanceLogFile {
+    writer: BufWriter<File>,
+    size: u64,
+}
+
+/// 各实例已打开的日志文件（首次写入时打开，实例销毁时关闭）
+static INSTANCE_LOG_WRITERS: LazyLock<Mutex<HashMap<String, InstanceLogFile>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 实例 ID 中只保留可用于文件名的字符
+pub fn sanitize_id(instance_id: &str) -> String {
+    instance_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// 实例日志文件路径
+fn instance_log_path(instance_id: &str) -> PathBuf {
+    get_logs_dir().join(format!(
+        "{}{}.log",
+        INSTANCE_LOG_PREFIX,
+        sanitize_id(instance_id)
+    ))
+}
+
+/// 实例日志轮转档路径
+fn rotated_log_path(instance_id: &str) -> PathBuf {
+    get_logs_dir().join(format!(
+        "{}{}.old.log",
+        INSTANCE_LOG_PREFIX,
+        sanitize_id(instance_id)
+    ))
+}
+
+/// 实例的全部日志文件（轮转档在前），不存在的文件不返回
+pub fn instance_log_files(instance_id: &str) -> Vec<PathBuf> {
+    [
+        rotated_log_path(instance_id),
+        instance_log_path(instance_id),
+    ]
+    .into_iter()
+    .filter(|p| p.is_file())
+    .collect()
+}
+
+/// 打开实例日志文件（追加写入），文件已超过上限时先轮转
+fn open_instance_log(instance_id: &str) -> Result<InstanceLogFile, String> {
+    let path = instance_log_path(instance_id);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("无法创建日志目录: {}", e))?;
+    }
+    if std::fs::metadata(&path).is_ok_and(|m| m.len() > INSTANCE_LOG_MAX_SIZE) {
+        let _ = std::fs::rename(&path, rotated_log_path(instance_id));
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("无法打开实例日志 [{}]: {}", path.display(), e))?;
+    let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+    Ok(InstanceLogFile {
+        writer: BufWriter::new(file),
+        size,
+    })
+}
+
+/// 把一条运行日志追加写入实例日志文件，写入失败只记录警告
+pub fn append(instance_id: &str, entry: &LogEntryDto) {
+    let Ok(mut writers) = INSTANCE_LOG_WRITERS.lock() else {
+        return;
+    };
+
+    let needs_rotate = writers
+        .get(instance_id)
+        .is_some_and(|f| f.size > INSTANCE_LOG_MAX_SIZE);
+    if needs_rotate {
+        if let Some(mut old) = writers.remove(instance_id) {
+            let _ = old.writer.flush();
+        }
+    }
+    if !writers.contains_key(instance_id) {
+        match open_instance_log(instance_id) {
+            Ok(file) => {
+                writers.insert(instance_id.to_string(), file);
+            }
+            Err(e) => {
+                warn!("{}", e);
+                return;
+            }
+        }
+    }
+    let Some(file) = writers.get_mut(instance_id) else {
+        return;
+    };
+
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f");
+    let level = entry.log_type.to_uppercase();
+    let mut text = String::new();
+    for line in entry.message.lines() {
+        text.push_str(&format!("[{}][{}] {}\n", timestamp, level, line));
+    }
+    let result = file
+        .writer
+        .write_all(text.as_bytes())
+        .and_then(|_| file.writer.flush());
+    match result {
+        Ok(()) => file.size += text.len() as u64,
+        Err(e) => warn!("Failed to write instance log {}: {}", instance_id, e),
+    }
+}
+
+/// 关闭实例的日志文件（实例销毁时调用，文件本身保留）
+pub fn close(instance_id: &str) {
+    if let Ok(mut writers) = INSTANCE_LOG_WRITERS.lock() {
+        if let Some(mut file) = writers.remove(instance_id) {
+            let _ = file.writer.flush();
+        }
+    }
+}
+
+/// 清理实例日志：删除超过保留期的文件，文件数超出上限时从最旧的开始删除（启动时调用）
+pub fn cleanup_instance_logs() {
+    let Ok(entries) = std::fs::read_dir(get_logs_dir()) else {
+        return;
+    };
+    let now = SystemTime::now();
+    let mut files: Vec<(PathBuf, SystemTime)> = entries
+        .flatten()
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            name.starts_with(INSTANCE_LOG_PREFIX) && name.ends_with(".log")
+        })
+        .filter_map(|e| {
+            let modified = e.metadata().ok()?.modified().ok()?;
+            Some((e.path(), modified))
+        })
+        .collect();
+
+    // 新的在前
+    files.sort_by(|a, b| b.1.cmp(&a.1));
+    let mut removed = 0;
+    for (index, (path, modified)) in files.iter().enumerate() {
+        let expired = now
+            .duration_since(*modified)
+            .is_ok_and(|age| age > INSTANCE_LOG_RETENTION);
+        if expired || index >= MAX_INSTANCE_LOG_FILES {
+            match std::fs::remove_file(path) {
+                Ok(()) => removed += 1,
+                Err(e) => warn!("清理实例日志失败 [{}]: {}", path.display(), e),
+            }
+        }
+    }
+    if removed > 0 {
+        info!("已清理 {} 个过期实例日志", removed);
+    }
+}
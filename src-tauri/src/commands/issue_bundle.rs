@@ -0,0 +1,232 @@
+//! 识别失败问题包导出
+//!
+//! 识别失败时一键打包排查所需的完整上下文：当前截图（原图与带识别框的标注图）、最近一轮
+//! 识别详情、识别到的节点定义、任务的 pipeline override、实例日志片段和系统/版本信息，
+//! 附 README 说明每个文件的含义，方便直接附在反馈里。拿不到的部分跳过并在 README 中注明。
+
+use std::io::Write;
+use std::sync::Arc;
+
+use log::{info, warn};
+use serde_json::Value;
+use tauri::State;
+
+use super::annotated_screenshot::{last_recognition_details, save_annotated_screenshot_impl};
+use super::maa_core::{get_pipeline_overrides_impl, version_info_impl};
+use super::system::{get_system_info, query_gpu_adapters};
+use super::types::MaaState;
+use super::utils::get_app_data_dir;
+
+/// 问题包中附带的最近日志条数
+const LOG_EXCERPT_LIMIT: usize = 300;
+
+/// 各文件在 README 中的说明
+const FILE_DESCRIPTIONS: &[(&str, &str)] = &[
+    ("screenshot.png", "失败时控制器缓存的最近一帧截图（原图）"),
+    (
+        "annotated.png",
+        "同一帧截图上画出最近一轮的识别框与分数，绿色为命中，红色为未命中（取分数最高的候选）",
+    ),
+    (
+        "recognitions.json",
+        "最近一轮 next 列表中各节点的识别结果与识别算法输出的完整详情（all / best / filtered）",
+    ),
+    (
+        "nodes.json",
+        "上述节点在已加载资源中的定义（合并后的节点配置）",
+    ),
+    (
+        "pipeline_overrides.json",
+        "实例当前任务的 pipeline override：提交时的原始值、运行中追加的各次值和叠加后的生效值",
+    ),
+    ("logs.json", "该实例最近的运行日志"),
+    (
+        "system_info.json",
+        "操作系统、MXU / MaaFramework 版本、显卡等环境信息",
+    ),
+];
+
+fn to_json_bytes<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, String> {
+    serde_json::to_vec_pretty(value).map_err(|e| format!("序列化失败: {}", e))
+}
+
+/// 收集问题包中的各文件内容，返回 (文件名, 内容) 列表和拿不到的部分说明
+fn collect_bundle_files(
+    state: &Arc<MaaState>,
+    instance_id: &str,
+) -> Result<(Vec<(&'static str, Vec<u8>)>, Vec<String>), String> {
+    let (controller, resource, instance_name) = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        (
+            instance.controller.clone(),
+            instance.resource.clone(),
+            instance.name.clone(),
+        )
+    };
+
+    let mut files: Vec<(&'static str, Vec<u8>)> = Vec::new();
+    let mut missing: Vec<String> = Vec::new();
+
+    // 截图
+    let screenshot = controller
+        .as_ref()
+        .and_then(|c| c.cached_image().ok())
+        .and_then(|buf| buf.to_vec())
+        .filter(|data| !data.is_empty());
+    match screenshot {
+        Some(png) => files.push(("screenshot.png", png)),
+        None => missing.push("screenshot.png: 控制器未连接或暂无截图".to_string()),
+    }
+    match save_annotated_screenshot_impl(state, instance_id)
+        .and_then(|result| std::fs::read(&result.path).map_err(|e| e.to_string()))
+    {
+        Ok(png) => files.push(("annotated.png", png)),
+        Err(e) => missing.push(format!("annotated.png: {}", e)),
+    }
+
+    // 识别详情与节点定义
+    let recognitions = last_recognition_details(instance_id);
+    if recognitions.is_empty() {
+        missing.push("recognitions.json: 当前没有识别记录".to_string());
+        missing.push("nodes.json: 当前没有识别记录".to_string());
+    } else {
+        let mut nodes = serde_json::Map::new();
+        match &resource {
+            Some(resource) => {
+                for node in recognitions
+                    .iter()
+                    .filter_map(|r| r.get("node").and_then(|n| n.as_str()))
+                {
+                    let data = resource
+                        .get_node_data(node)
+                        .ok()
+                        .flatten()
+                        .and_then(|data| serde_json::from_str::<Value>(&data).ok())
+                        .unwrap_or(Value::Null);
+                    nodes.insert(node.to_string(), data);
+                }
+                files.push(("nodes.json", to_json_bytes(&nodes)?));
+            }
+            None => missing.push("nodes.json: 资源未加载".to_string()),
+        }
+        files.push(("recognitions.json", to_json_bytes(&recognitions)?));
+    }
+
+    // pipeline override
+    match get_pipeline_overrides_impl(state, instance_id) {
+        Ok(overrides) if !overrides.is_empty() => {
+            files.push(("pipeline_overrides.json", to_json_bytes(&overrides)?));
+        }
+        Ok(_) => missing.push("pipeline_overrides.json: 当前没有运行中的任务".to_string()),
+        Err(e) => missing.push(format!("pipeline_overrides.json: {}", e)),
+    }
+
+    // 日志片段
+    let logs: Vec<_> = {
+        let buffer = state.log_buffer.lock().map_err(|e| e.to_string())?;
+        buffer
+            .get_all()
+            .get(instance_id)
+            .map(|entries| {
+                let skip = entries.len().saturating_sub(LOG_EXCERPT_LIMIT);
+                entries.iter().skip(skip).cloned().collect()
+            })
+            .unwrap_or_default()
+    };
+    if logs.is_empty() {
+        missing.push("logs.json: 该实例暂无日志".to_string());
+    } else {
+        files.push(("logs.json", to_json_bytes(&logs)?));
+    }
+
+    // 系统与版本信息
+    let system_info = serde_json::json!({
+        "instance_id": instance_id,
+        "instance_name": instance_name,
+        "exported_at": chrono::Local::now().to_rfc3339(),
+        "system": get_system_info(),
+        "version": version_info_impl(state),
+        "gpu_adapters": query_gpu_adapters(),
+    });
+    files.push(("system_info.json", to_json_bytes(&system_info)?));
+
+    Ok((files, missing))
+}
+
+fn build_readme(files: &[(&'static str, Vec<u8>)], missing: &[String]) -> String {
+    let mut readme = String::from(
+        "MXU 识别失败问题包\n\n反馈问题时请附上本压缩包，其中包含识别失败时的截图和上下文。\n\n文件说明：\n",
+    );
+    for (name, description) in FILE_DESCRIPTIONS {
+        if files.iter().any(|(f, _)| f == name) {
+            readme.push_str(&format!("- {}: {}\n", name, description));
+        }
+    }
+    if !missing.is_empty() {
+        readme.push_str("\n未包含的内容：\n");
+        for item in missing {
+            readme.push_str(&format!("- {}\n", item));
+        }
+    }
+    readme
+}
+
+fn export_issue_bundle_blocking(
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    project_name: Option<String>,
+) -> Result<String, String> {
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    let (files, missing) = collect_bundle_files(state, instance_id)?;
+
+    let exports_dir = get_app_data_dir()?.join("debug_exports");
+    std::fs::create_dir_all(&exports_dir)
+        .map_err(|e| format!("创建导出目录失败 [{}]: {}", exports_dir.display(), e))?;
+    let name = project_name.unwrap_or_else(|| "mxu".to_string());
+    let path = exports_dir.join(format!(
+        "{}-issue-{}.zip",
+        name,
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    let file = std::fs::File::create(&path)
+        .map_err(|e| format!("创建压缩包失败 [{}]: {}", path.display(), e))?;
+    let mut zip = ZipWriter::new(std::io::BufWriter::new(file));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let readme = build_readme(&files, &missing);
+    let entries = std::iter::once(("README.txt", readme.into_bytes())).chain(files);
+    for (name, data) in entries {
+        zip.start_file(name, options)
+            .and_then(|_| zip.write_all(&data).map_err(Into::into))
+            .map_err(|e| format!("写入 {} 失败: {}", name, e))?;
+    }
+    zip.finish().map_err(|e| format!("完成压缩包失败: {}", e))?;
+
+    if !missing.is_empty() {
+        warn!(
+            "[issue_bundle] {}: exported without {:?}",
+            instance_id, missing
+        );
+    }
+    info!("Issue bundle exported: {}", path.display());
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// 导出识别失败时的完整上下文包（zip，保存到 debug_exports），返回压缩包路径
+#[tauri::command]
+pub async fn export_issue_bundle(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    project_name: Option<String>,
+) -> Result<String, String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        export_issue_bundle_blocking(&state, &instance_id, project_name)
+    })
+    .await
+    .map_err(|e| format!("导出任务执行失败: {}", e))?
+}
@@ -0,0 +1,123 @@
+//! 任务结束时的最后一帧截图
+//!
+//! 任务进入终态（`Tasker.Task.Succeeded` / `Tasker.Task.Failed`）时从控制器缓存截图中取出当前画面，
+//! 按实例保留下来。之后的截图会覆盖控制器缓存，用户回来时仍可通过 `maa_get_last_frame`
+//! 查看任务结束时的画面（是否领取成功、卡在哪个界面）。
+//!
+//! 每个实例只保留最新一帧（原始 PNG），base64 编码在查询时才进行。
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use log::debug;
+use tauri::State;
+
+use super::types::{LastFrame, MaaState};
+
+/// 保留的最后一帧
+struct StoredFrame {
+    task_id: i64,
+    entry: String,
+    status: &'static str,
+    captured_at: i64,
+    png: Vec<u8>,
+}
+
+/// 各实例的最后一帧（instance_id -> 帧）
+static LAST_FRAMES: LazyLock<Mutex<HashMap<String, StoredFrame>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 任务进入终态时保留控制器当前的缓存截图
+///
+/// 在 tasker sink 中、转发回调之前调用，此时缓存截图仍是任务结束时的画面
+pub fn capture_on_task_end(state: &MaaState, instance_id: &str, message: &str, details: &str) {
+    let status = match message {
+        "Tasker.Task.Succeeded" => "succeeded",
+        "Tasker.Task.Failed" => "failed",
+        _ => return,
+    };
+    let Ok(details) = serde_json::from_str::<serde_json::Value>(details) else {
+        return;
+    };
+    let entry = details
+        .get("entry")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+    // 停止任务本身的结束不代表业务任务的画面
+    if entry == "MaaTaskerPostStop" {
+        return;
+    }
+    let task_id = details
+        .get("task_id")
+        .and_then(|v| v.as_i64())
+        .unwrap_or_default();
+
+    let Some(controller) = state
+        .instances
+        .lock()
+        .ok()
+        .and_then(|instances| instances.get(instance_id)?.controller.clone())
+    else {
+        return;
+    };
+    let Some(png) = controller
+        .cached_image()
+        .ok()
+        .and_then(|buf| buf.to_vec())
+        .filter(|data| !data.is_empty())
+    else {
+        return;
+    };
+
+    debug!(
+        "[last_frame] {}: kept frame of task {} ({}), {} bytes",
+        instance_id,
+        task_id,
+        status,
+        png.len()
+    );
+    if let Ok(mut frames) = LAST_FRAMES.lock() {
+        frames.insert(
+            instance_id.to_string(),
+            StoredFrame {
+                task_id,
+                entry: entry.to_string(),
+                status,
+                captured_at: chrono::Local::now().timestamp_millis(),
+                png,
+            },
+        );
+    }
+}
+
+/// 移除实例保留的最后一帧（销毁实例或重置运行时状态时调用）
+pub fn clear_last_frame(instance_id: &str) {
+    if let Ok(mut frames) = LAST_FRAMES.lock() {
+        frames.remove(instance_id);
+    }
+}
+
+/// 获取实例最近一个结束的任务保留的最后一帧，尚无任务结束时返回 None
+#[tauri::command]
+pub fn maa_get_last_frame(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<Option<LastFrame>, String> {
+    if !state
+        .instances
+        .lock()
+        .map_err(|e| e.to_string())?
+        .contains_key(&instance_id)
+    {
+        return Err("Instance not found".to_string());
+    }
+    let frames = LAST_FRAMES.lock().map_err(|e| e.to_string())?;
+    Ok(frames.get(&instance_id).map(|frame| LastFrame {
+        task_id: frame.task_id,
+        entry: frame.entry.clone(),
+        status: frame.status.to_string(),
+        captured_at: frame.captured_at,
+        data_url: format!("data:image/png;base64,{}", STANDARD.encode(&frame.png)),
+    }))
+}
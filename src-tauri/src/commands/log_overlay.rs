@@ -0,0 +1,293 @@
+//! 日志悬浮窗
+//!
+//! 置顶的小窗口（label 为 `log-overlay`），加载同一前端页面并以 `?overlay=log` 区分，
+//! 显示各实例最新的运行日志。日志经 push_log 写入后端缓冲区时转发给悬浮窗，
+//! 在悬浮窗点击日志时通过 jump_to_log 让主窗口日志面板跳转到对应位置。
+//!
+//! 开启 auto_show_overlay 后，任务开始时自动显示悬浮窗，所有实例任务结束后延迟
+//! [`AUTO_HIDE_DELAY`] 自动关闭（期间有任务重新开始则取消），避免任务间隙频繁闪烁。
+//! 手动打开的悬浮窗不会被自动关闭。
+//!
+//! 悬浮窗可切换到画面预览模式：以 `log-overlay` 订阅者身份驱动截图服务，并按
+//! [`PREVIEW_INTERVAL`] 把所选实例的缩略图与当前一轮识别框推送给悬浮窗，帧率较低以节省资源。
+
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use log::{info, warn};
+use tauri::{AppHandle, Emitter, Manager, State, WebviewUrl, WebviewWindowBuilder};
+
+use super::annotated_screenshot::last_recognitions;
+use super::types::{LogEntryDto, MaaState, OverlayPreviewFrame, TaggedLogEntry};
+use super::utils::{downscale_rgba, encode_png};
+
+/// 悬浮窗的窗口 label
+pub const OVERLAY_LABEL: &str = "log-overlay";
+
+/// 所有任务结束后自动关闭悬浮窗前的等待时间
+const AUTO_HIDE_DELAY: Duration = Duration::from_secs(5);
+
+/// 画面预览的推送间隔（约 1.7 fps）
+const PREVIEW_INTERVAL: Duration = Duration::from_millis(600);
+
+/// 预览缩略图的最长边（像素）
+const PREVIEW_MAX_SIDE: u32 = 480;
+
+/// 任务开始时是否自动显示悬浮窗
+static AUTO_SHOW: AtomicBool = AtomicBool::new(false);
+
+/// 当前悬浮窗是否由任务开始自动打开（手动打开时为 false，不参与自动关闭）
+static AUTO_OPENED: AtomicBool = AtomicBool::new(false);
+
+/// 自动关闭的计时代数，任务重新开始或手动操作时递增，使尚未执行的自动关闭失效
+static HIDE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// 当前画面预览的实例（日志模式时为 None）
+static PREVIEW_INSTANCE: Mutex<Option<String>> = Mutex::new(None);
+
+/// 画面预览的代次，切换实例或模式时递增，使旧的推送线程退出
+static PREVIEW_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// 显示悬浮窗，不存在时创建（不抢占焦点）
+fn show_overlay(app: &AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(OVERLAY_LABEL) {
+        return window.show().map_err(|e| e.to_string());
+    }
+    WebviewWindowBuilder::new(
+        app,
+        OVERLAY_LABEL,
+        WebviewUrl::App("index.html?overlay=log".into()),
+    )
+    .title("MXU Log")
+    .inner_size(420.0, 240.0)
+    .min_inner_size(240.0, 120.0)
+    .always_on_top(true)
+    .decorations(false)
+    .skip_taskbar(true)
+    .focused(false)
+    .build()
+    .map_err(|e| format!("创建日志悬浮窗失败: {}", e))?;
+    info!("Log overlay window created");
+    Ok(())
+}
+
+/// 关闭悬浮窗（不存在时忽略）
+fn close_overlay(app: &AppHandle) -> Result<(), String> {
+    match app.get_webview_window(OVERLAY_LABEL) {
+        Some(window) => window.close().map_err(|e| e.to_string()),
+        None => Ok(()),
+    }
+}
+
+/// 将一条新日志转发给悬浮窗（悬浮窗未打开时不发送）
+pub fn forward_log(app: &AppHandle, state: &MaaState, instance_id: &str, entry: &LogEntryDto) {
+    if app.get_webview_window(OVERLAY_LABEL).is_none() {
+        return;
+    }
+    let instance_name = state
+        .instances
+        .lock()
+        .ok()
+        .and_then(|instances| instances.get(instance_id)?.name.clone());
+    let payload = TaggedLogEntry {
+        instance_id: instance_id.to_string(),
+        instance_name,
+        entry: entry.clone(),
+    };
+    if let Err(e) = app.emit_to(OVERLAY_LABEL, "maa-overlay-log", payload) {
+        warn!("[log_overlay] Failed to forward log: {}", e);
+    }
+}
+
+/// 任务开始时调用：开启自动显示且悬浮窗未打开时自动打开，并取消待执行的自动关闭
+pub fn on_task_started(app: &AppHandle) {
+    if !AUTO_SHOW.load(Ordering::SeqCst) {
+        return;
+    }
+    HIDE_GENERATION.fetch_add(1, Ordering::SeqCst);
+    if app.get_webview_window(OVERLAY_LABEL).is_some() {
+        return;
+    }
+    match show_overlay(app) {
+        Ok(()) => AUTO_OPENED.store(true, Ordering::SeqCst),
+        Err(e) => warn!("[log_overlay] Failed to auto show overlay: {}", e),
+    }
+}
+
+/// 实例任务全部结束时调用：延迟后若所有实例都已空闲，关闭自动打开的悬浮窗
+pub fn on_tasks_finished(app: &AppHandle, state: &Arc<MaaState>) {
+    if !AUTO_OPENED.load(Ordering::SeqCst) {
+        return;
+    }
+    let generation = HIDE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let app = app.clone();
+    let state = Arc::clone(state);
+    std::thread::spawn(move || {
+        std::thread::sleep(AUTO_HIDE_DELAY);
+        if HIDE_GENERATION.load(Ordering::SeqCst) != generation
+            || !AUTO_OPENED.load(Ordering::SeqCst)
+        {
+            return;
+        }
+        let any_running = state
+            .instances
+            .lock()
+            .map(|instances| instances.values().any(|i| !i.task_ids.is_empty()))
+            .unwrap_or(true);
+        if any_running {
+            return;
+        }
+        AUTO_OPENED.store(false, Ordering::SeqCst);
+        match close_overlay(&app) {
+            Ok(()) => info!("Log overlay auto hidden after tasks finished"),
+            Err(e) => warn!("[log_overlay] Failed to auto hide overlay: {}", e),
+        }
+    });
+}
+
+/// 手动打开日志悬浮窗（手动打开的悬浮窗不会被自动关闭）
+#[tauri::command]
+pub async fn open_log_overlay(app: AppHandle) -> Result<(), String> {
+    AUTO_OPENED.store(false, Ordering::SeqCst);
+    HIDE_GENERATION.fetch_add(1, Ordering::SeqCst);
+    show_overlay(&app)
+}
+
+/// 关闭日志悬浮窗
+#[tauri::command]
+pub async fn close_log_overlay(app: AppHandle) -> Result<(), String> {
+    AUTO_OPENED.store(false, Ordering::SeqCst);
+    HIDE_GENERATION.fetch_add(1, Ordering::SeqCst);
+    close_overlay(&app)
+}
+
+/// 查询日志悬浮窗是否已打开
+#[tauri::command]
+pub fn is_log_overlay_open(app: AppHandle) -> bool {
+    app.get_webview_window(OVERLAY_LABEL).is_some()
+}
+
+/// 设置任务开始时是否自动显示日志悬浮窗（由前端在加载配置和修改设置时同步）
+#[tauri::command]
+pub fn set_auto_show_overlay(enabled: bool) {
+    info!("set_auto_show_overlay: {}", enabled);
+    AUTO_SHOW.store(enabled, Ordering::SeqCst);
+}
+
+/// 生成实例当前画面的预览帧，画面与识别结果都未变化时返回 None
+fn preview_frame(
+    state: &MaaState,
+    instance_id: &str,
+    last_hash: &mut Option<u64>,
+) -> Option<OverlayPreviewFrame> {
+    let controller = {
+        let instances = state.instances.lock().ok()?;
+        instances.get(instance_id)?.controller.clone()?
+    };
+    let png = controller
+        .cached_image()
+        .ok()
+        .and_then(|buf| buf.to_vec())
+        .filter(|data| !data.is_empty())?;
+    let recognitions = last_recognitions(instance_id);
+
+    let hash = {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        png.hash(&mut hasher);
+        serde_json::to_string(&recognitions)
+            .unwrap_or_default()
+            .hash(&mut hasher);
+        hasher.finish()
+    };
+    if *last_hash == Some(hash) {
+        return None;
+    }
+    *last_hash = Some(hash);
+
+    let image = match tauri::image::Image::from_bytes(&png) {
+        Ok(image) => image,
+        Err(e) => {
+            warn!("[log_overlay] Failed to decode preview frame: {}", e);
+            return None;
+        }
+    };
+    let (width, height) = (image.width(), image.height());
+    let thumbnail = if width.max(height) > PREVIEW_MAX_SIDE {
+        let (w, h, rgba) = downscale_rgba(width, height, image.rgba(), PREVIEW_MAX_SIDE);
+        encode_png(w, h, &rgba)
+    } else {
+        png
+    };
+    Some(OverlayPreviewFrame {
+        instance_id: instance_id.to_string(),
+        image: format!("data:image/png;base64,{}", STANDARD.encode(thumbnail)),
+        width,
+        height,
+        recognitions,
+    })
+}
+
+/// 画面预览推送循环，代次变化或悬浮窗关闭时退出
+fn preview_loop(app: AppHandle, state: Arc<MaaState>, instance_id: String, generation: u64) {
+    let mut last_hash = None;
+    while PREVIEW_GENERATION.load(Ordering::SeqCst) == generation {
+        if app.get_webview_window(OVERLAY_LABEL).is_none() {
+            // 悬浮窗已关闭，停止为其驱动截图
+            state
+                .screenshot_service
+                .unsubscribe(&instance_id, OVERLAY_LABEL);
+            if let Ok(mut preview) = PREVIEW_INSTANCE.lock() {
+                if PREVIEW_GENERATION.load(Ordering::SeqCst) == generation {
+                    *preview = None;
+                }
+            }
+            info!("Overlay preview stopped: overlay closed");
+            return;
+        }
+        state.screenshot_service.touch(&instance_id, OVERLAY_LABEL);
+        if let Some(frame) = preview_frame(&state, &instance_id, &mut last_hash) {
+            if let Err(e) = app.emit_to(OVERLAY_LABEL, "maa-overlay-frame", frame) {
+                warn!("[log_overlay] Failed to send preview frame: {}", e);
+            }
+        }
+        std::thread::sleep(PREVIEW_INTERVAL);
+    }
+}
+
+/// 设置悬浮窗画面预览的实例，传 None 切回日志模式
+#[tauri::command]
+pub async fn set_overlay_preview(
+    app: AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: Option<String>,
+) -> Result<(), String> {
+    let generation = PREVIEW_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let mut preview = PREVIEW_INSTANCE.lock().map_err(|e| e.to_string())?;
+    if let Some(previous) = preview.take() {
+        state
+            .screenshot_service
+            .unsubscribe(&previous, OVERLAY_LABEL);
+    }
+    let Some(instance_id) = instance_id else {
+        info!("Overlay preview disabled");
+        return Ok(());
+    };
+
+    info!("Overlay preview: {}", instance_id);
+    // 主窗口最小化到托盘时悬浮窗仍需预览，不作为本地预览订阅者暂停
+    state.screenshot_service.subscribe(
+        state.inner().clone(),
+        instance_id.clone(),
+        OVERLAY_LABEL.to_string(),
+        PREVIEW_INTERVAL.as_millis() as u64,
+        false,
+        tokio::runtime::Handle::current(),
+    );
+    *preview = Some(instance_id.clone());
+    let state = state.inner().clone();
+    std::thread::spawn(move || preview_loop(app, state, instance_id, generation));
+    Ok(())
+}
@@ -3,7 +3,7 @@
 //! 提供 MaaFramework Agent 启动和管理功能
 
 use log::{debug, error, info, warn};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::OpenOptions;
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Component, Path, PathBuf};
@@ -19,8 +19,31 @@ use maa_framework::controller::Controller;
 use maa_framework::resource::Resource;
 use maa_framework::tasker::Tasker;
 
-use super::types::{AgentConfig, MaaState, TaskConfig};
-use super::utils::{emit_callback_event, get_logs_dir, handle_task_callback, normalize_path};
+use super::action_trace;
+use super::agent_job;
+use super::breakpoint::handle_breakpoint_callback;
+use super::capture_mode;
+use super::dry_run::{build_dry_run_override, is_dry_run};
+use super::event_log;
+use super::failure_screenshot;
+use super::frame_dedup;
+use super::last_frame;
+use super::node_timing;
+use super::preempt;
+use super::reco_stats;
+use super::speed::apply_speed;
+use super::task_condition;
+use super::task_loop;
+use super::types::{
+    AgentConfig, AgentLaunchContext, AgentStopOutcome, InstanceRuntime, MaaState,
+    PipelineOverrideRecord, TaskConfig, TaskQueueLoop,
+};
+use super::unknown_screens;
+use super::utils::{
+    emit_callback_event, get_logs_dir, handle_task_callback, instance_label, kill_process_tree,
+    normalize_path, parse_pipeline_override,
+};
+use super::window_state;
 use regex::Regex;
 use std::sync::LazyLock;
 use std::time::{Duration, Instant};
@@ -29,10 +52,35 @@ use std::time::{Duration, Instant};
 #[derive(Clone, serde::Serialize)]
 pub struct AgentOutputEvent {
     pub instance_id: String,
+    /// 实例显示名（用于多实例日志标签）
+    pub instance_name: Option<String>,
     pub stream: String,
     pub line: String,
 }
 
+/// 单轮任务队列内 Agent 自动重启的次数上限
+const MAX_AGENT_RESTARTS: u32 = 3;
+
+/// Agent 进程存活检查间隔
+const AGENT_WATCHDOG_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 停止 Agent 时等待其自行退出的时长，超时后强制结束进程树
+const AGENT_STOP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Agent 自动重启事件载荷
+#[derive(Clone, serde::Serialize)]
+pub struct AgentRestartEvent {
+    pub instance_id: String,
+    /// restarting / restarted / failed / gave_up
+    pub status: String,
+    pub attempt: u32,
+    pub max_attempts: u32,
+    pub exit_code: Option<i32>,
+    /// 续跑的剩余任务数
+    pub remaining: usize,
+    pub error: Option<String>,
+}
+
 struct AgentOutputBatchState {
     lines: Vec<String>,
     first_stream: Option<String>,
@@ -165,11 +213,19 @@ impl AgentOutputBatcher {
 /// 发送 Agent 输出事件（Tauri WebView + WebSocket 浏览器客户端）
 fn emit_agent_output(app: &tauri::AppHandle, instance_id: &str, stream: &str, line: &str) {
     let clean_line = strip_ansi_escapes(line);
+    let instance_name = app.try_state::<Arc<MaaState>>().and_then(|state| {
+        state
+            .instances
+            .lock()
+            .ok()
+            .and_then(|instances| instances.get(instance_id)?.name.clone())
+    });
 
     // 广播到所有 WebSocket 客户端
     if let Some(ws) = app.try_state::<Arc<crate::ws_broadcast::WsBroadcast>>() {
         ws.send(crate::ws_broadcast::WsEvent::AgentOutput {
             instance_id: instance_id.to_string(),
+            instance_name: instance_name.clone(),
             stream: stream.to_string(),
             line: clean_line.clone(),
         });
@@ -178,6 +234,7 @@ fn emit_agent_output(app: &tauri::AppHandle, instance_id: &str, stream: &str, li
     // 发送到 Tauri WebView
     let event = AgentOutputEvent {
         instance_id: instance_id.to_string(),
+        instance_name,
         stream: stream.to_string(),
         line: clean_line,
     };
@@ -401,6 +458,7 @@ async fn start_single_agent(
             }
             msg
         })?;
+        agent_job::assign_agent_process(&child);
 
         // agent 日志文件路径（延迟创建：仅在有实际输出时才打开文件）
         let pid = child.id();
@@ -408,12 +466,19 @@ async fn start_single_agent(
         let agent_log_path = Arc::new(get_logs_dir().join(&log_filename));
         let log_file: Arc<Mutex<Option<std::fs::File>>> = Arc::new(Mutex::new(None));
         let output_batcher = AgentOutputBatcher::new(app.clone(), instance_id.clone());
+        // 后端日志中带上实例标签，多开时便于区分
+        let label: Arc<str> = app
+            .try_state::<Arc<MaaState>>()
+            .map(|state| instance_label(&state, &instance_id))
+            .unwrap_or_else(|| instance_id.clone())
+            .into();
 
         // 在单独线程中读取 stdout
         if let Some(stdout) = child.stdout.take() {
             let lf = log_file.clone();
             let lf_path = agent_log_path.clone();
             let batcher = output_batcher.clone();
+            let label = label.clone();
             thread::spawn(move || {
                 let mut reader = BufReader::new(stdout);
                 let mut buffer = Vec::new();
@@ -437,7 +502,7 @@ async fn start_single_agent(
                                     let _ = writeln!(file, "{} [stdout] {}", timestamp, clean_line);
                                 }
                             }
-                            info!(target: "agent", "[agent#{}][stdout] {}", agent_index, clean_line);
+                            info!(target: "agent", "[{}][agent#{}][stdout] {}", label, agent_index, clean_line);
                             batcher.enqueue("stdout", clean_line);
                         }
                         Err(_) => break,
@@ -451,6 +516,7 @@ async fn start_single_agent(
             let lf = log_file.clone();
             let lf_path = agent_log_path.clone();
             let batcher = output_batcher.clone();
+            let label = label.clone();
             thread::spawn(move || {
                 let mut reader = BufReader::new(stderr);
                 let mut buffer = Vec::new();
@@ -474,7 +540,7 @@ async fn start_single_agent(
                                     let _ = writeln!(file, "{} [stderr] {}", timestamp, clean_line);
                                 }
                             }
-                            warn!(target: "agent", "[agent#{}][stderr] {}", agent_index, clean_line);
+                            warn!(target: "agent", "[{}][agent#{}][stderr] {}", label, agent_index, clean_line);
                             batcher.enqueue("stderr", clean_line);
                         }
                         Err(_) => break,
@@ -523,6 +589,7 @@ pub async fn start_tasks_impl(
     tcp_compat_mode: bool,
     pi_envs: Option<HashMap<String, String>>,
     reset_state: bool,
+    queue_loop: Option<TaskQueueLoop>,
 ) -> Result<Vec<i64>, String> {
     info!("start_tasks_impl called");
 
@@ -530,6 +597,10 @@ pub async fn start_tasks_impl(
     info!("tasks: {:?}", tasks);
     info!("agent_configs: {:?}", agent_configs);
     info!("cwd: {}, tcp_compat_mode: {}", cwd, tcp_compat_mode);
+    info!("queue_loop: {:?}", queue_loop);
+    if let Some(config) = &queue_loop {
+        task_loop::validate_loop(config)?;
+    }
 
     let (resource, controller, tasker) = {
         debug!("[start_tasks] Acquiring instances lock...");
@@ -575,6 +646,12 @@ pub async fn start_tasks_impl(
             let maa_state_for_sink = Arc::clone(maa_state);
             let inst_id_for_sink = instance_id.clone();
             t.add_sink(move |msg, detail| {
+                let preempted = preempt::is_preempted_callback(
+                    &maa_state_for_sink,
+                    &inst_id_for_sink,
+                    msg,
+                    detail,
+                );
                 // 先更新后端 TaskRunState（单一真相来源）
                 handle_task_callback(
                     &maa_state_for_sink,
@@ -583,8 +660,38 @@ pub async fn start_tasks_impl(
                     msg,
                     detail,
                 );
-                // 再转发原始回调到前端
-                emit_callback_event(&app_handle, msg, detail);
+                event_log::record_callback(&inst_id_for_sink, msg, detail);
+                task_condition::apply_skip_condition(
+                    &maa_state_for_sink,
+                    &app_handle,
+                    &inst_id_for_sink,
+                    msg,
+                    detail,
+                );
+                capture_mode::before_capture(&maa_state_for_sink, &inst_id_for_sink, msg);
+                window_state::before_capture(&maa_state_for_sink, &inst_id_for_sink, msg);
+                frame_dedup::before_capture(&maa_state_for_sink, &inst_id_for_sink, msg);
+                unknown_screens::record_callback(
+                    &maa_state_for_sink,
+                    &inst_id_for_sink,
+                    msg,
+                    detail,
+                );
+                last_frame::capture_on_task_end(
+                    &maa_state_for_sink,
+                    &inst_id_for_sink,
+                    msg,
+                    detail,
+                );
+                // 再转发原始回调到前端（任务失败时按需附带截图）
+                let detail = failure_screenshot::attach_failure_screenshot(
+                    &maa_state_for_sink,
+                    &inst_id_for_sink,
+                    msg,
+                    detail,
+                );
+                let detail = preempt::tag_preempted_callback(detail, preempted);
+                emit_callback_event(&app_handle, msg, &*detail);
             })
             .map_err(|e| e.to_string())?;
             debug!("[start_tasks] Tasker sink added");
@@ -592,7 +699,30 @@ pub async fn start_tasks_impl(
             // 添加 Context Sink，用于接收 Node 级别的通知（包含 focus 消息）
             debug!("[start_tasks] Adding tasker context sink...");
             let app_handle = app.clone();
+            let maa_state_for_sink = Arc::clone(maa_state);
+            let inst_id_for_sink = instance_id.clone();
             t.add_context_sink(move |msg, detail| {
+                // 断点命中时在此阻塞任务线程，直到前端继续
+                handle_breakpoint_callback(
+                    &maa_state_for_sink,
+                    &app_handle,
+                    &inst_id_for_sink,
+                    msg,
+                    detail,
+                );
+                event_log::record_callback(&inst_id_for_sink, msg, detail);
+                action_trace::record_node(&inst_id_for_sink, msg, detail);
+                reco_stats::record_recognition(&maa_state_for_sink, &inst_id_for_sink, msg, detail);
+                node_timing::record_node_event(&inst_id_for_sink, msg, detail);
+                capture_mode::before_capture(&maa_state_for_sink, &inst_id_for_sink, msg);
+                window_state::before_capture(&maa_state_for_sink, &inst_id_for_sink, msg);
+                frame_dedup::before_capture(&maa_state_for_sink, &inst_id_for_sink, msg);
+                unknown_screens::record_callback(
+                    &maa_state_for_sink,
+                    &inst_id_for_sink,
+                    msg,
+                    detail,
+                );
                 emit_callback_event(&app_handle, msg, detail);
             })
             .map_err(|e| e.to_string())?;
@@ -621,7 +751,10 @@ pub async fn start_tasks_impl(
 
     // 启动所有 Agent（如果配置了）
     debug!("[start_tasks] Checking agent configs...");
-    let pi_envs = Arc::new(pi_envs.unwrap_or_default());
+    let pi_envs_map = pi_envs.unwrap_or_default();
+    let pi_envs = Arc::new(pi_envs_map.clone());
+    let restart_agent_configs = agent_configs.clone().unwrap_or_default();
+    let mut agents_started = false;
     if let Some(configs) = agent_configs {
         if configs.is_empty() {
             debug!("[start_tasks] Agent configs list is empty, skipping agent setup");
@@ -683,6 +816,7 @@ pub async fn start_tasks_impl(
             if let Some(instance) = instances.get_mut(&instance_id) {
                 instance.agent_clients.extend(new_clients);
                 instance.agent_children.extend(new_children);
+                agents_started = true;
             }
 
             info!(
@@ -699,17 +833,31 @@ pub async fn start_tasks_impl(
     debug!("[start_tasks] Submitting {} tasks...", tasks.len());
     // (maa_task_id, selected_task_id) 配对列表，用于后续初始化 TaskRunState
     let mut task_id_pairs: Vec<(i64, Option<String>)> = Vec::new();
+    // (maa_task_id, 任务配置)，用于 Agent 崩溃后续跑
+    let mut submitted_tasks: Vec<(i64, TaskConfig)> = Vec::new();
+    let dry_run = is_dry_run(&instance_id);
+    if dry_run {
+        info!("[start_tasks] Dry-run enabled, actions will be reported only");
+    }
     for (idx, task) in tasks.iter().enumerate() {
         debug!("[start_tasks] Preparing task {}: entry={}", idx, task.entry);
 
+        // dry-run 下替换所有节点动作；生成失败时不能退回原 override，否则会真的执行动作
+        let pipeline_override = if dry_run {
+            build_dry_run_override(&resource, &task.pipeline_override)?
+        } else {
+            task.pipeline_override.clone()
+        };
+        let pipeline_override = apply_speed(&resource, &instance_id, pipeline_override)?;
         info!(
             "[start_tasks] Calling post_task: entry={}, override={}",
             task.entry, task.pipeline_override
         );
-        match tasker.post_task(&task.entry, &task.pipeline_override) {
+        match tasker.post_task(&task.entry, &pipeline_override) {
             Ok(job) => {
                 info!("[start_tasks] post_task returned task_id: {}", job.id);
                 task_id_pairs.push((job.id, task.selected_task_id.clone()));
+                submitted_tasks.push((job.id, task.clone()));
                 debug!(
                     "[start_tasks] Task {} submitted successfully, task_id: {}",
                     idx, job.id
@@ -738,6 +886,12 @@ pub async fn start_tasks_impl(
             if reset_state {
                 // 首批：重置任务运行状态
                 instance.task_ids = task_ids.clone();
+                instance.task_report.clear();
+                instance.pipeline_overrides.clear();
+                instance.submitted_tasks.clear();
+                instance.preempted_task_ids.clear();
+                instance.agent_interrupted_task_ids.clear();
+                instance.retry_attempts.clear();
                 let state = &mut instance.task_run_state;
                 state.statuses.clear();
                 state.mappings.clear();
@@ -750,6 +904,41 @@ pub async fn start_tasks_impl(
                 state.pending_task_ids.extend(task_ids.iter().copied());
             }
 
+            // 记录 Agent 启动参数和已提交任务，供崩溃后自动重启续跑
+            if reset_state {
+                instance.agent_launch =
+                    (!restart_agent_configs.is_empty()).then(|| AgentLaunchContext {
+                        agent_configs: restart_agent_configs.clone(),
+                        cwd: cwd.clone(),
+                        tcp_compat_mode,
+                        pi_envs: pi_envs_map.clone(),
+                        tasks: Vec::new(),
+                        restart_count: 0,
+                    });
+            }
+            // 首批任务按循环配置设置循环上下文（追加批次不参与循环）
+            if reset_state {
+                let loop_tasks = submitted_tasks.iter().map(|(_, t)| t.clone()).collect();
+                task_loop::begin_loop(instance, queue_loop, loop_tasks);
+            }
+            // 记录各任务提交时的原始 override，用于查询与撤销运行中的修改
+            for (task_id, task) in &submitted_tasks {
+                let original = parse_pipeline_override(&task.pipeline_override).unwrap_or_default();
+                instance.pipeline_overrides.insert(
+                    *task_id,
+                    PipelineOverrideRecord {
+                        original,
+                        applied: Vec::new(),
+                    },
+                );
+            }
+            instance
+                .submitted_tasks
+                .extend(submitted_tasks.iter().cloned());
+            if let Some(launch) = instance.agent_launch.as_mut() {
+                launch.tasks.extend(submitted_tasks);
+            }
+
             let state = &mut instance.task_run_state;
             state.overall_status = Some("Running".to_string());
 
@@ -764,6 +953,10 @@ pub async fn start_tasks_impl(
     }
     debug!("[start_tasks] TaskRunState updated");
 
+    if agents_started {
+        spawn_agent_watchdog(app.clone(), Arc::clone(maa_state), instance_id.clone());
+    }
+
     info!(
         "[start_tasks] start_tasks_impl completed successfully, returning {} task_ids",
         task_ids.len()
@@ -787,6 +980,7 @@ pub async fn maa_start_tasks(
     tcp_compat_mode: bool,
     pi_envs: Option<HashMap<String, String>>,
     reset_state: Option<bool>,
+    loop_config: Option<TaskQueueLoop>,
 ) -> Result<Vec<i64>, String> {
     start_tasks_impl(
         app,
@@ -798,12 +992,294 @@ pub async fn maa_start_tasks(
         tcp_compat_mode,
         pi_envs,
         reset_state.unwrap_or(true),
+        loop_config,
     )
     .await
 }
 
+fn emit_agent_restart(app: &tauri::AppHandle, event: AgentRestartEvent) {
+    if let Err(e) = app.emit("maa-agent-restart", event) {
+        error!("Failed to emit maa-agent-restart: {}", e);
+    }
+}
+
+/// 监控本批 Agent 子进程，任务队列运行中出现异常退出时自动重启并续跑
+///
+/// 每次启动新一批 Agent 都会递增 `agent_generation`，旧的监控线程发现代次变化后自行退出。
+fn spawn_agent_watchdog(app: tauri::AppHandle, maa_state: Arc<MaaState>, instance_id: String) {
+    let generation = {
+        let Ok(mut instances) = maa_state.instances.lock() else {
+            return;
+        };
+        let Some(instance) = instances.get_mut(&instance_id) else {
+            return;
+        };
+        instance.agent_generation += 1;
+        instance.agent_generation
+    };
+
+    thread::spawn(move || loop {
+        thread::sleep(AGENT_WATCHDOG_INTERVAL);
+
+        let exit_code = {
+            let Ok(mut instances) = maa_state.instances.lock() else {
+                return;
+            };
+            let Some(instance) = instances.get_mut(&instance_id) else {
+                return;
+            };
+            if instance.agent_generation != generation || instance.agent_children.is_empty() {
+                return;
+            }
+
+            let exited = instance
+                .agent_children
+                .iter_mut()
+                .find_map(|child| child.try_wait().ok().flatten());
+            let Some(status) = exited else {
+                continue;
+            };
+
+            // 用户主动停止或队列已结束时 Agent 退出属于正常情况
+            let queue_running =
+                instance.task_run_state.overall_status.as_deref() == Some("Running");
+            if instance.stop_in_progress || !queue_running {
+                debug!(
+                    "[agent_watchdog] Agent exited ({}) outside of a running queue",
+                    status
+                );
+                return;
+            }
+
+            warn!(
+                "[agent_watchdog] Agent exited unexpectedly while tasks are running: {}",
+                status
+            );
+            status.code()
+        };
+
+        tauri::async_runtime::block_on(restart_agent_after_crash(
+            app,
+            maa_state,
+            instance_id,
+            exit_code,
+        ));
+        return;
+    });
+}
+
+/// 任务失败时若 Agent 已退出且仍可自动重启，将其记为被崩溃中断，返回是否已记录
+///
+/// 被中断任务的失败不计入状态，由 `restart_agent_after_crash` 重新执行
+pub fn mark_crash_interrupted(instance: &mut InstanceRuntime, task_id: i64) -> bool {
+    let can_restart = instance
+        .agent_launch
+        .as_ref()
+        .is_some_and(|launch| launch.restart_count < MAX_AGENT_RESTARTS);
+    if !can_restart || instance.stop_in_progress {
+        return false;
+    }
+    let exited = instance
+        .agent_children
+        .iter_mut()
+        .any(|child| matches!(child.try_wait(), Ok(Some(_))));
+    if exited {
+        instance.agent_interrupted_task_ids.insert(task_id);
+    }
+    exited
+}
+
+/// Agent 崩溃后重启 Agent、重新注册 MXU 动作、重连控制器，并从中断处续跑队列
+async fn restart_agent_after_crash(
+    app: tauri::AppHandle,
+    maa_state: Arc<MaaState>,
+    instance_id: String,
+    exit_code: Option<i32>,
+) {
+    let prepared = (|| -> Result<_, String> {
+        let mut instances = maa_state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances
+            .get_mut(&instance_id)
+            .ok_or("Instance not found")?;
+
+        // 重跑未结束（运行中或未开始）和被崩溃中断的任务；已成功或在 Agent 存活时失败的任务不再重跑
+        let ended: HashSet<i64> = instance
+            .task_report
+            .iter()
+            .filter(|r| r.status.is_some())
+            .map(|r| r.task_id)
+            .collect();
+        let interrupted = std::mem::take(&mut instance.agent_interrupted_task_ids);
+
+        let launch = instance
+            .agent_launch
+            .as_mut()
+            .ok_or("No agent launch context")?;
+        launch.restart_count += 1;
+        let rerun: Vec<(i64, TaskConfig)> = launch
+            .tasks
+            .iter()
+            .filter(|(id, _)| interrupted.contains(id) || !ended.contains(id))
+            .cloned()
+            .collect();
+        let launch = launch.clone();
+
+        // 移除旧 ID 的记录，续跑时以新 ID 重新加入，避免再次崩溃时重复提交
+        if launch.restart_count <= MAX_AGENT_RESTARTS {
+            preempt::remove_interrupted_tasks(instance, &rerun);
+        }
+        let remaining: Vec<TaskConfig> = rerun.into_iter().map(|(_, task)| task).collect();
+
+        instance.agent_restarting = true;
+        Ok((
+            launch,
+            remaining,
+            instance.resource.clone(),
+            instance.controller.clone(),
+            instance.tasker.clone(),
+        ))
+    })();
+
+    let (launch, remaining, resource, controller, tasker) = match prepared {
+        Ok(v) => v,
+        Err(e) => {
+            error!("[agent_restart] Cannot restart agent: {}", e);
+            return;
+        }
+    };
+
+    let event = |status: &str, error: Option<String>| AgentRestartEvent {
+        instance_id: instance_id.clone(),
+        status: status.to_string(),
+        attempt: launch.restart_count,
+        max_attempts: MAX_AGENT_RESTARTS,
+        exit_code,
+        remaining: remaining.len(),
+        error,
+    };
+    let finish_restart = || {
+        if let Ok(mut instances) = maa_state.instances.lock() {
+            if let Some(instance) = instances.get_mut(&instance_id) {
+                instance.agent_restarting = false;
+            }
+        }
+    };
+
+    if launch.restart_count > MAX_AGENT_RESTARTS {
+        error!(
+            "[agent_restart] Agent crashed {} times, giving up",
+            MAX_AGENT_RESTARTS
+        );
+        finish_restart();
+        emit_agent_restart(&app, event("gave_up", None));
+        return;
+    }
+
+    info!(
+        "[agent_restart] Restarting agent (attempt {}/{}), {} task(s) remaining",
+        launch.restart_count,
+        MAX_AGENT_RESTARTS,
+        remaining.len()
+    );
+    emit_agent_restart(&app, event("restarting", None));
+
+    // 停止当前队列（被中断任务的失败回调会被忽略）并清理旧 Agent
+    if let Some(tasker) = &tasker {
+        let _ = tasker.post_stop();
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while tasker.running() && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+    let stop_state = Arc::clone(&maa_state);
+    let stop_instance_id = instance_id.clone();
+    let stopped =
+        tokio::task::spawn_blocking(move || stop_agent_impl(&stop_state, &stop_instance_id))
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|r| r);
+    if let Err(e) = stopped {
+        warn!("[agent_restart] Failed to stop old agents: {}", e);
+    }
+
+    // 重新注册 MXU 内置动作
+    if let Some(resource) = &resource {
+        if let Err(e) = crate::mxu_actions::register_all_mxu_actions(resource, &app, &instance_id) {
+            warn!(
+                "[agent_restart] Failed to register MXU custom actions: {}",
+                e
+            );
+        }
+    }
+
+    // 控制器断连时重连
+    if let Some(controller) = &controller {
+        if !controller.connected() {
+            info!("[agent_restart] Reconnecting controller...");
+            if let Err(e) = controller.post_connection() {
+                warn!(
+                    "[agent_restart] Failed to post controller connection: {}",
+                    e
+                );
+            }
+            let deadline = Instant::now() + Duration::from_secs(30);
+            while !controller.connected() && Instant::now() < deadline {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+        }
+    }
+
+    // 等待期间用户可能已手动停止
+    let user_stopped = maa_state
+        .instances
+        .lock()
+        .ok()
+        .and_then(|instances| instances.get(&instance_id).map(|i| i.stop_in_progress))
+        .unwrap_or(true);
+    finish_restart();
+    if user_stopped {
+        info!("[agent_restart] Stop requested during restart, not resuming tasks");
+        return;
+    }
+    if remaining.is_empty() {
+        info!("[agent_restart] No remaining tasks to resume");
+        return;
+    }
+
+    let result = start_tasks_impl(
+        app.clone(),
+        &maa_state,
+        instance_id.clone(),
+        remaining.clone(),
+        Some(launch.agent_configs.clone()),
+        launch.cwd.clone(),
+        launch.tcp_compat_mode,
+        Some(launch.pi_envs.clone()),
+        false,
+        None,
+    )
+    .await;
+
+    match result {
+        Ok(_) => {
+            info!("[agent_restart] Agent restarted, queue resumed");
+            emit_agent_restart(&app, event("restarted", None));
+        }
+        Err(e) => {
+            error!("[agent_restart] Failed to resume tasks: {}", e);
+            emit_agent_restart(&app, event("failed", Some(e)));
+        }
+    }
+}
+
 /// 停止所有 Agent 的核心实现（Tauri invoke 和 HTTP handler 共享）
-pub fn stop_agent_impl(maa_state: &Arc<MaaState>, instance_id: &str) -> Result<(), String> {
+///
+/// 先断开连接让 Agent 自行退出，最多等待 `AGENT_STOP_TIMEOUT`，仍未退出的连同子进程强制结束，
+/// 保证在有限时间内返回。会阻塞调用线程，异步上下文中需放到 `spawn_blocking` 中调用
+pub fn stop_agent_impl(
+    maa_state: &Arc<MaaState>,
+    instance_id: &str,
+) -> Result<AgentStopOutcome, String> {
     info!("stop_agent_impl called for instance: {}", instance_id);
 
     let (clients, children) = {
@@ -818,58 +1294,78 @@ pub fn stop_agent_impl(maa_state: &Arc<MaaState>, instance_id: &str) -> Result<(
 
     if clients.is_empty() && children.is_empty() {
         debug!("[stop_agent] No agents to stop");
-        return Ok(());
+        return Ok(AgentStopOutcome::NotRunning);
     }
 
     info!(
-        "[stop_agent] Stopping {} agent client(s) and {} child process(es) in background...",
+        "[stop_agent] Stopping {} agent client(s) and {} child process(es)...",
         clients.len(),
         children.len()
     );
 
-    thread::spawn(move || {
-        for client in clients {
-            let _ = client.disconnect();
-        }
-
-        for (i, mut child) in children.into_iter().enumerate() {
-            debug!("Waiting for agent process #{} to exit...", i);
-
-            let start = std::time::Instant::now();
-            let timeout = std::time::Duration::from_secs(5);
-            let mut exited = false;
+    for client in clients {
+        let _ = client.disconnect();
+    }
 
-            while start.elapsed() < timeout {
-                match child.try_wait() {
-                    Ok(Some(_)) => {
-                        exited = true;
-                        break;
-                    }
-                    Ok(None) => {
-                        thread::sleep(std::time::Duration::from_millis(100));
-                    }
-                    Err(e) => {
-                        error!("Error waiting for agent #{}: {}", i, e);
-                        break;
-                    }
+    // 所有进程共用同一个截止时间
+    let deadline = Instant::now() + AGENT_STOP_TIMEOUT;
+    let mut killed = 0;
+    for (i, mut child) in children.into_iter().enumerate() {
+        debug!("Waiting for agent process #{} to exit...", i);
+
+        let exited = loop {
+            match child.try_wait() {
+                Ok(Some(_)) => break true,
+                Ok(None) if Instant::now() < deadline => {
+                    thread::sleep(Duration::from_millis(100));
+                }
+                Ok(None) => break false,
+                Err(e) => {
+                    error!("Error waiting for agent #{}: {}", i, e);
+                    break false;
                 }
             }
+        };
 
-            if !exited {
-                warn!("Agent process #{} did not exit in time, killing it...", i);
-                let _ = child.kill();
-                let _ = child.wait();
-            } else {
-                info!("Background: Agent #{} child process exited", i);
-            }
+        if exited {
+            info!("Agent #{} child process exited", i);
+        } else {
+            warn!(
+                "Agent process #{} did not exit in time, killing process tree...",
+                i
+            );
+            kill_process_tree(&mut child);
+            killed += 1;
         }
-    });
+    }
 
-    Ok(())
+    if killed == 0 {
+        return Ok(AgentStopOutcome::Graceful);
+    }
+
+    // 强杀后清理 Agent 相关记录：让旧的监控线程退出；非自动重启时丢弃重启上下文
+    if let Ok(mut instances) = maa_state.instances.lock() {
+        if let Some(instance) = instances.get_mut(instance_id) {
+            instance.agent_generation += 1;
+            if !instance.agent_restarting {
+                instance.agent_launch = None;
+            }
+        }
+    }
+    info!("[stop_agent] {} agent process(es) killed", killed);
+    Ok(AgentStopOutcome::Killed)
 }
 
 /// 停止所有 Agent 并断开连接 — Tauri invoke 入口，委托给 stop_agent_impl
+///
+/// 返回 Agent 是自行退出还是超时后被强制结束
 #[tauri::command]
-pub fn maa_stop_agent(state: State<'_, Arc<MaaState>>, instance_id: String) -> Result<(), String> {
-    stop_agent_impl(&state, &instance_id)
+pub async fn maa_stop_agent(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+) -> Result<AgentStopOutcome, String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || stop_agent_impl(&state, &instance_id))
+        .await
+        .map_err(|e| format!("停止 Agent 任务执行失败: {}", e))?
 }
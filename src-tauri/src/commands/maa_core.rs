@@ -3,8 +3,9 @@
 //! 提供 MaaFramework 初始化、版本检查、设备搜索、控制器、资源和任务管理
 
 use log::{debug, error, info, warn};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicI64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock, Mutex};
 use std::time::{Duration, Instant};
 
 use tauri::State;
@@ -15,11 +16,37 @@ use maa_framework::tasker::Tasker;
 use maa_framework::toolkit::Toolkit;
 use maa_framework::MaaStatus;
 
+use super::action_trace;
+use super::app_config::AppConfigState;
+use super::breakpoint::handle_breakpoint_callback;
+use super::capture_mode;
+use super::dry_run::{build_dry_run_override, is_dry_run};
+use super::error::{MxuError, MxuErrorCode, WithErrorCode};
+use super::event_log;
+use super::failure_screenshot;
+use super::frame_dedup;
+use super::hot_override;
+use super::last_frame;
+use super::node_timing;
+use super::preempt::{self, run_task_with_priority};
+use super::reco_stats;
+use super::screenshot_preprocess;
+use super::speed::{apply_speed, apply_speed_to_layers};
+use super::task_condition;
 use super::types::{
-    AdbDevice, ConnectionStatus, ControllerConfig, MaaState, TaskStatus, VersionCheckResult,
-    Win32Window,
+    AdbDevice, CachedImage, CachedImageFrame, ConnectionStatus, ControllerConfig, ControllerInfo,
+    EmulatorLaunchConfig, ImageRoi, InstanceStopOutcome, InstanceStopResult, MaaState,
+    PipelineHotUpdatedEvent, PipelineOverrideInfo, PipelineOverrideResult,
+    ResourceLoadProgressEvent, ResourceLoadRecord, ResourceLoadedInfo, RunTaskOutcome,
+    ScreencapTicket, TaskConfig, TaskStatus, VersionCheckResult, VersionInfo, Win32Window,
 };
-use super::utils::{emit_callback_event, get_maafw_dir, handle_task_callback, normalize_path};
+use super::unknown_screens;
+use super::usb_devices::{check_usb_device_ready, is_usb_serial, merge_usb_devices};
+use super::utils::{
+    emit_callback_event, encode_png, get_maafw_dir, handle_task_callback, instance_label,
+    merge_pipeline_override, normalize_path, parse_pipeline_override,
+};
+use super::window_state;
 
 /// MaaFramework 最小支持版本
 const MIN_MAAFW_VERSION: &str = "5.5.0-beta.1";
@@ -31,16 +58,66 @@ fn next_synthetic_conn_id() -> i64 {
     SYNTHETIC_CONN_ID.fetch_sub(1, Ordering::Relaxed)
 }
 
+/// 各控制器（按连接配置区分）的截图帧代数，控制器截图成功（含任务管线内部截图）时自增。
+/// 取缓存截图时据此判断帧是否变化，不必每次轮询都读取并 hash 整张 PNG
+static FRAME_GENERATIONS: LazyLock<Mutex<HashMap<ControllerConfig, u64>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 控制器回调中截图成功时递增该控制器的帧代数
+fn bump_frame_generation(config: &ControllerConfig, msg: &str, detail: &str) {
+    if msg != "Controller.Action.Succeeded" {
+        return;
+    }
+    let is_screencap = serde_json::from_str::<serde_json::Value>(detail)
+        .ok()
+        .and_then(|v| {
+            v.get("action")
+                .and_then(|a| a.as_str())
+                .map(|a| a.eq_ignore_ascii_case("screencap"))
+        })
+        .unwrap_or(false);
+    if is_screencap {
+        if let Ok(mut generations) = FRAME_GENERATIONS.lock() {
+            *generations.entry(config.clone()).or_insert(0) += 1;
+        }
+    }
+}
+
+/// 清理不再被任何实例使用的连接配置：移除 Pool 中的控制器及其帧代数
+fn release_controller_config(state: &MaaState, config: &ControllerConfig) {
+    if let Ok(mut pool) = state.controller_pool.lock() {
+        if pool.remove(config).is_some() {
+            info!("ControllerPool: removed unused entry for {:?}", config);
+        }
+    }
+    if let Ok(mut generations) = FRAME_GENERATIONS.lock() {
+        generations.remove(config);
+    }
+}
+
+fn frame_generation(config: Option<&ControllerConfig>) -> u64 {
+    config
+        .and_then(|config| {
+            FRAME_GENERATIONS
+                .lock()
+                .ok()
+                .and_then(|generations| generations.get(config).copied())
+        })
+        .unwrap_or(0)
+}
+
 /// 更新实例的 Controller 并清理不再使用的旧 Pool 条目
 fn update_instance_controller(
     state: &super::types::MaaState,
     instance_id: &str,
     controller: maa_framework::controller::Controller,
     new_config: super::types::ControllerConfig,
-) -> Result<(), String> {
+) -> Result<(), MxuError> {
     let cleanup_config = {
-        let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-        let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+        let mut instances = state.instances.lock().with_code(MxuErrorCode::Internal)?;
+        let instance = instances
+            .get_mut(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
 
         let old_config = instance.controller_config.clone();
         instance.controller = Some(controller);
@@ -56,10 +133,7 @@ fn update_instance_controller(
     };
 
     if let Some(old_cfg) = cleanup_config {
-        if let Ok(mut pool) = state.controller_pool.lock() {
-            pool.remove(&old_cfg);
-            info!("ControllerPool: removed unused entry for old config");
-        }
+        release_controller_config(state, &old_cfg);
     }
 
     Ok(())
@@ -71,10 +145,30 @@ fn update_instance_controller(
 
 /// 初始化 MaaFramework
 /// 如果提供 lib_dir 则使用该路径，否则自动从 exe 目录/maafw 加载
+///
+/// 幂等：已初始化时直接返回当前版本，不会重复加载库和初始化 Toolkit；
+/// 并发调用会等待前一次初始化完成。需要重新初始化时先调用 maa_reset
 #[tauri::command]
 pub fn maa_init(state: State<Arc<MaaState>>, lib_dir: Option<String>) -> Result<String, String> {
     info!("maa_init called, lib_dir: {:?}", lib_dir);
 
+    // 开机自启动延迟加载 MaaFramework 时，等延迟加载线程结束后再初始化，避免并发加载库
+    super::system::wait_deferred_maafw_load();
+
+    // 持有初始化锁直到完成，避免并发初始化
+    let mut initialized = state.initialized.lock().map_err(|e| e.to_string())?;
+    if *initialized {
+        let version = maa_framework::maa_version().to_string();
+        info!("maa_init already initialized, version: {}", version);
+        return Ok(version);
+    }
+
+    // CPU 不支持所需指令集时不加载，避免运行中崩溃
+    if let Some(err) = super::system::cpu_unsupported_error() {
+        error!("{}", err);
+        return Err(err);
+    }
+
     let lib_path = match lib_dir {
         Some(dir) if !dir.is_empty() => std::path::PathBuf::from(&dir),
         _ => get_maafw_dir()?,
@@ -152,36 +246,200 @@ pub fn maa_init(state: State<Arc<MaaState>>, lib_dir: Option<String>) -> Result<
         warn!("Failed to init toolkit option: {}", e);
     }
 
+    // 同步用户设置的日志级别
+    super::system::sync_maafw_log_level();
+
     let version = maa_framework::maa_version().to_string();
     info!("maa_init success, version: {}", version);
 
+    *initialized = true;
     Ok(version)
 }
 
+/// 清除各模块按实例保存的设置与运行记录（销毁实例与重置运行时状态共用）
+///
+/// 新增按实例保存状态的模块时需在此登记，否则实例销毁后状态会残留；需在释放实例锁后调用
+fn clear_instance_state(state: &MaaState, instance_id: &str) {
+    if let Ok(mut log_buffer) = state.log_buffer.lock() {
+        log_buffer.clear_instance(instance_id);
+    }
+    super::instance_log::close(instance_id);
+    super::recording::discard_recording(state, instance_id);
+    super::watchdog::clear_watchdog(instance_id);
+    super::breakpoint::clear_breakpoints(instance_id);
+    super::last_frame::clear_last_frame(instance_id);
+    super::dry_run::clear_instance(instance_id);
+    super::speed::clear_instance(instance_id);
+    super::capture_mode::clear_instance(instance_id);
+    super::window_state::clear_instance(instance_id);
+    super::screenshot_preprocess::clear_instance(instance_id);
+    super::frame_dedup::clear_instance(instance_id);
+    super::unknown_screens::clear_instance(instance_id);
+    super::task_condition::clear_instance(instance_id);
+    super::action_trace::clear_instance(instance_id);
+    super::annotated_screenshot::clear_instance(instance_id);
+    super::node_timing::clear_instance(instance_id);
+    super::reco_stats::clear_instance(instance_id);
+    crate::mxu_actions::clear_task_vars(instance_id);
+}
+
+/// 重置全部运行时状态的内部实现
+///
+/// 销毁所有实例（含控制器、资源、Tasker 和 Agent），清空控制器连接池、设备缓存，
+/// 通过 [`clear_instance_state`] 清除各实例的运行日志与各模块按实例保存的设置和记录，并清除初始化标志。
+/// 已加载的 MaaFramework 库无法卸载，lib_dir 与资源目录保持不变
+pub fn reset_impl(state: &MaaState) -> Result<(), String> {
+    info!("reset_impl called");
+
+    let mut initialized = state.initialized.lock().map_err(|e| e.to_string())?;
+
+    let removed = {
+        let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
+        if instances
+            .values()
+            .any(|inst| inst.tasker.as_ref().is_some_and(|t| t.running()))
+        {
+            return Err("有任务正在运行，请先停止所有任务后再重置".to_string());
+        }
+        std::mem::take(&mut *instances)
+    };
+
+    let instance_ids: Vec<String> = removed.keys().cloned().collect();
+    // 在 instances 锁外销毁实例，断开 Agent 等操作不阻塞其他命令
+    drop(removed);
+    for instance_id in &instance_ids {
+        clear_instance_state(state, instance_id);
+    }
+
+    state
+        .controller_pool
+        .lock()
+        .map_err(|e| e.to_string())?
+        .clear();
+    state
+        .pre_action_stop_requests
+        .lock()
+        .map_err(|e| e.to_string())?
+        .clear();
+    state
+        .cached_adb_devices
+        .lock()
+        .map_err(|e| e.to_string())?
+        .clear();
+    state
+        .cached_win32_windows
+        .lock()
+        .map_err(|e| e.to_string())?
+        .clear();
+    state
+        .cached_wlroots_sockets
+        .lock()
+        .map_err(|e| e.to_string())?
+        .clear();
+    if let Ok(mut generations) = FRAME_GENERATIONS.lock() {
+        generations.clear();
+    }
+
+    *initialized = false;
+    info!(
+        "reset_impl success, {} instance(s) destroyed",
+        instance_ids.len()
+    );
+    Ok(())
+}
+
+/// 重置全部运行时状态（"恢复出厂"），之后需重新调用 maa_init
+///
+/// 有任务运行时拒绝重置
+#[tauri::command]
+pub fn maa_reset(state: State<Arc<MaaState>>) -> Result<(), String> {
+    info!("maa_reset called");
+    reset_impl(&state)
+}
+
 /// 设置资源目录
+///
+/// 传入 instance_id 时仅设置该实例的资源目录，各实例互不影响；否则设置全局默认目录。
+/// 加载资源时相对路径优先基于实例资源目录解析
 #[tauri::command]
 pub fn maa_set_resource_dir(
     state: State<Arc<MaaState>>,
     resource_dir: String,
+    instance_id: Option<String>,
 ) -> Result<(), String> {
     info!(
-        "maa_set_resource_dir called, resource_dir: {}",
-        resource_dir
+        "maa_set_resource_dir called, resource_dir: {}, instance: {:?}",
+        resource_dir, instance_id
     );
-    *state.resource_dir.lock().map_err(|e| e.to_string())? =
-        Some(std::path::PathBuf::from(&resource_dir));
+    let dir = std::path::PathBuf::from(&resource_dir);
+    match instance_id {
+        Some(instance_id) => {
+            let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
+            let instance = instances
+                .get_mut(&instance_id)
+                .ok_or("Instance not found")?;
+            instance.resource_dir = Some(dir);
+        }
+        None => {
+            *state.resource_dir.lock().map_err(|e| e.to_string())? = Some(dir);
+        }
+    }
     info!("maa_set_resource_dir success");
     Ok(())
 }
 
-/// 获取 MaaFramework 版本
+/// 版本信息中无法获取的字段
+const UNKNOWN_VERSION: &str = "unknown";
+
+/// 获取各组件版本：MXU、MaaFramework、Agent 客户端、编译提交和编译时间
+///
+/// 库未加载等原因拿不到的字段返回 "unknown"，不报错
 #[tauri::command]
-pub fn maa_get_version() -> Result<String, String> {
+pub fn maa_get_version(state: State<Arc<MaaState>>) -> VersionInfo {
     debug!("maa_get_version called");
-    let version = std::panic::catch_unwind(|| maa_framework::maa_version().to_string())
-        .map_err(|_| "MaaFramework library not loaded".to_string())?;
-    info!("maa_get_version result: {}", version);
-    Ok(version)
+    let info = version_info_impl(&state);
+    info!("maa_get_version result: {:?}", info);
+    info
+}
+
+/// 收集 MXU / MaaFramework / Agent 版本与构建信息
+pub fn version_info_impl(state: &MaaState) -> VersionInfo {
+    let framework = std::panic::catch_unwind(|| maa_framework::maa_version().to_string())
+        .ok()
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| UNKNOWN_VERSION.to_string());
+
+    // MaaAgentClient 不单独导出版本，与 MaaFramework 同包分发，库存在时即为同一版本
+    let lib_dir = state.lib_dir.lock().ok().and_then(|dir| dir.clone());
+    let agent_lib = if cfg!(windows) {
+        "MaaAgentClient.dll"
+    } else if cfg!(target_os = "macos") {
+        "libMaaAgentClient.dylib"
+    } else {
+        "libMaaAgentClient.so"
+    };
+    let agent = match lib_dir {
+        Some(dir) if framework != UNKNOWN_VERSION && dir.join(agent_lib).exists() => {
+            framework.clone()
+        }
+        _ => UNKNOWN_VERSION.to_string(),
+    };
+
+    let build_time = option_env!("MXU_BUILD_TIMESTAMP")
+        .and_then(|ts| ts.parse::<i64>().ok())
+        .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_else(|| UNKNOWN_VERSION.to_string());
+
+    VersionInfo {
+        mxu: env!("CARGO_PKG_VERSION").to_string(),
+        framework,
+        agent,
+        commit: option_env!("MXU_GIT_COMMIT")
+            .unwrap_or(UNKNOWN_VERSION)
+            .to_string(),
+        build_time,
+    }
 }
 
 /// 检查 MaaFramework 版本是否满足最小要求
@@ -239,13 +497,59 @@ pub fn maa_check_version(state: State<Arc<MaaState>>) -> Result<VersionCheckResu
 // 设备搜索命令
 // ============================================================================
 
+/// 校验用户指定的 adb 可执行文件路径，只给了程序名（如 `adb`，从 PATH 查找）时不校验
+fn validate_adb_path(adb_path: &str) -> Result<(), String> {
+    let path = std::path::Path::new(adb_path);
+    if path.parent().is_some_and(|p| !p.as_os_str().is_empty()) && !path.is_file() {
+        return Err(format!("ADB 可执行文件不存在: {}", adb_path));
+    }
+    Ok(())
+}
+
 /// 查找 ADB 设备（结果会缓存到 MaaState）
 /// 查找 ADB 设备的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
-pub async fn find_adb_devices_impl(state: Arc<MaaState>) -> Result<Vec<AdbDevice>, String> {
+///
+/// 指定 adb_path 时只用该 adb 搜索（模拟器自带 adb 与系统 adb 版本冲突时使用），否则由框架自行查找，
+/// 并额外使用 Toolkit 配置中的 adb 搜索目录 / 模拟器安装目录
+pub async fn find_adb_devices_impl(
+    state: Arc<MaaState>,
+    adb_path: Option<String>,
+) -> Result<Vec<AdbDevice>, String> {
+    let adb_path = adb_path
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty());
+    if let Some(adb_path) = &adb_path {
+        validate_adb_path(adb_path)?;
+    }
+
     tokio::task::spawn_blocking(move || {
-        let devices = Toolkit::find_adb_devices().map_err(|e| e.to_string())?;
+        let mut devices = match &adb_path {
+            Some(adb_path) => Toolkit::find_adb_devices_with_adb(adb_path),
+            None => Toolkit::find_adb_devices(),
+        }
+        .map_err(|e| e.to_string())?;
+
+        // 未指定 adb 时，再用用户配置的额外 adb 各搜索一次，补充框架默认搜不到的模拟器
+        if adb_path.is_none() {
+            for extra_adb in super::toolkit_config::extra_adb_paths() {
+                let extra_adb = extra_adb.to_string_lossy().to_string();
+                match Toolkit::find_adb_devices_with_adb(&extra_adb) {
+                    Ok(extra) => {
+                        for device in extra {
+                            if !devices.iter().any(|d| d.address == device.address) {
+                                devices.push(device);
+                            }
+                        }
+                    }
+                    Err(e) => warn!(
+                        "find_adb_devices_impl: search with {} failed: {}",
+                        extra_adb, e
+                    ),
+                }
+            }
+        }
 
-        let result_devices: Vec<AdbDevice> = devices
+        let mut result_devices: Vec<AdbDevice> = devices
             .into_iter()
             .map(|d| AdbDevice {
                 name: d.name,
@@ -254,8 +558,11 @@ pub async fn find_adb_devices_impl(state: Arc<MaaState>) -> Result<Vec<AdbDevice
                 screencap_methods: d.screencap_methods,
                 input_methods: d.input_methods,
                 config: d.config.to_string(),
+                physical: false,
+                auth_state: None,
             })
             .collect();
+        merge_usb_devices(&mut result_devices);
 
         if let Ok(mut cached) = state.cached_adb_devices.lock() {
             *cached = result_devices.clone();
@@ -271,9 +578,188 @@ pub async fn find_adb_devices_impl(state: Arc<MaaState>) -> Result<Vec<AdbDevice
 #[tauri::command]
 pub async fn maa_find_adb_devices(
     state: State<'_, Arc<MaaState>>,
+    adb_path: Option<String>,
+) -> Result<Vec<AdbDevice>, String> {
+    info!("maa_find_adb_devices called, adb_path: {:?}", adb_path);
+    find_adb_devices_impl(state.inner().clone(), adb_path).await
+}
+
+/// 常见模拟器 ADB 端口（通用 / MuMu / MuMu 12 / 夜神 / 逍遥 / 雷电 / BlueStacks）
+const COMMON_ADB_PORTS: &[u16] = &[
+    5555, 5557, 5565, 7555, 16384, 16416, 16448, 62001, 62025, 21503, 21513,
+];
+
+/// 单个端口的 TCP 探测超时
+const ADB_PROBE_TCP_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// 单次 `adb connect` 的超时
+const ADB_PROBE_CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 判断 ADB 地址是否只给了 host（IP 或 localhost）而没有端口
+/// `emulator-5554` 等序列号不视为 host
+fn adb_host_without_port(address: &str) -> Option<&str> {
+    let address = address.trim();
+    if address.eq_ignore_ascii_case("localhost") || address.parse::<std::net::IpAddr>().is_ok() {
+        Some(address)
+    } else {
+        None
+    }
+}
+
+/// 将 `localhost:<port>` 统一为 `127.0.0.1:<port>`，用于地址去重
+fn normalize_adb_address(address: &str) -> String {
+    match address.split_once(':') {
+        Some((host, port)) if host.eq_ignore_ascii_case("localhost") => {
+            format!("127.0.0.1:{}", port)
+        }
+        _ => address.to_string(),
+    }
+}
+
+/// 执行 `adb connect <address>`，超时则结束进程并视为失败
+fn adb_connect_with_timeout(adb_path: &str, address: &str) -> bool {
+    let mut cmd = std::process::Command::new(adb_path);
+    cmd.args(["connect", address])
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = match cmd.spawn() {
+        Ok(c) => c,
+        Err(e) => {
+            warn!("Failed to run adb connect {}: {}", address, e);
+            return false;
+        }
+    };
+
+    let deadline = Instant::now() + ADB_PROBE_CONNECT_TIMEOUT;
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if Instant::now() < deadline => {
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                debug!("adb connect {} timed out", address);
+                return false;
+            }
+        }
+    }
+
+    let mut output = String::new();
+    if let Some(mut stdout) = child.stdout.take() {
+        use std::io::Read;
+        let _ = stdout.read_to_string(&mut output);
+    }
+    // "connected to x" / "already connected to x"，失败时为 "cannot connect to x" / "failed to connect to x"
+    let output = output.to_lowercase();
+    output.contains("connected to") && !output.contains("cannot") && !output.contains("failed")
+}
+
+/// 并行扫描 host 上的常见模拟器端口并尝试 `adb connect`，返回连接成功的地址
+pub fn probe_adb_ports(adb_path: &str, host: &str) -> Vec<String> {
+    let handles: Vec<_> = COMMON_ADB_PORTS
+        .iter()
+        .map(|&port| {
+            let adb_path = adb_path.to_string();
+            let host = host.to_string();
+            std::thread::spawn(move || {
+                let address = format!("{}:{}", host, port);
+                let sock_addr = std::net::ToSocketAddrs::to_socket_addrs(&(host.as_str(), port))
+                    .ok()
+                    .and_then(|mut addrs| addrs.next())?;
+                std::net::TcpStream::connect_timeout(&sock_addr, ADB_PROBE_TCP_TIMEOUT).ok()?;
+                adb_connect_with_timeout(&adb_path, &address).then_some(address)
+            })
+        })
+        .collect();
+
+    let found: Vec<String> = handles
+        .into_iter()
+        .filter_map(|h| h.join().ok().flatten())
+        .collect();
+    info!(
+        "probe_adb_ports: {} device(s) on {}: {:?}",
+        found.len(),
+        host,
+        found
+    );
+    found
+}
+
+/// 探测 ADB 端口的内部实现：扫描常见端口并返回可用设备（结果会并入设备缓存）
+pub async fn probe_adb_ports_impl(
+    state: Arc<MaaState>,
+    adb_path: String,
+    host: String,
+) -> Result<Vec<AdbDevice>, String> {
+    let host = adb_host_without_port(&host).ok_or_else(|| format!("无效的主机地址: {}", host))?;
+    // adb devices 中 localhost 显示为 127.0.0.1，统一后才能与搜索结果和设备缓存去重
+    let host = if host.eq_ignore_ascii_case("localhost") {
+        "127.0.0.1".to_string()
+    } else {
+        host.to_string()
+    };
+
+    tokio::task::spawn_blocking(move || {
+        let addresses = probe_adb_ports(&adb_path, &host);
+        if addresses.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // adb connect 成功后重新搜索，以获得各设备的截图/输入方式等完整信息
+        let devices: Vec<AdbDevice> = Toolkit::find_adb_devices()
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .filter(|d| addresses.contains(&normalize_adb_address(&d.address)))
+            .map(|d| AdbDevice {
+                name: d.name,
+                adb_path: d.adb_path.to_string_lossy().to_string(),
+                address: d.address,
+                screencap_methods: d.screencap_methods,
+                input_methods: d.input_methods,
+                config: d.config.to_string(),
+                physical: false,
+                auth_state: None,
+            })
+            .collect();
+
+        if let Ok(mut cached) = state.cached_adb_devices.lock() {
+            for device in &devices {
+                let address = normalize_adb_address(&device.address);
+                if !cached
+                    .iter()
+                    .any(|c| normalize_adb_address(&c.address) == address)
+                {
+                    cached.push(device.clone());
+                }
+            }
+        }
+
+        Ok(devices)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 扫描指定主机上常见模拟器 ADB 端口，返回能连上的设备（可能有多个，供用户选择）
+#[tauri::command]
+pub async fn maa_probe_adb_ports(
+    state: State<'_, Arc<MaaState>>,
+    adb_path: String,
+    host: String,
 ) -> Result<Vec<AdbDevice>, String> {
-    info!("maa_find_adb_devices called");
-    find_adb_devices_impl(state.inner().clone()).await
+    info!("maa_probe_adb_ports called, host: {}", host);
+    probe_adb_ports_impl(state.inner().clone(), adb_path, host).await
 }
 
 /// 查找 Win32 窗口的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
@@ -304,10 +790,12 @@ pub async fn find_win32_windows_impl(
                 }
             }
 
+            let handle = w.hwnd as u64;
             result_windows.push(Win32Window {
-                handle: w.hwnd as u64,
+                handle,
                 class_name: w.class_name,
                 window_name: w.window_name,
+                icon: crate::window_icon::window_icon(handle),
             });
         }
 
@@ -382,67 +870,127 @@ pub async fn maa_find_wlroots_sockets(
 // 实例管理命令
 // ============================================================================
 
-/// 创建实例（幂等操作，实例已存在时直接返回成功）
-#[tauri::command]
-pub fn maa_create_instance(state: State<Arc<MaaState>>, instance_id: String) -> Result<(), String> {
-    info!("maa_create_instance called, instance_id: {}", instance_id);
-
+/// 创建实例的内部实现（幂等操作，实例已存在时直接返回成功，传入非空名称时更新名称）
+///
+/// 返回实例的显示名（未设置名称时为实例 ID）
+pub fn create_instance_impl(
+    state: &MaaState,
+    instance_id: &str,
+    name: Option<String>,
+) -> Result<String, String> {
+    let name = name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
     let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
 
-    if instances.contains_key(&instance_id) {
-        debug!("maa_create_instance: instance already exists, returning success");
-        return Ok(());
+    if let Some(instance) = instances.get_mut(instance_id) {
+        debug!("create_instance_impl: instance already exists, returning success");
+        let renamed = name.is_some() && instance.name != name;
+        if renamed {
+            instance.name = name;
+        }
+        drop(instances);
+        if renamed {
+            crate::tray::refresh_tray_menu();
+        }
+        return Ok(instance_label(state, instance_id));
     }
 
     instances.insert(
-        instance_id.clone(),
-        super::types::InstanceRuntime::default(),
+        instance_id.to_string(),
+        super::types::InstanceRuntime::new(name),
     );
-    info!("maa_create_instance success, instance_id: {}", instance_id);
-    Ok(())
+    drop(instances);
+    crate::tray::refresh_tray_menu();
+    info!("create_instance_impl success, instance_id: {}", instance_id);
+    Ok(instance_label(state, instance_id))
+}
+
+/// 创建实例，返回实例的显示名（未设置名称时为实例 ID）
+#[tauri::command]
+pub fn maa_create_instance(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    name: Option<String>,
+) -> Result<String, String> {
+    info!("maa_create_instance called, instance_id: {}", instance_id);
+    create_instance_impl(&state, &instance_id, name)
+}
+
+/// 设置实例显示名的内部实现，传空字符串清除；名称允许重复（由前端提示）
+///
+/// 返回实例的显示名（清除后为实例 ID）
+pub fn set_instance_name_impl(
+    state: &MaaState,
+    instance_id: &str,
+    name: &str,
+) -> Result<String, String> {
+    info!("set_instance_name: {} -> {:?}", instance_id, name);
+    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
+    let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+    let name = name.trim();
+    instance.name = (!name.is_empty()).then(|| name.to_string());
+    drop(instances);
+    crate::tray::refresh_tray_menu();
+    Ok(instance_label(state, instance_id))
+}
+
+/// 设置实例显示名（用于托盘菜单、日志前缀和实例列表），返回新的显示名
+#[tauri::command]
+pub fn maa_set_instance_name(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    name: String,
+) -> Result<String, String> {
+    set_instance_name_impl(&state, &instance_id, &name)
 }
 
 /// 销毁实例的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
 pub fn destroy_instance_impl(state: &Arc<MaaState>, instance_id: &str) -> Result<(), String> {
     info!("destroy_instance_impl called, instance_id: {}", instance_id);
 
-    let cleanup_config = {
-        let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-        let old_config = instances
-            .get(instance_id)
-            .and_then(|inst| inst.controller_config.clone());
-        let removed = instances.remove(instance_id).is_some();
+    // 先标记停止并放行停在断点上的任务：任务线程会轮询实例锁，
+    // 若在持有锁时析构 Tasker 会等待任务线程结束而死锁
+    if let Ok(mut instances) = state.instances.lock() {
+        if let Some(instance) = instances.get_mut(instance_id) {
+            instance.stop_in_progress = true;
+        }
+    }
+    super::watchdog::clear_watchdog(instance_id);
+    super::breakpoint::clear_breakpoints(instance_id);
 
-        if removed {
-            info!(
-                "destroy_instance_impl success, instance_id: {}",
-                instance_id
-            );
-            old_config.filter(|cfg| {
-                !instances
-                    .values()
-                    .any(|inst| inst.controller_config.as_ref() == Some(cfg))
-            })
-        } else {
-            warn!(
-                "destroy_instance_impl: instance not found, instance_id: {}",
-                instance_id
-            );
-            None
+    let (removed, cleanup_config) = {
+        let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
+        match instances.remove(instance_id) {
+            Some(removed) => {
+                info!(
+                    "destroy_instance_impl success, instance_id: {}",
+                    instance_id
+                );
+                let cleanup_config = removed.controller_config.clone().filter(|cfg| {
+                    !instances
+                        .values()
+                        .any(|inst| inst.controller_config.as_ref() == Some(cfg))
+                });
+                (Some(removed), cleanup_config)
+            }
+            None => {
+                warn!(
+                    "destroy_instance_impl: instance not found, instance_id: {}",
+                    instance_id
+                );
+                (None, None)
+            }
         }
     };
+    // 释放实例锁后再析构实例（Tasker 析构会等待任务线程退出）
+    drop(removed);
 
-    // ControllerPool: 清理不再被任何实例使用的条目
+    // 清理不再被任何实例使用的 Pool 条目与帧代数
     if let Some(cfg) = cleanup_config {
-        if let Ok(mut pool) = state.controller_pool.lock() {
-            pool.remove(&cfg);
-            info!("ControllerPool: cleaned up entry after instance destroy");
-        }
+        release_controller_config(state, &cfg);
     }
 
-    if let Ok(mut log_buffer) = state.log_buffer.lock() {
-        log_buffer.clear_instance(instance_id);
-    }
+    clear_instance_state(state, instance_id);
+    crate::tray::refresh_tray_menu();
 
     Ok(())
 }
@@ -467,20 +1015,75 @@ pub fn maa_destroy_instance(
 pub async fn connect_controller_impl(
     state_arc: Arc<MaaState>,
     instance_id: String,
-    config: ControllerConfig,
+    mut config: ControllerConfig,
     on_event: Arc<dyn Fn(&str, &str) + Send + Sync + 'static>,
-) -> Result<i64, String> {
+) -> Result<i64, MxuError> {
     tokio::task::spawn_blocking(move || {
+        // ADB 只给了 host 没给端口时，自动扫描常见模拟器端口
+        if let ControllerConfig::Adb {
+            adb_path, address, ..
+        } = &mut config
+        {
+            validate_adb_path(adb_path).with_code(MxuErrorCode::InvalidArgument)?;
+            if is_usb_serial(address) {
+                check_usb_device_ready(adb_path, address)
+                    .with_code(MxuErrorCode::ControllerConnectFailed)?;
+            } else if let Some(host) = adb_host_without_port(address) {
+                let found = probe_adb_ports(adb_path, host);
+                match found.as_slice() {
+                    [] => {
+                        return Err(MxuError::new(
+                            MxuErrorCode::ControllerConnectFailed,
+                            format!("未在 {} 的常见模拟器端口上发现 ADB 设备", host),
+                        ));
+                    }
+                    [only] => {
+                        info!("ADB port auto-detected: {}", only);
+                        *address = only.clone();
+                    }
+                    _ => {
+                        return Err(MxuError::new(
+                            MxuErrorCode::ControllerConnectFailed,
+                            format!(
+                                "在 {} 上发现多个 ADB 设备，请选择其中一个: {}",
+                                host,
+                                found.join(", ")
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Win32 截图防遮挡：PrintWindow 模式下按试截结果决定实际截图方式
+        if let ControllerConfig::Win32 {
+            handle,
+            screencap_method,
+            ..
+        } = &mut config
+        {
+            *screencap_method =
+                capture_mode::win32_screencap_method(&instance_id, *handle, *screencap_method);
+        }
+
+        // 开启截图预处理时控制器需单独包装，不复用也不放入 ControllerPool
+        let preprocess = screenshot_preprocess::is_enabled(&instance_id);
+
         // ControllerPool: 检查是否有可复用的已连接控制器
-        let pooled = {
+        let pooled = if preprocess {
+            None
+        } else {
             let pool = state_arc
                 .controller_pool
                 .lock()
-                .map_err(|e| e.to_string())?;
+                .with_code(MxuErrorCode::Internal)?;
             pool.get(&config).filter(|c| c.connected()).cloned()
         };
 
         if let Some(pooled_ctrl) = pooled {
+            let pooled_ctrl =
+                screenshot_preprocess::wrap_controller(&instance_id, pooled_ctrl, false)
+                    .with_code(MxuErrorCode::ControllerConnectFailed)?;
             info!(
                 "ControllerPool hit: reusing connected controller for {:?}",
                 config
@@ -503,7 +1106,7 @@ pub async fn connect_controller_impl(
             let mut pool = state_arc
                 .controller_pool
                 .lock()
-                .map_err(|e| e.to_string())?;
+                .with_code(MxuErrorCode::Internal)?;
             pool.remove(&config);
         }
 
@@ -521,12 +1124,16 @@ pub async fn connect_controller_impl(
                 config,
                 ..
             } => {
-                let screencap = screencap_methods.parse::<u64>().map_err(|e| {
-                    format!("Invalid screencap_methods '{}': {}", screencap_methods, e)
-                })?;
+                let screencap = screencap_methods
+                    .parse::<u64>()
+                    .map_err(|e| {
+                        format!("Invalid screencap_methods '{}': {}", screencap_methods, e)
+                    })
+                    .with_code(MxuErrorCode::InvalidArgument)?;
                 let input = input_methods
                     .parse::<u64>()
-                    .map_err(|e| format!("Invalid input_methods '{}': {}", input_methods, e))?;
+                    .map_err(|e| format!("Invalid input_methods '{}': {}", input_methods, e))
+                    .with_code(MxuErrorCode::InvalidArgument)?;
                 let agent_path = get_maafw_dir()
                     .map(|p| p.join("MaaAgentBinary").to_string_lossy().to_string())
                     .unwrap_or_else(|_| "./MaaAgentBinary".to_string());
@@ -542,7 +1149,7 @@ pub async fn connect_controller_impl(
                     .config(config)
                     .agent_path(&agent_path)
                     .build()
-                    .map_err(|e| e.to_string())?
+                    .with_code(MxuErrorCode::ControllerConnectFailed)?
             }
             ControllerConfig::Win32 {
                 handle,
@@ -563,24 +1170,25 @@ pub async fn connect_controller_impl(
                     maa_framework::common::Win32InputMethod::from_bits_truncate(*keyboard_method)
                         .bits(),
                 )
-                .map_err(|e| e.to_string())?
+                .with_code(MxuErrorCode::ControllerConnectFailed)?
             }
             ControllerConfig::WlRoots {
                 wlr_socket_path,
                 use_win32_vk_code,
                 ..
             } => Controller::new_wlroots_with_vk_code(wlr_socket_path, *use_win32_vk_code)
-                .map_err(|e| e.to_string())?,
+                .with_code(MxuErrorCode::ControllerConnectFailed)?,
             ControllerConfig::PlayCover { address, uuid, .. } => {
                 let uuid_str = uuid.as_deref().unwrap_or("");
-                Controller::new_playcover(address, uuid_str).map_err(|e| e.to_string())?
+                Controller::new_playcover(address, uuid_str)
+                    .with_code(MxuErrorCode::ControllerConnectFailed)?
             }
             ControllerConfig::Dummy {
                 display_short_side, ..
             } => {
                 let short = display_short_side.unwrap_or(720);
                 Controller::new_custom(crate::dummy_controller::DummyController::new(short))
-                    .map_err(|e| e.to_string())?
+                    .with_code(MxuErrorCode::ControllerConnectFailed)?
             }
             ControllerConfig::Gamepad {
                 handle,
@@ -599,18 +1207,11 @@ pub async fn connect_controller_impl(
                     .map(|v| maa_framework::common::Win32ScreencapMethod::from_bits_truncate(v))
                     .unwrap_or(maa_framework::common::Win32ScreencapMethod::DXGI_DESKTOP_DUP);
 
-                Controller::new_gamepad(hwnd, gp_type, screencap).map_err(|e| e.to_string())?
+                Controller::new_gamepad(hwnd, gp_type, screencap)
+                    .with_code(MxuErrorCode::ControllerConnectFailed)?
             }
         };
 
-        // 注册回调（使用 on_event 抽象，Tauri 命令传入 emit_callback_event，HTTP 处理器传入无操作或 WebSocket 推送）
-        let on_event_clone = on_event.clone();
-        controller
-            .add_sink(move |msg, detail| {
-                on_event_clone(msg, detail);
-            })
-            .map_err(|e| e.to_string())?;
-
         let display_short_side = match &config {
             ControllerConfig::Adb {
                 display_short_side, ..
@@ -632,22 +1233,58 @@ pub async fn connect_controller_impl(
             } => display_short_side.unwrap_or(720),
         };
 
-        if let Err(e) = controller.set_screenshot_target_short_side(display_short_side) {
-            warn!(
-                "Failed to set screenshot target short side to {}: {}",
-                display_short_side, e
-            );
+        // 包装前先设置实际控制器的截图尺寸，包装后外层控制器再设置一次
+        if preprocess {
+            if let Err(e) = controller.set_screenshot_target_short_side(display_short_side) {
+                warn!(
+                    "Failed to set screenshot target short side to {}: {}",
+                    display_short_side, e
+                );
+            }
         }
+        let controller =
+            screenshot_preprocess::wrap_controller(&instance_id, controller, preprocess)
+                .with_code(MxuErrorCode::ControllerConnectFailed)?;
 
-        // 发起连接
-        let conn_id = controller.post_connection().map_err(|e| e.to_string())?;
-
-        // 存入 ControllerPool
-        {
+        // 注册回调（使用 on_event 抽象，Tauri 命令传入 emit_callback_event，HTTP 处理器传入无操作或 WebSocket 推送）
+        let on_event_clone = on_event.clone();
+        let instance_id_for_sink = instance_id.clone();
+        let state_for_sink = Arc::clone(&state_arc);
+        let config_for_sink = config.clone();
+        controller
+            .add_sink(move |msg, detail| {
+                bump_frame_generation(&config_for_sink, msg, detail);
+                event_log::record_callback(&instance_id_for_sink, msg, detail);
+                on_event_clone(msg, detail);
+                if let Some(trace) = action_trace::trace_controller_action(
+                    &state_for_sink,
+                    &instance_id_for_sink,
+                    msg,
+                    detail,
+                ) {
+                    on_event_clone(action_trace::ACTION_TRACE_MESSAGE, &trace);
+                }
+            })
+            .with_code(MxuErrorCode::ControllerConnectFailed)?;
+
+        if let Err(e) = controller.set_screenshot_target_short_side(display_short_side) {
+            warn!(
+                "Failed to set screenshot target short side to {}: {}",
+                display_short_side, e
+            );
+        }
+
+        // 发起连接
+        let conn_id = controller
+            .post_connection()
+            .with_code(MxuErrorCode::ControllerConnectFailed)?;
+
+        // 存入 ControllerPool
+        if !preprocess {
             let mut pool = state_arc
                 .controller_pool
                 .lock()
-                .map_err(|e| e.to_string())?;
+                .with_code(MxuErrorCode::Internal)?;
             pool.insert(config.clone(), controller.clone());
         }
 
@@ -658,7 +1295,7 @@ pub async fn connect_controller_impl(
         Ok(conn_id)
     })
     .await
-    .map_err(|e| e.to_string())?
+    .with_code(MxuErrorCode::Internal)?
 }
 
 /// 连接控制器（异步，通过回调通知完成状态）
@@ -668,13 +1305,34 @@ pub async fn maa_connect_controller(
     app: tauri::AppHandle,
     state: State<'_, Arc<MaaState>>,
     instance_id: String,
-    config: ControllerConfig,
-) -> Result<i64, String> {
+    mut config: ControllerConfig,
+    launch: Option<EmulatorLaunchConfig>,
+) -> Result<i64, MxuError> {
     info!(
         "maa_connect_controller called, instance_id: {}",
         instance_id
     );
 
+    // 配置了模拟器启动命令时，目标设备/窗口不存在则先拉起模拟器并等待就绪
+    if let Some(launch) = launch {
+        super::emulator_launch::ensure_emulator_ready(
+            &app,
+            state.inner(),
+            &instance_id,
+            &mut config,
+            &launch,
+        )
+        .await
+        .with_code(MxuErrorCode::ControllerConnectFailed)
+        .map_err(|e| e.with_detail(&instance_id))?;
+    }
+
+    // ADB 设备未授权时先等待用户在设备上允许调试
+    super::adb_auth::wait_for_authorization(&app, state.inner(), &instance_id, &config)
+        .await
+        .with_code(MxuErrorCode::ControllerConnectFailed)
+        .map_err(|e| e.with_detail(&instance_id))?;
+
     let app_clone = app.clone();
     let result = connect_controller_impl(
         state.inner().clone(),
@@ -682,11 +1340,10 @@ pub async fn maa_connect_controller(
         config,
         Arc::new(move |msg, detail| emit_callback_event(&app, msg, detail)),
     )
-    .await;
-    if result.is_ok() {
-        super::utils::emit_state_changed(&app_clone, &instance_id, "connected");
-    }
-    result
+    .await
+    .map_err(|e| e.with_detail(&instance_id))?;
+    super::utils::emit_state_changed(&app_clone, &instance_id, "connected");
+    Ok(result)
 }
 
 /// 获取连接状态（通过 MaaControllerConnected API 查询）
@@ -698,17 +1355,199 @@ pub fn maa_get_connection_status(
     let instances = state.instances.lock().map_err(|e| e.to_string())?;
     let instance = instances.get(&instance_id).ok_or("Instance not found")?;
 
-    if instance.controller.as_ref().is_some_and(|c| c.connected()) {
+    if instance.is_connected() {
         Ok(ConnectionStatus::Connected)
     } else {
         Ok(ConnectionStatus::Disconnected)
     }
 }
 
+/// 按位组合的方式值转为各项名称
+macro_rules! method_names {
+    ($flags:ty, $bits:expr) => {
+        <$flags>::from_bits_truncate($bits)
+            .iter_names()
+            .map(|(name, _)| name.to_string())
+            .collect::<Vec<_>>()
+    };
+}
+
+/// 查询当前控制器详情的内部实现
+///
+/// 类型、连接目标与截图/输入方式取自创建控制器时的配置，分辨率与 UUID 从已连接的控制器查询
+pub fn get_controller_info_impl(
+    state: &MaaState,
+    instance_id: &str,
+) -> Result<ControllerInfo, String> {
+    use maa_framework::common::{
+        AdbInputMethod, AdbScreencapMethod, Win32InputMethod, Win32ScreencapMethod,
+    };
+
+    let (controller, config) = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        (
+            instance.controller.clone(),
+            instance.controller_config.clone(),
+        )
+    };
+    let Some(controller) = controller else {
+        return Ok(ControllerInfo {
+            status: "none",
+            ..Default::default()
+        });
+    };
+
+    let mut info = ControllerInfo::default();
+    if controller.connected() {
+        info.status = "connected";
+        info.resolution = controller.resolution().ok();
+        info.uuid = controller.uuid().ok().filter(|uuid| !uuid.is_empty());
+    } else {
+        info.status = "disconnected";
+    }
+
+    let Some(config) = config else {
+        return Ok(info);
+    };
+    match config {
+        ControllerConfig::Adb {
+            adb_path,
+            address,
+            screencap_methods,
+            input_methods,
+            display_short_side,
+            ..
+        } => {
+            info.controller_type = Some("Adb".to_string());
+            info.target = Some(address);
+            info.adb_path = Some(adb_path);
+            if let Ok(bits) = screencap_methods.parse::<u64>() {
+                info.screencap_methods = method_names!(AdbScreencapMethod, bits);
+            }
+            if let Ok(bits) = input_methods.parse::<u64>() {
+                info.input_methods = method_names!(AdbInputMethod, bits);
+            }
+            info.display_short_side = display_short_side;
+        }
+        ControllerConfig::Win32 {
+            handle,
+            screencap_method,
+            mouse_method,
+            keyboard_method,
+            display_short_side,
+        } => {
+            info.controller_type = Some("Win32".to_string());
+            info.target = Some(format!("0x{:X}", handle));
+            info.screencap_methods = method_names!(Win32ScreencapMethod, screencap_method);
+            info.input_methods = method_names!(Win32InputMethod, mouse_method);
+            info.keyboard_methods = method_names!(Win32InputMethod, keyboard_method);
+            info.display_short_side = display_short_side;
+        }
+        ControllerConfig::WlRoots {
+            wlr_socket_path,
+            display_short_side,
+            ..
+        } => {
+            info.controller_type = Some("WlRoots".to_string());
+            info.target = Some(wlr_socket_path);
+            info.display_short_side = display_short_side;
+        }
+        ControllerConfig::Gamepad {
+            handle,
+            gamepad_type,
+            screencap_method,
+            display_short_side,
+        } => {
+            info.controller_type = Some("Gamepad".to_string());
+            info.target = Some(format!("0x{:X}", handle));
+            info.gamepad_type = Some(gamepad_type.unwrap_or_else(|| "Xbox360".to_string()));
+            info.screencap_methods = method_names!(
+                Win32ScreencapMethod,
+                screencap_method.unwrap_or(Win32ScreencapMethod::DXGI_DESKTOP_DUP.bits())
+            );
+            info.display_short_side = display_short_side;
+        }
+        ControllerConfig::PlayCover {
+            address,
+            display_short_side,
+            ..
+        } => {
+            info.controller_type = Some("PlayCover".to_string());
+            info.target = Some(address);
+            info.display_short_side = display_short_side;
+        }
+        ControllerConfig::Dummy { display_short_side } => {
+            info.controller_type = Some("Dummy".to_string());
+            info.display_short_side = display_short_side;
+        }
+    }
+    Ok(info)
+}
+
+/// 获取当前控制器详情（类型、连接目标、截图/输入方式、分辨率、UUID），未创建时 status 为 "none"
+#[tauri::command]
+pub fn maa_get_controller_info(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<ControllerInfo, String> {
+    get_controller_info_impl(&state, &instance_id)
+}
+
 // ============================================================================
 // 资源命令
 // ============================================================================
 
+/// 检查 interface.json 声明的 `min_maa_version` 是否满足，未声明时直接通过
+///
+/// 框架版本过旧时加载资源会报出难以理解的错误，因此提前拒绝并提示升级。
+/// 当前版本无法按语义化版本解析（如 DEBUG_VERSION）时只记录警告，不阻止加载
+fn check_resource_maa_version(app: &tauri::AppHandle) -> Result<(), String> {
+    use tauri::Manager;
+
+    let Some(app_config) = app.try_state::<Arc<AppConfigState>>() else {
+        return Ok(());
+    };
+    let required = app_config
+        .project_interface
+        .lock()
+        .map_err(|e| e.to_string())?
+        .as_ref()
+        .and_then(|pi| pi.get("min_maa_version"))
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_string());
+    let Some(required) = required.filter(|s| !s.is_empty()) else {
+        return Ok(());
+    };
+
+    let required_version = match semver::Version::parse(required.trim_start_matches('v')) {
+        Ok(v) => v,
+        Err(e) => {
+            warn!(
+                "Invalid min_maa_version '{}' in interface.json: {}",
+                required, e
+            );
+            return Ok(());
+        }
+    };
+    let current = std::panic::catch_unwind(|| maa_framework::maa_version().to_string())
+        .map_err(|_| "MaaFramework library not loaded (panic in maa_version)".to_string())?;
+    match semver::Version::parse(current.trim_start_matches('v')) {
+        Ok(current_version) if current_version < required_version => Err(format!(
+            "当前资源要求 MaaFramework 版本不低于 v{}，当前版本为 {}，请升级 MaaFramework 后再加载",
+            required_version, current
+        )),
+        Ok(_) => Ok(()),
+        Err(_) => {
+            warn!(
+                "Cannot compare MaaFramework version '{}' with min_maa_version '{}', skipped",
+                current, required
+            );
+            Ok(())
+        }
+    }
+}
+
 /// 加载资源的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
 pub fn load_resource_impl(
     state: &MaaState,
@@ -716,25 +1555,56 @@ pub fn load_resource_impl(
     paths: &[String],
     on_event: Arc<dyn Fn(&str, &str) + Send + Sync + 'static>,
     app: Option<&tauri::AppHandle>,
-) -> Result<Vec<i64>, String> {
+) -> Result<Vec<i64>, MxuError> {
     info!(
         "load_resource_impl called, instance: {}, paths: {:?}",
         instance_id, paths
     );
 
-    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+    if let Some(app) = app {
+        check_resource_maa_version(app).with_code(MxuErrorCode::ResourceLoadFailed)?;
+    }
+
+    let global_resource_dir = state
+        .resource_dir
+        .lock()
+        .with_code(MxuErrorCode::Internal)?
+        .clone();
+
+    let mut instances = state.instances.lock().with_code(MxuErrorCode::Internal)?;
+    let instance = instances
+        .get_mut(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let resource_dir = instance.resource_dir.clone().or(global_resource_dir);
 
     // 创建或获取资源
     if instance.resource.is_none() {
-        let res = Resource::new().map_err(|e| e.to_string())?;
+        let res = Resource::new().with_code(MxuErrorCode::ResourceLoadFailed)?;
+
+        // 新资源从空记录开始
+        *instance
+            .resource_load
+            .lock()
+            .with_code(MxuErrorCode::Internal)? = Default::default();
 
         // 注册回调
         let on_event_clone = on_event.clone();
+        let load_record = instance.resource_load.clone();
+        let instance_id_for_sink = instance_id.to_string();
+        let app_for_sink = app.cloned();
         res.add_sink(move |msg, detail| {
+            event_log::record_callback(&instance_id_for_sink, msg, detail);
+            if msg == "Resource.Loading.Succeeded" {
+                if let Ok(mut record) = load_record.lock() {
+                    record.duration_ms = record.started_at.map(|t| t.elapsed().as_millis() as u64);
+                }
+            }
+            if let Some(app) = app_for_sink.as_ref() {
+                track_resource_load_progress(app, &instance_id_for_sink, &load_record, msg, detail);
+            }
             on_event_clone(msg, detail);
         })
-        .map_err(|e| e.to_string())?;
+        .with_code(MxuErrorCode::ResourceLoadFailed)?;
 
         // 注册 MXU Custom Actions
         if let Some(app_handle) = app {
@@ -751,11 +1621,32 @@ pub fn load_resource_impl(
     let resource = instance.resource.as_ref().unwrap();
     let mut res_ids = Vec::new();
 
+    // 提交期间持有记录锁，sink 中的进度处理会等到 pending_jobs 填好后再执行
+    let mut record = instance
+        .resource_load
+        .lock()
+        .with_code(MxuErrorCode::Internal)?;
+    record.started_at = Some(Instant::now());
+    record.duration_ms = None;
+    record.pending_jobs.clear();
+    record.jobs_loaded = 0;
+    record.jobs_failed = 0;
+
     for path in paths {
-        let normalized = normalize_path(path).to_string_lossy().to_string();
+        let path = match resource_dir.as_ref() {
+            Some(dir) if std::path::Path::new(path).is_relative() => {
+                dir.join(path).to_string_lossy().to_string()
+            }
+            _ => path.clone(),
+        };
+        let normalized = normalize_path(&path).to_string_lossy().to_string();
         match resource.post_bundle(&normalized) {
             Ok(job) => {
                 info!("Posted resource bundle: {} -> id: {}", normalized, job.id);
+                if !record.paths.contains(&normalized) {
+                    record.paths.push(normalized.clone());
+                }
+                record.pending_jobs.insert(job.id, normalized);
                 res_ids.push(job.id);
             }
             Err(e) => {
@@ -763,10 +1654,85 @@ pub fn load_resource_impl(
             }
         }
     }
+    record.jobs_total = res_ids.len();
+
+    if let Some(app) = app {
+        emit_resource_load_progress(app, instance_id, &record, "started", None);
+    }
 
     Ok(res_ids)
 }
 
+/// 发送资源加载进度事件
+fn emit_resource_load_progress(
+    app: &tauri::AppHandle,
+    instance_id: &str,
+    record: &ResourceLoadRecord,
+    stage: &str,
+    path: Option<String>,
+) {
+    use tauri::Emitter;
+
+    let event = ResourceLoadProgressEvent {
+        instance_id: instance_id.to_string(),
+        stage: stage.to_string(),
+        path,
+        loaded: record.jobs_loaded,
+        failed: record.jobs_failed,
+        total: record.jobs_total,
+    };
+    if let Err(e) = app.emit("maa-resource-load-progress", event) {
+        error!("Failed to emit maa-resource-load-progress: {}", e);
+    }
+}
+
+/// 根据 Resource.Loading.* 回调更新本轮加载进度，并在全部资源包结束后发送 completed
+fn track_resource_load_progress(
+    app: &tauri::AppHandle,
+    instance_id: &str,
+    load_record: &std::sync::Mutex<ResourceLoadRecord>,
+    msg: &str,
+    detail: &str,
+) {
+    let stage = match msg {
+        "Resource.Loading.Starting" => "loading",
+        "Resource.Loading.Succeeded" => "succeeded",
+        "Resource.Loading.Failed" => "failed",
+        _ => return,
+    };
+    let Some(res_id) = serde_json::from_str::<serde_json::Value>(detail)
+        .ok()
+        .and_then(|v| v.get("res_id").and_then(|id| id.as_i64()))
+    else {
+        return;
+    };
+    let Ok(mut record) = load_record.lock() else {
+        return;
+    };
+
+    if stage == "loading" {
+        // 不属于本轮加载（如已被新一轮覆盖）的回调直接忽略
+        let Some(path) = record.pending_jobs.get(&res_id).cloned() else {
+            return;
+        };
+        emit_resource_load_progress(app, instance_id, &record, stage, Some(path));
+        return;
+    }
+
+    let Some(path) = record.pending_jobs.remove(&res_id) else {
+        return;
+    };
+    if stage == "succeeded" {
+        record.jobs_loaded += 1;
+    } else {
+        record.jobs_failed += 1;
+    }
+    emit_resource_load_progress(app, instance_id, &record, stage, Some(path));
+    if record.pending_jobs.is_empty() {
+        emit_resource_load_progress(app, instance_id, &record, "completed", None);
+    }
+}
+
 /// 加载资源（异步，通过回调通知完成状态）
 /// 返回资源加载请求 ID 列表，前端通过监听 maa-callback 事件获取完成状态
 #[tauri::command]
@@ -775,7 +1741,7 @@ pub fn maa_load_resource(
     state: State<Arc<MaaState>>,
     instance_id: String,
     paths: Vec<String>,
-) -> Result<Vec<i64>, String> {
+) -> Result<Vec<i64>, MxuError> {
     let res_ids = load_resource_impl(
         &state,
         &instance_id,
@@ -785,23 +1751,57 @@ pub fn maa_load_resource(
             move |msg, detail| emit_callback_event(&app, msg, detail)
         }),
         Some(&app),
-    )?;
+    )
+    .map_err(|e| e.with_detail(&instance_id))?;
 
     super::utils::emit_state_changed(&app, &instance_id, "resource-loading");
 
     Ok(res_ids)
 }
 
-/// 检查资源是否已加载（通过 MaaResourceLoaded API 查询）
+/// 查询资源加载情况（通过 MaaResourceLoaded API 判断是否已加载）
+///
+/// 已加载时附带资源目录列表、interface.json 中的版本号、资源 hash 和加载耗时；
+/// 未加载时 loaded 为 false 且其余字段为空
 #[tauri::command]
 pub fn maa_is_resource_loaded(
     state: State<Arc<MaaState>>,
+    app_config: State<Arc<AppConfigState>>,
     instance_id: String,
-) -> Result<bool, String> {
+) -> Result<ResourceLoadedInfo, String> {
     let instances = state.instances.lock().map_err(|e| e.to_string())?;
     let instance = instances.get(&instance_id).ok_or("Instance not found")?;
 
-    Ok(instance.resource.as_ref().is_some_and(|r| r.loaded()))
+    let resource = match instance.resource.as_ref() {
+        Some(r) if instance.is_resource_loaded() => r,
+        _ => {
+            return Ok(ResourceLoadedInfo {
+                loaded: false,
+                paths: Vec::new(),
+                version: None,
+                hash: None,
+                load_duration_ms: None,
+            })
+        }
+    };
+
+    let record = instance.resource_load.lock().map_err(|e| e.to_string())?;
+    let version = app_config
+        .project_interface
+        .lock()
+        .map_err(|e| e.to_string())?
+        .as_ref()
+        .and_then(|pi| pi.get("version"))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    Ok(ResourceLoadedInfo {
+        loaded: true,
+        paths: record.paths.clone(),
+        version,
+        hash: resource.hash().ok(),
+        load_duration_ms: record.duration_ms,
+    })
 }
 
 /// 获取已加载资源的 hash 值（用于完整性校验）
@@ -825,6 +1825,61 @@ pub fn maa_get_resource_hash(
     }
 }
 
+/// 重新加载资源的内部实现：按原加载顺序用已记录的资源目录重建资源，使磁盘上的修改生效
+///
+/// 任务运行中时拒绝重载；未加载过资源时返回空列表
+pub fn reload_resource_impl(
+    app: &tauri::AppHandle,
+    state: &MaaState,
+    instance_id: &str,
+) -> Result<Vec<i64>, String> {
+    let paths = {
+        let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+        if instance.resource.is_none() {
+            return Ok(Vec::new());
+        }
+        if instance.tasker.as_ref().is_some_and(|t| t.running()) {
+            return Err("任务运行中，无法重新加载资源".to_string());
+        }
+        let paths = instance
+            .resource_load
+            .lock()
+            .map_err(|e| e.to_string())?
+            .paths
+            .clone();
+        // 与 maa_destroy_resource 一致：tasker 绑定旧资源，需一并重建
+        instance.resource = None;
+        instance.tasker = None;
+        paths
+    };
+
+    info!("reload_resource_impl: {} -> {:?}", instance_id, paths);
+    let res_ids = load_resource_impl(
+        state,
+        instance_id,
+        &paths,
+        Arc::new({
+            let app = app.clone();
+            move |msg, detail| emit_callback_event(&app, msg, detail)
+        }),
+        Some(app),
+    )?;
+    super::utils::emit_state_changed(app, instance_id, "resource-loading");
+
+    Ok(res_ids)
+}
+
+/// 重新加载资源（资源文件更新后热生效），返回资源加载请求 ID 列表
+#[tauri::command]
+pub fn maa_reload_resource(
+    app: tauri::AppHandle,
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<Vec<i64>, String> {
+    reload_resource_impl(&app, &state, &instance_id)
+}
+
 /// 销毁资源（用于切换资源时重新创建）
 #[tauri::command]
 pub fn maa_destroy_resource(
@@ -853,18 +1908,32 @@ pub fn run_task_impl(
     app: &tauri::AppHandle,
     state: &Arc<MaaState>,
     instance_id: &str,
-    entry: &str,
-    pipeline_override: &str,
-    selected_task_id: Option<&str>,
-) -> Result<i64, String> {
-    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+    task: &TaskConfig,
+) -> Result<i64, MxuError> {
+    let entry = task.entry.as_str();
+    let pipeline_override = task.pipeline_override.as_str();
+    let selected_task_id = task.selected_task_id.as_deref();
+
+    let mut instances = state.instances.lock().with_code(MxuErrorCode::Internal)?;
+    let instance = instances
+        .get_mut(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
 
-    let resource = instance.resource.as_ref().ok_or("Resource not loaded")?;
+    // 前置条件：未就绪时返回对应错误码，由前端显示明确提示，而不是把底层错误抛出来
+    if !instance.is_connected() {
+        return Err(MxuError::controller_not_connected());
+    }
+    if !instance.is_resource_loaded() {
+        return Err(MxuError::resource_not_loaded());
+    }
+    let resource = instance
+        .resource
+        .as_ref()
+        .ok_or_else(MxuError::resource_not_loaded)?;
     let controller = instance
         .controller
         .as_ref()
-        .ok_or("Controller not connected")?;
+        .ok_or_else(MxuError::controller_not_connected)?;
 
     // 创建或获取 tasker
     let needs_new_tasker = match instance.tasker.as_ref() {
@@ -877,13 +1946,19 @@ pub fn run_task_impl(
             instance.tasker = None;
         }
 
-        let tasker = Tasker::new().map_err(|e| e.to_string())?;
+        let tasker = Tasker::new().with_code(MxuErrorCode::TaskRunFailed)?;
 
         let app_for_sink = app.clone();
         let maa_state_for_sink = Arc::clone(state);
         let instance_id_for_sink = instance_id.to_string();
         tasker
             .add_sink(move |msg, detail| {
+                let preempted = preempt::is_preempted_callback(
+                    &maa_state_for_sink,
+                    &instance_id_for_sink,
+                    msg,
+                    detail,
+                );
                 handle_task_callback(
                     &maa_state_for_sink,
                     &app_for_sink,
@@ -891,20 +1966,89 @@ pub fn run_task_impl(
                     msg,
                     detail,
                 );
-                emit_callback_event(&app_for_sink, msg, detail);
+                event_log::record_callback(&instance_id_for_sink, msg, detail);
+                task_condition::apply_skip_condition(
+                    &maa_state_for_sink,
+                    &app_for_sink,
+                    &instance_id_for_sink,
+                    msg,
+                    detail,
+                );
+                capture_mode::before_capture(&maa_state_for_sink, &instance_id_for_sink, msg);
+                window_state::before_capture(&maa_state_for_sink, &instance_id_for_sink, msg);
+                frame_dedup::before_capture(&maa_state_for_sink, &instance_id_for_sink, msg);
+                unknown_screens::record_callback(
+                    &maa_state_for_sink,
+                    &instance_id_for_sink,
+                    msg,
+                    detail,
+                );
+                last_frame::capture_on_task_end(
+                    &maa_state_for_sink,
+                    &instance_id_for_sink,
+                    msg,
+                    detail,
+                );
+                let detail = failure_screenshot::attach_failure_screenshot(
+                    &maa_state_for_sink,
+                    &instance_id_for_sink,
+                    msg,
+                    detail,
+                );
+                let detail = preempt::tag_preempted_callback(detail, preempted);
+                emit_callback_event(&app_for_sink, msg, &*detail);
             })
-            .map_err(|e| e.to_string())?;
+            .with_code(MxuErrorCode::TaskRunFailed)?;
 
         let app_for_context_sink = app.clone();
+        let maa_state_for_context_sink = Arc::clone(state);
+        let instance_id_for_context_sink = instance_id.to_string();
         tasker
             .add_context_sink(move |msg, detail| {
+                handle_breakpoint_callback(
+                    &maa_state_for_context_sink,
+                    &app_for_context_sink,
+                    &instance_id_for_context_sink,
+                    msg,
+                    detail,
+                );
+                event_log::record_callback(&instance_id_for_context_sink, msg, detail);
+                action_trace::record_node(&instance_id_for_context_sink, msg, detail);
+                reco_stats::record_recognition(
+                    &maa_state_for_context_sink,
+                    &instance_id_for_context_sink,
+                    msg,
+                    detail,
+                );
+                node_timing::record_node_event(&instance_id_for_context_sink, msg, detail);
+                capture_mode::before_capture(
+                    &maa_state_for_context_sink,
+                    &instance_id_for_context_sink,
+                    msg,
+                );
+                window_state::before_capture(
+                    &maa_state_for_context_sink,
+                    &instance_id_for_context_sink,
+                    msg,
+                );
+                frame_dedup::before_capture(
+                    &maa_state_for_context_sink,
+                    &instance_id_for_context_sink,
+                    msg,
+                );
+                unknown_screens::record_callback(
+                    &maa_state_for_context_sink,
+                    &instance_id_for_context_sink,
+                    msg,
+                    detail,
+                );
                 emit_callback_event(&app_for_context_sink, msg, detail);
             })
-            .map_err(|e| e.to_string())?;
+            .with_code(MxuErrorCode::TaskRunFailed)?;
 
         tasker
             .bind(resource, controller)
-            .map_err(|e| e.to_string())?;
+            .with_code(MxuErrorCode::TaskRunFailed)?;
 
         instance.tasker = Some(tasker);
     }
@@ -912,17 +2056,31 @@ pub fn run_task_impl(
     let tasker = instance.tasker.as_ref().unwrap();
 
     if !tasker.inited() {
-        return Err("Tasker not initialized even after rebuild".to_string());
+        return Err(MxuError::new(
+            MxuErrorCode::TaskRunFailed,
+            "Tasker not initialized even after rebuild",
+        ));
     }
 
+    // dry-run 下替换所有节点动作，只识别不操作
+    let effective_override = if is_dry_run(instance_id) {
+        build_dry_run_override(resource, pipeline_override)
+            .with_code(MxuErrorCode::TaskRunFailed)?
+    } else {
+        pipeline_override.to_string()
+    };
+    // 按速度系数缩放动作前后的等待
+    let effective_override = apply_speed(resource, instance_id, effective_override)
+        .with_code(MxuErrorCode::TaskRunFailed)?;
     let job = tasker
-        .post_task(entry, pipeline_override)
-        .map_err(|e| e.to_string())?;
+        .post_task(entry, &effective_override)
+        .with_code(MxuErrorCode::TaskRunFailed)?;
     let task_id = job.id;
 
     if !instance.task_ids.contains(&task_id) {
         instance.task_ids.push(task_id);
     }
+    instance.submitted_tasks.push((task_id, task.clone()));
 
     if let Some(selected_task_id) = selected_task_id {
         let task_run_state = &mut instance.task_run_state;
@@ -943,29 +2101,63 @@ pub fn run_task_impl(
 
 /// 运行单个任务
 /// 返回任务 ID，前端通过监听 maa-callback 事件获取完成状态
+///
+/// priority 高于当前执行中任务时会抢占执行（见 `preempt`），需等待当前任务停下，因此在阻塞线程中执行
+///
+/// validate_only 为 true 时不提交任务，只用已加载资源校验入口节点与 override，
+/// 返回校验结果（见 `resource_check::validate_task_impl`）
 #[tauri::command]
-pub fn maa_run_task(
+#[allow(clippy::too_many_arguments)]
+pub async fn maa_run_task(
     app: tauri::AppHandle,
-    state: State<Arc<MaaState>>,
+    state: State<'_, Arc<MaaState>>,
     instance_id: String,
     entry: String,
     pipeline_override: String,
     selected_task_id: Option<String>,
-) -> Result<i64, String> {
-    info!("maa_run_task called, entry: {}", entry);
-    let app_clone = app.clone();
-    let result = run_task_impl(
-        &app,
-        &state,
-        &instance_id,
-        &entry,
-        &pipeline_override,
-        selected_task_id.as_deref(),
+    priority: Option<i32>,
+    skip_if: Option<String>,
+    validate_only: Option<bool>,
+) -> Result<RunTaskOutcome, MxuError> {
+    info!(
+        "maa_run_task called, entry: {}, priority: {:?}, validate_only: {:?}",
+        entry, priority, validate_only
     );
-    if result.is_ok() {
-        super::utils::emit_state_changed(&app_clone, &instance_id, "task-started");
+    if validate_only.unwrap_or(false) {
+        let validation = super::resource_check::validate_task_for_instance(
+            &state,
+            &instance_id,
+            &entry,
+            &pipeline_override,
+        )
+        .with_code(MxuErrorCode::TaskRunFailed)
+        .map_err(|e| e.with_detail(&entry))?;
+        return Ok(RunTaskOutcome::Validated(validation));
     }
-    result
+    let maa_state = Arc::clone(&state);
+    let app_clone = app.clone();
+    let instance_id_clone = instance_id.clone();
+    let entry_clone = entry.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        run_task_with_priority(
+            &app_clone,
+            &maa_state,
+            &instance_id_clone,
+            TaskConfig {
+                entry: entry_clone,
+                pipeline_override,
+                selected_task_id,
+                priority: priority.unwrap_or(0),
+                skip_if,
+            },
+        )
+    })
+    .await
+    .with_code(MxuErrorCode::Internal)
+    .and_then(|r| r)
+    .map_err(|e| e.with_detail(&entry))?;
+    super::utils::emit_state_changed(&app, &instance_id, "task-started");
+    Ok(RunTaskOutcome::Submitted(result))
 }
 
 /// 获取任务状态
@@ -1000,6 +2192,8 @@ pub fn maa_get_task_status(
 pub fn stop_task_impl(state: &MaaState, instance_id: &str) -> Result<(), String> {
     let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
     let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+    // 循环执行时不再开始下一轮（当前任务在节点边界停下）
+    instance.queue_loop = None;
     let tasker = instance.tasker.as_ref().ok_or("Tasker not created")?;
 
     if instance.stop_in_progress {
@@ -1049,32 +2243,340 @@ pub fn maa_stop_task(
     result
 }
 
-/// 覆盖已提交任务的 Pipeline 配置（用于运行中修改尚未执行的任务选项）
+/// 一键停止时每个实例等待任务停下的超时
+const STOP_ALL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 等待任务停下期间重发停止请求的间隔
+const STOP_ALL_REPOST_INTERVAL: Duration = Duration::from_millis(800);
+
+/// 停止所有实例任务的内部实现（可从 Tauri 命令、HTTP 处理器和托盘菜单共享调用）
+///
+/// 对每个运行中（含循环执行中等待下一轮）的实例各开一个线程发送停止请求并等待任务停下，
+/// 各实例独立计算超时，个别实例卡住不影响其他实例；全部返回后汇总结果。
+/// 会阻塞等待，需在阻塞线程中调用
+pub fn stop_all_impl(app: &tauri::AppHandle, state: &Arc<MaaState>) -> Vec<InstanceStopResult> {
+    let targets: Vec<(String, Tasker)> = state
+        .instances
+        .lock()
+        .map(|instances| {
+            instances
+                .iter()
+                .filter_map(|(id, instance)| {
+                    let tasker = instance.tasker.clone()?;
+                    let active = tasker.running() || instance.queue_loop.is_some();
+                    active.then(|| (id.clone(), tasker))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    info!("stop_all: stopping {} instance(s)", targets.len());
+
+    let handles: Vec<_> = targets
+        .into_iter()
+        .map(|(instance_id, tasker)| {
+            let app = app.clone();
+            let state = Arc::clone(state);
+            std::thread::spawn(move || stop_and_wait(&app, &state, instance_id, &tasker))
+        })
+        .collect();
+    handles
+        .into_iter()
+        .filter_map(|handle| handle.join().ok())
+        .collect()
+}
+
+/// 停止单个实例的任务并等待其停下
+fn stop_and_wait(
+    app: &tauri::AppHandle,
+    state: &MaaState,
+    instance_id: String,
+    tasker: &Tasker,
+) -> InstanceStopResult {
+    if let Err(e) = stop_task_impl(state, &instance_id) {
+        if tasker.running() {
+            warn!("stop_all: failed to stop {}: {}", instance_id, e);
+            return InstanceStopResult {
+                instance_id,
+                outcome: InstanceStopOutcome::Failed,
+                error: Some(e),
+            };
+        }
+    }
+
+    let deadline = Instant::now() + STOP_ALL_TIMEOUT;
+    let mut last_post = Instant::now();
+    let mut outcome = InstanceStopOutcome::Stopped;
+    while tasker.running() {
+        if Instant::now() >= deadline {
+            warn!("stop_all: timed out waiting for {} to stop", instance_id);
+            outcome = InstanceStopOutcome::TimedOut;
+            break;
+        }
+        if last_post.elapsed() >= STOP_ALL_REPOST_INTERVAL {
+            let _ = tasker.post_stop();
+            last_post = Instant::now();
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    super::utils::emit_state_changed(app, &instance_id, "task-stopped");
+    InstanceStopResult {
+        instance_id,
+        outcome,
+        error: None,
+    }
+}
+
+/// 停止所有实例的任务，全部停妥（或各自超时）后返回每个实例的结果
+#[tauri::command]
+pub async fn maa_stop_all(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<MaaState>>,
+) -> Result<Vec<InstanceStopResult>, String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || stop_all_impl(&app, &state))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 覆盖已提交任务的 Pipeline 配置（用于运行中修改任务选项）
 /// 内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
+///
+/// 每次 override 只作用于本实例的指定任务，并按节点叠加在此前的 override 之上：
+/// 同一节点内后写入的字段覆盖先前的值，未涉及的字段保持不变。
+/// 成功应用的 override 会被记录，可通过 `maa_reset_pipeline` 一键撤销。
+/// 排队中和运行中的任务都可修改，运行中的任务从下一个节点开始使用新配置，
+/// 返回各字段何时生效（见 `hot_override`），应用或同步到后续轮次后发出 `maa-pipeline-hot-updated` 事件。
 pub fn override_pipeline_impl(
+    app: &tauri::AppHandle,
     state: &Arc<MaaState>,
     instance_id: &str,
     task_id: i64,
     pipeline_override: &str,
-) -> Result<bool, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(instance_id).ok_or("Instance not found")?;
-    let tasker = instance.tasker.as_ref().ok_or("Tasker not created")?;
+) -> Result<PipelineOverrideResult, String> {
+    use tauri::Emitter;
 
-    tasker
-        .override_pipeline(task_id, pipeline_override)
-        .map_err(|e| e.to_string())
+    let layers = parse_pipeline_override(pipeline_override)?;
+
+    let result = {
+        let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+        let tasker = instance.tasker.as_ref().ok_or("Tasker not created")?;
+
+        let status = tasker
+            .get_task_detail(task_id)
+            .ok()
+            .flatten()
+            .map(|d| d.status)
+            .unwrap_or(MaaStatus::INVALID);
+        // dry-run 下热更的节点同样替换为 dry-run 动作，避免运行中改回真实操作
+        let effective_override = match (is_dry_run(instance_id), instance.resource.as_ref()) {
+            (true, Some(resource)) => build_dry_run_override(resource, pipeline_override)?,
+            _ => pipeline_override.to_string(),
+        };
+        let effective_override = apply_speed_to_layers(instance_id, effective_override)?;
+        let applied = tasker
+            .override_pipeline(task_id, &effective_override)
+            .map_err(|e| e.to_string())?;
+
+        let entry = instance
+            .submitted_tasks
+            .iter()
+            .find(|(id, _)| *id == task_id)
+            .map(|(_, task)| task.entry.clone());
+        let mut result = PipelineOverrideResult {
+            applied,
+            ..Default::default()
+        };
+        let status = if applied { status } else { MaaStatus::INVALID };
+        hot_override::classify(&mut result, &layers, instance_id, status, entry.as_deref());
+
+        if applied {
+            instance
+                .pipeline_overrides
+                .entry(task_id)
+                .or_default()
+                .applied
+                .extend(layers.iter().cloned());
+        }
+        result.next_round = hot_override::sync_to_loop(instance, task_id, &layers);
+        result
+    };
+
+    if result.applied || result.next_round {
+        info!(
+            "Pipeline override updated on {} task {}: hot {:?}, deferred {:?}, restart {:?}, next round: {}",
+            instance_id,
+            task_id,
+            result.hot_fields,
+            result.deferred_fields,
+            result.restart_fields,
+            result.next_round
+        );
+        let event = PipelineHotUpdatedEvent {
+            instance_id: instance_id.to_string(),
+            task_id,
+            result: result.clone(),
+        };
+        if let Err(e) = app.emit("maa-pipeline-hot-updated", event) {
+            error!("Failed to emit maa-pipeline-hot-updated: {}", e);
+        }
+    }
+    Ok(result)
 }
 
-/// 覆盖已提交任务的 Pipeline 配置（用于运行中修改尚未执行的任务选项）
+/// 覆盖已提交任务的 Pipeline 配置（用于运行中修改任务选项）
 #[tauri::command]
 pub fn maa_override_pipeline(
+    app: tauri::AppHandle,
     state: State<Arc<MaaState>>,
     instance_id: String,
     task_id: i64,
     pipeline_override: String,
-) -> Result<bool, String> {
-    override_pipeline_impl(&state, &instance_id, task_id, &pipeline_override)
+) -> Result<PipelineOverrideResult, String> {
+    override_pipeline_impl(&app, &state, &instance_id, task_id, &pipeline_override)
+}
+
+/// 查询实例各任务当前生效的 Pipeline override 的内部实现
+///
+/// `effective` 为提交时的原始 override 依次叠加运行中各次 override 后的结果
+pub fn get_pipeline_overrides_impl(
+    state: &Arc<MaaState>,
+    instance_id: &str,
+) -> Result<Vec<PipelineOverrideInfo>, String> {
+    let instances = state.instances.lock().map_err(|e| e.to_string())?;
+    let instance = instances.get(instance_id).ok_or("Instance not found")?;
+
+    let mut infos: Vec<PipelineOverrideInfo> = instance
+        .pipeline_overrides
+        .iter()
+        .map(|(task_id, record)| {
+            let mut original = serde_json::Map::new();
+            for layer in &record.original {
+                merge_pipeline_override(&mut original, layer);
+            }
+            let mut effective = original.clone();
+            for layer in &record.applied {
+                merge_pipeline_override(&mut effective, layer);
+            }
+            PipelineOverrideInfo {
+                task_id: *task_id,
+                original: original.into(),
+                applied: record.applied.iter().cloned().map(Into::into).collect(),
+                effective: effective.into(),
+            }
+        })
+        .collect();
+    infos.sort_by_key(|info| info.task_id);
+    Ok(infos)
+}
+
+/// 查询实例各任务当前生效的 Pipeline override
+#[tauri::command]
+pub fn maa_get_pipeline_overrides(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<Vec<PipelineOverrideInfo>, String> {
+    get_pipeline_overrides_impl(&state, &instance_id)
+}
+
+/// 撤销实例上通过 `maa_override_pipeline` 应用的所有修改的内部实现，返回被还原的任务数
+///
+/// 对每个被修改过的字段：提交任务时原始 override 中有该字段则恢复为原始值，
+/// 否则恢复为资源中该节点的原始定义。已结束的任务无需还原，仅清除记录。
+pub fn reset_pipeline_impl(state: &Arc<MaaState>, instance_id: &str) -> Result<usize, String> {
+    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
+    let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+    let tasker = instance.tasker.as_ref().ok_or("Tasker not created")?;
+    // 循环执行时之后各轮也不再带上热更新的修改
+    if let Some(ctx) = instance.queue_loop.as_mut() {
+        ctx.hot_layers.clear();
+    }
+
+    let mut reset_count = 0;
+    for (task_id, record) in instance.pipeline_overrides.iter_mut() {
+        if record.applied.is_empty() {
+            continue;
+        }
+
+        let mut original = serde_json::Map::new();
+        for layer in &record.original {
+            merge_pipeline_override(&mut original, layer);
+        }
+
+        // 资源原始节点定义（按需读取并缓存）
+        let mut resource_nodes: HashMap<String, Option<serde_json::Value>> = HashMap::new();
+        let mut restore = serde_json::Map::new();
+        for layer in &record.applied {
+            for (node, fields) in layer {
+                let touched: Vec<&String> = match fields {
+                    serde_json::Value::Object(fields) => fields.keys().collect(),
+                    _ => Vec::new(),
+                };
+                for key in touched {
+                    let original_value = original
+                        .get(node)
+                        .and_then(|n| n.get(key))
+                        .cloned()
+                        .or_else(|| {
+                            resource_nodes
+                                .entry(node.clone())
+                                .or_insert_with(|| {
+                                    instance
+                                        .resource
+                                        .as_ref()
+                                        .and_then(|r| r.get_node_data(node).ok().flatten())
+                                        .and_then(|data| serde_json::from_str(&data).ok())
+                                })
+                                .as_ref()
+                                .and_then(|n| n.get(key))
+                                .cloned()
+                        });
+                    match original_value {
+                        Some(value) => {
+                            if let Some(restore_node) = restore
+                                .entry(node.clone())
+                                .or_insert_with(|| serde_json::Value::Object(Default::default()))
+                                .as_object_mut()
+                            {
+                                restore_node.insert(key.clone(), value);
+                            }
+                        }
+                        None => warn!(
+                            "reset_pipeline: original value of {}.{} not found, skipped",
+                            node, key
+                        ),
+                    }
+                }
+            }
+        }
+
+        record.applied.clear();
+        if restore.is_empty() {
+            continue;
+        }
+        let restore = serde_json::Value::Object(restore).to_string();
+        match tasker.override_pipeline(*task_id, &restore) {
+            Ok(true) => reset_count += 1,
+            Ok(false) => debug!("reset_pipeline: task {} already finished", task_id),
+            Err(e) => warn!("reset_pipeline: failed to restore task {}: {}", task_id, e),
+        }
+    }
+
+    info!(
+        "reset_pipeline: instance {} restored {} task(s)",
+        instance_id, reset_count
+    );
+    Ok(reset_count)
+}
+
+/// 撤销实例上通过 `maa_override_pipeline` 应用的所有修改，还原到资源原始状态
+#[tauri::command]
+pub fn maa_reset_pipeline(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<usize, String> {
+    reset_pipeline_impl(&state, &instance_id)
 }
 
 /// 检查是否正在运行
@@ -1116,31 +2618,141 @@ pub fn maa_post_click(
 // 截图命令
 // ============================================================================
 
+/// 等待主动截图完成时检查的间隔
+const SCREENCAP_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// 未指定时等待主动截图完成的超时
+const DEFAULT_SCREENCAP_WAIT_MS: u64 = 3000;
+
 /// 发起截图请求（内部实现）
-pub fn post_screencap_impl(state: &MaaState, instance_id: &str) -> Result<i64, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(instance_id).ok_or("Instance not found")?;
-    let controller = instance
-        .controller
-        .as_ref()
-        .ok_or("Controller not connected")?;
-    controller.post_screencap().map_err(|e| e.to_string())
+///
+/// 为本次截图分配序号并在后台等待其完成，完成后更新实例的截图记录，
+/// 调用方可据此确认取到的缓存帧不早于本次截图
+pub fn post_screencap_impl(state: &MaaState, instance_id: &str) -> Result<ScreencapTicket, String> {
+    let (controller, record) = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        let controller = instance
+            .controller
+            .clone()
+            .ok_or("Controller not connected")?;
+        (controller, instance.screencap_record.clone())
+    };
+
+    // 持有记录锁发起截图，保证序号与请求顺序一致
+    let (job_id, seq) = {
+        let mut record = record.lock().map_err(|e| e.to_string())?;
+        let job_id = controller.post_screencap().map_err(|e| e.to_string())?;
+        record.posted_seq += 1;
+        (job_id, record.posted_seq)
+    };
+
+    std::thread::spawn(move || {
+        if controller.wait(job_id) != MaaStatus::SUCCEEDED {
+            debug!("Screencap #{} (job {}) failed", seq, job_id);
+        }
+        if let Ok(mut record) = record.lock() {
+            if seq > record.completed_seq {
+                record.completed_seq = seq;
+                record.completed_at = Some(chrono::Local::now().timestamp_millis());
+            }
+        }
+    });
+
+    Ok(ScreencapTicket {
+        job_id,
+        seq,
+        requested_at: chrono::Local::now().timestamp_millis(),
+    })
 }
 
-/// 发起截图请求
+/// 发起截图请求，返回本次截图的序号与发起时间
 #[tauri::command]
-pub fn maa_post_screencap(state: State<Arc<MaaState>>, instance_id: String) -> Result<i64, String> {
+pub fn maa_post_screencap(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<ScreencapTicket, String> {
     post_screencap_impl(&state, &instance_id)
 }
 
+/// 等待序号不小于 `min_capture_seq` 的主动截图完成，超时返回错误
+pub fn wait_for_screencap(
+    state: &MaaState,
+    instance_id: &str,
+    min_capture_seq: u64,
+    timeout: Duration,
+) -> Result<(), String> {
+    let record = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        instance.screencap_record.clone()
+    };
+    let deadline = Instant::now() + timeout;
+    loop {
+        {
+            let record = record.lock().map_err(|e| e.to_string())?;
+            if record.completed_seq >= min_capture_seq {
+                return Ok(());
+            }
+            if min_capture_seq > record.posted_seq {
+                return Err(format!(
+                    "截图序号 {} 尚未发起（最近一次为 {}）",
+                    min_capture_seq, record.posted_seq
+                ));
+            }
+        }
+        if Instant::now() >= deadline {
+            return Err(format!("等待截图 #{} 完成超时", min_capture_seq));
+        }
+        std::thread::sleep(SCREENCAP_POLL_INTERVAL);
+    }
+}
+
 /// 获取缓存的截图（内部实现，返回 base64 编码的 PNG 图像）
-pub fn get_cached_image_impl(state: &MaaState, instance_id: &str) -> Result<String, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(instance_id).ok_or("Instance not found")?;
-    let controller = instance
-        .controller
-        .as_ref()
-        .ok_or("Controller not connected")?;
+///
+/// 编码结果按帧缓存：控制器帧代数与主动截图序号都未变化时直接复用上次的 data URL 和帧序号，
+/// 变化时才在实例锁外读取并编码新帧；`known_seq` 与当前帧序号一致时返回 not_modified，
+/// 不再携带图像数据
+pub fn get_cached_image_impl(
+    state: &MaaState,
+    instance_id: &str,
+    known_seq: Option<u64>,
+) -> Result<CachedImageFrame, String> {
+    let make_frame = |cache: &CachedImage, capture_seq: u64, captured_at: Option<i64>| {
+        let not_modified = known_seq == Some(cache.seq);
+        CachedImageFrame {
+            seq: cache.seq,
+            not_modified,
+            data_url: (!not_modified).then(|| cache.data_url.clone()),
+            capture_seq,
+            captured_at,
+        }
+    };
+
+    let (controller, capture_seq, captured_at, generation, prev_seq) = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        let controller = instance
+            .controller
+            .clone()
+            .ok_or("Controller not connected")?;
+
+        // 先读截图记录再取图像，保证返回的 capture_seq 不晚于图像
+        let (capture_seq, captured_at) = instance
+            .screencap_record
+            .lock()
+            .map(|record| (record.completed_seq, record.completed_at))
+            .map_err(|e| e.to_string())?;
+        let generation = frame_generation(instance.controller_config.as_ref());
+
+        if let Some(cache) = instance.image_cache.as_ref() {
+            if cache.generation == generation && cache.capture_seq == capture_seq {
+                return Ok(make_frame(cache, capture_seq, captured_at));
+            }
+        }
+        let prev_seq = instance.image_cache.as_ref().map_or(0, |c| c.seq);
+        (controller, capture_seq, captured_at, generation, prev_seq)
+    };
 
     let buffer = controller.cached_image().map_err(|e| e.to_string())?;
     let data = buffer
@@ -1151,18 +2763,130 @@ pub fn get_cached_image_impl(state: &MaaState, instance_id: &str) -> Result<Stri
         return Err("No image data available".to_string());
     }
 
+    let cache = {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+        CachedImage {
+            seq: prev_seq + 1,
+            generation,
+            capture_seq,
+            data_url: format!("data:image/png;base64,{}", STANDARD.encode(&data)),
+        }
+    };
+    let frame = make_frame(&cache, capture_seq, captured_at);
+
+    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
+    if let Some(instance) = instances.get_mut(instance_id) {
+        // 并发轮询时只保留帧代数更新的缓存
+        let has_newer = matches!(
+            &instance.image_cache,
+            Some(c) if (c.generation, c.capture_seq) >= (cache.generation, cache.capture_seq)
+        );
+        if !has_newer {
+            instance.image_cache = Some(cache);
+        }
+    }
+    Ok(frame)
+}
+
+/// 获取缓存的截图（返回 base64 编码的 PNG 图像及帧序号）
+///
+/// 传入前端已有的帧序号 `known_seq`，若帧未变化则返回 not_modified，前端复用已有图像；
+/// 传入 maa_post_screencap 返回的序号 `min_capture_seq` 时，先等待该次截图完成
+/// （超时 `timeout_ms`，默认 3 秒）再取图，避免取到发起截图前的旧帧
+#[tauri::command]
+pub async fn maa_get_cached_image(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    known_seq: Option<u64>,
+    min_capture_seq: Option<u64>,
+    timeout_ms: Option<u64>,
+) -> Result<CachedImageFrame, String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        if let Some(min_capture_seq) = min_capture_seq {
+            let timeout = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_SCREENCAP_WAIT_MS));
+            wait_for_screencap(&state, &instance_id, min_capture_seq, timeout)?;
+        }
+        get_cached_image_impl(&state, &instance_id, known_seq)
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|r| r)
+}
+
+/// 从缓存截图中裁剪指定区域（内部实现），越界部分截到图像范围内
+pub fn get_image_roi_impl(
+    state: &MaaState,
+    instance_id: &str,
+    roi: [i32; 4],
+) -> Result<ImageRoi, String> {
+    let controller = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        instance
+            .controller
+            .clone()
+            .ok_or("Controller not connected")?
+    };
+    let png = controller
+        .cached_image()
+        .map_err(|e| e.to_string())?
+        .to_vec()
+        .filter(|data| !data.is_empty())
+        .ok_or("No image data available")?;
+    let image =
+        tauri::image::Image::from_bytes(&png).map_err(|e| format!("解码截图失败: {}", e))?;
+    let (width, height) = (image.width(), image.height());
+
+    let [x, y, w, h] = roi;
+    let x0 = x.clamp(0, width as i32);
+    let y0 = y.clamp(0, height as i32);
+    let x1 = x.saturating_add(w.max(0)).clamp(0, width as i32);
+    let y1 = y.saturating_add(h.max(0)).clamp(0, height as i32);
+    if x1 <= x0 || y1 <= y0 {
+        return Err(format!(
+            "裁剪区域 [{}, {}, {}, {}] 与截图范围 {}x{} 无交集",
+            x, y, w, h, width, height
+        ));
+    }
+    let actual = [x0, y0, x1 - x0, y1 - y0];
+
+    let rgba = image.rgba();
+    let row_bytes = (width * 4) as usize;
+    let mut cropped = Vec::with_capacity((actual[2] * actual[3] * 4) as usize);
+    for row in y0..y1 {
+        let start = row as usize * row_bytes + x0 as usize * 4;
+        cropped.extend_from_slice(&rgba[start..start + actual[2] as usize * 4]);
+    }
+
     use base64::{engine::general_purpose::STANDARD, Engine as _};
-    let base64_str = STANDARD.encode(&data);
-    Ok(format!("data:image/png;base64,{}", base64_str))
+    let encoded = encode_png(actual[2] as u32, actual[3] as u32, &cropped);
+    Ok(ImageRoi {
+        roi: actual,
+        clamped: actual != roi,
+        image_width: width,
+        image_height: height,
+        data_url: format!("data:image/png;base64,{}", STANDARD.encode(encoded)),
+    })
 }
 
-/// 获取缓存的截图（返回 base64 编码的 PNG 图像）
+/// 从当前缓存截图中裁剪 [x, y, w, h] 区域，返回 base64 PNG 及实际裁剪区域
+///
+/// 用于调 ROI 时实时预览裁剪结果，坐标越界时截到图像范围内并在结果中注明
 #[tauri::command]
-pub fn maa_get_cached_image(
-    state: State<Arc<MaaState>>,
+pub async fn maa_get_image_roi(
+    state: State<'_, Arc<MaaState>>,
     instance_id: String,
-) -> Result<String, String> {
-    get_cached_image_impl(&state, &instance_id)
+    x: i32,
+    y: i32,
+    w: i32,
+    h: i32,
+) -> Result<ImageRoi, String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || get_image_roi_impl(&state, &instance_id, [x, y, w, h]))
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|r| r)
 }
 
 /// 订阅实例的实时截图（后端统一驱动截图循环）
@@ -1184,6 +2908,7 @@ pub async fn maa_screenshot_subscribe(
         instance_id,
         subscriber_id,
         interval_ms,
+        true,
         handle,
     );
     Ok(())
@@ -7,25 +7,115 @@
 //! - `utils`: 辅助函数
 //! - `maa_core`: Maa 核心命令（初始化、设备搜索、控制器、资源、任务）
 //! - `maa_agent`: Agent 相关命令
+//! - `agent_job`: Agent 子进程资源限制（Windows Job Object）
+//! - `action_trace`: 点击/滑动轨迹事件命令
+//! - `adb_auth`: ADB 授权丢失检测
+//! - `adb_files`: ADB 文件传输命令
+//! - `annotated_screenshot`: 带识别标注的截图命令
+//! - `report`: 任务报告生成命令
+//! - `benchmark`: 截图与识别性能测试命令
+//! - `breakpoint`: Pipeline 断点调试命令
+//! - `completion_webhook`: 任务队列完成 webhook 命令
+//! - `capture_mode`: Win32 截图防遮挡模式命令
+//! - `emulator_launch`: 连接前自动启动模拟器
+//! - `error`: 结构化错误码
+//! - `dry_run`: 任务 dry-run（只识别不操作）命令
+//! - `event_log`: 结构化事件日志（JSONL）命令
+//! - `failure_screenshot`: 任务失败自动截图命令
+//! - `frame_dedup`: 截图帧去重命令
+//! - `hot_override`: 运行中热更新 Pipeline override
+//! - `instance_log`: 按实例分文件保存的运行日志
+//! - `last_frame`: 任务结束时的最后一帧截图命令
+//! - `issue_bundle`: 识别失败问题包导出命令
+//! - `log_overlay`: 日志悬浮窗命令
+//! - `node_timing`: 节点执行耗时统计命令
+//! - `preempt`: 任务优先级抢占
+//! - `process_info`: 进程资源占用查询命令
+//! - `program_trust`: 外部程序信任列表命令
+//! - `proxy`: HTTP 代理解析（手动代理与系统代理）
+//! - `reco_stats`: 识别命中率统计命令
+//! - `recording`: 运行录像录制与导出命令
+//! - `resolution_check`: 连接后分辨率预检命令
+//! - `resource_check`: 资源静态检查命令
+//! - `resource_integrity`: 资源完整性快速校验命令
+//! - `resource_update`: 资源独立更新命令
+//! - `screenshot_preprocess`: 截图预处理命令
+//! - `share_code`: 任务配置分享码命令
+//! - `single_instance`: 单实例检测（命名 Mutex）
+//! - `speed`: 按实例的操作速度系数命令
 //! - `state`: 状态查询命令
+//! - `task_condition`: 任务跳过条件命令
+//! - `task_loop`: 任务队列循环执行
+//! - `task_retry`: 任务失败自动重试命令
+//! - `template_preview`: 模板图预览命令
+//! - `toolkit_config`: 设备搜索的 Toolkit 配置命令
 //! - `file_ops`: 文件操作命令
+//! - `unknown_screens`: 未知界面收集与打包命令
 //! - `update`: 更新安装相关命令
+//! - `watchdog`: 实例运行时长看门狗命令
+//! - `window_state`: Win32 目标窗口最小化检测命令
 //! - `download`: 下载相关命令
 //! - `system`: 系统相关命令
 //! - `tray`: 托盘相关命令
+//! - `usb_devices`: USB 真机识别
 
 pub mod types;
 pub mod utils;
 
+pub mod action_trace;
+pub mod adb_auth;
+pub mod adb_files;
+pub mod agent_job;
+pub mod annotated_screenshot;
 pub mod app_config;
+pub mod benchmark;
+pub mod breakpoint;
+pub mod capture_mode;
+pub mod completion_webhook;
 pub mod download;
+pub mod dry_run;
+pub mod emulator_launch;
+pub mod error;
+pub mod event_log;
+pub mod failure_screenshot;
 pub mod file_ops;
+pub mod frame_dedup;
+pub mod hot_override;
+pub mod instance_log;
+pub mod issue_bundle;
+pub mod last_frame;
+pub mod log_overlay;
 pub mod maa_agent;
 pub mod maa_core;
+pub mod node_timing;
+pub mod preempt;
+pub mod process_info;
+pub mod program_trust;
+pub mod proxy;
+pub mod reco_stats;
+pub mod recording;
+pub mod report;
+pub mod resolution_check;
+pub mod resource_check;
+pub mod resource_integrity;
+pub mod resource_update;
+pub mod screenshot_preprocess;
+pub mod share_code;
+pub mod single_instance;
+pub mod speed;
 pub mod state;
 pub mod system;
+pub mod task_condition;
+pub mod task_loop;
+pub mod task_retry;
+pub mod template_preview;
+pub mod toolkit_config;
 pub mod tray;
+pub mod unknown_screens;
 pub mod update;
+pub mod usb_devices;
+pub mod watchdog;
+pub mod window_state;
 
 // 重新导出类型（供 lib.rs 使用）
 pub use app_config::AppConfigState;
@@ -0,0 +1,228 @@
+//! 节点执行耗时统计
+//!
+//! 在 context sink 中按节点名累计执行次数与耗时（按实例区分），找出最费时的节点，
+//! 便于优化脚本（减少不必要的等待、缩小识别 ROI 等）。与 `reco_stats` 的命中率统计一起构成性能画像。
+//! 节点耗时取 `Node.PipelineNode.Starting` 到 `Succeeded/Failed` 的间隔，
+//! 识别与动作耗时分别取 `Node.Recognition.*`、`Node.Action.*` 的间隔。
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::Instant;
+
+use serde_json::Value;
+
+use super::types::NodeTimingStats;
+
+/// 计时的阶段
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Phase {
+    Node,
+    Recognition,
+    Action,
+}
+
+impl Phase {
+    /// 解析回调消息，返回阶段与是否为开始事件
+    fn parse(message: &str) -> Option<(Phase, bool)> {
+        let (phase, stage) = message.strip_prefix("Node.")?.split_once('.')?;
+        let phase = match phase {
+            "PipelineNode" => Phase::Node,
+            "Recognition" => Phase::Recognition,
+            "Action" => Phase::Action,
+            _ => return None,
+        };
+        match stage {
+            "Starting" => Some((phase, true)),
+            "Succeeded" | "Failed" => Some((phase, false)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+struct Timing {
+    count: u64,
+    total_ms: f64,
+    max_ms: f64,
+}
+
+impl Timing {
+    fn record(&mut self, ms: f64) {
+        self.count += 1;
+        self.total_ms += ms;
+        self.max_ms = self.max_ms.max(ms);
+    }
+
+    fn merge(&mut self, other: &Timing) {
+        self.count += other.count;
+        self.total_ms += other.total_ms;
+        self.max_ms = self.max_ms.max(other.max_ms);
+    }
+
+    fn avg_ms(&self) -> f64 {
+        if self.count > 0 {
+            self.total_ms / self.count as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+struct NodeAccumulator {
+    node: Timing,
+    recognition: Timing,
+    action: Timing,
+}
+
+impl NodeAccumulator {
+    fn timing_mut(&mut self, phase: Phase) -> &mut Timing {
+        match phase {
+            Phase::Node => &mut self.node,
+            Phase::Recognition => &mut self.recognition,
+            Phase::Action => &mut self.action,
+        }
+    }
+
+    fn merge(&mut self, other: &NodeAccumulator) {
+        self.node.merge(&other.node);
+        self.recognition.merge(&other.recognition);
+        self.action.merge(&other.action);
+    }
+
+    fn to_stats(&self, node: &str) -> NodeTimingStats {
+        NodeTimingStats {
+            node: node.to_string(),
+            runs: self.node.count,
+            total_ms: self.node.total_ms,
+            avg_ms: self.node.avg_ms(),
+            max_ms: self.node.max_ms,
+            recognitions: self.recognition.count,
+            recognition_total_ms: self.recognition.total_ms,
+            recognition_avg_ms: self.recognition.avg_ms(),
+            actions: self.action.count,
+            action_total_ms: self.action.total_ms,
+            action_avg_ms: self.action.avg_ms(),
+        }
+    }
+}
+
+/// 各实例的节点耗时统计（instance_id -> 节点名 -> 统计）
+static NODE_TIMING: LazyLock<Mutex<HashMap<String, HashMap<String, NodeAccumulator>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 进行中的阶段开始时间（(instance_id, 阶段, 节点名) -> 开始时间）
+static STARTED: LazyLock<Mutex<HashMap<(String, Phase, String), Instant>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 记录节点事件
+///
+/// 在 context sink 中调用，只处理 `Node.PipelineNode.*`、`Node.Recognition.*` 与 `Node.Action.*`
+pub fn record_node_event(instance_id: &str, message: &str, details: &str) {
+    let Some((phase, starting)) = Phase::parse(message) else {
+        return;
+    };
+    let Some(node) = serde_json::from_str::<Value>(details)
+        .ok()
+        .and_then(|v| v.get("name").and_then(|n| n.as_str()).map(String::from))
+    else {
+        return;
+    };
+
+    let key = (instance_id.to_string(), phase, node);
+    let Ok(mut started) = STARTED.lock() else {
+        return;
+    };
+    if starting {
+        started.insert(key, Instant::now());
+        return;
+    }
+    let Some(start) = started.remove(&key) else {
+        return;
+    };
+    drop(started);
+
+    let ms = start.elapsed().as_secs_f64() * 1000.0;
+    let (instance_id, phase, node) = key;
+    if let Ok(mut timing) = NODE_TIMING.lock() {
+        timing
+            .entry(instance_id)
+            .or_default()
+            .entry(node)
+            .or_default()
+            .timing_mut(phase)
+            .record(ms);
+    }
+}
+
+/// 实例当前正在执行的节点（已开始、尚未结束）
+pub fn running_nodes(instance_id: &str) -> Vec<String> {
+    STARTED
+        .lock()
+        .map(|started| {
+            started
+                .keys()
+                .filter(|(id, phase, _)| id == instance_id && *phase == Phase::Node)
+                .map(|(_, _, node)| node.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 查询节点执行耗时统计，按节点累计耗时从多到少排序
+///
+/// instance_id 为空时汇总所有实例
+#[tauri::command]
+pub fn maa_get_node_timing(instance_id: Option<String>) -> Result<Vec<NodeTimingStats>, String> {
+    let timing = NODE_TIMING.lock().map_err(|e| e.to_string())?;
+    let mut merged: HashMap<String, NodeAccumulator> = HashMap::new();
+    let sources: Vec<&HashMap<String, NodeAccumulator>> = match instance_id.as_deref() {
+        Some(id) => timing.get(id).into_iter().collect(),
+        None => timing.values().collect(),
+    };
+    for nodes in sources {
+        for (node, acc) in nodes {
+            merged.entry(node.clone()).or_default().merge(acc);
+        }
+    }
+
+    let mut result: Vec<NodeTimingStats> = merged
+        .iter()
+        .map(|(node, acc)| acc.to_stats(node))
+        .collect();
+    result.sort_by(|a, b| {
+        b.total_ms
+            .total_cmp(&a.total_ms)
+            .then(b.recognition_total_ms.total_cmp(&a.recognition_total_ms))
+            .then(a.node.cmp(&b.node))
+    });
+    Ok(result)
+}
+
+/// 移除实例的节点耗时统计（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut timing) = NODE_TIMING.lock() {
+        timing.remove(instance_id);
+    }
+    if let Ok(mut started) = STARTED.lock() {
+        started.retain(|(started_id, _, _), _| started_id != instance_id);
+    }
+}
+
+/// 重置节点执行耗时统计，instance_id 为空时重置所有实例
+#[tauri::command]
+pub fn maa_reset_node_timing(instance_id: Option<String>) -> Result<(), String> {
+    let mut timing = NODE_TIMING.lock().map_err(|e| e.to_string())?;
+    let mut started = STARTED.lock().map_err(|e| e.to_string())?;
+    match instance_id {
+        Some(id) => {
+            timing.remove(&id);
+            started.retain(|(started_id, _, _), _| started_id != &id);
+        }
+        None => {
+            timing.clear();
+            started.clear();
+        }
+    }
+    Ok(())
+}
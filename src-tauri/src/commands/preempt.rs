@@ -0,0 +1,276 @@
+//! 任务优先级抢占
+//!
+//! `maa_run_task` 可指定 priority（默认 0，队列任务取 `TaskConfig::priority`）。新任务优先级
+//! 高于当前执行中的任务时：先 post_stop 让 MaaFramework 在节点边界停下当前任务（不会停在动作中途），
+//! 等 tasker 空闲后按优先级重新排队：尚未结束的任务中优先级不低于新任务的先提交，然后是新任务，
+//! 其余被中断的任务按原顺序排在新任务之后。
+//! 被中断的任务会从入口重新执行；旧任务的失败回调不计入状态（与 Agent 崩溃重启相同）。
+//! 优先级不高于当前任务时照常追加到队尾。
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+use maa_framework::tasker::Tasker;
+use tauri::Emitter;
+
+use super::error::{MxuError, MxuErrorCode, WithErrorCode};
+use super::maa_core::run_task_impl;
+use super::types::{
+    InstanceRuntime, MaaState, PipelineOverrideRecord, ResubmittedTask, TaskConfig,
+    TaskPreemptedEvent,
+};
+
+/// 等待当前任务在节点边界停下的超时
+const STOP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 请求 tasker 在节点边界停下，并等待其空闲（抢占与失败重试共用），返回是否在超时前停下
+pub fn stop_at_node_boundary(tasker: &Tasker) -> bool {
+    let _ = tasker.post_stop();
+    let deadline = Instant::now() + STOP_TIMEOUT;
+    while tasker.running() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    !tasker.running()
+}
+
+/// 按优先级提交任务：优先级高于当前执行中的任务时抢占，否则追加到队尾
+///
+/// 抢占时会阻塞等待当前任务停下，需在阻塞线程中调用
+pub fn run_task_with_priority(
+    app: &tauri::AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    task: TaskConfig,
+) -> Result<i64, MxuError> {
+    let preempted = {
+        let mut instances = state.instances.lock().with_code(MxuErrorCode::Internal)?;
+        let instance = instances
+            .get_mut(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let running = instance.tasker.as_ref().is_some_and(|t| t.running());
+
+        // 当前执行中（或即将执行）的任务 = 第一个尚未结束的已提交任务
+        let finished: HashSet<i64> = instance
+            .task_report
+            .iter()
+            .filter(|r| r.status.is_some())
+            .map(|r| r.task_id)
+            .collect();
+        let unfinished: Vec<(i64, TaskConfig)> = instance
+            .submitted_tasks
+            .iter()
+            .filter(|(id, _)| !finished.contains(id) && instance.task_ids.contains(id))
+            .cloned()
+            .collect();
+
+        // 抢占过程中 tasker 正在停止，此时提交的任务可能被一并清掉
+        if instance.preempting {
+            return Err(MxuError::new(
+                MxuErrorCode::TaskPreempting,
+                "正在抢占执行其他任务，请稍后重试",
+            ));
+        }
+
+        let should_preempt = running
+            && !instance.stop_in_progress
+            && !instance.agent_restarting
+            && unfinished
+                .first()
+                .is_some_and(|(_, current)| task.priority > current.priority);
+        if should_preempt {
+            instance.preempting = true;
+            instance
+                .preempted_task_ids
+                .extend(unfinished.iter().map(|(id, _)| *id));
+            Some((unfinished, instance.tasker.clone()))
+        } else {
+            None
+        }
+    };
+
+    let Some((unfinished, tasker)) = preempted else {
+        return run_task_impl(app, state, instance_id, &task);
+    };
+
+    info!(
+        "[preempt] Task {} (priority {}) preempts {} queued task(s) on {}",
+        task.entry,
+        task.priority,
+        unfinished.len(),
+        instance_id
+    );
+
+    // 在节点边界停下当前任务
+    let stopped = tasker.as_ref().is_none_or(stop_at_node_boundary);
+
+    if !stopped {
+        // 停止请求已发出，被中断的任务按普通失败处理
+        warn!(
+            "[preempt] Current task did not stop in time on {}",
+            instance_id
+        );
+        if let Ok(mut instances) = state.instances.lock() {
+            if let Some(instance) = instances.get_mut(instance_id) {
+                for (id, _) in &unfinished {
+                    instance.preempted_task_ids.remove(id);
+                }
+                instance.preempting = false;
+            }
+        }
+        return Err(MxuError::new(
+            MxuErrorCode::TaskRunFailed,
+            "等待当前任务停止超时，未能抢占执行",
+        ));
+    }
+
+    // 移除被中断任务的旧记录（其回调已通过 preempted_task_ids 忽略），随后用新 ID 重新提交
+    let old_overrides = {
+        let mut instances = state.instances.lock().with_code(MxuErrorCode::Internal)?;
+        let instance = instances
+            .get_mut(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        instance.preempting = false;
+        remove_interrupted_tasks(instance, &unfinished)
+    };
+
+    // 优先级不低于新任务的先恢复，其余排在新任务之后，两组内部保持原顺序（均从入口重新执行）
+    let (ahead, behind): (Vec<_>, Vec<_>) = unfinished
+        .into_iter()
+        .zip(old_overrides)
+        .partition(|((_, config), _)| config.priority >= task.priority);
+    let (ahead, ahead_overrides): (Vec<_>, Vec<_>) = ahead.into_iter().unzip();
+    let (behind, behind_overrides): (Vec<_>, Vec<_>) = behind.into_iter().unzip();
+
+    let mut resubmitted = resubmit_tasks(app, state, instance_id, ahead, ahead_overrides);
+
+    // 新任务提交失败时不再恢复排在其后的任务，与普通提交失败一致交由前端处理
+    let task_id = run_task_impl(app, state, instance_id, &task)?;
+
+    resubmitted.extend(resubmit_tasks(
+        app,
+        state,
+        instance_id,
+        behind,
+        behind_overrides,
+    ));
+
+    let event = TaskPreemptedEvent {
+        instance_id: instance_id.to_string(),
+        task_id,
+        priority: task.priority,
+        resubmitted,
+    };
+    if let Err(e) = app.emit("maa-task-preempted", event) {
+        log::error!("Failed to emit maa-task-preempted: {}", e);
+    }
+
+    Ok(task_id)
+}
+
+/// 任务回调是否来自被抢占（或因失败重试而中断）的旧任务
+///
+/// 需在 `handle_task_callback` 之前调用：失败重试会在处理回调时把失败任务本身也加入
+/// `preempted_task_ids`，之后再判断会把真正的失败误标为被中断
+pub fn is_preempted_callback(
+    state: &MaaState,
+    instance_id: &str,
+    message: &str,
+    details: &str,
+) -> bool {
+    if !message.starts_with("Tasker.Task.") {
+        return false;
+    }
+    let Some(task_id) = serde_json::from_str::<serde_json::Value>(details)
+        .ok()
+        .and_then(|v| v.get("task_id").and_then(|id| id.as_i64()))
+    else {
+        return false;
+    };
+    state.instances.lock().ok().is_some_and(|instances| {
+        instances
+            .get(instance_id)
+            .is_some_and(|i| i.preempted_task_ids.contains(&task_id))
+    })
+}
+
+/// 在被抢占任务的回调详情中附加 `"preempted": true`，前端据此不把其失败记为任务失败
+pub fn tag_preempted_callback(details: Cow<'_, str>, preempted: bool) -> Cow<'_, str> {
+    if !preempted {
+        return details;
+    }
+    let Ok(serde_json::Value::Object(mut map)) = serde_json::from_str(&details) else {
+        return details;
+    };
+    map.insert("preempted".to_string(), serde_json::Value::Bool(true));
+    Cow::Owned(serde_json::Value::Object(map).to_string())
+}
+
+/// 移除被中断任务的旧记录（其回调应已通过 `preempted_task_ids` 忽略），
+/// 返回各任务的 override 记录，重新提交后随新 ID 保留
+pub fn remove_interrupted_tasks(
+    instance: &mut InstanceRuntime,
+    tasks: &[(i64, TaskConfig)],
+) -> Vec<Option<PipelineOverrideRecord>> {
+    let old_ids: HashSet<i64> = tasks.iter().map(|(id, _)| *id).collect();
+    instance.task_ids.retain(|id| !old_ids.contains(id));
+    instance
+        .submitted_tasks
+        .retain(|(id, _)| !old_ids.contains(id));
+    instance
+        .task_report
+        .retain(|r| !old_ids.contains(&r.task_id));
+    let old_overrides = tasks
+        .iter()
+        .map(|(id, _)| instance.pipeline_overrides.remove(id))
+        .collect();
+    let run_state = &mut instance.task_run_state;
+    run_state
+        .pending_task_ids
+        .retain(|id| !old_ids.contains(id));
+    run_state.mappings.retain(|id, _| !old_ids.contains(id));
+    if let Some(launch) = instance.agent_launch.as_mut() {
+        launch.tasks.retain(|(id, _)| !old_ids.contains(id));
+    }
+    old_overrides
+}
+
+/// 按原顺序用新 ID 重新提交被中断的任务（从入口重新执行），返回新旧 ID 对应关系
+///
+/// 某个任务提交失败时跳过该任务，继续提交其余任务
+pub fn resubmit_tasks(
+    app: &tauri::AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    tasks: Vec<(i64, TaskConfig)>,
+    old_overrides: Vec<Option<PipelineOverrideRecord>>,
+) -> Vec<ResubmittedTask> {
+    let mut resubmitted = Vec::new();
+    for ((old_task_id, config), old_override) in tasks.into_iter().zip(old_overrides) {
+        match run_task_impl(app, state, instance_id, &config) {
+            Ok(new_task_id) => {
+                if let Ok(mut instances) = state.instances.lock() {
+                    if let Some(instance) = instances.get_mut(instance_id) {
+                        if let Some(record) = old_override {
+                            instance.pipeline_overrides.insert(new_task_id, record);
+                        }
+                        if let Some(launch) = instance.agent_launch.as_mut() {
+                            launch.tasks.push((new_task_id, config.clone()));
+                        }
+                    }
+                }
+                resubmitted.push(ResubmittedTask {
+                    old_task_id,
+                    new_task_id,
+                });
+            }
+            Err(e) => warn!(
+                "Failed to resubmit task {} on {}: {}",
+                config.entry, instance_id, e
+            ),
+        }
+    }
+    resubmitted
+}
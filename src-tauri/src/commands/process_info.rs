@@ -0,0 +1,157 @@
+//! 进程资源占用查询命令
+//!
+//! 排查卡顿时查看 MXU 自身和 agent 子进程的 CPU / 内存 / 线程数 / 运行时长。
+//! CPU 使用率需要两次采样的差值：进程首次被查询时会间隔一小段时间采样两次，
+//! 之后复用上次的采样结果计算，前端轮询时开销很小。
+
+use std::collections::HashSet;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use tauri::State;
+
+use super::types::{MaaState, ProcessInfo};
+use super::utils::instance_label;
+
+/// 采样用的 System 与已采样过 CPU 的 PID
+struct Sampler {
+    system: System,
+    sampled: HashSet<Pid>,
+}
+
+static SAMPLER: LazyLock<Mutex<Sampler>> = LazyLock::new(|| {
+    Mutex::new(Sampler {
+        system: System::new(),
+        sampled: HashSet::new(),
+    })
+});
+
+/// 需要监视的进程：(pid, 角色, agent 所属实例名)
+fn monitored_pids(state: &MaaState) -> Vec<(u32, &'static str, Option<String>)> {
+    let mut result = vec![(std::process::id(), "mxu", None)];
+    let agents: Vec<(String, u32)> = state
+        .instances
+        .lock()
+        .map(|instances| {
+            instances
+                .iter()
+                .flat_map(|(id, instance)| {
+                    instance
+                        .agent_children
+                        .iter()
+                        .map(move |child| (id.clone(), child.id()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    // instance_label 会再次获取 instances 锁，必须在上面的锁释放后调用
+    for (instance_id, pid) in agents {
+        result.push((pid, "agent", Some(instance_label(state, &instance_id))));
+    }
+    result
+}
+
+/// 线程数（Windows 通过进程快照获取，Linux 由 sysinfo 提供）
+#[cfg(windows)]
+fn thread_count(pid: u32, _process: &sysinfo::Process) -> Option<usize> {
+    use winsafe::co::TH32CS;
+    use winsafe::HPROCESSLIST;
+
+    let mut snapshot = HPROCESSLIST::CreateToolhelp32Snapshot(TH32CS::SNAPPROCESS, None).ok()?;
+    let count = snapshot
+        .iter_processes()
+        .flatten()
+        .find(|entry| entry.th32ProcessID == pid)
+        .map(|entry| entry.cntThreads as usize);
+    count
+}
+
+#[cfg(not(windows))]
+fn thread_count(_pid: u32, process: &sysinfo::Process) -> Option<usize> {
+    process.tasks().map(|tasks| tasks.len())
+}
+
+/// 采样一组进程的资源占用，查不到的进程不返回
+fn sample_processes(targets: &[(u32, &str, Option<String>)]) -> Vec<ProcessInfo> {
+    let Ok(mut sampler) = SAMPLER.lock() else {
+        return Vec::new();
+    };
+    let pids: Vec<Pid> = targets
+        .iter()
+        .map(|(pid, _, _)| Pid::from_u32(*pid))
+        .collect();
+    let refresh_kind = ProcessRefreshKind::nothing().with_cpu().with_memory();
+
+    sampler
+        .system
+        .refresh_processes_specifics(ProcessesToUpdate::Some(&pids), true, refresh_kind);
+    // 首次查询的进程没有 CPU 基准，间隔最小采样周期再采一次
+    if pids.iter().any(|pid| !sampler.sampled.contains(pid)) {
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        sampler.system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&pids),
+            true,
+            refresh_kind,
+        );
+    }
+    // 只保留仍存在的进程，避免集合无限增长
+    let alive: HashSet<Pid> = sampler.system.processes().keys().copied().collect();
+    sampler.sampled.retain(|pid| alive.contains(pid));
+    sampler
+        .sampled
+        .extend(pids.iter().filter(|pid| alive.contains(pid)));
+
+    let cpu_count = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1) as f32;
+
+    targets
+        .iter()
+        .filter_map(|(pid, role, instance_name)| {
+            let process = sampler.system.process(Pid::from_u32(*pid))?;
+            Some(ProcessInfo {
+                pid: *pid,
+                name: process.name().to_string_lossy().to_string(),
+                role: role.to_string(),
+                instance_name: instance_name.clone(),
+                cpu_usage: process.cpu_usage() / cpu_count,
+                memory_bytes: process.memory(),
+                thread_count: thread_count(*pid, process),
+                run_time_secs: process.run_time(),
+            })
+        })
+        .collect()
+}
+
+/// 查询指定 PID 的资源占用（不传 PID 时查询 MXU 自身），进程不存在时返回错误
+#[tauri::command]
+pub async fn get_process_info(
+    state: State<'_, Arc<MaaState>>,
+    pid: Option<u32>,
+) -> Result<ProcessInfo, String> {
+    let pid = pid.unwrap_or_else(std::process::id);
+    let target = monitored_pids(&state)
+        .into_iter()
+        .find(|(p, _, _)| *p == pid)
+        .unwrap_or((pid, "other", None));
+
+    tokio::task::spawn_blocking(move || {
+        sample_processes(&[target])
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("进程不存在或无权访问: {}", pid))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 查询 MXU 自身和所有已登记 agent 进程的资源占用（资源监视面板用）
+#[tauri::command]
+pub async fn get_monitored_processes(
+    state: State<'_, Arc<MaaState>>,
+) -> Result<Vec<ProcessInfo>, String> {
+    let targets = monitored_pids(&state);
+    tokio::task::spawn_blocking(move || Ok(sample_processes(&targets)))
+        .await
+        .map_err(|e| e.to_string())?
+}
@@ -0,0 +1,334 @@
+//! 外部程序信任列表
+//!
+//! interface.json 可能来自第三方，其中的前置动作（run_action）、pretask 和 MXU_LAUNCH 能执行任意程序。
+//! 执行前按信任策略校验程序路径：
+//! - `trust_all`：不校验（兼容旧行为，默认）
+//! - `confirm`：不在信任列表中的程序弹窗确认，确认后加入信任列表
+//! - `strict`：只执行信任列表中的程序
+//!
+//! 信任列表按绝对路径匹配：不含路径分隔符的程序名按 PATH 查找，相对路径的实际解析
+//! 基准取决于调用方的工作目录，无法可靠对应到具体文件，在 confirm/strict 模式下直接拒绝。
+//! 通过 `cmd /c` 启动时（仅 Windows）参数同样会被 cmd 解释执行，因此按完整命令行
+//! （`cmd.exe /c <程序> <参数>`）校验和确认，信任程序本身不代表信任任意参数。
+//!
+//! 策略与信任列表持久化在数据目录的 trusted_programs.json。被拒绝执行时发出
+//! `maa-program-blocked` 事件供前端提示。
+
+use std::path::{Component, Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+use log::{info, warn};
+use tauri::{AppHandle, Emitter};
+
+use super::types::{ProgramBlockedEvent, ProgramTrustConfig, ProgramTrustMode};
+
+/// 信任配置的保存文件（位于数据目录）
+const TRUST_FILE: &str = "trusted_programs.json";
+
+/// 信任配置，首次访问时从文件载入
+static TRUST_CONFIG: LazyLock<Mutex<ProgramTrustConfig>> = LazyLock::new(|| {
+    let config = trust_file_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    Mutex::new(config)
+});
+
+/// 串行化确认弹窗，避免多个实例同时弹出同一程序的确认框
+static CONFIRM_LOCK: Mutex<()> = Mutex::new(());
+
+fn trust_file_path() -> Option<std::path::PathBuf> {
+    super::utils::get_app_data_dir()
+        .ok()
+        .map(|dir| dir.join(TRUST_FILE))
+}
+
+fn save_config(config: &ProgramTrustConfig) -> Result<(), String> {
+    let path = trust_file_path().ok_or("无法获取数据目录")?;
+    let content = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    std::fs::write(&path, content).map_err(|e| format!("保存信任列表失败: {}", e))
+}
+
+/// 在 PATH 中查找程序名（Windows 下无扩展名时按 PATHEXT 补全）
+fn find_in_path(name: &str) -> Option<PathBuf> {
+    let paths = std::env::var_os("PATH")?;
+    let extensions: Vec<String> = if cfg!(windows) && Path::new(name).extension().is_none() {
+        std::env::var("PATHEXT")
+            .unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string())
+            .split(';')
+            .filter(|ext| !ext.is_empty())
+            .map(|ext| ext.to_string())
+            .collect()
+    } else {
+        vec![String::new()]
+    };
+    std::env::split_paths(&paths)
+        .flat_map(|dir| {
+            extensions
+                .iter()
+                .map(move |ext| dir.join(format!("{}{}", name, ext)))
+        })
+        .find(|candidate| candidate.is_file())
+}
+
+/// 规范化程序路径：绝对路径取规范路径，单独的程序名按 PATH 解析，Windows 下去掉
+/// `\\?\` 前缀并忽略大小写。相对路径或找不到对应文件时返回 None
+fn normalize_program(program: &str) -> Option<String> {
+    let trimmed = program.trim();
+    let path = Path::new(trimmed);
+    let is_bare_name = matches!(
+        path.components().collect::<Vec<_>>().as_slice(),
+        [Component::Normal(_)]
+    ) && !trimmed.contains(['/', '\\']);
+    let resolved = if path.is_absolute() {
+        path.to_path_buf()
+    } else if is_bare_name {
+        find_in_path(trimmed)?
+    } else {
+        return None;
+    };
+    let normalized = std::fs::canonicalize(resolved)
+        .ok()?
+        .to_string_lossy()
+        .to_string();
+    if cfg!(windows) {
+        Some(
+            normalized
+                .strip_prefix(r"\\?\")
+                .unwrap_or(&normalized)
+                .to_lowercase(),
+        )
+    } else {
+        Some(normalized)
+    }
+}
+
+fn is_trusted(config: &ProgramTrustConfig, normalized: &str) -> bool {
+    config.programs.iter().any(|p| p == normalized)
+}
+
+fn add_trusted(normalized: String) -> Result<(), String> {
+    let mut config = TRUST_CONFIG.lock().map_err(|e| e.to_string())?;
+    if !is_trusted(&config, &normalized) {
+        config.programs.push(normalized);
+        save_config(&config)?;
+    }
+    Ok(())
+}
+
+/// 确认弹窗文案
+struct ConfirmTexts {
+    title: &'static str,
+    /// 文案模板，`{source}` 和 `{program}` 分别替换为来源和程序（命令行）
+    message: &'static str,
+    confirm: &'static str,
+    cancel: &'static str,
+}
+
+const CONFIRM_TEXTS_ZH_CN: ConfirmTexts = ConfirmTexts {
+    title: "执行外部程序",
+    message: "资源（{source}）请求执行不在信任列表中的程序：\n\n{program}\n\n仅在确认来源可靠时信任。信任后该程序以后不再询问。",
+    confirm: "信任并执行",
+    cancel: "拒绝",
+};
+
+const CONFIRM_TEXTS_ZH_TW: ConfirmTexts = ConfirmTexts {
+    title: "執行外部程式",
+    message: "資源（{source}）請求執行不在信任清單中的程式：\n\n{program}\n\n僅在確認來源可靠時信任。信任後該程式以後不再詢問。",
+    confirm: "信任並執行",
+    cancel: "拒絕",
+};
+
+const CONFIRM_TEXTS_JA: ConfirmTexts = ConfirmTexts {
+    title: "外部プログラムの実行",
+    message: "リソース（{source}）が信頼リストにないプログラムの実行を要求しています：\n\n{program}\n\n提供元が信頼できる場合のみ許可してください。信頼したプログラムは次回から確認されません。",
+    confirm: "信頼して実行",
+    cancel: "拒否",
+};
+
+const CONFIRM_TEXTS_KO: ConfirmTexts = ConfirmTexts {
+    title: "외부 프로그램 실행",
+    message: "리소스({source})가 신뢰 목록에 없는 프로그램의 실행을 요청했습니다:\n\n{program}\n\n출처를 확신할 수 있을 때만 신뢰하세요. 신뢰한 프로그램은 다시 묻지 않습니다.",
+    confirm: "신뢰하고 실행",
+    cancel: "거부",
+};
+
+const CONFIRM_TEXTS_EN: ConfirmTexts = ConfirmTexts {
+    title: "Run external program",
+    message: "The resource ({source}) wants to run a program that is not in the trusted list:\n\n{program}\n\nOnly trust it if you are sure about its source. Trusted programs will not be asked again.",
+    confirm: "Trust and run",
+    cancel: "Deny",
+};
+
+/// 按当前界面语言选择确认弹窗文案
+fn confirm_texts() -> &'static ConfirmTexts {
+    let language = crate::tray::current_language().to_ascii_lowercase();
+    if language.starts_with("zh-tw") || language.starts_with("zh-hk") {
+        &CONFIRM_TEXTS_ZH_TW
+    } else if language.starts_with("zh") {
+        &CONFIRM_TEXTS_ZH_CN
+    } else if language.starts_with("ja") {
+        &CONFIRM_TEXTS_JA
+    } else if language.starts_with("ko") {
+        &CONFIRM_TEXTS_KO
+    } else {
+        &CONFIRM_TEXTS_EN
+    }
+}
+
+/// 弹窗询问是否信任并执行该程序（阻塞直到用户选择，不能在主线程调用）
+fn ask_user(app: &AppHandle, program: &str, source: &str) -> bool {
+    use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+    let texts = confirm_texts();
+    let message = texts
+        .message
+        .replace("{source}", source)
+        .replace("{program}", program);
+    app.dialog()
+        .message(message)
+        .title(texts.title)
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            texts.confirm.to_string(),
+            texts.cancel.to_string(),
+        ))
+        .blocking_show()
+}
+
+/// 通过 `cmd /c` 启动时实际执行的完整命令行（用于展示和作为信任列表条目）
+fn cmd_command_line(program: &str, args: &str) -> String {
+    format!("cmd.exe /c {} {}", program, args.trim())
+        .trim_end()
+        .to_string()
+}
+
+fn emit_blocked(
+    app: &AppHandle,
+    instance_id: Option<&str>,
+    program: &str,
+    source: &str,
+    rejected_by_user: bool,
+) {
+    let event = ProgramBlockedEvent {
+        instance_id: instance_id.map(str::to_string),
+        program: program.to_string(),
+        source: source.to_string(),
+        rejected_by_user,
+    };
+    if let Err(e) = app.emit("maa-program-blocked", event) {
+        warn!("[program_trust] Failed to emit event: {}", e);
+    }
+}
+
+/// 执行外部程序前按信任策略校验，不允许执行时返回错误
+///
+/// `cmd_args` 为 Some 时表示通过 `cmd /c` 启动（仅 Windows 生效），按包含参数的完整命令行校验。
+/// confirm 模式下会阻塞等待用户在弹窗中选择，调用方需在非主线程中调用
+pub fn ensure_program_allowed(
+    app: &AppHandle,
+    instance_id: Option<&str>,
+    program: &str,
+    cmd_args: Option<&str>,
+    source: &str,
+) -> Result<(), String> {
+    let cmd_args = cmd_args.filter(|_| cfg!(windows));
+    let program_display = match cmd_args {
+        Some(args) => cmd_command_line(program, args),
+        None => program.to_string(),
+    };
+    let normalized = normalize_program(program).map(|normalized| match cmd_args {
+        Some(args) => cmd_command_line(&normalized, args),
+        None => normalized,
+    });
+    let program = program_display.as_str();
+    let mode = {
+        let config = TRUST_CONFIG.lock().map_err(|e| e.to_string())?;
+        let trusted = normalized
+            .as_deref()
+            .is_some_and(|normalized| is_trusted(&config, normalized));
+        if config.mode == ProgramTrustMode::TrustAll || trusted {
+            return Ok(());
+        }
+        config.mode
+    };
+
+    let Some(normalized) = normalized else {
+        warn!(
+            "[program_trust] Blocked unresolvable program from {}: {}",
+            source, program
+        );
+        emit_blocked(app, instance_id, program, source, false);
+        return Err(format!(
+            "无法确定程序的实际路径，已拒绝执行（请使用绝对路径）: {}",
+            program
+        ));
+    };
+
+    if mode == ProgramTrustMode::Strict {
+        warn!(
+            "[program_trust] Blocked untrusted program from {}: {}",
+            source, program
+        );
+        emit_blocked(app, instance_id, program, source, false);
+        return Err(format!("程序不在信任列表中，已拒绝执行: {}", program));
+    }
+
+    let _guard = CONFIRM_LOCK.lock().map_err(|e| e.to_string())?;
+    // 等待期间其他实例可能已确认信任同一程序
+    if TRUST_CONFIG
+        .lock()
+        .map(|config| is_trusted(&config, &normalized))
+        .unwrap_or(false)
+    {
+        return Ok(());
+    }
+    if ask_user(app, program, source) {
+        info!("[program_trust] Program trusted by user: {}", normalized);
+        add_trusted(normalized)?;
+        Ok(())
+    } else {
+        warn!("[program_trust] Program rejected by user: {}", program);
+        emit_blocked(app, instance_id, program, source, true);
+        Err(format!("用户拒绝执行程序: {}", program))
+    }
+}
+
+/// 获取外部程序信任策略与信任列表
+#[tauri::command]
+pub fn get_program_trust() -> Result<ProgramTrustConfig, String> {
+    TRUST_CONFIG
+        .lock()
+        .map(|config| config.clone())
+        .map_err(|e| e.to_string())
+}
+
+/// 设置外部程序信任策略（立即生效并持久化）
+#[tauri::command]
+pub fn set_program_trust_mode(mode: ProgramTrustMode) -> Result<(), String> {
+    info!("set_program_trust_mode: {:?}", mode);
+    let mut config = TRUST_CONFIG.lock().map_err(|e| e.to_string())?;
+    config.mode = mode;
+    save_config(&config)
+}
+
+/// 将程序加入信任列表
+#[tauri::command]
+pub fn trust_program(program: String) -> Result<(), String> {
+    info!("trust_program: {}", program);
+    if program.trim().is_empty() {
+        return Err("程序路径为空".to_string());
+    }
+    let normalized = normalize_program(&program)
+        .ok_or_else(|| format!("无法确定程序的实际路径（请使用绝对路径）: {}", program))?;
+    add_trusted(normalized)
+}
+
+/// 将程序移出信任列表（传入信任列表中保存的路径）
+#[tauri::command]
+pub fn untrust_program(program: String) -> Result<(), String> {
+    info!("untrust_program: {}", program);
+    let mut config = TRUST_CONFIG.lock().map_err(|e| e.to_string())?;
+    config.programs.retain(|p| *p != program);
+    save_config(&config)
+}
@@ -0,0 +1,275 @@
+//! HTTP 代理解析
+//!
+//! 下载、更新检查、资源更新与 WebView2 安装的 HTTP 客户端统一通过 `resolve_proxy` 选择代理：
+//! 1. 用户在设置中手动指定的代理
+//! 2. 系统代理：环境变量 `HTTPS_PROXY` / `HTTP_PROXY` / `ALL_PROXY`，
+//!    Windows 上再依次读取 Internet 选项（WinINet）与 WinHTTP 代理设置
+//! 3. 都探测不到时直连
+//!
+//! 手动代理由前端保存在配置文件中，同时写入 exe 目录下的 `cache/proxy_url`，
+//! 供 WebView2 启动前的运行时下载使用（此时前端尚未加载）。
+
+use log::{error, info};
+use serde::Serialize;
+
+/// 系统代理设置
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemProxy {
+    /// 代理地址（含协议前缀）
+    pub url: String,
+    /// 不走代理的地址列表（逗号分隔，reqwest `NoProxy` 格式）
+    pub no_proxy: Option<String>,
+    /// 来源：env / wininet / winhttp
+    pub source: &'static str,
+}
+
+/// 为没有协议前缀的代理地址补上 `http://`
+fn with_scheme(server: &str) -> String {
+    if server.contains("://") {
+        server.to_string()
+    } else {
+        format!("http://{}", server)
+    }
+}
+
+/// 从环境变量读取代理
+fn proxy_from_env() -> Option<SystemProxy> {
+    let url = [
+        "HTTPS_PROXY",
+        "https_proxy",
+        "HTTP_PROXY",
+        "http_proxy",
+        "ALL_PROXY",
+        "all_proxy",
+    ]
+    .iter()
+    .filter_map(|key| std::env::var(key).ok())
+    .map(|v| v.trim().to_string())
+    .find(|v| !v.is_empty())?;
+    let no_proxy = ["NO_PROXY", "no_proxy"]
+        .iter()
+        .filter_map(|key| std::env::var(key).ok())
+        .find(|v| !v.trim().is_empty());
+    Some(SystemProxy {
+        url: with_scheme(&url),
+        no_proxy,
+        source: "env",
+    })
+}
+
+/// 解析 Windows 代理服务器字符串
+///
+/// 形如 `host:port`，或按协议分别设置的 `http=host:port;https=host:port;socks=host:port`，
+/// 后者优先取 https，其次 http，最后 socks
+#[cfg(windows)]
+fn parse_windows_proxy_server(server: &str) -> Option<String> {
+    let server = server.trim();
+    if server.is_empty() {
+        return None;
+    }
+    if !server.contains('=') {
+        return Some(with_scheme(server.split(';').next()?.trim()));
+    }
+    let entries: Vec<(String, &str)> = server
+        .split(';')
+        .filter_map(|entry| {
+            let (scheme, addr) = entry.split_once('=')?;
+            let addr = addr.trim();
+            (!addr.is_empty()).then(|| (scheme.trim().to_ascii_lowercase(), addr))
+        })
+        .collect();
+    for scheme in ["https", "http"] {
+        if let Some((_, addr)) = entries.iter().find(|(s, _)| s == scheme) {
+            return Some(with_scheme(addr));
+        }
+    }
+    entries
+        .iter()
+        .find(|(s, _)| s == "socks")
+        .map(|(_, addr)| format!("socks5://{}", addr))
+}
+
+/// 将 Windows 的代理例外列表（分号分隔，支持 `*.` 前缀与 `<local>`）转换为 `NoProxy` 格式
+///
+/// 含其他通配符的条目（如 `192.168.*`）无法表达，直接忽略
+#[cfg(windows)]
+fn convert_windows_bypass(bypass: &str) -> Option<String> {
+    let entries: Vec<&str> = bypass
+        .split([';', ' '])
+        .map(str::trim)
+        .filter(|e| !e.is_empty() && *e != "<local>")
+        .map(|e| e.strip_prefix('*').unwrap_or(e))
+        .filter(|e| !e.contains('*'))
+        .collect();
+    (!entries.is_empty()).then(|| entries.join(","))
+}
+
+/// 从当前用户的 Internet 选项（WinINet）读取代理
+#[cfg(windows)]
+fn proxy_from_wininet() -> Option<SystemProxy> {
+    use winsafe::co::{KEY, REG_OPTION, RRF};
+    use winsafe::{RegistryValue, HKEY};
+
+    let hkey = HKEY::CURRENT_USER
+        .RegOpenKeyEx(
+            Some(r"Software\Microsoft\Windows\CurrentVersion\Internet Settings"),
+            REG_OPTION::NoValue,
+            KEY::READ,
+        )
+        .ok()?;
+    match hkey.RegGetValue(None, Some("ProxyEnable"), RRF::RT_REG_DWORD) {
+        Ok(RegistryValue::Dword(enabled)) if enabled != 0 => {}
+        _ => return None,
+    }
+    let server = match hkey.RegGetValue(None, Some("ProxyServer"), RRF::RT_REG_SZ) {
+        Ok(RegistryValue::Sz(server)) => server,
+        _ => return None,
+    };
+    let no_proxy = match hkey.RegGetValue(None, Some("ProxyOverride"), RRF::RT_REG_SZ) {
+        Ok(RegistryValue::Sz(bypass)) => convert_windows_bypass(&bypass),
+        _ => None,
+    };
+    Some(SystemProxy {
+        url: parse_windows_proxy_server(&server)?,
+        no_proxy,
+        source: "wininet",
+    })
+}
+
+/// 从 WinHTTP 代理设置（`netsh winhttp set proxy` 写入的注册表二进制值）读取代理
+///
+/// 结构：结构大小、计数器、标志（0x2 表示使用代理）各 4 字节，
+/// 随后是长度前缀的代理服务器与例外列表（ASCII）
+#[cfg(windows)]
+fn proxy_from_winhttp() -> Option<SystemProxy> {
+    use winsafe::co::{KEY, REG_OPTION, RRF};
+    use winsafe::{RegistryValue, HKEY};
+
+    let hkey = HKEY::LOCAL_MACHINE
+        .RegOpenKeyEx(
+            Some(r"SOFTWARE\Microsoft\Windows\CurrentVersion\Internet Settings\Connections"),
+            REG_OPTION::NoValue,
+            KEY::READ,
+        )
+        .ok()?;
+    let data = match hkey.RegGetValue(None, Some("WinHttpSettings"), RRF::RT_REG_BINARY) {
+        Ok(RegistryValue::Binary(data)) => data,
+        _ => return None,
+    };
+
+    let read_u32 = |offset: usize| -> Option<usize> {
+        let bytes: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+        Some(u32::from_le_bytes(bytes) as usize)
+    };
+    let read_str = |offset: usize| -> Option<(String, usize)> {
+        let len = read_u32(offset)?;
+        let bytes = data.get(offset + 4..offset + 4 + len)?;
+        Some((
+            String::from_utf8_lossy(bytes).into_owned(),
+            offset + 4 + len,
+        ))
+    };
+
+    if read_u32(8)? & 0x2 == 0 {
+        return None;
+    }
+    let (server, next) = read_str(12)?;
+    let no_proxy = read_str(next).and_then(|(bypass, _)| convert_windows_bypass(&bypass));
+    Some(SystemProxy {
+        url: parse_windows_proxy_server(&server)?,
+        no_proxy,
+        source: "winhttp",
+    })
+}
+
+/// 探测系统代理，探测不到时返回 None
+pub fn detect_system_proxy() -> Option<SystemProxy> {
+    if let Some(proxy) = proxy_from_env() {
+        return Some(proxy);
+    }
+    #[cfg(windows)]
+    {
+        if let Some(proxy) = proxy_from_wininet().or_else(proxy_from_winhttp) {
+            return Some(proxy);
+        }
+    }
+    None
+}
+
+/// 选择 HTTP 客户端使用的代理：手动代理优先，其次系统代理，返回 None 表示直连
+///
+/// 调用方在返回 None 时应调用 `no_proxy()`，避免 reqwest 再自行读取系统代理
+pub fn resolve_proxy(manual: Option<&str>, tag: &str) -> Result<Option<reqwest::Proxy>, String> {
+    if let Some(manual) = manual.map(str::trim).filter(|p| !p.is_empty()) {
+        info!("[{}] 使用代理: {}", tag, manual);
+        let proxy = reqwest::Proxy::all(manual).map_err(|e| {
+            error!("代理配置失败: {} (代理地址: {})", e, manual);
+            format!(
+                "代理配置失败: {}。请检查代理格式是否正确（支持 http:// 或 socks5://）",
+                e
+            )
+        })?;
+        return Ok(Some(proxy));
+    }
+
+    let Some(system) = detect_system_proxy() else {
+        info!("[{}] 未检测到系统代理，直连", tag);
+        return Ok(None);
+    };
+    // 系统代理格式有误时不影响直连
+    match reqwest::Proxy::all(&system.url) {
+        Ok(proxy) => {
+            info!("[{}] 使用系统代理 ({}): {}", tag, system.source, system.url);
+            let no_proxy = system
+                .no_proxy
+                .as_deref()
+                .and_then(reqwest::NoProxy::from_string);
+            Ok(Some(proxy.no_proxy(no_proxy)))
+        }
+        Err(e) => {
+            error!(
+                "[{}] 系统代理无效，改为直连: {} (代理地址: {})",
+                tag, e, system.url
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// 保存手动代理的文件（位于 exe 目录 cache 下，WebView2 运行时下载时读取）
+fn saved_proxy_file() -> Option<std::path::PathBuf> {
+    let exe_path = std::env::current_exe().ok()?;
+    Some(exe_path.parent()?.join("cache").join("proxy_url"))
+}
+
+/// 读取保存的手动代理
+pub fn get_saved_proxy() -> Option<String> {
+    let content = std::fs::read_to_string(saved_proxy_file()?).ok()?;
+    let url = content.trim();
+    (!url.is_empty()).then(|| url.to_string())
+}
+
+/// 保存手动代理（为空时删除），供前端加载前的 WebView2 运行时下载使用
+#[tauri::command]
+pub fn set_saved_proxy(url: Option<String>) -> Result<(), String> {
+    let file = saved_proxy_file().ok_or("无法获取程序目录")?;
+    match url.as_deref().map(str::trim).filter(|u| !u.is_empty()) {
+        Some(url) => {
+            if let Some(parent) = file.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| format!("无法创建目录: {}", e))?;
+            }
+            std::fs::write(&file, url).map_err(|e| format!("写入代理设置失败: {}", e))?;
+        }
+        None if file.exists() => {
+            std::fs::remove_file(&file).map_err(|e| format!("删除代理设置失败: {}", e))?;
+        }
+        None => {}
+    }
+    Ok(())
+}
+
+/// 查询探测到的系统代理（用于设置页提示），探测不到时返回 None
+#[tauri::command]
+pub fn get_system_proxy() -> Option<SystemProxy> {
+    detect_system_proxy()
+}
@@ -0,0 +1,202 @@
+//! 识别命中率统计
+//!
+//! 在 context sink 中累计每个识别节点的识别次数、命中次数与匹配分数分布（按实例区分），
+//! 供调整 pipeline 阈值时参考：常不命中且分数贴近阈值说明阈值偏高，命中分数普遍偏低则可能误命中。
+//! 分数取自识别详情中的最高分（best，未命中时取 all 中的最高分），无分数的算法只统计命中次数。
+//! 取到的识别详情同时交给 `annotated_screenshot` 记录，用于保存带标注的截图。
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use serde_json::Value;
+
+use super::annotated_screenshot;
+use super::types::{MaaState, RecoNodeStats};
+
+/// 分数分布的分段数
+const HISTOGRAM_BUCKETS: usize = 10;
+
+#[derive(Default, Clone)]
+struct NodeAccumulator {
+    attempts: u64,
+    hits: u64,
+    scored: u64,
+    score_sum: f64,
+    hit_scored: u64,
+    hit_score_sum: f64,
+    min_score: Option<f64>,
+    max_score: Option<f64>,
+    histogram: [u64; HISTOGRAM_BUCKETS],
+}
+
+impl NodeAccumulator {
+    fn record(&mut self, hit: bool, score: Option<f64>) {
+        self.attempts += 1;
+        if hit {
+            self.hits += 1;
+        }
+        let Some(score) = score else {
+            return;
+        };
+        self.scored += 1;
+        self.score_sum += score;
+        if hit {
+            self.hit_scored += 1;
+            self.hit_score_sum += score;
+        }
+        self.min_score = Some(self.min_score.map_or(score, |m| m.min(score)));
+        self.max_score = Some(self.max_score.map_or(score, |m| m.max(score)));
+        let bucket = ((score.clamp(0.0, 1.0) * HISTOGRAM_BUCKETS as f64) as usize)
+            .min(HISTOGRAM_BUCKETS - 1);
+        self.histogram[bucket] += 1;
+    }
+
+    fn merge(&mut self, other: &NodeAccumulator) {
+        self.attempts += other.attempts;
+        self.hits += other.hits;
+        self.scored += other.scored;
+        self.score_sum += other.score_sum;
+        self.hit_scored += other.hit_scored;
+        self.hit_score_sum += other.hit_score_sum;
+        self.min_score = match (self.min_score, other.min_score) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.max_score = match (self.max_score, other.max_score) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        for (bucket, count) in self.histogram.iter_mut().zip(other.histogram) {
+            *bucket += count;
+        }
+    }
+
+    fn to_stats(&self, node: &str) -> RecoNodeStats {
+        let avg = |sum: f64, count: u64| (count > 0).then(|| sum / count as f64);
+        RecoNodeStats {
+            node: node.to_string(),
+            attempts: self.attempts,
+            hits: self.hits,
+            scored: self.scored,
+            avg_score: avg(self.score_sum, self.scored),
+            avg_hit_score: avg(self.hit_score_sum, self.hit_scored),
+            min_score: self.min_score,
+            max_score: self.max_score,
+            score_histogram: self.histogram.to_vec(),
+        }
+    }
+}
+
+/// 各实例的节点统计（instance_id -> 节点名 -> 统计）
+static RECO_STATS: LazyLock<Mutex<HashMap<String, HashMap<String, NodeAccumulator>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 记录一次识别结果
+///
+/// 在 context sink 中调用，只处理 `Node.Recognition.Succeeded/Failed`（`Node.NextList.Starting`
+/// 时清空标注截图记录的上一轮识别结果）
+pub fn record_recognition(state: &MaaState, instance_id: &str, message: &str, details: &str) {
+    let hit = match message {
+        "Node.Recognition.Succeeded" => true,
+        "Node.Recognition.Failed" => false,
+        "Node.NextList.Starting" => {
+            annotated_screenshot::clear_recognitions(instance_id);
+            return;
+        }
+        _ => return,
+    };
+    let Ok(details) = serde_json::from_str::<Value>(details) else {
+        return;
+    };
+    let Some(node) = details.get("name").and_then(|v| v.as_str()) else {
+        return;
+    };
+    let detail = details
+        .get("reco_id")
+        .and_then(|v| v.as_i64())
+        .and_then(|reco_id| recognition_detail(state, instance_id, reco_id));
+    let score = detail.as_ref().and_then(best_score);
+    if let Some(detail) = &detail {
+        annotated_screenshot::record_recognition(instance_id, node, hit, detail);
+    }
+
+    if let Ok(mut stats) = RECO_STATS.lock() {
+        stats
+            .entry(instance_id.to_string())
+            .or_default()
+            .entry(node.to_string())
+            .or_default()
+            .record(hit, score);
+    }
+}
+
+/// 查询识别详情（算法输出的 JSON，含 all / best / filtered）
+fn recognition_detail(state: &MaaState, instance_id: &str, reco_id: i64) -> Option<Value> {
+    let tasker = {
+        let instances = state.instances.lock().ok()?;
+        instances.get(instance_id)?.tasker.clone()
+    }?;
+    let detail = tasker.get_recognition_detail(reco_id).ok()??;
+    // detail 可能是 JSON 对象或 JSON 字符串
+    match serde_json::to_value(&detail.detail).ok()? {
+        Value::String(raw) => serde_json::from_str(&raw).ok(),
+        value => Some(value),
+    }
+}
+
+/// 识别详情中的最高分：优先取 best，未命中时 best 为空，取 all 中的最高分
+pub fn best_score(detail: &Value) -> Option<f64> {
+    let score_of = |v: &Value| v.get("score").and_then(|s| s.as_f64());
+    if let Some(score) = detail.get("best").and_then(score_of) {
+        return Some(score);
+    }
+    detail
+        .get("all")
+        .and_then(|all| all.as_array())
+        .and_then(|all| all.iter().filter_map(score_of).reduce(f64::max))
+}
+
+/// 查询识别命中率统计，按识别次数从多到少排序
+///
+/// instance_id 为空时汇总所有实例
+#[tauri::command]
+pub fn maa_get_reco_stats(instance_id: Option<String>) -> Result<Vec<RecoNodeStats>, String> {
+    let stats = RECO_STATS.lock().map_err(|e| e.to_string())?;
+    let mut merged: HashMap<String, NodeAccumulator> = HashMap::new();
+    let sources: Vec<&HashMap<String, NodeAccumulator>> = match instance_id.as_deref() {
+        Some(id) => stats.get(id).into_iter().collect(),
+        None => stats.values().collect(),
+    };
+    for nodes in sources {
+        for (node, acc) in nodes {
+            merged.entry(node.clone()).or_default().merge(acc);
+        }
+    }
+
+    let mut result: Vec<RecoNodeStats> = merged
+        .iter()
+        .map(|(node, acc)| acc.to_stats(node))
+        .collect();
+    result.sort_by(|a, b| b.attempts.cmp(&a.attempts).then(a.node.cmp(&b.node)));
+    Ok(result)
+}
+
+/// 移除实例的识别命中率统计（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut stats) = RECO_STATS.lock() {
+        stats.remove(instance_id);
+    }
+}
+
+/// 重置识别命中率统计，instance_id 为空时重置所有实例
+#[tauri::command]
+pub fn maa_reset_reco_stats(instance_id: Option<String>) -> Result<(), String> {
+    let mut stats = RECO_STATS.lock().map_err(|e| e.to_string())?;
+    match instance_id {
+        Some(id) => {
+            stats.remove(&id);
+        }
+        None => stats.clear(),
+    }
+    Ok(())
+}
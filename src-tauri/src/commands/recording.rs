@@ -0,0 +1,506 @@
+//! 运行录像
+//!
+//! 排查“偶尔点错位置”这类间歇性问题时，录下任务运行期间的截图序列并导出为 GIF 或 mp4。
+//! 录制期间以非本地订阅者身份订阅后端截图服务（任务空闲时也能持续截图），按帧率采样
+//! 控制器的缓存截图写入临时目录，内容未变化的截图只延长上一帧的显示时长；
+//! 帧数或磁盘占用超过上限时丢弃最旧的帧。
+//!
+//! 停止时用 image 的 GIF 编码器合成 GIF（逐帧 NeuQuant 量化调色板）；要求 mp4 且 PATH 中
+//! 有 ffmpeg 时改用 ffmpeg 合成
+
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame, RgbaImage};
+use log::{debug, info, warn};
+use tauri::State;
+
+use super::types::{ClipExportResult, ClipFormat, MaaState};
+use super::utils::{downscale_rgba, get_logs_dir};
+
+/// 默认采样帧率
+const DEFAULT_FPS: u32 = 2;
+/// 采样帧率上限（截图服务的最小间隔为 100ms）
+const MAX_FPS: u32 = 10;
+/// 最多保留的帧数
+const MAX_FRAMES: usize = 600;
+/// 临时目录中帧文件的总大小上限（字节）
+const MAX_TOTAL_BYTES: u64 = 256 * 1024 * 1024;
+/// GIF 帧的最长边（像素）
+const MAX_GIF_SIDE: u32 = 640;
+/// GIF 颜色量化速度（1–30，越小质量越高、越慢）
+const GIF_QUANTIZE_SPEED: i32 = 10;
+/// GIF 帧的最短显示时长
+const MIN_GIF_DELAY: Duration = Duration::from_millis(20);
+/// 录制时在截图服务中使用的订阅者 ID
+const SUBSCRIBER_ID: &str = "clip-recorder";
+
+/// 已写入临时目录的一帧
+struct ClipFrame {
+    path: PathBuf,
+    size: u64,
+    captured_at: Instant,
+}
+
+/// 录制线程结束时交回的帧序列
+struct ClipFrames {
+    frames: VecDeque<ClipFrame>,
+    /// 超出上限而丢弃的最旧帧数
+    dropped: u32,
+    ended_at: Instant,
+}
+
+struct ClipRecorder {
+    stop: Arc<AtomicBool>,
+    worker: JoinHandle<ClipFrames>,
+    dir: PathBuf,
+}
+
+/// 正在录制的实例
+static RECORDERS: LazyLock<Mutex<HashMap<String, ClipRecorder>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 实例录制帧的临时目录
+fn clip_temp_dir(instance_id: &str) -> PathBuf {
+    let name: String = instance_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    std::env::temp_dir().join("mxu-clips").join(name)
+}
+
+/// 开始录制的内部实现
+fn start_recording_impl(
+    state: Arc<MaaState>,
+    instance_id: String,
+    fps: u32,
+    handle: tokio::runtime::Handle,
+) -> Result<(), String> {
+    {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(&instance_id).ok_or("Instance not found")?;
+        if instance.controller.is_none() {
+            return Err("Controller not connected".to_string());
+        }
+    }
+
+    let mut recorders = RECORDERS.lock().map_err(|e| e.to_string())?;
+    if recorders.contains_key(&instance_id) {
+        return Err("该实例正在录制".to_string());
+    }
+
+    let dir = clip_temp_dir(&instance_id);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("创建录像临时目录失败: {}", e))?;
+
+    let interval = Duration::from_millis(1000 / fps as u64);
+    state.screenshot_service.subscribe(
+        Arc::clone(&state),
+        instance_id.clone(),
+        SUBSCRIBER_ID.to_string(),
+        interval.as_millis() as u64,
+        false,
+        handle,
+    );
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let worker = {
+        let state = Arc::clone(&state);
+        let instance_id = instance_id.clone();
+        let dir = dir.clone();
+        let stop = Arc::clone(&stop);
+        std::thread::Builder::new()
+            .name("clip-recorder".to_string())
+            .spawn(move || record_loop(&state, &instance_id, &dir, interval, &stop))
+    };
+    let worker = match worker {
+        Ok(worker) => worker,
+        Err(e) => {
+            state
+                .screenshot_service
+                .unsubscribe(&instance_id, SUBSCRIBER_ID);
+            return Err(format!("启动录制线程失败: {}", e));
+        }
+    };
+
+    info!(
+        "[recording] Started: instance={}, fps={}, dir={:?}",
+        instance_id, fps, dir
+    );
+    recorders.insert(instance_id, ClipRecorder { stop, worker, dir });
+    Ok(())
+}
+
+/// 录制线程：按间隔采样缓存截图，帧内容变化时写入临时目录
+fn record_loop(
+    state: &MaaState,
+    instance_id: &str,
+    dir: &Path,
+    interval: Duration,
+    stop: &AtomicBool,
+) -> ClipFrames {
+    let mut frames: VecDeque<ClipFrame> = VecDeque::new();
+    let mut total_bytes = 0u64;
+    let mut dropped = 0u32;
+    let mut last_hash = None;
+    let mut index = 0u64;
+
+    while !stop.load(Ordering::SeqCst) {
+        let started = Instant::now();
+        // 刷新订阅，避免录制时间较长时被截图服务超时清理
+        state.screenshot_service.touch(instance_id, SUBSCRIBER_ID);
+
+        let controller = state
+            .instances
+            .lock()
+            .ok()
+            .and_then(|instances| instances.get(instance_id)?.controller.clone());
+        let Some(controller) = controller else {
+            info!(
+                "[recording] {}: controller disconnected, recording stopped",
+                instance_id
+            );
+            break;
+        };
+
+        let data = controller
+            .cached_image()
+            .ok()
+            .and_then(|buf| buf.to_vec())
+            .filter(|data| !data.is_empty());
+        if let Some(data) = data {
+            let hash = {
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                data.hash(&mut hasher);
+                hasher.finish()
+            };
+            if last_hash != Some(hash) {
+                last_hash = Some(hash);
+                index += 1;
+                let path = dir.join(format!("frame_{:06}.png", index));
+                match std::fs::write(&path, &data) {
+                    Ok(()) => {
+                        total_bytes += data.len() as u64;
+                        frames.push_back(ClipFrame {
+                            path,
+                            size: data.len() as u64,
+                            captured_at: started,
+                        });
+                    }
+                    Err(e) => warn!("[recording] Failed to write frame {:?}: {}", path, e),
+                }
+                // 超出上限时丢弃最旧的帧
+                while frames.len() > MAX_FRAMES || total_bytes > MAX_TOTAL_BYTES {
+                    let Some(oldest) = frames.pop_front() else {
+                        break;
+                    };
+                    total_bytes -= oldest.size;
+                    dropped += 1;
+                    let _ = std::fs::remove_file(&oldest.path);
+                }
+            }
+        }
+
+        if let Some(rest) = interval.checked_sub(started.elapsed()) {
+            std::thread::sleep(rest);
+        }
+    }
+
+    ClipFrames {
+        frames,
+        dropped,
+        ended_at: Instant::now(),
+    }
+}
+
+/// 停止录制并导出的内部实现
+fn stop_and_export_impl(
+    state: &MaaState,
+    instance_id: &str,
+    format: ClipFormat,
+) -> Result<ClipExportResult, String> {
+    let recorder = RECORDERS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(instance_id)
+        .ok_or("该实例未在录制")?;
+    recorder.stop.store(true, Ordering::SeqCst);
+    state
+        .screenshot_service
+        .unsubscribe(instance_id, SUBSCRIBER_ID);
+    let clip = recorder
+        .worker
+        .join()
+        .map_err(|_| "录制线程异常退出".to_string());
+
+    let result = clip.and_then(|clip| export_clip(&clip, &recorder.dir, format));
+    let _ = std::fs::remove_dir_all(&recorder.dir);
+    result
+}
+
+/// 将帧序列导出到日志目录下的 clips 子目录
+fn export_clip(
+    clip: &ClipFrames,
+    temp_dir: &Path,
+    format: ClipFormat,
+) -> Result<ClipExportResult, String> {
+    let frames: Vec<&ClipFrame> = clip.frames.iter().collect();
+    let Some(first) = frames.first() else {
+        return Err("录制期间没有获取到截图".to_string());
+    };
+    // 每帧显示到下一帧截取为止，最后一帧显示到停止录制
+    let delays: Vec<Duration> = frames
+        .iter()
+        .enumerate()
+        .map(|(i, frame)| {
+            let next = frames.get(i + 1).map_or(clip.ended_at, |f| f.captured_at);
+            next.duration_since(frame.captured_at)
+        })
+        .collect();
+    let duration_ms = clip.ended_at.duration_since(first.captured_at).as_millis() as u64;
+
+    let dir = get_logs_dir().join("clips");
+    std::fs::create_dir_all(&dir).map_err(|e| format!("创建录像目录失败: {}", e))?;
+    let stem = format!("clip_{}", chrono::Local::now().format("%Y%m%d_%H%M%S"));
+
+    if format == ClipFormat::Mp4 {
+        if ffmpeg_available() {
+            let path = dir.join(format!("{}.mp4", stem));
+            match encode_mp4(&frames, &delays, temp_dir, &path) {
+                Ok(()) => {
+                    info!(
+                        "[recording] Exported {} frame(s) to {:?}",
+                        frames.len(),
+                        path
+                    );
+                    return Ok(ClipExportResult {
+                        path: path.to_string_lossy().to_string(),
+                        format: ClipFormat::Mp4,
+                        frames: frames.len() as u32,
+                        dropped_frames: clip.dropped,
+                        duration_ms,
+                    });
+                }
+                Err(e) => warn!(
+                    "[recording] ffmpeg export failed, falling back to GIF: {}",
+                    e
+                ),
+            }
+        } else {
+            warn!("[recording] ffmpeg not found in PATH, falling back to GIF");
+        }
+    }
+
+    let path = dir.join(format!("{}.gif", stem));
+    let written = encode_gif(&frames, &delays, &path)?;
+    info!("[recording] Exported {} frame(s) to {:?}", written, path);
+    Ok(ClipExportResult {
+        path: path.to_string_lossy().to_string(),
+        format: ClipFormat::Gif,
+        frames: written,
+        dropped_frames: clip.dropped,
+        duration_ms,
+    })
+}
+
+// ============================================================================
+// mp4（ffmpeg）
+// ============================================================================
+
+fn ffmpeg_command() -> Command {
+    #[allow(unused_mut)]
+    let mut command = Command::new("ffmpeg");
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    command
+}
+
+fn ffmpeg_available() -> bool {
+    ffmpeg_command()
+        .arg("-version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// 用 ffmpeg concat 输入合成可变帧率的 mp4
+fn encode_mp4(
+    frames: &[&ClipFrame],
+    delays: &[Duration],
+    temp_dir: &Path,
+    path: &Path,
+) -> Result<(), String> {
+    let quote = |p: &Path| format!("'{}'", p.to_string_lossy().replace('\'', "'\\''"));
+    let mut list = String::new();
+    for (frame, delay) in frames.iter().zip(delays) {
+        list.push_str(&format!(
+            "file {}\nduration {:.3}\n",
+            quote(&frame.path),
+            delay.as_secs_f64()
+        ));
+    }
+    // concat 输入会忽略最后一个 duration，需再列一次最后一帧
+    if let Some(last) = frames.last() {
+        list.push_str(&format!("file {}\n", quote(&last.path)));
+    }
+    let list_path = temp_dir.join("frames.txt");
+    std::fs::write(&list_path, list).map_err(|e| e.to_string())?;
+
+    let output = ffmpeg_command()
+        .args(["-y", "-loglevel", "error"])
+        .args(["-f", "concat", "-safe", "0", "-i"])
+        .arg(&list_path)
+        // yuv420p 要求宽高为偶数
+        .args([
+            "-vf",
+            "scale=trunc(iw/2)*2:trunc(ih/2)*2,format=yuv420p",
+            "-vsync",
+            "vfr",
+        ])
+        .arg(path)
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(())
+}
+
+// ============================================================================
+// GIF 编码
+// ============================================================================
+
+/// 逐帧解码 PNG、缩小并写入 GIF，返回写入的帧数。分辨率与首帧不同的帧被跳过
+fn encode_gif(frames: &[&ClipFrame], delays: &[Duration], path: &Path) -> Result<u32, String> {
+    let file = File::create(path).map_err(|e| format!("创建 GIF 文件失败: {}", e))?;
+    let mut writer = BufWriter::new(file);
+    let mut encoder = GifEncoder::new_with_speed(&mut writer, GIF_QUANTIZE_SPEED);
+    encoder
+        .set_repeat(Repeat::Infinite)
+        .map_err(|e| e.to_string())?;
+    let mut canvas = None;
+    let mut written = 0u32;
+
+    for (frame, delay) in frames.iter().zip(delays) {
+        let image = match std::fs::read(&frame.path)
+            .map_err(|e| e.to_string())
+            .and_then(|png| tauri::image::Image::from_bytes(&png).map_err(|e| e.to_string()))
+        {
+            Ok(image) => image,
+            Err(e) => {
+                warn!("[recording] Failed to decode frame {:?}: {}", frame.path, e);
+                continue;
+            }
+        };
+        let (width, height, rgba) = if image.width().max(image.height()) > MAX_GIF_SIDE {
+            downscale_rgba(image.width(), image.height(), image.rgba(), MAX_GIF_SIDE)
+        } else {
+            (image.width(), image.height(), image.rgba().to_vec())
+        };
+
+        match canvas {
+            None => canvas = Some((width, height)),
+            Some(size) if size != (width, height) => {
+                debug!(
+                    "[recording] Skipping frame {:?}: resolution changed to {}x{}",
+                    frame.path, width, height
+                );
+                continue;
+            }
+            Some(_) => {}
+        }
+        let Some(buffer) = RgbaImage::from_raw(width, height, rgba) else {
+            continue;
+        };
+        // 过小的延时会被浏览器按 100ms 处理
+        let delay = Delay::from_saturating_duration((*delay).max(MIN_GIF_DELAY));
+        encoder
+            .encode_frame(Frame::from_parts(buffer, 0, 0, delay))
+            .map_err(|e| e.to_string())?;
+        written += 1;
+    }
+
+    // 释放编码器时写入 GIF 结束标记
+    drop(encoder);
+    if written == 0 {
+        drop(writer);
+        let _ = std::fs::remove_file(path);
+        return Err("没有可导出的帧".to_string());
+    }
+    writer.flush().map_err(|e| e.to_string())?;
+    Ok(written)
+}
+
+/// 开始录制实例的运行录像，`fps` 为采样帧率（默认 2，最大 10）
+///
+/// 必须为 async command：需要 tokio Handle 驱动截图服务的截图循环
+#[tauri::command]
+pub async fn start_recording_clip(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    fps: Option<u32>,
+) -> Result<(), String> {
+    let handle = tokio::runtime::Handle::current();
+    let fps = fps.unwrap_or(DEFAULT_FPS).clamp(1, MAX_FPS);
+    start_recording_impl(state.inner().clone(), instance_id, fps, handle)
+}
+
+/// 停止录制并导出录像（默认 GIF，mp4 需要 ffmpeg），文件保存在日志目录的 clips 子目录
+#[tauri::command]
+pub async fn stop_and_export(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    format: Option<ClipFormat>,
+) -> Result<ClipExportResult, String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        stop_and_export_impl(&state, &instance_id, format.unwrap_or_default())
+    })
+    .await
+    .map_err(|e| format!("导出录像任务执行失败: {}", e))?
+}
+
+/// 丢弃实例正在进行的录制，不导出（销毁实例或重置运行时状态时调用）
+pub fn discard_recording(state: &MaaState, instance_id: &str) {
+    let Some(recorder) = RECORDERS
+        .lock()
+        .ok()
+        .and_then(|mut recorders| recorders.remove(instance_id))
+    else {
+        return;
+    };
+    recorder.stop.store(true, Ordering::SeqCst);
+    state
+        .screenshot_service
+        .unsubscribe(instance_id, SUBSCRIBER_ID);
+    let _ = recorder.worker.join();
+    let _ = std::fs::remove_dir_all(&recorder.dir);
+    info!("[recording] Discarded recording for {}", instance_id);
+}
+
+/// 查询实例是否正在录制
+#[tauri::command]
+pub fn is_recording_clip(instance_id: String) -> bool {
+    RECORDERS
+        .lock()
+        .map(|recorders| recorders.contains_key(&instance_id))
+        .unwrap_or(false)
+}
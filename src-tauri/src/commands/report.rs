@@ -0,0 +1,484 @@
+//! 任务报告
+//!
+//! 任务队列结束后，将各任务的耗时、结果和结束时截图汇总为一张 PNG 图片，保存到 debug 目录。
+//! 文字使用系统自带的 CJK 字体绘制；任务结束时只在报告启用且需要该截图时保留缩略图。
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use ab_glyph::{point, Font, FontVec, PxScale, ScaleFont};
+use log::{info, warn};
+use tauri::State;
+
+use super::types::{MaaState, ReportThumbnail, TaskReportOptions, TaskReportScreenshots};
+use super::utils::{downscale_rgba, encode_png, get_logs_dir};
+
+/// 报告画布宽度
+const REPORT_WIDTH: u32 = 960;
+/// 左右边距
+const REPORT_PADDING: u32 = 32;
+/// 任务表格行高
+const ROW_HEIGHT: u32 = 36;
+/// 截图每行数量
+const SCREENSHOT_COLUMNS: u32 = 2;
+/// 截图之间的间距
+const SCREENSHOT_GAP: u32 = 16;
+/// 截图标题高度
+const SCREENSHOT_CAPTION_HEIGHT: u32 = 28;
+/// 截图单元格宽度，同时作为缩略图的最长边
+const SCREENSHOT_CELL_WIDTH: u32 =
+    (REPORT_WIDTH - REPORT_PADDING * 2 - SCREENSHOT_GAP * (SCREENSHOT_COLUMNS - 1))
+        / SCREENSHOT_COLUMNS;
+
+const COLOR_BACKGROUND: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
+const COLOR_ROW: [u8; 4] = [0xf3, 0xf4, 0xf6, 0xff];
+const COLOR_TEXT: [u8; 4] = [0x1f, 0x29, 0x37, 0xff];
+const COLOR_MUTED: [u8; 4] = [0x6b, 0x72, 0x80, 0xff];
+const COLOR_SUCCEEDED: [u8; 4] = [0x16, 0xa3, 0x4a, 0xff];
+const COLOR_FAILED: [u8; 4] = [0xdc, 0x26, 0x26, 0xff];
+const COLOR_PENDING: [u8; 4] = [0x9c, 0xa3, 0xaf, 0xff];
+
+/// 任务结束时需要保留截图的范围，None 表示未启用任务报告
+static REPORT_CAPTURE: Mutex<Option<TaskReportScreenshots>> = Mutex::new(None);
+
+/// 报告字体（首次生成报告时加载，找不到时为 None）
+static REPORT_FONT: OnceLock<Option<FontVec>> = OnceLock::new();
+
+/// 报告中的一行任务数据
+struct ReportRow {
+    name: String,
+    status: Option<String>,
+    duration_ms: Option<u64>,
+    screenshot: Option<ReportThumbnail>,
+}
+
+/// 设置任务报告是否启用及截图范围（决定任务结束时是否保留截图）
+pub fn apply_task_report_capture(enabled: bool, screenshots: TaskReportScreenshots) {
+    let mode = enabled.then_some(screenshots);
+    if let Ok(mut guard) = REPORT_CAPTURE.lock() {
+        *guard = mode;
+    }
+    info!("Task report capture: {:?}", mode);
+}
+
+/// 任务结束时是否需要为报告保留截图
+pub fn should_capture_screenshot(succeeded: bool) -> bool {
+    match REPORT_CAPTURE.lock().map(|guard| *guard) {
+        Ok(Some(TaskReportScreenshots::All)) => true,
+        Ok(Some(TaskReportScreenshots::Failed)) => !succeeded,
+        _ => false,
+    }
+}
+
+/// 将 PNG 截图解码并缩小为报告缩略图
+pub fn make_thumbnail(png: &[u8]) -> Option<ReportThumbnail> {
+    let image = match tauri::image::Image::from_bytes(png) {
+        Ok(image) => image,
+        Err(e) => {
+            warn!("Failed to decode task report screenshot: {}", e);
+            return None;
+        }
+    };
+    let (width, height, rgba) = if image.width().max(image.height()) > SCREENSHOT_CELL_WIDTH {
+        downscale_rgba(
+            image.width(),
+            image.height(),
+            image.rgba(),
+            SCREENSHOT_CELL_WIDTH,
+        )
+    } else {
+        (image.width(), image.height(), image.rgba().to_vec())
+    };
+    Some(ReportThumbnail {
+        width,
+        height,
+        rgba,
+    })
+}
+
+/// 生成任务报告的内部实现，返回报告文件路径
+pub fn generate_task_report_impl(
+    state: &MaaState,
+    instance_id: &str,
+    options: &TaskReportOptions,
+) -> Result<String, String> {
+    let rows: Vec<ReportRow> = {
+        let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+        let mappings = &instance.task_run_state.mappings;
+        instance
+            .task_report
+            .iter_mut()
+            .map(|record| {
+                let name = mappings
+                    .get(&record.task_id)
+                    .and_then(|sel_id| options.task_names.get(sel_id))
+                    .cloned()
+                    .unwrap_or_else(|| record.entry.clone());
+                let failed = record.status.as_deref() != Some("succeeded");
+                let include_screenshot = match options.screenshots {
+                    TaskReportScreenshots::All => true,
+                    TaskReportScreenshots::Failed => failed,
+                    TaskReportScreenshots::None => false,
+                };
+                ReportRow {
+                    name,
+                    status: record.status.clone(),
+                    duration_ms: record.duration_ms,
+                    // 缩略图只用于本次报告，取出后即释放
+                    screenshot: record.screenshot.take().filter(|_| include_screenshot),
+                }
+            })
+            .collect()
+    };
+
+    if rows.is_empty() {
+        return Err("没有可用于生成报告的任务记录".to_string());
+    }
+
+    let font = REPORT_FONT
+        .get_or_init(load_report_font)
+        .as_ref()
+        .ok_or("未找到可用于绘制报告的系统字体")?;
+    let (width, height, rgba) =
+        render_report(font, options.title.as_deref().unwrap_or("MXU"), &rows);
+    let png = encode_png(width, height, &rgba);
+
+    let dir = get_logs_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| format!("创建目录失败: {}", e))?;
+    let path = dir.join(format!(
+        "task_report_{}.png",
+        chrono::Local::now().format("%Y%m%d_%H%M%S")
+    ));
+    std::fs::write(&path, png).map_err(|e| format!("写入报告失败: {}", e))?;
+
+    info!(
+        "Task report generated: {} ({} tasks)",
+        path.display(),
+        rows.len()
+    );
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// 生成本轮任务队列的汇总图片报告（PNG），返回报告文件路径
+#[tauri::command]
+pub async fn generate_task_report(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    options: Option<TaskReportOptions>,
+) -> Result<String, String> {
+    let state = state.inner().clone();
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || generate_task_report_impl(&state, &instance_id, &options))
+        .await
+        .map_err(|e| format!("生成报告任务执行失败: {}", e))?
+}
+
+/// 设置任务报告是否启用及截图范围（持久化由前端写入配置）
+#[tauri::command]
+pub fn set_task_report_capture(enabled: bool, screenshots: TaskReportScreenshots) {
+    apply_task_report_capture(enabled, screenshots);
+}
+
+/// 按平台依次尝试的报告字体（优先带 CJK 字形的字体）
+fn font_candidates() -> Vec<PathBuf> {
+    #[cfg(windows)]
+    {
+        let dir = PathBuf::from(std::env::var("WINDIR").unwrap_or_else(|_| "C:\\Windows".into()))
+            .join("Fonts");
+        [
+            "msyh.ttc",
+            "msyh.ttf",
+            "simhei.ttf",
+            "segoeui.ttf",
+            "arial.ttf",
+        ]
+        .iter()
+        .map(|name| dir.join(name))
+        .collect()
+    }
+    #[cfg(target_os = "macos")]
+    {
+        [
+            "/System/Library/Fonts/PingFang.ttc",
+            "/System/Library/Fonts/Hiragino Sans GB.ttc",
+            "/System/Library/Fonts/STHeiti Medium.ttc",
+            "/Library/Fonts/Arial Unicode.ttf",
+            "/System/Library/Fonts/Helvetica.ttc",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect()
+    }
+    #[cfg(not(any(windows, target_os = "macos")))]
+    {
+        [
+            "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+            "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+            "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+            "/usr/share/fonts/wenquanyi/wqy-microhei/wqy-microhei.ttc",
+            "/usr/share/fonts/truetype/wqy/wqy-microhei.ttc",
+            "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect()
+    }
+}
+
+fn load_report_font() -> Option<FontVec> {
+    for path in font_candidates() {
+        let Ok(data) = std::fs::read(&path) else {
+            continue;
+        };
+        match FontVec::try_from_vec_and_index(data, 0) {
+            Ok(font) => {
+                info!("Task report font: {}", path.display());
+                return Some(font);
+            }
+            Err(e) => warn!("Failed to load font {}: {}", path.display(), e),
+        }
+    }
+    None
+}
+
+/// 绘制报告，返回 (宽, 高, RGBA)
+fn render_report(font: &FontVec, title: &str, rows: &[ReportRow]) -> (u32, u32, Vec<u8>) {
+    let succeeded = rows
+        .iter()
+        .filter(|r| r.status.as_deref() == Some("succeeded"))
+        .count();
+    let failed = rows.len() - succeeded;
+    let total_ms: u64 = rows.iter().filter_map(|r| r.duration_ms).sum();
+    let shots: Vec<(&str, &ReportThumbnail)> = rows
+        .iter()
+        .filter_map(|r| r.screenshot.as_ref().map(|s| (r.name.as_str(), s)))
+        .collect();
+
+    // 先计算总高度再分配画布
+    let header_height = 48 + 32;
+    let table_height = ROW_HEIGHT * rows.len() as u32;
+    let shots_height: u32 = if shots.is_empty() {
+        0
+    } else {
+        16 + shots
+            .chunks(SCREENSHOT_COLUMNS as usize)
+            .map(|chunk| {
+                let image_height = chunk.iter().map(|(_, s)| s.height).max().unwrap_or(0);
+                SCREENSHOT_CAPTION_HEIGHT + image_height + SCREENSHOT_GAP
+            })
+            .sum::<u32>()
+    };
+    let height = REPORT_PADDING * 2 + header_height + table_height + shots_height;
+
+    let mut canvas = ReportCanvas::new(font, REPORT_WIDTH, height);
+    let left = REPORT_PADDING as i32;
+    let right = (REPORT_WIDTH - REPORT_PADDING) as i32;
+    let mut y = REPORT_PADDING as i32;
+
+    // 标题与汇总
+    let title = canvas.fit_text(title, 26.0, right - left);
+    canvas.draw_text(left, y + 26, &title, 26.0, COLOR_TEXT);
+    y += 48;
+    let baseline = y + 14;
+    let mut x = left;
+    let summary = format!(
+        "{} · {} · ",
+        chrono::Local::now().format("%Y-%m-%d %H:%M"),
+        rows.len()
+    );
+    x += canvas.draw_text(x, baseline, &summary, 14.0, COLOR_MUTED);
+    canvas.fill_circle(x + 5, baseline - 5, 5, COLOR_SUCCEEDED);
+    x += 14;
+    x += canvas.draw_text(x, baseline, &format!("{} · ", succeeded), 14.0, COLOR_MUTED);
+    canvas.fill_circle(x + 5, baseline - 5, 5, COLOR_FAILED);
+    x += 14;
+    let tail = format!("{} · {}", failed, format_duration(total_ms));
+    canvas.draw_text(x, baseline, &tail, 14.0, COLOR_MUTED);
+    y += 32;
+
+    // 任务表格
+    for (idx, row) in rows.iter().enumerate() {
+        if idx % 2 == 0 {
+            canvas.fill_rect(left, y, right - left, ROW_HEIGHT as i32, COLOR_ROW);
+        }
+        let color = match row.status.as_deref() {
+            Some("succeeded") => COLOR_SUCCEEDED,
+            Some(_) => COLOR_FAILED,
+            None => COLOR_PENDING,
+        };
+        let center = y + ROW_HEIGHT as i32 / 2;
+        canvas.fill_circle(left + 18, center, 6, color);
+
+        let duration = row.duration_ms.map(format_duration).unwrap_or_default();
+        let duration_width = canvas.text_width(&duration, 14.0);
+        let duration_x = right - 12 - duration_width;
+        canvas.draw_text(duration_x, center + 5, &duration, 14.0, COLOR_MUTED);
+
+        let name_x = left + 40;
+        let name = canvas.fit_text(&row.name, 15.0, duration_x - 16 - name_x);
+        canvas.draw_text(name_x, center + 5, &name, 15.0, COLOR_TEXT);
+        y += ROW_HEIGHT as i32;
+    }
+
+    // 关键截图（每行 SCREENSHOT_COLUMNS 张，缩略图已按单元格宽度缩小）
+    if !shots.is_empty() {
+        y += 16;
+    }
+    for chunk in shots.chunks(SCREENSHOT_COLUMNS as usize) {
+        let mut row_height = 0;
+        for (col, (name, shot)) in chunk.iter().enumerate() {
+            let x = left + (col as u32 * (SCREENSHOT_CELL_WIDTH + SCREENSHOT_GAP)) as i32;
+            let caption = canvas.fit_text(name, 13.0, SCREENSHOT_CELL_WIDTH as i32);
+            canvas.draw_text(x, y + 18, &caption, 13.0, COLOR_TEXT);
+            canvas.draw_image(x, y + SCREENSHOT_CAPTION_HEIGHT as i32, shot);
+            row_height = row_height.max(SCREENSHOT_CAPTION_HEIGHT + shot.height);
+        }
+        y += (row_height + SCREENSHOT_GAP) as i32;
+    }
+
+    (canvas.width, canvas.height, canvas.rgba)
+}
+
+/// 报告画布（不透明 RGBA）
+struct ReportCanvas<'a> {
+    font: &'a FontVec,
+    width: u32,
+    height: u32,
+    rgba: Vec<u8>,
+}
+
+impl<'a> ReportCanvas<'a> {
+    fn new(font: &'a FontVec, width: u32, height: u32) -> Self {
+        let rgba = COLOR_BACKGROUND
+            .iter()
+            .copied()
+            .cycle()
+            .take((width * height * 4) as usize)
+            .collect();
+        Self {
+            font,
+            width,
+            height,
+            rgba,
+        }
+    }
+
+    /// 按覆盖率混合一个像素，超出画布的部分被忽略
+    fn blend(&mut self, x: i32, y: i32, color: [u8; 4], coverage: f32) {
+        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
+            return;
+        }
+        let alpha = coverage.clamp(0.0, 1.0) * color[3] as f32 / 255.0;
+        let idx = (y as usize * self.width as usize + x as usize) * 4;
+        for (dst, src) in self.rgba[idx..idx + 3].iter_mut().zip(color) {
+            *dst = (src as f32 * alpha + *dst as f32 * (1.0 - alpha)).round() as u8;
+        }
+    }
+
+    fn fill_rect(&mut self, x: i32, y: i32, w: i32, h: i32, color: [u8; 4]) {
+        for py in y..y + h {
+            for px in x..x + w {
+                self.blend(px, py, color, 1.0);
+            }
+        }
+    }
+
+    /// 填充圆形，边缘按覆盖率抗锯齿
+    fn fill_circle(&mut self, cx: i32, cy: i32, radius: i32, color: [u8; 4]) {
+        let r = radius as f32;
+        for py in cy - radius - 1..=cy + radius + 1 {
+            for px in cx - radius - 1..=cx + radius + 1 {
+                let dx = px as f32 + 0.5 - (cx as f32 + 0.5);
+                let dy = py as f32 + 0.5 - (cy as f32 + 0.5);
+                let coverage = r + 0.5 - (dx * dx + dy * dy).sqrt();
+                if coverage > 0.0 {
+                    self.blend(px, py, color, coverage);
+                }
+            }
+        }
+    }
+
+    /// 将缩略图绘制到 (x, y)，超出画布的部分被裁掉
+    fn draw_image(&mut self, x: i32, y: i32, image: &ReportThumbnail) {
+        for (row, pixels) in image
+            .rgba
+            .chunks_exact(image.width as usize * 4)
+            .enumerate()
+        {
+            for (col, px) in pixels.chunks_exact(4).enumerate() {
+                self.blend(
+                    x + col as i32,
+                    y + row as i32,
+                    [px[0], px[1], px[2], px[3]],
+                    1.0,
+                );
+            }
+        }
+    }
+
+    fn text_width(&self, text: &str, size: f32) -> i32 {
+        let font = self.font.as_scaled(PxScale::from(size));
+        text.chars()
+            .map(|c| font.h_advance(font.glyph_id(c)))
+            .sum::<f32>()
+            .ceil() as i32
+    }
+
+    /// 文本超出宽度时截断并加省略号
+    fn fit_text(&self, text: &str, size: f32, max_width: i32) -> String {
+        if self.text_width(text, size) <= max_width {
+            return text.to_string();
+        }
+        let mut chars: Vec<char> = text.chars().collect();
+        while !chars.is_empty() {
+            chars.pop();
+            let candidate: String = chars.iter().chain(['…'].iter()).collect();
+            if self.text_width(&candidate, size) <= max_width {
+                return candidate;
+            }
+        }
+        String::new()
+    }
+
+    /// 以 (x, baseline) 为起点绘制单行文本，返回文本宽度
+    fn draw_text(&mut self, x: i32, baseline: i32, text: &str, size: f32, color: [u8; 4]) -> i32 {
+        let scale = PxScale::from(size);
+        let font = self.font;
+        let scaled = font.as_scaled(scale);
+        let mut cursor = x as f32;
+        for c in text.chars() {
+            let id = font.glyph_id(c);
+            let glyph = id.with_scale_and_position(scale, point(cursor, baseline as f32));
+            cursor += scaled.h_advance(id);
+            if let Some(outlined) = font.outline_glyph(glyph) {
+                let bounds = outlined.px_bounds();
+                outlined.draw(|gx, gy, coverage| {
+                    self.blend(
+                        bounds.min.x as i32 + gx as i32,
+                        bounds.min.y as i32 + gy as i32,
+                        color,
+                        coverage,
+                    );
+                });
+            }
+        }
+        (cursor - x as f32).ceil() as i32
+    }
+}
+
+fn format_duration(ms: u64) -> String {
+    let secs = ms / 1000;
+    if secs >= 3600 {
+        format!(
+            "{}h {:02}m {:02}s",
+            secs / 3600,
+            secs % 3600 / 60,
+            secs % 60
+        )
+    } else if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}.{}s", secs, ms % 1000 / 100)
+    }
+}
@@ -0,0 +1,98 @@
+//! 连接后的分辨率预检
+//!
+//! 模拟器分辨率设置不对时识别会全部失败，用户却往往不知道原因。interface.json 的控制器
+//! 可声明 `expected_resolution`，前端在连接成功后调用 `maa_check_resolution`：截一张图
+//! 比较宽高比，不符时发出 `maa-resolution-mismatch` 事件并以期望分辨率作为建议。
+//!
+//! 截图会按 display_short_side 缩放，但宽高比与设备一致，因此只比较宽高比
+
+use std::sync::Arc;
+
+use log::{info, warn};
+use maa_framework::MaaStatus;
+use tauri::{Emitter, State};
+
+use super::types::{ExpectedResolution, MaaState, ResolutionCheck};
+use super::utils::png_size;
+
+/// 宽高比默认允许的相对误差
+const DEFAULT_TOLERANCE: f64 = 0.02;
+
+/// 分辨率预检的内部实现
+fn check_resolution_impl(
+    state: &MaaState,
+    instance_id: &str,
+    expected: ExpectedResolution,
+) -> Result<ResolutionCheck, String> {
+    if expected.width == 0 || expected.height == 0 {
+        return Err("期望分辨率无效".to_string());
+    }
+
+    let (controller, running) = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        let controller = instance
+            .controller
+            .clone()
+            .ok_or("Controller not connected")?;
+        let running = instance.tasker.as_ref().is_some_and(|t| t.running());
+        (controller, running)
+    };
+
+    // 任务运行中由任务管线更新截图缓存，不再额外截图
+    if !running {
+        let id = controller.post_screencap().map_err(|e| e.to_string())?;
+        if controller.wait(id) != MaaStatus::SUCCEEDED {
+            return Err("截图失败".to_string());
+        }
+    }
+    let (width, height) = controller
+        .cached_image()
+        .ok()
+        .and_then(|buf| buf.to_vec())
+        .and_then(|png| png_size(&png))
+        .ok_or("无法获取截图尺寸")?;
+
+    let actual_ratio = width as f64 / height as f64;
+    let expected_ratio = expected.width as f64 / expected.height as f64;
+    let tolerance = expected.tolerance.unwrap_or(DEFAULT_TOLERANCE);
+    let matched = (actual_ratio / expected_ratio - 1.0).abs() <= tolerance;
+
+    Ok(ResolutionCheck {
+        instance_id: instance_id.to_string(),
+        actual: (width, height),
+        expected: (expected.width, expected.height),
+        matched,
+    })
+}
+
+/// 连接成功后预检截图分辨率是否符合资源期望，不符时发出 `maa-resolution-mismatch` 事件
+#[tauri::command]
+pub async fn maa_check_resolution(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    expected: ExpectedResolution,
+) -> Result<ResolutionCheck, String> {
+    let state = state.inner().clone();
+    let check =
+        tokio::task::spawn_blocking(move || check_resolution_impl(&state, &instance_id, expected))
+            .await
+            .map_err(|e| format!("分辨率预检任务执行失败: {}", e))??;
+
+    if check.matched {
+        info!(
+            "[resolution_check] {}: {}x{} matches expected {}x{}",
+            check.instance_id, check.actual.0, check.actual.1, check.expected.0, check.expected.1
+        );
+    } else {
+        warn!(
+            "[resolution_check] {}: {}x{} does not match expected {}x{}",
+            check.instance_id, check.actual.0, check.actual.1, check.expected.0, check.expected.1
+        );
+        if let Err(e) = app.emit("maa-resolution-mismatch", check.clone()) {
+            warn!("Failed to emit maa-resolution-mismatch: {}", e);
+        }
+    }
+    Ok(check)
+}
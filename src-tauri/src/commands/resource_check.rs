@@ -0,0 +1,511 @@
+//! 资源静态检查
+//!
+//! 在加载前扫描资源目录，提前发现 pipeline 中的低级错误：
+//! JSON 语法错误、引用了不存在的节点、模板图缺失等。
+//! 提交任务前也可以只校验本次的入口节点与 override（见 `validate_task_impl`）
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use log::info;
+use maa_framework::resource::Resource;
+use serde_json::Value;
+
+use super::app_config::parse_jsonc;
+use super::template_preview::resolve_template;
+use super::types::{MaaState, ResourceIssue, ResourceIssueLevel, TaskValidation};
+use super::utils::{merge_pipeline_override, normalize_path, parse_pipeline_override};
+
+/// 引用其他节点的字段
+const NODE_REF_FIELDS: &[&str] = &["next", "interrupt", "on_error"];
+
+/// 节点引用前缀：`[JumpBack]` 仍指向节点，`[Anchor]` 指向锚点名
+const JUMP_BACK_PREFIX: &str = "[JumpBack]";
+const ANCHOR_PREFIX: &str = "[Anchor]";
+
+/// 任务校验中仅由 override 新增的节点的来源标记
+const OVERRIDE_SOURCE: &str = "pipeline_override";
+
+/// 单个 pipeline 节点（保留来源文件，用于定位问题）
+struct PipelineNode {
+    file: String,
+    body: Value,
+}
+
+/// 扫描资源目录，返回问题清单（先 error 后 warning）
+pub fn validate_resource_impl(dir: &str) -> Result<Vec<ResourceIssue>, String> {
+    let root = normalize_path(dir);
+    if !root.is_dir() {
+        return Err(format!("资源目录不存在: {}", root.display()));
+    }
+
+    let mut issues = Vec::new();
+    let pipeline_dir = root.join("pipeline");
+    let image_dir = root.join("image");
+
+    if !pipeline_dir.is_dir() {
+        issues.push(issue(
+            ResourceIssueLevel::Warning,
+            "pipeline",
+            "",
+            "资源目录下没有 pipeline 文件夹".to_string(),
+        ));
+        return Ok(issues);
+    }
+
+    let mut files = Vec::new();
+    collect_pipeline_files(&pipeline_dir, &mut files);
+    files.sort();
+
+    // 第一遍：解析所有 JSON 并收集节点（节点可跨文件引用）
+    let mut nodes: HashMap<String, PipelineNode> = HashMap::new();
+    for path in &files {
+        let rel = relative_display(&root, path);
+        let content = match std::fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) => {
+                issues.push(issue(
+                    ResourceIssueLevel::Error,
+                    &rel,
+                    "",
+                    format!("读取文件失败: {}", e),
+                ));
+                continue;
+            }
+        };
+        let json = match parse_jsonc(&content) {
+            Ok(v) => v,
+            Err(e) => {
+                issues.push(issue(
+                    ResourceIssueLevel::Error,
+                    &rel,
+                    &format!("{}:{}", e.line(), e.column()),
+                    format!("JSON 语法错误: {}", e),
+                ));
+                continue;
+            }
+        };
+        let Some(obj) = json.as_object() else {
+            issues.push(issue(
+                ResourceIssueLevel::Error,
+                &rel,
+                "",
+                "pipeline 文件顶层必须是对象".to_string(),
+            ));
+            continue;
+        };
+        for (name, body) in obj {
+            // `$schema` 等元数据字段不是节点
+            if name.starts_with('$') {
+                continue;
+            }
+            if !body.is_object() {
+                issues.push(issue(
+                    ResourceIssueLevel::Error,
+                    &rel,
+                    name,
+                    "节点定义必须是对象".to_string(),
+                ));
+                continue;
+            }
+            if let Some(prev) = nodes.get(name) {
+                issues.push(issue(
+                    ResourceIssueLevel::Warning,
+                    &rel,
+                    name,
+                    format!("节点与 {} 中的定义重名，后加载的会覆盖前者", prev.file),
+                ));
+            }
+            nodes.insert(
+                name.clone(),
+                PipelineNode {
+                    file: rel.clone(),
+                    body: body.clone(),
+                },
+            );
+        }
+    }
+
+    let anchors = collect_anchors(&nodes);
+
+    // 第二遍：检查节点引用和模板图
+    let mut names: Vec<&String> = nodes.keys().collect();
+    names.sort();
+    for name in names {
+        let node = &nodes[name];
+        for field in NODE_REF_FIELDS {
+            for target in node_refs(node.body.get(*field)) {
+                if let Some(anchor) = target.strip_prefix(ANCHOR_PREFIX) {
+                    if !anchors.contains(anchor) {
+                        issues.push(issue(
+                            ResourceIssueLevel::Warning,
+                            &node.file,
+                            &format!("{}.{}", name, field),
+                            format!("引用的锚点 \"{}\" 未被任何节点设置", anchor),
+                        ));
+                    }
+                    continue;
+                }
+                let target_name = target.strip_prefix(JUMP_BACK_PREFIX).unwrap_or(&target);
+                if !nodes.contains_key(target_name) {
+                    issues.push(issue(
+                        ResourceIssueLevel::Error,
+                        &node.file,
+                        &format!("{}.{}", name, field),
+                        format!("引用的节点 \"{}\" 不存在", target_name),
+                    ));
+                }
+            }
+        }
+
+        for template in node_templates(&node.body) {
+            if !image_dir.join(&template).exists() {
+                issues.push(issue(
+                    ResourceIssueLevel::Error,
+                    &node.file,
+                    &format!("{}.template", name),
+                    format!("模板图 \"image/{}\" 不存在", template),
+                ));
+            }
+        }
+    }
+
+    issues.sort_by_key(|i| i.level != ResourceIssueLevel::Error);
+
+    info!(
+        "validate_resource: {} files, {} nodes, {} issues in {}",
+        files.len(),
+        nodes.len(),
+        issues.len(),
+        root.display()
+    );
+
+    Ok(issues)
+}
+
+/// 节点的最终定义：已加载资源中的定义叠加本次 override，两者都没有时返回 None
+fn effective_node(
+    resource: &Resource,
+    merged: &serde_json::Map<String, Value>,
+    name: &str,
+) -> Option<Value> {
+    let base = resource
+        .get_node_data(name)
+        .ok()
+        .flatten()
+        .and_then(|data| serde_json::from_str::<Value>(&data).ok())
+        .filter(|v| v.is_object());
+    match (base, merged.get(name)) {
+        (Some(mut body), Some(Value::Object(fields))) => {
+            if let Some(target) = body.as_object_mut() {
+                for (key, value) in fields {
+                    target.insert(key.clone(), value.clone());
+                }
+            }
+            Some(body)
+        }
+        (Some(body), _) => Some(body),
+        (None, Some(fields)) => Some(fields.clone()),
+        (None, None) => None,
+    }
+}
+
+/// 校验一次任务提交而不执行：叠加 override 后从入口节点出发，
+/// 检查可达节点引用的节点、锚点与模板图是否存在，以及 override 是否会生效
+///
+/// 模板图按加载顺序倒序在各资源目录（`bundles`）的 `image` 下查找，
+/// 与 MaaFramework 后加载覆盖的规则一致
+pub fn validate_task_impl(
+    resource: &Resource,
+    bundles: &[String],
+    entry: &str,
+    pipeline_override: &str,
+) -> TaskValidation {
+    let mut issues = Vec::new();
+
+    let mut merged = serde_json::Map::new();
+    match parse_pipeline_override(pipeline_override) {
+        Ok(layers) => {
+            for layer in &layers {
+                merge_pipeline_override(&mut merged, layer);
+            }
+        }
+        Err(e) => issues.push(issue(ResourceIssueLevel::Error, OVERRIDE_SOURCE, "", e)),
+    }
+    let mut overridden_nodes: Vec<String> = merged.keys().cloned().collect();
+    overridden_nodes.sort();
+
+    let known: HashSet<String> = resource
+        .node_list()
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    let source = |name: &str| {
+        if known.contains(name) {
+            ""
+        } else {
+            OVERRIDE_SOURCE
+        }
+    };
+    for name in &overridden_nodes {
+        if !merged[name].is_object() {
+            issues.push(issue(
+                ResourceIssueLevel::Error,
+                OVERRIDE_SOURCE,
+                name,
+                "override 中的节点值必须是对象".to_string(),
+            ));
+        } else if !known.contains(name) {
+            issues.push(issue(
+                ResourceIssueLevel::Warning,
+                OVERRIDE_SOURCE,
+                name,
+                "节点在资源中不存在，将由 override 新建".to_string(),
+            ));
+        }
+    }
+
+    // 从入口节点广度优先遍历可达节点
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut anchors: HashSet<String> = HashSet::new();
+    let mut anchor_refs: Vec<(String, String)> = Vec::new();
+    let mut queue = VecDeque::from([entry.to_string()]);
+    while let Some(name) = queue.pop_front() {
+        if visited.contains(&name) {
+            continue;
+        }
+        let Some(body) = effective_node(resource, &merged, &name) else {
+            if name == entry {
+                issues.push(issue(
+                    ResourceIssueLevel::Error,
+                    "",
+                    entry,
+                    format!("入口节点 \"{}\" 不存在", entry),
+                ));
+            }
+            continue;
+        };
+        visited.insert(name.clone());
+        if name == entry && body.get("enabled") == Some(&Value::Bool(false)) {
+            issues.push(issue(
+                ResourceIssueLevel::Warning,
+                source(&name),
+                &format!("{}.enabled", name),
+                "入口节点已被禁用，任务不会执行任何动作".to_string(),
+            ));
+        }
+        anchors.extend(node_anchors(&body));
+
+        for field in NODE_REF_FIELDS {
+            let location = format!("{}.{}", name, field);
+            for target in node_refs(body.get(*field)) {
+                if let Some(anchor) = target.strip_prefix(ANCHOR_PREFIX) {
+                    anchor_refs.push((location.clone(), anchor.to_string()));
+                    continue;
+                }
+                let target_name = target.strip_prefix(JUMP_BACK_PREFIX).unwrap_or(&target);
+                if known.contains(target_name) || merged.contains_key(target_name) {
+                    queue.push_back(target_name.to_string());
+                } else {
+                    issues.push(issue(
+                        ResourceIssueLevel::Error,
+                        source(&name),
+                        &location,
+                        format!("引用的节点 \"{}\" 不存在", target_name),
+                    ));
+                }
+            }
+        }
+
+        // 未记录资源目录时无法定位模板图，跳过检查
+        if bundles.is_empty() {
+            continue;
+        }
+        for template in node_templates(&body) {
+            let found = bundles
+                .iter()
+                .rev()
+                .any(|bundle| resolve_template(Path::new(bundle), &template).is_some());
+            if !found {
+                issues.push(issue(
+                    ResourceIssueLevel::Error,
+                    source(&name),
+                    &format!("{}.template", name),
+                    format!("模板图 \"image/{}\" 不存在", template),
+                ));
+            }
+        }
+    }
+
+    for (location, anchor) in anchor_refs {
+        if !anchors.contains(&anchor) {
+            let node = location.split('.').next().unwrap_or_default();
+            issues.push(issue(
+                ResourceIssueLevel::Warning,
+                source(node),
+                &location,
+                format!("引用的锚点 \"{}\" 未被可达节点设置", anchor),
+            ));
+        }
+    }
+
+    for name in &overridden_nodes {
+        if !visited.contains(name) {
+            issues.push(issue(
+                ResourceIssueLevel::Warning,
+                source(name),
+                name,
+                format!(
+                    "从入口节点 \"{}\" 无法到达该节点，override 本次不会生效",
+                    entry
+                ),
+            ));
+        }
+    }
+
+    issues.sort_by_key(|i| i.level != ResourceIssueLevel::Error);
+    let valid = issues.iter().all(|i| i.level != ResourceIssueLevel::Error);
+
+    info!(
+        "validate_task: entry={}, {} reachable nodes, {} overridden, {} issues",
+        entry,
+        visited.len(),
+        overridden_nodes.len(),
+        issues.len()
+    );
+
+    TaskValidation {
+        entry: entry.to_string(),
+        valid,
+        reachable_nodes: visited.len(),
+        overridden_nodes,
+        issues,
+    }
+}
+
+/// 使用实例已加载的资源校验一次任务提交（不提交任务）
+pub fn validate_task_for_instance(
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    entry: &str,
+    pipeline_override: &str,
+) -> Result<TaskValidation, String> {
+    let (resource, bundles) = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        let resource = instance.resource.clone().ok_or("Resource not loaded")?;
+        let bundles = instance
+            .resource_load
+            .lock()
+            .map_err(|e| e.to_string())?
+            .paths
+            .clone();
+        (resource, bundles)
+    };
+    Ok(validate_task_impl(
+        &resource,
+        &bundles,
+        entry,
+        pipeline_override,
+    ))
+}
+
+/// 静态检查资源目录（JSON 语法、节点引用、模板图），返回问题清单
+#[tauri::command]
+pub async fn validate_resource(dir: String) -> Result<Vec<ResourceIssue>, String> {
+    tokio::task::spawn_blocking(move || validate_resource_impl(&dir))
+        .await
+        .map_err(|e| format!("资源检查任务执行失败: {}", e))?
+}
+
+fn issue(level: ResourceIssueLevel, file: &str, location: &str, message: String) -> ResourceIssue {
+    ResourceIssue {
+        level,
+        file: file.to_string(),
+        location: location.to_string(),
+        message,
+    }
+}
+
+/// 递归收集 pipeline 目录下的 .json / .jsonc 文件
+fn collect_pipeline_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_pipeline_files(&path, out);
+        } else if path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("json") || e.eq_ignore_ascii_case("jsonc"))
+        {
+            out.push(path);
+        }
+    }
+}
+
+fn relative_display(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// 提取引用字段中的节点名，支持字符串、字符串数组和 `{ "name": ... }` 对象写法
+fn node_refs(value: Option<&Value>) -> Vec<String> {
+    let to_name = |v: &Value| -> Option<String> {
+        match v {
+            Value::String(s) => Some(s.clone()),
+            Value::Object(o) => o.get("name").and_then(|n| n.as_str()).map(String::from),
+            _ => None,
+        }
+    };
+    match value {
+        Some(Value::Array(arr)) => arr.iter().filter_map(to_name).collect(),
+        Some(v) => to_name(v).into_iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+/// 提取节点设置的锚点名（`anchor` 支持字符串、数组和对象写法）
+fn node_anchors(body: &Value) -> Vec<String> {
+    match body.get("anchor") {
+        Some(Value::String(s)) => vec![s.clone()],
+        Some(Value::Array(arr)) => arr
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(String::from)
+            .collect(),
+        Some(Value::Object(obj)) => obj.keys().cloned().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// 收集所有节点设置的锚点名
+fn collect_anchors(nodes: &HashMap<String, PipelineNode>) -> HashSet<String> {
+    nodes
+        .values()
+        .flat_map(|node| node_anchors(&node.body))
+        .collect()
+}
+
+/// 提取节点引用的模板图路径（兼容 v1 平铺写法和 v2 `recognition.param` 写法）
+pub fn node_templates(body: &Value) -> Vec<String> {
+    let template = body.get("template").or_else(|| {
+        body.get("recognition")
+            .and_then(|r| r.get("param"))
+            .and_then(|p| p.get("template"))
+    });
+    match template {
+        Some(Value::String(s)) => vec![s.clone()],
+        Some(Value::Array(arr)) => arr
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(String::from)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,379 @@
+//! 资源完整性快速校验
+//!
+//! 资源文件可能被杀软误删、被同步盘或误操作改动，表现为"昨天好好的今天识别全错"。
+//! 运行前按清单（相对路径 → 大小 + SHA-256）比对资源目录，发现缺失或被改的文件时提示用户
+//! 重新下载资源。清单优先使用随资源包分发的 `resource_manifest.json`，没有时在首次校验时
+//! 按当前文件生成并保存在 `cache/resource_manifest` 下。
+//!
+//! 为了每次运行前都能快速完成，记录上次校验通过时各文件的大小和修改时间，未变化的文件
+//! 不再计算哈希。程序或资源更新会清除生成的清单，下次校验时按新文件重新生成。
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::{Instant, UNIX_EPOCH};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::types::{ResourceIntegrityReport, ResourceManifestSource};
+use super::utils::{get_app_data_dir, normalize_path};
+
+/// 随资源包分发的清单文件名（位于资源目录根部）
+const BUNDLED_MANIFEST_FILE: &str = "resource_manifest.json";
+
+/// 生成的清单与哈希缓存目录（相对应用数据目录）
+const MANIFEST_CACHE_DIR: &str = "cache/resource_manifest";
+
+/// 清单格式版本
+const MANIFEST_VERSION: u32 = 1;
+
+/// 清单中的单个文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ManifestEntry {
+    size: u64,
+    sha256: String,
+}
+
+/// 资源清单：相对资源目录的路径（`/` 分隔）→ 文件信息
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ResourceManifest {
+    #[serde(default)]
+    version: u32,
+    files: BTreeMap<String, ManifestEntry>,
+}
+
+/// 上次校验通过时文件的大小、修改时间和哈希，大小和修改时间未变时沿用哈希
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedHash {
+    size: u64,
+    modified_ms: i64,
+    sha256: String,
+}
+
+/// 当前磁盘上的文件信息
+struct FileStat {
+    path: PathBuf,
+    size: u64,
+    modified_ms: i64,
+}
+
+fn cache_dir() -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?.join(MANIFEST_CACHE_DIR))
+}
+
+/// 以资源目录绝对路径的哈希作为缓存文件名，不同资源目录互不干扰
+fn cache_key(root: &Path) -> String {
+    let digest = Sha256::digest(root.to_string_lossy().as_bytes());
+    digest
+        .iter()
+        .take(8)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn sha256_file(path: &Path) -> Result<String, String> {
+    let mut file =
+        std::fs::File::open(path).map_err(|e| format!("无法打开 [{}]: {}", path.display(), e))?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 1024 * 1024];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .map_err(|e| format!("读取 [{}] 失败: {}", path.display(), e))?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+fn stat_file(path: &Path) -> Option<FileStat> {
+    let meta = std::fs::metadata(path).ok().filter(|m| m.is_file())?;
+    let modified_ms = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_millis() as i64);
+    Some(FileStat {
+        path: path.to_path_buf(),
+        size: meta.len(),
+        modified_ms,
+    })
+}
+
+/// 递归收集资源目录下的文件（跳过清单文件本身），键为 `/` 分隔的相对路径
+fn collect_files(root: &Path, dir: &Path, out: &mut BTreeMap<String, FileStat>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(root, &path, out);
+            continue;
+        }
+        let Ok(rel) = path.strip_prefix(root) else {
+            continue;
+        };
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        if rel == BUNDLED_MANIFEST_FILE {
+            continue;
+        }
+        if let Some(stat) = stat_file(&path) {
+            out.insert(rel, stat);
+        }
+    }
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Option<T> {
+    let content = std::fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            warn!("[资源校验] 解析 {} 失败: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("无法创建目录 [{}]: {}", parent.display(), e))?;
+    }
+    let content = serde_json::to_string(value).map_err(|e| e.to_string())?;
+    std::fs::write(path, content).map_err(|e| format!("写入 [{}] 失败: {}", path.display(), e))
+}
+
+/// 计算文件哈希，大小和修改时间与缓存一致时直接沿用缓存的哈希
+fn hash_with_cache(
+    stat: &FileStat,
+    cached: Option<&CachedHash>,
+    hashed: &mut usize,
+) -> Result<String, String> {
+    if let Some(cached) =
+        cached.filter(|c| c.size == stat.size && c.modified_ms == stat.modified_ms)
+    {
+        return Ok(cached.sha256.clone());
+    }
+    *hashed += 1;
+    sha256_file(&stat.path)
+}
+
+/// 按当前文件生成清单，返回清单与对应的哈希缓存
+fn build_manifest(
+    files: &BTreeMap<String, FileStat>,
+    old_cache: &HashMap<String, CachedHash>,
+    hashed: &mut usize,
+) -> Result<(ResourceManifest, HashMap<String, CachedHash>), String> {
+    let mut manifest = ResourceManifest {
+        version: MANIFEST_VERSION,
+        files: BTreeMap::new(),
+    };
+    let mut cache = HashMap::new();
+    for (rel, stat) in files {
+        let sha256 = hash_with_cache(stat, old_cache.get(rel), hashed)?;
+        manifest.files.insert(
+            rel.clone(),
+            ManifestEntry {
+                size: stat.size,
+                sha256: sha256.clone(),
+            },
+        );
+        cache.insert(
+            rel.clone(),
+            CachedHash {
+                size: stat.size,
+                modified_ms: stat.modified_ms,
+                sha256,
+            },
+        );
+    }
+    Ok((manifest, cache))
+}
+
+/// 校验单个资源目录
+fn verify_dir(dir: &str, rebuild: bool) -> Result<ResourceIntegrityReport, String> {
+    let started = Instant::now();
+    let root = normalize_path(dir);
+    if !root.is_dir() {
+        return Err(format!("资源目录不存在: {}", root.display()));
+    }
+
+    let key = cache_key(&root);
+    let cache_dir = cache_dir()?;
+    let generated_path = cache_dir.join(format!("{}.json", key));
+    let hash_cache_path = cache_dir.join(format!("{}.hashes.json", key));
+    let old_cache: HashMap<String, CachedHash> = if rebuild {
+        HashMap::new()
+    } else {
+        read_json(&hash_cache_path).unwrap_or_default()
+    };
+
+    let mut files = BTreeMap::new();
+    collect_files(&root, &root, &mut files);
+    let mut hashed = 0;
+
+    let bundled: Option<ResourceManifest> = read_json(&root.join(BUNDLED_MANIFEST_FILE));
+    let generated: Option<ResourceManifest> = if bundled.is_some() || rebuild {
+        None
+    } else {
+        read_json(&generated_path)
+    };
+    let (manifest, source) = match (bundled, generated) {
+        (Some(manifest), _) => (manifest, ResourceManifestSource::Bundled),
+        (None, Some(manifest)) => (manifest, ResourceManifestSource::Generated),
+        (None, None) => {
+            // 首次校验：按当前文件生成清单，本次无从比对
+            let (manifest, cache) = build_manifest(&files, &old_cache, &mut hashed)?;
+            write_json(&generated_path, &manifest)?;
+            write_json(&hash_cache_path, &cache)?;
+            info!(
+                "[资源校验] 已为 {} 生成清单（{} 个文件）",
+                root.display(),
+                manifest.files.len()
+            );
+            return Ok(ResourceIntegrityReport {
+                path: root.to_string_lossy().to_string(),
+                source: ResourceManifestSource::Created,
+                total_files: manifest.files.len(),
+                hashed_files: hashed,
+                missing: Vec::new(),
+                modified: Vec::new(),
+                elapsed_ms: started.elapsed().as_millis() as u64,
+            });
+        }
+    };
+
+    let mut missing = Vec::new();
+    let mut modified = Vec::new();
+    let mut cache = HashMap::new();
+    for (rel, entry) in &manifest.files {
+        let Some(stat) = files.get(rel) else {
+            missing.push(rel.clone());
+            continue;
+        };
+        if stat.size != entry.size {
+            modified.push(rel.clone());
+            continue;
+        }
+        let sha256 = match hash_with_cache(stat, old_cache.get(rel), &mut hashed) {
+            Ok(sha256) => sha256,
+            Err(e) => {
+                warn!("[资源校验] {}", e);
+                modified.push(rel.clone());
+                continue;
+            }
+        };
+        if !sha256.eq_ignore_ascii_case(&entry.sha256) {
+            modified.push(rel.clone());
+            continue;
+        }
+        // 只缓存校验通过的文件，被改动的文件下次仍会重新计算
+        cache.insert(
+            rel.clone(),
+            CachedHash {
+                size: stat.size,
+                modified_ms: stat.modified_ms,
+                sha256,
+            },
+        );
+    }
+    if let Err(e) = write_json(&hash_cache_path, &cache) {
+        warn!("[资源校验] 保存哈希缓存失败: {}", e);
+    }
+
+    let report = ResourceIntegrityReport {
+        path: root.to_string_lossy().to_string(),
+        source,
+        total_files: manifest.files.len(),
+        hashed_files: hashed,
+        missing,
+        modified,
+        elapsed_ms: started.elapsed().as_millis() as u64,
+    };
+    info!(
+        "[资源校验] {}: {} 个文件，计算哈希 {} 个，缺失 {}，被修改 {}，耗时 {}ms",
+        report.path,
+        report.total_files,
+        report.hashed_files,
+        report.missing.len(),
+        report.modified.len(),
+        report.elapsed_ms
+    );
+    Ok(report)
+}
+
+/// 清除所有生成的清单与哈希缓存（程序或资源更新后调用），下次校验时按新文件重新生成
+pub fn invalidate_generated_manifests() {
+    let Ok(dir) = cache_dir() else {
+        return;
+    };
+    if dir.exists() {
+        match std::fs::remove_dir_all(&dir) {
+            Ok(()) => info!("[资源校验] 已清除生成的资源清单"),
+            Err(e) => warn!("[资源校验] 清除生成的资源清单失败: {}", e),
+        }
+    }
+}
+
+/// 按清单快速校验资源目录，返回各目录的缺失与被修改文件
+///
+/// rebuild 为 true 时丢弃生成的清单并按当前文件重新生成（随资源包分发的清单不受影响）
+#[tauri::command]
+pub async fn verify_resource_integrity(
+    paths: Vec<String>,
+    rebuild: Option<bool>,
+) -> Result<Vec<ResourceIntegrityReport>, String> {
+    let rebuild = rebuild.unwrap_or(false);
+    tokio::task::spawn_blocking(move || {
+        paths
+            .iter()
+            .map(|path| verify_dir(path, rebuild))
+            .collect::<Result<Vec<_>, _>>()
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|r| r)
+}
+
+/// 清除生成的资源清单，之后的校验以当前文件为准（修改过资源文件的开发者使用）
+#[tauri::command]
+pub fn reset_resource_manifests() {
+    invalidate_generated_manifests();
+}
+
+/// 为资源目录生成随资源包分发的清单文件，返回收录的文件数
+#[tauri::command]
+pub async fn generate_resource_manifest(path: String) -> Result<usize, String> {
+    tokio::task::spawn_blocking(move || {
+        let root = normalize_path(&path);
+        if !root.is_dir() {
+            return Err(format!("资源目录不存在: {}", root.display()));
+        }
+        let mut files = BTreeMap::new();
+        collect_files(&root, &root, &mut files);
+        let mut hashed = 0;
+        let (manifest, _) = build_manifest(&files, &HashMap::new(), &mut hashed)?;
+        let content = serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string())?;
+        let manifest_path = root.join(BUNDLED_MANIFEST_FILE);
+        std::fs::write(&manifest_path, content)
+            .map_err(|e| format!("写入 [{}] 失败: {}", manifest_path.display(), e))?;
+        info!(
+            "[资源校验] 已生成 {}（{} 个文件）",
+            manifest_path.display(),
+            manifest.files.len()
+        );
+        Ok(manifest.files.len())
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|r| r)
+}
@@ -0,0 +1,375 @@
+//! 资源更新命令
+//!
+//! 资源包（pipeline、模型等）独立于程序本体更新：通过 MirrorChyan 检查资源的新版本，
+//! 下载后沿用程序更新的流程做 SHA-256 校验、解压和增量/全量替换，
+//! 但拒绝包含程序文件的更新包，安装完成后重新加载各实例的资源使其立即生效
+
+use log::{info, warn};
+use sha2::{Digest, Sha256};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tauri::State;
+
+use super::download::download_file;
+use super::maa_core::reload_resource_impl;
+use super::proxy::resolve_proxy;
+use super::types::{MaaState, ResourceUpdateInfo, ResourceUpdateResult};
+use super::update::{
+    apply_full_update, apply_incremental_update, check_changes_json, cleanup_dir_contents,
+    extract_zip, move_to_old_folder,
+};
+use super::utils::build_user_agent;
+
+/// MirrorChyan 资源 API（主站与备用站，依次尝试）
+const MIRRORCHYAN_API_BASES: &[&str] = &[
+    "https://mirrorchyan.com/api/resources",
+    "https://mirrorchyan.net/api/resources",
+];
+
+/// 资源更新的下载与解压目录（相对目标目录）
+const RESOURCE_UPDATE_CACHE_DIR: &str = "cache/resource_update";
+
+/// 已安装资源版本的记录文件（相对目标目录）
+const RESOURCE_VERSION_FILE: &str = "cache/resource_version";
+
+/// 程序本体所在的根级目录，资源更新不允许写入
+const PROGRAM_DIRS: &[&str] = &["maafw"];
+
+/// 根目录下视为程序文件的扩展名（MXU 本体及其依赖库）
+const PROGRAM_EXTENSIONS: &[&str] = &["exe", "dll", "so", "dylib"];
+
+/// 构建 HTTP 客户端（手动代理优先，其次系统代理）
+fn build_client(proxy_url: Option<&str>, timeout_secs: u64) -> Result<reqwest::Client, String> {
+    let mut client_builder = reqwest::Client::builder()
+        .user_agent(build_user_agent())
+        .timeout(std::time::Duration::from_secs(timeout_secs))
+        .connect_timeout(std::time::Duration::from_secs(5));
+
+    client_builder = match resolve_proxy(proxy_url, "资源更新")? {
+        Some(proxy) => client_builder.proxy(proxy),
+        None => client_builder.no_proxy(),
+    };
+
+    client_builder
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))
+}
+
+/// 读取上次资源更新记录的版本
+fn read_installed_version(target_dir: &Path) -> Option<String> {
+    std::fs::read_to_string(target_dir.join(RESOURCE_VERSION_FILE))
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// 判断 latest 是否比 current 新：均为 semver 时按语义比较，否则版本号不同即视为有更新
+fn is_newer_version(latest: &str, current: &str) -> bool {
+    let parse = |v: &str| semver::Version::parse(v.trim().trim_start_matches(['v', 'V'])).ok();
+    match (parse(latest), parse(current)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => !latest.is_empty() && latest.trim() != current.trim(),
+    }
+}
+
+/// 计算文件 SHA-256 并与期望值比对（忽略大小写），期望值为空时视为校验失败
+pub fn verify_sha256(path: &Path, expected: &str) -> Result<(), String> {
+    if expected.trim().is_empty() {
+        return Err("缺少期望的 SHA-256，无法校验".to_string());
+    }
+    let mut file = std::fs::File::open(path)
+        .map_err(|e| format!("无法打开文件 [{}]: {}", path.display(), e))?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 1024 * 1024];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .map_err(|e| format!("读取文件失败: {}", e))?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    let actual: String = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    if actual.eq_ignore_ascii_case(expected.trim()) {
+        info!("SHA-256 校验通过 [{}]: {}", path.display(), actual);
+        Ok(())
+    } else {
+        Err(format!(
+            "SHA-256 不匹配（期望 {}，实际 {}）",
+            expected, actual
+        ))
+    }
+}
+
+/// 查找更新包中会覆盖程序本体的文件，返回其相对路径
+fn find_program_file(dir: &Path, rel: &Path) -> Option<String> {
+    for entry in std::fs::read_dir(dir).ok()?.flatten() {
+        let child_rel = rel.join(entry.file_name());
+        let path = entry.path();
+        if rel.as_os_str().is_empty() {
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            let is_program_file = path.is_file()
+                && path
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| PROGRAM_EXTENSIONS.contains(&e.to_lowercase().as_str()));
+            if PROGRAM_DIRS.contains(&name.as_str()) || is_program_file {
+                return Some(child_rel.to_string_lossy().to_string());
+            }
+        }
+        if path.is_dir() {
+            if let Some(found) = find_program_file(&path, &child_rel) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+/// 校验、解压并安装资源更新包，返回是否为增量更新
+fn install_resource_package(
+    package: &Path,
+    target_dir: &Path,
+    version_name: &str,
+    sha256: Option<&str>,
+) -> Result<bool, String> {
+    if let Some(expected) = sha256.filter(|h| !h.trim().is_empty()) {
+        if let Err(e) = verify_sha256(package, expected) {
+            let _ = std::fs::remove_file(package);
+            return Err(format!("更新包校验失败：{}", e));
+        }
+    } else {
+        warn!("[资源更新] 未提供 SHA-256，跳过校验");
+    }
+
+    let extract_dir = target_dir.join(RESOURCE_UPDATE_CACHE_DIR).join("extract");
+    if extract_dir.exists() {
+        cleanup_dir_contents(&extract_dir);
+    }
+    let extract_dir_str = extract_dir.to_string_lossy().to_string();
+    let target_dir_str = target_dir.to_string_lossy().to_string();
+
+    let result = (|| {
+        extract_zip(
+            package.to_string_lossy().to_string(),
+            extract_dir_str.clone(),
+        )?;
+
+        if let Some(file) = find_program_file(&extract_dir, Path::new("")) {
+            return Err(format!(
+                "资源更新包包含程序文件 {}，请通过程序更新安装",
+                file
+            ));
+        }
+
+        match check_changes_json(extract_dir_str.clone())? {
+            Some(changes) => {
+                info!(
+                    "[资源更新] 增量更新: deleted={}, added={}, modified={}",
+                    changes.deleted.len(),
+                    changes.added.len(),
+                    changes.modified.len()
+                );
+                apply_incremental_update(
+                    extract_dir_str.clone(),
+                    target_dir_str.clone(),
+                    changes.deleted,
+                )?;
+                Ok(true)
+            }
+            None => {
+                info!("[资源更新] 全量更新");
+                apply_full_update(extract_dir_str.clone(), target_dir_str.clone(), None)?;
+                Ok(false)
+            }
+        }
+    })();
+
+    cleanup_dir_contents(&extract_dir);
+    let incremental = result?;
+
+    let version_file = target_dir.join(RESOURCE_VERSION_FILE);
+    if let Err(e) = std::fs::write(&version_file, version_name) {
+        warn!("[资源更新] 写入版本记录失败: {}", e);
+    }
+    if let Err(e) = move_to_old_folder(package) {
+        warn!("[资源更新] 移动更新包失败: {}", e);
+    }
+    Ok(incremental)
+}
+
+/// 检查资源更新
+///
+/// resource_id 为资源在 MirrorChyan 上的 ID（与程序更新的 mirrorchyan_rid 分开），
+/// current_version 为空时使用 target_dir 下记录的上次资源更新版本
+#[tauri::command]
+pub async fn check_resource_update(
+    resource_id: String,
+    target_dir: String,
+    current_version: Option<String>,
+    cdk: Option<String>,
+    channel: Option<String>,
+    proxy_url: Option<String>,
+) -> Result<ResourceUpdateInfo, String> {
+    let current_version = current_version
+        .filter(|v| !v.trim().is_empty())
+        .or_else(|| read_installed_version(Path::new(&target_dir)));
+    let channel = channel.unwrap_or_else(|| "stable".to_string());
+    info!(
+        "[资源更新] 检查更新: {}, 当前版本: {:?}, 频道: {}",
+        resource_id, current_version, channel
+    );
+
+    let mut query = vec![
+        (
+            "current_version",
+            current_version.clone().unwrap_or_default(),
+        ),
+        ("user_agent", "MXU".to_string()),
+        ("channel", channel),
+    ];
+    if let Some(cdk) = cdk.filter(|c| !c.trim().is_empty()) {
+        query.push(("cdk", cdk));
+    }
+
+    let client = build_client(proxy_url.as_deref(), 10)?;
+    let mut response: Option<serde_json::Value> = None;
+    let mut last_error = String::new();
+    for base in MIRRORCHYAN_API_BASES {
+        let url = format!("{}/{}/latest", base, resource_id);
+        let json = match client.get(&url).query(&query).send().await {
+            Ok(resp) => resp.json::<serde_json::Value>().await,
+            Err(e) => {
+                warn!("[资源更新] {} 请求失败: {}", base, e);
+                last_error = format!("请求失败: {}", e);
+                continue;
+            }
+        };
+        match json {
+            Ok(json) => {
+                let code = json.get("code").and_then(|c| c.as_i64());
+                response = Some(json);
+                if code == Some(0) {
+                    break;
+                }
+                warn!("[资源更新] {} 返回错误: code={:?}，尝试备用站", base, code);
+            }
+            Err(e) => {
+                warn!("[资源更新] {} 响应解析失败: {}", base, e);
+                last_error = format!("解析响应失败: {}", e);
+            }
+        }
+    }
+
+    let json = response.ok_or_else(|| format!("检查资源更新失败: {}", last_error))?;
+    let code = json.get("code").and_then(|c| c.as_i64()).unwrap_or(-1);
+    let data = json.get("data").cloned().unwrap_or_default();
+    let str_field = |key: &str| data.get(key).and_then(|v| v.as_str()).map(String::from);
+
+    let version_name = str_field("version_name").unwrap_or_default();
+    let has_update = match current_version.as_deref() {
+        Some(current) => is_newer_version(&version_name, current),
+        None => !version_name.is_empty(),
+    };
+    info!(
+        "[资源更新] 检查完成: 最新版本={}, 有更新={}, code={}",
+        version_name, has_update, code
+    );
+
+    Ok(ResourceUpdateInfo {
+        has_update,
+        current_version,
+        version_name,
+        release_note: str_field("release_note").unwrap_or_default(),
+        download_url: str_field("url"),
+        sha256: str_field("sha256"),
+        update_type: str_field("update_type"),
+        channel: str_field("channel"),
+        file_size: data.get("filesize").and_then(|v| v.as_u64()),
+        error_code: (code != 0).then_some(code),
+        error_message: (code != 0)
+            .then(|| json.get("msg").and_then(|m| m.as_str()).map(String::from))
+            .flatten(),
+    })
+}
+
+/// 下载并安装资源更新
+///
+/// 复用 download_file 下载（进度通过 download-progress 事件上报），校验 SHA-256 后按
+/// 增量/全量方式更新 target_dir 中的资源，最后重新加载所有已加载资源的实例。
+/// 任务运行中的实例不会被重载，需在任务结束后手动重新加载
+#[tauri::command]
+pub async fn download_resource_update(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    url: String,
+    version_name: String,
+    target_dir: String,
+    sha256: Option<String>,
+    proxy_url: Option<String>,
+) -> Result<ResourceUpdateResult, String> {
+    info!(
+        "[资源更新] 下载资源更新: {} -> {}",
+        version_name, target_dir
+    );
+    let target_path = PathBuf::from(&target_dir);
+    let save_path = target_path
+        .join(RESOURCE_UPDATE_CACHE_DIR)
+        .join("resource_package.zip");
+
+    let download = download_file(
+        app.clone(),
+        url,
+        save_path.to_string_lossy().to_string(),
+        None,
+        proxy_url,
+        None,
+    )
+    .await?;
+
+    let version = version_name.clone();
+    let incremental = tokio::task::spawn_blocking(move || {
+        install_resource_package(
+            Path::new(&download.actual_save_path),
+            &target_path,
+            &version,
+            sha256.as_deref(),
+        )
+    })
+    .await
+    .map_err(|e| format!("资源更新任务执行失败: {}", e))??;
+
+    // 重新加载资源使更新立即生效
+    let instance_ids: Vec<String> = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        instances
+            .iter()
+            .filter(|(_, instance)| instance.resource.is_some())
+            .map(|(id, _)| id.clone())
+            .collect()
+    };
+    let mut reloaded_instances = Vec::new();
+    for instance_id in instance_ids {
+        match reload_resource_impl(&app, &state, &instance_id) {
+            Ok(_) => reloaded_instances.push(instance_id),
+            Err(e) => warn!("[资源更新] 实例 {} 重新加载资源失败: {}", instance_id, e),
+        }
+    }
+
+    info!(
+        "[资源更新] 完成: {} (incremental={}), 已重载实例: {:?}",
+        version_name, incremental, reloaded_instances
+    );
+    Ok(ResourceUpdateResult {
+        version_name,
+        incremental,
+        reloaded_instances,
+    })
+}
@@ -0,0 +1,237 @@
+//! 截图预处理
+//!
+//! 弱机或部分模拟器的截图偏暗、偏色时识别不稳定。开启后连接控制器时用自定义控制器包装
+//! 实际控制器：每次截图先由实际控制器截取，再按实例配置依次做 gamma / 对比度 / 亮度调整，
+//! 之后才交给 MaaFramework 识别。三项调整预先合成一张 256 项查找表逐字节查表，
+//! 结果编码为 BMP（不压缩）交回，额外开销只有一次解码和查表。
+//!
+//! 参数修改对下一帧立即生效；包装只在连接控制器时决定，开启后需重新连接控制器。
+//! 包装后的控制器只转发点击、滑动、按键、文字输入与应用启停，不与其他实例共享。
+
+use std::collections::{HashMap, HashSet};
+use std::io::Cursor;
+use std::sync::{LazyLock, Mutex};
+
+use log::{info, warn};
+use maa_framework::controller::Controller;
+use maa_framework::custom_controller::CustomControllerCallback;
+use maa_framework::MaaStatus;
+
+use super::types::ScreenshotPreprocess;
+
+/// 各实例的预处理参数（未设置的不处理）
+static CONFIGS: LazyLock<Mutex<HashMap<String, ScreenshotPreprocess>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 当前控制器已被包装的实例
+static WRAPPED: LazyLock<Mutex<HashSet<String>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
+
+fn preprocess_config(instance_id: &str) -> Option<ScreenshotPreprocess> {
+    CONFIGS
+        .lock()
+        .ok()
+        .and_then(|configs| configs.get(instance_id).copied())
+}
+
+/// 实例是否开启了截图预处理（连接控制器时据此决定是否包装）
+pub fn is_enabled(instance_id: &str) -> bool {
+    preprocess_config(instance_id).is_some()
+}
+
+/// 按 gamma → 对比度 → 亮度的顺序合成查找表
+fn build_lut(config: &ScreenshotPreprocess) -> [u8; 256] {
+    let mut lut = [0u8; 256];
+    for (i, value) in lut.iter_mut().enumerate() {
+        let mut x = (i as f32 / 255.0).powf(1.0 / config.gamma);
+        x = (x - 0.5) * config.contrast + 0.5;
+        x += config.brightness as f32 / 255.0;
+        *value = (x.clamp(0.0, 1.0) * 255.0).round() as u8;
+    }
+    lut
+}
+
+/// 对编码后的截图做预处理，返回 BMP 编码的结果
+fn apply(encoded: &[u8], config: &ScreenshotPreprocess) -> Result<Vec<u8>, String> {
+    let mut image = image::load_from_memory(encoded)
+        .map_err(|e| format!("解码截图失败: {}", e))?
+        .into_rgb8();
+    let lut = build_lut(config);
+    for byte in image.iter_mut() {
+        *byte = lut[*byte as usize];
+    }
+    let mut out = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut out), image::ImageFormat::Bmp)
+        .map_err(|e| format!("编码截图失败: {}", e))?;
+    Ok(out)
+}
+
+/// 截图前做预处理的包装控制器，其余操作转发给实际控制器
+struct PreprocessController {
+    instance_id: String,
+    inner: Controller,
+}
+
+impl PreprocessController {
+    fn wait_ok(&self, id: Result<i64, impl std::fmt::Display>) -> bool {
+        match id {
+            Ok(id) => self.inner.wait(id) == MaaStatus::SUCCEEDED,
+            Err(e) => {
+                warn!(
+                    "[screenshot_preprocess] Inner controller request failed: {}",
+                    e
+                );
+                false
+            }
+        }
+    }
+}
+
+impl CustomControllerCallback for PreprocessController {
+    fn connect(&self) -> bool {
+        self.wait_ok(self.inner.post_connection())
+    }
+
+    fn connected(&self) -> bool {
+        self.inner.connected()
+    }
+
+    fn request_uuid(&self) -> Option<String> {
+        Some(format!("MXU-PREPROCESS-{}", self.instance_id))
+    }
+
+    fn screencap(&self) -> Option<Vec<u8>> {
+        if !self.wait_ok(self.inner.post_screencap()) {
+            return None;
+        }
+        let encoded = self.inner.cached_image().ok()?.to_vec()?;
+        // 参数可能在运行中被关闭，此时原样返回
+        let Some(config) = preprocess_config(&self.instance_id) else {
+            return Some(encoded);
+        };
+        match apply(&encoded, &config) {
+            Ok(processed) => Some(processed),
+            Err(e) => {
+                warn!("[screenshot_preprocess] {}: {}", self.instance_id, e);
+                Some(encoded)
+            }
+        }
+    }
+
+    fn get_info(&self) -> String {
+        "{\"type\":\"MXU_PREPROCESS\"}".to_string()
+    }
+
+    fn click(&self, x: i32, y: i32) -> bool {
+        self.wait_ok(self.inner.post_click(x, y))
+    }
+
+    fn swipe(&self, x1: i32, y1: i32, x2: i32, y2: i32, duration: i32) -> bool {
+        self.wait_ok(self.inner.post_swipe(x1, y1, x2, y2, duration))
+    }
+
+    fn click_key(&self, keycode: i32) -> bool {
+        self.wait_ok(self.inner.post_click_key(keycode))
+    }
+
+    fn input_text(&self, text: &str) -> bool {
+        self.wait_ok(self.inner.post_input_text(text))
+    }
+
+    fn start_app(&self, intent: &str) -> bool {
+        self.wait_ok(self.inner.post_start_app(intent))
+    }
+
+    fn stop_app(&self, intent: &str) -> bool {
+        self.wait_ok(self.inner.post_stop_app(intent))
+    }
+}
+
+/// 连接控制器时调用：`wrap` 为 true 时把实际控制器包装为预处理控制器，否则原样返回
+///
+/// `wrap` 应在查找 ControllerPool 前由 [`is_enabled`] 取得：包装后的控制器不放入池中共享
+pub fn wrap_controller(
+    instance_id: &str,
+    controller: Controller,
+    wrap: bool,
+) -> Result<Controller, String> {
+    if let Ok(mut wrapped) = WRAPPED.lock() {
+        if wrap {
+            wrapped.insert(instance_id.to_string());
+        } else {
+            wrapped.remove(instance_id);
+        }
+    }
+    if !wrap {
+        return Ok(controller);
+    }
+    info!(
+        "[screenshot_preprocess] Wrapping controller for {}",
+        instance_id
+    );
+    Controller::new_custom(PreprocessController {
+        instance_id: instance_id.to_string(),
+        inner: controller,
+    })
+    .map_err(|e| e.to_string())
+}
+
+/// 查询实例当前生效的预处理参数（仅在控制器已包装时生效）
+pub fn active_config(instance_id: &str) -> Option<ScreenshotPreprocess> {
+    let wrapped = WRAPPED
+        .lock()
+        .map(|wrapped| wrapped.contains(instance_id))
+        .unwrap_or(false);
+    wrapped.then(|| preprocess_config(instance_id)).flatten()
+}
+
+/// 移除实例的截图预处理设置（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut entries) = CONFIGS.lock() {
+        entries.remove(instance_id);
+    }
+    if let Ok(mut entries) = WRAPPED.lock() {
+        entries.remove(instance_id);
+    }
+}
+
+/// 设置实例的截图预处理参数，传 None 关闭
+///
+/// 返回是否需要重新连接控制器才能生效（开启时当前控制器尚未包装）
+#[tauri::command]
+pub fn set_screenshot_preprocess(
+    instance_id: String,
+    config: Option<ScreenshotPreprocess>,
+) -> Result<bool, String> {
+    if let Some(config) = &config {
+        if !(-100..=100).contains(&config.brightness) {
+            return Err("亮度需在 -100 到 100 之间".to_string());
+        }
+        if !(0.5..=2.0).contains(&config.contrast) {
+            return Err("对比度需在 0.5 到 2 之间".to_string());
+        }
+        if !(0.2..=5.0).contains(&config.gamma) {
+            return Err("gamma 需在 0.2 到 5 之间".to_string());
+        }
+    }
+    info!("set_screenshot_preprocess: {} -> {:?}", instance_id, config);
+    let enabled = config.is_some();
+    {
+        let mut configs = CONFIGS.lock().map_err(|e| e.to_string())?;
+        match config {
+            Some(config) => configs.insert(instance_id.clone(), config),
+            None => configs.remove(&instance_id),
+        };
+    }
+    let wrapped = WRAPPED
+        .lock()
+        .map(|wrapped| wrapped.contains(&instance_id))
+        .unwrap_or(false);
+    Ok(enabled && !wrapped)
+}
+
+/// 获取实例的截图预处理参数，未开启时返回 None
+#[tauri::command]
+pub fn get_screenshot_preprocess(instance_id: String) -> Option<ScreenshotPreprocess> {
+    preprocess_config(&instance_id)
+}
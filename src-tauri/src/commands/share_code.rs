@@ -0,0 +1,153 @@
+//! 任务配置分享码
+//!
+//! 把实例的任务队列及各任务的选项值（即生成 pipeline override 的全部输入）压缩编码为一段
+//! 短字符串，方便在群里直接粘贴分享。格式为 `MXU<版本>:` 前缀 + base64url(deflate(JSON))。
+//! 编码后超过长度上限时提示改用文件分享；导入时校验版本、长度、解压后大小和结构，
+//! 防止恶意构造的分享码（如解压炸弹）。选项值与当前 interface 的匹配由前端完成。
+
+use std::io::{Read, Write};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+use log::info;
+
+use super::types::SharePayload;
+
+/// 当前分享码版本
+const SHARE_CODE_VERSION: u32 = 1;
+
+/// 分享码最大长度（字符），超出时改用文件分享
+const MAX_SHARE_CODE_LEN: usize = 6000;
+
+/// 解压后 JSON 的最大字节数
+const MAX_PAYLOAD_BYTES: u64 = 256 * 1024;
+
+/// 单个分享码最多包含的任务数
+const MAX_TASKS: usize = 200;
+
+/// 名称类字段的最大长度
+const MAX_NAME_LEN: usize = 256;
+
+/// 单个任务最多包含的选项数
+const MAX_OPTIONS_PER_TASK: usize = 256;
+
+/// 合法的选项值类型（与前端 OptionValue 一致）
+const OPTION_TYPES: &[&str] = &["select", "checkbox", "switch", "input", "hotkey"];
+
+fn share_code_prefix() -> String {
+    format!("MXU{}:", SHARE_CODE_VERSION)
+}
+
+/// 校验分享内容的结构与大小
+fn validate_payload(payload: &SharePayload) -> Result<(), String> {
+    if payload.tasks.is_empty() {
+        return Err("分享内容中没有任务".to_string());
+    }
+    if payload.tasks.len() > MAX_TASKS {
+        return Err(format!("任务数量超过上限 {}", MAX_TASKS));
+    }
+    if payload
+        .project
+        .as_ref()
+        .is_some_and(|p| p.len() > MAX_NAME_LEN)
+    {
+        return Err("项目名过长".to_string());
+    }
+    for task in &payload.tasks {
+        if task.name.is_empty() || task.name.len() > MAX_NAME_LEN {
+            return Err("任务名为空或过长".to_string());
+        }
+        if task
+            .custom_name
+            .as_ref()
+            .is_some_and(|n| n.len() > MAX_NAME_LEN)
+        {
+            return Err(format!("任务 \"{}\" 的自定义名称过长", task.name));
+        }
+        if task.options.len() > MAX_OPTIONS_PER_TASK {
+            return Err(format!("任务 \"{}\" 的选项数量超过上限", task.name));
+        }
+        for (key, value) in &task.options {
+            let option_type = value.get("type").and_then(|t| t.as_str());
+            if key.len() > MAX_NAME_LEN || !option_type.is_some_and(|t| OPTION_TYPES.contains(&t)) {
+                return Err(format!(
+                    "任务 \"{}\" 的选项 \"{}\" 格式无效",
+                    task.name, key
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 把任务队列配置编码为分享码
+#[tauri::command]
+pub fn export_share_code(payload: SharePayload) -> Result<String, String> {
+    validate_payload(&payload)?;
+    let json = serde_json::to_vec(&payload).map_err(|e| format!("序列化失败: {}", e))?;
+
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(&json)
+        .map_err(|e| format!("压缩失败: {}", e))?;
+    let compressed = encoder.finish().map_err(|e| format!("压缩失败: {}", e))?;
+
+    let code = format!(
+        "{}{}",
+        share_code_prefix(),
+        URL_SAFE_NO_PAD.encode(compressed)
+    );
+    if code.len() > MAX_SHARE_CODE_LEN {
+        return Err(format!(
+            "配置过大（分享码 {} 字符，上限 {}），请改用导出配置文件分享",
+            code.len(),
+            MAX_SHARE_CODE_LEN
+        ));
+    }
+    info!(
+        "export_share_code: {} tasks, {} bytes json -> {} chars",
+        payload.tasks.len(),
+        json.len(),
+        code.len()
+    );
+    Ok(code)
+}
+
+/// 解码分享码，返回其中的任务队列配置
+#[tauri::command]
+pub fn import_share_code(code: String) -> Result<SharePayload, String> {
+    // 聊天软件粘贴时可能带入换行或空格
+    let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+    if code.len() > MAX_SHARE_CODE_LEN {
+        return Err("分享码过长".to_string());
+    }
+    let Some(body) = code.strip_prefix(&share_code_prefix()) else {
+        let other_version = code
+            .strip_prefix("MXU")
+            .and_then(|rest| rest.split_once(':'))
+            .and_then(|(version, _)| version.parse::<u32>().ok());
+        return Err(match other_version {
+            Some(version) => format!("不支持的分享码版本 {}，请更新 MXU", version),
+            None => "不是有效的 MXU 分享码".to_string(),
+        });
+    };
+
+    let compressed = URL_SAFE_NO_PAD
+        .decode(body)
+        .map_err(|_| "分享码内容已损坏".to_string())?;
+    // 限制解压后的大小，防止解压炸弹
+    let mut json = Vec::new();
+    DeflateDecoder::new(compressed.as_slice())
+        .take(MAX_PAYLOAD_BYTES + 1)
+        .read_to_end(&mut json)
+        .map_err(|_| "分享码内容已损坏".to_string())?;
+    if json.len() as u64 > MAX_PAYLOAD_BYTES {
+        return Err("分享码内容过大".to_string());
+    }
+
+    let payload: SharePayload =
+        serde_json::from_slice(&json).map_err(|e| format!("分享码内容格式无效: {}", e))?;
+    validate_payload(&payload)?;
+    info!("import_share_code: {} tasks", payload.tasks.len());
+    Ok(payload)
+}
@@ -0,0 +1,217 @@
+//! 单实例检测
+//!
+//! 同一数据目录下同时运行多个 MXU 会争抢更新目录、日志文件和 WebView2 数据目录，
+//! 还可能同时操作同一台模拟器。启动时用按数据目录命名的 Windows 命名 Mutex 作为单实例锁，
+//! 检测到已有实例时提示用户：切换到已运行的窗口，或者强制多开。
+//! 需要长期多开时应通过 `--data-dir` 为每个实例指定不同的数据目录，此时互不影响。
+//!
+//! 切换窗口通过命名 Event 通知已运行的实例，由它自己把主窗口显示到前台（托盘隐藏时也能唤起）。
+//! 非 Windows 平台暂不检测。
+
+/// 单实例检测结果
+pub enum InstanceCheck {
+    /// 本进程是该数据目录下唯一的实例（或用户选择了强制多开），继续启动
+    Continue,
+    /// 已通知已运行的实例显示窗口，本进程应退出
+    Exit,
+}
+
+#[cfg(windows)]
+mod win {
+    use std::hash::{Hash, Hasher};
+    use std::sync::OnceLock;
+
+    use winsafe::co::{DLGID, ERROR, MB, WAIT};
+    use winsafe::{GetLastError, HEVENT, HWND};
+
+    /// 本进程持有的单实例锁（进程退出时由系统释放）
+    static INSTANCE_MUTEX: OnceLock<usize> = OnceLock::new();
+
+    /// 本进程持有的唤起事件句柄（进程退出时由系统释放）
+    static ACTIVATE_EVENT: OnceLock<usize> = OnceLock::new();
+
+    /// WinSafe 未封装命名 Mutex，单独声明
+    mod ffi {
+        use std::ffi::c_void;
+
+        #[link(name = "kernel32")]
+        extern "system" {
+            pub fn CreateMutexW(
+                attributes: *const c_void,
+                initial_owner: i32,
+                name: *const u16,
+            ) -> *mut c_void;
+        }
+    }
+
+    /// 按数据目录生成对象名后缀，不同数据目录的实例互不冲突
+    fn object_suffix() -> String {
+        let data_dir = crate::commands::utils::get_app_data_dir()
+            .map(|p| p.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        data_dir.hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+
+    fn mutex_name() -> String {
+        format!("Local\\MXU-SingleInstance-{}", object_suffix())
+    }
+
+    fn activate_event_name() -> String {
+        format!("Local\\MXU-Activate-{}", object_suffix())
+    }
+
+    /// 创建单实例锁和唤起事件，返回是否已有实例持有同名锁
+    fn acquire() -> bool {
+        let name: Vec<u16> = mutex_name()
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .collect();
+        let mutex = unsafe { ffi::CreateMutexW(std::ptr::null(), 0, name.as_ptr()) };
+        if mutex.is_null() {
+            // 创建失败时不阻止启动
+            log::warn!("Failed to create single instance mutex: {}", GetLastError());
+            return false;
+        }
+        let already_exists = GetLastError() == ERROR::ALREADY_EXISTS;
+        let _ = INSTANCE_MUTEX.set(mutex as usize);
+
+        // 自动复位事件：已运行实例收到一次通知就显示一次窗口
+        match HEVENT::CreateEvent(None, false, false, Some(&activate_event_name())) {
+            Ok(mut event) => {
+                let _ = ACTIVATE_EVENT.set(event.leak().ptr() as usize);
+            }
+            Err(e) => log::warn!("Failed to create activate event: {}", e),
+        }
+        already_exists
+    }
+
+    /// 通知已运行的实例显示主窗口
+    fn notify_running_instance() -> bool {
+        HEVENT::CreateEvent(None, false, false, Some(&activate_event_name()))
+            .and_then(|event| event.SetEvent())
+            .is_ok()
+    }
+
+    const PROMPT_ZH: &str = "已有 MXU 正在运行（使用同一数据目录）。\n\n\
+        同时运行多个实例可能互相操作同一设备，并导致更新、日志和 WebView2 数据目录冲突。\n\n\
+        是：切换到已运行的窗口\n\
+        否：仍然多开（建议改用 --data-dir 为每个实例指定不同的数据目录）\n\
+        取消：退出";
+
+    const PROMPT_EN: &str = "MXU is already running with the same data directory.\n\n\
+        Running multiple instances may operate the same device and cause conflicts in updates, \
+        logs and the WebView2 data directory.\n\n\
+        Yes: switch to the running window\n\
+        No: run another instance anyway \
+        (use --data-dir to give each instance its own data directory)\n\
+        Cancel: exit";
+
+    /// 读取配置中的界面语言（此时托盘尚未初始化），与托盘一致：未设置或跟随系统时按中文处理
+    fn is_chinese_language() -> bool {
+        let config = crate::commands::AppConfigState::default();
+        if let Ok(exe_dir) = crate::commands::utils::get_exe_directory() {
+            // 配置文件名取决于 interface.json 中的项目名称
+            config.load_interface(&exe_dir);
+        }
+        let Ok(data_dir) = crate::commands::utils::get_app_data_dir() else {
+            return true;
+        };
+        config.load_config(&data_dir);
+        config
+            .config
+            .lock()
+            .ok()
+            .and_then(|c| {
+                c.get("settings")
+                    .and_then(|s| s.get("language"))
+                    .and_then(|v| v.as_str())
+                    .map(|l| l == "system" || l.to_ascii_lowercase().starts_with("zh"))
+            })
+            .unwrap_or(true)
+    }
+
+    fn ask_user() -> DLGID {
+        let prompt = if is_chinese_language() {
+            PROMPT_ZH
+        } else {
+            PROMPT_EN
+        };
+        HWND::DESKTOP
+            .MessageBox(
+                prompt,
+                "MXU",
+                MB::YESNOCANCEL | MB::ICONWARNING | MB::SETFOREGROUND,
+            )
+            .unwrap_or(DLGID::CANCEL)
+    }
+
+    pub fn check(autostart: bool) -> super::InstanceCheck {
+        use super::InstanceCheck;
+
+        if !acquire() {
+            return InstanceCheck::Continue;
+        }
+
+        // 开机自启动时不弹窗，直接唤起已运行的实例，避免重复执行任务
+        let choice = if autostart { DLGID::YES } else { ask_user() };
+        match choice {
+            DLGID::YES => {
+                if !notify_running_instance() {
+                    log::warn!("Failed to notify running instance to show window");
+                }
+                InstanceCheck::Exit
+            }
+            DLGID::NO => {
+                log::warn!("Another MXU instance is running, user chose to continue anyway");
+                InstanceCheck::Continue
+            }
+            _ => InstanceCheck::Exit,
+        }
+    }
+
+    /// 等待其他进程的唤起通知（阻塞，需在独立线程中调用）
+    pub fn listen_activate(on_activate: impl Fn()) {
+        let Some(&event) = ACTIVATE_EVENT.get() else {
+            return;
+        };
+        let event = unsafe { HEVENT::from_ptr(event as *mut _) };
+        while let Ok(WAIT::OBJECT_0) = event.WaitForSingleObject(None) {
+            on_activate();
+        }
+    }
+}
+
+/// 启动时检测同一数据目录下是否已有 MXU 在运行（需在创建窗口前调用）
+///
+/// - 没有其他实例：持有单实例锁，返回 `Continue`
+/// - 已有实例：弹窗询问切换到已有窗口（返回 `Exit`）还是强制多开（返回 `Continue`）；
+///   `--autostart` 模式下不询问，直接唤起已有窗口
+pub fn check_single_instance(autostart: bool) -> InstanceCheck {
+    #[cfg(windows)]
+    {
+        win::check(autostart)
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = autostart;
+        InstanceCheck::Continue
+    }
+}
+
+/// 在后台线程监听其他实例发来的唤起通知，收到后显示主窗口
+pub fn start_activate_listener(app: tauri::AppHandle) {
+    #[cfg(windows)]
+    {
+        std::thread::spawn(move || {
+            win::listen_activate(|| crate::tray::show_main_window(&app));
+        });
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = app;
+    }
+}
@@ -0,0 +1,165 @@
+//! 操作节奏（速度系数）
+//!
+//! MaaFramework 没有全局调整动作节奏的选项，因此在 MXU 层按实例设置速度系数：
+//! 提交任务时通过 pipeline override 缩放各节点的 `pre_delay` / `post_delay`，
+//! 实际间隔 = 原间隔 / 系数。响应慢的模拟器调低系数可减少漏点，性能好的设备调高可节省时间。
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use log::info;
+use maa_framework::resource::Resource;
+
+use super::utils::{merge_pipeline_override, parse_pipeline_override};
+
+/// 默认速度系数
+pub const DEFAULT_SPEED: f64 = 1.0;
+
+/// 速度系数允许的范围
+const SPEED_RANGE: std::ops::RangeInclusive<f64> = 0.25..=4.0;
+
+/// 需要缩放的等待字段及 MaaFramework 的默认值（毫秒）
+const DELAY_FIELDS: &[(&str, u64)] = &[("pre_delay", 200), ("post_delay", 200)];
+
+/// 各实例的速度系数（未设置的实例为默认值）
+static SPEEDS: LazyLock<Mutex<HashMap<String, f64>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 实例当前的速度系数
+pub fn speed_factor(instance_id: &str) -> f64 {
+    SPEEDS
+        .lock()
+        .ok()
+        .and_then(|speeds| speeds.get(instance_id).copied())
+        .unwrap_or(DEFAULT_SPEED)
+}
+
+fn scale_delay(ms: u64, speed: f64) -> u64 {
+    (ms as f64 / speed).round() as u64
+}
+
+/// 按实例速度系数缩放任务所有节点的动作前后等待，系数为默认值时原样返回
+///
+/// 节点的最终等待 = 资源原始定义叠加任务 override 后的值（未定义时取默认值）
+pub fn apply_speed(
+    resource: &Resource,
+    instance_id: &str,
+    pipeline_override: String,
+) -> Result<String, String> {
+    let speed = speed_factor(instance_id);
+    if speed == DEFAULT_SPEED {
+        return Ok(pipeline_override);
+    }
+
+    let mut merged = serde_json::Map::new();
+    for layer in parse_pipeline_override(&pipeline_override)? {
+        merge_pipeline_override(&mut merged, &layer);
+    }
+
+    let mut nodes: Vec<String> = resource
+        .node_list()
+        .map_err(|e| format!("获取节点列表失败: {}", e))?;
+    let extra: Vec<String> = merged
+        .keys()
+        .filter(|k| !nodes.contains(k))
+        .cloned()
+        .collect();
+    nodes.extend(extra);
+
+    let mut scaled = serde_json::Map::new();
+    for node in nodes {
+        let data = resource
+            .get_node_data(&node)
+            .ok()
+            .flatten()
+            .and_then(|data| serde_json::from_str::<serde_json::Value>(&data).ok());
+        let overridden = merged.get(&node);
+
+        let mut fields = serde_json::Map::new();
+        for (key, default) in DELAY_FIELDS {
+            let value = overridden
+                .and_then(|n| n.get(*key))
+                .or_else(|| data.as_ref().and_then(|n| n.get(*key)));
+            // 非数字的写法无法缩放，保持原样
+            let ms = match value {
+                Some(v) => match v.as_u64() {
+                    Some(ms) => ms,
+                    None => continue,
+                },
+                None => *default,
+            };
+            fields.insert(key.to_string(), scale_delay(ms, speed).into());
+        }
+        scaled.insert(node, serde_json::Value::Object(fields));
+    }
+
+    info!(
+        "apply_speed: {} x{}, {} node(s) scaled",
+        instance_id,
+        speed,
+        scaled.len()
+    );
+    merge_pipeline_override(&mut merged, &scaled);
+    Ok(serde_json::Value::Object(merged).to_string())
+}
+
+/// 运行中热更 override 时按速度系数缩放其中显式给出的等待字段，系数为默认值时原样返回
+///
+/// 热更只叠加在已缩放的任务上，未涉及的节点与字段保持提交时的缩放结果
+pub fn apply_speed_to_layers(
+    instance_id: &str,
+    pipeline_override: String,
+) -> Result<String, String> {
+    let speed = speed_factor(instance_id);
+    if speed == DEFAULT_SPEED {
+        return Ok(pipeline_override);
+    }
+
+    let mut merged = serde_json::Map::new();
+    for layer in parse_pipeline_override(&pipeline_override)? {
+        merge_pipeline_override(&mut merged, &layer);
+    }
+    for fields in merged.values_mut() {
+        let Some(fields) = fields.as_object_mut() else {
+            continue;
+        };
+        for (key, _) in DELAY_FIELDS {
+            if let Some(ms) = fields.get(*key).and_then(|v| v.as_u64()) {
+                fields.insert(key.to_string(), scale_delay(ms, speed).into());
+            }
+        }
+    }
+    Ok(serde_json::Value::Object(merged).to_string())
+}
+
+/// 移除实例的运行速度设置（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut entries) = SPEEDS.lock() {
+        entries.remove(instance_id);
+    }
+}
+
+/// 设置实例的速度系数，对之后提交的任务生效
+#[tauri::command]
+pub fn maa_set_speed(instance_id: String, speed: f64) -> Result<(), String> {
+    if !SPEED_RANGE.contains(&speed) {
+        return Err(format!(
+            "速度系数需在 {} 到 {} 之间",
+            SPEED_RANGE.start(),
+            SPEED_RANGE.end()
+        ));
+    }
+    info!("maa_set_speed: {} -> {}", instance_id, speed);
+    let mut speeds = SPEEDS.lock().map_err(|e| e.to_string())?;
+    if speed == DEFAULT_SPEED {
+        speeds.remove(&instance_id);
+    } else {
+        speeds.insert(instance_id, speed);
+    }
+    Ok(())
+}
+
+/// 查询实例的速度系数
+#[tauri::command]
+pub fn maa_get_speed(instance_id: String) -> f64 {
+    speed_factor(&instance_id)
+}
@@ -6,9 +6,40 @@ use log::debug;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use tauri::State;
+use tauri::{Emitter, State};
 
-use super::types::{AdbDevice, AllInstanceStates, InstanceState, MaaState, Win32Window};
+use super::types::{
+    AdbDevice, AllInstanceStates, ControllerConfig, InstanceState, InstanceSummary, MaaState,
+    PingResult, TaggedLogEntry, Win32Window,
+};
+use super::utils::instance_label;
+
+/// 后端健康检查：返回当前时间戳和关键子系统状态
+///
+/// 只用 try_lock 读取且不调用 MaaFramework，保证不会阻塞；前端据此区分界面卡住还是后端卡住
+#[tauri::command]
+pub fn maa_ping(state: State<Arc<MaaState>>) -> PingResult {
+    let maa_initialized = state.initialized.try_lock().ok().map(|g| *g);
+    let (instance_count, running_instances) = match state.instances.try_lock() {
+        Ok(instances) => (
+            Some(instances.len()),
+            Some(
+                instances
+                    .values()
+                    .filter(|i| i.task_run_state.overall_status.as_deref() == Some("Running"))
+                    .count(),
+            ),
+        ),
+        Err(_) => (None, None),
+    };
+
+    PingResult {
+        timestamp_ms: chrono::Utc::now().timestamp_millis(),
+        maa_initialized,
+        instance_count,
+        running_instances,
+    }
+}
 
 /// 获取单个实例的运行时状态
 #[tauri::command]
@@ -90,6 +121,61 @@ pub fn maa_get_all_states(state: State<Arc<MaaState>>) -> Result<AllInstanceStat
     })
 }
 
+/// 控制器配置对应的设备标识
+fn device_label(config: &ControllerConfig) -> String {
+    match config {
+        ControllerConfig::Adb { address, .. } => address.clone(),
+        ControllerConfig::Win32 { handle, .. } => format!("Win32 0x{:X}", handle),
+        ControllerConfig::WlRoots {
+            wlr_socket_path, ..
+        } => wlr_socket_path.clone(),
+        ControllerConfig::Gamepad { handle, .. } => format!("Gamepad 0x{:X}", handle),
+        ControllerConfig::PlayCover { address, .. } => address.clone(),
+        ControllerConfig::Dummy { .. } => "Dummy".to_string(),
+    }
+}
+
+/// 列举实例的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
+pub fn list_instances_impl(state: &MaaState) -> Result<Vec<InstanceSummary>, String> {
+    let instances = state.instances.lock().map_err(|e| e.to_string())?;
+
+    let mut list: Vec<_> = instances
+        .iter()
+        .map(|(id, instance)| {
+            let connected = instance.controller.as_ref().is_some_and(|c| c.connected());
+            (
+                instance.created_at,
+                InstanceSummary {
+                    id: id.clone(),
+                    name: instance.name.clone(),
+                    created_at: instance.created_at.map(|t| t.to_rfc3339()),
+                    device: instance
+                        .controller_config
+                        .as_ref()
+                        .filter(|_| connected)
+                        .map(device_label),
+                },
+            )
+        })
+        .collect();
+    // 按创建顺序返回（无创建时间的排在最后）
+    list.sort_by(|(a_time, a), (b_time, b)| {
+        a_time
+            .is_none()
+            .cmp(&b_time.is_none())
+            .then(a_time.cmp(b_time))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    Ok(list.into_iter().map(|(_, summary)| summary).collect())
+}
+
+/// 列举所有实例的精简信息（id、名称、创建时间、已连接设备），按创建顺序返回
+#[tauri::command]
+pub fn maa_list_instances(state: State<Arc<MaaState>>) -> Result<Vec<InstanceSummary>, String> {
+    debug!("maa_list_instances called");
+    list_instances_impl(&state)
+}
+
 /// 获取缓存的 ADB 设备列表
 #[tauri::command]
 pub fn maa_get_cached_adb_devices(state: State<Arc<MaaState>>) -> Result<Vec<AdbDevice>, String> {
@@ -123,24 +209,60 @@ pub fn maa_get_cached_wlroots_sockets(state: State<Arc<MaaState>>) -> Result<Vec
 }
 
 /// 由前端调用，将已格式化的日志行输出到 stdout
+///
+/// 传入 instance_id 时每行带上实例标签（显示名或 ID），多开时便于区分
 #[tauri::command]
-pub fn log_to_stdout(message: String) {
+pub fn log_to_stdout(state: State<Arc<MaaState>>, message: String, instance_id: Option<String>) {
     let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S.%3f");
+    let prefix = instance_id
+        .map(|id| format!("[{}] ", instance_label(&state, &id)))
+        .unwrap_or_default();
     for line in message.lines() {
-        println!("[{timestamp}] {line}");
+        println!("[{timestamp}] {prefix}{line}");
     }
 }
 
-/// 前端推送一条运行日志到后端缓冲区
+/// 前端推送一条运行日志到后端缓冲区，返回后端分配的日志序号
+///
+/// 日志悬浮窗打开时同时转发给悬浮窗
 #[tauri::command]
 pub fn push_log(
+    app: tauri::AppHandle,
     state: State<Arc<MaaState>>,
     instance_id: String,
-    entry: super::types::LogEntryDto,
+    mut entry: super::types::LogEntryDto,
+) -> Result<u64, String> {
+    let seq = {
+        let mut buffer = state.log_buffer.lock().map_err(|e| e.to_string())?;
+        buffer.push(&instance_id, entry.clone())
+    };
+    super::instance_log::append(&instance_id, &entry);
+    entry.seq = Some(seq);
+    super::log_overlay::forward_log(&app, &state, &instance_id, &entry);
+    Ok(seq)
+}
+
+/// 请求主窗口日志面板跳转到指定序号的日志（悬浮窗等其他窗口点击日志时调用）
+#[tauri::command]
+pub fn jump_to_log(
+    app: tauri::AppHandle,
+    state: State<Arc<MaaState>>,
+    seq: u64,
 ) -> Result<(), String> {
-    let mut buffer = state.log_buffer.lock().map_err(|e| e.to_string())?;
-    buffer.push(&instance_id, entry);
-    Ok(())
+    let event = {
+        let buffer = state.log_buffer.lock().map_err(|e| e.to_string())?;
+        let (instance_id, entry) = buffer
+            .find_by_seq(seq)
+            .ok_or_else(|| format!("日志 #{} 已被清理", seq))?;
+        super::types::LogJumpEvent {
+            instance_id: instance_id.to_string(),
+            seq,
+            log_id: entry.id.clone(),
+            timestamp: entry.timestamp.clone(),
+        }
+    };
+    app.emit("maa-log-jump", event)
+        .map_err(|e| format!("Failed to emit maa-log-jump: {}", e))
 }
 
 /// 获取所有实例的运行日志（用于页面刷新后恢复）
@@ -156,6 +278,49 @@ pub fn get_all_logs(
         .collect())
 }
 
+/// 合并多个实例的运行日志的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
+///
+/// 每条日志带上 instance_id 和实例显示名，按时间排序；instance_ids 为空时合并所有实例
+pub fn get_merged_logs_impl(
+    state: &MaaState,
+    instance_ids: Option<&[String]>,
+) -> Result<Vec<TaggedLogEntry>, String> {
+    let names: HashMap<String, Option<String>> = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        instances
+            .iter()
+            .map(|(id, instance)| (id.clone(), instance.name.clone()))
+            .collect()
+    };
+    let buffer = state.log_buffer.lock().map_err(|e| e.to_string())?;
+
+    let mut merged: Vec<TaggedLogEntry> = buffer
+        .get_all()
+        .iter()
+        .filter(|(id, _)| instance_ids.is_none_or(|ids| ids.contains(id)))
+        .flat_map(|(id, entries)| {
+            let instance_name = names.get(id).cloned().flatten();
+            entries.iter().map(move |entry| TaggedLogEntry {
+                instance_id: id.clone(),
+                instance_name: instance_name.clone(),
+                entry: entry.clone(),
+            })
+        })
+        .collect();
+    // 时间戳为 ISO 8601 (UTC)，按字符串排序即按时间排序
+    merged.sort_by(|a, b| a.entry.timestamp.cmp(&b.entry.timestamp));
+    Ok(merged)
+}
+
+/// 获取多个实例合并后的运行日志（带实例标签，按时间排序）
+#[tauri::command]
+pub fn get_merged_logs(
+    state: State<Arc<MaaState>>,
+    instance_ids: Option<Vec<String>>,
+) -> Result<Vec<TaggedLogEntry>, String> {
+    get_merged_logs_impl(&state, instance_ids.as_deref())
+}
+
 /// 清空指定实例的运行日志
 #[tauri::command]
 pub fn clear_instance_logs(state: State<Arc<MaaState>>, instance_id: String) -> Result<(), String> {
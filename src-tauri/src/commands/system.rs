@@ -2,23 +2,26 @@
 //!
 //! 提供权限检查、系统信息查询、全局选项设置等功能
 
+use super::types::CpuFeatures;
+use super::types::ExeVolumeInfo;
+use super::types::GpuAdapterInfo;
 use super::types::MaaState;
 use super::types::SystemInfo;
 use super::types::WebView2DirInfo;
-use super::utils::get_maafw_dir;
+use super::utils::{get_exe_directory, get_maafw_dir};
 use clap::Parser;
-use log::info;
-#[cfg(windows)]
-use log::warn;
+use log::{info, warn};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::sync::OnceLock;
-use std::time::Duration;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
 use tauri::State;
 use tokio::time::sleep;
 
+/// 隐藏控制台窗口标志
 #[cfg(windows)]
-const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+pub(crate) const CREATE_NO_WINDOW: u32 = 0x0800_0000;
 
 /// 标记是否检测到可能缺少 VC++ 运行库
 static VCREDIST_MISSING: AtomicBool = AtomicBool::new(false);
@@ -46,6 +49,8 @@ pub struct Cli {
     pub instance: Option<String>,
     #[arg(short = 'q', long = "quit-after-run")]
     pub quit_after_run: bool,
+    #[arg(long = "data-dir")]
+    pub data_dir: Option<std::path::PathBuf>,
 }
 
 pub fn init_cli() -> &'static Cli {
@@ -104,9 +109,14 @@ MXU 命令行参数
   -q, --quit-after-run
       当本次启动实际触发自动执行后，在任务完成时自动退出
 
+  --data-dir <目录>
+      使用指定的数据目录（配置、日志、缓存），相对路径相对于程序所在目录
+      同时运行多个 MXU 时应为每个实例指定不同的数据目录，避免互相冲突
+
 示例:
   {exe_name} --autostart --instance \"日常任务\"
   {exe_name} --autostart -i \"日常任务\" --quit-after-run
+  {exe_name} --data-dir data2
 "
     )
 }
@@ -143,6 +153,67 @@ fn detect_host_arch() -> String {
     std::env::consts::ARCH.to_string()
 }
 
+/// 获取操作系统原生 CPU 架构（不受 WOW64 / x64 仿真影响）
+///
+/// ARM64 Windows 上以 x64 仿真运行、或 x86 进程运行在 64 位 Windows 时，进程架构与系统架构不同；
+/// 选择 WebView2 运行时和更新包时应以系统原生架构为准。
+pub fn detect_native_arch() -> String {
+    #[cfg(windows)]
+    if let Some(arch) = detect_windows_native_arch() {
+        return arch;
+    }
+
+    // macOS 下 detect_host_arch 已通过 sysctl 识别 Rosetta，其余平台与进程架构一致
+    detect_host_arch()
+}
+
+/// 通过 IsWow64Process2 获取 Windows 原生架构，旧系统（Win10 1709 之前）回退到 GetNativeSystemInfo
+#[cfg(windows)]
+fn detect_windows_native_arch() -> Option<String> {
+    use winsafe::co::PROCESSOR_ARCHITECTURE;
+
+    const IMAGE_FILE_MACHINE_I386: u16 = 0x014c;
+    const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
+    const IMAGE_FILE_MACHINE_ARM64: u16 = 0xaa64;
+
+    type IsWow64Process2Fn =
+        unsafe extern "system" fn(*mut std::ffi::c_void, *mut u16, *mut u16) -> i32;
+
+    // 动态获取函数地址，避免旧系统上因缺少导出而无法启动
+    let is_wow64_process2 = winsafe::HINSTANCE::GetModuleHandle(Some("kernel32.dll"))
+        .ok()
+        .and_then(|kernel32| kernel32.GetProcAddress("IsWow64Process2").ok());
+    if let Some(proc_addr) = is_wow64_process2 {
+        let is_wow64_process2 =
+            unsafe { std::mem::transmute::<*const std::ffi::c_void, IsWow64Process2Fn>(proc_addr) };
+        let mut process_machine: u16 = 0;
+        let mut native_machine: u16 = 0;
+        // -1 为当前进程伪句柄
+        let ok = unsafe {
+            is_wow64_process2(
+                -1isize as *mut std::ffi::c_void,
+                &mut process_machine,
+                &mut native_machine,
+            )
+        };
+        if ok != 0 {
+            match native_machine {
+                IMAGE_FILE_MACHINE_AMD64 => return Some("x86_64".to_string()),
+                IMAGE_FILE_MACHINE_ARM64 => return Some("aarch64".to_string()),
+                IMAGE_FILE_MACHINE_I386 => return Some("x86".to_string()),
+                other => warn!("IsWow64Process2 returned unknown machine: {:#x}", other),
+            }
+        }
+    }
+
+    match winsafe::GetNativeSystemInfo().wProcessorArchitecture {
+        PROCESSOR_ARCHITECTURE::AMD64 => Some("x86_64".to_string()),
+        PROCESSOR_ARCHITECTURE::ARM64 => Some("aarch64".to_string()),
+        PROCESSOR_ARCHITECTURE::INTEL => Some("x86".to_string()),
+        _ => None,
+    }
+}
+
 /// 检查当前进程是否以管理员权限运行
 #[tauri::command]
 pub fn is_elevated() -> bool {
@@ -233,6 +304,69 @@ pub fn restart_as_admin(app_handle: tauri::AppHandle) -> Result<(), String> {
     }
 }
 
+/// 重启时等待各实例任务停下的超时
+const RESTART_STOP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 以当前权限重启应用（保留原命令行参数）
+#[tauri::command]
+pub async fn restart_app(app_handle: tauri::AppHandle) -> Result<(), String> {
+    tokio::task::spawn_blocking(move || restart_app_impl(app_handle))
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|r| r)
+}
+
+/// 重启应用的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
+///
+/// 先停止所有任务、结束 agent 子进程并关闭主窗口以外的窗口，再启动新进程，
+/// 避免新旧进程短暂同时操作设备；会阻塞等待任务停止，需在阻塞线程中调用
+pub fn restart_app_impl(app_handle: tauri::AppHandle) -> Result<(), String> {
+    use tauri::Manager;
+
+    let exe_path = std::env::current_exe().map_err(|e| format!("获取程序路径失败: {}", e))?;
+    let args: Vec<std::ffi::OsString> = std::env::args_os().skip(1).collect();
+    info!("restart_app: restarting with args {:?}", args);
+
+    if let Some(state) = app_handle.try_state::<Arc<MaaState>>() {
+        let taskers: Vec<_> = state
+            .instances
+            .lock()
+            .map(|instances| {
+                instances
+                    .values()
+                    .filter_map(|i| i.tasker.clone())
+                    .filter(|t| t.running())
+                    .collect()
+            })
+            .unwrap_or_default();
+        for tasker in &taskers {
+            let _ = tasker.post_stop();
+        }
+        let deadline = Instant::now() + RESTART_STOP_TIMEOUT;
+        while taskers.iter().any(|t| t.running()) && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        state.cleanup_all_agent_children();
+    }
+
+    for (label, window) in app_handle.webview_windows() {
+        if label != "main" {
+            let _ = window.close();
+        }
+    }
+
+    let mut cmd = std::process::Command::new(&exe_path);
+    cmd.args(&args);
+    if let Ok(cwd) = std::env::current_dir() {
+        cmd.current_dir(cwd);
+    }
+    cmd.spawn().map_err(|e| format!("启动新进程失败: {}", e))?;
+
+    info!("restart_app: new process started, exiting current");
+    app_handle.exit(0);
+    Ok(())
+}
+
 /// 设置全局选项 - 保存调试图像
 #[tauri::command]
 pub fn maa_set_save_draw(enabled: bool) -> Result<bool, String> {
@@ -244,41 +378,354 @@ pub fn maa_set_save_draw(enabled: bool) -> Result<bool, String> {
         .map_err(|e| format!("设置保存调试图像失败: {}", e))
 }
 
+/// 当前日志级别（maa_init 加载库后据此同步 MaaFramework 的日志级别）
+static LOG_LEVEL: OnceLock<std::sync::Mutex<String>> = OnceLock::new();
+
+/// 日志级别映射：(后端日志过滤级别, MaaFramework MaaLoggingLevel)
+///
+/// 日志插件构建时固定为 Debug，因此可调范围为 error ~ debug
+fn parse_log_level(level: &str) -> Option<(log::LevelFilter, i32)> {
+    match level.to_ascii_lowercase().as_str() {
+        "error" => Some((log::LevelFilter::Error, 2)),
+        "warn" | "warning" => Some((log::LevelFilter::Warn, 3)),
+        "info" => Some((log::LevelFilter::Info, 4)),
+        "debug" => Some((log::LevelFilter::Debug, 5)),
+        _ => None,
+    }
+}
+
+/// 应用日志级别到后端日志 (供内部调用)，MaaFramework 仅在库已加载时同步
+pub fn apply_log_level(level: &str, maafw_loaded: bool) -> Result<(), String> {
+    let (filter, maa_level) =
+        parse_log_level(level).ok_or_else(|| format!("不支持的日志级别: {}", level))?;
+
+    log::set_max_level(filter);
+    *LOG_LEVEL
+        .get_or_init(|| std::sync::Mutex::new("debug".to_string()))
+        .lock()
+        .map_err(|e| e.to_string())? = level.to_ascii_lowercase();
+
+    if maafw_loaded {
+        maa_framework::set_stdout_level(maa_level)
+            .map_err(|e| format!("设置 MaaFramework 日志级别失败: {}", e))?;
+    }
+    info!("日志级别: {}", level);
+    Ok(())
+}
+
+/// 当前日志级别（未设置过时为 None）
+pub fn current_log_level() -> Option<String> {
+    LOG_LEVEL
+        .get()
+        .and_then(|l| l.lock().ok().map(|l| l.clone()))
+}
+
+/// 将当前日志级别同步到 MaaFramework（库加载后调用）
+pub fn sync_maafw_log_level() {
+    let Some(level) = current_log_level() else {
+        return;
+    };
+    if let Some((_, maa_level)) = parse_log_level(&level) {
+        if let Err(e) = maa_framework::set_stdout_level(maa_level) {
+            log::warn!("同步 MaaFramework 日志级别失败: {}", e);
+        }
+    }
+}
+
+/// 动态设置日志级别（error / warn / info / debug），立即生效
+///
+/// 同时调整后端日志插件与 MaaFramework 的 stdout 日志级别，持久化由前端写入配置
+#[tauri::command]
+pub fn set_log_level(state: State<Arc<MaaState>>, level: String) -> Result<(), String> {
+    let maafw_loaded = state.lib_dir.lock().map_err(|e| e.to_string())?.is_some();
+    apply_log_level(&level, maafw_loaded)
+}
+
 /// 打开文件（使用系统默认程序）
+///
+/// Windows 使用 `ShellExecuteEx`，macOS 使用 `open`，Linux 依次尝试 `xdg-open`、`gio open`、
+/// `kde-open5`、`kde-open`。打开失败时返回明确原因（文件不存在、命令不存在、没有关联的默认程序）。
 #[tauri::command]
 pub async fn open_file(file_path: String) -> Result<(), String> {
     info!("open_file: {}", file_path);
 
+    if !std::path::Path::new(&file_path).exists() {
+        return Err(format!("文件不存在: {}", file_path));
+    }
+    let result = tokio::task::spawn_blocking(move || open_with_default_app(&file_path))
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|r| r);
+    if let Err(e) = &result {
+        warn!("open_file failed: {}", e);
+    }
+    result
+}
+
+/// 使用系统默认程序打开路径（文件或目录）
+#[cfg(windows)]
+fn open_with_default_app(path: &str) -> Result<(), String> {
+    use winsafe::co::{ERROR, SEE_MASK, SW};
+    use winsafe::{ShellExecuteEx, SHELLEXECUTEINFO};
+
+    // 直接交给 Shell 处理，不经过 cmd，路径中的 & ^ 等字符不会被解释
+    ShellExecuteEx(&SHELLEXECUTEINFO {
+        file: path,
+        verb: Some("open"),
+        show: SW::SHOWNORMAL,
+        mask: SEE_MASK::NOASYNC | SEE_MASK::FLAG_NO_UI,
+        ..Default::default()
+    })
+    .map_err(|e| match e {
+        ERROR::NO_ASSOCIATION => format!("没有关联的默认程序: {}", path),
+        ERROR::FILE_NOT_FOUND | ERROR::PATH_NOT_FOUND => format!("文件不存在: {}", path),
+        ERROR::ACCESS_DENIED => format!("没有权限打开: {}", path),
+        other => format!("打开失败: {}", other),
+    })
+}
+
+/// 使用系统默认程序打开路径（文件或目录）
+#[cfg(target_os = "macos")]
+fn open_with_default_app(path: &str) -> Result<(), String> {
+    use std::process::Command;
+
+    // open 启动应用后立即返回，没有可用应用时以非零退出码结束并在 stderr 说明原因
+    let output = Command::new("open")
+        .arg(path)
+        .output()
+        .map_err(|e| format!("无法执行 open: {}", e))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    if stderr.contains("No application") {
+        Err(format!("没有关联的默认程序: {}", path))
+    } else if stderr.is_empty() {
+        Err(format!("打开失败: open 退出码 {}", output.status))
+    } else {
+        Err(format!("打开失败: {}", stderr))
+    }
+}
+
+/// 等待打开命令退出的时间，超时仍在运行视为已交给默认程序（部分桌面环境下会前台运行该程序）
+#[cfg(target_os = "linux")]
+const OPEN_COMMAND_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 使用系统默认程序打开路径（文件或目录）
+#[cfg(target_os = "linux")]
+fn open_with_default_app(path: &str) -> Result<(), String> {
+    use std::process::{Command, Stdio};
+
+    let candidates: [(&str, &[&str]); 4] = [
+        ("xdg-open", &[]),
+        ("gio", &["open"]),
+        ("kde-open5", &[]),
+        ("kde-open", &[]),
+    ];
+    let mut missing = Vec::new();
+    let mut errors = Vec::new();
+
+    for (program, args) in candidates {
+        // 默认程序会继承标准输出，不能使用管道，否则本进程放弃读取后程序写日志会收到 SIGPIPE
+        let spawned = Command::new(program)
+            .args(args)
+            .arg(path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        let mut child = match spawned {
+            Ok(child) => child,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                missing.push(program);
+                continue;
+            }
+            Err(e) => {
+                errors.push(format!("{}: {}", program, e));
+                continue;
+            }
+        };
+
+        let deadline = Instant::now() + OPEN_COMMAND_TIMEOUT;
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break Some(status),
+                Ok(None) if Instant::now() < deadline => {
+                    std::thread::sleep(Duration::from_millis(50))
+                }
+                _ => break None,
+            }
+        };
+        let Some(status) = status else {
+            // 仍在运行，由后台线程回收，避免产生僵尸进程
+            std::thread::spawn(move || child.wait());
+            return Ok(());
+        };
+        if status.success() {
+            return Ok(());
+        }
+        // xdg-open: 2 文件不存在，3 缺少所需工具，4 打开失败（通常是没有关联程序）
+        let reason = match (program, status.code()) {
+            ("xdg-open", Some(2)) => return Err(format!("文件不存在: {}", path)),
+            ("xdg-open", Some(3)) => "缺少所需工具".to_string(),
+            (_, Some(4)) | ("gio", Some(1)) => "没有关联的默认程序".to_string(),
+            _ => format!("退出码 {}", status),
+        };
+        errors.push(format!("{}: {}", program, reason));
+    }
+
+    if errors.is_empty() {
+        Err(format!(
+            "未找到可用的打开命令（已尝试 {}），请安装 xdg-utils",
+            missing.join("、")
+        ))
+    } else {
+        Err(format!("打开失败: {}", errors.join("; ")))
+    }
+}
+
+/// 在文件管理器中定位文件（打开所在文件夹并选中该文件）
+///
+/// Windows 使用 `explorer /select,`，macOS 使用 `open -R`，Linux 回退为打开父目录。
+/// 文件不存在时打开其父目录，返回值表示是否成功定位到文件本身。
+#[tauri::command]
+pub async fn open_folder_and_select(path: String) -> Result<bool, String> {
+    info!("open_folder_and_select: {}", path);
+
+    let target = std::path::PathBuf::from(&path);
+    if !target.exists() {
+        let parent = target
+            .parent()
+            .filter(|p| p.is_dir())
+            .ok_or_else(|| format!("路径不存在: {}", path))?;
+        warn!(
+            "open_folder_and_select: '{}' not found, opening parent directory",
+            path
+        );
+        open_file(parent.to_string_lossy().to_string()).await?;
+        return Ok(false);
+    }
+
     #[cfg(windows)]
     {
         use std::os::windows::process::CommandExt;
         use std::process::Command;
-        Command::new("cmd")
-            .args(["/c", "start", "", &file_path])
+        // explorer 要求 "/select," 与路径作为同一个参数，且路径需使用反斜杠
+        let native_path = target.to_string_lossy().replace('/', "\\");
+        Command::new("explorer")
+            .raw_arg(format!("/select,\"{}\"", native_path))
             .creation_flags(CREATE_NO_WINDOW)
             .spawn()
-            .map_err(|e| format!("Failed to open file: {}", e))?;
+            .map_err(|e| format!("Failed to open folder: {}", e))?;
     }
 
     #[cfg(target_os = "macos")]
     {
         use std::process::Command;
         Command::new("open")
-            .arg(&file_path)
+            .arg("-R")
+            .arg(&target)
             .spawn()
-            .map_err(|e| format!("Failed to open file: {}", e))?;
+            .map_err(|e| format!("Failed to open folder: {}", e))?;
     }
 
     #[cfg(target_os = "linux")]
     {
-        use std::process::Command;
-        Command::new("xdg-open")
-            .arg(&file_path)
-            .spawn()
-            .map_err(|e| format!("Failed to open file: {}", e))?;
+        let dir = if target.is_dir() {
+            target.as_path()
+        } else {
+            target.parent().unwrap_or(target.as_path())
+        };
+        let dir = dir.to_string_lossy().to_string();
+        tokio::task::spawn_blocking(move || open_with_default_app(&dir))
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|r| r)?;
     }
 
-    Ok(())
+    Ok(true)
+}
+
+/// 启动程序并把 input 写入其标准输入，等待退出并检查退出码
+fn pipe_to_program(program: &str, args: &[&str], input: &[u8]) -> Result<(), String> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut cmd = Command::new(program);
+    // xclip 会常驻后台提供剪贴板内容，不能占用输出管道，否则 wait 会一直阻塞
+    cmd.args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = cmd.spawn().map_err(|e| format!("{}: {}", program, e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(input)
+            .map_err(|e| format!("{}: {}", program, e))?;
+    }
+    let status = child.wait().map_err(|e| format!("{}: {}", program, e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{} exited with {}", program, status))
+    }
+}
+
+/// 复制文本到系统剪贴板
+///
+/// 不依赖 WebView 的剪贴板权限：Windows 使用 `clip`（UTF-16 带 BOM 以保留中文），
+/// macOS 使用 `pbcopy`，Linux 依次尝试 `wl-copy`、`xclip`、`xsel`。
+/// 全部失败时返回错误，由前端提示用户手动复制
+#[tauri::command]
+pub async fn copy_text_to_clipboard(text: String) -> Result<(), String> {
+    info!("copy_text_to_clipboard: {} chars", text.chars().count());
+
+    tokio::task::spawn_blocking(move || {
+        #[cfg(windows)]
+        let candidates: Vec<(&str, Vec<&str>, Vec<u8>)> = {
+            let mut input = vec![0xFF, 0xFE];
+            input.extend(text.encode_utf16().flat_map(|c| c.to_le_bytes()));
+            vec![("clip", vec![], input)]
+        };
+
+        #[cfg(target_os = "macos")]
+        let candidates: Vec<(&str, Vec<&str>, Vec<u8>)> =
+            vec![("pbcopy", vec![], text.clone().into_bytes())];
+
+        #[cfg(target_os = "linux")]
+        let candidates: Vec<(&str, Vec<&str>, Vec<u8>)> = vec![
+            ("wl-copy", vec![], text.clone().into_bytes()),
+            (
+                "xclip",
+                vec!["-selection", "clipboard"],
+                text.clone().into_bytes(),
+            ),
+            (
+                "xsel",
+                vec!["--clipboard", "--input"],
+                text.clone().into_bytes(),
+            ),
+        ];
+
+        let mut errors = Vec::new();
+        for (program, args, input) in &candidates {
+            match pipe_to_program(program, args, input) {
+                Ok(()) => return Ok(()),
+                Err(e) => errors.push(e),
+            }
+        }
+        warn!("copy_text_to_clipboard failed: {:?}", errors);
+        Err(format!("复制到剪贴板失败: {}", errors.join("; ")))
+    })
+    .await
+    .map_err(|e| e.to_string())?
 }
 
 /// 运行程序并等待其退出
@@ -532,6 +979,7 @@ pub fn get_process_path_from_hwnd(hwnd: u64) -> Result<String, String> {
 /// args: 附加参数（空格分隔）
 /// cwd: 工作目录（可选，默认为程序所在目录）
 /// wait_for_exit: 是否等待进程退出
+/// hide_window: 是否隐藏控制台窗口（仅 Windows，默认显示）
 #[tauri::command]
 pub fn set_pre_action_stop(
     state: State<Arc<MaaState>>,
@@ -550,8 +998,73 @@ pub fn set_pre_action_stop(
     Ok(())
 }
 
+/// 构建前置程序的启动命令（run_action 与连接前自动启动模拟器共用）
+///
+/// args 按 shell 语义分词（支持引号）；显式指定的工作目录必须存在，未指定时使用程序所在目录
+pub fn build_action_command(
+    program: &str,
+    args: &str,
+    cwd: Option<&str>,
+    use_cmd: bool,
+    hide_window: bool,
+) -> Result<std::process::Command, String> {
+    // 显式指定的工作目录必须存在，不回退到程序所在目录
+    let cwd = cwd.filter(|dir| !dir.trim().is_empty());
+    if let Some(dir) = cwd {
+        super::utils::validate_cwd(dir)?;
+    }
+
+    // 使用 shell 语义解析参数至数组（支持引号）
+    let args_vec: Vec<String> = if args.trim().is_empty() {
+        vec![]
+    } else {
+        shell_words::split(args).map_err(|e| format!("Failed to parse args: {}", e))?
+    };
+
+    let mut cmd = super::utils::build_launch_command(program, &args_vec, use_cmd, hide_window);
+
+    // 设置工作目录（未指定时默认使用程序所在目录）
+    if let Some(dir) = cwd {
+        cmd.current_dir(dir);
+    } else if let Some(parent) = std::path::Path::new(program).parent() {
+        if parent.exists() {
+            cmd.current_dir(parent);
+        }
+    }
+    Ok(cmd)
+}
+
+/// 在阻塞线程中执行程序信任校验（confirm 模式下会等待用户在弹窗中选择）
+///
+/// `cmd_args` 为 Some 时表示通过 `cmd /c` 启动，按包含参数的完整命令行校验
+pub async fn check_program_trust(
+    app: &tauri::AppHandle,
+    instance_id: &str,
+    program: &str,
+    cmd_args: Option<&str>,
+    source: &'static str,
+) -> Result<(), String> {
+    let app = app.clone();
+    let instance_id = instance_id.to_string();
+    let program = program.to_string();
+    let cmd_args = cmd_args.map(str::to_string);
+    tokio::task::spawn_blocking(move || {
+        super::program_trust::ensure_program_allowed(
+            &app,
+            Some(&instance_id),
+            &program,
+            cmd_args.as_deref(),
+            source,
+        )
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|r| r)
+}
+
 #[tauri::command]
 pub async fn run_action(
+    app: tauri::AppHandle,
     state: State<'_, Arc<MaaState>>,
     instance_id: String,
     program: String,
@@ -559,34 +1072,20 @@ pub async fn run_action(
     cwd: Option<String>,
     wait_for_exit: bool,
     use_cmd: Option<bool>,
+    hide_window: Option<bool>,
 ) -> Result<i32, String> {
     let use_cmd = use_cmd.unwrap_or(false);
+    let hide_window = hide_window.unwrap_or(false);
 
     info!(
-        "run_action: instance_id={}, program={}, args={}, wait={}, use_cmd={}",
-        instance_id, program, args, wait_for_exit, use_cmd
+        "run_action: instance_id={}, program={}, args={}, cwd={:?}, wait={}, use_cmd={}, hide_window={}",
+        instance_id, program, args, cwd, wait_for_exit, use_cmd, hide_window
     );
 
-    // 使用 shell 语义解析参数至数组（支持引号）
-    let args_vec: Vec<String> = if args.trim().is_empty() {
-        vec![]
-    } else {
-        shell_words::split(&args).map_err(|e| format!("Failed to parse args: {}", e))?
-    };
+    let cmd_args = use_cmd.then_some(args.as_str());
+    check_program_trust(&app, &instance_id, &program, cmd_args, "run_action").await?;
 
-    let mut cmd = super::utils::build_launch_command(&program, &args_vec, use_cmd);
-
-    // 设置工作目录
-    if let Some(ref dir) = cwd {
-        cmd.current_dir(dir);
-    } else {
-        // 默认使用程序所在目录作为工作目录
-        if let Some(parent) = std::path::Path::new(&program).parent() {
-            if parent.exists() {
-                cmd.current_dir(parent);
-            }
-        }
-    }
+    let mut cmd = build_action_command(&program, &args, cwd.as_deref(), use_cmd, hide_window)?;
 
     if wait_for_exit {
         let mut child = cmd
@@ -639,6 +1138,7 @@ pub async fn run_action(
 /// 并复用 `pre_action_stop_requests` 支持前置阶段的取消。
 #[tauri::command]
 pub async fn run_pretask(
+    app: tauri::AppHandle,
     state: State<'_, Arc<MaaState>>,
     instance_id: String,
     program: String,
@@ -650,6 +1150,11 @@ pub async fn run_pretask(
         instance_id, program, args, cwd
     );
 
+    let cwd = cwd.filter(|dir| !dir.trim().is_empty());
+    if let Some(ref dir) = cwd {
+        super::utils::validate_cwd(dir)?;
+    }
+
     // Windows 下相对可执行路径会相对“父进程当前目录”解析，而非下方设置的 `current_dir`，
     // 因此必须先基于 cwd 把相对 exec（如 `agent/go-service`）拼成绝对路径，复用 Agent
     // 启动时相同的解析逻辑，避免出现“系统找不到指定的路径 (os error 3)”。
@@ -659,8 +1164,9 @@ pub async fn run_pretask(
             .into_owned(),
         None => program.clone(),
     };
+    check_program_trust(&app, &instance_id, &resolved_program, None, "run_pretask").await?;
 
-    let mut cmd = super::utils::build_launch_command(&resolved_program, &args, false);
+    let mut cmd = super::utils::build_launch_command(&resolved_program, &args, false, false);
 
     // 设置工作目录
     if let Some(ref dir) = cwd {
@@ -712,6 +1218,9 @@ pub async fn run_pretask(
 #[tauri::command]
 pub async fn retry_load_maa_library() -> Result<String, String> {
     info!("retry_load_maa_library");
+    if let Some(err) = cpu_unsupported_error() {
+        return Err(err);
+    }
 
     let maafw_dir = get_maafw_dir()?;
     if !maafw_dir.exists() {
@@ -750,6 +1259,58 @@ pub fn is_autostart() -> bool {
     init_cli().autostart
 }
 
+/// 开机自启动延迟的截止时间（仅 --autostart 且配置了 autoStartDelaySec 时设置）
+static AUTOSTART_DELAY_DEADLINE: OnceLock<Instant> = OnceLock::new();
+
+/// 记录开机自启动延迟，返回是否实际启用了延迟 (供内部调用)
+pub fn begin_autostart_delay(delay_secs: u64) -> bool {
+    if delay_secs == 0 || !init_cli().autostart {
+        return false;
+    }
+    let deadline = Instant::now() + Duration::from_secs(delay_secs);
+    if AUTOSTART_DELAY_DEADLINE.set(deadline).is_err() {
+        return false;
+    }
+    *DEFERRED_MAAFW_LOAD.0.lock().unwrap() = true;
+    true
+}
+
+/// 延迟加载 MaaFramework 是否尚未完成，配合条件变量让 maa_init 等待延迟加载线程结束，
+/// 避免两边同时加载库
+static DEFERRED_MAAFW_LOAD: (Mutex<bool>, Condvar) = (Mutex::new(false), Condvar::new());
+
+/// 标记延迟加载 MaaFramework 已结束（无论成功与否），唤醒等待中的 maa_init (供内部调用)
+pub fn finish_deferred_maafw_load() {
+    let (pending, cvar) = &DEFERRED_MAAFW_LOAD;
+    *pending.lock().unwrap() = false;
+    cvar.notify_all();
+}
+
+/// 阻塞等待延迟加载 MaaFramework 结束（未延迟加载时立即返回） (供内部调用)
+pub fn wait_deferred_maafw_load() {
+    let (pending, cvar) = &DEFERRED_MAAFW_LOAD;
+    let guard = pending.lock().unwrap();
+    if *guard {
+        info!("Waiting for deferred MaaFramework load");
+    }
+    let _guard = cvar.wait_while(guard, |pending| *pending).unwrap();
+}
+
+/// 等待开机自启动延迟结束（未启用延迟时立即返回）
+///
+/// 前端在开机自启动模式下，初始化 MaaFramework 和连接设备前调用，
+/// 让系统在开机高峰期先稳定下来
+#[tauri::command]
+pub async fn wait_autostart_delay() {
+    if let Some(deadline) = AUTOSTART_DELAY_DEADLINE.get() {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if !remaining.is_zero() {
+            info!("Waiting {:?} for autostart delay", remaining);
+            sleep(remaining).await;
+        }
+    }
+}
+
 /// 获取命令行 -i/--instance 参数指定的启动实例名称
 #[tauri::command]
 pub fn get_start_instance() -> Option<String> {
@@ -961,6 +1522,12 @@ pub fn get_arch() -> String {
     detect_host_arch()
 }
 
+/// 获取操作系统原生 CPU 架构（WOW64 / x64 仿真下返回真实系统架构，用于选择下载包）
+#[tauri::command]
+pub fn get_native_arch() -> String {
+    detect_native_arch()
+}
+
 /// 获取操作系统类型
 #[tauri::command]
 pub fn get_os() -> String {
@@ -988,9 +1555,150 @@ pub fn get_system_info() -> SystemInfo {
         os_version,
         arch,
         tauri_version,
+        exe_volume: exe_volume_info().clone(),
+        cpu: cpu_features_info().clone(),
+    }
+}
+
+/// CPU 指令集的检测结果（启动时检测一次）
+pub fn cpu_features_info() -> &'static CpuFeatures {
+    static INFO: OnceLock<CpuFeatures> = OnceLock::new();
+    INFO.get_or_init(detect_cpu_features)
+}
+
+/// 通过 CPUID 检测指令集，AVX 系列同时要求操作系统已启用对应寄存器状态的保存
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn detect_cpu_features() -> CpuFeatures {
+    let sse4_2 = std::arch::is_x86_feature_detected!("sse4.2");
+    let avx = std::arch::is_x86_feature_detected!("avx");
+    let avx2 = std::arch::is_x86_feature_detected!("avx2");
+    let fma = std::arch::is_x86_feature_detected!("fma");
+    // MaaFramework 的最低要求
+    let missing: Vec<String> = [("SSE4.2", sse4_2), ("AVX", avx)]
+        .into_iter()
+        .filter(|(_, supported)| !supported)
+        .map(|(name, _)| name.to_string())
+        .collect();
+    let features = CpuFeatures {
+        x86: true,
+        sse4_2,
+        avx,
+        avx2,
+        fma,
+        missing,
+    };
+    info!("CPU features: {:?}", features);
+    features
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn detect_cpu_features() -> CpuFeatures {
+    CpuFeatures {
+        x86: false,
+        sse4_2: false,
+        avx: false,
+        avx2: false,
+        fma: false,
+        missing: Vec::new(),
+    }
+}
+
+/// CPU 不满足 MaaFramework 最低指令集要求时返回错误信息
+///
+/// 此时不加载 MaaFramework，避免执行到不支持的指令时整个程序崩溃
+pub fn cpu_unsupported_error() -> Option<String> {
+    let missing = &cpu_features_info().missing;
+    (!missing.is_empty()).then(|| format!("您的 CPU 不支持 {}，无法运行", missing.join(" / ")))
+}
+
+/// 查询 CPU 指令集支持情况（前端启动时检查，不满足要求时弹窗提示）
+#[tauri::command]
+pub fn check_cpu_features() -> CpuFeatures {
+    cpu_features_info().clone()
+}
+
+/// 程序所在卷的检测结果（启动时检测一次）
+pub fn exe_volume_info() -> &'static ExeVolumeInfo {
+    static INFO: OnceLock<ExeVolumeInfo> = OnceLock::new();
+    INFO.get_or_init(detect_exe_volume)
+}
+
+/// 检测程序所在卷的类型、可写性及是否位于云同步目录
+fn detect_exe_volume() -> ExeVolumeInfo {
+    let Ok(exe_dir) = get_exe_directory() else {
+        return ExeVolumeInfo {
+            drive_type: "unknown".to_string(),
+            writable: true,
+            cloud_synced: false,
+            issue: None,
+        };
+    };
+
+    let drive_type = detect_drive_type(&exe_dir);
+
+    // 实际写入一个探测文件，比检查只读属性更可靠（可覆盖权限、同步锁等情况）
+    let probe = exe_dir.join(".mxu_write_test");
+    let writable = std::fs::write(&probe, b"").is_ok();
+    let _ = std::fs::remove_file(&probe);
+
+    let cloud_synced = ["OneDrive", "OneDriveConsumer", "OneDriveCommercial"]
+        .iter()
+        .filter_map(|var| std::env::var_os(var))
+        .any(|dir| !dir.is_empty() && exe_dir.starts_with(&dir));
+
+    let issue = if drive_type == "network" {
+        Some("network")
+    } else if !writable {
+        Some("readonly")
+    } else if drive_type == "removable" || drive_type == "cdrom" {
+        Some("removable")
+    } else if cloud_synced {
+        Some("cloud")
+    } else {
+        None
+    };
+
+    ExeVolumeInfo {
+        drive_type: drive_type.to_string(),
+        writable,
+        cloud_synced,
+        issue: issue.map(String::from),
+    }
+}
+
+#[cfg(windows)]
+fn detect_drive_type(exe_dir: &std::path::Path) -> &'static str {
+    use std::path::Component;
+    use winsafe::co::DRIVE;
+
+    // 取卷根目录：盘符 "C:\" 或 UNC "\\server\share\"
+    let Some(Component::Prefix(prefix)) = exe_dir.components().next() else {
+        return "unknown";
+    };
+    let root = format!("{}\\", prefix.as_os_str().to_string_lossy());
+
+    match winsafe::GetDriveType(Some(&root)) {
+        DRIVE::FIXED => "fixed",
+        DRIVE::REMOTE => "network",
+        DRIVE::REMOVABLE => "removable",
+        DRIVE::CDROM => "cdrom",
+        DRIVE::RAMDISK => "ramdisk",
+        _ => "unknown",
     }
 }
 
+#[cfg(not(windows))]
+fn detect_drive_type(_exe_dir: &std::path::Path) -> &'static str {
+    // 非 Windows 平台暂不区分卷类型，仅依赖可写性检测
+    "fixed"
+}
+
+/// 检查程序所在卷是否为网络盘/可移动盘/只读/云同步目录
+#[tauri::command]
+pub fn check_exe_volume() -> ExeVolumeInfo {
+    exe_volume_info().clone()
+}
+
 /// 获取 Web 服务器实际监听端口
 ///
 /// 若服务器尚未完成绑定，最多等待 5 秒后返回（0 表示超时未启动或用户手动禁用）。
@@ -1019,6 +1727,212 @@ pub fn get_local_lan_ip() -> Option<String> {
     crate::web_server::get_local_ip().map(|s| s.to_string())
 }
 
+/// 查询显卡列表及驱动信息（查询失败时返回空列表）
+///
+/// Windows 通过 DXGI 枚举适配器（驱动版本取 UMD 版本，不含驱动日期），
+/// macOS 使用 system_profiler，Linux 使用 lspci
+pub fn query_gpu_adapters() -> Vec<GpuAdapterInfo> {
+    #[cfg(windows)]
+    {
+        use winsafe::prelude::*;
+        use winsafe::{co, CreateDXGIFactory1, IDXGIDevice};
+
+        let factory = match CreateDXGIFactory1() {
+            Ok(factory) => factory,
+            Err(e) => {
+                warn!("创建 DXGI Factory 失败: {}", e);
+                return Vec::new();
+            }
+        };
+        factory
+            .EnumAdapters1()
+            .filter_map(|adapter| adapter.ok())
+            .filter_map(|adapter| {
+                let desc = adapter.GetDesc1().ok()?;
+                // 跳过 Microsoft Basic Render Driver 等软件适配器
+                if desc.flags.has(co::DXGI_ADAPTER_FLAG::SOFTWARE) {
+                    return None;
+                }
+                // UMD 版本按 16 位分为四段，与设备管理器中的驱动版本一致
+                let driver_version = adapter
+                    .CheckInterfaceSupport(IDXGIDevice::IID.as_ref())
+                    .ok()
+                    .map(|v| {
+                        let v = v as u64;
+                        format!(
+                            "{}.{}.{}.{}",
+                            v >> 48,
+                            (v >> 32) & 0xFFFF,
+                            (v >> 16) & 0xFFFF,
+                            v & 0xFFFF
+                        )
+                    });
+                Some(GpuAdapterInfo {
+                    name: desc.Description(),
+                    vendor: gpu_vendor_name(desc.VendorId).map(String::from),
+                    driver_version,
+                    driver_date: None,
+                    memory_mb: Some(desc.DedicatedVideoMemory as u64 / 1024 / 1024),
+                })
+            })
+            .collect()
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let output = match std::process::Command::new("system_profiler")
+            .args(["SPDisplaysDataType", "-json"])
+            .output()
+        {
+            Ok(o) if o.status.success() => o,
+            _ => return Vec::new(),
+        };
+        let json: serde_json::Value =
+            serde_json::from_slice(&output.stdout).unwrap_or(serde_json::Value::Null);
+        json.get("SPDisplaysDataType")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .map(|v| GpuAdapterInfo {
+                        name: v
+                            .get("sppci_model")
+                            .and_then(|s| s.as_str())
+                            .unwrap_or_default()
+                            .to_string(),
+                        vendor: v
+                            .get("spdisplays_vendor")
+                            .and_then(|s| s.as_str())
+                            .map(String::from),
+                        ..Default::default()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let output = match std::process::Command::new("lspci").arg("-mm").output() {
+            Ok(o) if o.status.success() => o,
+            _ => return Vec::new(),
+        };
+        // -mm 输出格式：Slot "Class" "Vendor" "Device" ...
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|l| l.contains("VGA compatible controller") || l.contains("3D controller"))
+            .map(|l| {
+                let fields: Vec<&str> = l.split('"').collect();
+                GpuAdapterInfo {
+                    name: fields.get(5).unwrap_or(&"").to_string(),
+                    vendor: fields.get(3).map(|s| s.to_string()),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+}
+
+/// 按 PCI 厂商 ID 获取显卡厂商名称
+#[cfg(windows)]
+fn gpu_vendor_name(vendor_id: u32) -> Option<&'static str> {
+    match vendor_id {
+        0x10DE => Some("NVIDIA"),
+        0x1002 | 0x1022 => Some("AMD"),
+        0x8086 => Some("Intel"),
+        0x1414 => Some("Microsoft"),
+        0x5143 => Some("Qualcomm"),
+        _ => None,
+    }
+}
+
+/// 获取显卡列表及驱动信息（用于诊断 WebView2 渲染问题）
+#[tauri::command]
+pub async fn get_gpu_adapters() -> Result<Vec<GpuAdapterInfo>, String> {
+    tokio::task::spawn_blocking(query_gpu_adapters)
+        .await
+        .map_err(|e| format!("查询显卡信息失败: {}", e))
+}
+
+/// WebView2 禁用 GPU 加速的标记文件（位于 exe 目录 cache 下，启动时由 main.rs 读取）
+pub fn webview_gpu_disabled_marker() -> Option<std::path::PathBuf> {
+    let exe_path = std::env::current_exe().ok()?;
+    Some(
+        exe_path
+            .parent()?
+            .join("cache")
+            .join("webview_gpu_disabled"),
+    )
+}
+
+/// 设置 WebView2 是否禁用 GPU 加速（强制软件渲染，用于规避花屏/白屏），重启后生效
+#[tauri::command]
+pub fn set_webview_gpu(disabled: bool) -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        let marker = webview_gpu_disabled_marker().ok_or("无法获取程序目录")?;
+        if disabled {
+            if let Some(parent) = marker.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| format!("无法创建目录: {}", e))?;
+            }
+            std::fs::write(&marker, b"").map_err(|e| format!("写入设置失败: {}", e))?;
+        } else if marker.exists() {
+            std::fs::remove_file(&marker).map_err(|e| format!("删除设置失败: {}", e))?;
+        }
+        info!("WebView2 GPU disabled: {} (restart required)", disabled);
+        Ok(())
+    }
+    #[cfg(not(windows))]
+    {
+        let _ = disabled;
+        Err("此功能仅在 Windows 上可用".to_string())
+    }
+}
+
+/// 查询 WebView2 是否已设置为禁用 GPU 加速
+#[tauri::command]
+pub fn get_webview_gpu_disabled() -> bool {
+    webview_gpu_disabled_marker().is_some_and(|m| m.exists())
+}
+
+/// WebView2 运行时共享模式的标记文件（位于 exe 目录 cache 下，启动时由 main.rs 读取）
+///
+/// 存在时运行时安装到 `%LOCALAPPDATA%\MXU\webview2_runtime`，由多个 MXU 安装共用；
+/// 否则使用 exe 同级的 `cache/webview2_runtime`（独立模式）
+pub fn webview2_shared_marker() -> Option<std::path::PathBuf> {
+    let exe_path = std::env::current_exe().ok()?;
+    Some(exe_path.parent()?.join("cache").join("webview2_shared"))
+}
+
+/// 设置 WebView2 运行时是否使用共享模式，重启后生效
+///
+/// 切换模式不会删除原有运行时，共享目录可能正被其他 MXU 实例使用
+#[tauri::command]
+pub fn set_webview2_shared(shared: bool) -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        let marker = webview2_shared_marker().ok_or("无法获取程序目录")?;
+        if shared {
+            if let Some(parent) = marker.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| format!("无法创建目录: {}", e))?;
+            }
+            std::fs::write(&marker, b"").map_err(|e| format!("写入设置失败: {}", e))?;
+        } else if marker.exists() {
+            std::fs::remove_file(&marker).map_err(|e| format!("删除设置失败: {}", e))?;
+        }
+        info!("WebView2 runtime shared: {} (restart required)", shared);
+        Ok(())
+    }
+    #[cfg(not(windows))]
+    {
+        let _ = shared;
+        Err("此功能仅在 Windows 上可用".to_string())
+    }
+}
+
+/// 查询 WebView2 运行时是否使用共享模式
+#[tauri::command]
+pub fn get_webview2_shared() -> bool {
+    webview2_shared_marker().is_some_and(|m| m.exists())
+}
+
 /// 获取当前使用的 WebView2 目录
 #[tauri::command]
 pub fn get_webview2_dir() -> WebView2DirInfo {
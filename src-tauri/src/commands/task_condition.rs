@@ -0,0 +1,712 @@
+//! 任务跳过条件
+//!
+//! 任务可带一个跳过条件表达式（`TaskConfig::skip_if`），任务开始时求值，为真则把入口节点
+//! 替换为不识别、不操作的空节点，任务随即结束，后续任务照常执行。
+//!
+//! 表达式只支持受限的语法，由下面的递归下降解析器处理，不执行任何脚本：
+//! - 字面量：数字（可带负号，如 `-1`）、`'字符串'` / `"字符串"`、`true` / `false` / `null`
+//! - 比较：`==` `!=` `<` `<=` `>` `>=`；逻辑：`&&` / `and`、`||` / `or`、`!` / `not`；括号
+//! - 内置变量：`hour` `minute`（当前时间）、`weekday`（1 = 周一 … 7 = 周日）、`day` `month`、
+//!   `round`（循环执行的轮次，未循环为 0）、`last_result`（上个任务的结果：
+//!   `"succeeded"` / `"failed"` / `"skipped"`，没有时为 `"none"`）
+//! - 实例变量：`var.名称`（MXU_SET_VAR 写入的值，未设置时为 null）
+//!
+//! 例：`hour >= 22 || weekday == 7`、`last_result == "failed" and var.retry < 3`
+//!
+//! 表达式解析或求值失败时任务照常执行，并发出警告事件。
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use chrono::{Datelike, Timelike};
+use log::{info, warn};
+use serde_json::json;
+use tauri::{AppHandle, Emitter};
+
+use super::types::{InstanceRuntime, MaaState, TaskConditionEvent};
+
+/// 表达式长度上限
+const MAX_EXPRESSION_LEN: usize = 1000;
+
+/// 嵌套深度上限（括号与 `!`），避免过深的递归
+const MAX_DEPTH: usize = 32;
+
+/// 内置变量名
+const BUILTIN_VARS: &[&str] = &[
+    "hour",
+    "minute",
+    "weekday",
+    "day",
+    "month",
+    "round",
+    "last_result",
+];
+
+/// 实例变量前缀
+const VAR_PREFIX: &str = "var.";
+
+/// 各实例最近一个被跳过的任务 ID（用于 `last_result`）
+static LAST_SKIPPED: LazyLock<Mutex<HashMap<String, i64>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Num(f64),
+    Str(String),
+    Bool(bool),
+    Null,
+}
+
+impl Value {
+    fn from_json(value: Option<serde_json::Value>) -> Value {
+        match value {
+            Some(serde_json::Value::Number(n)) => n.as_f64().map(Value::Num).unwrap_or(Value::Null),
+            Some(serde_json::Value::String(s)) => Value::Str(s),
+            Some(serde_json::Value::Bool(b)) => Value::Bool(b),
+            _ => Value::Null,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Value::Num(n) => n.to_string(),
+            Value::Str(s) => format!("\"{}\"", s),
+            Value::Bool(b) => b.to_string(),
+            Value::Null => "null".to_string(),
+        }
+    }
+
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Num(n) => *n != 0.0,
+            Value::Str(s) => !s.is_empty(),
+            Value::Bool(b) => *b,
+            Value::Null => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            CompareOp::Eq => "==",
+            CompareOp::Ne => "!=",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(f64),
+    Str(String),
+    Ident(String),
+    Compare(CompareOp),
+    And,
+    Or,
+    Not,
+    Minus,
+    LParen,
+    RParen,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Num(n) => n.to_string(),
+            Token::Str(s) => format!("\"{}\"", s),
+            Token::Ident(name) => name.clone(),
+            Token::Compare(op) => op.symbol().to_string(),
+            Token::And => "&&".to_string(),
+            Token::Or => "||".to_string(),
+            Token::Not => "!".to_string(),
+            Token::Minus => "-".to_string(),
+            Token::LParen => "(".to_string(),
+            Token::RParen => ")".to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Expr {
+    Literal(Value),
+    Var(String),
+    Not(Box<Expr>),
+    Neg(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare(CompareOp, Box<Expr>, Box<Expr>),
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '-' => {
+                tokens.push(Token::Minus);
+                i += 1;
+            }
+            '&' | '|' => {
+                if next != Some(c) {
+                    return Err(format!("位置 {} 处应为 `{}{}`", i, c, c));
+                }
+                tokens.push(if c == '&' { Token::And } else { Token::Or });
+                i += 2;
+            }
+            '=' | '!' | '<' | '>' => {
+                let op = match (c, next == Some('=')) {
+                    ('=', true) => CompareOp::Eq,
+                    ('!', true) => CompareOp::Ne,
+                    ('<', true) => CompareOp::Le,
+                    ('>', true) => CompareOp::Ge,
+                    ('<', false) => CompareOp::Lt,
+                    ('>', false) => CompareOp::Gt,
+                    ('!', false) => {
+                        tokens.push(Token::Not);
+                        i += 1;
+                        continue;
+                    }
+                    _ => return Err(format!("位置 {} 处应为 `==`", i)),
+                };
+                tokens.push(Token::Compare(op));
+                i += if matches!(op, CompareOp::Lt | CompareOp::Gt) {
+                    1
+                } else {
+                    2
+                };
+            }
+            '\'' | '"' => {
+                let start = i + 1;
+                let Some(len) = chars[start..].iter().position(|&ch| ch == c) else {
+                    return Err(format!("位置 {} 处的字符串未结束", i));
+                };
+                tokens.push(Token::Str(chars[start..start + len].iter().collect()));
+                i = start + len + 1;
+            }
+            _ if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let n = text
+                    .parse::<f64>()
+                    .map_err(|_| format!("无效的数字 `{}`", text))?;
+                tokens.push(Token::Num(n));
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+                {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                tokens.push(match word.as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => Token::Ident(word),
+                });
+            }
+            _ => return Err(format!("位置 {} 处有无法识别的字符 `{}`", i, c)),
+        }
+    }
+    Ok(tokens)
+}
+
+/// 递归下降解析器
+///
+/// ```text
+/// or      := and (("||" | "or") and)*
+/// and     := unary (("&&" | "and") unary)*
+/// unary   := ("!" | "not") unary | compare
+/// compare := primary (op primary)?
+/// primary := "-" primary | 数字 | 字符串 | 标识符 | "(" or ")"
+/// ```
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn enter(&mut self) -> Result<(), String> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(format!("表达式嵌套超过 {} 层", MAX_DEPTH));
+        }
+        Ok(())
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, String> {
+        let mut left = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            left = Expr::Or(Box::new(left), Box::new(self.parse_and()?));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, String> {
+        let mut left = self.parse_unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            left = Expr::And(Box::new(left), Box::new(self.parse_unary()?));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, String> {
+        if self.peek() == Some(&Token::Not) {
+            self.pos += 1;
+            self.enter()?;
+            let inner = self.parse_unary()?;
+            self.depth -= 1;
+            return Ok(Expr::Not(Box::new(inner)));
+        }
+        self.parse_compare()
+    }
+
+    fn parse_compare(&mut self) -> Result<Expr, String> {
+        let left = self.parse_primary()?;
+        if let Some(Token::Compare(op)) = self.peek() {
+            let op = *op;
+            self.pos += 1;
+            let right = self.parse_primary()?;
+            return Ok(Expr::Compare(op, Box::new(left), Box::new(right)));
+        }
+        Ok(left)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Minus) => {
+                self.enter()?;
+                let inner = self.parse_primary()?;
+                self.depth -= 1;
+                Ok(match inner {
+                    Expr::Literal(Value::Num(n)) => Expr::Literal(Value::Num(-n)),
+                    inner => Expr::Neg(Box::new(inner)),
+                })
+            }
+            Some(Token::Num(n)) => Ok(Expr::Literal(Value::Num(n))),
+            Some(Token::Str(s)) => Ok(Expr::Literal(Value::Str(s))),
+            Some(Token::Ident(name)) => match name.as_str() {
+                "true" => Ok(Expr::Literal(Value::Bool(true))),
+                "false" => Ok(Expr::Literal(Value::Bool(false))),
+                "null" => Ok(Expr::Literal(Value::Null)),
+                _ if BUILTIN_VARS.contains(&name.as_str()) => Ok(Expr::Var(name)),
+                _ if name.len() > VAR_PREFIX.len() && name.starts_with(VAR_PREFIX) => {
+                    Ok(Expr::Var(name))
+                }
+                _ => Err(format!("未知的变量 `{}`", name)),
+            },
+            Some(Token::LParen) => {
+                self.enter()?;
+                let inner = self.parse_or()?;
+                self.depth -= 1;
+                match self.next() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err("缺少 `)`".to_string()),
+                }
+            }
+            Some(token) => Err(format!("此处不应出现 `{}`", token.describe())),
+            None => Err("表达式不完整".to_string()),
+        }
+    }
+}
+
+fn parse(source: &str) -> Result<Expr, String> {
+    if source.len() > MAX_EXPRESSION_LEN {
+        return Err(format!("表达式长度不能超过 {} 个字符", MAX_EXPRESSION_LEN));
+    }
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        pos: 0,
+        depth: 0,
+    };
+    if parser.tokens.is_empty() {
+        return Err("表达式为空".to_string());
+    }
+    let expr = parser.parse_or()?;
+    if let Some(token) = parser.peek() {
+        return Err(format!("多余的 `{}`", token.describe()));
+    }
+    Ok(expr)
+}
+
+fn compare(op: CompareOp, left: &Value, right: &Value) -> Result<bool, String> {
+    let ordering = match (left, right) {
+        (Value::Num(a), Value::Num(b)) => a.partial_cmp(b),
+        (Value::Str(a), Value::Str(b)) => Some(a.cmp(b)),
+        // 类型不同或不可排序的值只能判断是否相等
+        _ => {
+            return match op {
+                CompareOp::Eq => Ok(left == right),
+                CompareOp::Ne => Ok(left != right),
+                _ => Err(format!(
+                    "无法比较 {} 与 {} 的大小",
+                    left.describe(),
+                    right.describe()
+                )),
+            }
+        }
+    };
+    let Some(ordering) = ordering else {
+        return Ok(op == CompareOp::Ne);
+    };
+    Ok(match op {
+        CompareOp::Eq => ordering.is_eq(),
+        CompareOp::Ne => ordering.is_ne(),
+        CompareOp::Lt => ordering.is_lt(),
+        CompareOp::Le => ordering.is_le(),
+        CompareOp::Gt => ordering.is_gt(),
+        CompareOp::Ge => ordering.is_ge(),
+    })
+}
+
+fn eval(expr: &Expr, resolve: &dyn Fn(&str) -> Value) -> Result<Value, String> {
+    Ok(match expr {
+        Expr::Literal(value) => value.clone(),
+        Expr::Var(name) => resolve(name),
+        Expr::Not(inner) => Value::Bool(!eval(inner, resolve)?.truthy()),
+        Expr::Neg(inner) => match eval(inner, resolve)? {
+            Value::Num(n) => Value::Num(-n),
+            value => return Err(format!("无法对 {} 取负", value.describe())),
+        },
+        Expr::And(left, right) => {
+            Value::Bool(eval(left, resolve)?.truthy() && eval(right, resolve)?.truthy())
+        }
+        Expr::Or(left, right) => {
+            Value::Bool(eval(left, resolve)?.truthy() || eval(right, resolve)?.truthy())
+        }
+        Expr::Compare(op, left, right) => {
+            Value::Bool(compare(*op, &eval(left, resolve)?, &eval(right, resolve)?)?)
+        }
+    })
+}
+
+/// 上个已结束任务的结果
+fn last_result(instance: &InstanceRuntime, instance_id: &str, task_id: i64) -> String {
+    let Some(record) = instance
+        .task_report
+        .iter()
+        .rev()
+        .find(|r| r.task_id != task_id && r.status.is_some())
+    else {
+        return "none".to_string();
+    };
+    let skipped = LAST_SKIPPED
+        .lock()
+        .map(|skipped| skipped.get(instance_id) == Some(&record.task_id))
+        .unwrap_or(false);
+    if skipped {
+        "skipped".to_string()
+    } else {
+        record.status.clone().unwrap_or_default()
+    }
+}
+
+/// 对表达式求值
+fn evaluate(
+    expression: &str,
+    instance: &InstanceRuntime,
+    instance_id: &str,
+    task_id: i64,
+) -> Result<bool, String> {
+    let expr = parse(expression)?;
+    let now = chrono::Local::now();
+    let resolve = |name: &str| -> Value {
+        match name {
+            "hour" => Value::Num(now.hour() as f64),
+            "minute" => Value::Num(now.minute() as f64),
+            "weekday" => Value::Num(now.weekday().number_from_monday() as f64),
+            "day" => Value::Num(now.day() as f64),
+            "month" => Value::Num(now.month() as f64),
+            "round" => Value::Num(instance.task_run_state.round as f64),
+            "last_result" => Value::Str(last_result(instance, instance_id, task_id)),
+            _ => Value::from_json(
+                name.strip_prefix(VAR_PREFIX)
+                    .and_then(|key| crate::mxu_actions::get_task_var(instance_id, key)),
+            ),
+        }
+    };
+    Ok(eval(&expr, &resolve)?.truthy())
+}
+
+/// 任务开始时按跳过条件决定是否跳过，跳过或表达式有误时发出 `maa-task-condition` 事件
+///
+/// 在 tasker sink 中调用，只处理 `Tasker.Task.Starting`。跳过时用 override 把入口节点替换为
+/// 直接命中、不操作、没有后继的节点：回调同步执行，入口节点尚未读取，任务随即成功结束
+pub fn apply_skip_condition(
+    state: &Arc<MaaState>,
+    app: &AppHandle,
+    instance_id: &str,
+    message: &str,
+    details: &str,
+) {
+    if message != "Tasker.Task.Starting" {
+        return;
+    }
+    let Some(task_id) = serde_json::from_str::<serde_json::Value>(details)
+        .ok()
+        .and_then(|v| v.get("task_id").and_then(|id| id.as_i64()))
+    else {
+        return;
+    };
+
+    let event = {
+        let Ok(instances) = state.instances.lock() else {
+            return;
+        };
+        let Some(instance) = instances.get(instance_id) else {
+            return;
+        };
+        let Some(task) = instance
+            .submitted_tasks
+            .iter()
+            .find(|(id, _)| *id == task_id)
+            .map(|(_, task)| task)
+        else {
+            return;
+        };
+        let Some(expression) = task
+            .skip_if
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        else {
+            return;
+        };
+
+        let (skipped, error) = match evaluate(expression, instance, instance_id, task_id) {
+            Ok(skip) => (skip, None),
+            Err(e) => (false, Some(e)),
+        };
+        if skipped {
+            let mut skip_override = serde_json::Map::new();
+            skip_override.insert(
+                task.entry.clone(),
+                json!({
+                    "recognition": "DirectHit",
+                    "action": "DoNothing",
+                    "next": [],
+                    "on_error": [],
+                    "pre_delay": 0,
+                    "post_delay": 0,
+                }),
+            );
+            let overridden = instance.tasker.as_ref().is_some_and(|tasker| {
+                tasker
+                    .override_pipeline(
+                        task_id,
+                        &serde_json::Value::Object(skip_override).to_string(),
+                    )
+                    .unwrap_or(false)
+            });
+            if !overridden {
+                warn!(
+                    "[task_condition] Failed to skip task {} ({}), running it",
+                    task_id, task.entry
+                );
+                return;
+            }
+            if let Ok(mut last) = LAST_SKIPPED.lock() {
+                last.insert(instance_id.to_string(), task_id);
+            }
+        }
+        match &error {
+            None if !skipped => {
+                info!(
+                    "[task_condition] {} task {}: `{}` is false, running it",
+                    instance_id, task_id, expression
+                );
+                return;
+            }
+            Some(e) => warn!(
+                "[task_condition] {} task {}: invalid skip condition `{}`: {}, running it",
+                instance_id, task_id, expression, e
+            ),
+            None => info!(
+                "[task_condition] {} task {}: `{}` is true, skipped",
+                instance_id, task_id, expression
+            ),
+        }
+        TaskConditionEvent {
+            instance_id: instance_id.to_string(),
+            task_id,
+            entry: task.entry.clone(),
+            expression: expression.to_string(),
+            skipped,
+            error,
+        }
+    };
+
+    if let Err(e) = app.emit("maa-task-condition", event) {
+        log::error!("Failed to emit maa-task-condition: {}", e);
+    }
+}
+
+/// 移除实例的跳过记录（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut entries) = LAST_SKIPPED.lock() {
+        entries.remove(instance_id);
+    }
+}
+
+/// 检查跳过条件表达式的语法，有误时返回错误说明
+#[tauri::command]
+pub fn check_skip_condition(expression: String) -> Result<(), String> {
+    parse(expression.trim()).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(source: &str, vars: &[(&str, Value)]) -> Result<bool, String> {
+        let expr = parse(source)?;
+        let resolve = |name: &str| {
+            vars.iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| v.clone())
+                .unwrap_or(Value::Null)
+        };
+        Ok(eval(&expr, &resolve)?.truthy())
+    }
+
+    fn is_true(source: &str) -> bool {
+        check(source, &[]).unwrap()
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        assert!(is_true("true || false && false"));
+        assert!(!is_true("(true || false) && false"));
+        assert!(is_true("false and true or true"));
+    }
+
+    #[test]
+    fn not_applies_to_comparison() {
+        assert!(is_true("!1 == 2"));
+        assert!(is_true("not false"));
+        assert!(!is_true("!!false"));
+        assert!(is_true("not (1 > 2) and 1 != 2"));
+    }
+
+    #[test]
+    fn compares_numbers_and_strings() {
+        assert!(is_true("1 < 2 && 2 <= 2 && 3 > 2 && 3 >= 3"));
+        assert!(is_true("1.5 == 1.5"));
+        assert!(is_true("'abc' == \"abc\""));
+        assert!(is_true("'a' < 'b'"));
+        assert!(is_true("'1' != 1"));
+        assert!(is_true("null == null"));
+    }
+
+    #[test]
+    fn unary_minus() {
+        assert!(is_true("-1 < 0"));
+        assert!(is_true("--2 == 2"));
+        assert!(is_true("-(1) == -1"));
+        let vars = [("var.x", Value::Num(0.0))];
+        assert_eq!(check("var.x > -1", &vars), Ok(true));
+        assert_eq!(check("-var.x == 0", &vars), Ok(true));
+        assert!(check("-'a' == 1", &[]).is_err());
+        assert!(check("-var.missing == 1", &[]).is_err());
+    }
+
+    #[test]
+    fn resolves_variables() {
+        let vars = [
+            ("hour", Value::Num(23.0)),
+            ("last_result", Value::Str("failed".to_string())),
+            ("var.retry", Value::Num(2.0)),
+        ];
+        assert_eq!(check("hour >= 22 || weekday == 7", &vars), Ok(true));
+        assert_eq!(
+            check("last_result == \"failed\" and var.retry < 3", &vars),
+            Ok(true)
+        );
+        assert_eq!(check("var.unset == null", &vars), Ok(true));
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        for source in [
+            "",
+            "   ",
+            "1 &",
+            "1 = 1",
+            "'abc",
+            "(1 == 1",
+            "1 == 1)",
+            "1 ==",
+            "foo == 1",
+            "var. == 1",
+            "1 2",
+            "1 # 2",
+            "1.2.3 == 1",
+        ] {
+            assert!(parse(source).is_err(), "`{}` should be rejected", source);
+        }
+    }
+
+    #[test]
+    fn ordering_mismatched_types_fails() {
+        assert!(check("'a' < 1", &[]).is_err());
+        assert!(check("null > 0", &[]).is_err());
+        assert!(check("true >= false", &[]).is_err());
+    }
+
+    #[test]
+    fn enforces_limits() {
+        let long = format!("{} == 1", "1".repeat(MAX_EXPRESSION_LEN));
+        assert!(parse(&long).unwrap_err().contains("长度"));
+
+        let nested = |depth: usize| format!("{}true{}", "(".repeat(depth), ")".repeat(depth));
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+        assert!(parse(&nested(MAX_DEPTH + 1)).unwrap_err().contains("嵌套"));
+
+        assert!(parse(&format!("{}true", "!".repeat(MAX_DEPTH))).is_ok());
+        assert!(parse(&format!("{}true", "!".repeat(MAX_DEPTH + 1))).is_err());
+        assert!(parse(&format!("{}1", "-".repeat(MAX_DEPTH + 1))).is_err());
+    }
+}
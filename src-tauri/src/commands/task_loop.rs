@@ -0,0 +1,270 @@
+//! 任务队列循环执行
+//!
+//! `maa_start_tasks` 可传入循环配置（固定轮数或无限循环、每轮之间的间隔）。一轮为启动时提交的
+//! 这批任务，全部结束后发出 `maa-round-completed` 事件；还需继续时整体状态保持 Running，
+//! 等待间隔后重置本轮的运行记录，按原顺序重新提交同一批任务。
+//! 停止任务时清除循环上下文：tasker 在当前任务的节点边界停下，等待中的下一轮也不再开始。
+//! 当前轮次记录在 `TaskRunState` 中，随状态查询一并返回。
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::{info, warn};
+use serde_json::{Map, Value};
+use tauri::{AppHandle, Emitter};
+
+use super::maa_core::{run_task_impl, stop_task_impl};
+use super::task_retry::wait_unless;
+use super::types::{
+    InstanceRuntime, MaaState, PipelineOverrideRecord, QueueLoopContext, TaskConfig, TaskQueueLoop,
+    TaskRoundCompletedEvent,
+};
+use super::utils::{emit_state_changed, parse_pipeline_override};
+
+/// 每轮间隔上限
+const MAX_INTERVAL_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// 循环代次计数，每次开始新的循环时自增
+static LOOP_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// 校验循环配置
+pub fn validate_loop(config: &TaskQueueLoop) -> Result<(), String> {
+    if config.interval_ms > MAX_INTERVAL_MS {
+        return Err("循环间隔不能超过 24 小时".to_string());
+    }
+    Ok(())
+}
+
+/// 开始新的任务队列（首批）时设置循环上下文，`config` 为 None 时不循环
+pub fn begin_loop(
+    instance: &mut InstanceRuntime,
+    config: Option<TaskQueueLoop>,
+    tasks: Vec<TaskConfig>,
+) {
+    let state = &mut instance.task_run_state;
+    match config {
+        Some(config) if !tasks.is_empty() => {
+            info!(
+                "[task_loop] Looping {} task(s), rounds: {}, interval: {}ms",
+                tasks.len(),
+                config.rounds,
+                config.interval_ms
+            );
+            state.round = 1;
+            state.max_rounds = config.rounds;
+            instance.queue_loop = Some(QueueLoopContext {
+                config,
+                tasks,
+                hot_layers: HashMap::new(),
+                generation: LOOP_GENERATION.fetch_add(1, Ordering::Relaxed) + 1,
+            });
+        }
+        _ => {
+            state.round = 0;
+            state.max_rounds = 0;
+            instance.queue_loop = None;
+        }
+    }
+}
+
+/// 一轮结束后的处理
+pub struct RoundEnd {
+    event: TaskRoundCompletedEvent,
+    generation: u64,
+}
+
+impl RoundEnd {
+    /// 是否继续执行下一轮
+    pub fn continues(&self) -> bool {
+        self.event.continues
+    }
+}
+
+/// 本轮所有任务结束时调用（在 `handle_task_callback` 中持有实例锁时）
+///
+/// 未循环执行时返回 None。已是最后一轮或正在停止时清除循环上下文，调用方照常结束队列；
+/// 需要继续时调用方应保持整体状态为 Running。释放锁后调用 [`on_round_end`]
+pub fn finish_round(
+    instance: &mut InstanceRuntime,
+    instance_id: &str,
+    has_failed: bool,
+) -> Option<RoundEnd> {
+    let ctx = instance.queue_loop.as_ref()?;
+    let round = instance.task_run_state.round;
+    let max_rounds = ctx.config.rounds;
+    let continues = !instance.stop_in_progress && (max_rounds == 0 || round < max_rounds);
+    let end = RoundEnd {
+        event: TaskRoundCompletedEvent {
+            instance_id: instance_id.to_string(),
+            round,
+            max_rounds,
+            has_failed,
+            continues,
+            interval_ms: if continues { ctx.config.interval_ms } else { 0 },
+        },
+        generation: ctx.generation,
+    };
+    if !continues {
+        instance.queue_loop = None;
+    }
+    Some(end)
+}
+
+/// 发出本轮结束事件，需要继续时在后台线程中等待间隔后开始下一轮
+pub fn on_round_end(app: &AppHandle, state: &Arc<MaaState>, end: RoundEnd) {
+    info!(
+        "[task_loop] Round {}/{} finished on {} (failed: {}, continues: {})",
+        end.event.round,
+        end.event.max_rounds,
+        end.event.instance_id,
+        end.event.has_failed,
+        end.event.continues
+    );
+    if let Err(e) = app.emit("maa-round-completed", end.event.clone()) {
+        log::error!("Failed to emit maa-round-completed: {}", e);
+    }
+    if end.continues() {
+        let app = app.clone();
+        let state = Arc::clone(state);
+        std::thread::spawn(move || run_next_round(&app, &state, end));
+    }
+}
+
+/// 循环是否仍在进行（未被停止，也未被新的任务队列替换）
+fn loop_active(state: &MaaState, instance_id: &str, generation: u64) -> bool {
+    state
+        .instances
+        .lock()
+        .ok()
+        .and_then(|instances| {
+            let ctx = instances.get(instance_id)?.queue_loop.as_ref()?;
+            Some(ctx.generation == generation)
+        })
+        .unwrap_or(false)
+}
+
+fn run_next_round(app: &AppHandle, state: &Arc<MaaState>, end: RoundEnd) {
+    let instance_id = end.event.instance_id.as_str();
+    let interval = Duration::from_millis(end.event.interval_ms);
+    let stopped = || !loop_active(state, instance_id, end.generation);
+    if !wait_unless(interval, stopped) {
+        info!(
+            "[task_loop] Loop stopped on {}, not starting next round",
+            instance_id
+        );
+        return;
+    }
+
+    // 重置本轮的运行记录，轮次加一
+    let tasks = {
+        let Ok(mut instances) = state.instances.lock() else {
+            return;
+        };
+        let Some(instance) = instances.get_mut(instance_id) else {
+            return;
+        };
+        let Some(ctx) = instance
+            .queue_loop
+            .as_ref()
+            .filter(|ctx| ctx.generation == end.generation)
+        else {
+            return;
+        };
+        // 运行中热更新过的任务带上对应的 override
+        let hot = &ctx.hot_layers;
+        let tasks: Vec<(TaskConfig, Vec<Map<String, Value>>)> = ctx
+            .tasks
+            .iter()
+            .enumerate()
+            .map(|(i, task)| (task.clone(), hot.get(&i).cloned().unwrap_or_default()))
+            .collect();
+        instance.task_ids.clear();
+        instance.task_report.clear();
+        instance.pipeline_overrides.clear();
+        instance.submitted_tasks.clear();
+        instance.preempted_task_ids.clear();
+        instance.agent_interrupted_task_ids.clear();
+        instance.retry_attempts.clear();
+        if let Some(launch) = instance.agent_launch.as_mut() {
+            launch.tasks.clear();
+        }
+        let run_state = &mut instance.task_run_state;
+        run_state.statuses.clear();
+        run_state.mappings.clear();
+        run_state.pending_task_ids.clear();
+        run_state.current_task_index = 0;
+        run_state.round = end.event.round + 1;
+        run_state.overall_status = Some("Running".to_string());
+        tasks
+    };
+
+    let mut submitted = 0;
+    for (task, hot_layers) in tasks {
+        let original = parse_pipeline_override(&task.pipeline_override).unwrap_or_default();
+        let submit = if hot_layers.is_empty() {
+            task.clone()
+        } else {
+            let layers = original
+                .iter()
+                .chain(&hot_layers)
+                .cloned()
+                .map(Value::Object);
+            TaskConfig {
+                pipeline_override: Value::Array(layers.collect()).to_string(),
+                ..task.clone()
+            }
+        };
+        let task_id = match run_task_impl(app, state, instance_id, &submit) {
+            Ok(task_id) => task_id,
+            Err(e) => {
+                warn!(
+                    "[task_loop] Failed to submit task {} on {}: {}",
+                    task.entry, instance_id, e
+                );
+                continue;
+            }
+        };
+        submitted += 1;
+        if let Ok(mut instances) = state.instances.lock() {
+            if let Some(instance) = instances.get_mut(instance_id) {
+                instance.pipeline_overrides.insert(
+                    task_id,
+                    PipelineOverrideRecord {
+                        original,
+                        applied: hot_layers,
+                    },
+                );
+                let run_state = &mut instance.task_run_state;
+                if !run_state.pending_task_ids.contains(&task_id) {
+                    run_state.pending_task_ids.push(task_id);
+                }
+                if let Some(launch) = instance.agent_launch.as_mut() {
+                    launch.tasks.push((task_id, task));
+                }
+            }
+        }
+    }
+
+    if submitted == 0 {
+        warn!(
+            "[task_loop] No task submitted for round {} on {}, stopping loop",
+            end.event.round + 1,
+            instance_id
+        );
+        if let Err(e) = stop_task_impl(state, instance_id) {
+            warn!("[task_loop] Failed to stop tasks: {}", e);
+        }
+        emit_state_changed(app, instance_id, "task-stopped");
+        return;
+    }
+
+    info!(
+        "[task_loop] Round {} started on {} with {} task(s)",
+        end.event.round + 1,
+        instance_id,
+        submitted
+    );
+    emit_state_changed(app, instance_id, "task-started");
+}
@@ -0,0 +1,317 @@
+//! 任务失败自动重试
+//!
+//! 实例可配置失败重试策略（重试次数、间隔、重试前是否重连控制器/重启应用）。任务失败时
+//! 按策略处理：先让 tasker 在节点边界停下队列中其后的任务，等待间隔、按需重连或重启应用后，
+//! 从入口重新提交失败的任务及其后被中断的任务（与优先级抢占相同，旧任务的回调不计入状态）。
+//! 重试次数用尽仍失败时才按失败结束。每次重试发出 `maa-task-retry` 事件。
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+use maa_framework::MaaStatus;
+use tauri::{AppHandle, Emitter, State};
+
+use super::maa_core::stop_task_impl;
+use super::preempt::{remove_interrupted_tasks, resubmit_tasks, stop_at_node_boundary};
+use super::types::{
+    InstanceRuntime, MaaState, ResubmittedTask, TaskConfig, TaskRetryEvent, TaskRetryPolicy,
+};
+use super::utils::emit_state_changed;
+
+/// 单个任务最多允许配置的重试次数
+const MAX_RETRIES_LIMIT: u32 = 10;
+
+/// 重试间隔上限
+const MAX_INTERVAL_MS: u64 = 10 * 60 * 1000;
+
+/// 等待期间检查停止请求的间隔
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 一次待执行的重试
+pub struct RetryPlan {
+    task_id: i64,
+    /// 第几次重试（从 1 开始）
+    attempt: u32,
+    policy: TaskRetryPolicy,
+    /// 需要重新提交的任务：失败的任务及其后尚未结束的任务（按提交顺序）
+    tasks: Vec<(i64, TaskConfig)>,
+}
+
+/// 任务失败时按策略决定是否重试
+///
+/// 在 `handle_task_callback` 中持有实例锁时调用。需要重试时把失败任务及其后尚未结束的任务
+/// 标记为忽略回调并返回重试计划，调用方跳过本次失败的状态更新，释放锁后调用 [`schedule_retry`]
+pub fn plan_retry(instance: &mut InstanceRuntime, task_id: i64) -> Option<RetryPlan> {
+    let policy = instance.retry_policy.clone()?;
+    if policy.max_retries == 0 || instance.stop_in_progress || instance.preempting {
+        return None;
+    }
+    let attempt = instance.retry_attempts.get(&task_id).copied().unwrap_or(0) + 1;
+    if attempt > policy.max_retries {
+        return None;
+    }
+    let position = instance
+        .submitted_tasks
+        .iter()
+        .position(|(id, _)| *id == task_id)?;
+
+    let finished: HashSet<i64> = instance
+        .task_report
+        .iter()
+        .filter(|r| r.status.is_some() && r.task_id != task_id)
+        .map(|r| r.task_id)
+        .collect();
+    let tasks: Vec<(i64, TaskConfig)> = instance.submitted_tasks[position..]
+        .iter()
+        .filter(|(id, _)| {
+            *id == task_id || (!finished.contains(id) && instance.task_ids.contains(id))
+        })
+        .cloned()
+        .collect();
+    instance
+        .preempted_task_ids
+        .extend(tasks.iter().map(|(id, _)| *id));
+
+    Some(RetryPlan {
+        task_id,
+        attempt,
+        policy,
+        tasks,
+    })
+}
+
+/// 在后台线程中执行重试计划
+pub fn schedule_retry(app: AppHandle, state: Arc<MaaState>, instance_id: String, plan: RetryPlan) {
+    std::thread::spawn(move || run_retry(&app, &state, &instance_id, plan));
+}
+
+fn is_stopping(state: &MaaState, instance_id: &str) -> bool {
+    state
+        .instances
+        .lock()
+        .ok()
+        .and_then(|instances| instances.get(instance_id).map(|i| i.stop_in_progress))
+        .unwrap_or(true)
+}
+
+/// 可中断的等待，期间定期检查 `cancelled`，返回 false 表示等待被取消
+pub fn wait_unless(duration: Duration, cancelled: impl Fn() -> bool) -> bool {
+    let deadline = Instant::now() + duration;
+    while Instant::now() < deadline {
+        if cancelled() {
+            return false;
+        }
+        std::thread::sleep(
+            STOP_POLL_INTERVAL.min(deadline.saturating_duration_since(Instant::now())),
+        );
+    }
+    !cancelled()
+}
+
+/// 可中断的等待，返回 false 表示期间用户停止了任务
+fn wait_unless_stopping(state: &MaaState, instance_id: &str, duration: Duration) -> bool {
+    wait_unless(duration, || is_stopping(state, instance_id))
+}
+
+/// 重试前按策略重连控制器、重启应用
+fn prepare_controller(state: &MaaState, instance_id: &str, policy: &TaskRetryPolicy) {
+    let controller = state
+        .instances
+        .lock()
+        .ok()
+        .and_then(|instances| instances.get(instance_id)?.controller.clone());
+    let Some(controller) = controller else {
+        return;
+    };
+
+    if policy.reconnect {
+        info!("[task_retry] Reconnecting controller for {}", instance_id);
+        match controller.post_connection() {
+            Ok(id) if controller.wait(id) == MaaStatus::SUCCEEDED => {}
+            Ok(_) => warn!("[task_retry] Controller reconnection failed"),
+            Err(e) => warn!("[task_retry] Failed to post controller connection: {}", e),
+        }
+    }
+
+    if let Some(intent) = policy.restart_app.as_deref().filter(|s| !s.is_empty()) {
+        info!("[task_retry] Restarting app {} for {}", intent, instance_id);
+        match controller.post_stop_app(intent) {
+            Ok(id) => {
+                controller.wait(id);
+            }
+            Err(e) => warn!("[task_retry] Failed to stop app {}: {}", intent, e),
+        }
+        match controller.post_start_app(intent) {
+            Ok(id) if controller.wait(id) == MaaStatus::SUCCEEDED => {}
+            Ok(_) => warn!("[task_retry] Failed to start app {}", intent),
+            Err(e) => warn!("[task_retry] Failed to start app {}: {}", intent, e),
+        }
+    }
+}
+
+fn emit_retry(app: &AppHandle, event: TaskRetryEvent) {
+    if let Err(e) = app.emit("maa-task-retry", event) {
+        log::error!("Failed to emit maa-task-retry: {}", e);
+    }
+}
+
+/// 重试无法进行时按失败结束：停止任务，剩余任务一并标记为失败
+fn give_up(app: &AppHandle, state: &Arc<MaaState>, instance_id: &str) {
+    if let Err(e) = stop_task_impl(state, instance_id) {
+        warn!("[task_retry] Failed to stop tasks: {}", e);
+    }
+    emit_state_changed(app, instance_id, "task-stopped");
+}
+
+fn run_retry(app: &AppHandle, state: &Arc<MaaState>, instance_id: &str, plan: RetryPlan) {
+    let entry = plan
+        .tasks
+        .first()
+        .map(|(_, task)| task.entry.clone())
+        .unwrap_or_default();
+    let event =
+        |status: &str, resubmitted: Vec<ResubmittedTask>, error: Option<String>| TaskRetryEvent {
+            instance_id: instance_id.to_string(),
+            task_id: plan.task_id,
+            entry: entry.clone(),
+            attempt: plan.attempt,
+            max_retries: plan.policy.max_retries,
+            status: status.to_string(),
+            resubmitted,
+            error,
+        };
+
+    info!(
+        "[task_retry] Task {} failed on {}, retry {}/{}",
+        entry, instance_id, plan.attempt, plan.policy.max_retries
+    );
+    emit_retry(app, event("retrying", Vec::new(), None));
+
+    // 在节点边界停下队列中其后的任务
+    let tasker = state
+        .instances
+        .lock()
+        .ok()
+        .and_then(|instances| instances.get(instance_id)?.tasker.clone());
+    if let Some(tasker) = &tasker {
+        if !stop_at_node_boundary(tasker) {
+            warn!("[task_retry] Queue did not stop in time on {}", instance_id);
+            give_up(app, state, instance_id);
+            emit_retry(
+                app,
+                event("failed", Vec::new(), Some("等待队列停止超时".to_string())),
+            );
+            return;
+        }
+    }
+
+    // 移除旧记录，重试次数随新 ID 延续
+    let (old_overrides, previous_attempts) = {
+        let Ok(mut instances) = state.instances.lock() else {
+            return;
+        };
+        let Some(instance) = instances.get_mut(instance_id) else {
+            return;
+        };
+        let previous_attempts: Vec<u32> = plan
+            .tasks
+            .iter()
+            .map(|(id, _)| {
+                if *id == plan.task_id {
+                    plan.attempt
+                } else {
+                    instance.retry_attempts.get(id).copied().unwrap_or(0)
+                }
+            })
+            .collect();
+        for (id, _) in &plan.tasks {
+            instance.retry_attempts.remove(id);
+        }
+        (
+            remove_interrupted_tasks(instance, &plan.tasks),
+            previous_attempts,
+        )
+    };
+
+    let interval = Duration::from_millis(plan.policy.interval_ms);
+    if !wait_unless_stopping(state, instance_id, interval) {
+        info!("[task_retry] Stop requested, not retrying");
+        return;
+    }
+    prepare_controller(state, instance_id, &plan.policy);
+    if is_stopping(state, instance_id) {
+        info!("[task_retry] Stop requested, not retrying");
+        return;
+    }
+
+    let attempts_by_old_id: Vec<(i64, u32)> = plan
+        .tasks
+        .iter()
+        .map(|(id, _)| *id)
+        .zip(previous_attempts)
+        .collect();
+    let resubmitted = resubmit_tasks(app, state, instance_id, plan.tasks, old_overrides);
+    if !resubmitted.iter().any(|r| r.old_task_id == plan.task_id) {
+        warn!("[task_retry] Failed to resubmit task {}", entry);
+        give_up(app, state, instance_id);
+        emit_retry(
+            app,
+            event("failed", resubmitted, Some("重新提交任务失败".to_string())),
+        );
+        return;
+    }
+
+    if let Ok(mut instances) = state.instances.lock() {
+        if let Some(instance) = instances.get_mut(instance_id) {
+            for r in &resubmitted {
+                let attempts = attempts_by_old_id
+                    .iter()
+                    .find(|(id, _)| *id == r.old_task_id)
+                    .map_or(0, |(_, n)| *n);
+                if attempts > 0 {
+                    instance.retry_attempts.insert(r.new_task_id, attempts);
+                }
+            }
+        }
+    }
+    emit_state_changed(app, instance_id, "task-progress");
+    emit_retry(app, event("resubmitted", resubmitted, None));
+}
+
+/// 设置实例的任务失败重试策略，传 None 关闭重试（对之后失败的任务生效）
+#[tauri::command]
+pub fn set_task_retry_policy(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    policy: Option<TaskRetryPolicy>,
+) -> Result<(), String> {
+    if let Some(policy) = &policy {
+        if policy.max_retries > MAX_RETRIES_LIMIT {
+            return Err(format!("重试次数不能超过 {}", MAX_RETRIES_LIMIT));
+        }
+        if policy.interval_ms > MAX_INTERVAL_MS {
+            return Err("重试间隔不能超过 10 分钟".to_string());
+        }
+    }
+    info!("set_task_retry_policy: {} -> {:?}", instance_id, policy);
+    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
+    let instance = instances
+        .get_mut(&instance_id)
+        .ok_or("Instance not found")?;
+    instance.retry_policy = policy;
+    Ok(())
+}
+
+/// 获取实例的任务失败重试策略，未设置或实例不存在时返回 None
+#[tauri::command]
+pub fn get_task_retry_policy(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<Option<TaskRetryPolicy>, String> {
+    let instances = state.instances.lock().map_err(|e| e.to_string())?;
+    Ok(instances
+        .get(&instance_id)
+        .and_then(|instance| instance.retry_policy.clone()))
+}
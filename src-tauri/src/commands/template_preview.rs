@@ -0,0 +1,117 @@
+//! 模板图预览
+//!
+//! 调 pipeline 时查看识别节点引用的模板图：节点定义取自已加载资源（多目录叠加后的最终定义），
+//! 模板图按加载顺序倒序在各资源目录的 `image` 下查找，与 MaaFramework 后加载覆盖的规则一致。
+//! 模板可以是单张图片或一个目录（目录下所有图片都作为模板）
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use log::{info, warn};
+use tauri::State;
+
+use super::resource_check::node_templates;
+use super::types::{MaaState, TemplatePreview};
+use super::utils::normalize_path;
+
+/// 作为模板加载的图片扩展名
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "bmp"];
+
+/// 在资源目录的 image 下解析模板路径，拒绝越出 image 目录的路径
+pub fn resolve_template(bundle: &Path, template: &str) -> Option<PathBuf> {
+    let image_dir = normalize_path(&bundle.join("image").to_string_lossy());
+    let path = normalize_path(&image_dir.join(template).to_string_lossy());
+    if !path.starts_with(&image_dir) {
+        warn!("Template path escapes image dir: {}", template);
+        return None;
+    }
+    path.exists().then_some(path)
+}
+
+/// 读取模板文件（目录则读取其下所有图片，按文件名排序）
+fn read_template_files(path: &Path) -> Result<Vec<(PathBuf, Vec<u8>)>, String> {
+    let files = if path.is_dir() {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+            .map_err(|e| format!("读取模板目录失败 [{}]: {}", path.display(), e))?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|p| {
+                p.is_file()
+                    && p.extension()
+                        .and_then(|e| e.to_str())
+                        .is_some_and(|e| IMAGE_EXTENSIONS.iter().any(|x| e.eq_ignore_ascii_case(x)))
+            })
+            .collect();
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    files
+        .into_iter()
+        .map(|file| {
+            std::fs::read(&file)
+                .map(|data| (file.clone(), data))
+                .map_err(|e| format!("读取模板图失败 [{}]: {}", file.display(), e))
+        })
+        .collect()
+}
+
+/// 获取节点引用的模板图（base64），多模板节点按声明顺序返回全部
+#[tauri::command]
+pub fn maa_get_template_preview(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    node_name: String,
+) -> Result<Vec<TemplatePreview>, String> {
+    let (resource, bundles) = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(&instance_id).ok_or("Instance not found")?;
+        let resource = instance.resource.clone().ok_or("Resource not loaded")?;
+        let bundles = instance
+            .resource_load
+            .lock()
+            .map_err(|e| e.to_string())?
+            .paths
+            .clone();
+        (resource, bundles)
+    };
+
+    let node = resource
+        .get_node_data(&node_name)
+        .map_err(|e| format!("获取节点定义失败: {}", e))?
+        .ok_or_else(|| format!("节点 \"{}\" 不存在", node_name))?;
+    let node: serde_json::Value =
+        serde_json::from_str(&node).map_err(|e| format!("解析节点定义失败: {}", e))?;
+
+    let templates = node_templates(&node);
+    if templates.is_empty() {
+        return Err(format!("节点 \"{}\" 没有引用模板图", node_name));
+    }
+
+    let mut previews = Vec::new();
+    for template in &templates {
+        // 后加载的资源目录覆盖先加载的，因此倒序查找
+        let path = bundles
+            .iter()
+            .rev()
+            .find_map(|bundle| resolve_template(Path::new(bundle), template))
+            .ok_or_else(|| format!("模板图 \"image/{}\" 不存在", template))?;
+        for (file, data) in read_template_files(&path)? {
+            previews.push(TemplatePreview {
+                template: template.clone(),
+                path: file.to_string_lossy().to_string(),
+                base64: STANDARD.encode(&data),
+            });
+        }
+    }
+
+    info!(
+        "maa_get_template_preview: node={}, {} image(s)",
+        node_name,
+        previews.len()
+    );
+    Ok(previews)
+}
@@ -0,0 +1,117 @@
+//! 设备搜索的 Toolkit 配置命令
+//!
+//! MaaToolkit 只会在已知模拟器的默认位置和 PATH 中查找 adb，冷门模拟器或自定义安装位置经常搜不到。
+//! 这里允许用户补充：
+//! - 额外的 adb 搜索目录（或直接给出 adb 可执行文件）
+//! - 模拟器安装目录（在其中有限深度地查找 adb）
+//!
+//! 未指定 adb 路径搜索设备时，框架默认结果之外再用这些 adb 各搜索一次并合并。
+//! 配置由前端写入 mxu.json 的 settings.toolkitConfig，启动时读取应用。
+
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+use log::info;
+
+use super::types::ToolkitConfig;
+
+/// 在模拟器安装目录中查找 adb 的最大深度（adb 通常在安装目录下的 1～2 层子目录）
+const EMULATOR_DIR_SEARCH_DEPTH: usize = 3;
+
+/// 当前 Toolkit 配置
+static TOOLKIT_CONFIG: LazyLock<Mutex<ToolkitConfig>> =
+    LazyLock::new(|| Mutex::new(ToolkitConfig::default()));
+
+#[cfg(windows)]
+const ADB_FILE_NAME: &str = "adb.exe";
+#[cfg(not(windows))]
+const ADB_FILE_NAME: &str = "adb";
+
+/// 去掉空白项和重复项
+fn normalize_dirs(dirs: Vec<String>) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    for dir in dirs {
+        let dir = dir.trim().to_string();
+        if !dir.is_empty() && !result.contains(&dir) {
+            result.push(dir);
+        }
+    }
+    result
+}
+
+/// 应用 Toolkit 配置（启动时从配置读取，之后由前端修改）
+pub fn apply_toolkit_config(config: ToolkitConfig) {
+    let config = ToolkitConfig {
+        extra_adb_dirs: normalize_dirs(config.extra_adb_dirs),
+        emulator_dirs: normalize_dirs(config.emulator_dirs),
+    };
+    info!(
+        "Toolkit config: {} extra adb dir(s), {} emulator dir(s)",
+        config.extra_adb_dirs.len(),
+        config.emulator_dirs.len()
+    );
+    if let Ok(mut current) = TOOLKIT_CONFIG.lock() {
+        *current = config;
+    }
+}
+
+/// 在目录中有限深度地查找 adb
+fn find_adb_in_dir(dir: &Path, depth: usize, found: &mut Vec<PathBuf>) {
+    let candidate = dir.join(ADB_FILE_NAME);
+    if candidate.is_file() {
+        found.push(candidate);
+    }
+    if depth == 0 {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_type().is_ok_and(|t| t.is_dir()) {
+            find_adb_in_dir(&entry.path(), depth - 1, found);
+        }
+    }
+}
+
+/// 根据配置收集额外的 adb 可执行文件（已去重，不存在的路径忽略）
+pub fn extra_adb_paths() -> Vec<PathBuf> {
+    let config = TOOLKIT_CONFIG.lock().map(|c| c.clone()).unwrap_or_default();
+
+    let mut found = Vec::new();
+    for dir in &config.extra_adb_dirs {
+        let path = Path::new(dir);
+        if path.is_file() {
+            found.push(path.to_path_buf());
+        } else {
+            find_adb_in_dir(path, 0, &mut found);
+        }
+    }
+    for dir in &config.emulator_dirs {
+        find_adb_in_dir(Path::new(dir), EMULATOR_DIR_SEARCH_DEPTH, &mut found);
+    }
+
+    let mut unique: Vec<PathBuf> = Vec::new();
+    for path in found {
+        if !unique.contains(&path) {
+            unique.push(path);
+        }
+    }
+    unique
+}
+
+/// 设置 Toolkit 配置（持久化由前端写入配置），返回当前能找到的额外 adb 路径
+#[tauri::command]
+pub fn set_toolkit_config(config: ToolkitConfig) -> Vec<String> {
+    apply_toolkit_config(config);
+    extra_adb_paths()
+        .into_iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect()
+}
+
+/// 查询当前 Toolkit 配置
+#[tauri::command]
+pub fn get_toolkit_config() -> ToolkitConfig {
+    TOOLKIT_CONFIG.lock().map(|c| c.clone()).unwrap_or_default()
+}
@@ -26,3 +26,15 @@ pub fn update_tray_icon(icon_path: String) -> Result<(), String> {
 pub fn update_tray_tooltip(tooltip: String) -> Result<(), String> {
     tray::update_tray_tooltip(&tooltip)
 }
+
+/// 设置托盘图标上的任务进度环（total 为 0 或进度已满时恢复原始图标）
+#[tauri::command]
+pub fn set_tray_progress(current: u32, total: u32) -> Result<(), String> {
+    tray::set_tray_progress(current, total)
+}
+
+/// 切换托盘菜单语言
+#[tauri::command]
+pub fn set_tray_language(app: tauri::AppHandle, language: String) -> Result<(), String> {
+    tray::set_tray_language(&app, &language)
+}
@@ -5,7 +5,7 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
 use std::process::Child;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use serde::{Deserialize, Serialize};
@@ -30,6 +30,12 @@ pub struct AdbDevice {
     #[serde(with = "u64_as_string")]
     pub input_methods: u64,
     pub config: String,
+    /// 是否为 USB 连接的真机（非模拟器）
+    #[serde(default)]
+    pub physical: bool,
+    /// 真机的 adb 授权状态（device / unauthorized / offline），模拟器为 None
+    #[serde(default)]
+    pub auth_state: Option<String>,
 }
 
 /// 将 u64 序列化/反序列化为字符串，避免 JavaScript 精度丢失
@@ -58,6 +64,9 @@ pub struct Win32Window {
     pub handle: u64,
     pub class_name: String,
     pub window_name: String,
+    /// 窗口所属程序图标（PNG data URL），无法获取时为空
+    #[serde(default)]
+    pub icon: Option<String>,
 }
 
 /// 控制器类型
@@ -121,6 +130,33 @@ pub enum ConnectionStatus {
     Failed(String),
 }
 
+/// 当前控制器详情（maa_get_controller_info 返回）
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ControllerInfo {
+    /// "connected"：已连接；"disconnected"：控制器已创建但未连接或已断开；"none"：尚未创建控制器
+    pub status: &'static str,
+    /// 控制器类型（Adb / Win32 / WlRoots / Gamepad / PlayCover / Dummy）
+    pub controller_type: Option<String>,
+    /// 连接目标（ADB 地址、窗口句柄、socket 路径等）
+    pub target: Option<String>,
+    /// ADB 路径（仅 ADB 控制器）
+    pub adb_path: Option<String>,
+    /// 截图方式（按位组合时列出各项）
+    pub screencap_methods: Vec<String>,
+    /// 输入方式（Win32 为鼠标输入方式）
+    pub input_methods: Vec<String>,
+    /// 键盘输入方式（仅 Win32 控制器）
+    pub keyboard_methods: Vec<String>,
+    /// 手柄类型（仅 Gamepad 控制器）
+    pub gamepad_type: Option<String>,
+    /// 设备原始分辨率（宽, 高），未连接或获取失败时为空
+    pub resolution: Option<(i32, i32)>,
+    /// 截图缩放的目标短边
+    pub display_short_side: Option<i32>,
+    /// 控制器 UUID，未连接或获取失败时为空
+    pub uuid: Option<String>,
+}
+
 /// 任务状态
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TaskStatus {
@@ -143,6 +179,12 @@ pub struct TaskRunState {
     pub current_task_index: usize,
     /// 实例级整体状态（None/"Running"/"Succeeded"/"Failed"）
     pub overall_status: Option<String>,
+    /// 循环执行时当前为第几轮（从 1 开始，未循环执行时为 0）
+    #[serde(default)]
+    pub round: u32,
+    /// 循环执行的总轮数（0 表示无限循环或未循环执行）
+    #[serde(default)]
+    pub max_rounds: u32,
 }
 
 /// 实例运行时状态（用于前端查询）
@@ -169,9 +211,40 @@ pub struct AllInstanceStates {
     pub cached_wlroots_sockets: Vec<String>,
 }
 
+/// 实例精简信息（用于多开管理面板列表）
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceSummary {
+    pub id: String,
+    /// 实例名称（由前端创建实例时传入）
+    pub name: Option<String>,
+    /// 创建时间（RFC 3339）
+    pub created_at: Option<String>,
+    /// 已连接设备的标识（ADB 地址、窗口句柄等），未连接时为 None
+    pub device: Option<String>,
+}
+
+/// 后端健康检查结果
+///
+/// 各子系统状态通过 try_lock 读取，对应锁被占用时为 None（表示该子系统正忙，而不是等待它）
+#[derive(Debug, Clone, Serialize)]
+pub struct PingResult {
+    /// 后端当前时间（Unix 毫秒）
+    pub timestamp_ms: i64,
+    /// MaaFramework 是否已初始化
+    pub maa_initialized: Option<bool>,
+    /// 实例数
+    pub instance_count: Option<usize>,
+    /// 正在执行任务的实例数
+    pub running_instances: Option<usize>,
+}
+
 /// 实例运行时状态（持有 MaaFramework 对象句柄）
 #[derive(Default)]
 pub struct InstanceRuntime {
+    /// 实例名称（由前端创建实例时传入，仅用于展示）
+    pub name: Option<String>,
+    /// 实例创建时间
+    pub created_at: Option<chrono::DateTime<chrono::Local>>,
     pub resource: Option<Resource>,
     pub controller: Option<Controller>,
     /// 当前控制器的配置（用于 ControllerPool 引用管理）
@@ -187,6 +260,905 @@ pub struct InstanceRuntime {
     pub stop_started_at: Option<Instant>,
     /// 任务运行状态（后端管理，单一真相来源）
     pub task_run_state: TaskRunState,
+    /// 实例自己的资源目录，加载资源时相对路径基于此解析（未设置时使用全局资源目录）
+    pub resource_dir: Option<PathBuf>,
+    /// 资源加载记录（与资源回调共享，用于统计加载耗时）
+    pub resource_load: Arc<Mutex<ResourceLoadRecord>>,
+    /// 最近一次编码的截图缓存（同一帧重复获取时复用，避免重复 base64 编码）
+    pub image_cache: Option<CachedImage>,
+    /// 主动截图（maa_post_screencap）的序号记录（与等待截图完成的线程共享）
+    pub screencap_record: Arc<Mutex<ScreencapRecord>>,
+    /// 本轮任务队列的执行记录（用于生成任务报告）
+    pub task_report: Vec<TaskReportRecord>,
+    /// Agent 启动参数（用于 Agent 崩溃后自动重启并续跑队列）
+    pub agent_launch: Option<AgentLaunchContext>,
+    /// Agent 代次，每次启动新一批 Agent 时自增（旧的监控线程据此退出）
+    pub agent_generation: u64,
+    /// 是否正在自动重启 Agent（期间忽略被中断任务的失败回调）
+    pub agent_restarting: bool,
+    /// Agent 退出后才失败的任务 ID（视为被崩溃中断，重启后重新执行）
+    pub agent_interrupted_task_ids: HashSet<i64>,
+    /// 各任务的 Pipeline override 记录（task_id -> 记录），用于查询与撤销
+    pub pipeline_overrides: HashMap<i64, PipelineOverrideRecord>,
+    /// 本轮已提交的任务及其配置（按提交顺序），用于优先级抢占后重新提交
+    pub submitted_tasks: Vec<(i64, TaskConfig)>,
+    /// 是否正在进行优先级抢占
+    pub preempting: bool,
+    /// 因优先级抢占或失败重试被中断、已重新提交的旧任务 ID（忽略其后续回调）
+    pub preempted_task_ids: HashSet<i64>,
+    /// 任务失败重试策略（未设置时失败即结束）
+    pub retry_policy: Option<TaskRetryPolicy>,
+    /// 各任务已重试的次数（task_id -> 次数），重新提交后随新 ID 延续
+    pub retry_attempts: HashMap<i64, u32>,
+    /// 任务队列循环执行的上下文（未循环执行或已停止时为 None）
+    pub queue_loop: Option<QueueLoopContext>,
+}
+
+/// 任务队列循环执行的上下文
+#[derive(Clone)]
+pub struct QueueLoopContext {
+    pub config: TaskQueueLoop,
+    /// 每轮执行的任务（启动时提交的这批任务，按执行顺序）
+    pub tasks: Vec<TaskConfig>,
+    /// 运行中对各任务（按 `tasks` 中的下标）热更新的 override，之后各轮重新提交时一并应用
+    pub hot_layers: HashMap<usize, Vec<serde_json::Map<String, serde_json::Value>>>,
+    /// 本次循环的代次，等待下一轮的线程据此判断循环是否已被停止或替换
+    pub generation: u64,
+}
+
+/// Agent 启动参数及本轮已提交的任务
+#[derive(Clone)]
+pub struct AgentLaunchContext {
+    pub agent_configs: Vec<AgentConfig>,
+    pub cwd: String,
+    pub tcp_compat_mode: bool,
+    pub pi_envs: HashMap<String, String>,
+    /// 已提交的任务（MaaFramework task_id 与任务配置，按执行顺序）
+    pub tasks: Vec<(i64, TaskConfig)>,
+    /// 本轮已自动重启的次数
+    pub restart_count: u32,
+}
+
+/// 停止 Agent 的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentStopOutcome {
+    /// 没有运行中的 Agent
+    NotRunning,
+    /// Agent 在超时前自行退出
+    Graceful,
+    /// 至少一个 Agent 超时未退出，已强制结束其进程树
+    Killed,
+}
+
+/// 一键停止时单个实例的停止结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstanceStopOutcome {
+    /// 任务已在超时前停下
+    Stopped,
+    /// 超时后任务仍在运行
+    TimedOut,
+    /// 发送停止请求失败
+    Failed,
+}
+
+/// `maa_stop_all` 中单个实例的结果
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceStopResult {
+    pub instance_id: String,
+    pub outcome: InstanceStopOutcome,
+    /// 停止失败时的错误信息
+    pub error: Option<String>,
+}
+
+/// 单个任务的 Pipeline override 记录
+#[derive(Default)]
+pub struct PipelineOverrideRecord {
+    /// 提交任务时携带的原始 override
+    pub original: Vec<serde_json::Map<String, serde_json::Value>>,
+    /// 通过 maa_override_pipeline 追加的 override（按应用顺序）
+    pub applied: Vec<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// `maa_override_pipeline` 的结果：本次修改的各字段（`节点.字段`）何时生效
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PipelineOverrideResult {
+    /// 是否已应用到该任务（任务已结束时为 false）
+    pub applied: bool,
+    /// 从下一个节点开始生效（排队中的任务则在开始时生效）
+    pub hot_fields: Vec<String>,
+    /// 所在节点正在执行，待该节点下次执行时生效
+    pub deferred_fields: Vec<String>,
+    /// 本次运行中不再生效，需重新开始任务（入口节点已执行过，或任务已结束）
+    pub restart_fields: Vec<String>,
+    /// 是否已同步到循环执行的后续轮次
+    pub next_round: bool,
+}
+
+/// 运行中热更新 Pipeline override 后的确认事件
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineHotUpdatedEvent {
+    pub instance_id: String,
+    pub task_id: i64,
+    #[serde(flatten)]
+    pub result: PipelineOverrideResult,
+}
+
+/// 单个任务当前生效的 Pipeline override（查询结果）
+#[derive(Serialize)]
+pub struct PipelineOverrideInfo {
+    pub task_id: i64,
+    /// 提交任务时携带的原始 override（合并后）
+    pub original: serde_json::Value,
+    /// 运行中追加的各次 override（按应用顺序）
+    pub applied: Vec<serde_json::Value>,
+    /// 叠加后当前生效的 override
+    pub effective: serde_json::Value,
+}
+
+/// 单个任务的执行记录
+pub struct TaskReportRecord {
+    pub task_id: i64,
+    pub entry: String,
+    pub started_at: Instant,
+    /// 结束状态（"succeeded" / "failed"），未结束时为 None
+    pub status: Option<String>,
+    pub duration_ms: Option<u64>,
+    /// 任务结束时的截图缩略图（仅在任务报告启用且需要该截图时保留）
+    pub screenshot: Option<ReportThumbnail>,
+}
+
+/// 任务报告截图缩略图（RGBA 像素）
+pub struct ReportThumbnail {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+/// 任务报告中包含截图的范围
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskReportScreenshots {
+    #[default]
+    All,
+    Failed,
+    None,
+}
+
+/// Win32 截图防遮挡模式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureMode {
+    /// 使用连接配置中的截图方式
+    #[default]
+    Default,
+    /// 任务运行中每轮识别前把目标窗口恢复并提到前台
+    Foreground,
+    /// 连接时改用 PrintWindow 截图（无效时回退到配置的截图方式）
+    PrintWindow,
+}
+
+/// 截图帧去重配置（未设置时不去重）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameDedupConfig {
+    /// 相邻两帧感知哈希相似度达到该值（0~1）时视为画面未变化
+    pub similarity: f64,
+    /// 画面持续未变化时每轮识别前最多等待的时间（毫秒）
+    pub max_interval_ms: u64,
+}
+
+/// 未知界面收集配置（未设置时不收集）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct UnknownScreenConfig {
+    /// 连续多少轮识别全部未命中时视为遇到未知界面
+    pub threshold: u32,
+}
+
+/// 已收集的未知界面概况
+#[derive(Debug, Clone, Serialize)]
+pub struct UnknownScreensInfo {
+    /// 归档目录
+    pub dir: String,
+    /// 已归档的截图数量
+    pub count: usize,
+}
+
+/// 截图预处理参数（送去识别前统一调整，各项为默认值时不改变画面）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScreenshotPreprocess {
+    /// 亮度偏移（-100 ~ 100，0 为不调整）
+    pub brightness: i32,
+    /// 对比度倍数（0.5 ~ 2，1 为不调整）
+    pub contrast: f32,
+    /// gamma（0.2 ~ 5，1 为不调整，大于 1 时提亮暗部）
+    pub gamma: f32,
+}
+
+/// Win32 目标窗口最小化时的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MinimizedPolicy {
+    /// 只发出事件提示
+    #[default]
+    Notify,
+    /// 暂停任务，窗口恢复可见后自动继续
+    Pause,
+    /// 以不激活的方式恢复窗口
+    Restore,
+}
+
+/// 目标窗口最小化/可见状态变化事件
+#[derive(Debug, Clone, Serialize)]
+pub struct WindowStateEvent {
+    pub instance_id: String,
+    pub handle: u64,
+    pub minimized: bool,
+    pub visible: bool,
+    /// 该实例当前的最小化处理方式
+    pub policy: MinimizedPolicy,
+}
+
+/// 连接前自动启动模拟器的配置（maa_connect_controller 的可选参数）
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmulatorLaunchConfig {
+    /// 模拟器程序路径
+    pub program: String,
+    /// 启动参数（按 shell 语义分词）
+    #[serde(default)]
+    pub args: String,
+    /// 工作目录（为空时使用程序所在目录）
+    #[serde(default)]
+    pub cwd: Option<String>,
+    /// 通过 cmd /c 启动（仅 Windows）
+    #[serde(default)]
+    pub use_cmd: bool,
+    /// 隐藏控制台窗口（仅 Windows）
+    #[serde(default)]
+    pub hide_window: bool,
+    /// 等待设备/窗口就绪的超时（秒），为空时使用默认值
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// 设备/窗口出现后再等待的秒数（仅本次拉起了模拟器时生效）
+    #[serde(default)]
+    pub settle_secs: Option<u64>,
+    /// Win32 / Gamepad 控制器原窗口句柄失效时，用于查找新窗口的类名正则
+    #[serde(default)]
+    pub class_regex: Option<String>,
+    /// Win32 / Gamepad 控制器原窗口句柄失效时，用于查找新窗口的窗口名正则
+    #[serde(default)]
+    pub window_regex: Option<String>,
+}
+
+/// 连接前自动启动模拟器的阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmulatorLaunchStage {
+    /// 已启动模拟器程序，等待设备/窗口就绪
+    Launching,
+    /// 模拟器程序已在运行，跳过启动，等待设备/窗口就绪
+    AlreadyRunning,
+    /// 设备/窗口已就绪
+    Ready,
+    /// 等待超时
+    Timeout,
+}
+
+/// 连接前自动启动模拟器的进度事件
+#[derive(Debug, Clone, Serialize)]
+pub struct EmulatorLaunchEvent {
+    pub instance_id: String,
+    pub stage: EmulatorLaunchStage,
+    pub program: String,
+}
+
+/// ADB 授权状态变化阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdbAuthStage {
+    /// 设备变为未授权，等待用户在设备上允许调试
+    Unauthorized,
+    /// 授权已恢复
+    Authorized,
+    /// 等待授权超时，不再等待
+    Timeout,
+}
+
+/// ADB 设备授权状态变化事件
+#[derive(Debug, Clone, Serialize)]
+pub struct AdbAuthEvent {
+    pub instance_id: String,
+    /// 设备序列号或地址
+    pub address: String,
+    pub stage: AdbAuthStage,
+    /// 授权恢复后是否已自动重连（连接前等待授权时恒为 false，连接随后继续进行）
+    pub reconnected: bool,
+}
+
+/// 执行外部程序（run_action / run_pretask / MXU_LAUNCH）前的信任策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgramTrustMode {
+    /// 信任所有程序（兼容旧行为，默认）
+    #[default]
+    TrustAll,
+    /// 不在信任列表中的程序弹窗确认，确认后加入信任列表
+    Confirm,
+    /// 只执行信任列表中的程序
+    Strict,
+}
+
+/// 外部程序信任配置（持久化到数据目录的 trusted_programs.json）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProgramTrustConfig {
+    #[serde(default)]
+    pub mode: ProgramTrustMode,
+    /// 已信任的程序路径（规范化后）
+    #[serde(default)]
+    pub programs: Vec<String>,
+}
+
+/// 外部程序被拒绝执行的事件载荷（maa-program-blocked）
+#[derive(Debug, Clone, Serialize)]
+pub struct ProgramBlockedEvent {
+    /// 触发的实例，无法确定时为空
+    pub instance_id: Option<String>,
+    pub program: String,
+    /// 来源：run_action / run_pretask / MXU_LAUNCH
+    pub source: String,
+    /// 严格模式下不在信任列表中为 false，用户在确认框中拒绝为 true
+    pub rejected_by_user: bool,
+}
+
+/// 资源期望的分辨率（interface.json 控制器的 `expected_resolution`）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ExpectedResolution {
+    pub width: u32,
+    pub height: u32,
+    /// 宽高比允许的相对误差，默认 0.02
+    #[serde(default)]
+    pub tolerance: Option<f64>,
+}
+
+/// 连接后的分辨率预检结果（不符时同时作为 `maa-resolution-mismatch` 事件载荷）
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolutionCheck {
+    pub instance_id: String,
+    /// 截图尺寸（已按 display_short_side 缩放，宽高比与设备一致）
+    pub actual: (u32, u32),
+    /// 资源期望的分辨率，不符时作为建议分辨率
+    pub expected: (u32, u32),
+    pub matched: bool,
+}
+
+/// 实例运行时长看门狗配置
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchdogConfig {
+    /// 单轮任务允许的最大运行时长（秒）
+    pub max_runtime_secs: u64,
+    /// 停在断点上的时间是否计入运行时长
+    pub count_paused: bool,
+}
+
+/// 看门狗超时事件（`watchdog-timeout`）
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchdogTimeoutEvent {
+    pub instance_id: String,
+    pub max_runtime_secs: u64,
+    /// 超时时已计入的运行时长（秒）
+    pub elapsed_secs: u64,
+}
+
+/// 点击/滑动轨迹事件（以 `MXU.Action.Trace` 回调转发）
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionTraceEvent {
+    pub instance_id: String,
+    /// 控制器动作类型（click / long_press / swipe 等）
+    pub action: String,
+    /// 起点（点击类动作即点击点），设备坐标
+    pub begin: (i32, i32),
+    /// 终点（仅滑动类动作），设备坐标
+    pub end: Option<(i32, i32)>,
+    /// 所属 pipeline 节点（手动点击等非任务动作为空）
+    pub node: Option<String>,
+    /// 当前截图尺寸，用于换算到预览坐标
+    pub resolution: Option<(u32, u32)>,
+}
+
+/// 标注截图上画出的一次识别结果
+#[derive(Debug, Clone, Serialize)]
+pub struct AnnotatedRecognition {
+    /// 节点名
+    pub node: String,
+    /// 是否命中
+    pub hit: bool,
+    /// 识别框 [x, y, w, h]（未命中时为分数最高的候选框，没有候选时为 None）
+    pub rect: Option<[i32; 4]>,
+    /// 匹配分数
+    pub score: Option<f64>,
+}
+
+/// 悬浮窗画面预览的一帧（`maa-overlay-frame` 事件）
+#[derive(Debug, Clone, Serialize)]
+pub struct OverlayPreviewFrame {
+    pub instance_id: String,
+    /// 缩略图（PNG data URL）
+    pub image: String,
+    /// 原始截图尺寸，识别框按此坐标系换算到缩略图
+    pub width: u32,
+    pub height: u32,
+    /// 当前一轮的识别结果
+    pub recognitions: Vec<AnnotatedRecognition>,
+}
+
+/// 带标注截图的保存结果
+#[derive(Debug, Clone, Serialize)]
+pub struct AnnotatedScreenshotResult {
+    pub path: String,
+    pub width: u32,
+    pub height: u32,
+    /// 画上的识别结果（为空时保存的是原图）
+    pub recognitions: Vec<AnnotatedRecognition>,
+}
+
+/// 单个识别节点的命中率统计
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoNodeStats {
+    /// 节点名
+    pub node: String,
+    /// 识别次数
+    pub attempts: u64,
+    /// 命中次数
+    pub hits: u64,
+    /// 带匹配分数的识别次数（ColorMatch 等无分数的算法不计入）
+    pub scored: u64,
+    /// 平均匹配分数（取每次识别的最高分，无分数时为 None）
+    pub avg_score: Option<f64>,
+    /// 命中时的平均匹配分数
+    pub avg_hit_score: Option<f64>,
+    /// 最低/最高匹配分数
+    pub min_score: Option<f64>,
+    pub max_score: Option<f64>,
+    /// 分数分布：将 [0, 1] 等分为 10 段，依次为各段的识别次数
+    pub score_histogram: Vec<u64>,
+}
+
+/// 单个 pipeline 节点的执行耗时统计（耗时单位均为毫秒）
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeTimingStats {
+    /// 节点名
+    pub node: String,
+    /// 节点执行次数（命中后执行的次数）
+    pub runs: u64,
+    /// 节点累计/平均/最长耗时
+    pub total_ms: f64,
+    pub avg_ms: f64,
+    pub max_ms: f64,
+    /// 识别次数（含作为 next 候选未命中的识别）与累计/平均耗时
+    pub recognitions: u64,
+    pub recognition_total_ms: f64,
+    pub recognition_avg_ms: f64,
+    /// 动作执行次数与累计/平均耗时
+    pub actions: u64,
+    pub action_total_ms: f64,
+    pub action_avg_ms: f64,
+}
+
+/// 节点引用的模板图预览
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplatePreview {
+    /// pipeline 中声明的模板路径（相对 image 目录）
+    pub template: String,
+    /// 实际读取的图片文件（模板为目录时为目录下的各图片）
+    pub path: String,
+    /// 图片内容（base64）
+    pub base64: String,
+}
+
+/// 运行录像导出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipFormat {
+    #[default]
+    Gif,
+    /// 需要 PATH 中存在 ffmpeg，否则回退为 GIF
+    Mp4,
+}
+
+/// 运行录像导出结果
+#[derive(Debug, Clone, Serialize)]
+pub struct ClipExportResult {
+    /// 导出的文件路径
+    pub path: String,
+    /// 实际导出的格式（ffmpeg 不可用时 mp4 会回退为 gif）
+    pub format: ClipFormat,
+    /// 导出的帧数（内容未变化的连续截图合并为一帧）
+    pub frames: u32,
+    /// 超出录制上限而丢弃的最旧帧数
+    pub dropped_frames: u32,
+    /// 录像时长（毫秒）
+    pub duration_ms: u64,
+}
+
+/// 生成任务报告的选项
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaskReportOptions {
+    /// 报告标题（如实例名）
+    #[serde(default)]
+    pub title: Option<String>,
+    /// selectedTaskId → 显示名称
+    #[serde(default)]
+    pub task_names: HashMap<String, String>,
+    #[serde(default)]
+    pub screenshots: TaskReportScreenshots,
+}
+
+/// Agent 子进程资源限制（对应 mxu.json 中的 settings.agentResourceLimits，仅 Windows 生效）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentResourceLimits {
+    /// 单个 agent 进程的内存上限（MB），0 表示不限制
+    #[serde(default)]
+    pub memory_limit_mb: u64,
+    /// 所有 agent 合计的 CPU 配额（占整机 CPU 的百分比，1-100），0 表示不限制
+    #[serde(default)]
+    pub cpu_rate_percent: u32,
+}
+
+/// 进程资源占用（资源监视面板、性能问题反馈用）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub name: String,
+    /// 进程角色："mxu"（本程序）/ "agent"（已登记的 agent 子进程）/ "other"
+    pub role: String,
+    /// agent 所属实例的显示名称
+    pub instance_name: Option<String>,
+    /// CPU 使用率（占整机的百分比，0-100）
+    pub cpu_usage: f32,
+    /// 常驻内存（字节）
+    pub memory_bytes: u64,
+    /// 线程数（平台不支持时为 None）
+    pub thread_count: Option<usize>,
+    /// 运行时长（秒）
+    pub run_time_secs: u64,
+}
+
+/// 设备搜索的 Toolkit 配置（对应 mxu.json 中的 settings.toolkitConfig）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolkitConfig {
+    /// 额外的 adb 搜索目录（也可直接填 adb 可执行文件路径）
+    #[serde(default)]
+    pub extra_adb_dirs: Vec<String>,
+    /// 模拟器安装目录，会在其子目录中查找 adb
+    #[serde(default)]
+    pub emulator_dirs: Vec<String>,
+}
+
+/// 任务队列完成 webhook 设置（对应 mxu.json 中的 settings.completionWebhook）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompletionWebhookSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub url: String,
+}
+
+/// 任务队列完成 webhook 的请求体（schema 字段标识版本，结构变化时递增）
+#[derive(Debug, Clone, Serialize)]
+pub struct CompletionWebhookPayload {
+    pub schema: u32,
+    /// 固定为 "tasks_completed"
+    pub event: &'static str,
+    pub instance_id: String,
+    pub instance_name: Option<String>,
+    /// 整体结果（"Succeeded" / "Failed"）
+    pub status: String,
+    /// 是否有任务失败
+    pub has_error: bool,
+    /// 完成时间（RFC 3339）
+    pub finished_at: String,
+    /// 从第一个任务开始到全部结束的耗时
+    pub duration_ms: u64,
+    pub tasks: Vec<CompletionWebhookTask>,
+}
+
+/// webhook 请求体中的单个任务结果
+#[derive(Debug, Clone, Serialize)]
+pub struct CompletionWebhookTask {
+    pub task_id: i64,
+    /// 前端任务列表中的任务 ID（selectedTaskId），非队列任务为 None
+    pub selected_task_id: Option<String>,
+    pub entry: String,
+    /// "succeeded" / "failed"，未结束时为 None
+    pub status: Option<String>,
+    pub duration_ms: Option<u64>,
+}
+
+/// 截图/识别性能测试的单项耗时统计（毫秒）
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BenchmarkStat {
+    pub rounds: u32,
+    pub failures: u32,
+    pub avg_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+}
+
+/// 单种识别算法的性能测试结果
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkRecognition {
+    pub name: String,
+    /// 单次任务（截图 + 识别）的总耗时
+    pub task: BenchmarkStat,
+    /// 估算的识别耗时（任务平均耗时减去截图平均耗时）
+    pub recognition_avg_ms: f64,
+}
+
+/// 截图与识别性能测试结果
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkResult {
+    pub instance_id: String,
+    /// 测试时的控制器配置（含截图方式）
+    pub controller_config: Option<ControllerConfig>,
+    /// 截图分辨率 (宽, 高)
+    pub resolution: Option<(u32, u32)>,
+    pub screencap: BenchmarkStat,
+    /// 按平均截图耗时折算的帧率
+    pub fps: f64,
+    /// 资源未加载时为空
+    pub recognitions: Vec<BenchmarkRecognition>,
+    /// 测试时生效的截图预处理参数（未开启时为 None），便于对比开启前后的识别结果
+    pub preprocess: Option<ScreenshotPreprocess>,
+    /// 测试结果导出的 JSON 文件路径
+    pub report_path: Option<String>,
+    pub timestamp: String,
+}
+
+/// 已编码的截图缓存
+pub struct CachedImage {
+    /// 帧序号，帧变化时自增
+    pub seq: u64,
+    /// 生成缓存时控制器的截图帧代数，用于判断帧是否变化
+    pub generation: u64,
+    /// 生成缓存时已完成的主动截图序号
+    pub capture_seq: u64,
+    /// base64 编码后的 data URL
+    pub data_url: String,
+}
+
+/// 截图获取结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedImageFrame {
+    /// 帧序号
+    pub seq: u64,
+    /// 调用方传入的帧序号与当前一致时为 true，此时不返回图像数据
+    pub not_modified: bool,
+    /// base64 编码的 PNG data URL（not_modified 时为 None）
+    pub data_url: Option<String>,
+    /// 已完成的最近一次主动截图的序号（尚无时为 0）
+    pub capture_seq: u64,
+    /// 该次截图完成的时间（Unix 毫秒时间戳）
+    pub captured_at: Option<i64>,
+}
+
+/// 任务结束时保留的最后一帧截图
+#[derive(Debug, Clone, Serialize)]
+pub struct LastFrame {
+    /// 结束的任务 ID
+    pub task_id: i64,
+    /// 任务入口
+    pub entry: String,
+    /// 任务终态：succeeded / failed
+    pub status: String,
+    /// 截图时间（Unix 毫秒时间戳）
+    pub captured_at: i64,
+    /// base64 编码的 PNG data URL
+    pub data_url: String,
+}
+
+/// 主动截图的序号记录
+#[derive(Debug, Default)]
+pub struct ScreencapRecord {
+    /// 最近一次发起的截图序号，每次 maa_post_screencap 自增
+    pub posted_seq: u64,
+    /// 已完成（成功或失败）的最大截图序号
+    pub completed_seq: u64,
+    /// completed_seq 对应截图完成的时间（Unix 毫秒时间戳）
+    pub completed_at: Option<i64>,
+}
+
+/// maa_post_screencap 的返回结果
+#[derive(Debug, Clone, Serialize)]
+pub struct ScreencapTicket {
+    /// 控制器截图请求 ID
+    pub job_id: i64,
+    /// 本次截图的序号，可传给 maa_get_cached_image 的 min_capture_seq 等待该帧
+    pub seq: u64,
+    /// 发起截图的时间（Unix 毫秒时间戳）
+    pub requested_at: i64,
+}
+
+/// 分享码中的单个任务
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedTask {
+    /// interface 中的任务名
+    pub name: String,
+    #[serde(default)]
+    pub custom_name: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// 选项 key -> 选项值（与前端 OptionValue 结构一致）
+    #[serde(default)]
+    pub options: HashMap<String, serde_json::Value>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// 分享码承载的任务队列配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharePayload {
+    /// 导出时的项目名，导入时用于提示配置来自其他项目
+    #[serde(default)]
+    pub project: Option<String>,
+    pub tasks: Vec<SharedTask>,
+}
+
+/// 截图区域裁剪结果
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageRoi {
+    /// 实际裁剪的区域 [x, y, w, h]（越界部分已截到图像范围内）
+    pub roi: [i32; 4],
+    /// 请求的区域与实际裁剪区域不一致（发生了越界截断）时为 true
+    pub clamped: bool,
+    /// 原图尺寸
+    pub image_width: u32,
+    pub image_height: u32,
+    /// 裁剪结果，base64 编码的 PNG data URL
+    pub data_url: String,
+}
+
+/// 资源加载记录
+#[derive(Debug, Default)]
+pub struct ResourceLoadRecord {
+    /// 已提交加载的资源目录（按加载顺序，多目录叠加时全部保留）
+    pub paths: Vec<String>,
+    /// 最近一次加载的开始时间
+    pub started_at: Option<Instant>,
+    /// 最近一次加载的耗时（毫秒），收到 Resource.Loading.Succeeded 后写入
+    pub duration_ms: Option<u64>,
+    /// 最近一次加载中尚未结束的资源包（res_id -> 资源目录）
+    pub pending_jobs: HashMap<i64, String>,
+    /// 最近一次加载提交的资源包数
+    pub jobs_total: usize,
+    /// 最近一次加载中已成功的资源包数
+    pub jobs_loaded: usize,
+    /// 最近一次加载中失败的资源包数
+    pub jobs_failed: usize,
+}
+
+/// 资源加载进度事件载荷（maa-resource-load-progress）
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceLoadProgressEvent {
+    pub instance_id: String,
+    /// started / loading / succeeded / failed / completed
+    pub stage: String,
+    /// 当前资源包目录（started / completed 时为 None）
+    pub path: Option<String>,
+    pub loaded: usize,
+    pub failed: usize,
+    pub total: usize,
+}
+
+/// 资源检查问题级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResourceIssueLevel {
+    Error,
+    Warning,
+}
+
+/// 资源静态检查发现的问题
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceIssue {
+    pub level: ResourceIssueLevel,
+    /// 相对资源目录的文件路径
+    pub file: String,
+    /// 问题位置（"行:列" 或 "节点名.字段"）
+    pub location: String,
+    pub message: String,
+}
+
+/// 任务提交前的校验结果（`maa_run_task` 的 validate_only 模式）
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskValidation {
+    pub entry: String,
+    /// 没有 error 级别的问题
+    pub valid: bool,
+    /// 从入口节点可达的节点数
+    pub reachable_nodes: usize,
+    /// 本次 override 涉及的节点
+    pub overridden_nodes: Vec<String>,
+    /// 问题清单（先 error 后 warning）：`file` 为节点来源，仅由 override 新增的节点为
+    /// `pipeline_override`，资源中的节点为空；`location` 为 "节点名.字段"
+    pub issues: Vec<ResourceIssue>,
+}
+
+/// `maa_run_task` 的返回值：提交任务时为任务 ID，validate_only 模式下为校验结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum RunTaskOutcome {
+    Submitted(i64),
+    Validated(TaskValidation),
+}
+
+/// 资源完整性校验所用清单的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResourceManifestSource {
+    /// 随资源包分发的 resource_manifest.json
+    Bundled,
+    /// 之前校验时生成的清单
+    Generated,
+    /// 本次首次生成，没有可比对的内容
+    Created,
+}
+
+/// 单个资源目录的完整性校验结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceIntegrityReport {
+    pub path: String,
+    pub source: ResourceManifestSource,
+    /// 清单中的文件数
+    pub total_files: usize,
+    /// 本次实际计算哈希的文件数（其余文件大小和修改时间未变，沿用上次结果）
+    pub hashed_files: usize,
+    /// 缺失的文件（相对资源目录）
+    pub missing: Vec<String>,
+    /// 大小或哈希与清单不符的文件（相对资源目录）
+    pub modified: Vec<String>,
+    pub elapsed_ms: u64,
+}
+
+/// 已加载资源详情（用于前端展示当前资源版本）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceLoadedInfo {
+    /// 资源是否已加载（通过 MaaResourceLoaded API 查询）
+    pub loaded: bool,
+    /// 已加载的资源目录列表
+    pub paths: Vec<String>,
+    /// interface.json 中声明的版本号
+    pub version: Option<String>,
+    /// 资源 hash
+    pub hash: Option<String>,
+    /// 加载耗时（毫秒）
+    pub load_duration_ms: Option<u64>,
+}
+
+impl InstanceRuntime {
+    /// 创建新实例，记录名称与创建时间
+    pub fn new(name: Option<String>) -> Self {
+        // 实现了 Drop，不能使用结构体更新语法
+        let mut runtime = Self::default();
+        runtime.name = name;
+        runtime.created_at = Some(chrono::Local::now());
+        runtime
+    }
+
+    /// 控制器是否已连接（MaaControllerConnected）
+    pub fn is_connected(&self) -> bool {
+        self.controller.as_ref().is_some_and(|c| c.connected())
+    }
+
+    /// 资源是否已加载完成（MaaResourceLoaded）
+    pub fn is_resource_loaded(&self) -> bool {
+        self.resource.as_ref().is_some_and(|r| r.loaded())
+    }
 }
 
 impl Drop for InstanceRuntime {
@@ -225,6 +1197,29 @@ pub struct LogEntryDto {
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub html: Option<String>,
+    /// 后端分配的单调序号（跨实例唯一，用作日志锚点；推送时前端传入的值会被覆盖）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq: Option<u64>,
+}
+
+/// 跳转到指定日志的事件（主窗口日志面板据此滚动到对应位置）
+#[derive(Debug, Clone, Serialize)]
+pub struct LogJumpEvent {
+    pub instance_id: String,
+    pub seq: u64,
+    /// 前端日志 ID
+    pub log_id: String,
+    pub timestamp: String,
+}
+
+/// 带实例标签的运行日志（多实例合并视图）
+#[derive(Debug, Clone, Serialize)]
+pub struct TaggedLogEntry {
+    pub instance_id: String,
+    /// 实例显示名（未设置时为空，前端回退显示 instance_id）
+    pub instance_name: Option<String>,
+    #[serde(flatten)]
+    pub entry: LogEntryDto,
 }
 
 /// 每个实例的日志缓冲区默认上限
@@ -234,6 +1229,8 @@ const DEFAULT_MAX_LOGS: usize = 2000;
 pub struct LogBuffer {
     logs: HashMap<String, VecDeque<LogEntryDto>>,
     max_per_instance: usize,
+    /// 下一条日志的序号（清空日志不重置，保证单调）
+    next_seq: u64,
 }
 
 impl Default for LogBuffer {
@@ -241,6 +1238,7 @@ impl Default for LogBuffer {
         Self {
             logs: HashMap::new(),
             max_per_instance: DEFAULT_MAX_LOGS,
+            next_seq: 1,
         }
     }
 }
@@ -248,17 +1246,32 @@ impl Default for LogBuffer {
 impl LogBuffer {
     pub fn new(max_per_instance: usize) -> Self {
         Self {
-            logs: HashMap::new(),
             max_per_instance: max_per_instance.max(100),
+            ..Self::default()
         }
     }
 
-    pub fn push(&mut self, instance_id: &str, entry: LogEntryDto) {
+    /// 追加一条日志并分配序号，返回该序号
+    pub fn push(&mut self, instance_id: &str, mut entry: LogEntryDto) -> u64 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        entry.seq = Some(seq);
         let entries = self.logs.entry(instance_id.to_string()).or_default();
         entries.push_back(entry);
         while entries.len() > self.max_per_instance {
             entries.pop_front();
         }
+        seq
+    }
+
+    /// 按序号查找日志，返回所属实例与日志条目（已被容量限制或清空移除时为 None）
+    pub fn find_by_seq(&self, seq: u64) -> Option<(&str, &LogEntryDto)> {
+        self.logs.iter().find_map(|(id, entries)| {
+            entries
+                .iter()
+                .find(|e| e.seq == Some(seq))
+                .map(|e| (id.as_str(), e))
+        })
     }
 
     pub fn get_all(&self) -> &HashMap<String, VecDeque<LogEntryDto>> {
@@ -279,6 +1292,8 @@ impl LogBuffer {
 /// MaaFramework 运行时状态
 #[derive(Default)]
 pub struct MaaState {
+    /// maa_init 是否已完成（同时作为初始化锁，串行化并发的 maa_init / maa_reset）
+    pub initialized: Mutex<bool>,
     pub lib_dir: Mutex<Option<PathBuf>>,
     pub resource_dir: Mutex<Option<PathBuf>>,
     pub instances: Mutex<HashMap<String, InstanceRuntime>>,
@@ -352,6 +1367,121 @@ pub struct TaskConfig {
     /// 对应的前端选中任务 ID（用于后端跟踪 per-task 状态）
     #[serde(default)]
     pub selected_task_id: Option<String>,
+    /// 任务优先级，越大越优先（默认 0）
+    #[serde(default)]
+    pub priority: i32,
+    /// 跳过条件表达式，任务开始时求值为真则跳过（见 `task_condition`）
+    #[serde(default)]
+    pub skip_if: Option<String>,
+}
+
+/// 任务因跳过条件被跳过，或跳过条件有误而照常执行的事件
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskConditionEvent {
+    pub instance_id: String,
+    pub task_id: i64,
+    pub entry: String,
+    pub expression: String,
+    /// 是否已跳过
+    pub skipped: bool,
+    /// 表达式解析或求值失败的原因（此时任务照常执行）
+    pub error: Option<String>,
+}
+
+/// 抢占或失败重试后被重新提交的任务（旧 task_id -> 新 task_id）
+#[derive(Debug, Clone, Serialize)]
+pub struct ResubmittedTask {
+    pub old_task_id: i64,
+    pub new_task_id: i64,
+}
+
+/// 任务失败重试策略
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRetryPolicy {
+    /// 单个任务最多重试的次数
+    pub max_retries: u32,
+    /// 失败后等待多久再重试（毫秒）
+    #[serde(default)]
+    pub interval_ms: u64,
+    /// 重试前是否重连控制器
+    #[serde(default)]
+    pub reconnect: bool,
+    /// 重试前重启的应用（ADB 为包名或 Activity），为空时不重启
+    #[serde(default)]
+    pub restart_app: Option<String>,
+}
+
+/// 任务失败重试事件载荷（maa-task-retry）
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskRetryEvent {
+    pub instance_id: String,
+    /// 失败的任务 ID
+    pub task_id: i64,
+    pub entry: String,
+    /// 第几次重试（从 1 开始）
+    pub attempt: u32,
+    pub max_retries: u32,
+    /// "retrying"（准备重试）/ "resubmitted"（已重新提交）/ "failed"（重试未能进行，任务按失败结束）
+    pub status: String,
+    /// 重新提交的任务（失败任务及其后被中断的任务）
+    pub resubmitted: Vec<ResubmittedTask>,
+    pub error: Option<String>,
+}
+
+/// 任务队列循环执行配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskQueueLoop {
+    /// 执行的总轮数，0 表示无限循环
+    pub rounds: u32,
+    /// 每轮结束后等待多久再开始下一轮（毫秒）
+    #[serde(default)]
+    pub interval_ms: u64,
+}
+
+/// 任务队列一轮执行结束事件载荷（maa-round-completed）
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskRoundCompletedEvent {
+    pub instance_id: String,
+    /// 刚结束的是第几轮（从 1 开始）
+    pub round: u32,
+    /// 总轮数，0 表示无限循环
+    pub max_rounds: u32,
+    /// 本轮是否有任务失败
+    pub has_failed: bool,
+    /// 是否继续执行下一轮（false 表示已是最后一轮）
+    pub continues: bool,
+    /// 距下一轮开始的等待时间（毫秒）
+    pub interval_ms: u64,
+}
+
+/// 日志按任务分段的标记事件载荷（maa-log-section）
+///
+/// 每个任务开始与结束时各发出一次，前端据此把两者之间的日志折叠为一段
+#[derive(Debug, Clone, Serialize)]
+pub struct LogSectionEvent {
+    pub instance_id: String,
+    pub task_id: i64,
+    /// 任务入口
+    pub entry: String,
+    /// 对应的前端任务 ID（通过 MXU 任务列表提交的任务才有）
+    pub selected_task_id: Option<String>,
+    /// 分段边界：start / end
+    pub phase: &'static str,
+    /// 结束时的结果：succeeded / failed，开始时为 None
+    pub status: Option<&'static str>,
+    /// 循环执行时的当前轮次（从 1 开始），未循环时为 0
+    pub round: u32,
+}
+
+/// 高优先级任务抢占事件载荷（maa-task-preempted）
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskPreemptedEvent {
+    pub instance_id: String,
+    /// 抢占执行的高优先级任务 ID
+    pub task_id: i64,
+    pub priority: i32,
+    /// 被中断及排在其后、在高优先级任务完成后恢复执行的任务
+    pub resubmitted: Vec<ResubmittedTask>,
 }
 
 /// 版本检查结果
@@ -365,6 +1495,21 @@ pub struct VersionCheckResult {
     pub is_compatible: bool,
 }
 
+/// 组件版本信息（反馈问题时一次性获取），无法获取的字段为 "unknown"
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionInfo {
+    /// MXU 自身版本
+    pub mxu: String,
+    /// MaaFramework 核心版本（MaaFramework 动态库导出）
+    pub framework: String,
+    /// Agent 客户端版本（随 MaaFramework 一同分发的 MaaAgentClient 动态库）
+    pub agent: String,
+    /// MXU 编译时的提交哈希
+    pub commit: String,
+    /// MXU 编译时间（RFC 3339）
+    pub build_time: String,
+}
+
 /// changes.json 结构
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChangesJson {
@@ -397,6 +1542,40 @@ pub struct DownloadResult {
     pub detected_filename: Option<String>,
 }
 
+/// 资源更新检查结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResourceUpdateInfo {
+    /// 是否有新版本
+    pub has_update: bool,
+    /// 当前资源版本（未传入时取上次资源更新记录的版本）
+    pub current_version: Option<String>,
+    /// 最新版本号
+    pub version_name: String,
+    pub release_note: String,
+    /// 下载地址（需要 CDK，无 CDK 时为空）
+    pub download_url: Option<String>,
+    /// 更新包 SHA-256
+    pub sha256: Option<String>,
+    /// 更新类型：incremental / full
+    pub update_type: Option<String>,
+    pub channel: Option<String>,
+    pub file_size: Option<u64>,
+    /// MirrorChyan 业务错误码（成功时为空）
+    pub error_code: Option<i64>,
+    pub error_message: Option<String>,
+}
+
+/// 资源更新安装结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceUpdateResult {
+    /// 安装后的资源版本
+    pub version_name: String,
+    /// 是否为增量更新（包内含 changes.json）
+    pub incremental: bool,
+    /// 已重新加载资源的实例
+    pub reloaded_instances: Vec<String>,
+}
+
 /// 系统信息结构
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemInfo {
@@ -404,6 +1583,47 @@ pub struct SystemInfo {
     pub os_version: String,
     pub arch: String,
     pub tauri_version: String,
+    /// 程序所在卷的检测结果
+    pub exe_volume: ExeVolumeInfo,
+    /// CPU 指令集检测结果
+    pub cpu: CpuFeatures,
+}
+
+/// CPU 指令集支持情况（MaaFramework 的 OpenCV/ONNX 计算依赖 AVX，老 CPU 上运行会直接崩溃）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CpuFeatures {
+    /// 是否为 x86/x86_64 CPU（其他架构不检测指令集）
+    pub x86: bool,
+    pub sse4_2: bool,
+    pub avx: bool,
+    pub avx2: bool,
+    pub fma: bool,
+    /// 缺少的必需指令集，为空表示满足 MaaFramework 的最低要求
+    pub missing: Vec<String>,
+}
+
+/// 程序所在卷的类型与可写性（用于诊断网络盘/只读盘导致的各类怪问题）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExeVolumeInfo {
+    /// 卷类型：fixed / network / removable / cdrom / ramdisk / unknown
+    pub drive_type: String,
+    /// 程序目录是否可写
+    pub writable: bool,
+    /// 是否位于 OneDrive 等云同步目录
+    pub cloud_synced: bool,
+    /// 需要提示用户的问题：network / removable / readonly / cloud，正常时为 None
+    pub issue: Option<String>,
+}
+
+/// 显卡信息（用于诊断 WebView2 白屏/花屏问题）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GpuAdapterInfo {
+    pub name: String,
+    pub vendor: Option<String>,
+    pub driver_version: Option<String>,
+    pub driver_date: Option<String>,
+    /// 显存大小（MB）
+    pub memory_mb: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -422,9 +1642,41 @@ pub struct GitHubRelease {
     pub assets: Vec<GitHubAsset>,
 }
 
+/// 单个目标的连通性探测结果
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkProbeResult {
+    pub url: String,
+    /// 是否收到 HTTP 响应（任何状态码都算可达）
+    pub reachable: bool,
+    /// 从发出请求到收到响应头的耗时
+    pub latency_ms: Option<u64>,
+    pub status: Option<u16>,
+    pub error: Option<String>,
+}
+
 /// WebView2 目录信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebView2DirInfo {
     pub path: String,
     pub system: bool,
 }
+
+/// 目录路径及其是否存在
+#[derive(Debug, Clone, Serialize)]
+pub struct AppPathEntry {
+    pub path: String,
+    pub exists: bool,
+}
+
+/// 应用关键目录（get_app_paths 一次返回）
+#[derive(Debug, Clone, Serialize)]
+pub struct AppPaths {
+    pub exe_dir: AppPathEntry,
+    pub data_dir: AppPathEntry,
+    pub logs_dir: AppPathEntry,
+    pub cache_dir: AppPathEntry,
+    pub maafw_dir: AppPathEntry,
+    pub cwd: AppPathEntry,
+    /// 使用系统 WebView2 或非 Windows 平台时为 None
+    pub webview2_runtime_dir: Option<AppPathEntry>,
+}
@@ -0,0 +1,399 @@
+//! 未知界面收集
+//!
+//! 开启后按实例统计连续识别失败的轮数：一轮 next 列表全部未命中记为一次，命中即清零。
+//! 连续失败达到阈值时视为遇到了资源没有覆盖的界面，把控制器缓存的截图（原图）和上下文
+//! （实例、任务入口、当前节点与 next 列表）归档到 `unknown_screens` 目录，同一段连续失败只归档一次。
+//! 与已归档截图的差值哈希相似度达到 `DUPLICATE_SIMILARITY` 时视为重复不再保存，
+//! 数量超过 `MAX_ARCHIVED` 时删除最早的截图。用户可一键打包反馈给资源开发者补充识别。
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+use log::{info, warn};
+use serde_json::Value;
+
+use super::frame_dedup::{frame_hash, similarity, FrameHash};
+use super::instance_log::sanitize_id;
+use super::types::{MaaState, UnknownScreenConfig, UnknownScreensInfo};
+use super::utils::get_app_data_dir;
+
+/// 未设置时的默认触发阈值（连续失败轮数）
+const DEFAULT_THRESHOLD: u32 = 5;
+
+/// 触发阈值允许的范围
+const THRESHOLD_RANGE: std::ops::RangeInclusive<u32> = 1..=100;
+
+/// 与已归档截图的相似度达到该值时视为重复
+const DUPLICATE_SIMILARITY: f64 = 0.95;
+
+/// 最多保留的截图数量
+const MAX_ARCHIVED: usize = 200;
+
+/// 压缩包中的说明文件
+const README: &str = "MXU 未知界面截图\n\n\
+任务执行时连续识别失败（next 列表全部未命中）达到设定次数时自动保存的界面，反馈给资源开发者可据此补充识别。\n\n\
+每张截图（.png，原图）附带同名 .json，记录实例、任务入口、卡住的节点及其 next 列表、连续失败次数和截图时间。\n";
+
+/// 各实例的收集配置（未设置的不收集）
+static CONFIGS: LazyLock<Mutex<HashMap<String, UnknownScreenConfig>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 各实例当前的连续失败记录
+static STREAKS: LazyLock<Mutex<HashMap<String, FailureStreak>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 已归档截图的文件名（不含扩展名，按时间先后）与哈希，首次归档时从目录加载
+static ARCHIVED: LazyLock<Mutex<Option<Vec<(String, Option<FrameHash>)>>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+#[derive(Default)]
+struct FailureStreak {
+    /// 当前任务入口
+    entry: String,
+    /// 当前一轮识别所属的节点与 next 列表
+    node: String,
+    next_list: Vec<String>,
+    /// 当前一轮是否已开始、是否有命中
+    round_open: bool,
+    round_hit: bool,
+    /// 连续全部未命中的轮数
+    misses: u32,
+    /// 本段连续失败是否已归档
+    archived: bool,
+}
+
+impl FailureStreak {
+    /// 结束当前一轮，返回是否达到归档条件
+    fn close_round(&mut self, threshold: u32) -> bool {
+        if !self.round_open {
+            return false;
+        }
+        self.round_open = false;
+        if self.round_hit {
+            self.misses = 0;
+            self.archived = false;
+            return false;
+        }
+        self.misses += 1;
+        self.misses >= threshold && !self.archived
+    }
+}
+
+/// 未知界面归档目录
+fn unknown_screens_dir() -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?.join("unknown_screens"))
+}
+
+fn unknown_screen_config(instance_id: &str) -> Option<UnknownScreenConfig> {
+    CONFIGS
+        .lock()
+        .ok()
+        .and_then(|configs| configs.get(instance_id).copied())
+}
+
+/// 解码 PNG 并计算差值哈希
+fn png_hash(png: &[u8]) -> Option<FrameHash> {
+    let image = tauri::image::Image::from_bytes(png).ok()?;
+    frame_hash(image.rgba(), image.width(), image.height())
+}
+
+/// 读取目录中已有的截图（按文件名排序，文件名以时间开头）
+fn load_archived(dir: &Path) -> Vec<(String, Option<FrameHash>)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut stems: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "png"))
+        .filter_map(|path| path.file_stem()?.to_str().map(str::to_string))
+        .collect();
+    stems.sort();
+    stems
+        .into_iter()
+        .map(|stem| {
+            let hash = std::fs::read(dir.join(format!("{}.png", stem)))
+                .ok()
+                .and_then(|png| png_hash(&png));
+            (stem, hash)
+        })
+        .collect()
+}
+
+/// 处理 tasker sink 与 context sink 中的回调，连续识别失败达到阈值时归档当前截图
+///
+/// 任务开始时重置记录；`Node.NextList.Starting` 开始新的一轮（同时结束上一轮），
+/// `Node.Recognition.Succeeded` 标记本轮命中，`Node.NextList.Failed` 结束本轮
+pub fn record_callback(state: &MaaState, instance_id: &str, message: &str, details: &str) {
+    let Some(config) = unknown_screen_config(instance_id) else {
+        return;
+    };
+    if !matches!(
+        message,
+        "Tasker.Task.Starting"
+            | "Node.NextList.Starting"
+            | "Node.NextList.Failed"
+            | "Node.Recognition.Succeeded"
+    ) {
+        return;
+    }
+    let Ok(details) = serde_json::from_str::<Value>(details) else {
+        return;
+    };
+
+    let context = {
+        let Ok(mut streaks) = STREAKS.lock() else {
+            return;
+        };
+        let streak = streaks.entry(instance_id.to_string()).or_default();
+        let reached = match message {
+            "Tasker.Task.Starting" => {
+                *streak = FailureStreak {
+                    entry: details
+                        .get("entry")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                    ..Default::default()
+                };
+                false
+            }
+            "Node.Recognition.Succeeded" => {
+                streak.round_hit = true;
+                false
+            }
+            _ => streak.close_round(config.threshold),
+        };
+        // 控制器缓存的仍是上一轮的截图，上下文需在记录新一轮节点前取出
+        let context = reached.then(|| {
+            streak.archived = true;
+            serde_json::json!({
+                "entry": streak.entry,
+                "node": streak.node,
+                "next_list": streak.next_list,
+                "consecutive_failures": streak.misses,
+            })
+        });
+        if message == "Node.NextList.Starting" {
+            streak.node = details
+                .get("name")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            streak.next_list = details
+                .get("list")
+                .and_then(|v| v.as_array())
+                .map(|list| {
+                    list.iter()
+                        .filter_map(|item| item.as_str().or_else(|| item.get("name")?.as_str()))
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default();
+            streak.round_open = true;
+            streak.round_hit = false;
+        }
+        context
+    };
+
+    if let Some(context) = context {
+        if let Err(e) = archive_screen(state, instance_id, context) {
+            warn!("[unknown_screens] {}: archive failed: {}", instance_id, e);
+        }
+    }
+}
+
+/// 归档实例当前的缓存截图及上下文，与已归档截图重复时跳过
+fn archive_screen(state: &MaaState, instance_id: &str, mut context: Value) -> Result<(), String> {
+    let (controller, instance_name) = {
+        let instances = state.instances.lock().map_err(|e| e.to_string())?;
+        let instance = instances.get(instance_id).ok_or("Instance not found")?;
+        (instance.controller.clone(), instance.name.clone())
+    };
+    let png = controller
+        .and_then(|c| c.cached_image().ok())
+        .and_then(|buf| buf.to_vec())
+        .filter(|data| !data.is_empty())
+        .ok_or("控制器未连接或暂无截图")?;
+    let hash = png_hash(&png);
+
+    let dir = unknown_screens_dir()?;
+    let mut archived = ARCHIVED.lock().map_err(|e| e.to_string())?;
+    let archived = archived.get_or_insert_with(|| load_archived(&dir));
+    if let Some(hash) = &hash {
+        if let Some((stem, _)) = archived
+            .iter()
+            .find(|(_, h)| h.is_some_and(|h| similarity(&h, hash) >= DUPLICATE_SIMILARITY))
+        {
+            info!(
+                "[unknown_screens] {}: duplicate of {}, skipped",
+                instance_id, stem
+            );
+            return Ok(());
+        }
+    }
+
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("创建目录失败 [{}]: {}", dir.display(), e))?;
+    let now = chrono::Local::now();
+    let stem = format!(
+        "{}_{}",
+        now.format("%Y%m%d-%H%M%S-%3f"),
+        sanitize_id(instance_id)
+    );
+    if let Value::Object(map) = &mut context {
+        map.insert("instance_id".to_string(), instance_id.into());
+        map.insert("instance_name".to_string(), instance_name.into());
+        map.insert("captured_at".to_string(), now.to_rfc3339().into());
+    }
+    let png_path = dir.join(format!("{}.png", stem));
+    std::fs::write(&png_path, &png)
+        .map_err(|e| format!("保存截图失败 [{}]: {}", png_path.display(), e))?;
+    let json = serde_json::to_vec_pretty(&context).map_err(|e| format!("序列化失败: {}", e))?;
+    let json_path = dir.join(format!("{}.json", stem));
+    std::fs::write(&json_path, json)
+        .map_err(|e| format!("保存上下文失败 [{}]: {}", json_path.display(), e))?;
+    info!("[unknown_screens] {}: archived {}", instance_id, stem);
+    archived.push((stem, hash));
+
+    // 超出上限时删除最早的截图
+    let excess = archived.len().saturating_sub(MAX_ARCHIVED);
+    for (stem, _) in archived.drain(..excess) {
+        for ext in ["png", "json"] {
+            let _ = std::fs::remove_file(dir.join(format!("{}.{}", stem, ext)));
+        }
+    }
+    Ok(())
+}
+
+/// 移除实例的未知界面收集设置与连续失败记录（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut entries) = CONFIGS.lock() {
+        entries.remove(instance_id);
+    }
+    if let Ok(mut entries) = STREAKS.lock() {
+        entries.remove(instance_id);
+    }
+}
+
+/// 设置实例的未知界面收集（立即生效），关闭时清除连续失败记录
+#[tauri::command]
+pub fn set_unknown_screen_capture(
+    instance_id: String,
+    enabled: bool,
+    threshold: Option<u32>,
+) -> Result<(), String> {
+    let mut configs = CONFIGS.lock().map_err(|e| e.to_string())?;
+    if !enabled {
+        info!("set_unknown_screen_capture: {} disabled", instance_id);
+        configs.remove(&instance_id);
+        if let Ok(mut streaks) = STREAKS.lock() {
+            streaks.remove(&instance_id);
+        }
+        return Ok(());
+    }
+    let threshold = threshold.unwrap_or(DEFAULT_THRESHOLD);
+    if !THRESHOLD_RANGE.contains(&threshold) {
+        return Err(format!(
+            "连续失败次数需在 {} 到 {} 之间",
+            THRESHOLD_RANGE.start(),
+            THRESHOLD_RANGE.end()
+        ));
+    }
+    let config = UnknownScreenConfig { threshold };
+    info!(
+        "set_unknown_screen_capture: {} -> {:?}",
+        instance_id, config
+    );
+    configs.insert(instance_id, config);
+    Ok(())
+}
+
+/// 获取实例的未知界面收集配置，未开启时返回 None
+#[tauri::command]
+pub fn get_unknown_screen_capture(instance_id: String) -> Option<UnknownScreenConfig> {
+    unknown_screen_config(&instance_id)
+}
+
+/// 获取未知界面归档目录与已归档的截图数量
+#[tauri::command]
+pub fn get_unknown_screens_info() -> Result<UnknownScreensInfo, String> {
+    let dir = unknown_screens_dir()?;
+    let count = std::fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "png"))
+                .count()
+        })
+        .unwrap_or(0);
+    Ok(UnknownScreensInfo {
+        dir: dir.to_string_lossy().to_string(),
+        count,
+    })
+}
+
+fn export_unknown_screens_blocking(project_name: Option<String>) -> Result<String, String> {
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    let dir = unknown_screens_dir()?;
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|ext| ext == "png" || ext == "json")
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    if files.is_empty() {
+        return Err("还没有收集到未知界面".to_string());
+    }
+    files.sort();
+
+    let exports_dir = get_app_data_dir()?.join("debug_exports");
+    std::fs::create_dir_all(&exports_dir)
+        .map_err(|e| format!("创建导出目录失败 [{}]: {}", exports_dir.display(), e))?;
+    let name = project_name.unwrap_or_else(|| "mxu".to_string());
+    let path = exports_dir.join(format!(
+        "{}-unknown-screens-{}.zip",
+        name,
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    let file = std::fs::File::create(&path)
+        .map_err(|e| format!("创建压缩包失败 [{}]: {}", path.display(), e))?;
+    let mut zip = ZipWriter::new(std::io::BufWriter::new(file));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    zip.start_file("README.txt", options)
+        .and_then(|_| zip.write_all(README.as_bytes()).map_err(Into::into))
+        .map_err(|e| format!("写入 README.txt 失败: {}", e))?;
+    for file in &files {
+        let Some(name) = file.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let data = std::fs::read(file).map_err(|e| format!("读取 {} 失败: {}", name, e))?;
+        zip.start_file(name, options)
+            .and_then(|_| zip.write_all(&data).map_err(Into::into))
+            .map_err(|e| format!("写入 {} 失败: {}", name, e))?;
+    }
+    zip.finish().map_err(|e| format!("完成压缩包失败: {}", e))?;
+
+    info!("Unknown screens exported: {}", path.display());
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// 打包已收集的未知界面（zip，保存到 debug_exports），返回压缩包路径
+#[tauri::command]
+pub async fn export_unknown_screens(project_name: Option<String>) -> Result<String, String> {
+    tokio::task::spawn_blocking(move || export_unknown_screens_blocking(project_name))
+        .await
+        .map_err(|e| format!("导出任务执行失败: {}", e))?
+}
@@ -3,14 +3,46 @@
 //! 提供解压、增量/全量更新、文件移动等功能
 
 use log::{info, warn};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
 
+use super::error::{MxuError, MxuErrorCode};
 use super::file_ops::get_exe_dir;
+use super::resource_integrity::invalidate_generated_manifests;
 use super::types::ChangesJson;
 
+/// move_to_old_folder 会先清空再重建 old 目录，并发调用时需串行
+static OLD_FOLDER_LOCK: Mutex<()> = Mutex::new(());
+
+/// 正在执行的更新安装步骤数量（解压/替换文件期间退出会留下损坏的程序目录）
+static ACTIVE_UPDATE_STEPS: AtomicUsize = AtomicUsize::new(0);
+
+/// 更新安装步骤守卫，函数任意返回路径上都会递减计数
+struct UpdateStepGuard;
+
+impl UpdateStepGuard {
+    fn new() -> Self {
+        ACTIVE_UPDATE_STEPS.fetch_add(1, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Drop for UpdateStepGuard {
+    fn drop(&mut self) {
+        ACTIVE_UPDATE_STEPS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 是否正在解压或安装更新
+pub fn is_update_in_progress() -> bool {
+    ACTIVE_UPDATE_STEPS.load(Ordering::SeqCst) > 0
+}
+
 /// 解压压缩文件到指定目录，支持 zip 和 tar.gz/tgz 格式
 #[tauri::command]
 pub fn extract_zip(zip_path: String, dest_dir: String) -> Result<(), String> {
     info!("extract_zip called: {} -> {}", zip_path, dest_dir);
+    let _update_guard = UpdateStepGuard::new();
 
     let path_lower = zip_path.to_lowercase();
 
@@ -152,6 +184,7 @@ pub fn move_to_old_folder(source: &std::path::Path) -> Result<(), String> {
     if !source.exists() {
         return Ok(());
     }
+    let _old_folder_guard = OLD_FOLDER_LOCK.lock().unwrap_or_else(|e| e.into_inner());
 
     // 统一移动到 exe_dir/cache/old
     let exe_dir = get_exe_dir()?;
@@ -239,13 +272,19 @@ fn normalize_relative_path(raw: &str) -> &str {
 
 /// 应用增量更新：将 deleted 中的文件移动到 old 文件夹，然后复制新文件
 /// 即使移动旧文件失败，也会继续复制新文件，确保程序可用
+///
+/// 新文件用 concurrency 个线程并行复制（默认 CPU 核数），目标在机械硬盘上时退化为串行以免磁头抖动
 #[tauri::command]
 pub fn apply_incremental_update(
     extract_dir: String,
     target_dir: String,
     deleted_files: Vec<String>,
-) -> Result<(), String> {
+    concurrency: Option<usize>,
+) -> Result<(), MxuError> {
     info!("apply_incremental_update called");
+    let _update_guard = UpdateStepGuard::new();
+    // 更新会替换资源文件，之前生成的资源校验清单不再有效
+    invalidate_generated_manifests();
     info!("extract_dir: {}, target_dir: {}", extract_dir, target_dir);
     info!("deleted_files: {:?}", deleted_files);
 
@@ -277,7 +316,18 @@ pub fn apply_incremental_update(
     }
 
     // 2. 复制新包内容到目标目录（覆盖）- 这一步必须执行
-    copy_dir_contents(&extract_dir, &target_dir, Some(&["changes.json"]))?;
+    let concurrency = match concurrency.filter(|n| *n > 0) {
+        Some(n) => n,
+        None => std::thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let concurrency = if concurrency > 1 && is_rotational_disk(target_path) {
+        info!("目标位于机械硬盘，增量更新改为串行复制");
+        1
+    } else {
+        concurrency
+    };
+    copy_dir_contents_parallel(&extract_dir, &target_dir, &["changes.json"], concurrency)
+        .map_err(|e| MxuError::new(MxuErrorCode::UpdateFailed, e).with_detail(&target_dir))?;
 
     if !move_errors.is_empty() {
         info!(
@@ -290,20 +340,163 @@ pub fn apply_incremental_update(
     Ok(())
 }
 
+/// 全量更新默认保护的用户数据路径（相对程序目录）
+const DEFAULT_PROTECTED_PATHS: &[&str] = &["config", "presets"];
+
+/// 用户数据快照保存目录（相对程序目录）及保留份数
+const USER_DATA_BACKUP_DIR: &str = "cache/user_data_backup";
+const USER_DATA_BACKUP_KEEP: usize = 3;
+
+/// 解析保护清单为相对路径列表（统一分隔符，忽略空项和包含 `..` 的项）
+fn parse_protected_paths(paths: Option<Vec<String>>) -> Vec<std::path::PathBuf> {
+    let raw: Vec<String> = match paths {
+        Some(p) => p,
+        None => DEFAULT_PROTECTED_PATHS
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    };
+    raw.iter()
+        .map(|p| std::path::PathBuf::from(normalize_relative_path(p)))
+        .filter(|p| {
+            !p.as_os_str().is_empty()
+                && !p
+                    .components()
+                    .any(|c| matches!(c, std::path::Component::ParentDir))
+        })
+        .collect()
+}
+
+/// 路径本身或其祖先在保护清单中
+fn is_protected(rel: &std::path::Path, protected: &[std::path::PathBuf]) -> bool {
+    protected.iter().any(|p| rel.starts_with(p))
+}
+
+/// 路径下包含受保护的子路径（不能整体替换，需逐项复制）
+fn contains_protected(rel: &std::path::Path, protected: &[std::path::PathBuf]) -> bool {
+    protected.iter().any(|p| p.starts_with(rel) && p != rel)
+}
+
+/// 递归复制目录，跳过受保护的子路径（不移动整个目录到 old，仅覆盖新包中的文件）
+fn copy_dir_skip_protected(
+    src: &std::path::Path,
+    dst: &std::path::Path,
+    rel: &std::path::Path,
+    protected: &[std::path::PathBuf],
+) -> Result<(), String> {
+    std::fs::create_dir_all(dst).map_err(|e| format!("无法创建目录 [{}]: {}", dst.display(), e))?;
+
+    for entry in
+        std::fs::read_dir(src).map_err(|e| format!("无法读取目录 [{}]: {}", src.display(), e))?
+    {
+        let entry = entry.map_err(|e| format!("无法读取目录条目: {}", e))?;
+        let child_rel = rel.join(entry.file_name());
+        if is_protected(&child_rel, protected) {
+            info!("跳过受保护路径: {}", child_rel.display());
+            continue;
+        }
+        let src_item = entry.path();
+        let dst_item = dst.join(entry.file_name());
+
+        if src_item.is_dir() {
+            copy_dir_skip_protected(&src_item, &dst_item, &child_rel, protected)?;
+        } else {
+            copy_file_with_move_old(&src_item, &dst_item)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// 更新前为受保护的用户数据做快照备份，仅保留最近几份
+fn snapshot_user_data(
+    target_path: &std::path::Path,
+    protected: &[std::path::PathBuf],
+) -> Result<Option<std::path::PathBuf>, String> {
+    let existing: Vec<_> = protected
+        .iter()
+        .filter(|p| !p.starts_with("cache") && target_path.join(p).exists())
+        .collect();
+    if existing.is_empty() {
+        return Ok(None);
+    }
+
+    let backup_root = target_path.join(USER_DATA_BACKUP_DIR);
+    let snapshot_dir = backup_root.join(chrono::Local::now().format("%Y%m%d_%H%M%S").to_string());
+    for rel in existing {
+        let src = target_path.join(rel);
+        let dst = snapshot_dir.join(rel);
+        if src.is_dir() {
+            copy_dir_recursive(&src, &dst)?;
+        } else {
+            if let Some(parent) = dst.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| format!("无法创建目录 [{}]: {}", parent.display(), e))?;
+            }
+            std::fs::copy(&src, &dst)
+                .map_err(|e| format!("备份文件失败 [{}]: {}", src.display(), e))?;
+        }
+    }
+
+    // 清理过旧的快照
+    if let Ok(entries) = std::fs::read_dir(&backup_root) {
+        let mut snapshots: Vec<_> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .collect();
+        snapshots.sort();
+        let excess = snapshots.len().saturating_sub(USER_DATA_BACKUP_KEEP);
+        for old in snapshots.into_iter().take(excess) {
+            if let Err(e) = std::fs::remove_dir_all(&old) {
+                warn!("清理旧的用户数据快照失败: {} -> {}", old.display(), e);
+            }
+        }
+    }
+
+    Ok(Some(snapshot_dir))
+}
+
 /// 应用全量更新：将与新包根目录同名的文件夹/文件移动到 old 文件夹，然后复制新文件
 /// 即使移动旧文件失败，也会继续复制新文件，确保程序可用
+///
+/// protected_paths 为需要保护的用户数据路径（相对程序目录，默认 config 和 presets），
+/// 这些路径更新前会先做快照备份，更新时不会被移动、删除或覆盖
 #[tauri::command]
-pub fn apply_full_update(extract_dir: String, target_dir: String) -> Result<(), String> {
+pub fn apply_full_update(
+    extract_dir: String,
+    target_dir: String,
+    protected_paths: Option<Vec<String>>,
+) -> Result<(), MxuError> {
     info!("apply_full_update called");
+    let _update_guard = UpdateStepGuard::new();
+    // 更新会替换资源文件，之前生成的资源校验清单不再有效
+    invalidate_generated_manifests();
     info!("extract_dir: {}, target_dir: {}", extract_dir, target_dir);
 
     let extract_path = std::path::Path::new(&extract_dir);
     let target_path = std::path::Path::new(&target_dir);
     let mut move_errors: Vec<String> = Vec::new();
 
+    let protected = parse_protected_paths(protected_paths);
+    info!("protected paths: {:?}", protected);
+
+    // 0. 为用户数据做快照（失败不阻断更新，但要留下记录）
+    match snapshot_user_data(target_path, &protected) {
+        Ok(Some(dir)) => info!("用户数据已快照到: {}", dir.display()),
+        Ok(None) => {}
+        Err(e) => warn!("用户数据快照失败（将继续更新）: {}", e),
+    }
+
     // 1. 获取解压目录中的根级条目
     let entries: Vec<_> = std::fs::read_dir(extract_path)
-        .map_err(|e| format!("无法读取解压目录: {}", e))?
+        .map_err(|e| {
+            MxuError::new(
+                MxuErrorCode::UpdateFailed,
+                format!("无法读取解压目录: {}", e),
+            )
+            .with_detail(&extract_dir)
+        })?
         .filter_map(|e| e.ok())
         .collect();
 
@@ -311,12 +504,18 @@ pub fn apply_full_update(extract_dir: String, target_dir: String) -> Result<(),
     for entry in &entries {
         let name = entry.file_name();
         let target_item = target_path.join(&name);
+        let rel = std::path::Path::new(&name);
 
         // 跳过 changes.json
         if name == "changes.json" {
             continue;
         }
 
+        // 受保护路径及包含受保护子路径的目录都不整体移走
+        if is_protected(rel, &protected) || contains_protected(rel, &protected) {
+            continue;
+        }
+
         if target_item.exists() {
             if let Err(e) = move_to_old_folder(&target_item) {
                 warn!("移动旧文件失败（将继续更新）: {}", e);
@@ -335,8 +534,16 @@ pub fn apply_full_update(extract_dir: String, target_dir: String) -> Result<(),
         }
     }
 
-    // 3. 复制新包内容到目标目录 - 这一步必须执行
-    copy_dir_contents(&extract_dir, &target_dir, Some(&["changes.json"]))?;
+    // 3. 复制新包内容到目标目录 - 这一步必须执行（跳过受保护路径和 changes.json）
+    let mut skip_paths = protected;
+    skip_paths.push(std::path::PathBuf::from("changes.json"));
+    copy_dir_skip_protected(
+        extract_path,
+        target_path,
+        std::path::Path::new(""),
+        &skip_paths,
+    )
+    .map_err(|e| MxuError::new(MxuErrorCode::UpdateFailed, e).with_detail(&target_dir))?;
 
     if !move_errors.is_empty() {
         info!(
@@ -411,6 +618,211 @@ fn copy_dir_contents(src: &str, dst: &str, skip_files: Option<&[&str]>) -> Resul
     Ok(())
 }
 
+/// 并行复制目录内容（不包含根目录本身）
+///
+/// 先串行创建所有目标目录，避免多个线程同时创建同一目录的竞争，再由工作线程领取文件复制。
+/// 任一文件失败时其余线程停止领取新文件，返回首个错误（附带失败总数）
+fn copy_dir_contents_parallel(
+    src: &str,
+    dst: &str,
+    skip_files: &[&str],
+    concurrency: usize,
+) -> Result<(), String> {
+    let src_path = std::path::Path::new(src);
+    let dst_path = std::path::Path::new(dst);
+
+    let mut files: Vec<(std::path::PathBuf, std::path::PathBuf)> = Vec::new();
+    let mut dirs = vec![(src_path.to_path_buf(), dst_path.to_path_buf(), true)];
+    while let Some((src_dir, dst_dir, is_root)) = dirs.pop() {
+        std::fs::create_dir_all(&dst_dir)
+            .map_err(|e| format!("无法创建目录 [{}]: {}", dst_dir.display(), e))?;
+        for entry in std::fs::read_dir(&src_dir)
+            .map_err(|e| format!("无法读取目录 [{}]: {}", src_dir.display(), e))?
+        {
+            let entry = entry.map_err(|e| format!("无法读取目录条目: {}", e))?;
+            let file_name = entry.file_name();
+            if is_root && skip_files.iter().any(|s| *s == file_name.to_string_lossy()) {
+                continue;
+            }
+            let src_item = entry.path();
+            let dst_item = dst_dir.join(&file_name);
+            if src_item.is_dir() {
+                dirs.push((src_item, dst_item, false));
+            } else {
+                files.push((src_item, dst_item));
+            }
+        }
+    }
+
+    let workers = concurrency.clamp(1, files.len().max(1));
+    info!("复制 {} 个文件，并发数 {}", files.len(), workers);
+    if workers == 1 {
+        for (src_item, dst_item) in &files {
+            copy_file_with_move_old(src_item, dst_item)?;
+        }
+        return Ok(());
+    }
+
+    let next = AtomicUsize::new(0);
+    let failed = AtomicBool::new(false);
+    let errors: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                if failed.load(Ordering::Relaxed) {
+                    break;
+                }
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some((src_item, dst_item)) = files.get(index) else {
+                    break;
+                };
+                if let Err(e) = copy_file_with_move_old(src_item, dst_item) {
+                    warn!("{}", e);
+                    failed.store(true, Ordering::Relaxed);
+                    if let Ok(mut errors) = errors.lock() {
+                        errors.push(e);
+                    }
+                }
+            });
+        }
+    });
+
+    let errors = errors.into_inner().unwrap_or_else(|e| e.into_inner());
+    match errors.len() {
+        0 => Ok(()),
+        1 => Err(errors.into_iter().next().unwrap_or_default()),
+        n => Err(format!("{}（共 {} 个文件复制失败）", errors[0], n)),
+    }
+}
+
+/// 路径所在磁盘是否为机械硬盘（有寻道开销），无法判断时视为固态
+#[cfg(windows)]
+fn is_rotational_disk(path: &std::path::Path) -> bool {
+    use std::ffi::c_void;
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::{Component, Prefix};
+
+    const IOCTL_STORAGE_QUERY_PROPERTY: u32 = 0x002D_1400;
+    const STORAGE_DEVICE_SEEK_PENALTY_PROPERTY: u32 = 7;
+    const FILE_SHARE_READ_WRITE: u32 = 0x1 | 0x2;
+    const OPEN_EXISTING: u32 = 3;
+
+    #[repr(C)]
+    struct StoragePropertyQuery {
+        property_id: u32,
+        query_type: u32,
+        additional_parameters: [u8; 1],
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct DeviceSeekPenaltyDescriptor {
+        version: u32,
+        size: u32,
+        incurs_seek_penalty: u8,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn CreateFileW(
+            name: *const u16,
+            access: u32,
+            share: u32,
+            security: *const c_void,
+            disposition: u32,
+            flags: u32,
+            template: *mut c_void,
+        ) -> *mut c_void;
+        fn DeviceIoControl(
+            device: *mut c_void,
+            code: u32,
+            in_buffer: *const c_void,
+            in_size: u32,
+            out_buffer: *mut c_void,
+            out_size: u32,
+            returned: *mut u32,
+            overlapped: *mut c_void,
+        ) -> i32;
+        fn CloseHandle(handle: *mut c_void) -> i32;
+    }
+
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let letter = match path.components().next() {
+        Some(Component::Prefix(prefix)) => match prefix.kind() {
+            Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => letter as char,
+            _ => return false,
+        },
+        _ => return false,
+    };
+    let volume: Vec<u16> = std::ffi::OsStr::new(&format!("\\\\.\\{}:", letter))
+        .encode_wide()
+        .chain(Some(0))
+        .collect();
+
+    unsafe {
+        let handle = CreateFileW(
+            volume.as_ptr(),
+            0,
+            FILE_SHARE_READ_WRITE,
+            std::ptr::null(),
+            OPEN_EXISTING,
+            0,
+            std::ptr::null_mut(),
+        );
+        // INVALID_HANDLE_VALUE
+        if handle as isize == -1 {
+            return false;
+        }
+        let query = StoragePropertyQuery {
+            property_id: STORAGE_DEVICE_SEEK_PENALTY_PROPERTY,
+            query_type: 0,
+            additional_parameters: [0],
+        };
+        let mut descriptor = DeviceSeekPenaltyDescriptor::default();
+        let mut returned = 0u32;
+        let ok = DeviceIoControl(
+            handle,
+            IOCTL_STORAGE_QUERY_PROPERTY,
+            &query as *const _ as *const c_void,
+            std::mem::size_of::<StoragePropertyQuery>() as u32,
+            &mut descriptor as *mut _ as *mut c_void,
+            std::mem::size_of::<DeviceSeekPenaltyDescriptor>() as u32,
+            &mut returned,
+            std::ptr::null_mut(),
+        );
+        CloseHandle(handle);
+        ok != 0 && descriptor.incurs_seek_penalty != 0
+    }
+}
+
+/// 路径所在磁盘是否为机械硬盘（读取 /sys/dev/block 的 rotational），无法判断时视为固态
+#[cfg(target_os = "linux")]
+fn is_rotational_disk(path: &std::path::Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return false;
+    };
+    let dev = metadata.dev();
+    let major = ((dev >> 8) & 0xfff) | ((dev >> 32) & !0xfff);
+    let minor = (dev & 0xff) | ((dev >> 12) & !0xff);
+    let block = std::path::PathBuf::from(format!("/sys/dev/block/{}:{}", major, minor));
+    // 分区没有 queue 目录，需要看所属磁盘
+    [
+        block.join("queue/rotational"),
+        block.join("../queue/rotational"),
+    ]
+    .iter()
+    .find_map(|p| std::fs::read_to_string(p).ok())
+    .is_some_and(|content| content.trim() == "1")
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn is_rotational_disk(path: &std::path::Path) -> bool {
+    let _ = path;
+    false
+}
+
 /// 递归复制整个目录
 fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Result<(), String> {
     std::fs::create_dir_all(dst).map_err(|e| format!("无法创建目录 [{}]: {}", dst.display(), e))?;
@@ -494,6 +906,7 @@ pub fn fallback_update(
         "fallback_update called: extract_dir={}, target_dir={}, new_version={}",
         extract_dir, target_dir, new_version
     );
+    let _update_guard = UpdateStepGuard::new();
 
     let target_path = std::path::Path::new(&target_dir);
 
@@ -0,0 +1,186 @@
+//! USB 真机识别
+//!
+//! MaaToolkit 的设备搜索以模拟器为主，且不会列出未授权的设备。这里补充执行 `adb devices -l`，
+//! 把 USB 连接的真机（序列号既不是 `emulator-xxxx` 也不是 `host:port`）并入设备列表，
+//! 同时带上授权状态：`unauthorized` 的设备需要用户在手机上点「允许 USB 调试」。
+//! 连接真机前也会复查一次状态，给出比 MaaFramework 连接失败更明确的提示。
+//! 连接和运行过程中的授权丢失由 `adb_auth` 检测。
+
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use log::{debug, info, warn};
+
+use super::types::AdbDevice;
+
+/// `adb devices` 的超时（首次调用可能要启动 adb server）
+const ADB_DEVICES_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 已授权可用的设备状态
+pub const ADB_STATE_DEVICE: &str = "device";
+
+/// 未授权（需在手机上确认 USB 调试）的设备状态
+pub const ADB_STATE_UNAUTHORIZED: &str = "unauthorized";
+
+/// `adb devices -l` 中的一行
+struct AdbDeviceLine {
+    serial: String,
+    state: String,
+    model: Option<String>,
+}
+
+/// 判断 ADB 序列号是否为 USB 真机（排除 `emulator-5554`、`127.0.0.1:16384` 和只给了 host 的地址）
+pub fn is_usb_serial(serial: &str) -> bool {
+    let serial = serial.trim();
+    !serial.is_empty()
+        && !serial.starts_with("emulator-")
+        && !serial.contains(':')
+        && !serial.eq_ignore_ascii_case("localhost")
+        && serial.parse::<std::net::IpAddr>().is_err()
+}
+
+/// 执行 `adb devices -l`，超时则结束进程并返回 None
+fn run_adb_devices(adb_path: &str) -> Option<String> {
+    let mut cmd = Command::new(adb_path);
+    cmd.args(["devices", "-l"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = match cmd.spawn() {
+        Ok(c) => c,
+        Err(e) => {
+            debug!("Failed to run {} devices: {}", adb_path, e);
+            return None;
+        }
+    };
+
+    let deadline = Instant::now() + ADB_DEVICES_TIMEOUT;
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if Instant::now() < deadline => {
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                warn!("{} devices timed out", adb_path);
+                return None;
+            }
+        }
+    }
+
+    let mut output = String::new();
+    child.stdout.take()?.read_to_string(&mut output).ok()?;
+    Some(output)
+}
+
+/// 解析 `adb devices -l` 输出，跳过标题行和 daemon 提示
+fn parse_adb_devices(output: &str) -> Vec<AdbDeviceLine> {
+    output
+        .lines()
+        .filter(|line| !line.starts_with("List of devices") && !line.starts_with('*'))
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let serial = parts.next()?.to_string();
+            let state = parts.next()?.to_string();
+            let model = parts
+                .find_map(|p| p.strip_prefix("model:"))
+                .map(|m| m.replace('_', " "));
+            Some(AdbDeviceLine {
+                serial,
+                state,
+                model,
+            })
+        })
+        .collect()
+}
+
+/// 标记 MaaToolkit 搜到的设备中的 USB 真机，并补充它没有列出的真机（含未授权设备）
+///
+/// 用到的 adb 取自已搜到设备的 adb 路径，都没有时尝试 PATH 中的 `adb`
+pub fn merge_usb_devices(devices: &mut Vec<AdbDevice>) {
+    for device in devices.iter_mut() {
+        if is_usb_serial(&device.address) {
+            device.physical = true;
+            device.auth_state = Some(ADB_STATE_DEVICE.to_string());
+        }
+    }
+
+    let mut adb_paths: Vec<String> = Vec::new();
+    for device in devices.iter() {
+        if !adb_paths.contains(&device.adb_path) {
+            adb_paths.push(device.adb_path.clone());
+        }
+    }
+    if adb_paths.is_empty() {
+        adb_paths.push("adb".to_string());
+    }
+
+    let mut known: HashSet<String> = devices.iter().map(|d| d.address.clone()).collect();
+    for adb_path in &adb_paths {
+        let Some(output) = run_adb_devices(adb_path) else {
+            continue;
+        };
+        for line in parse_adb_devices(&output) {
+            if !is_usb_serial(&line.serial) || !known.insert(line.serial.clone()) {
+                continue;
+            }
+            info!(
+                "USB device found: {} ({}) via {}",
+                line.serial, line.state, adb_path
+            );
+            devices.push(AdbDevice {
+                name: line.model.unwrap_or_else(|| line.serial.clone()),
+                adb_path: adb_path.clone(),
+                address: line.serial,
+                screencap_methods: maa_framework::common::AdbScreencapMethod::DEFAULT.bits(),
+                input_methods: maa_framework::common::AdbInputMethod::DEFAULT.bits(),
+                config: "{}".to_string(),
+                physical: true,
+                auth_state: Some(line.state),
+            });
+        }
+    }
+}
+
+/// 查询 adb 设备列表中各设备的状态（序列号 -> 状态），adb 执行失败时返回 None
+pub fn query_device_states(adb_path: &str) -> Option<HashMap<String, String>> {
+    let output = run_adb_devices(adb_path)?;
+    Some(
+        parse_adb_devices(&output)
+            .into_iter()
+            .map(|d| (d.serial, d.state))
+            .collect(),
+    )
+}
+
+/// 连接 USB 真机前检查授权状态，未授权或已断开时返回可读的错误
+pub fn check_usb_device_ready(adb_path: &str, serial: &str) -> Result<(), String> {
+    // adb 执行失败时不拦截，交给 MaaFramework 连接
+    let Some(states) = query_device_states(adb_path) else {
+        return Ok(());
+    };
+    match states.get(serial).map(String::as_str) {
+        Some(ADB_STATE_DEVICE) => Ok(()),
+        Some(ADB_STATE_UNAUTHORIZED) => Err(format!(
+            "设备 {} 未授权 USB 调试，请在手机上点击「允许」后重试",
+            serial
+        )),
+        Some(state) => Err(format!(
+            "设备 {} 当前状态为 {}，请重新插拔数据线后重试",
+            serial, state
+        )),
+        None => Err(format!("未找到 USB 设备 {}，请检查数据线连接", serial)),
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! 提供路径处理和其他通用工具函数
 
-use super::types::{MaaCallbackEvent, MaaState, StateChangedEvent};
+use super::types::{
+    InstanceRuntime, LogSectionEvent, MaaCallbackEvent, MaaState, StateChangedEvent,
+    TaskReportRecord,
+};
 use crate::ws_broadcast::{WsBroadcast, WsEvent};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 use tauri::{AppHandle, Emitter, Manager};
 
 /// 发送回调事件到前端（Tauri WebView + WebSocket 浏览器客户端）
@@ -28,6 +32,17 @@ pub fn emit_callback_event<S: Into<String>>(app: &AppHandle, message: S, details
     }
 }
 
+/// 实例的日志标签：有显示名时用显示名，否则用 instance_id
+pub fn instance_label(state: &MaaState, instance_id: &str) -> String {
+    state
+        .instances
+        .lock()
+        .ok()
+        .and_then(|instances| instances.get(instance_id)?.name.clone())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| instance_id.to_string())
+}
+
 /// 发送实例状态变更事件（双通道：WS 浏览器客户端 + Tauri WebView）
 ///
 /// Tauri 端和 WebUI 端都会收到此事件，用于刷新 `isRunning`、连接状态等运行时信息。
@@ -54,7 +69,7 @@ pub fn emit_state_changed(app: &AppHandle, instance_id: &str, kind: &str) {
 ///
 /// 应在 tasker sink 中调用，在 `emit_callback_event` 之前处理任务状态变更。
 /// 负责跟踪 Tasker.Task.Starting / Succeeded / Failed，并在所有任务完成后
-/// 更新 `overall_status` 并发射 `tasks-completed` 事件。
+/// 更新 `overall_status` 并发射 `tasks-completed` 事件（循环执行时每轮结束交由 `task_loop` 处理）。
 pub fn handle_task_callback(
     maa_state: &Arc<MaaState>,
     app: &AppHandle,
@@ -71,15 +86,21 @@ pub fn handle_task_callback(
     }
 
     // 解析 task_id
-    let task_id: i64 = match serde_json::from_str::<serde_json::Value>(details)
-        .ok()
+    let details_json = serde_json::from_str::<serde_json::Value>(details).ok();
+    let task_id: i64 = match details_json
+        .as_ref()
         .and_then(|v| v.get("task_id").and_then(|id| id.as_i64()))
     {
         Some(id) => id,
         None => return,
     };
 
-    let all_done = {
+    // 任务结束时清理非持久化的实例变量（MXU_SET_VAR 等）
+    if !is_started {
+        crate::mxu_actions::clear_task_vars(instance_id);
+    }
+
+    let (all_done, round_end) = {
         let mut instances = match maa_state.instances.lock() {
             Ok(g) => g,
             Err(_) => return,
@@ -88,6 +109,40 @@ pub fn handle_task_callback(
             Some(i) => i,
             None => return,
         };
+        record_task_report(instance, task_id, details_json.as_ref(), message);
+        emit_log_section(
+            app,
+            instance_id,
+            instance,
+            task_id,
+            details_json.as_ref(),
+            message,
+        );
+        // Agent 自动重启期间，被中断任务的失败回调不计入状态（剩余任务会重新提交）
+        if instance.agent_restarting {
+            return;
+        }
+        // Agent 已退出时失败的任务是被崩溃中断的，由自动重启重新执行
+        if is_failed && super::maa_agent::mark_crash_interrupted(instance, task_id) {
+            return;
+        }
+        // 被高优先级任务抢占的旧任务已用新 ID 重新提交，其回调同样不计入
+        if instance.preempted_task_ids.contains(&task_id) {
+            return;
+        }
+        // 按失败重试策略重新提交时，本次失败不计入状态
+        if is_failed {
+            if let Some(plan) = super::task_retry::plan_retry(instance, task_id) {
+                drop(instances);
+                super::task_retry::schedule_retry(
+                    app.clone(),
+                    Arc::clone(maa_state),
+                    instance_id.to_string(),
+                    plan,
+                );
+                return;
+            }
+        }
         let state = &mut instance.task_run_state;
 
         if is_started {
@@ -98,7 +153,7 @@ pub fn handle_task_callback(
             if let Some(selected_id) = state.mappings.get(&task_id).cloned() {
                 state.statuses.insert(selected_id, "running".to_string());
             }
-            false // 未完成
+            (false, None) // 未完成
         } else {
             // 任务成功或失败
             let status_str = if is_succeeded { "succeeded" } else { "failed" };
@@ -118,20 +173,255 @@ pub fn handle_task_callback(
 
             if all_completed {
                 let has_failed = state.statuses.values().any(|s| s == "failed");
-                state.overall_status =
-                    Some(if has_failed { "Failed" } else { "Succeeded" }.to_string());
-                instance.task_ids.clear();
+                // 循环执行且还有下一轮时，整体状态保持 Running，不视为队列结束
+                let round_end = super::task_loop::finish_round(instance, instance_id, has_failed);
+                let continues = round_end.as_ref().is_some_and(|r| r.continues());
+                if !continues {
+                    instance.task_run_state.overall_status =
+                        Some(if has_failed { "Failed" } else { "Succeeded" }.to_string());
+                    instance.task_ids.clear();
+                    super::completion_webhook::notify_tasks_completed(instance_id, instance);
+                }
+                (!continues, round_end)
+            } else {
+                (false, None)
             }
-
-            all_completed
         }
     }; // 锁在此处释放
 
     // 通知前端刷新状态
     emit_state_changed(app, instance_id, "task-progress");
+    if is_started {
+        super::log_overlay::on_task_started(app);
+    }
+    if let Some(round_end) = round_end {
+        super::task_loop::on_round_end(app, maa_state, round_end);
+    }
     if all_done {
         emit_state_changed(app, instance_id, "tasks-completed");
+        super::log_overlay::on_tasks_finished(app, maa_state);
+    }
+}
+
+/// 发出日志分段标记（maa-log-section），内部停止任务不分段
+fn emit_log_section(
+    app: &AppHandle,
+    instance_id: &str,
+    instance: &InstanceRuntime,
+    task_id: i64,
+    details: Option<&serde_json::Value>,
+    message: &str,
+) {
+    let entry = details
+        .and_then(|v| v.get("entry"))
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+    if entry == "MaaTaskerPostStop" {
+        return;
+    }
+    let (phase, status) = match message {
+        "Tasker.Task.Starting" => ("start", None),
+        "Tasker.Task.Succeeded" => ("end", Some("succeeded")),
+        _ => ("end", Some("failed")),
+    };
+    let event = LogSectionEvent {
+        instance_id: instance_id.to_string(),
+        task_id,
+        entry: entry.to_string(),
+        selected_task_id: instance.task_run_state.mappings.get(&task_id).cloned(),
+        phase,
+        status,
+        round: instance.task_run_state.round,
+    };
+    if let Err(e) = app.emit("maa-log-section", event) {
+        log::error!("Failed to emit maa-log-section: {}", e);
+    }
+}
+
+/// 记录任务开始/结束时间及结束时的截图，供任务报告使用
+fn record_task_report(
+    instance: &mut InstanceRuntime,
+    task_id: i64,
+    details: Option<&serde_json::Value>,
+    message: &str,
+) {
+    if message == "Tasker.Task.Starting" {
+        let entry = details
+            .and_then(|v| v.get("entry"))
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        instance.task_report.retain(|r| r.task_id != task_id);
+        instance.task_report.push(TaskReportRecord {
+            task_id,
+            entry,
+            started_at: Instant::now(),
+            status: None,
+            duration_ms: None,
+            screenshot: None,
+        });
+        return;
+    }
+
+    let succeeded = message == "Tasker.Task.Succeeded";
+    // 未启用报告或该结果无需截图时不读取缓存图，避免每个任务都复制整张截图
+    let screenshot = if super::report::should_capture_screenshot(succeeded) {
+        instance
+            .controller
+            .as_ref()
+            .and_then(|c| c.cached_image().ok())
+            .and_then(|buf| buf.to_vec())
+            .filter(|data| !data.is_empty())
+            .and_then(|png| super::report::make_thumbnail(&png))
+    } else {
+        None
+    };
+    if let Some(record) = instance
+        .task_report
+        .iter_mut()
+        .find(|r| r.task_id == task_id)
+    {
+        record.status = Some(if succeeded { "succeeded" } else { "failed" }.to_string());
+        record.duration_ms = Some(record.started_at.elapsed().as_millis() as u64);
+        record.screenshot = screenshot;
+    }
+}
+
+/// 解析 Pipeline override 字符串，返回按顺序应用的各层节点表
+///
+/// 支持单个对象或对象数组（与 MaaFramework 一致），空字符串视为无 override
+pub fn parse_pipeline_override(
+    pipeline_override: &str,
+) -> Result<Vec<serde_json::Map<String, serde_json::Value>>, String> {
+    if pipeline_override.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let value: serde_json::Value = serde_json::from_str(pipeline_override)
+        .map_err(|e| format!("Pipeline override 不是合法的 JSON: {}", e))?;
+    let layers = match value {
+        serde_json::Value::Object(map) => vec![map],
+        serde_json::Value::Array(items) => items
+            .into_iter()
+            .map(|item| match item {
+                serde_json::Value::Object(map) => Ok(map),
+                _ => Err("Pipeline override 数组中只能包含对象".to_string()),
+            })
+            .collect::<Result<_, _>>()?,
+        _ => return Err("Pipeline override 必须是对象或对象数组".to_string()),
+    };
+    Ok(layers)
+}
+
+/// 将一层 override 叠加到目标节点表上
+///
+/// 叠加语义与 MaaFramework 一致：以节点为单位合并，同一节点内后出现的字段覆盖先前的值，
+/// 未涉及的字段保持不变；节点值不是对象时整体替换。
+pub fn merge_pipeline_override(
+    target: &mut serde_json::Map<String, serde_json::Value>,
+    layer: &serde_json::Map<String, serde_json::Value>,
+) {
+    for (node, fields) in layer {
+        match (target.get_mut(node), fields) {
+            (Some(serde_json::Value::Object(existing)), serde_json::Value::Object(fields)) => {
+                for (key, value) in fields {
+                    existing.insert(key.clone(), value.clone());
+                }
+            }
+            _ => {
+                target.insert(node.clone(), fields.clone());
+            }
+        }
+    }
+}
+
+/// 强制结束进程及其所有子进程，并回收该进程
+///
+/// Agent 常由启动脚本再拉起实际进程，只结束直接子进程会留下孤儿进程
+pub fn kill_process_tree(child: &mut std::process::Child) {
+    let pid = child.id();
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        if let Err(e) = std::process::Command::new("taskkill")
+            .args(["/F", "/T", "/PID", &pid.to_string()])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+        {
+            log::warn!("taskkill failed for process tree {}: {}", pid, e);
+        }
+    }
+
+    #[cfg(unix)]
+    {
+        // 先收集全部后代进程，父进程被结束后子进程会被重新挂到 init 下而无法追踪
+        fn collect_descendants(pid: u32, out: &mut Vec<u32>) {
+            let Ok(output) = std::process::Command::new("pgrep")
+                .args(["-P", &pid.to_string()])
+                .output()
+            else {
+                return;
+            };
+            for child in String::from_utf8_lossy(&output.stdout)
+                .split_whitespace()
+                .filter_map(|s| s.parse().ok())
+            {
+                collect_descendants(child, out);
+                out.push(child);
+            }
+        }
+
+        let mut descendants = Vec::new();
+        collect_descendants(pid, &mut descendants);
+        for descendant in descendants {
+            unsafe {
+                libc::kill(descendant as libc::pid_t, libc::SIGKILL);
+            }
+        }
+    }
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// 从 PNG 头读取图片尺寸
+pub fn png_size(data: &[u8]) -> Option<(u32, u32)> {
+    if data.len() < 24 || &data[12..16] != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(data[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(data[20..24].try_into().ok()?);
+    Some((width, height))
+}
+
+/// 将 RGBA 像素编码为 PNG，编码失败（如像素长度与尺寸不符）时返回空数据
+pub fn encode_png(width: u32, height: u32, rgba: &[u8]) -> Vec<u8> {
+    use image::codecs::png::PngEncoder;
+    use image::{ExtendedColorType, ImageEncoder};
+
+    let mut png = Vec::new();
+    if let Err(e) =
+        PngEncoder::new(&mut png).write_image(rgba, width, height, ExtendedColorType::Rgba8)
+    {
+        log::warn!("Failed to encode PNG ({}x{}): {}", width, height, e);
+        png.clear();
     }
+    png
+}
+
+/// 按最长边等比缩小 RGBA 图像（区域平均）
+pub fn downscale_rgba(width: u32, height: u32, rgba: &[u8], max_side: u32) -> (u32, u32, Vec<u8>) {
+    use image::{imageops, ImageBuffer, Rgba};
+
+    let Some(source) = ImageBuffer::<Rgba<u8>, _>::from_raw(width, height, rgba) else {
+        return (width, height, rgba.to_vec());
+    };
+    let scale = max_side as f64 / width.max(height) as f64;
+    let new_width = ((width as f64 * scale).round() as u32).max(1);
+    let new_height = ((height as f64 * scale).round() as u32).max(1);
+    let resized = imageops::thumbnail(&source, new_width, new_height);
+    (new_width, new_height, resized.into_raw())
 }
 
 /// 发送配置变更事件（双通道：WS 浏览器客户端 + Tauri WebView）
@@ -152,7 +442,16 @@ pub fn emit_config_changed(app: &AppHandle) {
 /// 获取应用数据目录
 /// - macOS: ~/Library/Application Support/MXU/
 /// - Windows/Linux: exe 所在目录（保持便携式部署）
+/// - 命令行指定了 `--data-dir` 时使用该目录
 pub fn get_app_data_dir() -> Result<PathBuf, String> {
+    // 命令行 --data-dir 指定时优先使用（多开时各实例使用不同数据目录）
+    if let Some(dir) = &super::system::init_cli().data_dir {
+        if dir.is_absolute() {
+            return Ok(dir.clone());
+        }
+        return Ok(get_exe_directory()?.join(dir));
+    }
+
     #[cfg(target_os = "macos")]
     {
         let home = std::env::var("HOME").map_err(|_| "无法获取 HOME 环境变量".to_string())?;
@@ -236,11 +535,28 @@ pub fn build_user_agent() -> String {
     format!("MXU/{} ({}; {}) Tauri/{}", version, os, arch, tauri_version)
 }
 
+/// 校验外部程序的工作目录：必须存在且是目录
+///
+/// 不存在时直接报错（错误信息带上用户给的原始路径），避免静默回退到程序所在目录，
+/// 让用户误以为设置已生效
+pub fn validate_cwd(cwd: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(cwd);
+    if !path.exists() {
+        return Err(format!("工作目录不存在: {}", cwd));
+    }
+    if !path.is_dir() {
+        return Err(format!("工作目录不是文件夹: {}", cwd));
+    }
+    Ok(path)
+}
+
 /// 构建启动程序的 Command
 ///
 /// - 子进程的 stdout/stderr 设为 null，避免继承父进程的标准流。
 /// - 当 `use_cmd` 为 true 时（仅 Windows），通过 `cmd /c` 启动并设置
 ///   `CREATE_NO_WINDOW` 标志隐藏控制台窗口。
+/// - 当 `hide_window` 为 true 时（仅 Windows），直接启动的控制台程序同样设置
+///   `CREATE_NO_WINDOW`，不再弹出黑框；GUI 程序不受该标志影响。
 ///
 /// 注意：曾经使用 `CREATE_BREAKAWAY_FROM_JOB` 使子进程脱离父进程 Job 对象，
 /// 但 Windows 计划任务创建的 Job 默认不允许 breakaway（未设置
@@ -256,6 +572,7 @@ pub fn build_launch_command(
     program: &str,
     args: &[String],
     use_cmd: bool,
+    hide_window: bool,
 ) -> std::process::Command {
     use std::process::Stdio;
 
@@ -277,8 +594,11 @@ pub fn build_launch_command(
     // 不继承父进程的标准流
     cmd.stdout(Stdio::null()).stderr(Stdio::null());
 
+    #[cfg(not(target_os = "windows"))]
+    let _ = hide_window;
+
     #[cfg(target_os = "windows")]
-    if use_cmd {
+    if use_cmd || hide_window {
         use std::os::windows::process::CommandExt;
         const CREATE_NO_WINDOW: u32 = 0x0800_0000;
         cmd.creation_flags(CREATE_NO_WINDOW);
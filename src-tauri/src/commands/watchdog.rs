@@ -0,0 +1,154 @@
+//! 实例运行时长看门狗
+//!
+//! 为实例设置 `max_runtime_secs` 后，后台线程统计本轮任务的运行时长，超时即停止任务并发出
+//! `watchdog-timeout` 事件，防止挂机时某个实例卡死无限运行。任务不在运行时计时清零。
+//! 停在断点上的时间是否计入由 `count_paused` 决定（默认不计入）。
+//! 每个实例至多一个看门狗线程，重新设置、关闭或销毁实例后旧线程自行退出。
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+use tauri::{AppHandle, Emitter, State};
+
+use super::breakpoint::is_paused;
+use super::maa_core::stop_task_impl;
+use super::types::{MaaState, WatchdogConfig, WatchdogTimeoutEvent};
+
+/// 看门狗检查间隔
+const WATCHDOG_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 各实例的看门狗（instance_id -> (代次, 配置)），代次变化时旧线程退出
+static WATCHDOGS: LazyLock<Mutex<HashMap<String, (u64, WatchdogConfig)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 看门狗代次，每次设置自增
+static WATCHDOG_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// 移除实例的看门狗（销毁实例或重置运行时状态时调用），线程在下一次检查时退出
+pub fn clear_watchdog(instance_id: &str) {
+    if let Ok(mut watchdogs) = WATCHDOGS.lock() {
+        if watchdogs.remove(instance_id).is_some() {
+            info!("[watchdog] Cleared for instance {}", instance_id);
+        }
+    }
+}
+
+/// 看门狗线程主循环
+fn watchdog_loop(state: Arc<MaaState>, app: AppHandle, instance_id: String, generation: u64) {
+    let mut elapsed = Duration::ZERO;
+    let mut last_tick = Instant::now();
+    let mut fired = false;
+
+    loop {
+        std::thread::sleep(WATCHDOG_INTERVAL);
+        let now = Instant::now();
+        let tick = now - last_tick;
+        last_tick = now;
+
+        let config = match WATCHDOGS.lock() {
+            Ok(watchdogs) => match watchdogs.get(&instance_id) {
+                Some((g, config)) if *g == generation => config.clone(),
+                _ => return,
+            },
+            Err(_) => return,
+        };
+
+        let running = match state.instances.lock() {
+            Ok(instances) => match instances.get(&instance_id) {
+                Some(instance) => instance.tasker.as_ref().is_some_and(|t| t.running()),
+                // 实例已销毁
+                None => {
+                    clear_watchdog(&instance_id);
+                    return;
+                }
+            },
+            Err(_) => return,
+        };
+
+        if !running {
+            elapsed = Duration::ZERO;
+            fired = false;
+            continue;
+        }
+        if fired || (!config.count_paused && is_paused(&instance_id)) {
+            continue;
+        }
+
+        elapsed += tick;
+        if elapsed.as_secs() < config.max_runtime_secs {
+            continue;
+        }
+
+        fired = true;
+        warn!(
+            "[watchdog] Instance {} exceeded max runtime {}s, stopping tasks",
+            instance_id, config.max_runtime_secs
+        );
+        if let Err(e) = stop_task_impl(&state, &instance_id) {
+            warn!("[watchdog] Failed to stop instance {}: {}", instance_id, e);
+        }
+        let event = WatchdogTimeoutEvent {
+            instance_id: instance_id.clone(),
+            max_runtime_secs: config.max_runtime_secs,
+            elapsed_secs: elapsed.as_secs(),
+        };
+        if let Err(e) = app.emit("watchdog-timeout", event) {
+            warn!("Failed to emit watchdog-timeout: {}", e);
+        }
+    }
+}
+
+/// 设置实例的最大运行时长，`max_runtime_secs` 为空或 0 时关闭看门狗
+///
+/// `count_paused` 为 true 时停在断点上的时间也计入运行时长
+#[tauri::command]
+pub fn set_watchdog(
+    app: AppHandle,
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    max_runtime_secs: Option<u64>,
+    count_paused: Option<bool>,
+) -> Result<(), String> {
+    if !state
+        .instances
+        .lock()
+        .map_err(|e| e.to_string())?
+        .contains_key(&instance_id)
+    {
+        return Err("Instance not found".to_string());
+    }
+    let Some(max_runtime_secs) = max_runtime_secs.filter(|secs| *secs > 0) else {
+        clear_watchdog(&instance_id);
+        return Ok(());
+    };
+    let config = WatchdogConfig {
+        max_runtime_secs,
+        count_paused: count_paused.unwrap_or(false),
+    };
+    info!("[watchdog] set {} -> {:?}", instance_id, config);
+
+    let generation = WATCHDOG_GENERATION.fetch_add(1, Ordering::Relaxed) + 1;
+    WATCHDOGS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(instance_id.clone(), (generation, config));
+
+    let state = state.inner().clone();
+    std::thread::Builder::new()
+        .name("instance-watchdog".to_string())
+        .spawn(move || watchdog_loop(state, app, instance_id, generation))
+        .map_err(|e| format!("启动看门狗线程失败: {}", e))?;
+    Ok(())
+}
+
+/// 获取实例的看门狗配置，未设置时返回 None
+#[tauri::command]
+pub fn get_watchdog(instance_id: String) -> Result<Option<WatchdogConfig>, String> {
+    let watchdogs = WATCHDOGS.lock().map_err(|e| e.to_string())?;
+    Ok(watchdogs
+        .get(&instance_id)
+        .map(|(_, config)| config.clone()))
+}
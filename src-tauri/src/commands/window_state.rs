@@ -0,0 +1,234 @@
+//! Win32 目标窗口最小化检测
+//!
+//! 目标窗口最小化后 Win32 控制器截图会失败或截到黑屏（PrintWindow 同样截不到最小化的窗口），
+//! 任务只会默默识别失败。后台线程轮询已连接 Win32 / Gamepad 控制器的目标窗口，最小化或恢复时
+//! 发出 `maa-window-state-changed` 事件。按实例可选最小化时的处理：
+//! - `notify`：只发事件提示（默认）
+//! - `pause`：任务开始及每轮识别前窗口仍最小化时阻塞任务线程，恢复可见后自动继续，停止任务时立即退出等待
+//! - `restore`：任务开始及每轮识别前以不激活的方式恢复窗口，不抢占前台
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+
+use log::info;
+use tauri::AppHandle;
+
+use super::types::{ControllerConfig, MaaState, MinimizedPolicy};
+
+/// 窗口状态轮询间隔
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 暂停等待期间检查窗口和停止请求的间隔
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 各实例最小化时的处理方式（未设置的为 Notify）
+static POLICIES: LazyLock<Mutex<HashMap<String, MinimizedPolicy>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 获取实例最小化时的处理方式
+pub fn minimized_policy(instance_id: &str) -> MinimizedPolicy {
+    POLICIES
+        .lock()
+        .ok()
+        .and_then(|policies| policies.get(instance_id).copied())
+        .unwrap_or_default()
+}
+
+/// 窗口句柄是否仍有效（非 Windows 平台恒为 false）
+pub fn window_exists(handle: u64) -> bool {
+    platform::window_state(handle).is_some()
+}
+
+/// 实例已连接的 Win32 / Gamepad 控制器目标窗口句柄
+fn target_window(state: &MaaState, instance_id: &str) -> Option<u64> {
+    let instances = state.instances.lock().ok()?;
+    let instance = instances.get(instance_id)?;
+    instance.controller.as_ref()?;
+    match instance.controller_config.as_ref()? {
+        ControllerConfig::Win32 { handle, .. } | ControllerConfig::Gamepad { handle, .. } => {
+            Some(*handle).filter(|h| *h != 0)
+        }
+        _ => None,
+    }
+}
+
+/// 任务开始和每轮识别前按处理方式应对最小化的窗口
+///
+/// 在 tasker sink 和 context sink 中调用，其余消息直接返回
+pub fn before_capture(state: &MaaState, instance_id: &str, message: &str) {
+    if message != "Tasker.Task.Starting" && message != "Node.NextList.Starting" {
+        return;
+    }
+    let policy = minimized_policy(instance_id);
+    if policy == MinimizedPolicy::Notify {
+        return;
+    }
+    let Some(handle) = target_window(state, instance_id) else {
+        return;
+    };
+    if !platform::window_state(handle).is_some_and(|(minimized, _)| minimized) {
+        return;
+    }
+
+    if policy == MinimizedPolicy::Restore {
+        log::debug!(
+            "[window_state] {}: restoring minimized window 0x{:X}",
+            instance_id,
+            handle
+        );
+        platform::restore_no_activate(handle);
+        return;
+    }
+
+    info!(
+        "[window_state] {}: target window minimized, pausing until restored",
+        instance_id
+    );
+    loop {
+        std::thread::sleep(PAUSE_POLL_INTERVAL);
+        let stopping = state
+            .instances
+            .lock()
+            .ok()
+            .and_then(|instances| instances.get(instance_id).map(|i| i.stop_in_progress))
+            .unwrap_or(true);
+        if stopping {
+            info!(
+                "[window_state] {}: stop requested while waiting for window",
+                instance_id
+            );
+            return;
+        }
+        if minimized_policy(instance_id) != MinimizedPolicy::Pause {
+            break;
+        }
+        // 窗口被关闭时不再等待，交由截图失败处理
+        match platform::window_state(handle) {
+            Some((true, _)) => continue,
+            _ => break,
+        }
+    }
+    info!("[window_state] {}: resumed", instance_id);
+}
+
+/// 启动窗口状态轮询线程（应用启动时调用一次，非 Windows 平台不启动）
+#[cfg(windows)]
+pub fn start_window_monitor(app: AppHandle, state: Arc<MaaState>) {
+    use tauri::Emitter;
+
+    use super::types::WindowStateEvent;
+
+    std::thread::spawn(move || {
+        // instance_id -> (句柄, 是否最小化, 是否可见)
+        let mut last: HashMap<String, (u64, bool, bool)> = HashMap::new();
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let instance_ids: Vec<String> = match state.instances.lock() {
+                Ok(instances) => instances.keys().cloned().collect(),
+                Err(_) => continue,
+            };
+            let targets: Vec<(String, u64)> = instance_ids
+                .into_iter()
+                .filter_map(|id| target_window(&state, &id).map(|h| (id, h)))
+                .collect();
+            last.retain(|id, _| targets.iter().any(|(t, _)| t == id));
+
+            for (instance_id, handle) in targets {
+                let Some((minimized, visible)) = platform::window_state(handle) else {
+                    last.remove(&instance_id);
+                    continue;
+                };
+                let previous = last.insert(instance_id.clone(), (handle, minimized, visible));
+                // 首次检测到窗口时只在已最小化的情况下提示
+                let changed = match previous {
+                    Some((h, m, v)) => h != handle || m != minimized || v != visible,
+                    None => minimized,
+                };
+                if !changed {
+                    continue;
+                }
+                info!(
+                    "[window_state] {}: window 0x{:X} minimized={} visible={}",
+                    instance_id, handle, minimized, visible
+                );
+                let event = WindowStateEvent {
+                    instance_id: instance_id.clone(),
+                    handle,
+                    minimized,
+                    visible,
+                    policy: minimized_policy(&instance_id),
+                };
+                if let Err(e) = app.emit("maa-window-state-changed", event) {
+                    log::warn!("[window_state] Failed to emit event: {}", e);
+                }
+            }
+        }
+    });
+}
+
+#[cfg(not(windows))]
+pub fn start_window_monitor(app: AppHandle, state: Arc<MaaState>) {
+    let _ = (app, state);
+}
+
+#[cfg(windows)]
+mod platform {
+    use winsafe::{co::SW, HWND};
+
+    fn hwnd(handle: u64) -> HWND {
+        unsafe { HWND::from_ptr(handle as *mut _) }
+    }
+
+    /// 查询窗口状态：(是否最小化, 是否可见)，窗口已不存在时返回 None
+    pub fn window_state(handle: u64) -> Option<(bool, bool)> {
+        let hwnd = hwnd(handle);
+        if !hwnd.IsWindow() {
+            return None;
+        }
+        Some((hwnd.IsIconic(), hwnd.IsWindowVisible()))
+    }
+
+    /// 恢复最小化的窗口但不激活（不抢前台焦点）
+    pub fn restore_no_activate(handle: u64) {
+        hwnd(handle).ShowWindow(SW::SHOWNOACTIVATE);
+    }
+}
+
+#[cfg(not(windows))]
+mod platform {
+    pub fn window_state(handle: u64) -> Option<(bool, bool)> {
+        let _ = handle;
+        None
+    }
+
+    pub fn restore_no_activate(handle: u64) {
+        let _ = handle;
+    }
+}
+
+/// 移除实例的最小化处理策略（销毁实例或重置运行时状态时调用）
+pub fn clear_instance(instance_id: &str) {
+    if let Ok(mut entries) = POLICIES.lock() {
+        entries.remove(instance_id);
+    }
+}
+
+/// 设置实例的目标窗口最小化处理方式（立即生效）
+#[tauri::command]
+pub fn set_minimized_policy(instance_id: String, policy: MinimizedPolicy) -> Result<(), String> {
+    info!("set_minimized_policy: {} -> {:?}", instance_id, policy);
+    let mut policies = POLICIES.lock().map_err(|e| e.to_string())?;
+    if policy == MinimizedPolicy::Notify {
+        policies.remove(&instance_id);
+    } else {
+        policies.insert(instance_id, policy);
+    }
+    Ok(())
+}
+
+/// 查询实例的目标窗口最小化处理方式
+#[tauri::command]
+pub fn get_minimized_policy(instance_id: String) -> MinimizedPolicy {
+    minimized_policy(&instance_id)
+}
@@ -4,6 +4,7 @@ mod mxu_actions;
 pub mod screenshot_service;
 mod tray;
 mod web_server;
+mod window_icon;
 pub mod ws_broadcast;
 
 use commands::{AppConfigState, MaaState};
@@ -66,6 +67,23 @@ pub fn run() {
             // 创建 WebSocket 广播器（容量 256，被 emit_callback_event/emit_agent_output 共用）
             let ws_broadcast = Arc::new(WsBroadcast::new(256));
 
+            // 检测程序所在卷（网络盘/只读盘/云同步目录会导致文件锁、更新失败、日志写不了等问题）
+            let volume = commands::system::exe_volume_info();
+            match &volume.issue {
+                Some(issue) => log::warn!(
+                    "Exe volume issue: {} (drive_type={}, writable={}, cloud_synced={})",
+                    issue,
+                    volume.drive_type,
+                    volume.writable,
+                    volume.cloud_synced
+                ),
+                None => log::info!(
+                    "Exe volume: drive_type={}, writable={}",
+                    volume.drive_type,
+                    volume.writable
+                ),
+            }
+
             // 加载 interface.json（含 import 处理和翻译）
             match commands::utils::get_exe_directory() {
                 Ok(exe_dir) => {
@@ -90,6 +108,9 @@ pub fn run() {
             app.manage(ws_broadcast.clone());
             app.manage(app_config.clone());
 
+            // 开机自启动延迟秒数（与 Web 服务器设置一起从配置中读取）
+            let autostart_delay_secs: u64;
+
             // 启动 HTTP Web 服务器（后台 tokio 任务，不阻塞 Tauri 启动）
             {
                 let maa_clone = maa_state.clone();
@@ -122,6 +143,87 @@ pub fn run() {
 
                 web_server::set_web_server_enabled(web_server_enabled);
 
+                // 日志级别（MaaFramework 部分在 maa_init 加载库后同步）
+                if let Some(level) = settings_obj
+                    .and_then(|s| s.get("logLevel"))
+                    .and_then(|v| v.as_str())
+                {
+                    if let Err(e) = commands::system::apply_log_level(level, false) {
+                        log::warn!("{}", e);
+                    }
+                }
+
+                // 结构化事件日志（JSONL）
+                if settings_obj
+                    .and_then(|s| s.get("eventLogEnabled"))
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false)
+                {
+                    if let Err(e) = commands::event_log::apply_event_log_enabled(true) {
+                        log::warn!("{}", e);
+                    }
+                }
+
+                // 任务失败时附带截图
+                if settings_obj
+                    .and_then(|s| s.get("failureScreenshotEnabled"))
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false)
+                {
+                    commands::failure_screenshot::apply_failure_screenshot_enabled(true);
+                }
+
+                // 任务报告（决定任务结束时是否保留截图缩略图）
+                if let Some(report) = settings_obj.and_then(|s| s.get("taskReport")) {
+                    let enabled = report
+                        .get("enabled")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false);
+                    let screenshots = report
+                        .get("screenshots")
+                        .and_then(|v| serde_json::from_value(v.clone()).ok())
+                        .unwrap_or_default();
+                    commands::report::apply_task_report_capture(enabled, screenshots);
+                }
+
+                // 点击/滑动轨迹事件
+                if settings_obj
+                    .and_then(|s| s.get("actionTraceEnabled"))
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false)
+                {
+                    commands::action_trace::apply_action_trace_enabled(true);
+                }
+
+                // 任务队列完成 webhook
+                if let Some(webhook) = settings_obj
+                    .and_then(|s| s.get("completionWebhook"))
+                    .and_then(|v| serde_json::from_value(v.clone()).ok())
+                {
+                    commands::completion_webhook::apply_completion_webhook(webhook);
+                }
+
+                // Agent 子进程资源限制
+                if let Some(limits) = settings_obj
+                    .and_then(|s| s.get("agentResourceLimits"))
+                    .and_then(|v| serde_json::from_value(v.clone()).ok())
+                {
+                    commands::agent_job::apply_agent_resource_limits(limits);
+                }
+
+                // 设备搜索的额外 adb 目录 / 模拟器安装目录
+                if let Some(toolkit_config) = settings_obj
+                    .and_then(|s| s.get("toolkitConfig"))
+                    .and_then(|v| serde_json::from_value(v.clone()).ok())
+                {
+                    commands::toolkit_config::apply_toolkit_config(toolkit_config);
+                }
+
+                autostart_delay_secs = settings_obj
+                    .and_then(|s| s.get("autoStartDelaySec"))
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(0);
+
                 drop(settings);
 
                 if web_server_enabled {
@@ -169,45 +271,51 @@ pub fn run() {
                 }
             }
 
-            // 启动时自动加载 MaaFramework DLL
-            if let Ok(maafw_dir) = commands::get_maafw_dir() {
-                if maafw_dir.exists() {
-                    #[cfg(windows)]
-                    let dll_path = maafw_dir.join("MaaFramework.dll");
-                    #[cfg(target_os = "macos")]
-                    let dll_path = maafw_dir.join("libMaaFramework.dylib");
-                    #[cfg(target_os = "linux")]
-                    let dll_path = maafw_dir.join("libMaaFramework.so");
-
-                    match maa_framework::load_library(&dll_path) {
-                        Ok(()) => {
-                            log::info!("MaaFramework loaded from {:?}", dll_path);
-                            // 预先设置 lib_dir，使 HTTP /api/maa/initialized 立即反映加载状态
-                            *maa_state.lib_dir.lock().unwrap() = Some(maafw_dir.clone());
-                        }
-                        Err(e) => {
-                            log::error!("Failed to load MaaFramework: {}", e);
-                            // 检查是否是 DLL 存在但加载失败的情况（可能是运行库缺失）
-                            if dll_path.exists() {
-                                log::warn!(
-                                    "DLLs exist but failed to load, possibly missing VC++ runtime: {}",
-                                    e
-                                );
-                                // 设置标记，前端加载完成后会查询此标记
-                                commands::system::set_vcredist_missing(true);
-                            }
-                        }
-                    }
-                } else {
-                    log::warn!("MaaFramework directory not found: {:?}", maafw_dir);
-                }
+            // 监听其他 MXU 进程发来的唤起通知（单实例检测时选择切换到已有窗口）
+            commands::single_instance::start_activate_listener(app.handle().clone());
+
+            // 启动时异步清理过期的实例日志
+            std::thread::spawn(commands::instance_log::cleanup_instance_logs);
+
+            // 开机自启动延迟：延迟结束后再加载 MaaFramework DLL，避免与开机时的其他程序抢资源
+            if commands::system::begin_autostart_delay(autostart_delay_secs) {
+                log::info!(
+                    "Autostart delay {}s, deferring MaaFramework load",
+                    autostart_delay_secs
+                );
+                let maa_clone = maa_state.clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(std::time::Duration::from_secs(autostart_delay_secs));
+                    load_maafw_library(&maa_clone);
+                    commands::system::finish_deferred_maafw_load();
+                });
+            } else {
+                load_maafw_library(&maa_state);
             }
 
+            // 轮询 Win32 目标窗口的最小化/可见状态
+            commands::window_state::start_window_monitor(app.handle().clone(), maa_state.clone());
+
+            // 任务运行期间轮询已连接 ADB 设备的授权状态
+            commands::adb_auth::start_adb_auth_monitor(app.handle().clone(), maa_state.clone());
+
             // DLL 加载完成后再注册 maa_state（确保 lib_dir 已设置）
             app.manage(maa_state);
 
-            // 初始化系统托盘
-            if let Err(e) = tray::init_tray(app.handle()) {
+            // 初始化系统托盘（菜单语言跟随配置，前端启动后会再同步一次实际语言）
+            let tray_language = app_config
+                .config
+                .lock()
+                .ok()
+                .and_then(|c| {
+                    c.get("settings")
+                        .and_then(|s| s.get("language"))
+                        .and_then(|v| v.as_str())
+                        .map(String::from)
+                })
+                .filter(|l| l != "system")
+                .unwrap_or_else(|| "zh-CN".to_string());
+            if let Err(e) = tray::init_tray(app.handle(), &tray_language) {
                 log::error!("Failed to initialize system tray: {}", e);
             }
 
@@ -216,33 +324,82 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             // Maa 核心命令
             commands::maa_core::maa_init,
+            commands::maa_core::maa_reset,
             commands::maa_core::maa_set_resource_dir,
             commands::maa_core::maa_get_version,
             commands::maa_core::maa_check_version,
             commands::maa_core::maa_find_adb_devices,
+            commands::maa_core::maa_probe_adb_ports,
             commands::maa_core::maa_find_win32_windows,
             commands::maa_core::maa_find_wlroots_sockets,
             commands::maa_core::maa_create_instance,
             commands::maa_core::maa_destroy_instance,
+            commands::maa_core::maa_set_instance_name,
             commands::maa_core::maa_connect_controller,
+            commands::emulator_launch::maa_prepare_emulator,
             commands::maa_core::maa_get_connection_status,
+            commands::maa_core::maa_get_controller_info,
             commands::maa_core::maa_load_resource,
             commands::maa_core::maa_is_resource_loaded,
             commands::maa_core::maa_get_resource_hash,
             commands::maa_core::maa_destroy_resource,
+            commands::maa_core::maa_reload_resource,
+            commands::resource_check::validate_resource,
+            commands::resource_integrity::verify_resource_integrity,
+            commands::resource_integrity::reset_resource_manifests,
+            commands::resource_integrity::generate_resource_manifest,
+            commands::template_preview::maa_get_template_preview,
             commands::maa_core::maa_run_task,
             commands::maa_core::maa_get_task_status,
             commands::maa_core::maa_stop_task,
+            commands::maa_core::maa_stop_all,
             commands::maa_core::maa_override_pipeline,
+            commands::maa_core::maa_get_pipeline_overrides,
+            commands::maa_core::maa_reset_pipeline,
             commands::maa_core::maa_is_running,
             commands::maa_core::maa_post_click,
             commands::maa_core::maa_post_screencap,
             commands::maa_core::maa_get_cached_image,
+            commands::maa_core::maa_get_image_roi,
             commands::maa_core::maa_screenshot_subscribe,
             commands::maa_core::maa_screenshot_unsubscribe,
             // Agent 命令
             commands::maa_agent::maa_start_tasks,
             commands::maa_agent::maa_stop_agent,
+            commands::report::generate_task_report,
+            commands::report::set_task_report_capture,
+            // 断点调试命令
+            commands::breakpoint::set_breakpoints,
+            commands::breakpoint::get_breakpoints,
+            commands::breakpoint::continue_from_breakpoint,
+            // dry-run 模式命令
+            commands::dry_run::set_dry_run,
+            commands::dry_run::get_dry_run,
+            commands::capture_mode::set_capture_mode,
+            commands::capture_mode::get_capture_mode,
+            commands::window_state::set_minimized_policy,
+            commands::window_state::get_minimized_policy,
+            commands::frame_dedup::set_frame_dedup,
+            commands::frame_dedup::get_frame_dedup,
+            commands::unknown_screens::set_unknown_screen_capture,
+            commands::unknown_screens::get_unknown_screen_capture,
+            commands::unknown_screens::get_unknown_screens_info,
+            commands::screenshot_preprocess::set_screenshot_preprocess,
+            commands::screenshot_preprocess::get_screenshot_preprocess,
+            // 看门狗命令
+            commands::watchdog::set_watchdog,
+            commands::watchdog::get_watchdog,
+            // 性能测试命令
+            commands::benchmark::maa_benchmark,
+            // 分辨率预检命令
+            commands::resolution_check::maa_check_resolution,
+            // 运行录像命令
+            commands::recording::start_recording_clip,
+            commands::recording::stop_and_export,
+            commands::recording::is_recording_clip,
+            // ADB 文件传输命令
+            commands::adb_files::maa_adb_push,
+            commands::adb_files::maa_adb_pull,
             // 文件操作命令
             commands::file_ops::read_local_file,
             commands::file_ops::read_local_file_base64,
@@ -251,18 +408,32 @@ pub fn run() {
             commands::file_ops::get_data_dir,
             commands::file_ops::clear_log_files,
             commands::file_ops::get_cwd,
+            commands::file_ops::get_app_paths,
             commands::file_ops::check_exe_path,
             commands::file_ops::set_executable,
             commands::file_ops::export_logs,
+            commands::issue_bundle::export_issue_bundle,
+            commands::unknown_screens::export_unknown_screens,
+            commands::share_code::export_share_code,
+            commands::share_code::import_share_code,
             // 状态查询命令
+            commands::state::maa_ping,
             commands::state::maa_get_instance_state,
             commands::state::maa_get_all_states,
+            commands::state::maa_list_instances,
             commands::state::maa_get_cached_adb_devices,
             commands::state::maa_get_cached_win32_windows,
             commands::state::maa_get_cached_wlroots_sockets,
             commands::state::log_to_stdout,
             commands::state::push_log,
+            commands::state::jump_to_log,
+            commands::log_overlay::open_log_overlay,
+            commands::log_overlay::close_log_overlay,
+            commands::log_overlay::is_log_overlay_open,
+            commands::log_overlay::set_auto_show_overlay,
+            commands::log_overlay::set_overlay_preview,
             commands::state::get_all_logs,
+            commands::state::get_merged_logs,
             commands::state::clear_instance_logs,
             // 更新安装命令
             commands::update::extract_zip,
@@ -271,51 +442,102 @@ pub fn run() {
             commands::update::apply_full_update,
             commands::update::cleanup_extract_dir,
             commands::update::fallback_update,
+            // 资源更新命令
+            commands::resource_update::check_resource_update,
+            commands::resource_update::download_resource_update,
             commands::update::move_file_to_old,
             commands::update::cleanup_update_artifacts,
             // 下载命令
             commands::download::get_github_release_by_version,
+            commands::download::get_network_status,
+            commands::proxy::get_system_proxy,
+            commands::proxy::set_saved_proxy,
             commands::download::download_file,
             commands::download::cancel_download,
             // 系统相关命令
             commands::system::is_elevated,
             commands::system::is_workstation_locked,
             commands::system::is_autostart,
+            commands::system::wait_autostart_delay,
             commands::system::get_start_instance,
             commands::system::has_quit_after_run_flag,
             commands::system::restart_as_admin,
+            commands::system::restart_app,
             commands::system::maa_set_save_draw,
+            commands::system::set_log_level,
+            commands::event_log::set_event_log_enabled,
+            commands::event_log::get_event_log_enabled,
+            commands::failure_screenshot::set_failure_screenshot_enabled,
+            commands::failure_screenshot::get_failure_screenshot_enabled,
+            commands::action_trace::set_action_trace_enabled,
+            commands::action_trace::get_action_trace_enabled,
+            commands::agent_job::set_agent_resource_limits,
+            commands::agent_job::get_agent_resource_limits,
+            commands::toolkit_config::set_toolkit_config,
+            commands::toolkit_config::get_toolkit_config,
+            commands::process_info::get_process_info,
+            commands::process_info::get_monitored_processes,
+            commands::task_retry::set_task_retry_policy,
+            commands::task_retry::get_task_retry_policy,
+            commands::task_condition::check_skip_condition,
+            commands::reco_stats::maa_get_reco_stats,
+            commands::reco_stats::maa_reset_reco_stats,
+            commands::node_timing::maa_get_node_timing,
+            commands::node_timing::maa_reset_node_timing,
+            commands::last_frame::maa_get_last_frame,
+            commands::speed::maa_set_speed,
+            commands::speed::maa_get_speed,
+            commands::annotated_screenshot::maa_save_annotated_screenshot,
             commands::system::open_file,
+            commands::system::open_folder_and_select,
+            commands::system::copy_text_to_clipboard,
+            commands::completion_webhook::set_completion_webhook,
             commands::system::run_and_wait,
             commands::system::set_pre_action_stop,
             commands::system::run_action,
             commands::system::run_pretask,
+            commands::program_trust::get_program_trust,
+            commands::program_trust::set_program_trust_mode,
+            commands::program_trust::trust_program,
+            commands::program_trust::untrust_program,
             commands::system::is_process_running,
             commands::system::get_process_path_from_hwnd,
             commands::system::retry_load_maa_library,
             commands::system::check_vcredist_missing,
+            commands::system::check_cpu_features,
             commands::system::autostart_enable,
             commands::system::autostart_disable,
             commands::system::autostart_is_enabled,
             commands::system::get_arch,
+            commands::system::get_native_arch,
             commands::system::get_os,
             commands::system::get_system_info,
+            commands::system::check_exe_volume,
             commands::system::get_web_server_port,
             commands::system::get_local_lan_ip,
             commands::system::get_webview2_dir,
+            commands::system::get_gpu_adapters,
+            commands::system::set_webview_gpu,
+            commands::system::get_webview_gpu_disabled,
+            commands::system::set_webview2_shared,
+            commands::system::get_webview2_shared,
             // 托盘相关命令
             commands::tray::set_minimize_to_tray,
             commands::tray::get_minimize_to_tray,
             commands::tray::update_tray_icon,
             commands::tray::update_tray_tooltip,
+            commands::tray::set_tray_progress,
+            commands::tray::set_tray_language,
             // 配置同步命令（WebUI 实时同步）
             commands::app_config::notify_config_changed,
         ])
         .on_window_event(|window, event| {
             match event {
-                // 窗口关闭请求：检查是否最小化到托盘
+                // 窗口关闭请求：检查是否最小化到托盘，下载/更新进行中时先确认
                 tauri::WindowEvent::CloseRequested { api, .. } => {
-                    if tray::handle_close_requested(window.app_handle()) {
+                    if tray::handle_close_requested(window.app_handle())
+                        || tray::confirm_exit_if_busy(window.app_handle())
+                    {
                         api.prevent_close();
                     }
                 }
@@ -331,3 +553,44 @@ pub fn run() {
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+/// 启动时自动加载 MaaFramework DLL
+fn load_maafw_library(maa_state: &MaaState) {
+    if let Some(err) = commands::system::cpu_unsupported_error() {
+        log::error!("Skip loading MaaFramework: {}", err);
+        return;
+    }
+    if let Ok(maafw_dir) = commands::get_maafw_dir() {
+        if maafw_dir.exists() {
+            #[cfg(windows)]
+            let dll_path = maafw_dir.join("MaaFramework.dll");
+            #[cfg(target_os = "macos")]
+            let dll_path = maafw_dir.join("libMaaFramework.dylib");
+            #[cfg(target_os = "linux")]
+            let dll_path = maafw_dir.join("libMaaFramework.so");
+
+            match maa_framework::load_library(&dll_path) {
+                Ok(()) => {
+                    log::info!("MaaFramework loaded from {:?}", dll_path);
+                    commands::system::sync_maafw_log_level();
+                    // 预先设置 lib_dir，使 HTTP /api/maa/initialized 立即反映加载状态
+                    *maa_state.lib_dir.lock().unwrap() = Some(maafw_dir.clone());
+                }
+                Err(e) => {
+                    log::error!("Failed to load MaaFramework: {}", e);
+                    // 检查是否是 DLL 存在但加载失败的情况（可能是运行库缺失）
+                    if dll_path.exists() {
+                        log::warn!(
+                            "DLLs exist but failed to load, possibly missing VC++ runtime: {}",
+                            e
+                        );
+                        // 设置标记，前端加载完成后会查询此标记
+                        commands::system::set_vcredist_missing(true);
+                    }
+                }
+            }
+        } else {
+            log::warn!("MaaFramework directory not found: {:?}", maafw_dir);
+        }
+    }
+}
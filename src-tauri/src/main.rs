@@ -5,25 +5,31 @@
 mod webview2;
 
 fn main() {
-    let _cli = mxu_lib::commands::system::init_cli();
+    let cli = mxu_lib::commands::system::init_cli();
 
     #[cfg(target_os = "windows")]
     {
-        // 设置 WebView2 数据目录为程序所在目录下的 webview_data 文件夹
-        // 这样可以避免用户名包含特殊字符（如中文）导致 WebView2 无法创建数据目录的问题
+        // 设置 WebView2 数据目录为数据目录（默认即程序所在目录）下的 webview_data 文件夹
+        // 这样可以避免用户名包含特殊字符（如中文）导致 WebView2 无法创建数据目录的问题，
+        // 通过 --data-dir 多开时各实例也不会共用同一个 WebView2 数据目录
         if let Ok(exe_path) = std::env::current_exe() {
             if let Some(exe_dir) = exe_path.parent() {
-                let webview_data_dir = exe_dir.join("cache").join("webview_data");
+                let data_dir = mxu_lib::commands::utils::get_app_data_dir()
+                    .unwrap_or_else(|_| exe_dir.to_path_buf());
+                let webview_data_dir = data_dir.join("cache").join("webview_data");
                 // 确保目录存在
                 let _ = std::fs::create_dir_all(&webview_data_dir);
                 std::env::set_var("WEBVIEW2_USER_DATA_FOLDER", &webview_data_dir);
 
+                // 用户设置了禁用 GPU 加速时强制软件渲染，规避部分显卡驱动导致的花屏/白屏
+                if mxu_lib::commands::system::get_webview_gpu_disabled() {
+                    std::env::set_var("WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS", "--disable-gpu");
+                }
+
                 // 检测已缓存的 WebView2 固定版本运行时
-                // 验证目录包含关键文件以确保运行时完整可用
+                // 验证目录包含关键文件以确保运行时完整可用，损坏的由 ensure_webview2 清理后重装
                 if let Ok(webview2_runtime_dir) = webview2::get_webview2_runtime_dir() {
-                    if webview2_runtime_dir.is_dir()
-                        && webview2_runtime_dir.join("msedgewebview2.exe").exists()
-                    {
+                    if webview2::is_runtime_dir_valid(&webview2_runtime_dir) {
                         std::env::set_var(
                             "WEBVIEW2_BROWSER_EXECUTABLE_FOLDER",
                             &webview2_runtime_dir,
@@ -56,8 +62,16 @@ fn main() {
             use winsafe::co::{SEE_MASK, SW};
             use winsafe::{ShellExecuteEx, SHELLEXECUTEINFO};
 
+            // 透传命令行参数，保证 --data-dir 等参数在提权后仍然生效
+            let parameters = std::env::args()
+                .skip(1)
+                .map(|arg| quote_windows_arg(&arg))
+                .collect::<Vec<_>>()
+                .join(" ");
+
             let result = ShellExecuteEx(&SHELLEXECUTEINFO {
                 file: &exe_path.to_string_lossy(),
+                parameters: (!parameters.is_empty()).then_some(parameters.as_str()),
                 verb: Option::from("runas"),
                 show: SW::SHOWNORMAL,
                 mask: SEE_MASK::NOASYNC | SEE_MASK::FLAG_NO_UI,
@@ -71,5 +85,43 @@ fn main() {
         }
     }
 
+    // 提权重启之后再检测，避免普通权限进程与其拉起的管理员进程互相误判
+    if let mxu_lib::commands::single_instance::InstanceCheck::Exit =
+        mxu_lib::commands::single_instance::check_single_instance(cli.autostart)
+    {
+        return;
+    }
+
     mxu_lib::run()
 }
+
+/// 按 Windows 命令行解析规则（CommandLineToArgvW）为参数加引号，保证新进程解析出相同的参数
+///
+/// 反斜杠只在紧接引号（含结尾补上的引号）时需要加倍，参数中的引号转义为 `\"`
+#[cfg(target_os = "windows")]
+fn quote_windows_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '\n', '\u{b}', '"']) {
+        return arg.to_string();
+    }
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('"');
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
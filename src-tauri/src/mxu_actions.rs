@@ -1,6 +1,11 @@
 //! MXU 内置 Custom Actions
 //!
-//! 提供 MXU 特有的自定义动作实现，如 MXU_SLEEP 等
+//! 提供 MXU 特有的自定义动作实现，如 MXU_SLEEP、MXU_WAIT_UNTIL、MXU_BRANCH、MXU_SET_VAR 等
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Mutex, OnceLock};
 
 use chrono::TimeZone;
 use log::{info, warn};
@@ -188,6 +193,110 @@ fn mxu_waituntil_action_fn(
     true
 }
 
+// ============================================================================
+// MXU_WAIT_UNTIL Custom Action
+// ============================================================================
+
+/// MXU_WAIT_UNTIL 动作名称常量
+const MXU_WAIT_UNTIL_ACTION: &str = "MXU_WAIT_UNTIL_ACTION";
+
+/// 解析 MXU_WAIT_UNTIL 的目标时刻（本地时区）
+/// - `timestamp`：Unix 时间戳（秒），原样使用
+/// - `hour`/`minute`：今天的该时刻，已过则取明天的该时刻
+fn resolve_wait_until_target(
+    json: &serde_json::Value,
+    now: chrono::DateTime<chrono::Local>,
+) -> Result<chrono::DateTime<chrono::Local>, String> {
+    if let Some(ts) = json.get("timestamp").and_then(|v| v.as_i64()) {
+        return chrono::Local
+            .timestamp_opt(ts, 0)
+            .single()
+            .ok_or_else(|| format!("Invalid timestamp: {}", ts));
+    }
+
+    let hour = json
+        .get("hour")
+        .and_then(|v| v.as_u64())
+        .filter(|h| *h < 24)
+        .ok_or("Missing or invalid 'hour' parameter")? as u32;
+    let minute = match json.get("minute") {
+        None => 0,
+        Some(v) => v
+            .as_u64()
+            .filter(|m| *m < 60)
+            .ok_or("Invalid 'minute' parameter")? as u32,
+    };
+
+    let naive = now
+        .date_naive()
+        .and_hms_opt(hour, minute, 0)
+        .ok_or_else(|| format!("Invalid target time {:02}:{:02}", hour, minute))?;
+    let today_target = chrono::Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .ok_or_else(|| {
+            format!(
+                "Invalid local time {:02}:{:02} (e.g. due to DST transition)",
+                hour, minute
+            )
+        })?;
+
+    if today_target > now {
+        Ok(today_target)
+    } else {
+        Ok(today_target + chrono::Duration::days(1))
+    }
+}
+
+/// MXU_WAIT_UNTIL custom action 回调函数
+/// 从 custom_action_param 中读取 `hour`/`minute` 或 `timestamp`，阻塞到目标时刻后返回成功
+/// 目标时刻已过则立即继续，等待期间响应 stop
+fn mxu_wait_until_action_fn(
+    ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+) -> bool {
+    let param_str = args.param;
+    info!("[MXU_WAIT_UNTIL] Received param: {}", param_str);
+
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(param_str) else {
+        warn!("[MXU_WAIT_UNTIL] Failed to parse param JSON");
+        return false;
+    };
+
+    let now = chrono::Local::now();
+    let target = match resolve_wait_until_target(&json, now) {
+        Ok(t) => t,
+        Err(e) => {
+            warn!("[MXU_WAIT_UNTIL] {}", e);
+            return false;
+        }
+    };
+
+    if target <= now {
+        info!(
+            "[MXU_WAIT_UNTIL] Target {} already passed, continuing",
+            target.format("%Y-%m-%d %H:%M:%S")
+        );
+        return true;
+    }
+
+    // 按剩余毫秒向上取整到秒，避免提前几百毫秒返回
+    let wait_secs = ((target - now).num_milliseconds().max(0) as u64).div_ceil(1000);
+    info!(
+        "[MXU_WAIT_UNTIL] Waiting until {} ({}s from now)",
+        target.format("%Y-%m-%d %H:%M:%S"),
+        wait_secs
+    );
+
+    if !wait_with_stop_check(ctx, wait_secs) {
+        warn!("[MXU_WAIT_UNTIL] Interrupted by stop request");
+        return false;
+    }
+
+    info!("[MXU_WAIT_UNTIL] Wait completed, target time reached");
+    true
+}
+
 // ============================================================================
 // MXU_LAUNCH Custom Action
 // ============================================================================
@@ -197,9 +306,13 @@ const MXU_LAUNCH_ACTION: &str = "MXU_LAUNCH_ACTION";
 
 /// MXU_LAUNCH custom action 回调函数
 /// 从 custom_action_param 中读取 program, args, wait_for_exit，启动外部程序
-fn mxu_launch_action_fn(
-    _ctx: &maa_framework::context::Context,
+/// hide_window 为 true 时隐藏控制台窗口（仅 Windows，默认显示）
+/// cwd 为工作目录（可选，默认为程序所在目录；指定了但不存在时动作失败）
+/// 启动前按外部程序信任策略校验，不允许执行时动作失败
+fn mxu_launch_action_impl(
     args: &maa_framework::custom::ActionArgs,
+    app_handle: &AppHandle,
+    instance_id: &str,
 ) -> bool {
     let param_str = args.param;
     info!("[MXU_LAUNCH] Received param: {}", param_str);
@@ -241,6 +354,11 @@ fn mxu_launch_action_fn(
         .and_then(|v| v.as_bool())
         .unwrap_or(false);
 
+    let hide_window = json
+        .get("hide_window")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
     // 如果启用了跳过检查且程序已在运行，直接返回成功
     if skip_if_running {
         if crate::commands::system::check_process_running(&program) {
@@ -252,6 +370,17 @@ fn mxu_launch_action_fn(
         }
     }
 
+    if let Err(e) = crate::commands::program_trust::ensure_program_allowed(
+        app_handle,
+        Some(instance_id),
+        &program,
+        use_cmd.then_some(args_str.as_str()),
+        "MXU_LAUNCH",
+    ) {
+        log::error!("[MXU_LAUNCH] {}", e);
+        return false;
+    }
+
     info!(
         "[MXU_LAUNCH] Launching: program={}, args={}, wait_for_exit={}",
         program, args_str, wait_for_exit
@@ -272,10 +401,25 @@ fn mxu_launch_action_fn(
         }
     };
 
-    let mut cmd = crate::commands::utils::build_launch_command(&program, &args_vec, use_cmd);
+    // 显式指定的工作目录必须存在，不回退到程序所在目录
+    let cwd = json
+        .get("cwd")
+        .and_then(|v| v.as_str())
+        .filter(|dir| !dir.trim().is_empty());
+    if let Some(dir) = cwd {
+        if let Err(e) = crate::commands::utils::validate_cwd(dir) {
+            log::error!("[MXU_LAUNCH] {}", e);
+            return false;
+        }
+    }
+
+    let mut cmd =
+        crate::commands::utils::build_launch_command(&program, &args_vec, use_cmd, hide_window);
 
-    // 默认使用程序所在目录作为工作目录
-    if let Some(parent) = std::path::Path::new(&program).parent() {
+    // 设置工作目录（未指定时默认使用程序所在目录）
+    if let Some(dir) = cwd {
+        cmd.current_dir(dir);
+    } else if let Some(parent) = std::path::Path::new(&program).parent() {
         if parent.exists() {
             cmd.current_dir(parent);
         }
@@ -833,6 +977,490 @@ fn execute_power_sleep() -> bool {
     }
 }
 
+// ============================================================================
+// MXU_BRANCH Custom Action
+// ============================================================================
+
+/// MXU_BRANCH 动作名称常量
+const MXU_BRANCH_ACTION: &str = "MXU_BRANCH_ACTION";
+
+/// MXU_BRANCH 计数器（进程内有效，按 key 区分）
+static BRANCH_COUNTERS: OnceLock<Mutex<HashMap<String, u64>>> = OnceLock::new();
+
+/// MXU_BRANCH custom action 回调函数
+/// 根据 condition 判断返回成功/失败，供 pipeline 通过 next / on_error 分流：
+/// - `hour`：当前小时在 [start, end) 内（支持跨零点，如 22 → 6）
+/// - `weekday`：今天在 days 列表中（1 = 周一 … 7 = 周日）
+/// - `counter`：计数器 key 自增后，是 every 的倍数或不超过 limit
+/// - `random`：以 probability（0~1）概率成功
+/// - `var`：实例变量 key 满足 equals / min / max（见 MXU_GET_VAR）
+///
+/// 参数缺失或无法判断时返回 default（默认 false）并输出警告
+fn mxu_branch_action_impl(args: &maa_framework::custom::ActionArgs, instance_id: &str) -> bool {
+    let param_str = args.param;
+    info!("[MXU_BRANCH] Received param: {}", param_str);
+
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(param_str) else {
+        warn!("[MXU_BRANCH] Failed to parse param JSON, fallback to false");
+        return false;
+    };
+
+    let fallback = json
+        .get("default")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let condition = json
+        .get("condition")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    let result = match condition.as_str() {
+        "hour" => branch_by_hour(&json),
+        "weekday" => branch_by_weekday(&json),
+        "counter" => branch_by_counter(&json),
+        "random" => branch_by_random(&json),
+        "var" => branch_by_var(&json, instance_id),
+        other => Err(format!("Unknown condition: '{}'", other)),
+    };
+
+    match result {
+        Ok(matched) => {
+            info!("[MXU_BRANCH] Condition '{}' => {}", condition, matched);
+            matched
+        }
+        Err(e) => {
+            warn!("[MXU_BRANCH] {}, fallback to {}", e, fallback);
+            fallback
+        }
+    }
+}
+
+fn branch_by_hour(json: &serde_json::Value) -> Result<bool, String> {
+    let read_hour = |key: &str| -> Result<u32, String> {
+        json.get(key)
+            .and_then(|v| v.as_u64())
+            .filter(|h| *h <= 24)
+            .map(|h| h as u32)
+            .ok_or_else(|| format!("Missing or invalid '{}' (expected 0-24)", key))
+    };
+    let start = read_hour("start")?;
+    let end = read_hour("end")?;
+
+    let hour = chrono::Timelike::hour(&chrono::Local::now());
+    Ok(if start <= end {
+        hour >= start && hour < end
+    } else {
+        // 跨零点区间，如 22 → 6
+        hour >= start || hour < end
+    })
+}
+
+fn branch_by_weekday(json: &serde_json::Value) -> Result<bool, String> {
+    let days: Vec<u32> = json
+        .get("days")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|d| d.as_u64())
+                .filter(|d| (1..=7).contains(d))
+                .map(|d| d as u32)
+                .collect()
+        })
+        .unwrap_or_default();
+    if days.is_empty() {
+        return Err("Missing or invalid 'days' (expected 1-7, Monday = 1)".to_string());
+    }
+
+    let today = chrono::Datelike::weekday(&chrono::Local::now()).number_from_monday();
+    Ok(days.contains(&today))
+}
+
+fn branch_by_counter(json: &serde_json::Value) -> Result<bool, String> {
+    let key = json
+        .get("key")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.trim().is_empty())
+        .ok_or("Missing or empty 'key'")?;
+    let every = json
+        .get("every")
+        .and_then(|v| v.as_u64())
+        .filter(|n| *n > 0);
+    let limit = json.get("limit").and_then(|v| v.as_u64());
+    if every.is_none() && limit.is_none() {
+        return Err("Counter requires 'every' or 'limit'".to_string());
+    }
+
+    let mut counters = BRANCH_COUNTERS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .map_err(|e| format!("Failed to lock counters: {}", e))?;
+    let count = counters.entry(key.to_string()).or_insert(0);
+    *count += 1;
+    info!("[MXU_BRANCH] Counter '{}' = {}", key, count);
+
+    Ok(match every {
+        Some(every) => *count % every == 0,
+        None => limit.is_some_and(|limit| *count <= limit),
+    })
+}
+
+fn branch_by_random(json: &serde_json::Value) -> Result<bool, String> {
+    let probability = json
+        .get("probability")
+        .and_then(|v| v.as_f64())
+        .filter(|p| (0.0..=1.0).contains(p))
+        .ok_or("Missing or invalid 'probability' (expected 0-1)")?;
+
+    // 不额外引入 rand 依赖：用带随机种子的 RandomState 对当前时间取哈希
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+    );
+    let roll = (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64;
+    Ok(roll < probability)
+}
+
+fn branch_by_var(json: &serde_json::Value, instance_id: &str) -> Result<bool, String> {
+    let key = var_key(json)?;
+    let value = get_task_var(instance_id, key);
+    check_var(json, value.as_ref())
+}
+
+// ============================================================================
+// MXU_SET_VAR / MXU_GET_VAR / MXU_INC_VAR Custom Actions
+// ============================================================================
+
+/// 实例变量动作名称常量
+const MXU_SET_VAR_ACTION: &str = "MXU_SET_VAR_ACTION";
+const MXU_GET_VAR_ACTION: &str = "MXU_GET_VAR_ACTION";
+const MXU_INC_VAR_ACTION: &str = "MXU_INC_VAR_ACTION";
+
+/// 持久化变量的保存文件（位于数据目录）
+const TASK_VARS_FILE: &str = "task_vars.json";
+
+/// 实例变量值，persist 为 false 时在任务结束时清理
+struct TaskVar {
+    value: serde_json::Value,
+    persist: bool,
+}
+
+impl TaskVar {
+    fn persistent(value: serde_json::Value) -> Self {
+        Self {
+            value,
+            persist: true,
+        }
+    }
+}
+
+/// 各实例的变量（instance_id -> key -> 变量），首次访问时载入持久化变量
+static TASK_VARS: OnceLock<Mutex<HashMap<String, HashMap<String, TaskVar>>>> = OnceLock::new();
+
+fn task_vars() -> &'static Mutex<HashMap<String, HashMap<String, TaskVar>>> {
+    TASK_VARS.get_or_init(|| {
+        let persisted: HashMap<String, HashMap<String, serde_json::Value>> = task_vars_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        let vars = persisted
+            .into_iter()
+            .map(|(instance_id, vars)| {
+                let vars = vars
+                    .into_iter()
+                    .map(|(key, value)| (key, TaskVar::persistent(value)))
+                    .collect();
+                (instance_id, vars)
+            })
+            .collect();
+        Mutex::new(vars)
+    })
+}
+
+fn task_vars_path() -> Option<std::path::PathBuf> {
+    crate::commands::utils::get_app_data_dir()
+        .ok()
+        .map(|dir| dir.join(TASK_VARS_FILE))
+}
+
+/// 写入所有持久化变量，失败只记录日志
+fn save_persistent_vars(vars: &HashMap<String, HashMap<String, TaskVar>>) {
+    let persisted: HashMap<&String, HashMap<&String, &serde_json::Value>> = vars
+        .iter()
+        .map(|(instance_id, vars)| {
+            let vars: HashMap<_, _> = vars
+                .iter()
+                .filter(|(_, var)| var.persist)
+                .map(|(key, var)| (key, &var.value))
+                .collect();
+            (instance_id, vars)
+        })
+        .filter(|(_, vars)| !vars.is_empty())
+        .collect();
+    let Some(path) = task_vars_path() else {
+        return;
+    };
+    let result = serde_json::to_string_pretty(&persisted)
+        .map_err(|e| e.to_string())
+        .and_then(|content| std::fs::write(&path, content).map_err(|e| e.to_string()));
+    if let Err(e) = result {
+        warn!("[MXU_VAR] Failed to save persistent vars: {}", e);
+    }
+}
+
+/// 读取实例变量
+pub(crate) fn get_task_var(instance_id: &str, key: &str) -> Option<serde_json::Value> {
+    let vars = task_vars().lock().ok()?;
+    vars.get(instance_id)?.get(key).map(|var| var.value.clone())
+}
+
+/// 写入实例变量，value 为 null 时删除；persist 为 None 时沿用已有变量的设置
+fn set_task_var(instance_id: &str, key: &str, value: serde_json::Value, persist: Option<bool>) {
+    let Ok(mut vars) = task_vars().lock() else {
+        return;
+    };
+    let instance_vars = vars.entry(instance_id.to_string()).or_default();
+    let was_persist = instance_vars.get(key).is_some_and(|var| var.persist);
+    let persist = persist.unwrap_or(was_persist);
+    if value.is_null() {
+        instance_vars.remove(key);
+    } else {
+        instance_vars.insert(key.to_string(), TaskVar { value, persist });
+    }
+    if persist || was_persist {
+        save_persistent_vars(&vars);
+    }
+}
+
+/// 清理实例的非持久化变量（任务结束时调用）
+pub(crate) fn clear_task_vars(instance_id: &str) {
+    if let Ok(mut vars) = task_vars().lock() {
+        if let Some(instance_vars) = vars.get_mut(instance_id) {
+            instance_vars.retain(|_, var| var.persist);
+        }
+    }
+}
+
+fn var_key(json: &serde_json::Value) -> Result<&str, String> {
+    json.get("key")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| "Missing or empty 'key'".to_string())
+}
+
+/// 判断变量是否满足条件：
+/// - `equals`：与给定值相等（数字按数值比较）
+/// - `min` / `max`：数值在闭区间内
+/// - 都未指定时：变量存在且不是 false / 0 / 空字符串
+fn check_var(json: &serde_json::Value, value: Option<&serde_json::Value>) -> Result<bool, String> {
+    let equals = json.get("equals");
+    let min = json.get("min").and_then(|v| v.as_f64());
+    let max = json.get("max").and_then(|v| v.as_f64());
+
+    let Some(value) = value else {
+        return Ok(false);
+    };
+    if equals.is_none() && min.is_none() && max.is_none() {
+        return Ok(match value {
+            serde_json::Value::Bool(b) => *b,
+            serde_json::Value::Number(n) => n.as_f64() != Some(0.0),
+            serde_json::Value::String(s) => !s.is_empty(),
+            _ => true,
+        });
+    }
+
+    if let Some(expected) = equals {
+        let matched = match (value.as_f64(), expected.as_f64()) {
+            (Some(a), Some(b)) => a == b,
+            _ => value == expected,
+        };
+        if !matched {
+            return Ok(false);
+        }
+    }
+    if min.is_some() || max.is_some() {
+        let Some(number) = value.as_f64() else {
+            return Err(format!("Var value {} is not a number", value));
+        };
+        if min.is_some_and(|min| number < min) || max.is_some_and(|max| number > max) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn parse_var_param(args: &maa_framework::custom::ActionArgs) -> Option<serde_json::Value> {
+    match serde_json::from_str::<serde_json::Value>(args.param) {
+        Ok(json) => Some(json),
+        Err(e) => {
+            warn!("[MXU_VAR] Failed to parse param JSON: {}", e);
+            None
+        }
+    }
+}
+
+/// MXU_SET_VAR custom action 回调函数
+/// 参数：key、value（任意 JSON，null 表示删除）、persist（是否跨任务保留并写入磁盘，默认 false）
+fn mxu_set_var_action_impl(args: &maa_framework::custom::ActionArgs, instance_id: &str) -> bool {
+    let Some(json) = parse_var_param(args) else {
+        return false;
+    };
+    let key = match var_key(&json) {
+        Ok(key) => key,
+        Err(e) => {
+            warn!("[MXU_SET_VAR] {}", e);
+            return false;
+        }
+    };
+    let value = json.get("value").cloned().unwrap_or_default();
+    let persist = json.get("persist").and_then(|v| v.as_bool());
+    info!("[MXU_SET_VAR] {} = {}", key, value);
+    set_task_var(instance_id, key, value, persist);
+    true
+}
+
+/// MXU_GET_VAR custom action 回调函数
+/// 参数：key 及可选的 equals / min / max，变量满足条件时成功，否则失败（供 next / on_error 分流）
+fn mxu_get_var_action_impl(args: &maa_framework::custom::ActionArgs, instance_id: &str) -> bool {
+    let Some(json) = parse_var_param(args) else {
+        return false;
+    };
+    let result = var_key(&json).and_then(|key| {
+        let value = get_task_var(instance_id, key);
+        info!(
+            "[MXU_GET_VAR] {} = {}",
+            key,
+            value
+                .as_ref()
+                .map_or("<unset>".to_string(), |v| v.to_string())
+        );
+        check_var(&json, value.as_ref())
+    });
+    match result {
+        Ok(matched) => matched,
+        Err(e) => {
+            warn!("[MXU_GET_VAR] {}, fallback to false", e);
+            false
+        }
+    }
+}
+
+/// MXU_INC_VAR custom action 回调函数
+/// 参数：key、step（默认 1）、limit（可选）、persist（同 MXU_SET_VAR）
+/// 变量不存在时从 0 开始；指定 limit 时自增后超过 limit 返回失败，可用于"执行 N 次后停止"
+fn mxu_inc_var_action_impl(args: &maa_framework::custom::ActionArgs, instance_id: &str) -> bool {
+    let Some(json) = parse_var_param(args) else {
+        return false;
+    };
+    let key = match var_key(&json) {
+        Ok(key) => key,
+        Err(e) => {
+            warn!("[MXU_INC_VAR] {}", e);
+            return false;
+        }
+    };
+    let step = json.get("step").and_then(|v| v.as_i64()).unwrap_or(1);
+    let limit = json.get("limit").and_then(|v| v.as_i64());
+    let persist = json.get("persist").and_then(|v| v.as_bool());
+
+    let current = match get_task_var(instance_id, key) {
+        None => 0,
+        Some(value) => match value.as_i64() {
+            Some(n) => n,
+            None => {
+                warn!("[MXU_INC_VAR] Var '{}' is not an integer: {}", key, value);
+                return false;
+            }
+        },
+    };
+    let next = current.saturating_add(step);
+    set_task_var(instance_id, key, serde_json::Value::from(next), persist);
+    info!("[MXU_INC_VAR] {} = {}", key, next);
+
+    limit.is_none_or(|limit| next <= limit)
+}
+
+// ============================================================================
+// MXU_DRY_RUN Custom Action
+// ============================================================================
+
+/// MXU_DRY_RUN 动作名称常量（dry-run 模式下替换所有节点的原动作）
+pub(crate) const MXU_DRY_RUN_ACTION: &str = "MXU_DRY_RUN_ACTION";
+
+const MXU_DRY_RUN_EVENT: &str = "maa-dry-run-action";
+
+/// dry-run 动作事件载荷
+#[derive(Clone, Serialize)]
+struct DryRunActionEvent {
+    instance_id: String,
+    node: String,
+    /// 原本要执行的动作类型
+    action: String,
+    /// 原动作的目标坐标（固定坐标 [x, y] 或 [x, y, w, h] 的中心点），为空表示作用于识别命中位置
+    target: Option<(i64, i64)>,
+    /// 原动作的关键参数（target / begin / end / key / input_text / custom_action 等）
+    detail: serde_json::Value,
+}
+
+/// 解析固定坐标形式的 target，返回点击点
+fn dry_run_target_point(target: Option<&serde_json::Value>) -> Option<(i64, i64)> {
+    let coords: Vec<i64> = target?
+        .as_array()?
+        .iter()
+        .map(|v| v.as_i64())
+        .collect::<Option<_>>()?;
+    match coords.as_slice() {
+        [x, y] => Some((*x, *y)),
+        [x, y, w, h] => Some((x + w / 2, y + h / 2)),
+        _ => None,
+    }
+}
+
+/// MXU_DRY_RUN custom action 回调函数
+/// 从 custom_action_param 中读取原节点名、动作类型和参数，只上报不执行，始终返回成功
+fn mxu_dry_run_action_impl(
+    args: &maa_framework::custom::ActionArgs,
+    app_handle: &AppHandle,
+    instance_id: &str,
+) -> bool {
+    let json: serde_json::Value = serde_json::from_str(args.param).unwrap_or_default();
+    let node = json
+        .get("node")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    let action = json
+        .get("action")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    let detail = json.get("detail").cloned().unwrap_or_default();
+    let target = dry_run_target_point(detail.get("target"));
+
+    match target {
+        Some((x, y)) => info!("[dry-run] {} 将执行 {} ({}, {})", node, action, x, y),
+        None => info!("[dry-run] {} 将执行 {} {}", node, action, detail),
+    }
+
+    if let Err(e) = app_handle.emit(
+        MXU_DRY_RUN_EVENT,
+        DryRunActionEvent {
+            instance_id: instance_id.to_string(),
+            node,
+            action,
+            target,
+            detail,
+        },
+    ) {
+        warn!("[MXU_DRY_RUN] Failed to emit dry-run event: {}", e);
+    }
+    true
+}
+
 // ============================================================================
 // 注册入口
 // ============================================================================
@@ -878,11 +1506,42 @@ pub fn register_all_mxu_actions(
 
     reg_action!(MXU_SLEEP_ACTION, mxu_sleep_action_fn);
     reg_action!(MXU_WAITUNTIL_ACTION, mxu_waituntil_action_fn);
-    reg_action!(MXU_LAUNCH_ACTION, mxu_launch_action_fn);
+    reg_action!(MXU_WAIT_UNTIL_ACTION, mxu_wait_until_action_fn);
     reg_action!(MXU_WEBHOOK_ACTION, mxu_webhook_action_fn);
     reg_action!(MXU_NOTIFY_ACTION, mxu_notify_action_fn);
     reg_action!(MXU_POWER_ACTION, mxu_power_action_fn);
 
+    // 需要区分实例的动作（实例变量）
+    macro_rules! reg_instance_action {
+        ($name:expr, $fn_name:expr) => {
+            let action_instance_id = instance_id.to_string();
+            let wrapper = move |_ctx: &maa_framework::context::Context,
+                                args: &maa_framework::custom::ActionArgs|
+                  -> bool {
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    $fn_name(args, &action_instance_id)
+                }))
+                .unwrap_or_else(|_| {
+                    log::error!("[MXU] Custom action {} panicked", $name);
+                    false
+                })
+            };
+
+            if let Err(e) = resource.register_custom_action($name, Box::new(FnAction::new(wrapper)))
+            {
+                warn!("[MXU] Failed to register {}: {:?}", $name, e);
+                failed_count += 1;
+            } else {
+                info!("[MXU] Custom action {} registered successfully", $name);
+            }
+        };
+    }
+
+    reg_instance_action!(MXU_BRANCH_ACTION, mxu_branch_action_impl);
+    reg_instance_action!(MXU_SET_VAR_ACTION, mxu_set_var_action_impl);
+    reg_instance_action!(MXU_GET_VAR_ACTION, mxu_get_var_action_impl);
+    reg_instance_action!(MXU_INC_VAR_ACTION, mxu_inc_var_action_impl);
+
     let killproc_app_handle = app_handle.clone();
     let killproc_instance_id = instance_id.to_string();
     let killproc_wrapper = move |ctx: &maa_framework::context::Context,
@@ -926,6 +1585,58 @@ pub fn register_all_mxu_actions(
         );
     }
 
+    let launch_app_handle = app_handle.clone();
+    let launch_instance_id = instance_id.to_string();
+    let launch_wrapper = move |_ctx: &maa_framework::context::Context,
+                               args: &maa_framework::custom::ActionArgs|
+          -> bool {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            mxu_launch_action_impl(args, &launch_app_handle, &launch_instance_id)
+        }))
+        .unwrap_or_else(|_| {
+            log::error!("[MXU] Custom action {} panicked", MXU_LAUNCH_ACTION);
+            false
+        })
+    };
+
+    if let Err(e) =
+        resource.register_custom_action(MXU_LAUNCH_ACTION, Box::new(FnAction::new(launch_wrapper)))
+    {
+        warn!("[MXU] Failed to register {}: {:?}", MXU_LAUNCH_ACTION, e);
+        failed_count += 1;
+    } else {
+        info!(
+            "[MXU] Custom action {} registered successfully",
+            MXU_LAUNCH_ACTION
+        );
+    }
+
+    let dry_run_app_handle = app_handle.clone();
+    let dry_run_instance_id = instance_id.to_string();
+    let dry_run_wrapper = move |_ctx: &maa_framework::context::Context,
+                                args: &maa_framework::custom::ActionArgs|
+          -> bool {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            mxu_dry_run_action_impl(args, &dry_run_app_handle, &dry_run_instance_id)
+        }))
+        .unwrap_or_else(|_| {
+            log::error!("[MXU] Custom action {} panicked", MXU_DRY_RUN_ACTION);
+            false
+        })
+    };
+
+    if let Err(e) = resource
+        .register_custom_action(MXU_DRY_RUN_ACTION, Box::new(FnAction::new(dry_run_wrapper)))
+    {
+        warn!("[MXU] Failed to register {}: {:?}", MXU_DRY_RUN_ACTION, e);
+        failed_count += 1;
+    } else {
+        info!(
+            "[MXU] Custom action {} registered successfully",
+            MXU_DRY_RUN_ACTION
+        );
+    }
+
     if failed_count > 0 {
         warn!(
             "[MXU] Failed to register {} custom actions, continuing anyway",
@@ -10,6 +10,8 @@
 //! - `subscribe` 确保截图循环仅被启动一次（CAS 原子操作）。
 //! - 截图循环每次迭代清理超时（30s 无活动）的订阅者，按最小间隔驱动 `post_screencap`。
 //! - 任务运行中跳过 `post_screencap`（任务管线已自动更新缓存）。
+//! - 主窗口最小化到托盘时暂停本地 UI 预览订阅者（Web UI 订阅者不受影响）；
+//!   任务逻辑所需的截图由任务管线自行驱动，不经过本服务，因此也不受影响。
 
 use std::collections::HashMap;
 use std::sync::{
@@ -24,6 +26,8 @@ use crate::commands::types::MaaState;
 const SUBSCRIBER_TIMEOUT_SECS: u64 = 30;
 /// 截图最小间隔（ms），防止后端被过度请求。
 const MIN_INTERVAL_MS: u64 = 100;
+/// 本地预览暂停且无其他订阅者时，循环检查恢复状态的间隔（ms）。
+const PAUSED_POLL_MS: u64 = 500;
 
 // ============================================================================
 // 数据结构
@@ -32,6 +36,8 @@ const MIN_INTERVAL_MS: u64 = 100;
 struct ScreenshotSubscriber {
     interval_ms: u64,
     last_active: Instant,
+    /// 是否为主窗口内的 UI 预览订阅者（最小化到托盘时暂停）
+    local: bool,
 }
 
 struct InstanceScreenshotStream {
@@ -43,6 +49,8 @@ struct InstanceScreenshotStream {
 #[derive(Default)]
 pub struct ScreenshotService {
     streams: Mutex<HashMap<String, Arc<InstanceScreenshotStream>>>,
+    /// 本地 UI 预览是否暂停（主窗口最小化到托盘时为 true）
+    local_paused: AtomicBool,
 }
 
 // ============================================================================
@@ -54,6 +62,7 @@ impl ScreenshotService {
     ///
     /// 若该实例的截图循环尚未启动，则自动以最小订阅间隔驱动启动。
     /// 若 `subscriber_id` 已存在，则更新其 `interval_ms`（帧率变更时复用）。
+    /// `local` 表示订阅者是否为主窗口内的 UI 预览（Tauri 命令订阅为 true，Web UI 为 false）。
     /// `handle` 是调用方提供的 tokio runtime handle，用于 spawn 截图循环。
     /// 调用方（async Tauri 命令或 axum handler）均运行在 tokio 上下文中，可通过
     /// `tokio::runtime::Handle::current()` 获取。
@@ -63,6 +72,7 @@ impl ScreenshotService {
         instance_id: String,
         subscriber_id: String,
         interval_ms: u64,
+        local: bool,
         handle: tokio::runtime::Handle,
     ) {
         let stream = {
@@ -85,6 +95,7 @@ impl ScreenshotService {
                 ScreenshotSubscriber {
                     interval_ms: interval_ms.max(MIN_INTERVAL_MS),
                     last_active: Instant::now(),
+                    local,
                 },
             );
         }
@@ -107,6 +118,19 @@ impl ScreenshotService {
         }
     }
 
+    /// 暂停/恢复本地 UI 预览订阅者。
+    ///
+    /// 暂停期间截图循环仅按 Web UI 订阅者驱动；没有其他订阅者时循环空转等待恢复，
+    /// 订阅关系保持不变，恢复后无需前端重新订阅。
+    pub fn set_local_paused(&self, paused: bool) {
+        if self.local_paused.swap(paused, Ordering::SeqCst) != paused {
+            log::info!(
+                "[screenshot] local preview {}",
+                if paused { "paused" } else { "resumed" }
+            );
+        }
+    }
+
     /// 刷新订阅者的活跃时间戳，防止被 30s 超时清理。
     ///
     /// Web UI 可在每次 GET /screenshot 时调用，也可不调用（依赖显式 unsubscribe）。
@@ -140,7 +164,12 @@ fn capture_loop(
         loop {
             // 1. 清理超时订阅者，计算当前最小间隔
             //    MutexGuard 在块末尾 drop，不跨越 .await
-            let min_interval_ms: u64 = {
+            //    本地预览暂停时不计入本地订阅者；无有效订阅者时本轮不截图
+            let local_paused = maa_state
+                .screenshot_service
+                .local_paused
+                .load(Ordering::SeqCst);
+            let (min_interval_ms, has_active_subs): (u64, bool) = {
                 let mut subs = stream.subscribers.lock().unwrap();
                 let now = Instant::now();
                 subs.retain(|_, sub| {
@@ -149,11 +178,15 @@ fn capture_loop(
                 if subs.is_empty() {
                     break;
                 }
-                subs.values()
+                let min_active = subs
+                    .values()
+                    .filter(|s| !(local_paused && s.local))
                     .map(|s| s.interval_ms)
-                    .min()
-                    .unwrap_or(1000)
-                    .max(MIN_INTERVAL_MS)
+                    .min();
+                (
+                    min_active.unwrap_or(PAUSED_POLL_MS).max(MIN_INTERVAL_MS),
+                    min_active.is_some(),
+                )
             };
 
             // 2. 检查实例状态，获取截图决策（仅用 bool 传递，不持有 Controller）
@@ -192,7 +225,7 @@ fn capture_loop(
 
             // 3. 需要截图时，重新加锁获取 Controller 引用并调用 post_screencap
             //    MutexGuard 及 Controller 引用均在块末尾 drop，不跨越 .await
-            if should_screencap {
+            if should_screencap && has_active_subs {
                 if let Ok(instances) = maa_state.instances.lock() {
                     if let Some(inst) = instances.get(&instance_id) {
                         if let Some(ctrl) = inst.controller.as_ref() {
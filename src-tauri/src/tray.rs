@@ -1,20 +1,184 @@
 use std::sync::{
     atomic::{AtomicBool, Ordering},
-    Mutex, OnceLock,
+    Arc, Mutex, OnceLock,
 };
 use tauri::{
     image::Image,
-    menu::{Menu, MenuItem},
+    menu::{IsMenuItem, Menu, MenuItem, Submenu},
     tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent},
     AppHandle, Emitter, Manager, Wry,
 };
 
+use crate::commands::{
+    download, maa_core::stop_all_impl, state::list_instances_impl, update, MaaState,
+};
+
 /// 全局设置：关闭时是否最小化到托盘
 static MINIMIZE_TO_TRAY: AtomicBool = AtomicBool::new(false);
 
 /// 全局托盘图标引用，用于动态更新图标
 static TRAY_ICON: OnceLock<Mutex<Option<TrayIcon>>> = OnceLock::new();
 
+/// 当前托盘菜单语言
+static TRAY_LANGUAGE: OnceLock<Mutex<String>> = OnceLock::new();
+
+/// 前端设置的自定义 tooltip（为空时使用当前语言的默认文案）
+static CUSTOM_TOOLTIP: OnceLock<Mutex<Option<String>>> = OnceLock::new();
+
+/// 未叠加进度环的原始托盘图标
+static BASE_ICON: OnceLock<Mutex<Option<Image<'static>>>> = OnceLock::new();
+
+/// 当前显示的任务进度 (current, total)，为空表示显示原始图标
+static TRAY_PROGRESS: OnceLock<Mutex<Option<(u32, u32)>>> = OnceLock::new();
+
+/// 进度环已完成部分的颜色（RGB）
+const PROGRESS_COLOR: [u8; 3] = [34, 197, 94];
+/// 进度环未完成部分的颜色（RGBA）
+const PROGRESS_TRACK_COLOR: [u8; 4] = [0, 0, 0, 110];
+
+/// 托盘菜单及退出确认文案
+struct TrayTexts {
+    show: &'static str,
+    instances: &'static str,
+    no_instances: &'static str,
+    start: &'static str,
+    stop: &'static str,
+    quit: &'static str,
+    tooltip: &'static str,
+    busy_exit_title: &'static str,
+    busy_exit_message: &'static str,
+    busy_exit_confirm: &'static str,
+    busy_exit_cancel: &'static str,
+}
+
+const TRAY_TEXTS_ZH: TrayTexts = TrayTexts {
+    show: "显示主窗口",
+    instances: "实例",
+    no_instances: "暂无实例",
+    start: "开始任务",
+    stop: "停止任务",
+    quit: "退出",
+    tooltip: "MXU（单击显示主窗口）",
+    busy_exit_title: "正在更新",
+    busy_exit_message: "正在下载或安装更新，现在退出可能导致更新不完整。确定要退出吗？",
+    busy_exit_confirm: "退出",
+    busy_exit_cancel: "取消",
+};
+
+const TRAY_TEXTS_EN: TrayTexts = TrayTexts {
+    show: "Show Main Window",
+    instances: "Instances",
+    no_instances: "No instances",
+    start: "Start Tasks",
+    stop: "Stop Tasks",
+    quit: "Quit",
+    tooltip: "MXU (click to show window)",
+    busy_exit_title: "Update in progress",
+    busy_exit_message:
+        "An update is being downloaded or installed. Quitting now may leave it incomplete. Quit anyway?",
+    busy_exit_confirm: "Quit",
+    busy_exit_cancel: "Cancel",
+};
+
+/// 按语言代码选择文案：zh-* 使用中文，其余使用英文
+fn tray_texts(language: &str) -> &'static TrayTexts {
+    if language.to_ascii_lowercase().starts_with("zh") {
+        &TRAY_TEXTS_ZH
+    } else {
+        &TRAY_TEXTS_EN
+    }
+}
+
+fn current_tray_texts() -> &'static TrayTexts {
+    let lang = TRAY_LANGUAGE.get_or_init(|| Mutex::new("zh-CN".to_string()));
+    match lang.lock() {
+        Ok(lang) => tray_texts(&lang),
+        Err(_) => &TRAY_TEXTS_ZH,
+    }
+}
+
+/// 当前界面语言代码（后端弹窗等需要区分更多语言时使用）
+pub fn current_language() -> String {
+    let lang = TRAY_LANGUAGE.get_or_init(|| Mutex::new("zh-CN".to_string()));
+    lang.lock()
+        .map(|lang| lang.clone())
+        .unwrap_or_else(|_| "zh-CN".to_string())
+}
+
+/// 当前界面语言是否为中文（后端弹窗等文案按此选择中英文）
+pub fn is_chinese_language() -> bool {
+    let lang = TRAY_LANGUAGE.get_or_init(|| Mutex::new("zh-CN".to_string()));
+    lang.lock()
+        .map(|lang| lang.to_ascii_lowercase().starts_with("zh"))
+        .unwrap_or(true)
+}
+
+/// 托盘菜单中实例项的 id 前缀，后接 instance_id
+const INSTANCE_MENU_PREFIX: &str = "instance:";
+
+/// 构建实例子菜单：按创建顺序列出当前所有实例，点击后切换到对应实例
+fn build_instances_submenu(app: &AppHandle, texts: &TrayTexts) -> tauri::Result<Submenu<Wry>> {
+    let instances = app
+        .try_state::<Arc<MaaState>>()
+        .and_then(|state| list_instances_impl(&state).ok())
+        .unwrap_or_default();
+
+    let mut items = Vec::with_capacity(instances.len().max(1));
+    for instance in &instances {
+        let label = instance.name.as_deref().unwrap_or(&instance.id);
+        let id = format!("{}{}", INSTANCE_MENU_PREFIX, instance.id);
+        items.push(MenuItem::with_id(app, id, label, true, None::<&str>)?);
+    }
+    if items.is_empty() {
+        items.push(MenuItem::with_id(
+            app,
+            "no_instances",
+            texts.no_instances,
+            false,
+            None::<&str>,
+        )?);
+    }
+
+    let refs: Vec<&dyn IsMenuItem<Wry>> = items.iter().map(|i| i as &dyn IsMenuItem<Wry>).collect();
+    Submenu::with_id_and_items(app, "instances", texts.instances, true, &refs)
+}
+
+/// 构建托盘菜单
+fn build_tray_menu(app: &AppHandle, texts: &TrayTexts) -> tauri::Result<Menu<Wry>> {
+    let show_i = MenuItem::with_id(app, "show", texts.show, true, None::<&str>)?;
+    let instances_m = build_instances_submenu(app, texts)?;
+    let start_i = MenuItem::with_id(app, "start", texts.start, true, None::<&str>)?;
+    let stop_i = MenuItem::with_id(app, "stop", texts.stop, true, None::<&str>)?;
+    let quit_i = MenuItem::with_id(app, "quit", texts.quit, true, None::<&str>)?;
+
+    Menu::with_items(app, &[&show_i, &instances_m, &start_i, &stop_i, &quit_i])
+}
+
+/// 按当前语言重建托盘菜单（实例创建、销毁或改名后调用，托盘未初始化时忽略）
+pub fn refresh_tray_menu() {
+    let Some(tray_mutex) = TRAY_ICON.get() else {
+        return;
+    };
+    let guard = match tray_mutex.lock() {
+        Ok(guard) => guard,
+        Err(e) => {
+            log::warn!("Failed to lock tray mutex: {}", e);
+            return;
+        }
+    };
+    let Some(tray) = guard.as_ref() else {
+        return;
+    };
+    match build_tray_menu(tray.app_handle(), current_tray_texts()) {
+        Ok(menu) => {
+            if let Err(e) = tray.set_menu(Some(menu)) {
+                log::warn!("Failed to set tray menu: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Failed to build tray menu: {}", e),
+    }
+}
+
 /// 设置最小化到托盘选项
 pub fn set_minimize_to_tray(enabled: bool) {
     MINIMIZE_TO_TRAY.store(enabled, Ordering::SeqCst);
@@ -26,25 +190,30 @@ pub fn get_minimize_to_tray() -> bool {
 }
 
 /// 初始化系统托盘
-pub fn init_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
-    // 创建托盘菜单项
-    let show_i = MenuItem::with_id(app, "show", "显示主窗口", true, None::<&str>)?;
-    let start_i = MenuItem::with_id(app, "start", "开始任务", true, None::<&str>)?;
-    let stop_i = MenuItem::with_id(app, "stop", "停止任务", true, None::<&str>)?;
-    let quit_i = MenuItem::with_id(app, "quit", "退出", true, None::<&str>)?;
+/// language: 菜单语言（如 "zh-CN"、"en-US"），非中文一律使用英文文案
+pub fn init_tray(app: &AppHandle, language: &str) -> Result<(), Box<dyn std::error::Error>> {
+    *TRAY_LANGUAGE
+        .get_or_init(|| Mutex::new(String::new()))
+        .lock()
+        .map_err(|e| e.to_string())? = language.to_string();
+    let texts = tray_texts(language);
 
-    let menu = Menu::with_items(app, &[&show_i, &start_i, &stop_i, &quit_i])?;
+    // 创建托盘菜单
+    let menu = build_tray_menu(app, texts)?;
 
     // 获取图标
     let icon = app
         .default_window_icon()
         .cloned()
         .unwrap_or_else(|| Image::from_bytes(include_bytes!("../icons/icon.png")).unwrap());
+    if let Ok(mut base) = BASE_ICON.get_or_init(|| Mutex::new(None)).lock() {
+        *base = Some(icon.clone().to_owned());
+    }
 
     // 创建托盘图标
     let tray = TrayIconBuilder::<Wry>::new()
         .icon(icon)
-        .tooltip("MXU")
+        .tooltip(texts.tooltip)
         .menu(&menu)
         .show_menu_on_left_click(false)
         .on_menu_event(|app, event| {
@@ -60,16 +229,36 @@ pub fn init_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
                 "stop" => {
-                    // 发送停止任务事件到前端
-                    if let Some(window) = app.get_webview_window("main") {
-                        let _ = window.emit("tray-stop-tasks", ());
+                    // 在后端停止所有实例的任务，完成后把结果发给前端做收尾
+                    if let Some(state) = app.try_state::<Arc<MaaState>>() {
+                        let state = state.inner().clone();
+                        let app = app.clone();
+                        std::thread::spawn(move || {
+                            let results = stop_all_impl(&app, &state);
+                            if let Some(window) = app.get_webview_window("main") {
+                                let _ = window.emit("tray-stop-all-finished", results);
+                            }
+                        });
                     }
                 }
                 "quit" => {
-                    // 真正退出应用
-                    app.exit(0);
+                    // 真正退出应用（下载/更新进行中时先确认）
+                    if !confirm_exit_if_busy(app) {
+                        app.exit(0);
+                    }
+                }
+                _ => {
+                    // 切换到实例：显示主窗口并通知前端切换标签页
+                    if let Some(instance_id) = id.strip_prefix(INSTANCE_MENU_PREFIX) {
+                        show_main_window(app);
+                        if let Some(window) = app.get_webview_window("main") {
+                            let _ = window.emit(
+                                "tray-focus-instance",
+                                serde_json::json!({ "instance_id": instance_id }),
+                            );
+                        }
+                    }
                 }
-                _ => {}
             }
         })
         .on_tray_icon_event(|tray, event| {
@@ -97,11 +286,21 @@ pub fn init_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
 }
 
 /// 显示主窗口
-fn show_main_window(app: &AppHandle) {
+pub(crate) fn show_main_window(app: &AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
         let _ = window.show();
         let _ = window.unminimize();
         let _ = window.set_focus();
+        // 窗口恢复后继续 UI 预览截图
+        set_preview_paused(app, false);
+        let _ = window.emit("restored-from-tray", ());
+    }
+}
+
+/// 暂停/恢复主窗口的截图预览流（任务所需截图不受影响）
+fn set_preview_paused(app: &AppHandle, paused: bool) {
+    if let Some(state) = app.try_state::<Arc<MaaState>>() {
+        state.screenshot_service.set_local_paused(paused);
     }
 }
 
@@ -111,6 +310,9 @@ pub fn handle_close_requested(app: &AppHandle) -> bool {
         // 最小化到托盘而不是关闭
         if let Some(window) = app.get_webview_window("main") {
             let _ = window.hide();
+            // 预览不可见，暂停 UI 预览截图以节省资源
+            set_preview_paused(app, true);
+            let _ = window.emit("minimized-to-tray", ());
         }
         true // 阻止关闭
     } else {
@@ -118,6 +320,37 @@ pub fn handle_close_requested(app: &AppHandle) -> bool {
     }
 }
 
+/// 下载/更新进行中时弹出退出确认框，返回 true 表示已接管退出流程（调用方应阻止本次退出）
+///
+/// 用户确认后中止下载、清理临时文件并退出应用
+pub fn confirm_exit_if_busy(app: &AppHandle) -> bool {
+    use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+    if !download::has_active_downloads() && !update::is_update_in_progress() {
+        return false;
+    }
+
+    log::warn!("Exit requested while a download/update is in progress, asking for confirmation");
+    let texts = current_tray_texts();
+    let app_handle = app.clone();
+    app.dialog()
+        .message(texts.busy_exit_message)
+        .title(texts.busy_exit_title)
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            texts.busy_exit_confirm.to_string(),
+            texts.busy_exit_cancel.to_string(),
+        ))
+        .show(move |confirmed| {
+            if confirmed {
+                log::info!("Exit confirmed during download/update, cleaning up");
+                download::abort_active_downloads();
+                app_handle.exit(0);
+            }
+        });
+    true
+}
+
 /// 更新托盘图标
 /// icon_path: 图标文件的相对路径（相对于 exe 目录）
 pub fn update_tray_icon(icon_path: &str) -> Result<(), String> {
@@ -151,8 +384,20 @@ pub fn update_tray_icon(icon_path: &str) -> Result<(), String> {
     let icon_data = std::fs::read(&canonical_path)
         .map_err(|e| format!("Failed to read icon file {:?}: {}", canonical_path, e))?;
 
-    // 创建图标
+    // 创建图标，任务进行中时保留进度环
     let icon = Image::from_bytes(&icon_data).map_err(|e| format!("Failed to parse icon: {}", e))?;
+    if let Ok(mut base) = BASE_ICON.get_or_init(|| Mutex::new(None)).lock() {
+        *base = Some(icon.clone().to_owned());
+    }
+    let progress = TRAY_PROGRESS
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .ok()
+        .and_then(|p| *p);
+    let icon = match progress {
+        Some((current, total)) => draw_progress_ring(&icon, current, total),
+        None => icon.to_owned(),
+    };
 
     // 更新托盘图标
     let tray_mutex = TRAY_ICON.get_or_init(|| Mutex::new(None));
@@ -180,9 +425,138 @@ pub fn update_tray_tooltip(tooltip: &str) -> Result<(), String> {
     if let Some(tray) = guard.as_ref() {
         tray.set_tooltip(Some(tooltip))
             .map_err(|e| format!("Failed to set tray tooltip: {}", e))?;
+        if let Ok(mut custom) = CUSTOM_TOOLTIP.get_or_init(|| Mutex::new(None)).lock() {
+            *custom = Some(tooltip.to_string());
+        }
         log::info!("Tray tooltip updated: {}", tooltip);
         Ok(())
     } else {
         Err("Tray icon not initialized".to_string())
     }
 }
+
+/// 切换托盘菜单语言并重建菜单（未设置自定义 tooltip 时同步更新默认 tooltip）
+pub fn set_tray_language(app: &AppHandle, language: &str) -> Result<(), String> {
+    {
+        let lang_mutex = TRAY_LANGUAGE.get_or_init(|| Mutex::new(String::new()));
+        let mut lang = lang_mutex
+            .lock()
+            .map_err(|e| format!("Failed to lock tray language: {}", e))?;
+        if *lang == language {
+            return Ok(());
+        }
+        *lang = language.to_string();
+    }
+    let texts = current_tray_texts();
+
+    let tray_mutex = TRAY_ICON.get_or_init(|| Mutex::new(None));
+    let guard = tray_mutex
+        .lock()
+        .map_err(|e| format!("Failed to lock tray mutex: {}", e))?;
+
+    if let Some(tray) = guard.as_ref() {
+        let menu =
+            build_tray_menu(app, texts).map_err(|e| format!("Failed to build tray menu: {}", e))?;
+        tray.set_menu(Some(menu))
+            .map_err(|e| format!("Failed to set tray menu: {}", e))?;
+
+        let has_custom_tooltip = CUSTOM_TOOLTIP
+            .get_or_init(|| Mutex::new(None))
+            .lock()
+            .map(|c| c.is_some())
+            .unwrap_or(false);
+        if !has_custom_tooltip {
+            tray.set_tooltip(Some(texts.tooltip))
+                .map_err(|e| format!("Failed to set tray tooltip: {}", e))?;
+        }
+        log::info!("Tray language updated: {}", language);
+        Ok(())
+    } else {
+        Err("Tray icon not initialized".to_string())
+    }
+}
+
+/// 设置托盘图标上的任务进度环
+///
+/// 进度为空（total 为 0）或已满时恢复原始图标；进度未变化时不重绘
+pub fn set_tray_progress(current: u32, total: u32) -> Result<(), String> {
+    let progress = (total > 0 && current < total).then_some((current, total));
+    {
+        let mut last = TRAY_PROGRESS
+            .get_or_init(|| Mutex::new(None))
+            .lock()
+            .map_err(|e| format!("Failed to lock tray progress: {}", e))?;
+        if *last == progress {
+            return Ok(());
+        }
+        *last = progress;
+    }
+
+    let base = BASE_ICON
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .map_err(|e| format!("Failed to lock tray base icon: {}", e))?
+        .clone()
+        .ok_or("Tray icon not initialized")?;
+    let icon = match progress {
+        Some((current, total)) => draw_progress_ring(&base, current, total),
+        None => base,
+    };
+
+    let tray_mutex = TRAY_ICON.get_or_init(|| Mutex::new(None));
+    let guard = tray_mutex
+        .lock()
+        .map_err(|e| format!("Failed to lock tray mutex: {}", e))?;
+
+    if let Some(tray) = guard.as_ref() {
+        tray.set_icon(Some(icon))
+            .map_err(|e| format!("Failed to set tray icon: {}", e))?;
+        log::debug!("Tray progress updated: {:?}", progress);
+        Ok(())
+    } else {
+        Err("Tray icon not initialized".to_string())
+    }
+}
+
+/// 在图标外缘叠加进度环：从顶部顺时针绘制，已完成部分为绿色，其余为半透明暗色
+fn draw_progress_ring(base: &Image<'_>, current: u32, total: u32) -> Image<'static> {
+    use image::{Pixel, Rgba, RgbaImage};
+
+    let (width, height) = (base.width(), base.height());
+    let Some(mut canvas) = RgbaImage::from_raw(width, height, base.rgba().to_vec()) else {
+        return base.clone().to_owned();
+    };
+    let fraction = current.min(total) as f32 / total.max(1) as f32;
+
+    let size = width.min(height) as f32;
+    let outer = size / 2.0;
+    let thickness = (size / 7.0).max(2.0);
+    let inner = outer - thickness;
+    let (cx, cy) = (width as f32 / 2.0, height as f32 / 2.0);
+    let sweep = fraction * std::f32::consts::TAU;
+
+    for (x, y, pixel) in canvas.enumerate_pixels_mut() {
+        let dx = x as f32 + 0.5 - cx;
+        let dy = y as f32 + 0.5 - cy;
+        let dist = (dx * dx + dy * dy).sqrt();
+        // 环内外边缘各 1 像素做抗锯齿
+        let coverage = (outer - dist).min(dist - inner).clamp(-0.5, 0.5) + 0.5;
+        if coverage <= 0.0 {
+            continue;
+        }
+        // 以正上方为 0，顺时针增大
+        let mut angle = dx.atan2(-dy);
+        if angle < 0.0 {
+            angle += std::f32::consts::TAU;
+        }
+        let [r, g, b, a] = if angle <= sweep {
+            let [r, g, b] = PROGRESS_COLOR;
+            [r, g, b, 255]
+        } else {
+            PROGRESS_TRACK_COLOR
+        };
+        pixel.blend(&Rgba([r, g, b, (a as f32 * coverage).round() as u8]));
+    }
+
+    Image::new_owned(canvas.into_raw(), width, height)
+}
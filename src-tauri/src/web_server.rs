@@ -24,14 +24,21 @@ use tower_http::cors::CorsLayer;
 
 use crate::commands::{
     app_config::AppConfigState,
+    emulator_launch::ensure_emulator_ready,
     maa_agent::{start_tasks_impl, stop_agent_impl},
     maa_core::{
-        connect_controller_impl, destroy_instance_impl, find_adb_devices_impl,
-        find_win32_windows_impl, find_wlroots_sockets_impl, get_cached_image_impl,
-        load_resource_impl, override_pipeline_impl, post_click_impl, post_screencap_impl,
-        run_task_impl, stop_task_impl,
+        connect_controller_impl, create_instance_impl, destroy_instance_impl,
+        find_adb_devices_impl, find_win32_windows_impl, find_wlroots_sockets_impl,
+        get_cached_image_impl, get_pipeline_overrides_impl, load_resource_impl,
+        override_pipeline_impl, post_click_impl, post_screencap_impl, probe_adb_ports_impl,
+        reset_pipeline_impl, set_instance_name_impl, stop_all_impl, stop_task_impl,
+    },
+    preempt::run_task_with_priority,
+    resource_check::validate_task_for_instance,
+    state::{get_merged_logs_impl, list_instances_impl},
+    types::{
+        AgentConfig, ControllerConfig, EmulatorLaunchConfig, MaaState, TaskConfig, TaskQueueLoop,
     },
-    types::{AgentConfig, ControllerConfig, MaaState, TaskConfig},
     utils::{emit_callback_event, emit_config_changed, emit_state_changed},
 };
 use crate::ws_broadcast::WsBroadcast;
@@ -222,13 +229,20 @@ pub async fn start_web_server(
         .route("/maa/initialized", get(handle_get_maa_initialized))
         // Maa 设备扫描
         .route("/maa/devices", get(handle_get_adb_devices))
+        .route("/maa/devices/probe", get(handle_probe_adb_ports))
         .route("/maa/windows", get(handle_get_win32_windows))
         .route("/maa/wlroots-sockets", get(handle_get_wlroots_sockets))
         // Maa 实例管理
+        .route("/maa/instances", get(handle_list_instances))
+        .route("/maa/tasks/stop-all", axum::routing::post(handle_stop_all))
         .route(
             "/maa/instances/:id",
             axum::routing::put(handle_create_instance).delete(handle_destroy_instance),
         )
+        .route(
+            "/maa/instances/:id/name",
+            axum::routing::put(handle_set_instance_name),
+        )
         // Maa 实例操作（通过 instance_id 路径参数）
         .route(
             "/maa/instances/:id/connect",
@@ -242,6 +256,10 @@ pub async fn start_web_server(
             "/maa/instances/:id/tasks/run",
             axum::routing::post(handle_run_task),
         )
+        .route(
+            "/maa/instances/:id/tasks/validate",
+            axum::routing::post(handle_validate_task),
+        )
         .route(
             "/maa/instances/:id/tasks/start",
             axum::routing::post(handle_start_tasks),
@@ -254,6 +272,14 @@ pub async fn start_web_server(
             "/maa/instances/:id/tasks/:task_id/pipeline",
             axum::routing::post(handle_override_pipeline),
         )
+        .route(
+            "/maa/instances/:id/pipeline/overrides",
+            get(handle_get_pipeline_overrides),
+        )
+        .route(
+            "/maa/instances/:id/pipeline/reset",
+            axum::routing::post(handle_reset_pipeline),
+        )
         .route(
             "/maa/instances/:id/agent/stop",
             axum::routing::post(handle_stop_agent),
@@ -273,6 +299,7 @@ pub async fn start_web_server(
         )
         // 运行日志（跨刷新持久化）
         .route("/logs", get(handle_get_all_logs))
+        .route("/logs/merged", get(handle_get_merged_logs))
         .route(
             "/logs/:id",
             axum::routing::post(handle_push_log).delete(handle_clear_instance_logs),
@@ -285,6 +312,7 @@ pub async fn start_web_server(
             "/system/restart-as-admin",
             axum::routing::post(handle_restart_as_admin),
         )
+        .route("/system/restart", axum::routing::post(handle_restart_app))
         // 本地文件代理（浏览器通过此端点访问 exe 目录下的资源文件）
         .route("/local-file", get(handle_serve_local_file))
         .with_state(state);
@@ -700,10 +728,69 @@ async fn handle_get_maa_initialized(State(state): State<WebState>) -> impl IntoR
 // Phase 2: Maa 操作端点
 // ============================================================================
 
-/// GET /api/maa/devices
-/// 扫描并返回 ADB 设备列表（会更新 MaaState 缓存）
-async fn handle_get_adb_devices(State(state): State<WebState>) -> impl IntoResponse {
-    match find_adb_devices_impl(state.maa_state).await {
+/// GET /api/maa/devices?adb_path=...
+/// 扫描并返回 ADB 设备列表（会更新 MaaState 缓存），可选指定 adb 可执行文件路径
+async fn handle_get_adb_devices(
+    State(state): State<WebState>,
+    axum::extract::Query(params): axum::extract::Query<std::collections::HashMap<String, String>>,
+) -> impl IntoResponse {
+    let adb_path = params.get("adb_path").cloned();
+    match find_adb_devices_impl(state.maa_state, adb_path).await {
+        Ok(devices) => Json(serde_json::to_value(&devices).unwrap_or_default()).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
+/// GET /api/maa/devices/probe?adb_path=...&host=...
+/// 扫描指定主机上常见模拟器 ADB 端口，返回能连上的设备
+async fn handle_probe_adb_ports(
+    State(state): State<WebState>,
+    axum::extract::Query(params): axum::extract::Query<std::collections::HashMap<String, String>>,
+) -> impl IntoResponse {
+    let (Some(adb_path), Some(host)) = (params.get("adb_path"), params.get("host")) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": "Missing adb_path or host" })),
+        )
+            .into_response();
+    };
+
+    // adb_path 会被直接执行：设备搜索检测到的 adb 直接放行，其余路径需通过程序信任校验
+    let detected = state
+        .maa_state
+        .cached_adb_devices
+        .lock()
+        .map(|devices| devices.iter().any(|d| d.adb_path == *adb_path))
+        .unwrap_or(false);
+    if !detected {
+        let app = state.app_handle.clone();
+        let program = adb_path.clone();
+        let allowed = tokio::task::spawn_blocking(move || {
+            crate::commands::program_trust::ensure_program_allowed(
+                &app,
+                None,
+                &program,
+                None,
+                "adb_probe",
+            )
+        })
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|r| r);
+        if let Err(e) = allowed {
+            return (
+                StatusCode::FORBIDDEN,
+                Json(serde_json::json!({ "error": e })),
+            )
+                .into_response();
+        }
+    }
+
+    match probe_adb_ports_impl(state.maa_state, adb_path.clone(), host.clone()).await {
         Ok(devices) => Json(serde_json::to_value(&devices).unwrap_or_default()).into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -745,14 +832,60 @@ async fn handle_get_wlroots_sockets(State(state): State<WebState>) -> impl IntoR
     }
 }
 
+/// GET /api/maa/instances
+/// 按创建顺序返回所有实例的精简信息
+async fn handle_list_instances(State(state): State<WebState>) -> impl IntoResponse {
+    match list_instances_impl(&state.maa_state) {
+        Ok(list) => Json(list).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
 /// PUT /api/maa/instances/:id
 /// 创建实例（幂等）
 async fn handle_create_instance(
     State(state): State<WebState>,
     axum::extract::Path(instance_id): axum::extract::Path<String>,
+    body: Option<Json<InstanceNameRequest>>,
 ) -> impl IntoResponse {
-    ensure_instance_exists(&state.maa_state, &instance_id);
-    Json(serde_json::json!({ "ok": true })).into_response()
+    let name = body.and_then(|Json(body)| body.name);
+    match create_instance_impl(&state.maa_state, &instance_id, name) {
+        Ok(name) => Json(serde_json::json!({ "ok": true, "name": name })).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
+/// PUT /api/maa/instances/:id 与 PUT /api/maa/instances/:id/name 请求体
+#[derive(serde::Deserialize)]
+struct InstanceNameRequest {
+    #[serde(default)]
+    name: Option<String>,
+}
+
+/// PUT /api/maa/instances/:id/name
+/// 设置实例显示名，传空字符串清除，与 Tauri invoke `maa_set_instance_name` 使用同一套实现
+async fn handle_set_instance_name(
+    State(state): State<WebState>,
+    axum::extract::Path(instance_id): axum::extract::Path<String>,
+    Json(body): Json<InstanceNameRequest>,
+) -> impl IntoResponse {
+    let name = body.name.unwrap_or_default();
+    match set_instance_name_impl(&state.maa_state, &instance_id, &name) {
+        Ok(name) => Json(serde_json::json!({ "ok": true, "name": name })).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
 }
 
 /// DELETE /api/maa/instances/:id
@@ -776,19 +909,47 @@ fn ensure_instance_exists(maa_state: &Arc<MaaState>, instance_id: &str) {
     if let Ok(mut instances) = maa_state.instances.lock() {
         instances
             .entry(instance_id.to_string())
-            .or_insert_with(crate::commands::types::InstanceRuntime::default);
+            .or_insert_with(|| crate::commands::types::InstanceRuntime::new(None));
     }
 }
 
+/// POST /connect 请求体：控制器配置，可额外带 `launch`（连接前自动启动模拟器）
+#[derive(serde::Deserialize)]
+struct ConnectRequest {
+    #[serde(flatten)]
+    config: ControllerConfig,
+    #[serde(default)]
+    launch: Option<EmulatorLaunchConfig>,
+}
+
 /// POST /api/maa/instances/:id/connect
 /// 连接控制器；自动创建不存在的实例
 async fn handle_connect_controller(
     State(state): State<WebState>,
     axum::extract::Path(instance_id): axum::extract::Path<String>,
-    Json(config): Json<ControllerConfig>,
+    Json(req): Json<ConnectRequest>,
 ) -> impl IntoResponse {
     ensure_instance_exists(&state.maa_state, &instance_id);
 
+    let mut config = req.config;
+    if let Some(launch) = req.launch {
+        if let Err(e) = ensure_emulator_ready(
+            &state.app_handle,
+            &state.maa_state,
+            &instance_id,
+            &mut config,
+            &launch,
+        )
+        .await
+        {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "error": e })),
+            )
+                .into_response();
+        }
+    }
+
     let app_handle = state.app_handle.clone();
     let on_event = Arc::new(move |msg: &str, detail: &str| {
         emit_callback_event(&app_handle, msg, detail);
@@ -801,7 +962,7 @@ async fn handle_connect_controller(
         }
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({ "error": e })),
+            Json(serde_json::json!({ "error": e.to_string() })),
         )
             .into_response(),
     }
@@ -850,7 +1011,7 @@ async fn handle_load_resource(
         }
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({ "error": e })),
+            Json(serde_json::json!({ "error": e.to_string() })),
         )
             .into_response(),
     }
@@ -858,40 +1019,75 @@ async fn handle_load_resource(
 
 /// POST /api/maa/instances/:id/tasks/run
 /// 运行一批任务（不启动 agent，适用于已连接的实例）
-/// Body: `[{"entry": "TaskName", "pipelineOverride": "{}", "selected_task_id": "..." }]`
+/// Body: `[{"entry": "TaskName", "pipelineOverride": "{}", "selected_task_id": "...", "priority": 0 }]`
 async fn handle_run_task(
     State(state): State<WebState>,
     axum::extract::Path(instance_id): axum::extract::Path<String>,
     Json(tasks): Json<Vec<TaskConfig>>,
 ) -> impl IntoResponse {
-    let mut task_ids = Vec::new();
     let maa = state.maa_state;
+    let app_handle = state.app_handle.clone();
+    let instance_id_clone = instance_id.clone();
+
+    // 高优先级任务抢占时需等待当前任务停下，放到阻塞线程中执行
+    let result = tokio::task::spawn_blocking(move || {
+        let mut task_ids = Vec::new();
+        for task in tasks {
+            task_ids.push(run_task_with_priority(
+                &app_handle,
+                &maa,
+                &instance_id_clone,
+                task,
+            )?);
+        }
+        Ok::<_, String>(task_ids)
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|r| r);
 
-    for task in &tasks {
-        match run_task_impl(
-            &state.app_handle,
-            &maa,
-            &instance_id,
-            &task.entry,
-            &task.pipeline_override,
-            task.selected_task_id.as_deref(),
-        ) {
-            Ok(id) => task_ids.push(id),
-            Err(e) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(serde_json::json!({ "error": e })),
-                )
-                    .into_response();
-            }
+    let task_ids = match result {
+        Ok(ids) => ids,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "error": e })),
+            )
+                .into_response();
         }
-    }
+    };
 
     emit_state_changed(&state.app_handle, &instance_id, "task-started");
 
     Json(serde_json::json!({ "taskIds": task_ids })).into_response()
 }
 
+/// POST /api/maa/instances/:id/tasks/validate
+/// 校验任务的入口节点与 pipeline override，不实际运行（对应 `maa_run_task` 的 validate_only 模式）
+/// Body: `{"entry": "TaskName", "pipeline_override": "{}"}`
+async fn handle_validate_task(
+    State(state): State<WebState>,
+    axum::extract::Path(instance_id): axum::extract::Path<String>,
+    Json(task): Json<TaskConfig>,
+) -> impl IntoResponse {
+    let maa = state.maa_state;
+    let result = tokio::task::spawn_blocking(move || {
+        validate_task_for_instance(&maa, &instance_id, &task.entry, &task.pipeline_override)
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|r| r);
+
+    match result {
+        Ok(validation) => Json(validation).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
 /// POST /api/maa/instances/:id/tasks/start 请求体
 #[derive(serde::Deserialize)]
 struct StartTasksRequest {
@@ -906,6 +1102,8 @@ struct StartTasksRequest {
     pi_envs: Option<std::collections::HashMap<String, String>>,
     #[serde(default)]
     reset_state: Option<bool>,
+    #[serde(default)]
+    loop_config: Option<TaskQueueLoop>,
 }
 
 /// POST /api/maa/instances/:id/tasks/start
@@ -941,6 +1139,7 @@ async fn handle_start_tasks(
         body.tcp_compat_mode.unwrap_or(false),
         body.pi_envs,
         body.reset_state.unwrap_or(true),
+        body.loop_config,
     )
     .await
     {
@@ -972,6 +1171,21 @@ async fn handle_stop_task(
     }
 }
 
+/// POST /api/maa/tasks/stop-all
+/// 停止所有实例的任务，与 Tauri invoke `maa_stop_all` 使用同一套实现
+async fn handle_stop_all(State(state): State<WebState>) -> impl IntoResponse {
+    let maa_state = state.maa_state.clone();
+    let app_handle = state.app_handle.clone();
+    match tokio::task::spawn_blocking(move || stop_all_impl(&app_handle, &maa_state)).await {
+        Ok(results) => Json(results).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
 /// POST /api/maa/instances/:id/tasks/:task_id/pipeline 请求体
 #[derive(serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -987,12 +1201,45 @@ async fn handle_override_pipeline(
     Json(body): Json<OverridePipelineRequest>,
 ) -> impl IntoResponse {
     match override_pipeline_impl(
+        &state.app_handle,
         &state.maa_state,
         &instance_id,
         task_id,
         &body.pipeline_override,
     ) {
-        Ok(success) => Json(serde_json::json!({ "success": success })).into_response(),
+        Ok(result) => Json(result).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
+/// GET /api/maa/instances/:id/pipeline/overrides
+/// 查询各任务当前生效的 Pipeline override，与 Tauri invoke `maa_get_pipeline_overrides` 使用同一套实现
+async fn handle_get_pipeline_overrides(
+    State(state): State<WebState>,
+    axum::extract::Path(instance_id): axum::extract::Path<String>,
+) -> impl IntoResponse {
+    match get_pipeline_overrides_impl(&state.maa_state, &instance_id) {
+        Ok(overrides) => Json(overrides).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
+/// POST /api/maa/instances/:id/pipeline/reset
+/// 撤销运行中对 Pipeline 的所有修改，与 Tauri invoke `maa_reset_pipeline` 使用同一套实现
+async fn handle_reset_pipeline(
+    State(state): State<WebState>,
+    axum::extract::Path(instance_id): axum::extract::Path<String>,
+) -> impl IntoResponse {
+    match reset_pipeline_impl(&state.maa_state, &instance_id) {
+        Ok(count) => Json(serde_json::json!({ "reset": count })).into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({ "error": e })),
@@ -1007,8 +1254,13 @@ async fn handle_stop_agent(
     State(state): State<WebState>,
     axum::extract::Path(instance_id): axum::extract::Path<String>,
 ) -> impl IntoResponse {
-    match stop_agent_impl(&state.maa_state, &instance_id) {
-        Ok(()) => Json(serde_json::json!({ "ok": true })).into_response(),
+    let maa_state = state.maa_state.clone();
+    let result = tokio::task::spawn_blocking(move || stop_agent_impl(&maa_state, &instance_id))
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|r| r);
+    match result {
+        Ok(outcome) => Json(serde_json::json!({ "ok": true, "outcome": outcome })).into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({ "error": e })),
@@ -1068,7 +1320,9 @@ async fn handle_get_screenshot(
     let mut fallback_triggered = false;
 
     loop {
-        match get_cached_image_impl(&state.maa_state, &instance_id) {
+        match get_cached_image_impl(&state.maa_state, &instance_id, None)
+            .map(|frame| frame.data_url.unwrap_or_default())
+        {
             Ok(data_url) if !data_url.is_empty() => {
                 if let Some(b64) = data_url.strip_prefix("data:image/png;base64,") {
                     use base64::{engine::general_purpose::STANDARD, Engine as _};
@@ -1131,6 +1385,7 @@ async fn handle_screenshot_subscribe(
         instance_id,
         subscriber_id,
         interval_ms,
+        false,
         handle,
     );
     Json(serde_json::json!({ "ok": true })).into_response()
@@ -1232,16 +1487,31 @@ async fn handle_get_all_logs(State(state): State<WebState>) -> impl IntoResponse
     }
 }
 
-/// POST /api/logs/:id — 推送一条运行日志
+/// GET /api/logs/merged?instances=a,b — 获取多个实例合并后的运行日志（带实例标签）
+async fn handle_get_merged_logs(
+    State(state): State<WebState>,
+    axum::extract::Query(params): axum::extract::Query<std::collections::HashMap<String, String>>,
+) -> impl IntoResponse {
+    let instance_ids: Option<Vec<String>> = params
+        .get("instances")
+        .map(|v| v.split(',').map(|s| s.trim().to_string()).collect());
+    match get_merged_logs_impl(&state.maa_state, instance_ids.as_deref()) {
+        Ok(logs) => Json(logs).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+    }
+}
+
+/// POST /api/logs/:id — 推送一条运行日志，返回后端分配的序号
 async fn handle_push_log(
     State(state): State<WebState>,
     axum::extract::Path(instance_id): axum::extract::Path<String>,
     Json(entry): Json<crate::commands::types::LogEntryDto>,
 ) -> impl IntoResponse {
+    crate::commands::instance_log::append(&instance_id, &entry);
     match state.maa_state.log_buffer.lock() {
         Ok(mut buffer) => {
-            buffer.push(&instance_id, entry);
-            StatusCode::NO_CONTENT.into_response()
+            let seq = buffer.push(&instance_id, entry);
+            Json(serde_json::json!({ "seq": seq })).into_response()
         }
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
@@ -1276,6 +1546,25 @@ async fn handle_is_elevated() -> impl IntoResponse {
     .into_response()
 }
 
+/// POST /api/system/restart
+/// 以当前权限重启应用（需等待任务停止，在阻塞线程中执行）
+async fn handle_restart_app(State(state): State<WebState>) -> impl IntoResponse {
+    let result = tokio::task::spawn_blocking(move || {
+        crate::commands::system::restart_app_impl(state.app_handle)
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|r| r);
+    match result {
+        Ok(()) => Json(serde_json::json!({ "ok": true })).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
 /// POST /api/system/restart-as-admin
 /// 以管理员权限重启应用
 async fn handle_restart_as_admin(State(state): State<WebState>) -> impl IntoResponse {
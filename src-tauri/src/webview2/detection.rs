@@ -15,6 +15,21 @@ fn get_system_wow64_directory() -> Option<PathBuf> {
     GetSystemWow64Directory().map(PathBuf::from).ok()
 }
 
+/// WebView2 可用的 EdgeUpdate Client GUID（按加载器默认搜索顺序）
+///
+/// 独立运行时（Stable）之外，WebView2 加载器也会使用 Edge Beta/Dev/Canary 自带的运行时。
+/// 参考: https://learn.microsoft.com/en-us/microsoft-edge/webview2/concepts/distribution#understanding-the-webview2-runtime-and-preview-channels
+const WEBVIEW2_CLIENT_GUIDS: &[&str] = &[
+    // WebView2 Runtime (Stable)
+    "{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
+    // Edge Beta
+    "{2CD8A007-E189-409D-A2C8-9AF4EF3C72AA}",
+    // Edge Dev
+    "{0D50BFEC-CD6A-4F9A-964C-C7416E3ACB10}",
+    // Edge Canary（仅 per-user 安装）
+    "{65C35B14-6C1D-4122-AC46-7148CC9D6497}",
+];
+
 /// 检测 WebView2 是否已安装（注册表 + DLL 双重检测）
 ///
 /// 根据微软官方文档，检查 pv (REG_SZ) 注册表值：
@@ -22,35 +37,37 @@ fn get_system_wow64_directory() -> Option<PathBuf> {
 /// - HKCU 用于 per-user 安装（标准用户权限安装）
 /// - pv 值必须存在且不为空、不为 "0.0.0.0"
 ///
+/// Stable 运行时与 Beta/Dev/Canary 通道任一存在即视为可用
+///
 /// 参考: https://learn.microsoft.com/en-us/microsoft-edge/webview2/concepts/distribution#detect-if-a-suitable-webview2-runtime-is-already-installed
 pub fn is_webview2_installed() -> bool {
     // // 测试：强制视为未安装，以调试下载/安装流程。调试完请删除或注释下面这行。
     // return false;
 
-    let registry_locations: &[(HKEY, &str)] = &[
+    let registry_roots: &[(HKEY, &str)] = &[
         (
             HKEY::LOCAL_MACHINE,
-            r"SOFTWARE\WOW6432Node\Microsoft\EdgeUpdate\Clients\{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
+            r"SOFTWARE\WOW6432Node\Microsoft\EdgeUpdate\Clients",
         ),
         (
             HKEY::LOCAL_MACHINE,
-            r"SOFTWARE\Microsoft\EdgeUpdate\Clients\{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
-        ),
-        (
-            HKEY::CURRENT_USER,
-            r"Software\Microsoft\EdgeUpdate\Clients\{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
+            r"SOFTWARE\Microsoft\EdgeUpdate\Clients",
         ),
+        (HKEY::CURRENT_USER, r"Software\Microsoft\EdgeUpdate\Clients"),
     ];
 
     let mut registry_found = false;
-    for (root, path) in registry_locations {
-        let result = root.RegOpenKeyEx(Some(path), REG_OPTION::NoValue, KEY::READ);
-        if let Ok(hkey) = result {
-            let value_result = hkey.RegGetValue(None, Some("pv"), RRF::RT_REG_SZ);
-            if let Ok(RegistryValue::Sz(version)) = value_result {
-                if !version.is_empty() && version != "0.0.0.0" {
-                    registry_found = true;
-                    break;
+    'search: for guid in WEBVIEW2_CLIENT_GUIDS {
+        for (root, clients) in registry_roots {
+            let path = format!(r"{}\{}", clients, guid);
+            let result = root.RegOpenKeyEx(Some(&path), REG_OPTION::NoValue, KEY::READ);
+            if let Ok(hkey) = result {
+                let value_result = hkey.RegGetValue(None, Some("pv"), RRF::RT_REG_SZ);
+                if let Ok(RegistryValue::Sz(version)) = value_result {
+                    if !version.is_empty() && version != "0.0.0.0" {
+                        registry_found = true;
+                        break 'search;
+                    }
                 }
             }
         }
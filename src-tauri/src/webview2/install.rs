@@ -1,8 +1,9 @@
 //! WebView2 下载与本地解压
 //!
 //! 从微软官方 CDN 下载 **Fixed Version Runtime（固定版本运行时）**，
-//! 解压到程序目录下的 `cache/webview2_runtime/` 目录，通过环境变量
-//! `WEBVIEW2_BROWSER_EXECUTABLE_FOLDER` 指定运行时路径，不影响系统。
+//! 解压到程序目录下的 `cache/webview2_runtime/` 目录（独立模式），或
+//! `%LOCALAPPDATA%\MXU\webview2_runtime/` 目录（共享模式，多个 MXU 安装共用），
+//! 通过环境变量 `WEBVIEW2_BROWSER_EXECUTABLE_FOLDER` 指定运行时路径，不影响系统。
 
 use super::detection::{is_webview2_disabled, is_webview2_installed};
 use super::dialog::CustomDialog;
@@ -12,8 +13,8 @@ use std::os::windows::process::CommandExt;
 use std::path::PathBuf;
 use winsafe::GetSystemDirectory;
 
-/// WebView2 Fixed Version Runtime 版本号及对应的下载 GUID。
-/// **三者必须保持一致**——更新版本时需同时更新 `WEBVIEW2_VERSION`、`GUID_X64` 和 `GUID_ARM64`。
+/// WebView2 Fixed Version Runtime 版本号及对应的下载 GUID 和 cab 哈希。
+/// **必须保持一致**——更新版本时需同时更新 `WEBVIEW2_VERSION`、`GUID_*` 和 `SHA256_*`。
 /// GUID 可在 https://developer.microsoft.com/en-us/microsoft-edge/webview2/ 页面
 /// 从 Fixed Version 的下载链接中获取，
 /// 或前往 https://github.com/nicehash/NiceHashQuickMiner/releases 查看
@@ -22,15 +23,26 @@ const WEBVIEW2_VERSION: &str = "145.0.3800.65";
 const GUID_X64: &str = "c411606c-d282-4304-8420-8ae6b1dd3e9a";
 /// 对应 WEBVIEW2_VERSION 145.0.3800.65 的 ARM64 下载 GUID
 const GUID_ARM64: &str = "2d2cf37b-d24c-4c72-b5bc-e8061e7a7583";
+/// 对应 WEBVIEW2_VERSION 145.0.3800.65 的 x64 cab 文件 SHA-256（小写十六进制）。
+/// 可下载 cab 后执行 `certutil -hashfile <cab> SHA256` 获取；留空则拒绝安装该架构的运行时
+const SHA256_X64: &str = "";
+/// 对应 WEBVIEW2_VERSION 145.0.3800.65 的 ARM64 cab 文件 SHA-256（小写十六进制），留空则拒绝安装
+const SHA256_ARM64: &str = "";
+
+/// 下载的 cab 校验失败时的最大下载次数（含首次）
+const MAX_DOWNLOAD_ATTEMPTS: u32 = 2;
 
 /// 隐藏控制台窗口标志
 const CREATE_NO_WINDOW: u32 = 0x08000000;
 
-/// 获取当前架构对应的下载标签和 GUID
-fn get_arch_info() -> Result<(&'static str, &'static str), String> {
+/// 获取当前进程架构对应的下载标签、GUID 和 cab 哈希
+///
+/// Fixed Version 运行时由本进程加载，架构须与进程一致（x64 仿真运行在 ARM64 上时仍使用 x64 运行时），
+/// 不能按系统原生架构选择；原生架构只用于选择更新包
+fn get_arch_info() -> Result<(&'static str, &'static str, &'static str), String> {
     match std::env::consts::ARCH {
-        "x86_64" => Ok(("x64", GUID_X64)),
-        "aarch64" => Ok(("arm64", GUID_ARM64)),
+        "x86_64" => Ok(("x64", GUID_X64, SHA256_X64)),
+        "aarch64" => Ok(("arm64", GUID_ARM64, SHA256_ARM64)),
         other => Err(format!(
             "不支持的 CPU 架构: {}。当前应用仅支持 64 位 Windows（x64、ARM64），请在 64 位系统上运行。",
             other
@@ -38,8 +50,31 @@ fn get_arch_info() -> Result<(&'static str, &'static str), String> {
     }
 }
 
-/// 获取 WebView2 固定版本运行时的目录路径（exe 同级 cache 目录下）
+/// 是否启用了共享运行时模式
+fn is_shared_mode() -> bool {
+    mxu_lib::commands::system::get_webview2_shared()
+}
+
+/// 共享模式下的运行时目录（`%LOCALAPPDATA%\MXU\webview2_runtime`）
+fn get_shared_runtime_dir() -> Option<PathBuf> {
+    let local_app_data = std::env::var_os("LOCALAPPDATA").filter(|v| !v.is_empty())?;
+    Some(
+        PathBuf::from(local_app_data)
+            .join("MXU")
+            .join("webview2_runtime"),
+    )
+}
+
+/// 获取 WebView2 固定版本运行时的目录路径
+///
+/// 共享模式下位于 `%LOCALAPPDATA%\MXU` 下，否则位于 exe 同级 cache 目录下
 pub fn get_webview2_runtime_dir() -> Result<PathBuf, String> {
+    if is_shared_mode() {
+        match get_shared_runtime_dir() {
+            Some(dir) => return Ok(dir),
+            None => warn!("无法获取 LOCALAPPDATA 目录，WebView2 运行时回退到独立模式"),
+        }
+    }
     let exe_path = std::env::current_exe().map_err(|e| format!("获取程序路径失败: {}", e))?;
     let exe_dir = exe_path
         .parent()
@@ -47,21 +82,98 @@ pub fn get_webview2_runtime_dir() -> Result<PathBuf, String> {
     Ok(exe_dir.join("cache").join("webview2_runtime"))
 }
 
-/// 验证运行时目录包含关键可执行文件
+/// 运行时目录中必须存在且非空的关键文件（缺失或被截断通常是解压中断或被杀软删除）
+const REQUIRED_RUNTIME_FILES: &[&str] = &["msedgewebview2.exe", "msedge.dll", "icudtl.dat"];
+
+/// 验证运行时目录包含完整的关键文件
 fn validate_runtime_dir(runtime_dir: &std::path::Path) -> Result<(), String> {
-    if !runtime_dir.join("msedgewebview2.exe").exists() {
-        return Err(
-            "解压后的 WebView2 运行时目录不完整（未找到 msedgewebview2.exe）。\n\
-            请删除 cache/webview2_runtime/ 目录后重启程序重试。"
-                .to_string(),
-        );
+    let missing: Vec<&str> = REQUIRED_RUNTIME_FILES
+        .iter()
+        .copied()
+        .filter(|name| {
+            std::fs::metadata(runtime_dir.join(name))
+                .map(|m| !m.is_file() || m.len() == 0)
+                .unwrap_or(true)
+        })
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "WebView2 运行时目录 [{}] 不完整（缺少或损坏: {}）",
+            runtime_dir.display(),
+            missing.join(", ")
+        ));
     }
     Ok(())
 }
 
+/// 检查已缓存的运行时是否完整可用
+pub fn is_runtime_dir_valid(runtime_dir: &std::path::Path) -> bool {
+    runtime_dir.is_dir() && validate_runtime_dir(runtime_dir).is_ok()
+}
+
+/// 删除运行时目录
+///
+/// 删除前检查是否为符号链接/重解析点（含 junction/mount point），防止通过构造链接删除任意目录；
+/// 共享模式下还会检查是否正被其他 MXU 实例使用
+fn remove_runtime_dir(runtime_dir: &std::path::Path) -> Result<(), String> {
+    if !runtime_dir.exists() {
+        return Ok(());
+    }
+    let meta = std::fs::symlink_metadata(runtime_dir)
+        .map_err(|e| format!("读取运行时目录元数据失败: {}", e))?;
+    let is_reparse = {
+        use std::os::windows::fs::MetadataExt;
+        const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
+        meta.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT != 0
+    };
+    if meta.file_type().is_symlink() || is_reparse {
+        return Err(format!(
+            "运行时目录 [{}] 是符号链接或重解析点，出于安全原因拒绝操作。\n\
+            请手动删除该链接后重试。",
+            runtime_dir.display()
+        ));
+    }
+    if is_shared_mode() && is_runtime_dir_in_use(runtime_dir) {
+        return Err(format!(
+            "共享 WebView2 运行时目录 [{}] 正被其他 MXU 实例使用，无法更新。\n\n\
+            请关闭所有 MXU 实例后重试，或在设置中切换为独立运行时。",
+            runtime_dir.display()
+        ));
+    }
+    std::fs::remove_dir_all(runtime_dir).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::PermissionDenied {
+            format!(
+                "删除旧的 WebView2 运行时目录失败，可能有正在运行的程序正在使用该目录。\n\n\
+                请关闭所有已运行的本应用实例后重试。\n\n系统错误: {}",
+                e
+            )
+        } else {
+            format!("删除旧的 WebView2 运行时目录失败: {}", e)
+        }
+    })
+}
+
+/// 清理校验失败的已缓存运行时，之后由 `ensure_webview2` 重新下载解压
+///
+/// 目录不存在或完整时不做任何操作；删除失败时返回错误
+fn remove_broken_runtime() -> Result<(), String> {
+    let runtime_dir = get_webview2_runtime_dir()?;
+    if !runtime_dir.exists() {
+        return Ok(());
+    }
+    let Err(reason) = validate_runtime_dir(&runtime_dir) else {
+        return Ok(());
+    };
+    warn!(
+        "已缓存的 WebView2 运行时损坏，自动清理后重新安装: {}",
+        reason
+    );
+    remove_runtime_dir(&runtime_dir)
+}
+
 fn show_download_failed_dialog(error: &str) {
     match get_arch_info() {
-        Ok((arch_label, _)) => {
+        Ok((arch_label, _, _)) => {
             let cab_name = format!(
                 "Microsoft.WebView2.FixedVersionRuntime.{}.{}.cab",
                 WEBVIEW2_VERSION, arch_label
@@ -98,6 +210,27 @@ fn show_download_failed_dialog(error: &str) {
     }
 }
 
+/// 检测运行时目录是否正被其他进程使用
+///
+/// 运行中的 msedgewebview2.exe 及其加载的 DLL 会阻止目录重命名，
+/// 借此在删除前判断是否有其他 MXU 实例正在使用共享运行时，避免删除到一半导致对方崩溃
+fn is_runtime_dir_in_use(runtime_dir: &std::path::Path) -> bool {
+    let probe =
+        runtime_dir.with_file_name(format!("webview2_runtime_probe_{}", std::process::id()));
+    if std::fs::rename(runtime_dir, &probe).is_err() {
+        return true;
+    }
+    if let Err(e) = std::fs::rename(&probe, runtime_dir) {
+        warn!(
+            "恢复运行时目录名称失败 [{}] -> [{}]: {}",
+            probe.display(),
+            runtime_dir.display(),
+            e
+        );
+    }
+    false
+}
+
 /// 递归复制目录内容
 fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Result<(), String> {
     std::fs::create_dir_all(dst).map_err(|e| format!("无法创建目录 [{}]: {}", dst.display(), e))?;
@@ -133,6 +266,16 @@ fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Result<()
     Ok(())
 }
 
+/// 校验 cab 文件的 SHA-256
+fn verify_cab_sha256(cab_path: &std::path::Path, expected: &str) -> Result<(), String> {
+    mxu_lib::commands::resource_update::verify_sha256(cab_path, expected).map_err(|e| {
+        format!(
+            "cab 文件校验失败，文件可能不完整、已损坏或不是 {} 版本。\n{}",
+            WEBVIEW2_VERSION, e
+        )
+    })
+}
+
 /// 获取 expand.exe 的完整路径（通过 Windows API 获取系统目录，避免依赖可被篡改的环境变量）
 fn get_expand_exe_path() -> Result<std::path::PathBuf, String> {
     let res = GetSystemDirectory();
@@ -207,36 +350,8 @@ fn do_extract(
         }
     }
 
-    // 准备目标目录：删除前检查是否为符号链接/重解析点（含 junction/mount point），
-    // 防止通过构造链接删除任意目录
-    if runtime_dir.exists() {
-        let meta = std::fs::symlink_metadata(runtime_dir)
-            .map_err(|e| format!("读取运行时目录元数据失败: {}", e))?;
-        let is_reparse = {
-            use std::os::windows::fs::MetadataExt;
-            const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
-            meta.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT != 0
-        };
-        if meta.file_type().is_symlink() || is_reparse {
-            return Err(format!(
-                "运行时目录 [{}] 是符号链接或重解析点，出于安全原因拒绝操作。\n\
-                请手动删除该链接后重试。",
-                runtime_dir.display()
-            ));
-        }
-        if let Err(e) = std::fs::remove_dir_all(runtime_dir) {
-            let msg = if e.kind() == std::io::ErrorKind::PermissionDenied {
-                format!(
-                    "删除旧的 WebView2 运行时目录失败，可能有正在运行的程序正在使用该目录。\n\n\
-                    请关闭所有已运行的本应用实例后重试。\n\n系统错误: {}",
-                    e
-                )
-            } else {
-                format!("删除旧的 WebView2 运行时目录失败: {}", e)
-            };
-            return Err(msg);
-        }
-    }
+    // 准备目标目录（删除时会做符号链接安全检查）
+    remove_runtime_dir(runtime_dir)?;
     std::fs::create_dir_all(runtime_dir).map_err(|e| {
         if e.kind() == std::io::ErrorKind::PermissionDenied {
             format!(
@@ -274,7 +389,7 @@ fn try_extract_local_cab(runtime_dir: &std::path::Path) -> Option<Result<(), Str
             return None;
         }
     };
-    let (expected_arch, _) = match get_arch_info() {
+    let (expected_arch, _, expected_sha256) = match get_arch_info() {
         Ok(info) => info,
         Err(e) => {
             warn!(
@@ -325,6 +440,23 @@ fn try_extract_local_cab(runtime_dir: &std::path::Path) -> Option<Result<(), Str
     // 如果在操作过程中文件被外部删除/修改（TOCTOU），视为 cab 不可用并回退到在线下载
     if let Some(cab_path) = matched {
         info!("检测到本地 WebView2 cab 文件: {}", cab_path.display());
+        if let Err(e) = verify_cab_sha256(&cab_path, expected_sha256) {
+            warn!("本地 WebView2 cab 校验失败，将回退到在线下载: {}", e);
+            let _ = std::fs::remove_file(&cab_path);
+            CustomDialog::show_error(
+                "WebView2 cab 校验失败",
+                &format!(
+                    "本地 WebView2 运行时 cab 文件校验失败：\r\n\
+                     {}\r\n\r\n\
+                     该文件已被删除，将尝试在线下载。\r\n\
+                     如需手动放置，请重新下载 {} 版本（{}）的 cab 文件。",
+                    e.replace('\n', "\r\n"),
+                    WEBVIEW2_VERSION,
+                    expected_arch
+                ),
+            );
+            return None;
+        }
         let progress_dialog = CustomDialog::new_progress(
             "正在解压 WebView2",
             "检测到本地 WebView2 运行时 cab 文件，正在解压...",
@@ -368,9 +500,23 @@ fn try_extract_local_cab(runtime_dir: &std::path::Path) -> Option<Result<(), Str
     None
 }
 
+/// 校验刚解压的运行时，不完整时直接清理掉，避免下次启动误用残缺目录
+fn validate_installed_runtime(runtime_dir: &std::path::Path) -> Result<(), String> {
+    let Err(reason) = validate_runtime_dir(runtime_dir) else {
+        return Ok(());
+    };
+    match remove_runtime_dir(runtime_dir) {
+        Ok(()) => Err(format!("{}。\n已自动清理该目录，请重启程序重试。", reason)),
+        Err(e) => {
+            warn!("清理不完整的 WebView2 运行时失败: {}", e);
+            Err(format!("{}。\n请删除该目录后重启程序重试。", reason))
+        }
+    }
+}
+
 /// 下载或解压 WebView2 Fixed Version Runtime 到本地
 pub fn download_and_extract() -> Result<(), String> {
-    let (arch_label, guid) = get_arch_info()?;
+    let (arch_label, guid, expected_sha256) = get_arch_info()?;
     let cab_name = format!(
         "Microsoft.WebView2.FixedVersionRuntime.{}.{}.cab",
         WEBVIEW2_VERSION, arch_label
@@ -380,13 +526,21 @@ pub fn download_and_extract() -> Result<(), String> {
         guid, cab_name
     );
 
+    // 未记录哈希时无法校验 cab，拒绝安装未经校验的运行时（也避免误删用户手动放置的 cab）
+    if expected_sha256.is_empty() {
+        return Err(format!(
+            "未记录 WebView2 {}（{}）的 cab 哈希，无法校验运行时文件",
+            WEBVIEW2_VERSION, arch_label
+        ));
+    }
+
     let runtime_dir = get_webview2_runtime_dir()?;
 
     // 优先检测 exe 同目录下是否存在已下载的 cab 文件
     if let Some(result) = try_extract_local_cab(&runtime_dir) {
         if result.is_ok() {
             info!("已从本地 cab 安装 WebView2 固定版本运行时");
-            validate_runtime_dir(&runtime_dir)?;
+            validate_installed_runtime(&runtime_dir)?;
             std::env::set_var("WEBVIEW2_BROWSER_EXECUTABLE_FOLDER", &runtime_dir);
         }
         return result;
@@ -405,12 +559,21 @@ pub fn download_and_extract() -> Result<(), String> {
     let cab_path = temp_dir.join(format!("{}_{}", std::process::id(), &cab_name));
 
     // 下载 cab 文件（流式写入磁盘）
-    let download_result = (|| -> Result<(), String> {
-        let client = reqwest::blocking::Client::builder()
+    let download = || -> Result<(), String> {
+        let mut client_builder = reqwest::blocking::Client::builder()
             .danger_accept_invalid_certs(false)
             .tls_built_in_root_certs(true)
             .connect_timeout(std::time::Duration::from_secs(30))
-            .timeout(std::time::Duration::from_secs(600))
+            .timeout(std::time::Duration::from_secs(600));
+        // 前端尚未加载，手动代理从 exe 目录下保存的代理设置读取
+        let saved_proxy = mxu_lib::commands::proxy::get_saved_proxy();
+        client_builder =
+            match mxu_lib::commands::proxy::resolve_proxy(saved_proxy.as_deref(), "WebView2 下载")?
+            {
+                Some(proxy) => client_builder.proxy(proxy),
+                None => client_builder.no_proxy(),
+            };
+        let client = client_builder
             .build()
             .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
 
@@ -469,15 +632,36 @@ pub fn download_and_extract() -> Result<(), String> {
         std::io::Write::flush(&mut file).map_err(|e| format!("刷新文件缓冲失败: {}", e))?;
 
         Ok(())
-    })();
+    };
 
-    let download_err = download_result.err();
-    if let Some(ref e) = download_err {
+    // 下载后校验哈希，不匹配时删除重新下载；网络错误不重试
+    let mut download_result = Err(String::new());
+    for attempt in 1..=MAX_DOWNLOAD_ATTEMPTS {
+        if let Err(e) = download() {
+            download_result = Err(e);
+            break;
+        }
+        download_result = verify_cab_sha256(&cab_path, expected_sha256);
+        let Err(ref e) = download_result else {
+            break;
+        };
+        warn!(
+            "WebView2 cab 校验失败（第 {}/{} 次下载）: {}",
+            attempt, MAX_DOWNLOAD_ATTEMPTS, e
+        );
+        let _ = std::fs::remove_file(&cab_path);
+        if let Some(ref pw) = progress_dialog {
+            pw.set_progress(0);
+            pw.set_status("文件校验失败，正在重新下载...".to_string());
+        }
+    }
+
+    if let Err(e) = download_result {
         if let Some(pw) = progress_dialog {
             pw.close();
         }
         let _ = std::fs::remove_file(&cab_path);
-        return Err(e.clone());
+        return Err(e);
     }
 
     // 更新进度：解压中
@@ -499,7 +683,7 @@ pub fn download_and_extract() -> Result<(), String> {
     extract_result?;
 
     // 校验运行时目录完整性
-    validate_runtime_dir(&runtime_dir)?;
+    validate_installed_runtime(&runtime_dir)?;
 
     // 设置环境变量供当前进程使用
     info!(
@@ -534,8 +718,11 @@ pub fn ensure_webview2() -> bool {
                  方法三：加入我们的 QQ 群，获取帮助和支持\r\n\
                  - 群号可在我们的官网或文档底部找到\r\n\r\n\
                  点击确定后将尝试下载独立 WebView2 运行时以继续运行。\r\n\
-                 若想恢复使用系统 WebView2，请删除 exe 目录下的 cache/webview2_runtime 文件夹",
-                reason
+                 若想恢复使用系统 WebView2，请删除 {} 文件夹",
+                reason,
+                get_webview2_runtime_dir()
+                    .map(|d| d.display().to_string())
+                    .unwrap_or_else(|_| "cache/webview2_runtime".to_string())
             ),
         );
     } else if is_webview2_installed() {
@@ -544,8 +731,12 @@ pub fn ensure_webview2() -> bool {
         return true;
     }
 
-    // 系统不可用或被禁用，下载独立 WebView2 运行时
+    // 系统不可用或被禁用，下载独立 WebView2 运行时（已有但损坏的先清理掉）
     info!("系统 WebView2 不可用，尝试下载独立运行时");
+    if let Err(e) = remove_broken_runtime() {
+        show_download_failed_dialog(&e);
+        return false;
+    }
     match download_and_extract() {
         Ok(()) => true,
         Err(e) => {
@@ -6,3 +6,4 @@ mod install;
 
 pub use install::ensure_webview2;
 pub use install::get_webview2_runtime_dir;
+pub use install::is_runtime_dir_valid;
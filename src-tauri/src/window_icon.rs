@@ -0,0 +1,150 @@
+//! Win32 窗口图标提取
+//!
+//! 通过窗口所属进程的可执行文件提取图标（`SHGetFileInfo`），转换为 PNG data URL 供前端展示。
+//! 同一可执行文件的图标按路径缓存，提取失败同样缓存为空，避免重复尝试。
+//! 非 Windows 平台始终返回 `None`。
+
+/// 获取窗口所属进程的图标（PNG data URL），无法获取时返回 `None`
+#[cfg(windows)]
+pub fn window_icon(hwnd: u64) -> Option<String> {
+    use std::collections::HashMap;
+    use std::sync::{LazyLock, Mutex};
+
+    /// 可执行文件路径 -> 图标 data URL
+    static ICON_CACHE: LazyLock<Mutex<HashMap<String, Option<String>>>> =
+        LazyLock::new(|| Mutex::new(HashMap::new()));
+
+    let exe_path = process_path(hwnd)?;
+    if let Some(cached) = ICON_CACHE.lock().ok()?.get(&exe_path) {
+        return cached.clone();
+    }
+
+    let icon = extract_icon_rgba(&exe_path).map(|(width, height, rgba)| {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+        let png = crate::commands::utils::encode_png(width, height, &rgba);
+        format!("data:image/png;base64,{}", STANDARD.encode(png))
+    });
+    if icon.is_none() {
+        log::debug!("window_icon: no icon for {}", exe_path);
+    }
+
+    if let Ok(mut cache) = ICON_CACHE.lock() {
+        cache.insert(exe_path, icon.clone());
+    }
+    icon
+}
+
+#[cfg(not(windows))]
+pub fn window_icon(hwnd: u64) -> Option<String> {
+    let _ = hwnd;
+    None
+}
+
+/// 获取窗口所属进程的可执行文件路径（枚举窗口时频繁调用，不输出日志）
+#[cfg(windows)]
+fn process_path(hwnd: u64) -> Option<String> {
+    use winsafe::co::{PROCESS, PROCESS_NAME};
+    use winsafe::{HPROCESS, HWND};
+
+    if hwnd == 0 {
+        return None;
+    }
+    let hwnd = unsafe { HWND::from_ptr(hwnd as *mut _) };
+    let (_, pid) = hwnd.GetWindowThreadProcessId();
+    if pid == 0 {
+        return None;
+    }
+    let process = HPROCESS::OpenProcess(PROCESS::QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+    process.QueryFullProcessImageName(PROCESS_NAME::WIN32).ok()
+}
+
+/// 提取可执行文件的大图标，返回 (宽, 高, RGBA 像素)
+#[cfg(windows)]
+fn extract_icon_rgba(exe_path: &str) -> Option<(u32, u32, Vec<u8>)> {
+    use winsafe::co::{FILE_ATTRIBUTE, SHGFI};
+    use winsafe::guard::DeleteObjectGuard;
+
+    let (_, shfi) = winsafe::SHGetFileInfo(
+        exe_path,
+        FILE_ATTRIBUTE::NORMAL,
+        SHGFI::ICON | SHGFI::LARGEICON,
+    )
+    .ok()?;
+    if shfi.hIcon.ptr().is_null() {
+        return None;
+    }
+
+    // GetIconInfo 创建的位图需由调用方释放
+    let info = shfi.hIcon.GetIconInfo().ok()?;
+    let (color, mask) = unsafe {
+        (
+            DeleteObjectGuard::new(info.hbmColor.raw_copy()),
+            DeleteObjectGuard::new(info.hbmMask.raw_copy()),
+        )
+    };
+    icon_bitmaps_to_rgba(&color, &mask)
+}
+
+/// 读取图标的彩色位图和掩码位图，合成 RGBA 像素
+///
+/// 彩色位图没有 alpha 通道（全部为 0）时按掩码位图计算透明度
+#[cfg(windows)]
+fn icon_bitmaps_to_rgba(
+    color: &winsafe::HBITMAP,
+    mask: &winsafe::HBITMAP,
+) -> Option<(u32, u32, Vec<u8>)> {
+    use winsafe::{co, BITMAPINFO, HBITMAP, HWND};
+
+    // 仅有掩码位图的单色图标不处理
+    if color.ptr().is_null() {
+        return None;
+    }
+
+    let bitmap = color.GetObject().ok()?;
+    let (width, height) = (bitmap.bmWidth, bitmap.bmHeight);
+    if width <= 0 || height <= 0 {
+        return None;
+    }
+
+    let hdc = HWND::DESKTOP.GetDC().ok()?;
+    // 以 32 位自顶向下格式读取位图像素（BGRA）
+    let read_bits = |hbm: &HBITMAP| -> Option<Vec<u8>> {
+        let mut bmi = BITMAPINFO::default();
+        bmi.bmiHeader.biWidth = width;
+        bmi.bmiHeader.biHeight = -height;
+        bmi.bmiHeader.biPlanes = 1;
+        bmi.bmiHeader.biBitCount = 32;
+        bmi.bmiHeader.biCompression = co::BI::RGB;
+        let mut pixels = vec![0u8; (width * height * 4) as usize];
+        let lines = unsafe {
+            hdc.GetDIBits(
+                hbm,
+                0,
+                height as u32,
+                Some(&mut pixels),
+                &mut bmi,
+                co::DIB::RGB_COLORS,
+            )
+        };
+        matches!(lines, Ok(n) if n == height).then_some(pixels)
+    };
+    let mut pixels = read_bits(color)?;
+    let mask_bits = if mask.ptr().is_null() {
+        None
+    } else {
+        read_bits(mask)
+    };
+
+    let has_alpha = pixels.chunks_exact(4).any(|p| p[3] != 0);
+    for (i, px) in pixels.chunks_exact_mut(4).enumerate() {
+        px.swap(0, 2);
+        if !has_alpha {
+            // 掩码为白色的像素透明
+            let transparent = mask_bits
+                .as_ref()
+                .is_some_and(|m| m.get(i * 4).copied().unwrap_or(0) != 0);
+            px[3] = if transparent { 0 } else { 255 };
+        }
+    }
+    Some((width as u32, height as u32, pixels))
+}
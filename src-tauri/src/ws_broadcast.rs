@@ -20,6 +20,8 @@ pub enum WsEvent {
     #[serde(rename = "maa-agent-output")]
     AgentOutput {
         instance_id: String,
+        /// 实例显示名（用于多实例日志标签）
+        instance_name: Option<String>,
         stream: String,
         line: String,
     },
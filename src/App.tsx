@@ -16,7 +16,8 @@ import {
   ConnectionPanel,
 } from '@/components';
 import { BackgroundOverlay } from '@/components/BackgroundOverlay';
-import type { BadPathType } from '@/components';
+import { BreakpointModal } from '@/components/BreakpointModal';
+import type { BadPathType, VolumeIssueType } from '@/components';
 import {
   autoLoadInterface,
   loadConfig,
@@ -28,6 +29,7 @@ import {
   maaService,
   proxySettingsForUpdateDownload,
   stopInstanceTasksAndExitApp,
+  finishStopAll,
 } from '@/services';
 import { loadIconAsDataUrl } from '@/services/contentResolver';
 import * as wsService from '@/services/wsService';
@@ -55,8 +57,12 @@ async function getGlobalShortcut() {
 import { loggers } from '@/utils/logger';
 import { setBackendPort, getApiBase, apiGet } from '@/utils/backendApi';
 import { getAllLogsFromBackend } from '@/utils/logStdout';
-import { useMaaCallbackLogger, useMaaAgentLogger } from '@/utils/useMaaCallbackLogger';
-import { getInterfaceLangKey } from '@/i18n';
+import {
+  getSelectedTaskDisplayName,
+  useMaaCallbackLogger,
+  useMaaAgentLogger,
+} from '@/utils/useMaaCallbackLogger';
+import i18n, { getCurrentLanguage, getInterfaceLangKey } from '@/i18n';
 import { applyTheme, resolveThemeMode, registerCustomAccent, clearCustomAccents } from '@/themes';
 import { Toaster } from 'sonner';
 import { loadWebUIAppearance, loadWebUILayout } from '@/services/appearanceStorage';
@@ -84,8 +90,10 @@ import { ConnectionLostOverlay } from './components/app/ConnectionLostOverlay';
 import { WebUIBetaBanner } from './components/app/WebUIBetaBanner';
 import { startGlobalCallbackListener } from './components/connection/callbackCache';
 import { useIsMobile } from '@/hooks/useIsMobile';
+import { useBackendHealth } from '@/hooks/useBackendHealth';
 import { ScrollText } from 'lucide-react';
 import { defaultWindowSize } from '@/types/config';
+import type { InstanceStopResult } from '@/types/maa';
 
 const log = loggers.app;
 
@@ -99,6 +107,37 @@ const LazySettingsPage = lazy(async () => {
   return { default: module.SettingsPage };
 });
 
+/**
+ * 任务队列结束后生成汇总图片报告（需在设置中启用）
+ * 生成失败只记录警告，不影响任务结果
+ */
+async function generateTaskReportIfEnabled(instanceId: string) {
+  const state = useAppStore.getState();
+  if (!isTauri() || !state.taskReport.enabled) return;
+
+  const instance = state.instances.find((i) => i.id === instanceId);
+  if (!instance) return;
+
+  const taskNames: Record<string, string> = {};
+  for (const task of instance.selectedTasks) {
+    taskNames[task.id] = getSelectedTaskDisplayName(instanceId, task.id) ?? task.taskName;
+  }
+
+  try {
+    const path = await maaService.generateTaskReport(instanceId, {
+      title: instance.name,
+      task_names: taskNames,
+      screenshots: state.taskReport.screenshots,
+    });
+    state.addLog(instanceId, {
+      type: 'info',
+      message: i18n.t('logs.messages.taskReportGenerated', { path }),
+    });
+  } catch (err) {
+    log.warn('生成任务报告失败:', err);
+  }
+}
+
 const LazyWelcomeDialog = lazy(async () => {
   const module = await import('@/components/WelcomeDialog');
   return { default: module.WelcomeDialog };
@@ -129,6 +168,11 @@ const LazyBadPathModal = lazy(async () => {
   return { default: module.BadPathModal };
 });
 
+const LazyCpuUnsupportedModal = lazy(async () => {
+  const module = await import('@/components/CpuUnsupportedModal');
+  return { default: module.CpuUnsupportedModal };
+});
+
 const LazyVersionWarningModal = lazy(async () => {
   const module = await import('./components/app/VersionWarningModal');
   return { default: module.VersionWarningModal };
@@ -143,6 +187,8 @@ function App() {
   const [showVCRedistModal, setShowVCRedistModal] = useState(false);
   const [showBadPathModal, setShowBadPathModal] = useState(false);
   const [badPathType, setBadPathType] = useState<BadPathType>('root');
+  const [volumeIssue, setVolumeIssue] = useState<VolumeIssueType | null>(null);
+  const [cpuMissingFeatures, setCpuMissingFeatures] = useState<string[]>([]);
   const [backgroundImageDataUrl, setBackgroundImageDataUrl] = useState<string | undefined>(
     undefined,
   );
@@ -158,6 +204,7 @@ function App() {
   // 启用 MAA 回调日志监听
   useMaaCallbackLogger();
   useMaaAgentLogger();
+  useBackendHealth();
 
   useEffect(() => {
     void startGlobalCallbackListener().catch(() => {});
@@ -528,6 +575,37 @@ function App() {
     }
   }, [projectInterface, language, interfaceTranslations]);
 
+  // 同步托盘菜单语言（system 偏好需使用 i18n 解析后的实际语言）
+  useEffect(() => {
+    if (!isTauri()) return;
+    invoke('set_tray_language', { language: getCurrentLanguage() }).catch((err) => {
+      log.warn('设置托盘语言失败:', err);
+    });
+  }, [language]);
+
+  // 托盘图标进度环：汇总所有运行中实例的任务完成情况，无任务运行时恢复原图标
+  const trayProgress = useAppStore((state) => {
+    let current = 0;
+    let total = 0;
+    for (const instance of state.instances) {
+      if (!instance.isRunning) continue;
+      const statuses = Object.values(state.instanceTaskRunStatus[instance.id] ?? {}).filter(
+        (status) => status !== 'idle',
+      );
+      total += statuses.length;
+      current += statuses.filter((status) => status === 'succeeded' || status === 'failed').length;
+    }
+    return `${current}/${total}`;
+  });
+
+  useEffect(() => {
+    if (!isTauri()) return;
+    const [current, total] = trayProgress.split('/').map(Number);
+    invoke('set_tray_progress', { current, total }).catch((err) => {
+      log.warn('设置托盘进度失败:', err);
+    });
+  }, [trayProgress]);
+
   // 设置窗口图标（根据 ProjectInterface V2 协议）
   useEffect(() => {
     if (!projectInterface?.icon) return;
@@ -744,6 +822,10 @@ function App() {
       // 检查 MaaFramework 版本兼容性
       // 注意：即使完整库加载失败（旧版本缺少某些函数），版本检查仍应工作
       try {
+        // 开机自启动配置了延迟时，等系统稳定后再初始化
+        if (isAutoStart) {
+          await invoke('wait_autostart_delay');
+        }
         // 尝试初始化，即使失败也会设置 lib_dir
         try {
           await maaService.init();
@@ -1098,6 +1180,28 @@ function App() {
         } catch (err) {
           log.warn('检查程序路径失败:', err);
         }
+
+        // 网络盘/可移动盘/只读/云同步目录仅警告，不阻止加载
+        try {
+          const volume = await invoke<{ issue: VolumeIssueType | null }>('check_exe_volume');
+          if (volume.issue) {
+            log.warn('检测到程序所在卷问题:', volume.issue);
+            setVolumeIssue(volume.issue);
+          }
+        } catch (err) {
+          log.warn('检查程序所在卷失败:', err);
+        }
+
+        // CPU 缺少 MaaFramework 所需指令集时不会加载 MaaFramework，明确提示而非运行中崩溃
+        try {
+          const cpu = await invoke<{ missing: string[] }>('check_cpu_features');
+          if (cpu.missing.length > 0) {
+            log.error('CPU 不支持所需指令集:', cpu.missing);
+            setCpuMissingFeatures(cpu.missing);
+          }
+        } catch (err) {
+          log.warn('检查 CPU 指令集失败:', err);
+        }
       }
 
       // 路径没问题，继续加载 interface
@@ -1218,8 +1322,9 @@ function App() {
       kind === 'task-progress' ||
       kind === 'tasks-completed';
 
-    const handleStateChanged = (_instanceId: string, kind: string) => {
+    const handleStateChanged = (instanceId: string, kind: string) => {
       if (isTaskKind(kind)) pendingTaskKind = true;
+      if (kind === 'tasks-completed') void generateTaskReportIfEnabled(instanceId);
       if (debounceTimer) clearTimeout(debounceTimer);
       const shouldSyncRunning = pendingTaskKind;
       debounceTimer = setTimeout(async () => {
@@ -1627,12 +1732,13 @@ function App() {
     };
   }, [hotkeys?.globalEnabled, hotkeys?.startTasks, hotkeys?.stopTasks]);
 
-  // 监听托盘菜单事件（开始/停止任务）
+  // 监听托盘菜单事件（开始/停止任务、切换实例）
   useEffect(() => {
     if (!isTauri()) return;
 
     let unlistenStart: (() => void) | null = null;
     let unlistenStop: (() => void) | null = null;
+    let unlistenFocus: (() => void) | null = null;
 
     const setupTrayListeners = async () => {
       try {
@@ -1645,9 +1751,19 @@ function App() {
           );
         });
 
-        unlistenStop = await listen('tray-stop-tasks', () => {
-          log.info('收到托盘停止任务事件');
-          document.dispatchEvent(new CustomEvent('mxu-stop-tasks', { detail: { source: 'tray' } }));
+        // 托盘停止由后端一次停下所有实例，这里只对已停下的实例做收尾
+        unlistenStop = await listen<InstanceStopResult[]>('tray-stop-all-finished', (event) => {
+          log.info('收到托盘停止所有任务结果:', event.payload);
+          void finishStopAll(event.payload);
+        });
+
+        unlistenFocus = await listen<{ instance_id: string }>('tray-focus-instance', (event) => {
+          const { instance_id: instanceId } = event.payload;
+          log.info('收到托盘切换实例事件:', instanceId);
+          const { instances, setActiveInstance, setCurrentPage } = useAppStore.getState();
+          if (!instances.some((i) => i.id === instanceId)) return;
+          setActiveInstance(instanceId);
+          setCurrentPage('main');
         });
 
         log.info('托盘事件监听已注册');
@@ -1661,6 +1777,7 @@ function App() {
     return () => {
       if (unlistenStart) unlistenStart();
       if (unlistenStop) unlistenStop();
+      if (unlistenFocus) unlistenFocus();
     };
   }, []);
 
@@ -1842,6 +1959,26 @@ function App() {
           </Suspense>
         )}
 
+        {/* 程序所在卷问题提示（可继续使用） */}
+        {volumeIssue && !showBadPathModal && (
+          <Suspense fallback={null}>
+            <LazyBadPathModal show type={volumeIssue} onContinue={() => setVolumeIssue(null)} />
+          </Suspense>
+        )}
+
+        {/* CPU 指令集不满足要求提示 */}
+        {cpuMissingFeatures.length > 0 && !showBadPathModal && (
+          <Suspense fallback={null}>
+            <LazyCpuUnsupportedModal
+              missing={cpuMissingFeatures}
+              onContinue={() => setCpuMissingFeatures([])}
+            />
+          </Suspense>
+        )}
+
+        {/* Pipeline 断点命中弹窗 */}
+        <BreakpointModal />
+
         {/* MaaFramework 版本警告弹窗 */}
         {versionWarning && (
           <Suspense fallback={null}>
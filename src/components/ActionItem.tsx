@@ -27,6 +27,7 @@ const defaultValues: Omit<ActionConfig, 'id'> = {
   waitForExit: false,
   skipIfRunning: true,
   useCmd: false,
+  hideWindow: false,
 };
 
 /** 参数预览标签 */
@@ -363,6 +364,15 @@ export function ActionItem({
                 disabled={disabled}
               />
             )}
+            {isWindowsBackend && (
+              <SwitchField
+                label={t('action.hideWindow')}
+                hint={t('action.hideWindowHint')}
+                value={currentAction.hideWindow ?? false}
+                onChange={(v) => updateAction({ hideWindow: v })}
+                disabled={disabled}
+              />
+            )}
           </div>
         </div>
       </div>
@@ -2,14 +2,19 @@ import { useTranslation } from 'react-i18next';
 import { AlertTriangle, FolderOpen } from 'lucide-react';
 import { exit } from '@tauri-apps/plugin-process';
 
-export type BadPathType = 'root' | 'temp';
+export type BadPathType = 'root' | 'temp' | VolumeIssueType;
+
+/** 程序所在卷的问题类型（可继续使用，仅警告） */
+export type VolumeIssueType = 'network' | 'removable' | 'readonly' | 'cloud';
 
 interface BadPathModalProps {
   show: boolean;
   type: BadPathType;
+  /** 提供时显示「继续使用」按钮（卷问题不阻止使用） */
+  onContinue?: () => void;
 }
 
-export function BadPathModal({ show, type }: BadPathModalProps) {
+export function BadPathModal({ show, type, onContinue }: BadPathModalProps) {
   const { t } = useTranslation();
 
   const handleExit = async () => {
@@ -19,6 +24,7 @@ export function BadPathModal({ show, type }: BadPathModalProps) {
   if (!show) return null;
 
   const isRoot = type === 'root';
+  const isTemp = type === 'temp';
 
   return (
     <div className="fixed inset-0 z-50 flex items-center justify-center bg-black/50 backdrop-blur-sm animate-in fade-in duration-200">
@@ -44,7 +50,9 @@ export function BadPathModal({ show, type }: BadPathModalProps) {
               <p className="text-text-primary font-medium">
                 {isRoot
                   ? t('badPath.rootTitle', '别把程序放在磁盘根目录啦！')
-                  : t('badPath.tempTitle', '你好像直接双击压缩包里的程序了')}
+                  : isTemp
+                    ? t('badPath.tempTitle', '你好像直接双击压缩包里的程序了')
+                    : t(`badPath.${type}Title`)}
               </p>
               <p className="text-sm text-text-secondary">
                 {isRoot
@@ -52,10 +60,12 @@ export function BadPathModal({ show, type }: BadPathModalProps) {
                       'badPath.rootDescription',
                       '程序放在 C盘、D盘 这种根目录下会出问题的。找个文件夹放进去再用吧，比如「D:\\我的软件\\」之类的。',
                     )
-                  : t(
-                      'badPath.tempDescription',
-                      '程序现在在临时目录里跑着呢，一关掉可能就没了。先把压缩包解压到一个文件夹里，再打开里面的程序吧。',
-                    )}
+                  : isTemp
+                    ? t(
+                        'badPath.tempDescription',
+                        '程序现在在临时目录里跑着呢，一关掉可能就没了。先把压缩包解压到一个文件夹里，再打开里面的程序吧。',
+                      )
+                    : t(`badPath.${type}Description`)}
               </p>
             </div>
           </div>
@@ -73,6 +83,14 @@ export function BadPathModal({ show, type }: BadPathModalProps) {
 
         {/* 底部按钮 */}
         <div className="flex items-center justify-end gap-2 px-4 py-3 bg-bg-tertiary border-t border-border">
+          {onContinue && (
+            <button
+              onClick={onContinue}
+              className="px-4 py-2 text-sm text-text-secondary hover:bg-bg-hover rounded-lg transition-colors"
+            >
+              {t('badPath.continue')}
+            </button>
+          )}
           <button
             onClick={handleExit}
             className="px-4 py-2 text-sm bg-accent text-white hover:bg-accent-hover rounded-lg transition-colors"
@@ -0,0 +1,134 @@
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { CirclePause, Play } from 'lucide-react';
+
+import { maaService, type BreakpointHitEvent } from '@/services/maaService';
+import { useAppStore } from '@/stores/appStore';
+import { loggers } from '@/utils/logger';
+
+const log = loggers.task;
+
+/**
+ * Pipeline 断点命中弹窗
+ *
+ * 自行监听断点事件：任务执行到断点节点时展示当时画面和识别结果，确认后继续执行
+ */
+export function BreakpointModal() {
+  const { t } = useTranslation();
+  const instances = useAppStore((s) => s.instances);
+  // 多个实例可能同时命中断点，按命中顺序逐个处理
+  const [hits, setHits] = useState<BreakpointHitEvent[]>([]);
+
+  useEffect(() => {
+    let cancelled = false;
+    const unlisteners: (() => void)[] = [];
+
+    const setup = async () => {
+      const unlistenHit = await maaService.onBreakpointHit((hit) => {
+        setHits((prev) => [...prev.filter((h) => h.instance_id !== hit.instance_id), hit]);
+      });
+      const unlistenResumed = await maaService.onBreakpointResumed(({ instance_id }) => {
+        setHits((prev) => prev.filter((h) => h.instance_id !== instance_id));
+      });
+      if (cancelled) {
+        unlistenHit();
+        unlistenResumed();
+      } else {
+        unlisteners.push(unlistenHit, unlistenResumed);
+      }
+    };
+    setup().catch((err) => log.warn('Failed to setup breakpoint listener:', err));
+
+    return () => {
+      cancelled = true;
+      unlisteners.forEach((unlisten) => unlisten());
+    };
+  }, []);
+
+  const hit = hits[0];
+  if (!hit) return null;
+
+  const instanceName = instances.find((i) => i.id === hit.instance_id)?.name ?? hit.instance_id;
+
+  const handleContinue = async () => {
+    try {
+      await maaService.continueFromBreakpoint(hit.instance_id);
+    } catch (err) {
+      log.error('从断点继续失败:', err);
+    }
+    setHits((prev) => prev.filter((h) => h.instance_id !== hit.instance_id));
+  };
+
+  const handleClearAndContinue = async () => {
+    try {
+      await maaService.setBreakpoints(hit.instance_id, []);
+    } catch (err) {
+      log.error('移除断点失败:', err);
+    }
+    setHits((prev) => prev.filter((h) => h.instance_id !== hit.instance_id));
+  };
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-center justify-center bg-black/50 backdrop-blur-sm animate-in fade-in duration-200">
+      <div className="w-full max-w-2xl mx-4 bg-bg-secondary rounded-xl shadow-2xl border border-border overflow-hidden animate-in zoom-in-95 duration-200">
+        {/* 标题栏 */}
+        <div className="flex items-center px-4 py-3 bg-bg-tertiary border-b border-border">
+          <div className="flex items-center gap-2">
+            <CirclePause className="w-5 h-5 text-warning" />
+            <span className="text-sm font-medium text-text-primary">
+              {t('breakpoint.title', { instance: instanceName })}
+            </span>
+          </div>
+        </div>
+
+        {/* 内容区 */}
+        <div className="p-5 space-y-4 max-h-[70vh] overflow-y-auto">
+          <p className="text-sm text-text-secondary">
+            {t('breakpoint.pausedAt')}
+            <span className="ml-1 font-mono text-text-primary">{hit.node}</span>
+          </p>
+
+          {hit.screenshot ? (
+            <img
+              src={hit.screenshot}
+              alt={hit.node}
+              className="w-full rounded-lg border border-border"
+            />
+          ) : (
+            <div className="p-3 bg-bg-tertiary rounded-lg text-xs text-text-muted">
+              {t('breakpoint.noScreenshot')}
+            </div>
+          )}
+
+          {hit.recognition && (
+            <details className="bg-bg-tertiary rounded-lg">
+              <summary className="px-3 py-2 text-sm text-text-secondary cursor-pointer">
+                {t('breakpoint.recognition')}
+              </summary>
+              <pre className="px-3 pb-3 text-xs font-mono text-text-muted whitespace-pre-wrap break-all">
+                {JSON.stringify(hit.recognition, null, 2)}
+              </pre>
+            </details>
+          )}
+        </div>
+
+        {/* 底部按钮 */}
+        <div className="flex items-center justify-end gap-2 px-4 py-3 bg-bg-tertiary border-t border-border">
+          <button
+            onClick={handleClearAndContinue}
+            className="px-4 py-2 text-sm text-text-secondary hover:bg-bg-hover rounded-lg transition-colors"
+          >
+            {t('breakpoint.clearAndContinue')}
+          </button>
+          <button
+            onClick={handleContinue}
+            className="flex items-center gap-1.5 px-4 py-2 text-sm bg-accent text-white hover:bg-accent-hover rounded-lg transition-colors"
+          >
+            <Play className="w-4 h-4" />
+            {t('breakpoint.continue')}
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
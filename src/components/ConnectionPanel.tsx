@@ -10,6 +10,7 @@ import {
   Loader2,
   Check,
   AlertCircle,
+  AlertTriangle,
   Wifi,
   WifiOff,
   CheckCircle,
@@ -23,7 +24,8 @@ import { resolveI18nText } from '@/services/contentResolver';
 import type { AdbDevice, Win32Window, ControllerConfig } from '@/types/maa';
 import type { ControllerItem, ResourceItem } from '@/types/interface';
 import { computeResourcePaths } from '@/utils/resourcePath';
-import { getProcessNameFromPath } from '@/utils/paths';
+import { getProcessNameFromPath, isTauri } from '@/utils/paths';
+import { getEmulatorLaunch } from '@/utils/emulatorLaunch';
 import { parseWin32ScreencapMethod, parseWin32InputMethod } from '@/types/maa';
 import { getInterfaceLangKey } from '@/i18n';
 import { generateId } from '@/stores/helpers';
@@ -32,6 +34,7 @@ import {
   waitForCtrlResult,
   waitForResResult,
   autoReconnectAttempted,
+  EmulatorLaunchEditor,
 } from './connection';
 
 export function ConnectionPanel() {
@@ -55,6 +58,7 @@ export function ConnectionPanel() {
     setSelectedResource,
     instanceConnectionStatus,
     instanceResourceLoaded,
+    instanceResolutionCheck,
     setInstanceConnectionStatus,
     setInstanceResourceLoaded,
     setInstanceSavedDevice,
@@ -75,6 +79,14 @@ export function ConnectionPanel() {
     ? instanceConnectionStatus[activeInstanceId]
     : undefined;
   const storedResourceLoaded = activeInstanceId ? instanceResourceLoaded[activeInstanceId] : false;
+  const resolutionCheck = activeInstanceId ? instanceResolutionCheck[activeInstanceId] : undefined;
+  const resolutionWarning =
+    resolutionCheck && !resolutionCheck.matched
+      ? t('controller.resolutionMismatch', {
+          actual: resolutionCheck.actual.join('x'),
+          expected: resolutionCheck.expected.join('x'),
+        })
+      : null;
 
   // 设备相关状态
   const [isSearching, setIsSearching] = useState(false);
@@ -95,6 +107,10 @@ export function ConnectionPanel() {
   const [isResourceLoaded, setIsResourceLoaded] = useState(false);
   const [resourceError, setResourceError] = useState<string | null>(null);
   const [showResourceDropdown, setShowResourceDropdown] = useState(false);
+  const [resourceLoadProgress, setResourceLoadProgress] = useState<{
+    done: number;
+    total: number;
+  } | null>(null);
 
   // 记录已加载的资源名称，避免重复加载
   const lastLoadedResourceRef = useRef<string | null>(null);
@@ -310,6 +326,32 @@ export function ConnectionPanel() {
     }
   }, [storedResourceLoaded, isLoadingResource, currentResourceName]);
 
+  // 监听资源加载进度
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+    let disposed = false;
+
+    maaService
+      .onResourceLoadProgress((event) => {
+        if (event.instance_id !== instanceId) return;
+        setResourceLoadProgress({ done: event.loaded + event.failed, total: event.total });
+      })
+      .then((fn) => {
+        if (disposed) fn();
+        else unlisten = fn;
+      });
+
+    return () => {
+      disposed = true;
+      unlisten?.();
+    };
+  }, [instanceId]);
+
+  // 加载结束后清除进度
+  useEffect(() => {
+    if (!isLoadingResource) setResourceLoadProgress(null);
+  }, [isLoadingResource]);
+
   // 判断是否需要搜索设备（PlayCover 不需要搜索）
   const needsDeviceSearch =
     controllerType === 'Adb' ||
@@ -392,7 +434,7 @@ export function ConnectionPanel() {
       const savedDevice = activeInstance?.savedDevice;
 
       if (controllerType === 'Adb') {
-        const devices = await maaService.findAdbDevices();
+        const devices = await maaService.findAdbDevices(useAppStore.getState().adbPath);
         setCachedAdbDevices(devices);
 
         // 自动连接策略：
@@ -505,7 +547,11 @@ export function ConnectionPanel() {
     targetType: 'device' | 'window',
   ) => {
     await startGlobalCallbackListener();
-    const ctrlId = await maaService.connectController(instanceId, config);
+    const ctrlId = await maaService.connectController(
+      instanceId,
+      config,
+      getEmulatorLaunch(instanceId),
+    );
 
     // 注册 ctrl_id 与设备/窗口名及类型的映射，用于日志显示
     registerCtrlIdName(instanceId, ctrlId, deviceName || '', targetType);
@@ -548,7 +594,7 @@ export function ConnectionPanel() {
         throw new Error(t('maa.initFailed'));
       }
 
-      await maaService.createInstance(instanceId).catch(() => {});
+      await maaService.createInstance(instanceId, activeInstance?.name).catch(() => {});
 
       let config: ControllerConfig;
       let deviceName = '';
@@ -627,7 +673,7 @@ export function ConnectionPanel() {
     setResourceError(null);
 
     try {
-      await maaService.createInstance(instanceId).catch(() => {});
+      await maaService.createInstance(instanceId, activeInstance?.name).catch(() => {});
       await startGlobalCallbackListener();
       // 计算完整的资源路径（包括 controller.attach_resource_path）
       const resourcePaths = computeResourcePaths(resource, currentController, basePath);
@@ -789,6 +835,16 @@ export function ConnectionPanel() {
     // 保存设备名称到实例配置
     setInstanceSavedDevice(instanceId, { adbDeviceName: device.name });
 
+    // 未授权的真机连不上，提示用户先在手机上允许 USB 调试
+    if (device.physical && device.auth_state !== 'device') {
+      setDeviceError(
+        device.auth_state === 'unauthorized'
+          ? t('controller.usbUnauthorizedHint')
+          : t('controller.usbOfflineHint', { state: device.auth_state ?? '' }),
+      );
+      return;
+    }
+
     // 自动连接
     setIsConnecting(true);
     setDeviceError(null);
@@ -806,7 +862,7 @@ export function ConnectionPanel() {
         throw new Error(t('maa.initFailed'));
       }
 
-      await maaService.createInstance(instanceId).catch(() => {});
+      await maaService.createInstance(instanceId, activeInstance?.name).catch(() => {});
 
       const config: ControllerConfig = {
         type: 'Adb',
@@ -892,7 +948,7 @@ export function ConnectionPanel() {
         throw new Error(t('maa.initFailed'));
       }
 
-      await maaService.createInstance(instanceId).catch(() => {});
+      await maaService.createInstance(instanceId, activeInstance?.name).catch(() => {});
 
       let config: ControllerConfig;
       if (controllerType === 'Win32') {
@@ -949,7 +1005,7 @@ export function ConnectionPanel() {
         throw new Error(t('maa.initFailed'));
       }
 
-      await maaService.createInstance(instanceId).catch(() => {});
+      await maaService.createInstance(instanceId, activeInstance?.name).catch(() => {});
 
       const config: ControllerConfig = {
         type: 'WlRoots',
@@ -983,7 +1039,7 @@ export function ConnectionPanel() {
       const savedDevice = activeInstance?.savedDevice;
 
       if (controllerType === 'Adb') {
-        const devices = await maaService.findAdbDevices();
+        const devices = await maaService.findAdbDevices(useAppStore.getState().adbPath);
         setCachedAdbDevices(devices);
 
         // 尝试匹配保存的设备名称
@@ -1067,7 +1123,15 @@ export function ConnectionPanel() {
         return cachedAdbDevices.map((device) => ({
           id: `${device.adb_path}:${device.address}`,
           name: device.name,
-          description: device.address,
+          description: device.physical
+            ? `${device.address} · ${
+                device.auth_state === 'device'
+                  ? t('controller.usbDevice')
+                  : device.auth_state === 'unauthorized'
+                    ? t('controller.usbUnauthorized')
+                    : (device.auth_state ?? '')
+              }`
+            : device.address,
           selected: selectedAdbDevice?.address === device.address,
           onClick: () => handleSelectAdbDevice(device),
           isHistorical: false,
@@ -1097,6 +1161,7 @@ export function ConnectionPanel() {
           id: String(window.handle),
           name: window.window_name || '(无标题)',
           description: window.class_name,
+          icon: window.icon,
           selected: selectedWindow?.handle === window.handle,
           onClick: () => handleSelectWindow(window),
           isHistorical: false,
@@ -1224,6 +1289,11 @@ export function ConnectionPanel() {
           >
             <Wifi className="w-3 h-3" />
             {getDeviceStatusText()}
+            {resolutionWarning && (
+              <span title={resolutionWarning}>
+                <AlertTriangle className="w-3 h-3 text-warning" />
+              </span>
+            )}
           </span>
         ) : hasHistoricalDevice && currentController ? (
           <span
@@ -1317,7 +1387,9 @@ export function ConnectionPanel() {
                           // 切换控制器：销毁实例（清除 tasker）并重新创建
                           // 保留资源加载状态（资源在 Rust 后端独立存储）
                           await maaService.destroyInstance(instanceId).catch(() => {});
-                          await maaService.createInstance(instanceId).catch(() => {});
+                          await maaService
+                            .createInstance(instanceId, activeInstance?.name)
+                            .catch(() => {});
 
                           setSelectedController(instanceId, controller.name);
                           setIsConnected(false);
@@ -1482,6 +1554,9 @@ export function ConnectionPanel() {
                               {item.isHistorical && (
                                 <History className="w-3.5 h-3.5 text-warning flex-shrink-0" />
                               )}
+                              {'icon' in item && item.icon && (
+                                <img src={item.icon} alt="" className="w-4 h-4 flex-shrink-0" />
+                              )}
                               <div className="min-w-0 flex-1">
                                 <div className="text-sm text-text-primary truncate">
                                   {item.name}
@@ -1548,6 +1623,34 @@ export function ConnectionPanel() {
               </div>
             )}
 
+            {/* 真机适配提示 */}
+            {isConnected && controllerType === 'Adb' && selectedAdbDevice?.physical && (
+              <div className="flex items-center gap-2 px-2.5 py-1.5 rounded-md bg-bg-tertiary text-text-muted text-xs">
+                <Smartphone className="w-3.5 h-3.5 flex-shrink-0" />
+                <span>{t('controller.usbDeviceHint')}</span>
+              </div>
+            )}
+
+            {/* 分辨率预检警告 */}
+            {isConnected && resolutionWarning && (
+              <div className="flex items-center gap-2 px-2.5 py-1.5 rounded-md bg-warning/10 text-warning text-xs">
+                <AlertTriangle className="w-3.5 h-3.5 flex-shrink-0" />
+                <span>{resolutionWarning}</span>
+              </div>
+            )}
+
+            {/* 连接前启动模拟器 */}
+            {isTauri() &&
+              (controllerType === 'Adb' ||
+                controllerType === 'Win32' ||
+                controllerType === 'Gamepad') && (
+                <EmulatorLaunchEditor
+                  instanceId={instanceId}
+                  controllerType={controllerType}
+                  disabled={isConnecting || isRunning}
+                />
+              )}
+
             {/* 分隔线 */}
             <div className="border-t border-border" />
 
@@ -1618,6 +1721,26 @@ export function ConnectionPanel() {
                 );
               })()}
 
+              {/* 资源加载进度 */}
+              {isLoadingResource && resourceLoadProgress && resourceLoadProgress.total > 0 && (
+                <div className="mt-1.5 space-y-1">
+                  <div className="text-xs text-text-muted">
+                    {t('resource.loadingProgress', {
+                      done: resourceLoadProgress.done,
+                      total: resourceLoadProgress.total,
+                    })}
+                  </div>
+                  <div className="h-1 bg-bg-tertiary rounded-full overflow-hidden">
+                    <div
+                      className="h-full bg-accent transition-all duration-300"
+                      style={{
+                        width: `${Math.max(5, (resourceLoadProgress.done / resourceLoadProgress.total) * 100)}%`,
+                      }}
+                    />
+                  </div>
+                </div>
+              )}
+
               {/* 资源下拉菜单 - 使用 fixed 定位向上展开 */}
               {showResourceDropdown && resourceDropdownPos && (
                 <div
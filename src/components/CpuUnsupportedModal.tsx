@@ -0,0 +1,66 @@
+import { useTranslation } from 'react-i18next';
+import { AlertTriangle, Cpu } from 'lucide-react';
+import { exit } from '@tauri-apps/plugin-process';
+
+interface CpuUnsupportedModalProps {
+  /** 缺少的必需指令集 */
+  missing: string[];
+  /** 继续使用（MaaFramework 不会加载，只能查看和编辑配置） */
+  onContinue: () => void;
+}
+
+export function CpuUnsupportedModal({ missing, onContinue }: CpuUnsupportedModalProps) {
+  const { t } = useTranslation();
+  const features = missing.join(' / ');
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-center justify-center bg-black/50 backdrop-blur-sm animate-in fade-in duration-200">
+      <div className="w-full max-w-md mx-4 bg-bg-secondary rounded-xl shadow-2xl border border-border overflow-hidden animate-in zoom-in-95 duration-200">
+        {/* 标题栏 */}
+        <div className="flex items-center px-4 py-3 bg-bg-tertiary border-b border-border">
+          <div className="flex items-center gap-2">
+            <AlertTriangle className="w-5 h-5 text-error" />
+            <span className="text-sm font-medium text-text-primary">
+              {t('cpuUnsupported.title')}
+            </span>
+          </div>
+        </div>
+
+        {/* 内容区 */}
+        <div className="p-5 space-y-4">
+          <div className="flex flex-col items-center text-center space-y-3">
+            <div className="w-16 h-16 rounded-full bg-error/10 flex items-center justify-center">
+              <Cpu className="w-8 h-8 text-error" />
+            </div>
+            <div className="space-y-2">
+              <p className="text-text-primary font-medium">
+                {t('cpuUnsupported.message', { features })}
+              </p>
+              <p className="text-sm text-text-secondary">{t('cpuUnsupported.description')}</p>
+            </div>
+          </div>
+
+          <div className="p-3 bg-bg-tertiary rounded-lg">
+            <p className="text-xs text-text-muted">{t('cpuUnsupported.hint')}</p>
+          </div>
+        </div>
+
+        {/* 底部按钮 */}
+        <div className="flex items-center justify-end gap-2 px-4 py-3 bg-bg-tertiary border-t border-border">
+          <button
+            onClick={onContinue}
+            className="px-4 py-2 text-sm text-text-secondary hover:bg-bg-hover rounded-lg transition-colors"
+          >
+            {t('cpuUnsupported.continue')}
+          </button>
+          <button
+            onClick={() => exit(0)}
+            className="px-4 py-2 text-sm bg-accent text-white hover:bg-accent-hover rounded-lg transition-colors"
+          >
+            {t('cpuUnsupported.exit')}
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
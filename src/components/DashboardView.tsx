@@ -38,7 +38,7 @@ import { isTaskCompatible } from '@/stores/helpers';
 import { isPretaskName, getPretaskItem, buildPretaskArgs, buildPretaskDef } from '@/types/pretasks';
 import { splitTasksIntoThreeSegments } from '@/utils/taskSegmentation';
 import { startGlobalCallbackListener } from '@/components/connection/callbackCache';
-import { stopInstanceTasks } from '@/services/taskStopService';
+import { stopAllInstanceTasks, stopInstanceTasks } from '@/services/taskStopService';
 import { buildPiEnvVars } from '@/utils/piEnv';
 
 const log = loggers.ui;
@@ -927,6 +927,21 @@ export function DashboardView({ onClose }: DashboardViewProps) {
 
   const handleClose = onClose ?? toggleDashboardView;
 
+  const [isStoppingAll, setIsStoppingAll] = useState(false);
+  const hasRunning = instances.some((i) => i.isRunning);
+
+  const handleStopAll = async () => {
+    setIsStoppingAll(true);
+    try {
+      const results = await stopAllInstanceTasks();
+      log.info('全部停止结果:', results);
+    } catch (err) {
+      log.error('全部停止失败:', err);
+    } finally {
+      setIsStoppingAll(false);
+    }
+  };
+
   const handleSelectInstance = (instanceId: string) => {
     setActiveInstance(instanceId);
     handleClose();
@@ -1059,9 +1074,23 @@ export function DashboardView({ onClose }: DashboardViewProps) {
           </div>
         </div>
 
-        {/* 右侧：帧率 + 退出按钮 */}
+        {/* 右侧：帧率 + 全部停止 + 退出按钮 */}
         <div className="flex items-center gap-4">
           <FrameRateSelector compact />
+          {hasRunning && (
+            <button
+              onClick={handleStopAll}
+              disabled={isStoppingAll}
+              className="flex items-center gap-1.5 px-3 py-1.5 text-sm bg-error/10 hover:bg-error/20 text-error rounded-lg transition-colors disabled:opacity-50"
+            >
+              {isStoppingAll ? (
+                <Loader2 className="w-4 h-4 animate-spin" />
+              ) : (
+                <StopCircle className="w-4 h-4" />
+              )}
+              {t('dashboard.stopAll')}
+            </button>
+          )}
           <button
             onClick={handleClose}
             className="px-3 py-1.5 text-sm bg-bg-hover hover:bg-bg-active text-text-secondary rounded-lg transition-colors"
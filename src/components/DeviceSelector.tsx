@@ -21,6 +21,7 @@ import type { AdbDevice, Win32Window, ControllerConfig } from '@/types/maa';
 import type { ControllerItem } from '@/types/interface';
 import { parseWin32ScreencapMethod, parseWin32InputMethod } from '@/types/maa';
 import { loggers } from '@/utils/logger';
+import { getEmulatorLaunch } from '@/utils/emulatorLaunch';
 
 const log = loggers.device;
 
@@ -172,7 +173,7 @@ export function DeviceSelector({
       }
 
       if (controllerType === 'Adb') {
-        const devices = await maaService.findAdbDevices();
+        const devices = await maaService.findAdbDevices(useAppStore.getState().adbPath);
         setCachedAdbDevices(devices);
         if (devices.length === 1) {
           setSelectedAdbDevice(devices[0]);
@@ -268,7 +269,11 @@ export function DeviceSelector({
         throw new Error('请先选择设备');
       }
 
-      const ctrlId = await maaService.connectController(instanceId, config);
+      const ctrlId = await maaService.connectController(
+        instanceId,
+        config,
+        getEmulatorLaunch(instanceId),
+      );
 
       // 注册 ctrl_id 与设备名/类型的映射
       let deviceName = '';
@@ -331,6 +336,16 @@ export function DeviceSelector({
     setSelectedAdbDevice(device);
     setShowDropdown(false);
 
+    // 未授权的真机连不上，提示用户先在手机上允许 USB 调试
+    if (device.physical && device.auth_state !== 'device') {
+      setError(
+        device.auth_state === 'unauthorized'
+          ? t('controller.usbUnauthorizedHint')
+          : t('controller.usbOfflineHint', { state: device.auth_state ?? '' }),
+      );
+      return;
+    }
+
     // 自动连接
     setIsConnecting(true);
     setError(null);
@@ -353,7 +368,11 @@ export function DeviceSelector({
         display_short_side: controllerDef.display_short_side,
       };
 
-      const ctrlId = await maaService.connectController(instanceId, config);
+      const ctrlId = await maaService.connectController(
+        instanceId,
+        config,
+        getEmulatorLaunch(instanceId),
+      );
 
       // 注册 ctrl_id 与设备名/类型的映射
       registerCtrlIdName(instanceId, ctrlId, device.name || device.address, 'device');
@@ -404,7 +423,11 @@ export function DeviceSelector({
         };
       }
 
-      const ctrlId = await maaService.connectController(instanceId, config);
+      const ctrlId = await maaService.connectController(
+        instanceId,
+        config,
+        getEmulatorLaunch(instanceId),
+      );
 
       // 注册 ctrl_id 与窗口名/类型的映射
       registerCtrlIdName(instanceId, ctrlId, win.window_name || win.class_name, 'window');
@@ -443,7 +466,11 @@ export function DeviceSelector({
         use_win32_vk_code: controllerDef.wlroots?.use_win32_vk_code ?? false,
       };
 
-      const ctrlId = await maaService.connectController(instanceId, config);
+      const ctrlId = await maaService.connectController(
+        instanceId,
+        config,
+        getEmulatorLaunch(instanceId),
+      );
 
       // 注册 ctrl_id 与窗口名/类型的映射
       registerCtrlIdName(instanceId, ctrlId, socketPath, 'device');
@@ -465,7 +492,15 @@ export function DeviceSelector({
       return cachedAdbDevices.map((device) => ({
         id: `${device.adb_path}:${device.address}`,
         name: device.name,
-        description: device.address,
+        description: device.physical
+          ? `${device.address} · ${
+              device.auth_state === 'device'
+                ? t('controller.usbDevice')
+                : device.auth_state === 'unauthorized'
+                  ? t('controller.usbUnauthorized')
+                  : (device.auth_state ?? '')
+            }`
+          : device.address,
         selected: selectedAdbDevice?.address === device.address,
         onClick: () => handleSelectAdbDevice(device),
       }));
@@ -475,6 +510,7 @@ export function DeviceSelector({
         id: String(window.handle),
         name: window.window_name || '(无标题)',
         description: window.class_name,
+        icon: window.icon,
         selected: selectedWindow?.handle === window.handle,
         onClick: () => handleSelectWindow(window),
       }));
@@ -642,6 +678,9 @@ export function DeviceSelector({
                         item.selected && 'bg-accent/10',
                       )}
                     >
+                      {'icon' in item && item.icon && (
+                        <img src={item.icon} alt="" className="w-4 h-4 flex-shrink-0 mr-2" />
+                      )}
                       <div className="min-w-0 flex-1">
                         <div className="text-sm text-text-primary truncate">{item.name}</div>
                         <div className="text-xs text-text-muted truncate">{item.description}</div>
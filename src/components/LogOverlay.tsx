@@ -0,0 +1,270 @@
+import { useEffect, useLayoutEffect, useRef, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { Monitor, ScrollText, X } from 'lucide-react';
+import clsx from 'clsx';
+import { maaService } from '@/services/maaService';
+import type { InstanceSummary, OverlayPreviewFrame } from '@/types/maa';
+import { applyTheme, resolveThemeMode } from '@/themes';
+import { getMergedLogsFromBackend, type TaggedLogEntryDto } from '@/utils/logStdout';
+import { loggers } from '@/utils/logger';
+
+const log = loggers.ui;
+
+/** 悬浮窗最多保留的日志条数 */
+const MAX_OVERLAY_LOGS = 200;
+
+function getLogColor(type: string) {
+  switch (type) {
+    case 'success':
+      return 'text-success';
+    case 'warning':
+      return 'text-warning';
+    case 'error':
+      return 'text-error';
+    case 'agent':
+      return 'text-text-muted';
+    case 'focus':
+      return 'text-accent';
+    case 'info':
+      return 'text-info';
+    default:
+      return 'text-text-secondary';
+  }
+}
+
+/** 悬浮窗显示模式：日志 / 画面预览 */
+type OverlayMode = 'log' | 'preview';
+
+/** 画面预览：缩略图上按原始截图坐标叠加当前一轮的识别框（命中为绿色，未命中为红色） */
+function PreviewView({ frame }: { frame: OverlayPreviewFrame }) {
+  const fontSize = Math.max(frame.height / 24, 12);
+  return (
+    <div className="relative w-full h-full">
+      <img src={frame.image} alt="" className="absolute inset-0 w-full h-full object-contain" />
+      <svg
+        viewBox={`0 0 ${frame.width} ${frame.height}`}
+        preserveAspectRatio="xMidYMid meet"
+        className="absolute inset-0 w-full h-full pointer-events-none"
+      >
+        {frame.recognitions.map((reco) => {
+          if (!reco.rect) return null;
+          const [x, y, w, h] = reco.rect;
+          const color = reco.hit ? '#22c55e' : '#ef4444';
+          return (
+            <g key={reco.node}>
+              <rect
+                x={x}
+                y={y}
+                width={w}
+                height={h}
+                fill="none"
+                stroke={color}
+                strokeWidth={2}
+                vectorEffect="non-scaling-stroke"
+              />
+              {reco.score !== null && (
+                <text
+                  x={x}
+                  y={Math.max(y - fontSize / 4, fontSize)}
+                  fill={color}
+                  fontSize={fontSize}
+                >
+                  {reco.score.toFixed(2)}
+                </text>
+              )}
+            </g>
+          );
+        })}
+      </svg>
+    </div>
+  );
+}
+
+/**
+ * 日志悬浮窗（独立窗口 `log-overlay` 中渲染）
+ *
+ * 显示各实例最新的运行日志，点击某条日志让主窗口日志面板跳转到对应位置；
+ * 可切换到画面预览模式，低帧率显示所选实例的画面缩略图并叠加识别框
+ */
+export function LogOverlay() {
+  const { t, i18n } = useTranslation();
+  const [logs, setLogs] = useState<TaggedLogEntryDto[]>([]);
+  const [mode, setMode] = useState<OverlayMode>('log');
+  const [instances, setInstances] = useState<InstanceSummary[]>([]);
+  const [previewInstanceId, setPreviewInstanceId] = useState<string | null>(null);
+  const [frame, setFrame] = useState<OverlayPreviewFrame | null>(null);
+  const containerRef = useRef<HTMLDivElement>(null);
+
+  // 悬浮窗不加载配置，跟随系统深浅色
+  useEffect(() => {
+    applyTheme(resolveThemeMode('system'), 'deepsea');
+  }, []);
+
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+    let disposed = false;
+
+    const setup = async () => {
+      try {
+        const initial = await getMergedLogsFromBackend();
+        if (!disposed) setLogs(initial.slice(-MAX_OVERLAY_LOGS));
+      } catch (err) {
+        log.warn('加载悬浮窗日志失败:', err);
+      }
+      const fn = await maaService.onOverlayLog((entry) => {
+        setLogs((prev) => [...prev, entry].slice(-MAX_OVERLAY_LOGS));
+      });
+      if (disposed) fn();
+      else unlisten = fn;
+    };
+
+    void setup();
+    return () => {
+      disposed = true;
+      if (unlisten) unlisten();
+    };
+  }, []);
+
+  // 进入预览模式时刷新实例列表，默认预览第一个已连接设备的实例
+  useEffect(() => {
+    if (mode !== 'preview') return;
+    maaService
+      .listInstances()
+      .then((list) => {
+        setInstances(list);
+        setPreviewInstanceId((prev) =>
+          prev && list.some((i) => i.id === prev)
+            ? prev
+            : (list.find((i) => i.device) ?? list[0])?.id ?? null,
+        );
+      })
+      .catch((err) => log.warn('获取实例列表失败:', err));
+  }, [mode]);
+
+  // 预览实例变化时通知后端改为推送该实例的画面（后端会替换之前的预览）
+  useEffect(() => {
+    if (mode !== 'preview' || !previewInstanceId) return;
+    let unlisten: (() => void) | null = null;
+    let disposed = false;
+
+    setFrame(null);
+    const setup = async () => {
+      const fn = await maaService.onOverlayFrame((next) => {
+        if (next.instance_id === previewInstanceId) setFrame(next);
+      });
+      if (disposed) {
+        fn();
+        return;
+      }
+      unlisten = fn;
+      await maaService.setOverlayPreview(previewInstanceId);
+    };
+
+    setup().catch((err) => log.warn('开启画面预览失败:', err));
+    return () => {
+      disposed = true;
+      if (unlisten) unlisten();
+    };
+  }, [mode, previewInstanceId]);
+
+  // 离开预览模式或关闭悬浮窗时停止推送
+  useEffect(() => {
+    if (mode !== 'preview') return;
+    return () => {
+      maaService.setOverlayPreview(null).catch(() => {});
+    };
+  }, [mode]);
+
+  // 新日志到达或切回日志模式时保持滚动到底部
+  useLayoutEffect(() => {
+    const el = containerRef.current;
+    if (el) el.scrollTop = el.scrollHeight;
+  }, [logs, mode]);
+
+  const handleJump = (entry: TaggedLogEntryDto) => {
+    if (entry.seq === undefined) return;
+    maaService.jumpToLog(entry.seq).catch((err) => log.warn('跳转到日志失败:', err));
+  };
+
+  return (
+    <div className="h-screen flex flex-col bg-bg-primary/90 text-xs select-none">
+      <div
+        data-tauri-drag-region
+        className="flex items-center justify-between px-2 py-1 border-b border-border shrink-0"
+      >
+        <span data-tauri-drag-region className="font-medium text-text-primary">
+          {mode === 'log' ? t('logs.title') : t('logs.overlayPreview')}
+        </span>
+        <div className="flex items-center gap-1">
+          {mode === 'preview' && instances.length > 1 && (
+            <select
+              value={previewInstanceId ?? ''}
+              onChange={(e) => setPreviewInstanceId(e.target.value)}
+              className="max-w-32 px-1 py-0.5 bg-bg-tertiary border border-border rounded text-text-primary focus:outline-none"
+            >
+              {instances.map((instance) => (
+                <option key={instance.id} value={instance.id}>
+                  {instance.name || instance.id}
+                </option>
+              ))}
+            </select>
+          )}
+          <button
+            onClick={() => setMode(mode === 'log' ? 'preview' : 'log')}
+            className="p-0.5 rounded text-text-secondary hover:bg-bg-tertiary hover:text-text-primary"
+            title={mode === 'log' ? t('logs.overlayPreview') : t('logs.overlayLogs')}
+          >
+            {mode === 'log' ? (
+              <Monitor className="w-3.5 h-3.5" />
+            ) : (
+              <ScrollText className="w-3.5 h-3.5" />
+            )}
+          </button>
+          <button
+            onClick={() => maaService.closeLogOverlay().catch(() => {})}
+            className="p-0.5 rounded text-text-secondary hover:bg-bg-tertiary hover:text-text-primary"
+            title={t('logs.closeOverlay')}
+          >
+            <X className="w-3.5 h-3.5" />
+          </button>
+        </div>
+      </div>
+      {mode === 'preview' && (
+        <div className="flex-1 min-h-0 flex items-center justify-center p-1">
+          {frame ? (
+            <PreviewView frame={frame} />
+          ) : (
+            <span className="text-text-muted">
+              {previewInstanceId ? t('logs.overlayWaitingFrame') : t('logs.overlayNoInstance')}
+            </span>
+          )}
+        </div>
+      )}
+      <div
+        ref={containerRef}
+        className={clsx('flex-1 overflow-y-auto px-1 py-1 font-mono', mode !== 'log' && 'hidden')}
+      >
+        {logs.map((entry) => (
+          <div
+            key={`${entry.instance_id}-${entry.id}`}
+            onClick={() => handleJump(entry)}
+            className={clsx(
+              'px-1 py-0.5 rounded flex gap-2 cursor-pointer hover:bg-bg-hover',
+              getLogColor(entry.type),
+            )}
+          >
+            <span className="text-text-muted tabular-nums shrink-0">
+              {new Date(entry.timestamp).toLocaleTimeString(i18n.language, { hour12: false })}
+            </span>
+            <span className="text-text-muted shrink-0">
+              [{entry.instance_name || entry.instance_id}]
+            </span>
+            <span className="min-w-0 flex-1 break-words whitespace-pre-wrap">
+              {entry.message}
+            </span>
+          </div>
+        ))}
+      </div>
+    </div>
+  );
+}
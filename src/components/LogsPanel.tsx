@@ -8,12 +8,22 @@ import {
   useLayoutEffect,
 } from 'react';
 import { useTranslation } from 'react-i18next';
-import { Eraser, Copy, ChevronUp, ChevronDown, Archive } from 'lucide-react';
+import {
+  Eraser,
+  Copy,
+  ChevronUp,
+  ChevronDown,
+  ChevronRight,
+  Archive,
+  PictureInPicture2,
+} from 'lucide-react';
 import clsx from 'clsx';
+import { toast } from 'sonner';
 import { invoke } from '@tauri-apps/api/core';
 import { useAppStore, type LogType } from '@/stores/appStore';
 import { ContextMenu, useContextMenu, type MenuItem } from './ContextMenu';
 import { isTauri } from '@/utils/paths';
+import { maaService } from '@/services/maaService';
 import { useExportLogs } from '@/utils/useExportLogs';
 import { ExportLogsModal } from './settings/ExportLogsModal';
 import { useIsMobile } from '@/hooks/useIsMobile';
@@ -26,6 +36,39 @@ import type { LogEntry } from '@/stores/types';
 const DEFAULT_VISIBLE_LOG_LIMIT = 500;
 const EXPANDED_LOG_LIMIT = 2000;
 const BOTTOM_FOLLOW_THRESHOLD_PX = 24;
+const JUMP_HIGHLIGHT_MS = 2000;
+
+/** 一个任务分段：开始标记、段内日志与（任务结束后的）结束标记 */
+interface LogSectionGroup {
+  start: LogEntry;
+  end?: LogEntry;
+  children: LogEntry[];
+}
+
+type LogRow = { log: LogEntry; nested: boolean } | { group: LogSectionGroup };
+
+/** 按分段标记把日志分组，未闭合的分段在遇到下一个开始标记时结束 */
+function groupLogsBySection(logs: LogEntry[]): (LogEntry | LogSectionGroup)[] {
+  const result: (LogEntry | LogSectionGroup)[] = [];
+  let current: LogSectionGroup | null = null;
+  for (const log of logs) {
+    if (log.section?.phase === 'start') {
+      current = { start: log, children: [] };
+      result.push(current);
+    } else if (
+      log.section?.phase === 'end' &&
+      current?.start.section?.taskId === log.section.taskId
+    ) {
+      current.end = log;
+      current = null;
+    } else if (current) {
+      current.children.push(log);
+    } else {
+      result.push(log);
+    }
+  }
+  return result;
+}
 
 function formatLogTime(date: Date, locale?: string) {
   return date.toLocaleTimeString(locale || undefined, {
@@ -45,6 +88,11 @@ export function LogsPanel() {
   const [visibleLogLimit, setVisibleLogLimit] = useState(DEFAULT_VISIBLE_LOG_LIMIT);
   const [isAtTop, setIsAtTop] = useState(false);
   const [isExpandingLogs, setIsExpandingLogs] = useState(false);
+  // 悬浮窗点击日志后请求跳转的目标
+  const [jumpTarget, setJumpTarget] = useState<{ instanceId: string; logId: string } | null>(null);
+  const [highlightLogId, setHighlightLogId] = useState<string | null>(null);
+  // 用户手动展开/折叠过的分段（按开始标记的日志 ID），未记录的分段在任务结束后默认折叠
+  const [sectionToggles, setSectionToggles] = useState<Record<string, boolean>>({});
 
   const {
     sidePanelExpanded,
@@ -53,6 +101,7 @@ export function LogsPanel() {
     instanceLogs,
     clearLogs,
     setMaxLogsPerInstance,
+    setActiveInstance,
   } = useAppStore();
   const { state: menuState, show: showMenu, hide: hideMenu } = useContextMenu();
   const { exportModal, handleExportLogs, closeExportModal, openExportedFile } = useExportLogs();
@@ -60,6 +109,25 @@ export function LogsPanel() {
   // 获取当前实例的日志
   const logs = activeInstanceId ? instanceLogs[activeInstanceId] || [] : [];
   const visibleLogs = useMemo(() => logs.slice(-visibleLogLimit), [logs, visibleLogLimit]);
+  const groupedLogs = useMemo(() => groupLogsBySection(visibleLogs), [visibleLogs]);
+  const isSectionCollapsed = useCallback(
+    (group: LogSectionGroup) => sectionToggles[group.start.id] ?? !!group.end,
+    [sectionToggles],
+  );
+  const logRows = useMemo(() => {
+    const rows: LogRow[] = [];
+    for (const item of groupedLogs) {
+      if (!('start' in item)) {
+        rows.push({ log: item, nested: false });
+        continue;
+      }
+      rows.push({ group: item });
+      if (!isSectionCollapsed(item)) {
+        rows.push(...item.children.map((log) => ({ log, nested: true })));
+      }
+    }
+    return rows;
+  }, [groupedLogs, isSectionCollapsed]);
   const canShowMoreLogs =
     visibleLogLimit === DEFAULT_VISIBLE_LOG_LIMIT &&
     visibleLogs.length === DEFAULT_VISIBLE_LOG_LIMIT;
@@ -70,6 +138,70 @@ export function LogsPanel() {
     isFollowingTailRef.current = true;
   }, [activeInstanceId]);
 
+  // 监听日志跳转请求（悬浮窗点击日志时由后端转发）
+  useEffect(() => {
+    let cancelled = false;
+    let unlisten: (() => void) | undefined;
+
+    maaService
+      .onLogJump(({ instance_id, log_id }) => {
+        setActiveInstance(instance_id);
+        setJumpTarget({ instanceId: instance_id, logId: log_id });
+      })
+      .then((fn) => {
+        if (cancelled) fn();
+        else unlisten = fn;
+      })
+      .catch(() => {});
+
+    return () => {
+      cancelled = true;
+      unlisten?.();
+    };
+  }, [setActiveInstance]);
+
+  // 目标日志不在可见范围内时扩大显示条数（需在切换实例重置条数之后执行）
+  useEffect(() => {
+    if (!jumpTarget || jumpTarget.instanceId !== activeInstanceId) return;
+    const index = logs.findIndex((log) => log.id === jumpTarget.logId);
+    if (index < 0) {
+      setJumpTarget(null);
+      return;
+    }
+    const required = logs.length - index;
+    if (required > visibleLogLimit) {
+      setVisibleLogLimit(Math.max(required, EXPANDED_LOG_LIMIT));
+      return;
+    }
+    // 目标日志在折叠的分段内时展开该分段
+    const group = groupedLogs.find(
+      (item): item is LogSectionGroup =>
+        'start' in item && item.children.some((log) => log.id === jumpTarget.logId),
+    );
+    if (group && isSectionCollapsed(group)) {
+      setSectionToggles((prev) => ({ ...prev, [group.start.id]: false }));
+    }
+  }, [jumpTarget, activeInstanceId, logs, visibleLogLimit, groupedLogs, isSectionCollapsed]);
+
+  useLayoutEffect(() => {
+    if (!jumpTarget || jumpTarget.instanceId !== activeInstanceId) return;
+    const el = logsContainerRef.current?.querySelector<HTMLElement>(
+      `[data-log-id="${CSS.escape(jumpTarget.logId)}"]`,
+    );
+    if (!el) return;
+
+    isFollowingTailRef.current = false;
+    el.scrollIntoView({ block: 'center' });
+    setHighlightLogId(jumpTarget.logId);
+    setJumpTarget(null);
+  }, [jumpTarget, activeInstanceId, logRows]);
+
+  useEffect(() => {
+    if (!highlightLogId) return;
+    const timer = setTimeout(() => setHighlightLogId(null), JUMP_HIGHLIGHT_MS);
+    return () => clearTimeout(timer);
+  }, [highlightLogId]);
+
   useLayoutEffect(() => {
     if (!isFollowingTailRef.current) return;
 
@@ -111,8 +243,10 @@ export function LogsPanel() {
     const text = visibleLogs
       .map((log) => `[${log.timestamp.toLocaleTimeString()}] ${log.message}`)
       .join('\n');
-    navigator.clipboard.writeText(text);
-  }, [visibleLogs]);
+    maaService.copyText(text).catch(() => {
+      toast.error(t('logs.copyFailed'));
+    });
+  }, [visibleLogs, t]);
 
   const handleShowMoreLogs = useCallback(() => {
     if (isExpandingLogs) return;
@@ -184,7 +318,16 @@ export function LogsPanel() {
           label: t('debug.exportLogs'),
           icon: Archive,
           disabled: !isTauri(),
-          onClick: handleExportLogs,
+          onClick: () => handleExportLogs(),
+        },
+        {
+          id: 'export-instance-logs',
+          label: t('logs.exportInstanceLogs'),
+          icon: Archive,
+          disabled: !isTauri() || !activeInstanceId,
+          onClick: () => {
+            if (activeInstanceId) handleExportLogs(activeInstanceId);
+          },
         },
         {
           id: 'copy',
@@ -214,6 +357,7 @@ export function LogsPanel() {
     },
     [
       t,
+      activeInstanceId,
       logs.length,
       sidePanelExpanded,
       handleExportLogs,
@@ -256,6 +400,20 @@ export function LogsPanel() {
       >
         <span className="text-sm font-medium text-text-primary">{t('logs.title')}</span>
         <div className="flex items-center gap-1.5">
+          {isTauri() && (
+            <button
+              onClick={(e) => {
+                e.stopPropagation();
+                maaService.openLogOverlay().catch((err) => {
+                  toast.error(t('logs.openOverlayFailed', { error: String(err) }));
+                });
+              }}
+              className="p-1 rounded-md transition-colors text-text-secondary hover:bg-bg-tertiary hover:text-text-primary"
+              title={t('logs.openOverlay')}
+            >
+              <PictureInPicture2 className="w-3.5 h-3.5" />
+            </button>
+          )}
           <button
             onClick={(e) => {
               e.stopPropagation();
@@ -336,44 +494,105 @@ export function LogsPanel() {
                 </button>
               </div>
             )}
-            {visibleLogs.map((log, index) => (
-              <Fragment key={log.id}>
-                {log.html ? (
-                  // 富文本内容（focus 消息支持 Markdown/HTML）
-                  <div
-                    className={clsx(
-                      'py-1.5 px-2 rounded-md flex items-start gap-3',
-                      getLogColor(log.type),
+            {logRows.map((row, index) => {
+              if ('group' in row) {
+                const { start, end, children } = row.group;
+                const collapsed = isSectionCollapsed(row.group);
+                return (
+                  <Fragment key={start.id}>
+                    <button
+                      type="button"
+                      data-log-id={start.id}
+                      onClick={() =>
+                        setSectionToggles((prev) => ({ ...prev, [start.id]: !collapsed }))
+                      }
+                      className={clsx(
+                        'w-full py-1.5 px-2 rounded-md flex items-start gap-3 text-left',
+                        'hover:bg-bg-hover transition-colors',
+                        getLogColor(end?.type ?? start.type),
+                        highlightLogId === start.id && 'ring-1 ring-accent',
+                      )}
+                    >
+                      <span className="text-text-muted/90 w-[64px] flex-shrink-0 tabular-nums text-[11px] leading-4">
+                        {formatLogTime(start.timestamp, i18n.language)}
+                      </span>
+                      <ChevronRight
+                        className={clsx(
+                          'w-3.5 h-3.5 flex-shrink-0 transition-transform',
+                          !collapsed && 'rotate-90',
+                        )}
+                      />
+                      <span className="min-w-0 flex-1 break-words leading-4 font-medium">
+                        {start.section?.name}
+                      </span>
+                      <span className="flex-shrink-0 text-[11px] text-text-muted">
+                        {end
+                          ? t(`logs.section.${end.section?.status ?? 'failed'}`, {
+                              count: children.length,
+                            })
+                          : t('logs.section.running', { count: children.length })}
+                      </span>
+                    </button>
+                    {index < logRows.length - 1 && (
+                      <div className="mx-4 my-1 h-px bg-border/50" aria-hidden="true" />
                     )}
-                  >
-                    <span className="text-text-muted/90 w-[64px] flex-shrink-0 tabular-nums text-[11px] leading-4">
-                      {formatLogTime(log.timestamp, i18n.language)}
-                    </span>
-                    <span
-                      className="min-w-0 flex-1 break-words leading-4 focus-content"
-                      dangerouslySetInnerHTML={{ __html: log.html }}
-                    />
-                  </div>
-                ) : (
-                  <div
-                    className={clsx(
-                      'py-1.5 px-2 rounded-md flex items-start gap-3',
-                      getLogColor(log.type),
-                    )}
-                  >
-                    <span className="text-text-muted/90 w-[64px] flex-shrink-0 tabular-nums text-[11px] leading-4">
-                      {formatLogTime(log.timestamp, i18n.language)}
-                    </span>
-                    <span className="min-w-0 flex-1 break-words whitespace-pre-wrap leading-4">
-                      {log.message}
-                    </span>
-                  </div>
-                )}
-                {index < visibleLogs.length - 1 && (
-                  <div className="mx-4 my-1 h-px bg-border/50" aria-hidden="true" />
-                )}
-              </Fragment>
-            ))}
+                  </Fragment>
+                );
+              }
+              const { log, nested } = row;
+              return (
+                <Fragment key={log.id}>
+                  {log.html ? (
+                    // 富文本内容（focus 消息支持 Markdown/HTML）
+                    <div
+                      data-log-id={log.id}
+                      className={clsx(
+                        'py-1.5 px-2 rounded-md flex items-start gap-3',
+                        nested && 'ml-4',
+                        getLogColor(log.type),
+                        highlightLogId === log.id && 'ring-1 ring-accent',
+                      )}
+                    >
+                      <span className="text-text-muted/90 w-[64px] flex-shrink-0 tabular-nums text-[11px] leading-4">
+                        {formatLogTime(log.timestamp, i18n.language)}
+                      </span>
+                      <span
+                        className="min-w-0 flex-1 break-words leading-4 focus-content"
+                        dangerouslySetInnerHTML={{ __html: log.html }}
+                      />
+                    </div>
+                  ) : (
+                    <div
+                      data-log-id={log.id}
+                      className={clsx(
+                        'py-1.5 px-2 rounded-md flex items-start gap-3',
+                        nested && 'ml-4',
+                        getLogColor(log.type),
+                        highlightLogId === log.id && 'ring-1 ring-accent',
+                      )}
+                    >
+                      <span className="text-text-muted/90 w-[64px] flex-shrink-0 tabular-nums text-[11px] leading-4">
+                        {formatLogTime(log.timestamp, i18n.language)}
+                      </span>
+                      <span className="min-w-0 flex-1 break-words whitespace-pre-wrap leading-4">
+                        {log.message}
+                        {log.image && (
+                          // 任务失败时的截图
+                          <img
+                            src={log.image}
+                            alt={log.message}
+                            className="block mt-1.5 max-w-full max-h-48 rounded border border-border"
+                          />
+                        )}
+                      </span>
+                    </div>
+                  )}
+                  {index < logRows.length - 1 && (
+                    <div className="mx-4 my-1 h-px bg-border/50" aria-hidden="true" />
+                  )}
+                </Fragment>
+              );
+            })}
             <div ref={logsEndRef} aria-hidden="true" />
           </>
         )}
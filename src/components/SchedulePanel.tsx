@@ -9,9 +9,10 @@ import {
   Trash2,
   ToggleLeft,
   ToggleRight,
+  Repeat,
 } from 'lucide-react';
 import { useAppStore } from '@/stores/appStore';
-import type { SchedulePolicy } from '@/types/interface';
+import type { QueueLoopConfig, SchedulePolicy } from '@/types/interface';
 import clsx from 'clsx';
 import { ConfirmDialog } from './ConfirmDialog';
 
@@ -21,6 +22,25 @@ const generateId = () => Math.random().toString(36).substring(2, 9);
 // 校验 "HH:mm" 格式
 const TIME_PATTERN = /^([01]\d|2[0-3]):[0-5]\d$/;
 
+const DEFAULT_QUEUE_LOOP: QueueLoopConfig = { enabled: false, rounds: 0, intervalSec: 0 };
+
+// 循环轮数上限（0 表示无限循环）
+const MAX_LOOP_ROUNDS = 9999;
+
+// 循环间隔上限（与后端一致，24 小时）
+const MAX_LOOP_INTERVAL_SEC = 24 * 60 * 60;
+
+// 输入框统一样式
+const numberInputClass = clsx(
+  'w-full px-2 py-1.5 text-sm rounded border',
+  'bg-bg-primary text-text-primary border-border',
+  'focus:outline-none focus:border-accent focus:ring-1 focus:ring-accent/20',
+);
+
+// 解析非负整数输入，非法时返回 0
+const parseNonNegativeInt = (value: string, max: number) =>
+  Math.min(max, Math.max(0, Math.floor(Number(value)) || 0));
+
 interface SchedulePanelProps {
   instanceId: string;
   onClose: () => void;
@@ -322,6 +342,7 @@ export function SchedulePanel({ instanceId, onClose }: SchedulePanelProps) {
   const instance = getActiveInstance();
   const policies = instance?.schedulePolicies || [];
   const anyEnabled = policies.some((p) => p.enabled);
+  const queueLoop = instance?.queueLoop ?? DEFAULT_QUEUE_LOOP;
 
   // 点击外部关闭面板
   useEffect(() => {
@@ -374,6 +395,13 @@ export function SchedulePanel({ instanceId, onClose }: SchedulePanelProps) {
     updateInstance(instanceId, { schedulePolicies: updatedPolicies });
   }, [anyEnabled, instanceId, policies, updateInstance]);
 
+  const handleUpdateQueueLoop = useCallback(
+    (updates: Partial<QueueLoopConfig>) => {
+      updateInstance(instanceId, { queueLoop: { ...queueLoop, ...updates } });
+    },
+    [instanceId, queueLoop, updateInstance],
+  );
+
   return (
     <div
       ref={panelRef}
@@ -453,6 +481,67 @@ export function SchedulePanel({ instanceId, onClose }: SchedulePanelProps) {
         </button>
       </div>
 
+      {/* 循环执行 */}
+      <div className="px-4 py-3 border-t border-border space-y-2">
+        <div className="flex items-center justify-between">
+          <div className="flex items-center gap-2">
+            <Repeat className="w-4 h-4 text-accent" />
+            <span className="text-sm font-medium text-text-primary">{t('schedule.queueLoop')}</span>
+          </div>
+          <button
+            onClick={() => handleUpdateQueueLoop({ enabled: !queueLoop.enabled })}
+            className="p-1 rounded hover:bg-bg-hover"
+          >
+            {queueLoop.enabled ? (
+              <ToggleRight className="w-5 h-5 text-accent" />
+            ) : (
+              <ToggleLeft className="w-5 h-5 text-text-muted" />
+            )}
+          </button>
+        </div>
+        {queueLoop.enabled && (
+          <>
+            <div className="grid grid-cols-2 gap-2">
+              <div className="space-y-1.5">
+                <label className="text-xs font-medium text-text-secondary">
+                  {t('schedule.queueLoopRounds')}
+                </label>
+                <input
+                  type="number"
+                  min={0}
+                  max={MAX_LOOP_ROUNDS}
+                  value={queueLoop.rounds}
+                  onChange={(e) =>
+                    handleUpdateQueueLoop({
+                      rounds: parseNonNegativeInt(e.target.value, MAX_LOOP_ROUNDS),
+                    })
+                  }
+                  className={numberInputClass}
+                />
+              </div>
+              <div className="space-y-1.5">
+                <label className="text-xs font-medium text-text-secondary">
+                  {t('schedule.queueLoopInterval')}
+                </label>
+                <input
+                  type="number"
+                  min={0}
+                  max={MAX_LOOP_INTERVAL_SEC}
+                  value={queueLoop.intervalSec}
+                  onChange={(e) =>
+                    handleUpdateQueueLoop({
+                      intervalSec: parseNonNegativeInt(e.target.value, MAX_LOOP_INTERVAL_SEC),
+                    })
+                  }
+                  className={numberInputClass}
+                />
+              </div>
+            </div>
+            <p className="text-xs text-text-muted">{t('schedule.queueLoopHint')}</p>
+          </>
+        )}
+      </div>
+
       {/* 提示信息 */}
       <div className="px-4 py-2 border-t border-border bg-bg-tertiary rounded-b-lg">
         <p className="text-xs text-text-muted">{t('schedule.hint')}</p>
@@ -12,9 +12,11 @@ import {
   Copy,
   Unplug,
   MousePointerClick,
+  History,
 } from 'lucide-react';
 import clsx from 'clsx';
 import { maaService } from '@/services/maaService';
+import type { LastFrame } from '@/types/maa';
 import { useAppStore } from '@/stores/appStore';
 import { ContextMenu, useContextMenu, type MenuItem } from './ContextMenu';
 import { getFrameInterval } from './FrameRateSelector';
@@ -55,6 +57,9 @@ export function ScreenshotPanel() {
   const [screenshotUrl, setScreenshotUrl] = useState<string | null>(null);
   const [error, setError] = useState<string | null>(null);
   const [isFullscreen, setIsFullscreen] = useState(false);
+  // 正在查看的任务结束帧（screenshotUrl 被实时帧替换后自动不再显示标签）
+  const [lastFrame, setLastFrame] = useState<LastFrame | null>(null);
+  const showingLastFrame = !!lastFrame && screenshotUrl === lastFrame.data_url;
 
   const { state: menuState, show: showMenu, hide: hideMenu } = useContextMenu();
   const fullscreenImgRef = useRef<HTMLImageElement>(null);
@@ -323,6 +328,7 @@ export function ScreenshotPanel() {
   // 实例切换时重置自动启动标记
   useEffect(() => {
     hasAutoStartedRef.current = false;
+    setLastFrame(null);
   }, [instanceId]);
 
   // 保存截图
@@ -370,6 +376,29 @@ export function ScreenshotPanel() {
     }
   }, [instanceId, captureFrame]);
 
+  // 查看最近一次任务结束时的画面（停止实时流，避免被新帧覆盖）
+  const viewLastFrame = useCallback(async () => {
+    if (!instanceId) return;
+
+    try {
+      const frame = await maaService.getLastFrame(instanceId);
+      if (!frame) {
+        useAppStore.getState().addLog(instanceId, {
+          type: 'info',
+          message: t('screenshot.noLastFrame'),
+        });
+        return;
+      }
+      streamingRef.current = false;
+      setIsStreaming(false);
+      setLastFrame(frame);
+      setScreenshotUrl(frame.data_url);
+      setError(null);
+    } catch (err) {
+      log.warn('获取最后一帧失败:', err);
+    }
+  }, [instanceId, setIsStreaming, t]);
+
   // 断开连接（销毁实例）
   const disconnect = useCallback(async () => {
     if (!instanceId) return;
@@ -445,6 +474,13 @@ export function ScreenshotPanel() {
           disabled: !screenshotUrl,
           onClick: () => setIsFullscreen(true),
         },
+        {
+          id: 'last-frame',
+          label: t('contextMenu.viewLastFrame'),
+          icon: History,
+          disabled: !instanceId,
+          onClick: viewLastFrame,
+        },
         { id: 'divider-2', label: '', divider: true },
         {
           id: 'save',
@@ -481,6 +517,7 @@ export function ScreenshotPanel() {
       screenshotUrl,
       toggleStreaming,
       forceRefresh,
+      viewLastFrame,
       saveScreenshot,
       copyScreenshot,
       disconnect,
@@ -624,6 +661,21 @@ export function ScreenshotPanel() {
                       LIVE
                     </div>
                   )}
+                  {/* 任务结束帧标签 */}
+                  {showingLastFrame && lastFrame && (
+                    <div
+                      className={clsx(
+                        'absolute top-2 left-2 px-1.5 py-0.5 rounded text-white text-xs',
+                        lastFrame.status === 'succeeded' ? 'bg-success/80' : 'bg-error/80',
+                      )}
+                      title={lastFrame.entry}
+                    >
+                      {t('screenshot.lastFrameLabel', {
+                        status: t(`screenshot.lastFrameStatus.${lastFrame.status}`),
+                        time: new Date(lastFrame.captured_at).toLocaleTimeString(),
+                      })}
+                    </div>
+                  )}
                 </>
               ) : (
                 <div className="flex flex-col items-center gap-2 text-text-muted">
@@ -29,6 +29,7 @@ import { ConfirmDialog } from './ConfirmDialog';
 import { getInterfaceLangKey } from '@/i18n';
 import { exportFileWithToast, exportWithToast } from '@/utils/tabExportImport';
 import clsx from 'clsx';
+import { toast } from 'sonner';
 
 type CloseConfirmData =
   | { type: 'single'; ids: string[]; name: string }
@@ -147,8 +148,13 @@ export function TabBar() {
   };
 
   const handleSaveEdit = () => {
-    if (editingId && editName.trim()) {
-      renameInstance(editingId, editName.trim());
+    const newName = editName.trim();
+    if (editingId && newName) {
+      // 名称允许重复，但多开时容易混淆，给出提示
+      if (instances.some((i) => i.id !== editingId && i.name === newName)) {
+        toast.warning(t('instance.duplicateName', { name: newName }));
+      }
+      renameInstance(editingId, newName);
     }
     setEditingId(null);
     setEditName('');
@@ -2,13 +2,23 @@ import { useState, useCallback, useMemo, useEffect, useRef } from 'react';
 import { useTranslation } from 'react-i18next';
 import { useSortable } from '@dnd-kit/sortable';
 import { CSS } from '@dnd-kit/utilities';
-import { GripVertical, ChevronRight, X, Loader2, FileText, Link, AlertCircle } from 'lucide-react';
+import {
+  GripVertical,
+  ChevronRight,
+  X,
+  Loader2,
+  FileText,
+  Link,
+  AlertCircle,
+  Filter,
+  ShieldCheck,
+} from 'lucide-react';
 import { useAppStore, type TaskRunStatus } from '@/stores/appStore';
 import { maaService } from '@/services/maaService';
 import { useResolvedContent } from '@/services/contentResolver';
 import { generateTaskPipelineOverride } from '@/utils';
 import { OptionEditor, SwitchGrid, switchHasNestedOptions } from './OptionEditor';
-import { ContextMenu, useContextMenu } from './ContextMenu';
+import { ContextMenu, useContextMenu, type MenuItem } from './ContextMenu';
 import { Tooltip } from './ui/Tooltip';
 import { ConfirmDialog } from './ConfirmDialog';
 import { buildListItemMenuItems, InlineNameEditor } from './listItemShared';
@@ -296,11 +306,81 @@ function OptionListRenderer({
   );
 }
 
+/** 跳过条件编辑器：失焦或回车时校验表达式，合法才保存 */
+function SkipConditionEditor({
+  instanceId,
+  taskId,
+  value,
+  disabled,
+}: {
+  instanceId: string;
+  taskId: string;
+  value: string | undefined;
+  disabled: boolean;
+}) {
+  const { setTaskSkipIf } = useAppStore();
+  const { t } = useTranslation();
+  const [draft, setDraft] = useState(value ?? '');
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    setDraft(value ?? '');
+    setError(null);
+  }, [value]);
+
+  const commit = async () => {
+    const expression = draft.trim();
+    if (expression === (value ?? '')) {
+      setError(null);
+      return;
+    }
+    if (expression) {
+      const checkError = await maaService.checkSkipCondition(expression);
+      setError(checkError);
+      if (checkError) return;
+    }
+    setTaskSkipIf(instanceId, taskId, expression);
+  };
+
+  return (
+    <div className="space-y-1">
+      <label className="block text-xs font-medium text-text-secondary">
+        {t('taskItem.skipCondition')}
+      </label>
+      <input
+        type="text"
+        value={draft}
+        onChange={(e) => setDraft(e.target.value)}
+        onBlur={commit}
+        onKeyDown={(e) => {
+          if (e.key === 'Enter') e.currentTarget.blur();
+        }}
+        disabled={disabled}
+        spellCheck={false}
+        placeholder={t('taskItem.skipConditionPlaceholder')}
+        className={clsx(
+          'w-full px-2.5 py-1.5 text-sm font-mono rounded-md border',
+          'bg-bg-secondary text-text-primary',
+          'focus:outline-none focus:ring-1',
+          'placeholder:text-text-muted disabled:opacity-50 disabled:cursor-not-allowed',
+          error
+            ? 'border-error focus:border-error focus:ring-error/20'
+            : 'border-border focus:border-accent focus:ring-accent/20',
+        )}
+      />
+      <p className={clsx('text-xs', error ? 'text-error' : 'text-text-muted')}>
+        {error ? t('taskItem.skipConditionInvalid', { error }) : t('taskItem.skipConditionHint')}
+      </p>
+    </div>
+  );
+}
+
 export function TaskItem({ instanceId, task }: TaskItemProps) {
   const { t } = useTranslation();
   const [isEditing, setIsEditing] = useState(false);
   const [showDeleteConfirm, setShowDeleteConfirm] = useState(false);
   const [editName, setEditName] = useState('');
+  const [showSkipCondition, setShowSkipCondition] = useState(false);
 
   const {
     projectInterface,
@@ -325,6 +405,8 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
     interfaceTranslations,
     animatingTaskIds,
     removeAnimatingTaskId,
+    instanceResourceLoaded,
+    addLog,
   } = useAppStore();
 
   // 获取任务运行状态
@@ -401,9 +483,14 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
   // 紧凑模式：实例运行时，未启用的任务显示为紧凑样式
   const isCompact = isInstanceRunning && !task.enabled;
 
-  // 判断是否可以编辑选项：实例未运行时始终可以编辑，运行中只有 pending 或 idle 状态的任务可以编辑
-  const canEditOptions =
-    !isInstanceRunning || taskRunStatus === 'idle' || taskRunStatus === 'pending';
+  // 运行中可热更新配置的任务：排队中与运行中的任务，循环执行时已结束的任务（下一轮生效）
+  const canOverrideRunning =
+    taskRunStatus === 'pending' ||
+    taskRunStatus === 'running' ||
+    (!!instance?.queueLoop?.enabled && taskRunStatus !== 'idle');
+
+  // 判断是否可以编辑选项：实例未运行时始终可以编辑，运行中 idle 状态或可热更新的任务可以编辑
+  const canEditOptions = !isInstanceRunning || taskRunStatus === 'idle' || canOverrideRunning;
 
   // 判断是否可以调整顺序/删除（实例运行时禁用）
   const canReorder = !isInstanceRunning;
@@ -416,7 +503,7 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
   const isAnimating = animatingTaskIds.includes(task.id);
   const animationElementRef = useRef<HTMLDivElement | null>(null);
 
-  // 当选项值变化且任务状态为 pending 时，调用 overridePipeline 更新任务配置
+  // 当选项值变化且任务已提交时，调用 overridePipeline 更新任务配置（运行中从下一个节点开始生效）
   useEffect(() => {
     const currentOptionValues = JSON.stringify(task.optionValues);
 
@@ -434,8 +521,8 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
     // 更新 ref
     prevOptionValuesRef.current = currentOptionValues;
 
-    // 只有 pending 状态的任务才需要调用 overridePipeline
-    if (taskRunStatus !== 'pending') {
+    // 只有已提交且可热更新的任务才需要调用 overridePipeline
+    if (!isInstanceRunning || !canOverrideRunning) {
       return;
     }
 
@@ -458,7 +545,8 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
     });
   }, [
     task.optionValues,
-    taskRunStatus,
+    isInstanceRunning,
+    canOverrideRunning,
     instanceId,
     task.id,
     projectInterface,
@@ -531,8 +619,10 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
   const hasOptions = !!taskDef?.option && taskDef.option.length > 0;
   // 判断是否有描述内容（包括正在加载的情况）
   const hasDescription = !!resolvedDescription.html || resolvedDescription.loading;
-  // 有选项或有描述时都可以展开
-  const canExpand = hasOptions || hasDescription;
+  // 已设置跳过条件或正在编辑时显示跳过条件编辑器
+  const hasSkipCondition = !!task.skipIf || showSkipCondition;
+  // 有选项、描述或跳过条件时都可以展开
+  const canExpand = hasOptions || hasDescription || hasSkipCondition;
 
   // 生成选项预览信息（最多显示3个）
   const optionPreviews = useMemo(() => {
@@ -646,6 +736,60 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
     setEditName('');
   };
 
+  // 校验任务的 override 与节点引用（不实际运行），结果写入实例日志
+  const handleValidateTask = useCallback(async () => {
+    if (!taskDef) return;
+    const pipelineOverride = generateTaskPipelineOverride(
+      task,
+      projectInterface,
+      currentControllerName,
+      currentResourceName,
+      useAppStore.getState().globalOptionValues,
+    );
+    try {
+      const result = await maaService.validateTask(instanceId, taskDef.entry, pipelineOverride);
+      for (const issue of result.issues) {
+        addLog(instanceId, {
+          type: issue.level,
+          message: t('logs.messages.taskValidationIssue', {
+            name: displayName,
+            location: issue.location,
+            message: issue.message,
+          }),
+        });
+      }
+      const errors = result.issues.filter((i) => i.level === 'error').length;
+      addLog(instanceId, {
+        type: result.valid ? 'success' : 'error',
+        message: result.valid
+          ? t('logs.messages.taskValidationPassed', {
+              name: displayName,
+              nodes: result.reachable_nodes,
+              warnings: result.issues.length - errors,
+            })
+          : t('logs.messages.taskValidationFailed', { name: displayName, errors }),
+      });
+    } catch (err) {
+      addLog(instanceId, {
+        type: 'error',
+        message: t('logs.messages.taskValidationError', {
+          name: displayName,
+          error: err instanceof Error ? err.message : String(err),
+        }),
+      });
+    }
+  }, [
+    task,
+    taskDef,
+    displayName,
+    instanceId,
+    projectInterface,
+    currentControllerName,
+    currentResourceName,
+    addLog,
+    t,
+  ]);
+
   // 右键菜单处理
   const handleContextMenu = useCallback(
     (e: React.MouseEvent) => {
@@ -698,6 +842,27 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
         },
       });
 
+      // 跳过条件入口放在展开菜单项之后
+      const skipItem: MenuItem = {
+        id: 'skip-condition',
+        label: t('contextMenu.editSkipCondition'),
+        icon: Filter,
+        disabled: isInstanceRunning,
+        onClick: () => {
+          setShowSkipCondition(true);
+          if (!task.expanded) toggleTaskExpanded(instanceId, task.id);
+        },
+      };
+      const validateItem: MenuItem = {
+        id: 'validate',
+        label: t('contextMenu.validateTask'),
+        icon: ShieldCheck,
+        disabled: !instanceResourceLoaded[instanceId],
+        onClick: handleValidateTask,
+      };
+      const dividerIndex = menuItems.findIndex((item) => item.id === 'divider-2');
+      menuItems.splice(dividerIndex, 0, skipItem, validateItem);
+
       showMenu(e, menuItems);
     },
     [
@@ -717,6 +882,8 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
       confirmBeforeDelete,
       showMenu,
       isInstanceRunning,
+      instanceResourceLoaded,
+      handleValidateTask,
     ],
   );
 
@@ -998,6 +1165,17 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
                   currentResourceName={currentResourceName}
                 />
               )}
+              {/* 跳过条件 - 已设置或从右键菜单打开时显示 */}
+              {hasSkipCondition && (
+                <div className={hasOptions || hasDescription || isIncompatible ? 'mt-4' : ''}>
+                  <SkipConditionEditor
+                    instanceId={instanceId}
+                    taskId={task.id}
+                    value={task.skipIf}
+                    disabled={isInstanceRunning}
+                  />
+                </div>
+              )}
             </div>
           </div>
         </div>
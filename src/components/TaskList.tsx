@@ -28,6 +28,7 @@ import {
   ClipboardPaste,
   Copy,
   FileText,
+  KeyRound,
 } from 'lucide-react';
 import { useAppStore } from '@/stores/appStore';
 import { TaskItem } from './TaskItem';
@@ -45,6 +46,10 @@ import {
   getImportErrorType,
 } from '@/utils/tabExportImport';
 import { generateId, initializeAllOptionValues, sanitizeOptionValues } from '@/stores/helpers';
+import { buildSharePayload, resolveSharedTasks } from '@/utils/shareCode';
+import { maaService } from '@/services/maaService';
+import { formatError } from '@/utils/mxuError';
+import { isTauri } from '@/utils/paths';
 import { isPretaskName } from '@/types/pretasks';
 import { loggers } from '@/utils/logger';
 import { toast } from 'sonner';
@@ -184,9 +189,47 @@ function useImportConfigActions(instanceId: string) {
     }
   };
 
+  /** 从剪贴板中的分享码导入，任务追加到当前列表末尾 */
+  const importFromShareCode = async () => {
+    if (!projectInterface || !instanceId) return;
+
+    try {
+      const code = await navigator.clipboard.readText();
+      const payload = await maaService.importShareCode(code);
+      const { tasks, skipped } = resolveSharedTasks(payload, projectInterface);
+      for (const taskName of skipped) {
+        loggers.config.warn(
+          `导入分享码时，任务 "${taskName}" 在当前 Project Interface 中不存在，已跳过`,
+        );
+      }
+      if (tasks.length === 0) {
+        toast.error(t('preset.shareCodeNoTasks'));
+        return;
+      }
+
+      const current = useAppStore.getState().instances.find((i) => i.id === instanceId);
+      updateInstance(instanceId, {
+        selectedTasks: [
+          ...(current?.selectedTasks ?? []),
+          ...tasks.map((task) => ({ ...task, expanded: true })),
+        ],
+      });
+      if (skipped.length > 0) {
+        toast.warning(
+          t('preset.shareCodeImportedPartial', { count: tasks.length, skipped: skipped.length }),
+        );
+      } else {
+        toast.success(t('preset.shareCodeImported', { count: tasks.length }));
+      }
+    } catch (err) {
+      toast.error(t('preset.shareCodeImportFailed', { error: formatError(err, t) }));
+    }
+  };
+
   return {
     importFromClipboard: () => handleImport('clipboard'),
     importFromFile: () => handleImport('file'),
+    importFromShareCode,
   };
 }
 
@@ -278,7 +321,9 @@ export function TaskList() {
   const instance = getActiveInstance();
   const isInstanceRunning = instance?.isRunning || false;
   const { state: menuState, showAt: showMenuAt, hide: hideMenu } = useContextMenu();
-  const { importFromClipboard, importFromFile } = useImportConfigActions(instance?.id ?? '');
+  const { importFromClipboard, importFromFile, importFromShareCode } = useImportConfigActions(
+    instance?.id ?? '',
+  );
 
   // 滚动容器引用
   const scrollContainerRef = useRef<HTMLDivElement>(null);
@@ -401,6 +446,16 @@ export function TaskList() {
               icon: FileText,
               onClick: importFromFile,
             },
+            ...(isTauri()
+              ? [
+                  {
+                    id: 'import-share-code',
+                    label: t('contextMenu.importFromShareCode'),
+                    icon: KeyRound,
+                    onClick: importFromShareCode,
+                  },
+                ]
+              : []),
           ],
         },
         {
@@ -435,6 +490,29 @@ export function TaskList() {
                 }
               },
             },
+            ...(isTauri()
+              ? [
+                  {
+                    id: 'export-share-code',
+                    label: t('contextMenu.exportToShareCode'),
+                    icon: KeyRound,
+                    onClick: () => {
+                      if (projectInterface) {
+                        maaService
+                          .exportShareCode(buildSharePayload(instance, projectInterface))
+                          .then((code) => maaService.copyText(code))
+                          .then(
+                            () => toast.success(t('preset.shareCodeCopied')),
+                            (err) =>
+                              toast.error(
+                                t('preset.shareCodeExportFailed', { error: formatError(err, t) }),
+                              ),
+                          );
+                      }
+                    },
+                  },
+                ]
+              : []),
           ],
         },
       ];
@@ -452,6 +530,7 @@ export function TaskList() {
       projectInterface,
       importFromClipboard,
       importFromFile,
+      importFromShareCode,
     ],
   );
 
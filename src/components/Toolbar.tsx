@@ -23,7 +23,7 @@ import {
   resolveCompatTaskDef,
 } from '@/types/pretasks';
 import { splitTasksIntoThreeSegments, shouldSkipScreenshot } from '@/utils/taskSegmentation';
-import type { TaskConfig, ControllerConfig } from '@/types/maa';
+import type { TaskConfig, ControllerConfig, TaskQueueLoop } from '@/types/maa';
 import { normalizeAgentConfigs } from '@/types/interface';
 import { parseWin32ScreencapMethod, parseWin32InputMethod } from '@/types/maa';
 import { SchedulePanel } from './SchedulePanel';
@@ -41,6 +41,8 @@ import { stopInstanceTasks } from '@/services/taskStopService';
 import { isTauri } from '@/utils/paths';
 import { onStateChanged } from '@/services/wsService';
 import { buildPiEnvVars } from '@/utils/piEnv';
+import { buildEmulatorTargetConfig, getEmulatorLaunch } from '@/utils/emulatorLaunch';
+import { formatError } from '@/utils/mxuError';
 
 const log = loggers.task;
 const PRE_ACTION_CANCELLED_ERROR = 'MXU_PRE_ACTION_CANCELLED';
@@ -463,6 +465,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
                 basePath,
                 preAction.waitForExit ?? true,
                 preAction.useCmd ?? false,
+                preAction.hideWindow ?? false,
               );
               throwIfPreActionStopped(targetId);
 
@@ -509,7 +512,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
                 throwIfPreActionStopped(targetId);
                 try {
                   if (controllerType === 'Adb') {
-                    const devices = await maaService.findAdbDevices();
+                    const devices = await maaService.findAdbDevices(useAppStore.getState().adbPath);
                     if (savedDevice?.adbDeviceName) {
                       deviceFound = devices.some((d) => d.name === savedDevice.adbDeviceName);
                     } else {
@@ -563,7 +566,9 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
                 ) {
                   try {
                     if (controllerType === 'Adb') {
-                      const devices = await maaService.findAdbDevices();
+                      const devices = await maaService.findAdbDevices(
+                        useAppStore.getState().adbPath,
+                      );
                       if (devices.length > 0) {
                         addLog(targetId, {
                           type: 'info',
@@ -662,10 +667,23 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
           const controllerType = controller?.type;
 
           await ensureMaaInitialized();
-          await maaService.createInstance(targetId).catch((err) => {
+          await maaService.createInstance(targetId, targetInstance.name).catch((err) => {
             log.warn('创建实例失败（可能已存在）:', err);
           });
 
+          // 连接前自动启动模拟器：先拉起模拟器并等待设备/窗口就绪，再按常规流程搜索连接
+          const emulatorLaunch = shouldUseDummyController ? undefined : getEmulatorLaunch(targetId);
+          const emulatorTarget = emulatorLaunch ? buildEmulatorTargetConfig(targetId) : null;
+          if (emulatorLaunch && emulatorTarget) {
+            onPhaseChange?.('searching');
+            await beginPreActionControl(targetId);
+            try {
+              await maaService.prepareEmulator(targetId, emulatorTarget, emulatorLaunch);
+            } finally {
+              await endPreActionControl(targetId);
+            }
+          }
+
           let config: ControllerConfig | null = null;
           let deviceName = '';
           let targetType: 'device' | 'window' = 'device';
@@ -676,7 +694,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
             onPhaseChange?.('searching');
 
             if (controllerType === 'Adb' && savedDevice.adbDeviceName) {
-              const devices = await maaService.findAdbDevices();
+              const devices = await maaService.findAdbDevices(useAppStore.getState().adbPath);
               const matchedDevice = devices.find((d) => d.name === savedDevice.adbDeviceName);
               if (!matchedDevice) {
                 log.warn(`实例 ${targetInstance.name}: 未找到设备 ${savedDevice.adbDeviceName}`);
@@ -754,7 +772,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
             onPhaseChange?.('searching');
 
             if (controllerType === 'Adb') {
-              const devices = await maaService.findAdbDevices();
+              const devices = await maaService.findAdbDevices(useAppStore.getState().adbPath);
               if (devices.length === 0) {
                 log.warn(`实例 ${targetInstance.name}: 未搜索到任何 ADB 设备`);
                 addLog(targetId, {
@@ -909,7 +927,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
 
             let ctrlId: number;
             try {
-              ctrlId = await maaService.connectController(targetId, config);
+              ctrlId = await maaService.connectController(targetId, config, emulatorLaunch);
             } catch (err) {
               unsubscribe();
               throw err;
@@ -1034,6 +1052,30 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
           }
         }
 
+        // 运行前快速校验资源完整性，文件缺失或被改动时提示重新下载（不阻止运行）
+        if (resource && isTauri()) {
+          try {
+            const reports = await maaService.verifyResourceIntegrity(
+              computeResourcePaths(resource, controller, basePath),
+            );
+            const missing = reports.flatMap((r) => r.missing);
+            const modified = reports.flatMap((r) => r.modified);
+            if (missing.length > 0 || modified.length > 0) {
+              log.warn(`实例 ${targetInstance.name}: 资源文件缺失或被改动`, { missing, modified });
+              addLog(targetId, {
+                type: 'warning',
+                message: t('action.resourceIntegrityWarning', {
+                  missing: missing.length,
+                  modified: modified.length,
+                  files: [...missing, ...modified].slice(0, 3).join(', '),
+                }),
+              });
+            }
+          } catch (err) {
+            log.warn(`实例 ${targetInstance.name}: 资源完整性校验失败:`, err);
+          }
+        }
+
         // 查询后端真实状态，纠正前端可能过时的缓存
         const backendState = await maaService.getInstanceState(targetId);
         if (backendState && !backendState.resourceLoaded && instanceResourceLoaded[targetId]) {
@@ -1124,6 +1166,16 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
           ].join(', ')}`,
         );
 
+        // 循环执行：收尾段会切换到 Dummy Controller，含收尾特殊任务时只执行一轮
+        const loopSetting = targetInstance.queueLoop;
+        const queueLoop: TaskQueueLoop | null =
+          loopSetting?.enabled && !hasTrailingBatch
+            ? { rounds: loopSetting.rounds, interval_ms: loopSetting.intervalSec * 1000 }
+            : null;
+        if (loopSetting?.enabled && hasTrailingBatch) {
+          addLog(targetId, { type: 'warning', message: t('schedule.queueLoopSkipped') });
+        }
+
         const buildTaskConfigs = (batchTasks: RunnableTask[]): TaskConfig[] =>
           batchTasks.map(({ selectedTask, taskDef, specialTask }) => {
             const taskDisplayName =
@@ -1144,6 +1196,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
                 useAppStore.getState().globalOptionValues,
               ),
               selected_task_id: selectedTask.id,
+              skip_if: selectedTask.skipIf,
             };
           });
 
@@ -1174,6 +1227,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
             tcpCompatMode,
             piEnvs,
             resetState,
+            resetState ? queueLoop : null,
           );
 
           log.info(`实例 ${targetInstance.name}: ${batchName}任务已提交, task_ids:`, batchTaskIds);
@@ -1256,7 +1310,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
         const errMsg = err instanceof Error ? err.message : String(err);
         const cancelled = errMsg === PRE_ACTION_CANCELLED_ERROR;
         if (!cancelled) {
-          const localizedErrMsg = errMsg
+          const localizedErrMsg = formatError(err, t)
             .replace(
               ' [[hint:spawn_file_not_found]]',
               ` ${t('taskList.autoConnect.agentSpawnHintFileNotFound')}`,
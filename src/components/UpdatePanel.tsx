@@ -11,10 +11,12 @@ import {
 } from 'lucide-react';
 import { useAppStore, type DownloadProgress } from '@/stores/appStore';
 import { simpleMarkdownToHtml } from '@/services/contentResolver';
+import { toast } from 'sonner';
 import {
   downloadUpdate,
   getUpdateSavePath,
   MIRRORCHYAN_ERROR_CODES,
+  openManualDownload,
   savePendingUpdateInfo,
 } from '@/services/updateService';
 import { proxySettingsForUpdateDownload } from '@/services/proxyService';
@@ -94,6 +96,16 @@ export function UpdatePanel({ onClose, anchorRef }: UpdatePanelProps) {
         });
       } else {
         setDownloadStatus('failed');
+        if (result.networkUnavailable) {
+          const url = updateInfo.downloadUrl;
+          toast.error(t('mirrorChyan.networkUnavailable'), {
+            description: t('mirrorChyan.networkUnavailableHint'),
+            action: {
+              label: t('mirrorChyan.manualDownload'),
+              onClick: () => openManualDownload(url),
+            },
+          });
+        }
       }
     } catch (error) {
       loggers.ui.error('下载失败:', error);
@@ -106,6 +118,7 @@ export function UpdatePanel({ onClose, anchorRef }: UpdatePanelProps) {
     setDownloadStatus,
     setDownloadProgress,
     setDownloadSavePath,
+    t,
   ]);
 
   // 自动下载已由 App.tsx 在检查更新后立即触发，此处不再重复处理
@@ -5,6 +5,8 @@ import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
 import { getCacheDir, joinPath } from '@/utils/paths';
 import { loggers } from '@/utils/logger';
+import { formatError } from '@/utils/mxuError';
+import { downloadWithProxy } from '@/services/proxyService';
 
 const log = loggers.app;
 
@@ -86,20 +88,16 @@ export function VCRedistModal({ show, onClose }: VCRedistModalProps) {
 
       log.info(`开始下载 VC++ 运行库: ${vcredistUrl} -> ${downloadPath}`);
 
-      const sessionId = await invoke<number>('download_file', {
-        url: vcredistUrl,
-        savePath: downloadPath,
-        totalSize: null,
-      });
+      const result = await downloadWithProxy(vcredistUrl, downloadPath);
 
-      currentSessionId.current = sessionId;
+      currentSessionId.current = result.session_id;
       log.info('VC++ 运行库下载完成');
 
       // 2. 运行安装程序并等待
       setStatus('installing');
-      log.info(`运行安装程序: ${downloadPath}`);
+      log.info(`运行安装程序: ${result.actual_save_path}`);
 
-      const exitCode = await invoke<number>('run_and_wait', { filePath: downloadPath });
+      const exitCode = await invoke<number>('run_and_wait', { filePath: result.actual_save_path });
       log.info(`安装程序退出，退出码: ${exitCode}`);
 
       // 3. 重试加载 DLL
@@ -118,7 +116,7 @@ export function VCRedistModal({ show, onClose }: VCRedistModalProps) {
     } catch (err) {
       log.error('VC++ 运行库安装流程失败:', err);
       setStatus('download_failed');
-      setError(err instanceof Error ? err.message : String(err));
+      setError(formatError(err, t));
     }
   }, [t]);
 
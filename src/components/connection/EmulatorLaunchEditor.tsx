@@ -0,0 +1,100 @@
+import { useCallback } from 'react';
+import { useTranslation } from 'react-i18next';
+import { Rocket } from 'lucide-react';
+import { useAppStore } from '@/stores/appStore';
+import type { ControllerType, EmulatorLaunchSettings } from '@/types/interface';
+import { DEFAULT_EMULATOR_LAUNCH } from '@/utils/emulatorLaunch';
+import { FileField, NumberField, SwitchButton, TextField } from '../FormControls';
+
+interface EmulatorLaunchEditorProps {
+  instanceId: string;
+  controllerType: ControllerType;
+  disabled?: boolean;
+}
+
+/**
+ * 连接前自动启动模拟器设置（保存在实例配置中）
+ * 连接时目标设备/窗口不存在则先用配置的程序拉起模拟器并等待就绪
+ */
+export function EmulatorLaunchEditor({
+  instanceId,
+  controllerType,
+  disabled,
+}: EmulatorLaunchEditorProps) {
+  const { t } = useTranslation();
+  const settings = useAppStore(
+    (state) => state.instances.find((i) => i.id === instanceId)?.emulatorLaunch,
+  );
+  const updateInstance = useAppStore((state) => state.updateInstance);
+  const launch = { ...DEFAULT_EMULATOR_LAUNCH, ...settings };
+
+  const update = useCallback(
+    (updates: Partial<EmulatorLaunchSettings>) => {
+      updateInstance(instanceId, { emulatorLaunch: { ...launch, ...updates } });
+    },
+    [instanceId, launch, updateInstance],
+  );
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center justify-between gap-2">
+        <div className="flex items-center gap-2 min-w-0">
+          <Rocket className="w-3.5 h-3.5 text-text-secondary flex-shrink-0" />
+          <span className="text-xs text-text-secondary truncate">
+            {t('controller.emulatorLaunch')}
+          </span>
+        </div>
+        <SwitchButton
+          value={launch.enabled}
+          onChange={(enabled) => update({ enabled })}
+          disabled={disabled}
+        />
+      </div>
+      {launch.enabled && (
+        <div className="space-y-2 pl-5">
+          <p className="text-[10px] text-text-muted">{t('controller.emulatorLaunchHint')}</p>
+          <FileField
+            label={t('controller.emulatorLaunchProgram')}
+            value={launch.program}
+            onChange={(program) => update({ program })}
+            placeholder={t('action.programPlaceholder')}
+            disabled={disabled}
+          />
+          <TextField
+            label={t('controller.emulatorLaunchArgs')}
+            value={launch.args}
+            onChange={(args) => update({ args })}
+            disabled={disabled}
+          />
+          {controllerType === 'Adb' && (
+            <TextField
+              label={t('controller.emulatorLaunchAdbAddress')}
+              hint={t('controller.emulatorLaunchAdbAddressHint')}
+              value={launch.adbAddress ?? ''}
+              onChange={(adbAddress) => update({ adbAddress })}
+              placeholder="127.0.0.1:16384"
+              disabled={disabled}
+            />
+          )}
+          <div className="flex gap-4">
+            <NumberField
+              label={t('controller.emulatorLaunchTimeout')}
+              value={launch.timeoutSecs}
+              onChange={(timeoutSecs) => update({ timeoutSecs })}
+              min={10}
+              suffix={t('controller.emulatorLaunchSeconds')}
+              disabled={disabled}
+            />
+            <NumberField
+              label={t('controller.emulatorLaunchSettle')}
+              value={launch.settleSecs}
+              onChange={(settleSecs) => update({ settleSecs })}
+              suffix={t('controller.emulatorLaunchSeconds')}
+              disabled={disabled}
+            />
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -9,3 +9,4 @@ export {
 
 export { useDeviceConnection } from './useDeviceConnection';
 export { useResourceLoading } from './useResourceLoading';
+export { EmulatorLaunchEditor } from './EmulatorLaunchEditor';
@@ -1,10 +1,11 @@
 import { useState, useCallback, useRef } from 'react';
 import { useTranslation } from 'react-i18next';
 import { maaService } from '@/services/maaService';
-import { useAppStore } from '@/stores/appStore';
+import { getInstanceName, useAppStore } from '@/stores/appStore';
 import type { AdbDevice, Win32Window, ControllerConfig } from '@/types/maa';
 import { parseWin32ScreencapMethod, parseWin32InputMethod } from '@/types/maa';
 import type { ControllerItem } from '@/types/interface';
+import { getEmulatorLaunch } from '@/utils/emulatorLaunch';
 import { startGlobalCallbackListener, waitForCtrlResult } from './callbackCache';
 
 interface UseDeviceConnectionProps {
@@ -65,7 +66,11 @@ export function useDeviceConnection({
   const connectControllerInternal = useCallback(
     async (config: ControllerConfig, deviceName: string, targetType: 'device' | 'window') => {
       await startGlobalCallbackListener();
-      const ctrlId = await maaService.connectController(instanceId, config);
+      const ctrlId = await maaService.connectController(
+        instanceId,
+        config,
+        getEmulatorLaunch(instanceId),
+      );
 
       registerCtrlIdName(instanceId, ctrlId, deviceName || '', targetType);
 
@@ -103,7 +108,7 @@ export function useDeviceConnection({
       const savedDevice = activeInstance?.savedDevice;
 
       if (controllerType === 'Adb') {
-        const devices = await maaService.findAdbDevices();
+        const devices = await maaService.findAdbDevices(useAppStore.getState().adbPath);
         setCachedAdbDevices(devices);
 
         let autoSelected: AdbDevice | null = null;
@@ -202,7 +207,7 @@ export function useDeviceConnection({
           throw new Error(t('maa.initFailed'));
         }
 
-        await maaService.createInstance(instanceId).catch(() => {});
+        await maaService.createInstance(instanceId, getInstanceName(instanceId)).catch(() => {});
 
         const config: ControllerConfig = {
           type: 'Adb',
@@ -256,7 +261,7 @@ export function useDeviceConnection({
           throw new Error(t('maa.initFailed'));
         }
 
-        await maaService.createInstance(instanceId).catch(() => {});
+        await maaService.createInstance(instanceId, getInstanceName(instanceId)).catch(() => {});
 
         let config: ControllerConfig;
         if (controllerType === 'Win32') {
@@ -320,7 +325,7 @@ export function useDeviceConnection({
           throw new Error(t('maa.initFailed'));
         }
 
-        await maaService.createInstance(instanceId).catch(() => {});
+        await maaService.createInstance(instanceId, getInstanceName(instanceId)).catch(() => {});
 
         const config: ControllerConfig = {
           type: 'WlRoots',
@@ -358,7 +363,7 @@ export function useDeviceConnection({
         throw new Error(t('maa.initFailed'));
       }
 
-      await maaService.createInstance(instanceId).catch(() => {});
+      await maaService.createInstance(instanceId, getInstanceName(instanceId)).catch(() => {});
 
       setInstanceSavedDevice(instanceId, { playcoverAddress });
 
@@ -1,7 +1,7 @@
 import { useState, useCallback, useRef } from 'react';
 import { useTranslation } from 'react-i18next';
 import { maaService } from '@/services/maaService';
-import { useAppStore } from '@/stores/appStore';
+import { getInstanceName, useAppStore } from '@/stores/appStore';
 import { resolveI18nText } from '@/services/contentResolver';
 import { isDebugVersion } from '@/services/updateService';
 import type { ResourceItem, ControllerItem } from '@/types/interface';
@@ -39,7 +39,7 @@ export function useResourceLoading({
       setResourceError(null);
 
       try {
-        await maaService.createInstance(instanceId).catch(() => {});
+        await maaService.createInstance(instanceId, getInstanceName(instanceId)).catch(() => {});
         await startGlobalCallbackListener();
 
         const resourceDisplayName = resolveI18nText(resource.label, translations) || resource.name;
@@ -31,7 +31,8 @@ export type { MenuItem, ContextMenuState } from './ContextMenu';
 export { InstallConfirmModal } from './InstallConfirmModal';
 export { VCRedistModal } from './VCRedistModal';
 export { BadPathModal } from './BadPathModal';
-export type { BadPathType } from './BadPathModal';
+export type { BadPathType, VolumeIssueType } from './BadPathModal';
+export { BreakpointModal } from './BreakpointModal';
 export { OnboardingOverlay } from './OnboardingOverlay';
 export {
   VersionInfo,
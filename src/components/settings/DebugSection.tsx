@@ -11,15 +11,87 @@ import {
   ExternalLink,
   Server,
   EthernetPort,
+  CirclePause,
+  Layers,
+  MonitorOff,
+  ScanEye,
+  Gauge,
+  Loader2,
+  FileJson,
+  Camera,
+  AppWindow,
+  Minimize2,
+  Video,
+  Square,
+  MousePointerClick,
+  Cpu,
+  Crosshair,
+  Smartphone,
+  Copy,
+  PackageSearch,
+  Images,
+  RotateCcw,
+  FileCheck,
+  FolderSearch,
+  SunMedium,
+  Timer,
+  ScanSearch,
+  Hourglass,
 } from 'lucide-react';
+import { toast } from 'sonner';
 
 import { useAppStore } from '@/stores/appStore';
 import { maaService } from '@/services/maaService';
+import type {
+  BenchmarkResult,
+  CaptureMode,
+  ClipExportResult,
+  ClipFormat,
+  ControllerInfo,
+  FrameDedupConfig,
+  MinimizedPolicy,
+  ScreenshotPreprocess,
+  TaskRetryPolicy,
+  UnknownScreenConfig,
+  UnknownScreensInfo,
+  VersionInfo,
+  WatchdogConfig,
+} from '@/types/maa';
+import { saveInstanceRuntimeSettings } from '@/utils/instanceRuntimeSettings';
 import { loggers } from '@/utils/logger';
 import { isTauri, getDebugDir, getConfigDir, openDirectory } from '@/utils/paths';
 import { useExportLogs } from '@/utils/useExportLogs';
 import { SwitchButton } from '@/components/FormControls';
 import { ExportLogsModal } from './ExportLogsModal';
+import { ProcessMonitor } from './ProcessMonitor';
+
+type PreprocessField = keyof ScreenshotPreprocess;
+
+const PREPROCESS_FIELDS: PreprocessField[] = ['brightness', 'contrast', 'gamma'];
+
+/** 各项预处理参数的取值范围，与后端校验一致 */
+const PREPROCESS_RANGES: Record<PreprocessField, [number, number]> = {
+  brightness: [-100, 100],
+  contrast: [0.5, 2],
+  gamma: [0.2, 5],
+};
+
+const PREPROCESS_LABEL_KEYS: Record<PreprocessField, string> = {
+  brightness: 'debug.preprocessBrightness',
+  contrast: 'debug.preprocessContrast',
+  gamma: 'debug.preprocessGamma',
+};
+
+/** 刚开启时的参数：提亮暗部，其余不调整 */
+const DEFAULT_PREPROCESS: ScreenshotPreprocess = { brightness: 0, contrast: 1, gamma: 1.2 };
+
+function preprocessToInputs(config: ScreenshotPreprocess | null): Record<PreprocessField, string> {
+  return {
+    brightness: config ? String(config.brightness) : '',
+    contrast: config ? String(config.contrast) : '',
+    gamma: config ? String(config.gamma) : '',
+  };
+}
 
 export function DebugSection() {
   const { t } = useTranslation();
@@ -30,6 +102,14 @@ export function DebugSection() {
     setDevMode,
     saveDraw,
     setSaveDraw,
+    logLevel,
+    setLogLevel,
+    eventLogEnabled,
+    setEventLogEnabled,
+    failureScreenshotEnabled,
+    setFailureScreenshotEnabled,
+    actionTraceEnabled,
+    setActionTraceEnabled,
     tcpCompatMode,
     setTcpCompatMode,
     allowLanAccess,
@@ -38,12 +118,23 @@ export function DebugSection() {
     setWebServerEnabled,
     webServerPort: configuredPort,
     setWebServerPort: setConfiguredPort,
+    agentResourceLimits,
+    setAgentResourceLimits,
+    adbPath,
+    setAdbPath,
+    toolkitConfig,
+    setToolkitConfig,
+    updateCopyConcurrency,
+    setUpdateCopyConcurrency,
     backendOS,
     backendArch,
+    activeInstanceId,
   } = useAppStore();
 
   const [mxuVersion, setMxuVersion] = useState<string | null>(null);
   const [maafwVersion, setMaafwVersion] = useState<string | null>(null);
+  const [versionInfo, setVersionInfo] = useState<VersionInfo | null>(null);
+  const [controllerInfo, setControllerInfo] = useState<ControllerInfo | null>(null);
   const [exeDir, setExeDir] = useState<string | null>(null);
   const [cwd, setCwd] = useState<string | null>(null);
   const [webview2Dir, setWebview2Dir] = useState<{ path: string; system: boolean } | null>(null);
@@ -52,11 +143,476 @@ export function DebugSection() {
     osVersion: string;
     arch: string;
     tauriVersion: string;
+    exeVolume?: { driveType: string; writable: boolean };
+    cpu?: { x86: boolean; avx: boolean; avx2: boolean; missing: string[] };
   } | null>(null);
   const [webServerPort, setWebServerPort] = useState<number>(0);
   const [lanIp, setLanIp] = useState<string | null>(null);
   const [showRestartPrompt, setShowRestartPrompt] = useState(false);
   const [portInput, setPortInput] = useState(String(configuredPort));
+  const [adbPathInput, setAdbPathInput] = useState(adbPath);
+  const [adbPathChecking, setAdbPathChecking] = useState(false);
+  const [extraAdbDirsInput, setExtraAdbDirsInput] = useState(toolkitConfig.extraAdbDirs.join('\n'));
+  const [emulatorDirsInput, setEmulatorDirsInput] = useState(toolkitConfig.emulatorDirs.join('\n'));
+  const [agentMemoryInput, setAgentMemoryInput] = useState(
+    String(agentResourceLimits.memoryLimitMb),
+  );
+  const [agentCpuInput, setAgentCpuInput] = useState(String(agentResourceLimits.cpuRatePercent));
+  const [copyConcurrencyInput, setCopyConcurrencyInput] = useState(String(updateCopyConcurrency));
+  const [breakpointInput, setBreakpointInput] = useState('');
+  const [webview2Shared, setWebview2Shared] = useState(false);
+  const [webviewGpuDisabled, setWebviewGpuDisabled] = useState(false);
+  const [dryRun, setDryRun] = useState(false);
+  const [speed, setSpeed] = useState(1);
+  const [speedInput, setSpeedInput] = useState('1');
+  const [captureMode, setCaptureMode] = useState<CaptureMode>('default');
+  const [minimizedPolicy, setMinimizedPolicy] = useState<MinimizedPolicy>('notify');
+  const [frameDedup, setFrameDedup] = useState<FrameDedupConfig | null>(null);
+  const [frameDedupSimilarityInput, setFrameDedupSimilarityInput] = useState('');
+  const [unknownScreens, setUnknownScreens] = useState<UnknownScreenConfig | null>(null);
+  const [unknownThresholdInput, setUnknownThresholdInput] = useState('');
+  const [unknownScreensInfo, setUnknownScreensInfo] = useState<UnknownScreensInfo | null>(null);
+  const [unknownScreensExporting, setUnknownScreensExporting] = useState(false);
+  const [preprocess, setPreprocess] = useState<ScreenshotPreprocess | null>(null);
+  const [preprocessInputs, setPreprocessInputs] = useState<Record<PreprocessField, string>>(
+    preprocessToInputs(null),
+  );
+  const [taskRetry, setTaskRetry] = useState<TaskRetryPolicy | null>(null);
+  const [retryCountInput, setRetryCountInput] = useState('');
+  const [retryIntervalInput, setRetryIntervalInput] = useState('');
+  const [retryRestartAppInput, setRetryRestartAppInput] = useState('');
+  const [watchdog, setWatchdog] = useState<WatchdogConfig | null>(null);
+  const [watchdogMinutesInput, setWatchdogMinutesInput] = useState('');
+  const [benchmarkRunning, setBenchmarkRunning] = useState(false);
+  const [benchmarkResult, setBenchmarkResult] = useState<BenchmarkResult | null>(null);
+  const [benchmarkError, setBenchmarkError] = useState<string | null>(null);
+  const [clipRecording, setClipRecording] = useState(false);
+  const [clipExporting, setClipExporting] = useState<ClipFormat | null>(null);
+  const [clipResult, setClipResult] = useState<
+    (ClipExportResult & { requested: ClipFormat }) | null
+  >(null);
+  const [clipError, setClipError] = useState<string | null>(null);
+
+  const loadControllerInfo = useCallback(() => {
+    if (!activeInstanceId) return;
+    maaService
+      .getControllerInfo(activeInstanceId)
+      .then(setControllerInfo)
+      .catch((err) => loggers.ui.warn('获取控制器详情失败:', err));
+  }, [activeInstanceId]);
+
+  useEffect(() => {
+    loadControllerInfo();
+  }, [loadControllerInfo]);
+
+  // 切换实例时加载该实例已设置的断点、dry-run 状态、速度系数、截图防遮挡模式、最小化处理方式、帧去重、未知界面收集、截图预处理、失败重试、看门狗和录制状态
+  useEffect(() => {
+    if (!activeInstanceId) return;
+    maaService
+      .getBreakpoints(activeInstanceId)
+      .then((nodes) => setBreakpointInput(nodes.join(', ')))
+      .catch((err) => loggers.ui.warn('获取断点失败:', err));
+    maaService
+      .getDryRun(activeInstanceId)
+      .then(setDryRun)
+      .catch((err) => loggers.ui.warn('获取 dry-run 状态失败:', err));
+    maaService
+      .getSpeed(activeInstanceId)
+      .then((value) => {
+        setSpeed(value);
+        setSpeedInput(String(value));
+      })
+      .catch((err) => loggers.ui.warn('获取速度系数失败:', err));
+    maaService
+      .getCaptureMode(activeInstanceId)
+      .then(setCaptureMode)
+      .catch((err) => loggers.ui.warn('获取截图防遮挡模式失败:', err));
+    maaService
+      .getMinimizedPolicy(activeInstanceId)
+      .then(setMinimizedPolicy)
+      .catch((err) => loggers.ui.warn('获取窗口最小化处理方式失败:', err));
+    maaService
+      .getFrameDedup(activeInstanceId)
+      .then((config) => {
+        setFrameDedup(config);
+        setFrameDedupSimilarityInput(config ? String(config.similarity) : '');
+      })
+      .catch((err) => loggers.ui.warn('获取截图帧去重配置失败:', err));
+    maaService
+      .getUnknownScreenCapture(activeInstanceId)
+      .then((config) => {
+        setUnknownScreens(config);
+        setUnknownThresholdInput(config ? String(config.threshold) : '');
+      })
+      .catch((err) => loggers.ui.warn('获取未知界面收集配置失败:', err));
+    maaService
+      .getUnknownScreensInfo()
+      .then(setUnknownScreensInfo)
+      .catch((err) => loggers.ui.warn('获取未知界面收集情况失败:', err));
+    maaService
+      .getScreenshotPreprocess(activeInstanceId)
+      .then((config) => {
+        setPreprocess(config);
+        setPreprocessInputs(preprocessToInputs(config));
+      })
+      .catch((err) => loggers.ui.warn('获取截图预处理参数失败:', err));
+    maaService
+      .getTaskRetryPolicy(activeInstanceId)
+      .then((policy) => {
+        setTaskRetry(policy);
+        setRetryCountInput(policy ? String(policy.max_retries) : '');
+        setRetryIntervalInput(policy ? String(policy.interval_ms / 1000) : '');
+        setRetryRestartAppInput(policy?.restart_app ?? '');
+      })
+      .catch((err) => loggers.ui.warn('获取任务失败重试策略失败:', err));
+    maaService
+      .getWatchdog(activeInstanceId)
+      .then((config) => {
+        setWatchdog(config);
+        setWatchdogMinutesInput(config ? String(config.max_runtime_secs / 60) : '');
+      })
+      .catch((err) => loggers.ui.warn('获取看门狗配置失败:', err));
+    maaService
+      .isRecordingClip(activeInstanceId)
+      .then(setClipRecording)
+      .catch((err) => loggers.ui.warn('获取录制状态失败:', err));
+  }, [activeInstanceId]);
+
+  const handleBenchmark = async () => {
+    if (!activeInstanceId) return;
+    setBenchmarkRunning(true);
+    setBenchmarkError(null);
+    try {
+      setBenchmarkResult(await maaService.benchmark(activeInstanceId));
+    } catch (err) {
+      loggers.ui.error('性能测试失败:', err);
+      setBenchmarkError(String(err));
+    } finally {
+      setBenchmarkRunning(false);
+    }
+  };
+
+  const handleAnnotatedScreenshot = async () => {
+    if (!activeInstanceId) return;
+    try {
+      const result = await maaService.saveAnnotatedScreenshot(activeInstanceId);
+      toast.success(t('debug.annotatedScreenshotSaved', { count: result.recognitions.length }));
+      await maaService.openFolderAndSelect(result.path);
+    } catch (err) {
+      loggers.ui.error('保存标注截图失败:', err);
+      toast.error(t('debug.annotatedScreenshotFailed', { error: String(err) }));
+    }
+  };
+
+  const handleResetResourceManifests = async () => {
+    try {
+      await maaService.resetResourceManifests();
+      toast.success(t('debug.resourceManifestReset'));
+    } catch (err) {
+      loggers.ui.error('重建资源校验清单失败:', err);
+      toast.error(t('debug.resourceManifestResetFailed', { error: String(err) }));
+    }
+  };
+
+  const handleExportIssueBundle = async () => {
+    if (!activeInstanceId) return;
+    try {
+      const zipPath = await maaService.exportIssueBundle(activeInstanceId, projectInterface?.name);
+      toast.success(t('debug.issueBundleExported'));
+      await maaService.openFolderAndSelect(zipPath);
+    } catch (err) {
+      loggers.ui.error('导出问题包失败:', err);
+      toast.error(t('debug.issueBundleFailed', { error: String(err) }));
+    }
+  };
+
+  const handleStartClip = async () => {
+    if (!activeInstanceId) return;
+    setClipError(null);
+    try {
+      await maaService.startRecordingClip(activeInstanceId);
+      setClipRecording(true);
+    } catch (err) {
+      loggers.ui.error('开始录制失败:', err);
+      setClipError(String(err));
+    }
+  };
+
+  const handleExportClip = async (format: ClipFormat) => {
+    if (!activeInstanceId) return;
+    setClipExporting(format);
+    setClipError(null);
+    try {
+      const result = await maaService.stopAndExportClip(activeInstanceId, format);
+      setClipResult({ ...result, requested: format });
+    } catch (err) {
+      loggers.ui.error('导出录像失败:', err);
+      setClipResult(null);
+      setClipError(String(err));
+    } finally {
+      setClipRecording(false);
+      setClipExporting(null);
+    }
+  };
+
+  const handleDryRunToggle = async (v: boolean) => {
+    if (!activeInstanceId) return;
+    try {
+      await maaService.setDryRun(activeInstanceId, v);
+      setDryRun(v);
+      saveInstanceRuntimeSettings(activeInstanceId, { dryRun: v });
+    } catch (err) {
+      loggers.ui.error('设置 dry-run 失败:', err);
+    }
+  };
+
+  // 非法输入恢复为当前值
+  const handleSpeedBlur = async () => {
+    if (!activeInstanceId) return;
+    const parsed = parseFloat(speedInput);
+    if (!Number.isFinite(parsed) || parsed < 0.25 || parsed > 4) {
+      setSpeedInput(String(speed));
+      return;
+    }
+    if (parsed === speed) return;
+    try {
+      await maaService.setSpeed(activeInstanceId, parsed);
+      setSpeed(parsed);
+      setSpeedInput(String(parsed));
+      saveInstanceRuntimeSettings(activeInstanceId, { speed: parsed });
+    } catch (err) {
+      loggers.ui.error('设置速度系数失败:', err);
+      setSpeedInput(String(speed));
+    }
+  };
+
+  const handleCaptureModeChange = async (mode: CaptureMode) => {
+    if (!activeInstanceId) return;
+    try {
+      await maaService.setCaptureMode(activeInstanceId, mode);
+      setCaptureMode(mode);
+    } catch (err) {
+      loggers.ui.error('设置截图防遮挡模式失败:', err);
+    }
+  };
+
+  const handleMinimizedPolicyChange = async (policy: MinimizedPolicy) => {
+    if (!activeInstanceId) return;
+    try {
+      await maaService.setMinimizedPolicy(activeInstanceId, policy);
+      setMinimizedPolicy(policy);
+    } catch (err) {
+      loggers.ui.error('设置窗口最小化处理方式失败:', err);
+    }
+  };
+
+  const applyFrameDedup = async (config: FrameDedupConfig | null) => {
+    if (!activeInstanceId) return;
+    try {
+      await maaService.setFrameDedup(activeInstanceId, config);
+      setFrameDedup(config);
+      setFrameDedupSimilarityInput(config ? String(config.similarity) : '');
+    } catch (err) {
+      loggers.ui.error('设置截图帧去重失败:', err);
+    }
+  };
+
+  const handleFrameDedupToggle = (enabled: boolean) =>
+    applyFrameDedup(enabled ? { similarity: 0.98, max_interval_ms: 2000 } : null);
+
+  // 非法输入恢复为当前值
+  const handleFrameDedupSimilarityBlur = () => {
+    if (!frameDedup) return;
+    const parsed = parseFloat(frameDedupSimilarityInput);
+    if (!Number.isFinite(parsed) || parsed < 0.5 || parsed > 1) {
+      setFrameDedupSimilarityInput(String(frameDedup.similarity));
+      return;
+    }
+    if (parsed !== frameDedup.similarity) {
+      applyFrameDedup({ ...frameDedup, similarity: parsed });
+    }
+  };
+
+  const applyUnknownScreens = async (config: UnknownScreenConfig | null) => {
+    if (!activeInstanceId) return;
+    try {
+      await maaService.setUnknownScreenCapture(activeInstanceId, config);
+      setUnknownScreens(config);
+      setUnknownThresholdInput(config ? String(config.threshold) : '');
+      saveInstanceRuntimeSettings(activeInstanceId, { unknownScreens: config });
+    } catch (err) {
+      loggers.ui.error('设置未知界面收集失败:', err);
+    }
+  };
+
+  const handleUnknownScreensToggle = (enabled: boolean) =>
+    applyUnknownScreens(enabled ? { threshold: 5 } : null);
+
+  // 非法输入恢复为当前值
+  const handleUnknownThresholdBlur = () => {
+    if (!unknownScreens) return;
+    const parsed = Number(unknownThresholdInput);
+    if (!Number.isInteger(parsed) || parsed < 1 || parsed > 100) {
+      setUnknownThresholdInput(String(unknownScreens.threshold));
+      return;
+    }
+    if (parsed !== unknownScreens.threshold) {
+      applyUnknownScreens({ threshold: parsed });
+    }
+  };
+
+  const handleExportUnknownScreens = async () => {
+    setUnknownScreensExporting(true);
+    try {
+      const zipPath = await maaService.exportUnknownScreens(projectInterface?.name);
+      toast.success(t('debug.unknownScreensExported'));
+      await maaService.openFolderAndSelect(zipPath);
+    } catch (err) {
+      loggers.ui.error('打包未知界面失败:', err);
+      toast.error(t('debug.unknownScreensExportFailed', { error: String(err) }));
+    } finally {
+      setUnknownScreensExporting(false);
+      maaService
+        .getUnknownScreensInfo()
+        .then(setUnknownScreensInfo)
+        .catch((err) => loggers.ui.warn('获取未知界面收集情况失败:', err));
+    }
+  };
+
+  const applyPreprocess = async (config: ScreenshotPreprocess | null) => {
+    if (!activeInstanceId) return;
+    try {
+      const needsReconnect = await maaService.setScreenshotPreprocess(activeInstanceId, config);
+      setPreprocess(config);
+      setPreprocessInputs(preprocessToInputs(config));
+      saveInstanceRuntimeSettings(activeInstanceId, { screenshotPreprocess: config });
+      if (needsReconnect) {
+        toast.info(t('debug.preprocessNeedsReconnect'));
+      }
+    } catch (err) {
+      loggers.ui.error('设置截图预处理失败:', err);
+      toast.error(String(err));
+    }
+  };
+
+  const handlePreprocessToggle = (enabled: boolean) =>
+    applyPreprocess(enabled ? { ...DEFAULT_PREPROCESS } : null);
+
+  // 非法输入恢复为当前值
+  const handlePreprocessBlur = (field: PreprocessField) => {
+    if (!preprocess) return;
+    const [min, max] = PREPROCESS_RANGES[field];
+    const parsed = parseFloat(preprocessInputs[field]);
+    if (!Number.isFinite(parsed) || parsed < min || parsed > max) {
+      setPreprocessInputs(preprocessToInputs(preprocess));
+      return;
+    }
+    const value = field === 'brightness' ? Math.round(parsed) : parsed;
+    if (value !== preprocess[field]) {
+      applyPreprocess({ ...preprocess, [field]: value });
+    }
+  };
+
+  const formatPreprocess = (config: ScreenshotPreprocess) => {
+    const items = PREPROCESS_FIELDS.map((f) => `${t(PREPROCESS_LABEL_KEYS[f])} ${config[f]}`);
+    return items.join(' · ');
+  };
+
+  const syncTaskRetry = (policy: TaskRetryPolicy | null) => {
+    setTaskRetry(policy);
+    setRetryCountInput(policy ? String(policy.max_retries) : '');
+    setRetryIntervalInput(policy ? String(policy.interval_ms / 1000) : '');
+    setRetryRestartAppInput(policy?.restart_app ?? '');
+  };
+
+  const applyTaskRetry = async (policy: TaskRetryPolicy | null) => {
+    if (!activeInstanceId) return;
+    try {
+      await maaService.setTaskRetryPolicy(activeInstanceId, policy);
+      syncTaskRetry(policy);
+      saveInstanceRuntimeSettings(activeInstanceId, { taskRetry: policy });
+    } catch (err) {
+      loggers.ui.error('设置任务失败重试策略失败:', err);
+      toast.error(t('debug.taskRetryFailed', { error: String(err) }));
+    }
+  };
+
+  const handleTaskRetryToggle = (enabled: boolean) =>
+    applyTaskRetry(
+      enabled ? { max_retries: 2, interval_ms: 5000, reconnect: false, restart_app: null } : null,
+    );
+
+  // 非法输入恢复为当前值
+  const handleRetryInputsBlur = () => {
+    if (!taskRetry) return;
+    const count = parseInt(retryCountInput, 10);
+    const seconds = parseFloat(retryIntervalInput);
+    if (!Number.isInteger(count) || count < 1 || count > 10 || !(seconds >= 0 && seconds <= 600)) {
+      syncTaskRetry(taskRetry);
+      return;
+    }
+    const next: TaskRetryPolicy = {
+      ...taskRetry,
+      max_retries: count,
+      interval_ms: Math.round(seconds * 1000),
+      restart_app: retryRestartAppInput.trim() || null,
+    };
+    if (
+      next.max_retries !== taskRetry.max_retries ||
+      next.interval_ms !== taskRetry.interval_ms ||
+      next.restart_app !== (taskRetry.restart_app ?? null)
+    ) {
+      applyTaskRetry(next);
+    }
+  };
+
+  const applyWatchdog = async (config: WatchdogConfig | null) => {
+    if (!activeInstanceId) return;
+    try {
+      await maaService.setWatchdog(
+        activeInstanceId,
+        config?.max_runtime_secs ?? null,
+        config?.count_paused ?? false,
+      );
+      setWatchdog(config);
+      setWatchdogMinutesInput(config ? String(config.max_runtime_secs / 60) : '');
+      saveInstanceRuntimeSettings(activeInstanceId, { watchdog: config });
+    } catch (err) {
+      loggers.ui.error('设置看门狗失败:', err);
+      toast.error(t('debug.watchdogFailed', { error: String(err) }));
+    }
+  };
+
+  const handleWatchdogToggle = (enabled: boolean) =>
+    applyWatchdog(enabled ? { max_runtime_secs: 3600, count_paused: false } : null);
+
+  // 非法输入恢复为当前值
+  const handleWatchdogMinutesBlur = () => {
+    if (!watchdog) return;
+    const minutes = parseFloat(watchdogMinutesInput);
+    if (!(minutes >= 1 && minutes <= 1440)) {
+      setWatchdogMinutesInput(String(watchdog.max_runtime_secs / 60));
+      return;
+    }
+    const secs = Math.round(minutes * 60);
+    if (secs !== watchdog.max_runtime_secs) {
+      applyWatchdog({ ...watchdog, max_runtime_secs: secs });
+    }
+  };
+
+  const handleBreakpointBlur = async () => {
+    if (!activeInstanceId) return;
+    const nodes = breakpointInput
+      .split(/[,，\s]+/)
+      .map((n) => n.trim())
+      .filter(Boolean);
+    try {
+      await maaService.setBreakpoints(activeInstanceId, nodes);
+      setBreakpointInput(nodes.join(', '));
+    } catch (err) {
+      loggers.ui.error('设置断点失败:', err);
+    }
+  };
 
   useEffect(() => {
     setPortInput(String(configuredPort));
@@ -87,23 +643,37 @@ export function DebugSection() {
         setMaafwVersion(null);
       }
 
+      // Agent 版本及编译信息
+      if (isTauri()) {
+        try {
+          setVersionInfo(await maaService.getVersionInfo());
+        } catch {
+          setVersionInfo(null);
+        }
+      }
+
       // 路径信息和系统信息（仅在 Tauri 环境有意义）
       if (isTauri()) {
         try {
           const { invoke } = await import('@tauri-apps/api/core');
-          const [exeDirResult, cwdResult, sysInfo, webview2DirResult, port, localIp] =
-            await Promise.all([
-              invoke<string>('get_exe_dir'),
-              invoke<string>('get_cwd'),
-              invoke<{ os: string; os_version: string; arch: string; tauri_version: string }>(
-                'get_system_info',
-              ),
-              invoke<{ path: string; system: boolean }>('get_webview2_dir'),
-              invoke<number>('get_web_server_port'),
-              invoke<string | null>('get_local_lan_ip'),
-            ]);
-          setExeDir(exeDirResult);
-          setCwd(cwdResult);
+          const [appPaths, sysInfo, webview2DirResult, port, localIp] = await Promise.all([
+            maaService.getAppPaths(),
+            invoke<{
+              os: string;
+              os_version: string;
+              arch: string;
+              tauri_version: string;
+              exe_volume: { drive_type: string; writable: boolean };
+              cpu: { x86: boolean; avx: boolean; avx2: boolean; missing: string[] };
+            }>('get_system_info'),
+            invoke<{ path: string; system: boolean }>('get_webview2_dir'),
+            invoke<number>('get_web_server_port'),
+            invoke<string | null>('get_local_lan_ip'),
+          ]);
+          setWebview2Shared(await invoke<boolean>('get_webview2_shared'));
+          setWebviewGpuDisabled(await invoke<boolean>('get_webview_gpu_disabled'));
+          setExeDir(appPaths?.exe_dir.path ?? null);
+          setCwd(appPaths?.cwd.path ?? null);
           setWebview2Dir(webview2DirResult);
           setWebServerPort(port);
           setLanIp(localIp);
@@ -112,6 +682,11 @@ export function DebugSection() {
             osVersion: sysInfo.os_version,
             arch: sysInfo.arch,
             tauriVersion: sysInfo.tauri_version,
+            exeVolume: {
+              driveType: sysInfo.exe_volume.drive_type,
+              writable: sysInfo.exe_volume.writable,
+            },
+            cpu: sysInfo.cpu,
           });
         } catch {
           setExeDir(null);
@@ -208,10 +783,115 @@ export function DebugSection() {
     }
   }, [portInput, configuredPort, setConfiguredPort]);
 
+  // 用指定的 adb 搜索一次设备，成功后才保存路径；清空则恢复由框架自行查找
+  const handleAdbPathBlur = useCallback(async () => {
+    const path = adbPathInput.trim();
+    if (path === adbPath) return;
+    if (!path) {
+      setAdbPath('');
+      return;
+    }
+    setAdbPathChecking(true);
+    try {
+      const devices = await maaService.findAdbDevices(path);
+      setAdbPath(path);
+      setAdbPathInput(path);
+      toast.success(t('debug.adbPathSaved', { count: devices.length }));
+    } catch (err) {
+      loggers.ui.error('验证 adb 路径失败:', err);
+      toast.error(t('debug.adbPathInvalid', { error: String(err) }));
+      setAdbPathInput(adbPath);
+    } finally {
+      setAdbPathChecking(false);
+    }
+  }, [adbPathInput, adbPath, setAdbPath, t]);
+
+  // 额外 adb 目录 / 模拟器安装目录：每行一个，失焦时保存并提示能找到的 adb 数量
+  const handleToolkitConfigBlur = useCallback(async () => {
+    const toLines = (text: string) =>
+      text
+        .split('\n')
+        .map((line) => line.trim())
+        .filter(Boolean);
+    const extraAdbDirs = toLines(extraAdbDirsInput);
+    const emulatorDirs = toLines(emulatorDirsInput);
+    if (
+      extraAdbDirs.join('\n') === toolkitConfig.extraAdbDirs.join('\n') &&
+      emulatorDirs.join('\n') === toolkitConfig.emulatorDirs.join('\n')
+    ) {
+      return;
+    }
+    try {
+      const found = await setToolkitConfig({ extraAdbDirs, emulatorDirs });
+      setExtraAdbDirsInput(extraAdbDirs.join('\n'));
+      setEmulatorDirsInput(emulatorDirs.join('\n'));
+      if (extraAdbDirs.length > 0 || emulatorDirs.length > 0) {
+        toast.success(t('debug.toolkitConfigSaved', { count: found.length }));
+      }
+    } catch (err) {
+      loggers.ui.error('设置 Toolkit 配置失败:', err);
+      toast.error(t('debug.toolkitConfigFailed', { error: String(err) }));
+    }
+  }, [extraAdbDirsInput, emulatorDirsInput, toolkitConfig, setToolkitConfig, t]);
+
+  // 非法输入恢复为当前值，0 表示不限制
+  const handleAgentMemoryBlur = useCallback(() => {
+    const parsed = parseInt(agentMemoryInput, 10);
+    if (!Number.isFinite(parsed) || parsed < 0) {
+      setAgentMemoryInput(String(agentResourceLimits.memoryLimitMb));
+      return;
+    }
+    if (parsed !== agentResourceLimits.memoryLimitMb) {
+      setAgentResourceLimits({ memoryLimitMb: parsed });
+    }
+  }, [agentMemoryInput, agentResourceLimits.memoryLimitMb, setAgentResourceLimits]);
+
+  const handleAgentCpuBlur = useCallback(() => {
+    const parsed = parseInt(agentCpuInput, 10);
+    if (!Number.isFinite(parsed) || parsed < 0 || parsed > 100) {
+      setAgentCpuInput(String(agentResourceLimits.cpuRatePercent));
+      return;
+    }
+    if (parsed !== agentResourceLimits.cpuRatePercent) {
+      setAgentResourceLimits({ cpuRatePercent: parsed });
+    }
+  }, [agentCpuInput, agentResourceLimits.cpuRatePercent, setAgentResourceLimits]);
+
+  // 非法输入恢复为当前值，0 表示按 CPU 核数
+  const handleCopyConcurrencyBlur = useCallback(() => {
+    const parsed = parseInt(copyConcurrencyInput, 10);
+    if (!Number.isFinite(parsed) || parsed < 0) {
+      setCopyConcurrencyInput(String(updateCopyConcurrency));
+      return;
+    }
+    if (parsed !== updateCopyConcurrency) {
+      setUpdateCopyConcurrency(parsed);
+    }
+  }, [copyConcurrencyInput, updateCopyConcurrency, setUpdateCopyConcurrency]);
+
+  const handleWebview2SharedToggle = useCallback(async (v: boolean) => {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core');
+      await invoke('set_webview2_shared', { shared: v });
+      setWebview2Shared(v);
+    } catch (err) {
+      loggers.ui.error('设置 WebView2 共享模式失败:', err);
+    }
+  }, []);
+
+  const handleWebviewGpuToggle = useCallback(async (v: boolean) => {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core');
+      await invoke('set_webview_gpu', { disabled: v });
+      setWebviewGpuDisabled(v);
+    } catch (err) {
+      loggers.ui.error('设置 WebView2 GPU 加速失败:', err);
+    }
+  }, []);
+
   const handleRestart = useCallback(async () => {
     try {
-      const { restartApp } = await import('@/services/updateService');
-      await restartApp();
+      await maaService.restartApp();
     } catch (err) {
       loggers.ui.error('重启失败:', err);
     }
@@ -258,6 +938,26 @@ export function DebugSection() {
             {t('debug.mxuVersion')}:{' '}
             <span className="font-mono text-text-primary">{mxuVersion || '-'}</span>
           </p>
+          {versionInfo && (
+            <>
+              <p>
+                {t('debug.agentVersion')}:{' '}
+                <span className="font-mono text-text-primary">{versionInfo.agent}</span>
+              </p>
+              <p>
+                {t('debug.buildCommit')}:{' '}
+                <span className="font-mono text-text-primary">{versionInfo.commit}</span>
+              </p>
+              <p>
+                {t('debug.buildTime')}:{' '}
+                <span className="font-mono text-text-primary">
+                  {versionInfo.build_time === 'unknown'
+                    ? versionInfo.build_time
+                    : new Date(versionInfo.build_time).toLocaleString()}
+                </span>
+              </p>
+            </>
+          )}
         </div>
 
         {/* 环境信息 */}
@@ -298,6 +998,120 @@ export function DebugSection() {
               {t('debug.tauriVersion')}:{' '}
               <span className="font-mono text-text-primary">{systemInfo.tauriVersion}</span>
             </p>
+            {systemInfo.exeVolume && (
+              <p>
+                {t('debug.exeVolume')}:{' '}
+                <span className="font-mono text-text-primary">
+                  {systemInfo.exeVolume.driveType}
+                  {!systemInfo.exeVolume.writable && ` (${t('debug.exeVolumeReadonly')})`}
+                </span>
+              </p>
+            )}
+            {systemInfo.cpu?.x86 && (
+              <p>
+                {t('debug.cpuFeatures')}:{' '}
+                <span className="font-mono text-text-primary">
+                  AVX {systemInfo.cpu.avx ? '✓' : '✗'} · AVX2 {systemInfo.cpu.avx2 ? '✓' : '✗'}
+                </span>
+                {systemInfo.cpu.missing.length > 0 && (
+                  <span className="text-error">
+                    {' '}
+                    {t('debug.cpuFeaturesMissing', {
+                      features: systemInfo.cpu.missing.join(' / '),
+                    })}
+                  </span>
+                )}
+              </p>
+            )}
+          </div>
+        )}
+
+        {/* 控制器详情（当前实例） */}
+        {controllerInfo && (
+          <div className="text-sm text-text-secondary space-y-1">
+            <div className="flex items-center gap-2">
+              <p className="font-medium text-text-primary">{t('debug.controllerInfo')}</p>
+              <button
+                onClick={loadControllerInfo}
+                className="p-1 rounded hover:bg-bg-hover transition-colors"
+                title={t('debug.controllerInfoRefresh')}
+              >
+                <RefreshCw className="w-3.5 h-3.5" />
+              </button>
+            </div>
+            <p>
+              {t('debug.controllerStatus')}:{' '}
+              <span
+                className={
+                  controllerInfo.status === 'connected' ? 'text-success' : 'text-text-primary'
+                }
+              >
+                {t(`debug.controllerStatusValue.${controllerInfo.status}`)}
+              </span>
+            </p>
+            {controllerInfo.controller_type && (
+              <p>
+                {t('debug.controllerType')}:{' '}
+                <span className="font-mono text-text-primary">
+                  {controllerInfo.controller_type}
+                  {controllerInfo.gamepad_type && ` (${controllerInfo.gamepad_type})`}
+                </span>
+              </p>
+            )}
+            {controllerInfo.target && (
+              <p className="break-all">
+                {t('debug.controllerTarget')}:{' '}
+                <span className="font-mono text-text-primary">{controllerInfo.target}</span>
+              </p>
+            )}
+            {controllerInfo.adb_path && (
+              <p className="break-all">
+                {t('debug.adbPath')}:{' '}
+                <span className="font-mono text-text-primary text-xs">
+                  {controllerInfo.adb_path}
+                </span>
+              </p>
+            )}
+            {controllerInfo.screencap_methods.length > 0 && (
+              <p>
+                {t('debug.controllerScreencap')}:{' '}
+                <span className="font-mono text-text-primary">
+                  {controllerInfo.screencap_methods.join(' | ')}
+                </span>
+              </p>
+            )}
+            {controllerInfo.input_methods.length > 0 && (
+              <p>
+                {t('debug.controllerInput')}:{' '}
+                <span className="font-mono text-text-primary">
+                  {controllerInfo.input_methods.join(' | ')}
+                  {controllerInfo.keyboard_methods.length > 0 &&
+                    ` / ${controllerInfo.keyboard_methods.join(' | ')}`}
+                </span>
+              </p>
+            )}
+            {controllerInfo.resolution && (
+              <p>
+                {t('debug.controllerResolution')}:{' '}
+                <span className="font-mono text-text-primary">
+                  {controllerInfo.resolution[0]} × {controllerInfo.resolution[1]}
+                </span>
+              </p>
+            )}
+            {controllerInfo.display_short_side != null && (
+              <p>
+                {t('debug.controllerShortSide')}:{' '}
+                <span className="font-mono text-text-primary">
+                  {controllerInfo.display_short_side}
+                </span>
+              </p>
+            )}
+            {controllerInfo.uuid && (
+              <p className="break-all">
+                UUID:{' '}
+                <span className="font-mono text-text-primary text-xs">{controllerInfo.uuid}</span>
+              </p>
+            )}
           </div>
         )}
 
@@ -346,7 +1160,7 @@ export function DebugSection() {
             {t('debug.openLogDir')}
           </button>
           <button
-            onClick={handleExportLogs}
+            onClick={() => handleExportLogs()}
             disabled={exportModal.show && exportModal.status === 'exporting'}
             className="flex items-center gap-2 px-3 py-2 text-sm bg-bg-tertiary hover:bg-bg-hover rounded-lg transition-colors disabled:opacity-50"
             title={t('debug.exportLogsHint')}
@@ -354,8 +1168,174 @@ export function DebugSection() {
             <Archive className="w-4 h-4" />
             {t('debug.exportLogs')}
           </button>
+          {isTauri() && activeInstanceId && (
+            <button
+              onClick={handleBenchmark}
+              disabled={benchmarkRunning}
+              className="flex items-center gap-2 px-3 py-2 text-sm bg-bg-tertiary hover:bg-bg-hover rounded-lg transition-colors disabled:opacity-50"
+              title={t('debug.benchmarkHint')}
+            >
+              {benchmarkRunning ? (
+                <Loader2 className="w-4 h-4 animate-spin" />
+              ) : (
+                <Gauge className="w-4 h-4" />
+              )}
+              {t('debug.benchmark')}
+            </button>
+          )}
+          {isTauri() && activeInstanceId && (
+            <button
+              onClick={handleAnnotatedScreenshot}
+              className="flex items-center gap-2 px-3 py-2 text-sm bg-bg-tertiary hover:bg-bg-hover rounded-lg transition-colors"
+              title={t('debug.annotatedScreenshotHint')}
+            >
+              <Crosshair className="w-4 h-4" />
+              {t('debug.annotatedScreenshot')}
+            </button>
+          )}
+          {isTauri() && activeInstanceId && (
+            <button
+              onClick={handleExportIssueBundle}
+              className="flex items-center gap-2 px-3 py-2 text-sm bg-bg-tertiary hover:bg-bg-hover rounded-lg transition-colors"
+              title={t('debug.issueBundleHint')}
+            >
+              <PackageSearch className="w-4 h-4" />
+              {t('debug.issueBundle')}
+            </button>
+          )}
+          {isTauri() && (
+            <button
+              onClick={handleResetResourceManifests}
+              className="flex items-center gap-2 px-3 py-2 text-sm bg-bg-tertiary hover:bg-bg-hover rounded-lg transition-colors"
+              title={t('debug.resourceManifestResetHint')}
+            >
+              <FileCheck className="w-4 h-4" />
+              {t('debug.resourceManifestResetButton')}
+            </button>
+          )}
+          {isTauri() && activeInstanceId && !clipRecording && (
+            <button
+              onClick={handleStartClip}
+              className="flex items-center gap-2 px-3 py-2 text-sm bg-bg-tertiary hover:bg-bg-hover rounded-lg transition-colors"
+              title={t('debug.recordClipHint')}
+            >
+              <Video className="w-4 h-4" />
+              {t('debug.recordClip')}
+            </button>
+          )}
+          {isTauri() &&
+            activeInstanceId &&
+            clipRecording &&
+            (['gif', 'mp4'] as const).map((format) => (
+              <button
+                key={format}
+                onClick={() => handleExportClip(format)}
+                disabled={clipExporting !== null}
+                className="flex items-center gap-2 px-3 py-2 text-sm bg-bg-tertiary hover:bg-bg-hover rounded-lg transition-colors disabled:opacity-50"
+              >
+                {clipExporting === format ? (
+                  <Loader2 className="w-4 h-4 animate-spin" />
+                ) : (
+                  <Square className="w-4 h-4 text-error" />
+                )}
+                {t(format === 'gif' ? 'debug.clipExportGif' : 'debug.clipExportMp4')}
+              </button>
+            ))}
         </div>
 
+        {/* 性能测试结果 */}
+        {(benchmarkResult || benchmarkError) && (
+          <div className="p-3 bg-bg-tertiary rounded-lg text-sm text-text-secondary space-y-1">
+            {benchmarkError ? (
+              <p className="text-error break-all">{benchmarkError}</p>
+            ) : (
+              benchmarkResult && (
+                <>
+                  <p>
+                    {t('debug.benchmarkScreencap')}:{' '}
+                    <span className="font-mono text-text-primary">
+                      {benchmarkResult.screencap.avg_ms.toFixed(1)} ms ·{' '}
+                      {benchmarkResult.fps.toFixed(1)} FPS
+                      {benchmarkResult.resolution &&
+                        ` · ${benchmarkResult.resolution[0]}x${benchmarkResult.resolution[1]}`}
+                    </span>
+                  </p>
+                  {benchmarkResult.recognitions.map((reco) => (
+                    <p key={reco.name}>
+                      {reco.name}:{' '}
+                      <span className="font-mono text-text-primary">
+                        {reco.task.rounds > 0
+                          ? `~${reco.recognition_avg_ms.toFixed(1)} ms`
+                          : t('debug.benchmarkFailed')}
+                      </span>
+                    </p>
+                  ))}
+                  {benchmarkResult.preprocess && (
+                    <p>
+                      {t('debug.preprocess')}:{' '}
+                      <span className="font-mono text-text-primary">
+                        {formatPreprocess(benchmarkResult.preprocess)}
+                      </span>
+                    </p>
+                  )}
+                  {benchmarkResult.report_path && (
+                    <button
+                      onClick={() =>
+                        maaService.openFolderAndSelect(benchmarkResult.report_path!).catch((err) => {
+                          loggers.ui.error('打开性能测试结果失败:', err);
+                          toast.error(t('debug.openFileFailed', { error: String(err) }));
+                        })
+                      }
+                      className="text-xs text-accent hover:underline"
+                    >
+                      {t('debug.benchmarkOpenResult')}
+                    </button>
+                  )}
+                </>
+              )
+            )}
+          </div>
+        )}
+
+        {/* 运行录像导出结果 */}
+        {(clipResult || clipError) && (
+          <div className="p-3 bg-bg-tertiary rounded-lg text-sm text-text-secondary space-y-1">
+            {clipError ? (
+              <p className="text-error break-all">{clipError}</p>
+            ) : (
+              clipResult && (
+                <>
+                  <p className="font-mono text-text-primary">
+                    {t('debug.clipSummary', {
+                      frames: clipResult.frames,
+                      seconds: (clipResult.duration_ms / 1000).toFixed(1),
+                    })}
+                  </p>
+                  {clipResult.dropped_frames > 0 && (
+                    <p>{t('debug.clipDropped', { count: clipResult.dropped_frames })}</p>
+                  )}
+                  {clipResult.format !== clipResult.requested && (
+                    <p className="text-warning">{t('debug.clipMp4Fallback')}</p>
+                  )}
+                  <button
+                    onClick={() =>
+                      maaService
+                        .openFolderAndSelect(clipResult.path)
+                        .catch((err) => {
+                          loggers.ui.error('打开录像文件失败:', err);
+                          toast.error(t('debug.openFileFailed', { error: String(err) }));
+                        })
+                    }
+                    className="text-xs text-accent hover:underline"
+                  >
+                    {t('debug.clipOpenResult')}
+                  </button>
+                </>
+              )
+            )}
+          </div>
+        )}
+
         {/* 开发模式 */}
         <div className="flex items-center justify-between pt-4 border-t border-border">
           <div className="flex items-center gap-3">
@@ -380,6 +1360,444 @@ export function DebugSection() {
           <SwitchButton value={saveDraw} onChange={(v) => setSaveDraw(v)} />
         </div>
 
+        {/* 详细日志 */}
+        <div className="flex items-center justify-between pt-4 border-t border-border">
+          <div className="flex items-center gap-3">
+            <ScrollText className="w-5 h-5 text-accent" />
+            <div>
+              <span className="font-medium text-text-primary">{t('debug.verboseLog')}</span>
+              <p className="text-xs text-text-muted mt-0.5">{t('debug.verboseLogHint')}</p>
+            </div>
+          </div>
+          <SwitchButton
+            value={logLevel === 'debug'}
+            onChange={(v) => setLogLevel(v ? 'debug' : 'info')}
+          />
+        </div>
+
+        {/* 结构化事件日志 */}
+        {isTauri() && (
+          <div className="flex items-center justify-between pt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <FileJson className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('debug.eventLog')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.eventLogHint')}</p>
+              </div>
+            </div>
+            <SwitchButton value={eventLogEnabled} onChange={(v) => setEventLogEnabled(v)} />
+          </div>
+        )}
+
+        {/* 任务失败自动截图 */}
+        {isTauri() && (
+          <div className="flex items-center justify-between pt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <Camera className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">
+                  {t('debug.failureScreenshot')}
+                </span>
+                <p className="text-xs text-text-muted mt-0.5">
+                  {t('debug.failureScreenshotHint')}
+                </p>
+              </div>
+            </div>
+            <SwitchButton
+              value={failureScreenshotEnabled}
+              onChange={(v) => setFailureScreenshotEnabled(v)}
+            />
+          </div>
+        )}
+
+        {/* 点击/滑动轨迹事件 */}
+        {isTauri() && (
+          <div className="flex items-center justify-between pt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <MousePointerClick className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('debug.actionTrace')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.actionTraceHint')}</p>
+              </div>
+            </div>
+            <SwitchButton value={actionTraceEnabled} onChange={(v) => setActionTraceEnabled(v)} />
+          </div>
+        )}
+
+        {/* Pipeline 断点（当前实例） */}
+        {isTauri() && activeInstanceId && (
+          <div className="flex items-center justify-between gap-4 pt-4 border-t border-border">
+            <div className="flex items-center gap-3 shrink-0">
+              <CirclePause className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('debug.breakpoints')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.breakpointsHint')}</p>
+              </div>
+            </div>
+            <input
+              type="text"
+              value={breakpointInput}
+              placeholder={t('debug.breakpointsPlaceholder')}
+              onChange={(e) => setBreakpointInput(e.target.value)}
+              onBlur={handleBreakpointBlur}
+              onKeyDown={(e) => {
+                if (e.key === 'Enter') e.currentTarget.blur();
+              }}
+              className="w-56 px-2.5 py-1.5 text-sm font-mono bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+            />
+          </div>
+        )}
+
+        {/* Dry-run 模式（当前实例） */}
+        {isTauri() && activeInstanceId && (
+          <div className="flex items-center justify-between pt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <ScanEye className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('debug.dryRun')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.dryRunHint')}</p>
+              </div>
+            </div>
+            <SwitchButton value={dryRun} onChange={handleDryRunToggle} />
+          </div>
+        )}
+
+        {/* 操作速度系数（当前实例） */}
+        {isTauri() && activeInstanceId && (
+          <div className="flex items-center justify-between pt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <Timer className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('debug.speed')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.speedHint')}</p>
+              </div>
+            </div>
+            <input
+              type="number"
+              min={0.25}
+              max={4}
+              step={0.25}
+              value={speedInput}
+              onChange={(e) => setSpeedInput(e.target.value)}
+              onBlur={handleSpeedBlur}
+              onKeyDown={(e) => {
+                if (e.key === 'Enter') e.currentTarget.blur();
+              }}
+              className="w-24 px-2.5 py-1.5 text-sm font-mono text-right bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+            />
+          </div>
+        )}
+
+        {/* Win32 截图防遮挡模式（当前实例） */}
+        {isTauri() && activeInstanceId && (
+          <div className="flex items-center justify-between pt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <AppWindow className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('debug.captureMode')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.captureModeHint')}</p>
+              </div>
+            </div>
+            <select
+              value={captureMode}
+              onChange={(e) => handleCaptureModeChange(e.target.value as CaptureMode)}
+              className="px-2 py-1 text-sm bg-bg-tertiary border border-border rounded-md text-text-primary focus:outline-none focus:ring-2 focus:ring-accent/50"
+            >
+              <option value="default">{t('debug.captureModeDefault')}</option>
+              <option value="foreground">{t('debug.captureModeForeground')}</option>
+              <option value="print_window">{t('debug.captureModePrintWindow')}</option>
+            </select>
+          </div>
+        )}
+
+        {/* Win32 目标窗口最小化处理方式（当前实例） */}
+        {isTauri() && activeInstanceId && (
+          <div className="flex items-center justify-between pt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <Minimize2 className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('debug.minimizedPolicy')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.minimizedPolicyHint')}</p>
+              </div>
+            </div>
+            <select
+              value={minimizedPolicy}
+              onChange={(e) => handleMinimizedPolicyChange(e.target.value as MinimizedPolicy)}
+              className="px-2 py-1 text-sm bg-bg-tertiary border border-border rounded-md text-text-primary focus:outline-none focus:ring-2 focus:ring-accent/50"
+            >
+              <option value="notify">{t('debug.minimizedPolicyNotify')}</option>
+              <option value="pause">{t('debug.minimizedPolicyPause')}</option>
+              <option value="restore">{t('debug.minimizedPolicyRestore')}</option>
+            </select>
+          </div>
+        )}
+
+        {/* 截图帧去重（当前实例） */}
+        {isTauri() && activeInstanceId && (
+          <div className="pt-4 border-t border-border space-y-3">
+            <div className="flex items-center justify-between">
+              <div className="flex items-center gap-3">
+                <Images className="w-5 h-5 text-accent" />
+                <div>
+                  <span className="font-medium text-text-primary">{t('debug.frameDedup')}</span>
+                  <p className="text-xs text-text-muted mt-0.5">{t('debug.frameDedupHint')}</p>
+                </div>
+              </div>
+              <SwitchButton value={frameDedup !== null} onChange={handleFrameDedupToggle} />
+            </div>
+            {frameDedup && (
+              <div className="flex items-center justify-between pl-8">
+                <span className="text-sm text-text-secondary">
+                  {t('debug.frameDedupSimilarity')}
+                </span>
+                <input
+                  type="number"
+                  min={0.5}
+                  max={1}
+                  step={0.01}
+                  value={frameDedupSimilarityInput}
+                  onChange={(e) => setFrameDedupSimilarityInput(e.target.value)}
+                  onBlur={handleFrameDedupSimilarityBlur}
+                  onKeyDown={(e) => {
+                    if (e.key === 'Enter') e.currentTarget.blur();
+                  }}
+                  className="w-24 px-2.5 py-1.5 text-sm font-mono text-right bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+                />
+              </div>
+            )}
+          </div>
+        )}
+
+        {/* 未知界面收集（当前实例） */}
+        {isTauri() && activeInstanceId && (
+          <div className="pt-4 border-t border-border space-y-3">
+            <div className="flex items-center justify-between">
+              <div className="flex items-center gap-3">
+                <ScanSearch className="w-5 h-5 text-accent" />
+                <div>
+                  <span className="font-medium text-text-primary">{t('debug.unknownScreens')}</span>
+                  <p className="text-xs text-text-muted mt-0.5">{t('debug.unknownScreensHint')}</p>
+                </div>
+              </div>
+              <SwitchButton value={unknownScreens !== null} onChange={handleUnknownScreensToggle} />
+            </div>
+            {unknownScreens && (
+              <div className="flex items-center justify-between pl-8">
+                <span className="text-sm text-text-secondary">
+                  {t('debug.unknownScreensThreshold')}
+                </span>
+                <input
+                  type="number"
+                  min={1}
+                  max={100}
+                  step={1}
+                  value={unknownThresholdInput}
+                  onChange={(e) => setUnknownThresholdInput(e.target.value)}
+                  onBlur={handleUnknownThresholdBlur}
+                  onKeyDown={(e) => {
+                    if (e.key === 'Enter') e.currentTarget.blur();
+                  }}
+                  className="w-24 px-2.5 py-1.5 text-sm font-mono text-right bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+                />
+              </div>
+            )}
+            <div className="flex items-center justify-between pl-8">
+              <span className="text-sm text-text-secondary">
+                {t('debug.unknownScreensCount', { count: unknownScreensInfo?.count ?? 0 })}
+              </span>
+              <div className="flex items-center gap-2">
+                <button
+                  onClick={() => unknownScreensInfo && openDirectory(unknownScreensInfo.dir)}
+                  disabled={!unknownScreensInfo?.count}
+                  className="flex items-center gap-2 px-3 py-1.5 text-sm bg-bg-tertiary hover:bg-bg-hover rounded-lg transition-colors disabled:opacity-50"
+                >
+                  <FolderOpen className="w-4 h-4" />
+                  {t('debug.unknownScreensOpen')}
+                </button>
+                <button
+                  onClick={handleExportUnknownScreens}
+                  disabled={!unknownScreensInfo?.count || unknownScreensExporting}
+                  className="flex items-center gap-2 px-3 py-1.5 text-sm bg-bg-tertiary hover:bg-bg-hover rounded-lg transition-colors disabled:opacity-50"
+                >
+                  {unknownScreensExporting ? (
+                    <Loader2 className="w-4 h-4 animate-spin" />
+                  ) : (
+                    <Archive className="w-4 h-4" />
+                  )}
+                  {t('debug.unknownScreensExport')}
+                </button>
+              </div>
+            </div>
+          </div>
+        )}
+
+        {/* 截图预处理（当前实例） */}
+        {isTauri() && activeInstanceId && (
+          <div className="pt-4 border-t border-border space-y-3">
+            <div className="flex items-center justify-between">
+              <div className="flex items-center gap-3">
+                <SunMedium className="w-5 h-5 text-accent" />
+                <div>
+                  <span className="font-medium text-text-primary">{t('debug.preprocess')}</span>
+                  <p className="text-xs text-text-muted mt-0.5">{t('debug.preprocessHint')}</p>
+                </div>
+              </div>
+              <SwitchButton value={preprocess !== null} onChange={handlePreprocessToggle} />
+            </div>
+            {preprocess && (
+              <div className="space-y-3 pl-8">
+                {PREPROCESS_FIELDS.map((field) => (
+                  <div key={field} className="flex items-center justify-between">
+                    <span className="text-sm text-text-secondary">
+                      {t(PREPROCESS_LABEL_KEYS[field])}
+                    </span>
+                    <input
+                      type="number"
+                      min={PREPROCESS_RANGES[field][0]}
+                      max={PREPROCESS_RANGES[field][1]}
+                      step={field === 'brightness' ? 1 : 0.05}
+                      value={preprocessInputs[field]}
+                      onChange={(e) =>
+                        setPreprocessInputs((prev) => ({ ...prev, [field]: e.target.value }))
+                      }
+                      onBlur={() => handlePreprocessBlur(field)}
+                      onKeyDown={(e) => {
+                        if (e.key === 'Enter') e.currentTarget.blur();
+                      }}
+                      className="w-24 px-2.5 py-1.5 text-sm font-mono text-right bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+                    />
+                  </div>
+                ))}
+              </div>
+            )}
+          </div>
+        )}
+
+        {/* 任务失败重试（当前实例） */}
+        {isTauri() && activeInstanceId && (
+          <div className="pt-4 border-t border-border space-y-3">
+            <div className="flex items-center justify-between">
+              <div className="flex items-center gap-3">
+                <RotateCcw className="w-5 h-5 text-accent" />
+                <div>
+                  <span className="font-medium text-text-primary">{t('debug.taskRetry')}</span>
+                  <p className="text-xs text-text-muted mt-0.5">{t('debug.taskRetryHint')}</p>
+                </div>
+              </div>
+              <SwitchButton value={taskRetry !== null} onChange={handleTaskRetryToggle} />
+            </div>
+            {taskRetry && (
+              <div className="space-y-3 pl-8">
+                <div className="flex items-center justify-between">
+                  <span className="text-sm text-text-secondary">
+                    {t('debug.taskRetryMaxRetries')}
+                  </span>
+                  <input
+                    type="number"
+                    min={1}
+                    max={10}
+                    value={retryCountInput}
+                    onChange={(e) => setRetryCountInput(e.target.value)}
+                    onBlur={handleRetryInputsBlur}
+                    onKeyDown={(e) => {
+                      if (e.key === 'Enter') e.currentTarget.blur();
+                    }}
+                    className="w-24 px-2.5 py-1.5 text-sm font-mono text-right bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+                  />
+                </div>
+                <div className="flex items-center justify-between">
+                  <span className="text-sm text-text-secondary">
+                    {t('debug.taskRetryInterval')}
+                  </span>
+                  <input
+                    type="number"
+                    min={0}
+                    max={600}
+                    value={retryIntervalInput}
+                    onChange={(e) => setRetryIntervalInput(e.target.value)}
+                    onBlur={handleRetryInputsBlur}
+                    onKeyDown={(e) => {
+                      if (e.key === 'Enter') e.currentTarget.blur();
+                    }}
+                    className="w-24 px-2.5 py-1.5 text-sm font-mono text-right bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+                  />
+                </div>
+                <div className="flex items-center justify-between">
+                  <span className="text-sm text-text-secondary">
+                    {t('debug.taskRetryReconnect')}
+                  </span>
+                  <SwitchButton
+                    value={taskRetry.reconnect}
+                    onChange={(v) => applyTaskRetry({ ...taskRetry, reconnect: v })}
+                  />
+                </div>
+                <div className="flex items-center justify-between gap-4">
+                  <span className="text-sm text-text-secondary shrink-0">
+                    {t('debug.taskRetryRestartApp')}
+                  </span>
+                  <input
+                    type="text"
+                    value={retryRestartAppInput}
+                    placeholder={t('debug.taskRetryRestartAppPlaceholder')}
+                    onChange={(e) => setRetryRestartAppInput(e.target.value)}
+                    onBlur={handleRetryInputsBlur}
+                    onKeyDown={(e) => {
+                      if (e.key === 'Enter') e.currentTarget.blur();
+                    }}
+                    className="flex-1 min-w-0 px-2.5 py-1.5 text-sm font-mono bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+                  />
+                </div>
+              </div>
+            )}
+          </div>
+        )}
+
+        {/* 运行时长看门狗（当前实例） */}
+        {isTauri() && activeInstanceId && (
+          <div className="pt-4 border-t border-border space-y-3">
+            <div className="flex items-center justify-between">
+              <div className="flex items-center gap-3">
+                <Hourglass className="w-5 h-5 text-accent" />
+                <div>
+                  <span className="font-medium text-text-primary">{t('debug.watchdog')}</span>
+                  <p className="text-xs text-text-muted mt-0.5">{t('debug.watchdogHint')}</p>
+                </div>
+              </div>
+              <SwitchButton value={watchdog !== null} onChange={handleWatchdogToggle} />
+            </div>
+            {watchdog && (
+              <div className="space-y-3 pl-8">
+                <div className="flex items-center justify-between">
+                  <span className="text-sm text-text-secondary">
+                    {t('debug.watchdogMaxMinutes')}
+                  </span>
+                  <input
+                    type="number"
+                    min={1}
+                    max={1440}
+                    value={watchdogMinutesInput}
+                    onChange={(e) => setWatchdogMinutesInput(e.target.value)}
+                    onBlur={handleWatchdogMinutesBlur}
+                    onKeyDown={(e) => {
+                      if (e.key === 'Enter') e.currentTarget.blur();
+                    }}
+                    className="w-24 px-2.5 py-1.5 text-sm font-mono text-right bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+                  />
+                </div>
+                <div className="flex items-center justify-between">
+                  <span className="text-sm text-text-secondary">
+                    {t('debug.watchdogCountPaused')}
+                  </span>
+                  <SwitchButton
+                    value={watchdog.count_paused}
+                    onChange={(v) => applyWatchdog({ ...watchdog, count_paused: v })}
+                  />
+                </div>
+              </div>
+            )}
+          </div>
+        )}
+
         {/* 通信兼容模式 */}
         <div className="flex items-center justify-between pt-4 border-t border-border">
           <div className="flex items-center gap-3">
@@ -392,6 +1810,174 @@ export function DebugSection() {
           <SwitchButton value={tcpCompatMode} onChange={(v) => setTcpCompatMode(v)} />
         </div>
 
+        {/* 共享 WebView2 运行时（仅 Windows） */}
+        {isTauri() && backendOS === 'windows' && (
+          <div className="flex items-center justify-between pt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <Layers className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('debug.webview2Shared')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.webview2SharedHint')}</p>
+              </div>
+            </div>
+            <SwitchButton value={webview2Shared} onChange={handleWebview2SharedToggle} />
+          </div>
+        )}
+
+        {/* 禁用 WebView2 GPU 加速（仅 Windows） */}
+        {isTauri() && backendOS === 'windows' && (
+          <div className="flex items-center justify-between pt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <MonitorOff className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">
+                  {t('debug.webviewGpuDisabled')}
+                </span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.webviewGpuDisabledHint')}</p>
+              </div>
+            </div>
+            <SwitchButton value={webviewGpuDisabled} onChange={handleWebviewGpuToggle} />
+          </div>
+        )}
+
+        {/* 指定 adb 路径 */}
+        {isTauri() && (
+          <div className="pt-4 border-t border-border space-y-3">
+            <div className="flex items-center gap-3">
+              <Smartphone className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('debug.adbPath')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.adbPathHint')}</p>
+              </div>
+              {adbPathChecking && <Loader2 className="w-4 h-4 animate-spin text-text-muted" />}
+            </div>
+            <input
+              type="text"
+              value={adbPathInput}
+              onChange={(e) => setAdbPathInput(e.target.value)}
+              onBlur={handleAdbPathBlur}
+              onKeyDown={(e) => {
+                if (e.key === 'Enter') e.currentTarget.blur();
+              }}
+              disabled={adbPathChecking}
+              placeholder={t('debug.adbPathPlaceholder')}
+              className="w-full px-3 py-2 rounded-lg bg-bg-tertiary border border-border text-sm font-mono text-text-primary placeholder:text-text-muted focus:outline-none focus:ring-1 focus:ring-accent disabled:opacity-50"
+            />
+          </div>
+        )}
+
+        {/* 设备搜索的额外 adb 目录 / 模拟器安装目录 */}
+        {isTauri() && (
+          <div className="pt-4 border-t border-border space-y-3">
+            <div className="flex items-center gap-3">
+              <FolderSearch className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('debug.toolkitConfig')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('debug.toolkitConfigHint')}</p>
+              </div>
+            </div>
+            <div className="space-y-1 pl-8">
+              <span className="text-sm text-text-secondary">{t('debug.extraAdbDirs')}</span>
+              <textarea
+                rows={2}
+                value={extraAdbDirsInput}
+                onChange={(e) => setExtraAdbDirsInput(e.target.value)}
+                onBlur={handleToolkitConfigBlur}
+                placeholder={t('debug.extraAdbDirsPlaceholder')}
+                className="w-full px-3 py-2 rounded-lg bg-bg-tertiary border border-border text-sm font-mono text-text-primary placeholder:text-text-muted focus:outline-none focus:ring-1 focus:ring-accent resize-y"
+              />
+            </div>
+            <div className="space-y-1 pl-8">
+              <span className="text-sm text-text-secondary">{t('debug.emulatorDirs')}</span>
+              <textarea
+                rows={2}
+                value={emulatorDirsInput}
+                onChange={(e) => setEmulatorDirsInput(e.target.value)}
+                onBlur={handleToolkitConfigBlur}
+                placeholder={t('debug.emulatorDirsPlaceholder')}
+                className="w-full px-3 py-2 rounded-lg bg-bg-tertiary border border-border text-sm font-mono text-text-primary placeholder:text-text-muted focus:outline-none focus:ring-1 focus:ring-accent resize-y"
+              />
+            </div>
+          </div>
+        )}
+
+        {/* 进程资源监视 */}
+        {isTauri() && <ProcessMonitor />}
+
+        {/* Agent 资源限制（仅 Windows） */}
+        {isTauri() && backendOS === 'windows' && (
+          <div className="pt-4 border-t border-border space-y-3">
+            <div className="flex items-center gap-3">
+              <Cpu className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">
+                  {t('debug.agentResourceLimits')}
+                </span>
+                <p className="text-xs text-text-muted mt-0.5">
+                  {t('debug.agentResourceLimitsHint')}
+                </p>
+              </div>
+            </div>
+            <div className="flex items-center justify-between pl-8">
+              <span className="text-sm text-text-secondary">{t('debug.agentMemoryLimit')}</span>
+              <input
+                type="number"
+                min={0}
+                value={agentMemoryInput}
+                onChange={(e) => setAgentMemoryInput(e.target.value)}
+                onBlur={handleAgentMemoryBlur}
+                onKeyDown={(e) => {
+                  if (e.key === 'Enter') e.currentTarget.blur();
+                }}
+                className="w-24 px-2.5 py-1.5 text-sm font-mono text-right bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+              />
+            </div>
+            <div className="flex items-center justify-between pl-8">
+              <span className="text-sm text-text-secondary">{t('debug.agentCpuLimit')}</span>
+              <input
+                type="number"
+                min={0}
+                max={100}
+                value={agentCpuInput}
+                onChange={(e) => setAgentCpuInput(e.target.value)}
+                onBlur={handleAgentCpuBlur}
+                onKeyDown={(e) => {
+                  if (e.key === 'Enter') e.currentTarget.blur();
+                }}
+                className="w-24 px-2.5 py-1.5 text-sm font-mono text-right bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+              />
+            </div>
+          </div>
+        )}
+
+        {/* 增量更新并行复制线程数 */}
+        {isTauri() && (
+          <div className="flex items-center justify-between pt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <Copy className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">
+                  {t('debug.updateCopyConcurrency')}
+                </span>
+                <p className="text-xs text-text-muted mt-0.5">
+                  {t('debug.updateCopyConcurrencyHint')}
+                </p>
+              </div>
+            </div>
+            <input
+              type="number"
+              min={0}
+              value={copyConcurrencyInput}
+              onChange={(e) => setCopyConcurrencyInput(e.target.value)}
+              onBlur={handleCopyConcurrencyBlur}
+              onKeyDown={(e) => {
+                if (e.key === 'Enter') e.currentTarget.blur();
+              }}
+              className="w-24 px-2.5 py-1.5 text-sm font-mono text-right bg-bg-tertiary border border-border rounded-lg text-text-primary focus:outline-none focus:ring-1 focus:ring-accent"
+            />
+          </div>
+        )}
+
         {/* 启用 Web 服务器 */}
         <div className="flex items-center justify-between pt-4 border-t border-border">
           <div className="flex items-center gap-3">
@@ -1,6 +1,8 @@
 import { useState, useCallback } from 'react';
 import { useTranslation } from 'react-i18next';
 import { Loader2, CheckCircle, XCircle, Archive, FolderOpen, Copy, Check } from 'lucide-react';
+import { toast } from 'sonner';
+import { maaService } from '@/services/maaService';
 
 type ExportStatus = 'exporting' | 'success' | 'error';
 
@@ -27,13 +29,13 @@ export function ExportLogsModal({
   const handleCopy = useCallback(async () => {
     if (!zipPath) return;
     try {
-      await navigator.clipboard.writeText(zipPath);
+      await maaService.copyText(zipPath);
       setCopied(true);
       setTimeout(() => setCopied(false), 2000);
     } catch {
-      // 复制失败，静默处理
+      toast.error(t('logs.copyFailed'));
     }
-  }, [zipPath]);
+  }, [zipPath, t]);
 
   if (!show) return null;
 
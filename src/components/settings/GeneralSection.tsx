@@ -6,18 +6,27 @@ import {
   BrushCleaning,
   Check,
   ChevronDown,
+  FileImage,
   ListChecks,
   Maximize2,
+  PictureInPicture2,
   Play,
   Power,
   Rocket,
   Settings2,
+  ShieldCheck,
+  Webhook,
+  X,
 } from 'lucide-react';
 
 import { useAppStore } from '@/stores/appStore';
 import { defaultAddTaskPanelHeight, defaultWindowSize } from '@/types/config';
+import type { TaskReportScreenshots } from '@/types/config';
+import type { ProgramTrustConfig, ProgramTrustMode } from '@/types/maa';
+import { maaService } from '@/services/maaService';
+import { loggers } from '@/utils/logger';
 import { isTauri } from '@/utils/paths';
-import { SwitchButton } from '@/components/FormControls';
+import { NumberInput, SwitchButton } from '@/components/FormControls';
 import { DesktopOnlyWrapper } from '@/components/ui/DesktopOnlyWrapper';
 import { FrameRateSelector } from '../FrameRateSelector';
 
@@ -30,6 +39,12 @@ export function GeneralSection() {
     setConfirmBeforeDelete,
     minimizeToTray,
     setMinimizeToTray,
+    autoShowOverlay,
+    setAutoShowOverlay,
+    taskReport,
+    setTaskReport,
+    completionWebhook,
+    setCompletionWebhook,
     setRightPanelWidth,
     setRightPanelCollapsed,
     setAddTaskPanelHeight,
@@ -41,6 +56,8 @@ export function GeneralSection() {
     autoStartRemovedInstanceName,
     autoClearLogsOnLaunch,
     setAutoClearLogsOnLaunch,
+    autoStartDelaySec,
+    setAutoStartDelaySec,
     projectInterface,
   } = useAppStore();
 
@@ -49,6 +66,42 @@ export function GeneralSection() {
   const [autoStartLoading, setAutoStartLoading] = useState(false);
   const isWindowsRef = useRef(false);
 
+  // webhook URL 输入框（失焦时保存）
+  const [webhookUrlInput, setWebhookUrlInput] = useState(completionWebhook.url);
+  useEffect(() => {
+    setWebhookUrlInput(completionWebhook.url);
+  }, [completionWebhook.url]);
+
+  // 外部程序信任配置（持久化在后端，不走 store）
+  const [programTrust, setProgramTrust] = useState<ProgramTrustConfig | null>(null);
+  useEffect(() => {
+    if (!isTauri()) return;
+    maaService
+      .getProgramTrust()
+      .then(setProgramTrust)
+      .catch((err) => loggers.ui.warn('获取外部程序信任配置失败:', err));
+  }, []);
+
+  const handleProgramTrustModeChange = async (mode: ProgramTrustMode) => {
+    try {
+      await maaService.setProgramTrustMode(mode);
+      setProgramTrust((prev) => (prev ? { ...prev, mode } : prev));
+    } catch (err) {
+      loggers.ui.error('设置外部程序信任策略失败:', err);
+    }
+  };
+
+  const handleUntrustProgram = async (program: string) => {
+    try {
+      await maaService.untrustProgram(program);
+      setProgramTrust((prev) =>
+        prev ? { ...prev, programs: prev.programs.filter((p) => p !== program) } : prev,
+      );
+    } catch (err) {
+      loggers.ui.error('移出信任程序失败:', err);
+    }
+  };
+
   // 自定义下拉框状态
   const [instanceDropdownOpen, setInstanceDropdownOpen] = useState(false);
   const dropdownRef = useRef<HTMLDivElement>(null);
@@ -170,6 +223,26 @@ export function GeneralSection() {
             </div>
           )}
 
+          {/* 开机自启动延迟 */}
+          {isTauri() && autoStartEnabled && (
+            <div className="flex items-center justify-between gap-4 pl-8">
+              <div>
+                <span className="text-sm text-text-primary">{t('settings.autoStartDelay')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('settings.autoStartDelayHint')}</p>
+              </div>
+              <div className="flex items-center gap-2 flex-shrink-0">
+                <NumberInput
+                  value={autoStartDelaySec}
+                  onChange={setAutoStartDelaySec}
+                  min={0}
+                  max={600}
+                  className="w-20"
+                />
+                <span className="text-xs text-text-muted">{t('settings.autoStartDelayUnit')}</span>
+              </div>
+            </div>
+          )}
+
           {/* ② 启动后自动执行 */}
           <div className={isTauri() ? 'pt-4 border-t border-border' : ''}>
             <div className="flex items-center gap-3 mb-3">
@@ -289,6 +362,138 @@ export function GeneralSection() {
         </div>
       </DesktopOnlyWrapper>
 
+      {/* ④ 任务运行时自动显示日志悬浮窗 */}
+      <DesktopOnlyWrapper>
+        <div className="bg-bg-secondary rounded-xl p-4 border border-border">
+          <div className="flex items-center justify-between">
+            <div className="flex items-center gap-3">
+              <PictureInPicture2 className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">
+                  {t('settings.autoShowOverlay')}
+                </span>
+                <p className="text-xs text-text-muted mt-0.5">
+                  {t('settings.autoShowOverlayHint')}
+                </p>
+              </div>
+            </div>
+            <SwitchButton value={autoShowOverlay} onChange={(v) => setAutoShowOverlay(v)} />
+          </div>
+        </div>
+      </DesktopOnlyWrapper>
+
+      {/* ⑤ 任务报告 */}
+      <DesktopOnlyWrapper>
+        <div className="bg-bg-secondary rounded-xl p-4 border border-border">
+          <div className="flex items-center justify-between">
+            <div className="flex items-center gap-3">
+              <FileImage className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('settings.taskReport')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('settings.taskReportHint')}</p>
+              </div>
+            </div>
+            <SwitchButton
+              value={taskReport.enabled}
+              onChange={(v) => setTaskReport({ enabled: v })}
+            />
+          </div>
+          {taskReport.enabled && (
+            <div className="flex items-center justify-between pt-4 mt-4 border-t border-border">
+              <span className="text-sm text-text-secondary">
+                {t('settings.taskReportScreenshots')}
+              </span>
+              <select
+                value={taskReport.screenshots}
+                onChange={(e) =>
+                  setTaskReport({ screenshots: e.target.value as TaskReportScreenshots })
+                }
+                className="px-2 py-1 text-sm bg-bg-tertiary border border-border rounded-md text-text-primary focus:outline-none focus:ring-2 focus:ring-accent/50"
+              >
+                <option value="all">{t('settings.taskReportScreenshotsAll')}</option>
+                <option value="failed">{t('settings.taskReportScreenshotsFailed')}</option>
+                <option value="none">{t('settings.taskReportScreenshotsNone')}</option>
+              </select>
+            </div>
+          )}
+          <div className="flex items-center justify-between pt-4 mt-4 border-t border-border">
+            <div className="flex items-center gap-3">
+              <Webhook className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">
+                  {t('settings.completionWebhook')}
+                </span>
+                <p className="text-xs text-text-muted mt-0.5">
+                  {t('settings.completionWebhookHint')}
+                </p>
+              </div>
+            </div>
+            <SwitchButton
+              value={completionWebhook.enabled}
+              onChange={(v) => setCompletionWebhook({ enabled: v })}
+            />
+          </div>
+          {completionWebhook.enabled && (
+            <input
+              type="text"
+              value={webhookUrlInput}
+              onChange={(e) => setWebhookUrlInput(e.target.value)}
+              onBlur={() => setCompletionWebhook({ url: webhookUrlInput.trim() })}
+              placeholder={t('settings.completionWebhookPlaceholder')}
+              className="w-full mt-3 px-3 py-2.5 rounded-lg bg-bg-tertiary border border-border text-sm text-text-primary placeholder:text-text-muted focus:outline-none focus:ring-2 focus:ring-accent/50"
+            />
+          )}
+        </div>
+      </DesktopOnlyWrapper>
+
+      {/* 外部程序信任 */}
+      {isTauri() && programTrust && (
+        <div className="bg-bg-secondary rounded-xl p-4 border border-border">
+          <div className="flex items-center justify-between gap-4">
+            <div className="flex items-center gap-3">
+              <ShieldCheck className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">{t('settings.programTrust')}</span>
+                <p className="text-xs text-text-muted mt-0.5">{t('settings.programTrustHint')}</p>
+              </div>
+            </div>
+            <select
+              value={programTrust.mode}
+              onChange={(e) => handleProgramTrustModeChange(e.target.value as ProgramTrustMode)}
+              className="px-2 py-1 text-sm bg-bg-tertiary border border-border rounded-md text-text-primary focus:outline-none focus:ring-2 focus:ring-accent/50"
+            >
+              <option value="trust_all">{t('settings.programTrustAll')}</option>
+              <option value="confirm">{t('settings.programTrustConfirm')}</option>
+              <option value="strict">{t('settings.programTrustStrict')}</option>
+            </select>
+          </div>
+          {programTrust.mode !== 'trust_all' && (
+            <div className="pt-4 mt-4 border-t border-border space-y-1">
+              <span className="text-sm text-text-secondary">{t('settings.programTrustList')}</span>
+              {programTrust.programs.length === 0 ? (
+                <p className="text-xs text-text-muted">{t('settings.programTrustListEmpty')}</p>
+              ) : (
+                programTrust.programs.map((program) => (
+                  <div
+                    key={program}
+                    className="flex items-center justify-between gap-2 px-2 py-1 rounded-md bg-bg-tertiary"
+                  >
+                    <span className="text-xs font-mono text-text-primary break-all">{program}</span>
+                    <button
+                      onClick={() => handleUntrustProgram(program)}
+                      className="p-0.5 rounded text-text-muted hover:text-error hover:bg-bg-hover shrink-0"
+                      title={t('settings.programTrustRemove')}
+                    >
+                      <X className="w-3.5 h-3.5" />
+                    </button>
+                  </div>
+                ))
+              )}
+            </div>
+          )}
+        </div>
+      )}
+
       {/* ⑥ 显示选项预览 */}
       <div className="bg-bg-secondary rounded-xl p-4 border border-border">
         <div className="flex items-center justify-between">
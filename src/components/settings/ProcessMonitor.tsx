@@ -0,0 +1,127 @@
+import { useState, useEffect, useCallback } from 'react';
+import { useTranslation } from 'react-i18next';
+import { Activity, Copy } from 'lucide-react';
+import { toast } from 'sonner';
+import { maaService } from '@/services/maaService';
+import type { ProcessInfo } from '@/types/maa';
+import { loggers } from '@/utils/logger';
+import { SwitchButton } from '@/components/FormControls';
+
+/** 资源监视刷新间隔（毫秒） */
+const REFRESH_INTERVAL_MS = 2000;
+
+function formatMemory(bytes: number): string {
+  return `${(bytes / 1024 / 1024).toFixed(1)} MB`;
+}
+
+function formatRunTime(secs: number): string {
+  const h = Math.floor(secs / 3600);
+  const m = Math.floor((secs % 3600) / 60);
+  const s = secs % 60;
+  return h > 0 ? `${h}h ${m}m` : `${m}m ${s}s`;
+}
+
+/** MXU 自身和 agent 进程的资源监视面板（仅桌面端） */
+export function ProcessMonitor() {
+  const { t } = useTranslation();
+  const [enabled, setEnabled] = useState(false);
+  const [processes, setProcesses] = useState<ProcessInfo[]>([]);
+
+  useEffect(() => {
+    if (!enabled) return;
+    let cancelled = false;
+    const refresh = async () => {
+      try {
+        const result = await maaService.getMonitoredProcesses();
+        if (!cancelled) setProcesses(result);
+      } catch (err) {
+        loggers.ui.warn('查询进程资源占用失败:', err);
+      }
+    };
+    refresh();
+    const timer = setInterval(refresh, REFRESH_INTERVAL_MS);
+    return () => {
+      cancelled = true;
+      clearInterval(timer);
+    };
+  }, [enabled]);
+
+  const processLabel = useCallback(
+    (p: ProcessInfo) =>
+      p.role === 'agent'
+        ? t('debug.processMonitorAgent', { name: p.instanceName ?? '-' })
+        : p.role === 'mxu'
+          ? 'MXU'
+          : p.name,
+    [t],
+  );
+
+  // 复制为纯文本，方便反馈性能问题时附上
+  const handleCopy = useCallback(async () => {
+    const lines = processes.map(
+      (p) =>
+        `${processLabel(p)} (${p.name}, PID ${p.pid}): CPU ${p.cpuUsage.toFixed(1)}%, ` +
+        `${formatMemory(p.memoryBytes)}, threads ${p.threadCount ?? '-'}, ` +
+        `up ${formatRunTime(p.runTimeSecs)}`,
+    );
+    try {
+      await maaService.copyText(lines.join('\n'));
+      toast.success(t('debug.processMonitorCopied'));
+    } catch {
+      toast.error(t('logs.copyFailed'));
+    }
+  }, [processes, processLabel, t]);
+
+  return (
+    <div className="pt-4 border-t border-border space-y-3">
+      <div className="flex items-center justify-between">
+        <div className="flex items-center gap-3">
+          <Activity className="w-5 h-5 text-accent" />
+          <div>
+            <span className="font-medium text-text-primary">{t('debug.processMonitor')}</span>
+            <p className="text-xs text-text-muted mt-0.5">{t('debug.processMonitorHint')}</p>
+          </div>
+        </div>
+        <SwitchButton value={enabled} onChange={setEnabled} />
+      </div>
+      {enabled && processes.length > 0 && (
+        <div className="pl-8 space-y-2">
+          <table className="w-full text-xs text-text-secondary">
+            <thead>
+              <tr className="text-left text-text-muted">
+                <th className="font-normal py-1">{t('debug.processMonitorProcess')}</th>
+                <th className="font-normal py-1 text-right">CPU</th>
+                <th className="font-normal py-1 text-right">{t('debug.processMonitorMemory')}</th>
+                <th className="font-normal py-1 text-right">{t('debug.processMonitorThreads')}</th>
+                <th className="font-normal py-1 text-right">{t('debug.processMonitorUptime')}</th>
+              </tr>
+            </thead>
+            <tbody className="font-mono">
+              {processes.map((p) => (
+                <tr key={p.pid} className="border-t border-border">
+                  <td
+                    className="py-1 font-sans text-text-primary"
+                    title={`${p.name} (PID ${p.pid})`}
+                  >
+                    {processLabel(p)}
+                  </td>
+                  <td className="py-1 text-right">{p.cpuUsage.toFixed(1)}%</td>
+                  <td className="py-1 text-right">{formatMemory(p.memoryBytes)}</td>
+                  <td className="py-1 text-right">{p.threadCount ?? '-'}</td>
+                  <td className="py-1 text-right">{formatRunTime(p.runTimeSecs)}</td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+          <button
+            onClick={handleCopy}
+            className="flex items-center gap-1.5 px-3 py-1.5 text-xs rounded-lg bg-bg-tertiary hover:bg-bg-hover text-text-secondary transition-colors"
+          >
+            <Copy className="w-3.5 h-3.5" />
+            {t('debug.processMonitorCopy')}
+          </button>
+        </div>
+      )}
+    </div>
+  );
+}
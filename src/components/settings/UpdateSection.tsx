@@ -15,18 +15,25 @@ import {
   Network,
 } from 'lucide-react';
 import clsx from 'clsx';
+import { toast } from 'sonner';
 
 import { useAppStore } from '@/stores/appStore';
 import {
   checkAndPrepareDownload,
   openMirrorChyanWebsite,
+  openManualDownload,
   downloadUpdate,
   getUpdateSavePath,
   cancelDownload,
   MIRRORCHYAN_ERROR_CODES,
   isDebugVersion,
 } from '@/services/updateService';
-import { createProxySettings, proxySettingsForUpdateDownload } from '@/services/proxyService';
+import {
+  createProxySettings,
+  getSystemProxy,
+  proxySettingsForUpdateDownload,
+  type SystemProxy,
+} from '@/services/proxyService';
 import { resolveI18nText } from '@/services/contentResolver';
 import { getInterfaceLangKey } from '@/i18n';
 import { loggers } from '@/utils/logger';
@@ -63,6 +70,7 @@ export function UpdateSection() {
   const [showCdk, setShowCdk] = useState(false);
   const [proxyInput, setProxyInput] = useState(proxySettings?.url || '');
   const [proxyError, setProxyError] = useState(false);
+  const [systemProxy, setSystemProxy] = useState<SystemProxy | null>(null);
   const [checkFailed, setCheckFailed] = useState(false);
   const [, setDebugLog] = useState<string[]>([]);
 
@@ -107,6 +115,11 @@ export function UpdateSection() {
     }
   }, [proxyInput, setProxySettings]);
 
+  // 探测系统代理，未填写代理地址时提示下载将使用的代理
+  useEffect(() => {
+    getSystemProxy().then(setSystemProxy);
+  }, []);
+
   // 同步 proxySettings 到 proxyInput
   useEffect(() => {
     if (proxySettings?.url && proxySettings.url !== proxyInput) {
@@ -154,6 +167,16 @@ export function UpdateSection() {
           setDownloadStatus('completed');
         } else {
           setDownloadStatus('failed');
+          if (result.networkUnavailable) {
+            const url = info.downloadUrl;
+            toast.error(t('mirrorChyan.networkUnavailable'), {
+              description: t('mirrorChyan.networkUnavailableHint'),
+              action: {
+                label: t('mirrorChyan.manualDownload'),
+                onClick: () => openManualDownload(url),
+              },
+            });
+          }
         }
       } catch (error) {
         loggers.ui.error('下载失败:', error);
@@ -168,6 +191,7 @@ export function UpdateSection() {
       setDownloadSavePath,
       proxySettings,
       mirrorChyanSettings.cdk,
+      t,
     ],
   );
 
@@ -481,6 +505,13 @@ export function UpdateSection() {
                 )}
                 <div className="mt-3 text-xs text-text-muted leading-relaxed space-y-1">
                   <p>{t('proxy.urlHint')}</p>
+                  {!proxySettings?.url && (
+                    <p>
+                      {systemProxy
+                        ? t('proxy.systemDetected', { url: systemProxy.url })
+                        : t('proxy.systemNone')}
+                    </p>
+                  )}
                 </div>
               </div>
             )}
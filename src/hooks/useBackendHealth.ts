@@ -0,0 +1,67 @@
+import { useEffect } from 'react';
+import { useTranslation } from 'react-i18next';
+import { toast } from 'sonner';
+import { maaService } from '@/services/maaService';
+import { loggers } from '@/utils/logger';
+import { isTauri } from '@/utils/paths';
+
+const PING_INTERVAL_MS = 15_000;
+const PING_TIMEOUT_MS = 5_000;
+/** 连续多少次 ping 异常（超时或子系统一直忙）后提示用户 */
+const UNHEALTHY_THRESHOLD = 2;
+const TOAST_ID = 'backend-health';
+
+const log = loggers.app;
+
+/**
+ * 定期 ping 后端，超时或子系统状态长时间读不到时提示用户，用于区分界面卡住还是后端卡住。
+ * 仅在 Tauri 环境生效。
+ */
+export function useBackendHealth() {
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    if (!isTauri()) return;
+
+    let disposed = false;
+    let failures = 0;
+    let warned = false;
+
+    const check = async () => {
+      let healthy: boolean;
+      try {
+        const result = await Promise.race([
+          maaService.ping(),
+          new Promise<null>((resolve) => setTimeout(() => resolve(null), PING_TIMEOUT_MS)),
+        ]);
+        healthy = result !== null && result.instance_count !== null;
+      } catch (err) {
+        log.warn('后端健康检查失败:', err);
+        healthy = false;
+      }
+      if (disposed) return;
+
+      if (healthy) {
+        failures = 0;
+        if (warned) {
+          warned = false;
+          toast.success(t('health.backendRecovered'), { id: TOAST_ID });
+        }
+        return;
+      }
+
+      failures += 1;
+      if (failures >= UNHEALTHY_THRESHOLD && !warned) {
+        warned = true;
+        log.warn('后端无响应，连续失败次数:', failures);
+        toast.warning(t('health.backendUnresponsive'), { id: TOAST_ID, duration: Infinity });
+      }
+    };
+
+    const timer = setInterval(() => void check(), PING_INTERVAL_MS);
+    return () => {
+      disposed = true;
+      clearInterval(timer);
+    };
+  }, [t]);
+}
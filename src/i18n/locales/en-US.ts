@@ -99,8 +99,35 @@ export default {
     hotkeysGlobalOnlyStart: 'only start works in global mode',
     minimizeToTray: 'Minimize to tray on close',
     minimizeToTrayHint: 'Hide to system tray instead of exiting when clicking close button',
+    autoShowOverlay: 'Show log overlay while running',
+    autoShowOverlayHint:
+      'Automatically show the log overlay when tasks start and close it a few seconds after all tasks finish (manually opened overlays are unaffected)',
+    programTrust: 'External program trust',
+    programTrustHint:
+      'Check program paths before running pre-actions, pretasks and MXU_LAUNCH from the resource',
+    programTrustAll: 'Trust all programs',
+    programTrustConfirm: 'Ask when not trusted',
+    programTrustStrict: 'Only run trusted programs',
+    programTrustList: 'Trusted programs',
+    programTrustListEmpty: 'No trusted programs yet',
+    programTrustRemove: 'Remove from trusted list',
+    taskReport: 'Task Report',
+    taskReportHint:
+      'Generate a summary image (durations, results, screenshots) to the debug folder when the queue finishes',
+    taskReportScreenshots: 'Report Screenshots',
+    taskReportScreenshotsAll: 'All tasks',
+    taskReportScreenshotsFailed: 'Failed tasks only',
+    taskReportScreenshotsNone: 'No screenshots',
+    completionWebhook: 'Completion webhook',
+    completionWebhookHint:
+      'POST a run summary JSON (task results, durations, errors) to the URL when the task queue finishes',
+    completionWebhookPlaceholder: 'Enter full URL (e.g. https://example.com/hook)',
     autoStart: 'Launch at startup',
     autoStartHint: 'Automatically start this application when the system boots',
+    autoStartDelay: 'Startup delay',
+    autoStartDelayHint:
+      'When launched at system startup, wait this many seconds before loading MaaFramework and connecting devices. 0 disables the delay',
+    autoStartDelayUnit: 's',
     autoStartInstance: 'Auto-execute on startup',
     autoStartInstanceHint:
       'Select a configuration to activate and run tasks automatically after startup. Scheduled tasks for other configurations will still run normally',
@@ -160,6 +187,11 @@ export default {
         'When enabled, launches the program via cmd /c to detach from the current process tree. Some games may detect the process tree (Windows only)',
       cmdYes: 'Launch via cmd /c',
       cmdNo: 'Launch as direct subprocess',
+      hideLabel: 'Hide console window',
+      hideDescription:
+        'When enabled, command-line programs start without a console window. GUI programs are not affected (Windows only)',
+      hideYes: 'Hide window',
+      hideNo: 'Show window',
     },
     notify: {
       label: '💬 System Notification',
@@ -279,6 +311,12 @@ export default {
     incompatibleController: 'Not supported by current controller',
     incompatibleResource: 'Not supported by current resource',
     supportedControllers: 'Only: {{controllers}}',
+    // Skip condition
+    skipCondition: 'Skip Condition',
+    skipConditionPlaceholder: 'e.g. hour >= 22 || last_result == "failed"',
+    skipConditionHint:
+      'Skips the task when the expression is true as it starts. Available: hour, minute, weekday, day, month, round, last_result and var.<name>',
+    skipConditionInvalid: 'Invalid expression: {{error}}',
   },
 
   // Options
@@ -306,6 +344,9 @@ export default {
     useCmd: 'Launch via cmd',
     useCmdHint:
       'When enabled, launches the program via cmd /c to detach from the current process tree. Some games may detect the process tree (Windows only)',
+    hideWindow: 'Hide console window',
+    hideWindowHint:
+      'When enabled, command-line programs start without a console window. Disable it if you need to see the output (Windows only)',
     preActionSkipped: 'Pre-program {{name}} is already running, skipped',
     waitingForDevice: 'Waiting for device to be ready...',
     waitingForWindow: 'Waiting for window to be ready...',
@@ -330,6 +371,8 @@ export default {
     pretaskExitCode: 'Pre-task exit code: {{code}}',
     pretaskFailed: 'Pre-task failed: {{error}}',
     preActionConnectDelay: 'Waiting {{seconds}} seconds before connecting...',
+    resourceIntegrityWarning:
+      '{{missing}} resource file(s) missing and {{modified}} modified ({{files}}). They may have been removed by antivirus software or corrupted; consider re-downloading the resources',
     autoPreActionName: '▶️ Launch {{name}}',
     autoPreActionAdded: 'Auto-added pre-action: {{name}} (disabled by default)',
     removeConfirmTitle: 'Delete pre-action',
@@ -370,6 +413,13 @@ export default {
     exportShareHint: 'Sharing my {{projectName}} config "{{tabName}}" with you~',
     exportShareFooter:
       '👆 Copy this message, open {{projectName}}, create a new tab, and tap "Import Config" to use it instantly',
+    shareCodeCopied: 'Share code copied to clipboard',
+    shareCodeExportFailed: 'Failed to create share code: {{error}}',
+    shareCodeImported: 'Added {{count}} tasks from share code',
+    shareCodeImportedPartial:
+      'Added {{count}} tasks from share code, skipped {{skipped}} tasks not found in this project',
+    shareCodeNoTasks: 'The share code contains no tasks available in this project',
+    shareCodeImportFailed: 'Failed to import share code: {{error}}',
   },
 
   // Controller
@@ -399,6 +449,25 @@ export default {
     savedDeviceNotFound: 'Previous device not found, please check connection or select another',
     savedWindowNotFound: 'Previous window not found, please check connection or select another',
     connectedLog: 'Connected controller [{{name}}]',
+    resolutionMismatch:
+      'Screenshot aspect ratio ({{actual}}) does not match what the resource expects; recognition may fail. Set the emulator resolution to {{expected}}',
+    usbDevice: 'USB device',
+    usbUnauthorized: 'Unauthorized',
+    usbUnauthorizedHint:
+      'USB debugging is not authorized. Tap "Allow" on the phone, then refresh the device list',
+    usbOfflineHint: 'Device state is {{state}}. Replug the USB cable, then refresh the device list',
+    usbDeviceHint:
+      'Connected to a USB device: screenshots on real phones are slower; try another screencap method if capturing fails, and adjust the display settings on the phone if the resolution does not match the resource',
+    emulatorLaunch: 'Launch emulator before connecting',
+    emulatorLaunchHint:
+      'When connecting, if the target device or window is missing, start this program and wait until it is ready',
+    emulatorLaunchProgram: 'Emulator program',
+    emulatorLaunchArgs: 'Launch arguments',
+    emulatorLaunchAdbAddress: 'ADB address',
+    emulatorLaunchAdbAddressHint: 'Device address to wait for after the emulator starts',
+    emulatorLaunchTimeout: 'Wait timeout',
+    emulatorLaunchSettle: 'Delay after ready',
+    emulatorLaunchSeconds: 's',
   },
 
   // Resource
@@ -406,6 +475,7 @@ export default {
     title: 'Resource',
     selectResource: 'Select Resource',
     loading: 'Loading resource...',
+    loadingProgress: 'Loading resources ({{done}}/{{total}})...',
     loaded: 'Resource loaded',
     loadFailed: 'Failed to load resource',
     loadResource: 'Load Resource',
@@ -416,6 +486,13 @@ export default {
     incompatibleController: 'Not supported by current controller',
   },
 
+  // Backend health check
+  health: {
+    backendUnresponsive:
+      'The backend has not responded for a while and may be stuck. Try exporting logs and restarting the app',
+    backendRecovered: 'The backend is responding again',
+  },
+
   // MaaFramework
   maa: {
     notInitialized: 'MaaFramework not initialized',
@@ -436,6 +513,12 @@ export default {
     fullscreen: 'Fullscreen',
     exitFullscreen: 'Exit Fullscreen',
     clickHint: 'Click on the image to send a tap to the device',
+    noLastFrame: 'No frame from a finished task yet',
+    lastFrameLabel: '{{status}} · {{time}}',
+    lastFrameStatus: {
+      succeeded: 'Task succeeded',
+      failed: 'Task failed',
+    },
     // Frame rate settings
     frameRate: {
       title: 'Screenshot Frame Rate',
@@ -450,11 +533,25 @@ export default {
 
   // Logs
   logs: {
+    section: {
+      succeeded: 'Succeeded · {{count}} lines',
+      failed: 'Failed · {{count}} lines',
+      running: 'Running · {{count}} lines',
+    },
     title: 'Logs',
     clear: 'Clear',
+    openOverlay: 'Open log overlay',
+    closeOverlay: 'Close overlay',
+    overlayPreview: 'Screen preview',
+    overlayLogs: 'Logs',
+    overlayNoInstance: 'No instance to preview',
+    overlayWaitingFrame: 'Waiting for frames…',
+    openOverlayFailed: 'Failed to open log overlay: {{error}}',
     autoscroll: 'Auto Scroll',
     noLogs: 'No logs',
     copyAll: 'Copy All',
+    exportInstanceLogs: 'Export logs of this instance',
+    copyFailed: 'Copy failed, please select the text and copy it manually',
     showMoreLogs: 'Show more logs',
     expand: 'Expand panels above',
     collapse: 'Collapse panels above',
@@ -477,6 +574,8 @@ export default {
       taskStarting: 'Task started: {{name}}',
       taskSucceeded: 'Task completed: {{name}}',
       taskFailed: 'Task failed: {{name}}',
+      taskInterrupted: 'Task interrupted, will run again: {{name}}',
+      taskReportGenerated: 'Task report generated: {{path}}',
       stopTask: 'Stop Task',
       // Schedule messages
       scheduleStarting: 'Scheduled execution started [{{policy}}] {{time}}',
@@ -491,6 +590,56 @@ export default {
       agentLogFloodWarning:
         'Agent is in a log flood state. To avoid performance issues, log display has been paused. The complete log is available in the local log file.',
       agentLogFloodRecovered: 'Agent log flood has eased',
+      agentRestarting:
+        'Agent exited unexpectedly (exit code {{code}}), restarting ({{attempt}}/{{max}})',
+      agentRestarted: 'Agent restarted, resuming {{remaining}} remaining task(s)',
+      agentRestartFailed: 'Failed to restart agent: {{error}}',
+      agentRestartGaveUp: 'Agent crashed {{max}} times, automatic restart stopped',
+      taskPreempted:
+        'High-priority task {{name}} preempted the queue, {{count}} task(s) will resume after it finishes',
+      taskRetrying: 'Task {{name}} failed, retrying ({{attempt}}/{{max}})',
+      taskRetryFailed: 'Failed to retry task {{name}}: {{error}}',
+      taskSkippedByCondition: 'Task {{name}} skipped, condition {{expression}} is true',
+      taskConditionInvalid:
+        'Skip condition of task {{name}} is invalid, running it anyway: {{error}}',
+      taskValidationPassed:
+        'Task {{name}} passed validation: {{nodes}} reachable nodes, {{warnings}} warnings',
+      taskValidationFailed: 'Task {{name}} failed validation: {{errors}} errors',
+      taskValidationIssue: 'Task {{name}} validation: {{location}} {{message}}',
+      taskValidationError: 'Failed to validate task {{name}}: {{error}}',
+      roundCompleted: 'Round {{round}}/{{total}} finished',
+      sectionStart: '── {{name}} started ──',
+      sectionEnd: '── {{name}} ended ──',
+      sectionRound: '{{name}} (round {{round}})',
+      pipelineHotUpdated: 'Options of task "{{name}}" updated, taking effect from the next node',
+      pipelineHotDeferred:
+        'The node of {{fields}} is running, the change applies the next time it runs',
+      pipelineHotRestart: '{{fields}} will not take effect in this run, restart the task to apply',
+      pipelineHotNextRound:
+        '{{fields}} will not take effect in this round, applying from the next round',
+      roundCompletedNext: 'Round {{round}}/{{total}} finished, next round starts in {{seconds}}s',
+      watchdogTimeout: 'Run exceeded the {{minutes}}-minute limit, the watchdog stopped the tasks',
+      windowMinimized:
+        'Target window is minimized, screenshots will fail or be black. Please restore the window',
+      windowMinimizedPaused:
+        'Target window is minimized, tasks will pause before the next recognition and resume once it is restored',
+      windowMinimizedRestore:
+        'Target window is minimized, it will be restored before the next recognition',
+      windowRestored: 'Target window restored',
+      emulatorLaunching: 'Launched emulator {{name}}, waiting for the device...',
+      emulatorAlreadyRunning: 'Emulator {{name}} is already running, waiting for the device...',
+      emulatorReady: 'Emulator is ready, connecting',
+      emulatorTimeout: 'Timed out waiting for emulator {{name}}',
+      adbUnauthorized:
+        'Device {{address}} is unauthorized, tap "Allow USB debugging" on the device',
+      adbReauthorized: 'Device {{address}} authorized',
+      adbReauthorizedReconnected: 'Device {{address}} authorized again, reconnected automatically',
+      adbAuthTimeout:
+        'Timed out waiting for device {{address}} to be authorized, allow debugging on the device and reconnect',
+      programUntrusted: 'Blocked untrusted program ({{source}}): {{program}}',
+      programRejected: 'Program execution denied ({{source}}): {{program}}',
+      dryRunAction: '[dry-run] {{node}} would run {{action}} (at recognized area)',
+      dryRunActionAt: '[dry-run] {{node}} would run {{action}} ({{x}}, {{y}})',
       // Hotkeys
       hotkeyDetected: 'Hotkey detected: {{combo}} ({{action}})',
       hotkeyActionStart: 'Start tasks',
@@ -533,6 +682,9 @@ export default {
     interfaceVersion: '{{name}} version',
     maafwVersion: 'maafw version',
     mxuVersion: 'mxu version',
+    agentVersion: 'agent version',
+    buildCommit: 'build commit',
+    buildTime: 'build time',
     environment: 'Environment',
     envTauri: 'Tauri Desktop',
     envBrowser: 'Browser',
@@ -540,11 +692,68 @@ export default {
     operatingSystem: 'Operating System',
     architecture: 'Architecture',
     tauriVersion: 'Tauri Version',
+    exeVolume: 'Program Volume',
+    exeVolumeReadonly: 'read-only',
+    controllerInfo: 'Controller details',
+    controllerInfoRefresh: 'Refresh',
+    controllerStatus: 'Status',
+    controllerStatusValue: {
+      connected: 'Connected',
+      disconnected: 'Disconnected',
+      none: 'No controller created yet',
+    },
+    controllerType: 'Type',
+    controllerTarget: 'Target',
+    controllerScreencap: 'Screenshot method',
+    controllerInput: 'Input method',
+    controllerResolution: 'Resolution',
+    controllerShortSide: 'Screenshot short side',
+    cpuFeatures: 'CPU Instruction Sets',
+    cpuFeaturesMissing: '(missing {{features}}, tasks cannot run)',
     pathInfo: 'Path Information',
     cwd: 'Current Working Directory',
     exeDir: 'Executable Directory',
     webview2Dir: 'WebView2 Directory',
     webview2System: 'System',
+    webview2Shared: 'Shared WebView2 runtime',
+    webview2SharedHint:
+      'Install the standalone runtime to %LOCALAPPDATA%\\MXU and share it across MXU installs to save space. Takes effect after restart',
+    webviewGpuDisabled: 'Disable WebView2 GPU acceleration',
+    webviewGpuDisabledHint:
+      'Turn on if the UI shows garbled graphics, blank screens or flickering to use software rendering instead. Takes effect after restart',
+    adbPath: 'ADB path',
+    adbPathHint:
+      "Use a specific adb executable when the emulator's bundled adb conflicts with the system adb",
+    adbPathPlaceholder: 'Leave empty to detect automatically',
+    adbPathSaved: 'Using the specified adb, {{count}} device(s) found',
+    adbPathInvalid: 'The specified adb is not usable: {{error}}',
+    toolkitConfig: 'Device search directories',
+    toolkitConfigHint:
+      'If devices from less common emulators or custom install locations are not found, add the adb directory or emulator install directory here (one per line)',
+    extraAdbDirs: 'Extra adb directories',
+    extraAdbDirsPlaceholder: 'Directory containing adb, or path to the adb executable',
+    emulatorDirs: 'Emulator install directories',
+    emulatorDirsPlaceholder: 'adb will be searched in subdirectories',
+    toolkitConfigSaved: 'Saved, found {{count}} usable adb',
+    toolkitConfigFailed: 'Failed to save device search directories: {{error}}',
+    processMonitor: 'Resource monitor',
+    processMonitorHint:
+      'Shows CPU and memory usage of MXU and agent processes, useful for diagnosing lag or reporting performance issues',
+    processMonitorProcess: 'Process',
+    processMonitorMemory: 'Memory',
+    processMonitorThreads: 'Threads',
+    processMonitorUptime: 'Uptime',
+    processMonitorAgent: 'Agent ({{name}})',
+    processMonitorCopy: 'Copy data',
+    processMonitorCopied: 'Resource usage copied',
+    agentResourceLimits: 'Agent resource limits',
+    agentResourceLimitsHint:
+      'Limits agent processes via a Job Object, 0 means unlimited; all agent processes are terminated when MXU exits',
+    agentMemoryLimit: 'Memory limit per agent (MB)',
+    agentCpuLimit: 'Total CPU quota for all agents (%)',
+    updateCopyConcurrency: 'Update copy threads',
+    updateCopyConcurrencyHint:
+      'Threads used to copy files during incremental updates. 0 uses the CPU core count; copying is serial on hard disk drives',
     resetWindowLayout: 'Reset Window Layout',
     openConfigDir: 'Open Config Dir',
     openLogDir: 'Open Log Dir',
@@ -559,6 +768,107 @@ export default {
     saveDraw: 'Save Debug Images',
     saveDrawHint:
       'Save recognition and action debug images to log directory (auto-disabled on restart)',
+    verboseLog: 'Verbose Logging',
+    verboseLogHint:
+      'Record debug-level logs. When off, only info and above are recorded. Takes effect immediately',
+    eventLog: 'Structured event log',
+    eventLogHint:
+      'Also write task start/end, recognition results and errors to debug/logs/events.jsonl in JSONL format for script analysis',
+    failureScreenshot: 'Screenshot on task failure',
+    failureScreenshotHint:
+      'Attach a screenshot (downscaled if large) to the log when a task fails, to show where it got stuck and help with bug reports',
+    actionTrace: 'Click trace overlay',
+    actionTraceHint:
+      'Emit a coordinate event for every click and swipe so the screenshot preview can mark where the task actually tapped',
+    breakpoints: 'Pipeline Breakpoints',
+    breakpointsHint:
+      'Pause the current instance before these nodes run so you can inspect the screen',
+    breakpointsPlaceholder: 'Node names, comma separated',
+    dryRun: 'Dry-run mode',
+    dryRunHint:
+      'Tasks started on the current instance only capture and recognize; actions are skipped and logged instead',
+    speed: 'Action speed',
+    speedHint:
+      'Scales the wait before and after each action in tasks started on the current instance (0.25 - 4, default 1). Lower it if a slow emulator misses taps; raise it on fast devices to save time',
+    captureMode: 'Capture occlusion mode',
+    captureModeHint:
+      'Win32 controllers only: Foreground brings the window to front before each recognition; PrintWindow takes effect after reconnecting',
+    captureModeDefault: 'Default',
+    captureModeForeground: 'Bring to foreground',
+    captureModePrintWindow: 'PrintWindow',
+    minimizedPolicy: 'When Minimized',
+    minimizedPolicyHint:
+      'Win32 controllers only: screenshots fail or go black while the target window is minimized. Pause tasks until the window is restored, or restore it automatically (without taking focus)',
+    minimizedPolicyNotify: 'Notify only',
+    minimizedPolicyPause: 'Pause tasks',
+    minimizedPolicyRestore: 'Restore window',
+    frameDedup: 'Frame deduplication',
+    frameDedupHint:
+      'Gradually lower the recognition rate while the screen stays unchanged to save CPU, and restore it as soon as the screen changes (current instance)',
+    frameDedupSimilarity: 'Similarity threshold',
+    unknownScreens: 'Unknown screen collection',
+    unknownScreensHint:
+      'When every recognition misses for several rounds in a row, archive the current screenshot and context to the unknown_screens folder (similar screenshots are kept once, up to 200). Pack them to help resource developers add recognition',
+    unknownScreensThreshold: 'Consecutive failures',
+    unknownScreensCount: '{{count}} collected',
+    unknownScreensOpen: 'Open folder',
+    unknownScreensExport: 'Pack',
+    unknownScreensExported: 'Unknown screens packed',
+    unknownScreensExportFailed: 'Failed to pack unknown screens: {{error}}',
+    preprocess: 'Screenshot preprocessing',
+    preprocessHint:
+      'Adjust brightness, contrast and gamma of screenshots before recognition to stabilize recognition on dark or tinted screens (current instance)',
+    preprocessBrightness: 'Brightness',
+    preprocessContrast: 'Contrast',
+    preprocessGamma: 'Gamma',
+    preprocessNeedsReconnect:
+      'Screenshot preprocessing takes effect after reconnecting the controller',
+    taskRetry: 'Retry failed tasks',
+    taskRetryHint:
+      'Automatically retry failed tasks of this instance; a task only fails after all retries are used up',
+    taskRetryMaxRetries: 'Max retries',
+    taskRetryInterval: 'Retry interval (s)',
+    taskRetryReconnect: 'Reconnect controller before retrying',
+    taskRetryRestartApp: 'Restart app before retrying',
+    taskRetryRestartAppPlaceholder: 'Package or activity, empty to skip',
+    taskRetryFailed: 'Failed to set retry policy: {{error}}',
+    watchdog: 'Max runtime watchdog',
+    watchdogHint: "Stop the current instance's tasks when a run exceeds the limit",
+    watchdogMaxMinutes: 'Max runtime (minutes)',
+    watchdogCountPaused: 'Count time paused at breakpoints',
+    watchdogFailed: 'Failed to set watchdog: {{error}}',
+    benchmark: 'Benchmark',
+    benchmarkHint:
+      'Measure screenshot and recognition time of the current instance; results are exported to the log folder',
+    benchmarkScreencap: 'Screenshot',
+    benchmarkFailed: 'Failed',
+    benchmarkOpenResult: 'Open result file',
+    recordClip: 'Record clip',
+    annotatedScreenshot: 'Annotated screenshot',
+    annotatedScreenshotHint:
+      'Save the current screenshot with the latest recognition boxes and scores drawn on it, handy for bug reports',
+    annotatedScreenshotSaved: 'Annotated screenshot saved ({{count}} recognition results)',
+    annotatedScreenshotFailed: 'Failed to save annotated screenshot: {{error}}',
+    issueBundle: 'Issue Bundle',
+    issueBundleHint:
+      'Export the screenshot, recognition details, node config, override, logs and system info at the time of failure, with a README',
+    issueBundleExported: 'Issue bundle exported',
+    issueBundleFailed: 'Failed to export issue bundle: {{error}}',
+    resourceManifestResetButton: 'Rebuild resource manifest',
+    resourceManifestResetHint:
+      'Regenerate the integrity manifest from the current files after changing resources (bundled manifests are not affected)',
+    resourceManifestReset:
+      'Resource manifests cleared; they will be regenerated from the current files on the next run',
+    resourceManifestResetFailed: 'Failed to rebuild resource manifest: {{error}}',
+    recordClipHint:
+      "Record the current instance's screenshots at 2 FPS; on stop, the clip is exported to the clips folder in the log directory",
+    clipExportGif: 'Stop & export GIF',
+    clipExportMp4: 'Stop & export MP4',
+    clipSummary: '{{frames}} frames · {{seconds}} s',
+    clipDropped: 'Recording limit exceeded; the oldest {{count}} frames were dropped',
+    clipMp4Fallback: 'No usable ffmpeg found; exported as GIF instead',
+    clipOpenResult: 'Open clip file',
+    openFileFailed: 'Failed to open: {{error}}',
     tcpCompatMode: 'Communication Compat Mode',
     tcpCompatModeHint:
       'Try enabling this if the app crashes immediately after starting tasks. Only use in this case, as it may reduce performance',
@@ -601,6 +911,8 @@ export default {
   // Instance
   instance: {
     defaultName: 'Config 1',
+    duplicateName:
+      'Another tab is already named "{{name}}"; consider using a different name to tell them apart',
   },
 
   // Connection panel
@@ -612,6 +924,7 @@ export default {
   dashboard: {
     title: 'Dashboard',
     toggle: 'Dashboard View',
+    stopAll: 'Stop All',
     exit: 'Exit Dashboard',
     instances: 'instances',
     noInstances: 'No instances',
@@ -675,6 +988,10 @@ export default {
     downloadFailed: 'Download Failed',
     viewDetails: 'View Details',
     noDownloadUrl: 'No download URL available. Please fill in CDK or check network environment',
+    networkUnavailable: 'Network unavailable',
+    networkUnavailableHint:
+      'Cannot reach the download server. Check your network or proxy settings, or download it manually in the browser',
+    manualDownload: 'Download manually',
     openFolder: 'Open Folder',
     retry: 'Retry',
     preparingDownload: 'Preparing download...',
@@ -752,6 +1069,12 @@ export default {
     startedAt: 'Started at: {{time}}',
     deletePolicyTitle: 'Delete schedule',
     deletePolicyConfirm: 'Are you sure you want to delete schedule "{{name}}"?',
+    queueLoop: 'Loop execution',
+    queueLoopRounds: 'Rounds (0 = infinite)',
+    queueLoopInterval: 'Interval (seconds)',
+    queueLoopHint:
+      'Repeat the task queue for the given number of rounds. Stopping ends the loop at the current task',
+    queueLoopSkipped: 'The task list contains trailing special tasks, running a single round only',
     // Index corresponds to Date.getDay(): 0=Sun, 1=Mon, ..., 6=Sat
     weekdays: ['Sun', 'Mon', 'Tue', 'Wed', 'Thu', 'Fri', 'Sat'],
   },
@@ -764,6 +1087,20 @@ export default {
     taskNotFound: 'Task not found',
     controllerNotFound: 'Controller not found',
     resourceNotFound: 'Resource not found',
+    codes: {
+      INSTANCE_NOT_FOUND: 'Instance not found',
+      CONTROLLER_NOT_CONNECTED: 'Controller not connected',
+      CONTROLLER_CONNECT_FAILED: 'Controller connection failed',
+      RESOURCE_NOT_LOADED: 'Resource not loaded',
+      RESOURCE_LOAD_FAILED: 'Resource loading failed',
+      TASK_RUN_FAILED: 'Failed to submit task',
+      TASK_PREEMPTING: 'Another task is preempting, please try again later',
+      DOWNLOAD_FAILED: 'Download failed',
+      DOWNLOAD_CANCELLED: 'Download cancelled',
+      UPDATE_FAILED: 'Update installation failed',
+      INVALID_ARGUMENT: 'Invalid argument',
+      INTERNAL: 'Internal error',
+    },
   },
 
   // Context Menu
@@ -783,9 +1120,11 @@ export default {
     exportConfig: 'Export Config',
     exportToClipboard: 'Export to Clipboard',
     exportToTxt: 'Export as txt File',
+    exportToShareCode: 'Copy share code',
     importConfig: 'Import Config',
     importFromClipboard: 'Import from Clipboard',
     importFromTxt: 'Import from txt File',
+    importFromShareCode: 'Import from share code',
 
     // Pre-action context menu
     duplicateAction: 'Duplicate',
@@ -809,6 +1148,8 @@ export default {
     moveToBottom: 'Move to Bottom',
     expandOptions: 'Expand Options',
     collapseOptions: 'Collapse Options',
+    editSkipCondition: 'Set Skip Condition',
+    validateTask: 'Validate Task Config',
     selectAll: 'Select All Tasks',
     deselectAll: 'Deselect All',
     expandAllTasks: 'Expand All',
@@ -822,6 +1163,7 @@ export default {
     fullscreen: 'Fullscreen',
     saveScreenshot: 'Save Screenshot',
     copyScreenshot: 'Copy Screenshot',
+    viewLastFrame: 'View Last Frame',
 
     // Connection panel context menu
     refreshDevices: 'Refresh Device List',
@@ -888,6 +1230,16 @@ export default {
     desktopHint: 'For a more stable experience, consider using the desktop client',
   },
 
+  // Breakpoint debugging
+  breakpoint: {
+    title: 'Breakpoint hit - {{instance}}',
+    pausedAt: 'Task paused before node:',
+    noScreenshot: 'No screenshot available',
+    recognition: 'Recognition result',
+    clearAndContinue: 'Remove all breakpoints and continue',
+    continue: 'Continue',
+  },
+
   // Bad path warning
   badPath: {
     title: 'Wrong Location',
@@ -899,15 +1251,40 @@ export default {
       'The program is running from a temporary folder. It may disappear when closed. Please extract the archive to a folder first, then run the program from there.',
     hint: 'Tip: We recommend extracting to a dedicated folder like "D:\\MaaXXX". Avoid Desktop or Downloads for easier management.',
     exit: 'Exit',
+    networkTitle: 'The program is on a network drive',
+    networkDescription:
+      'Network drives often cause file locks, failed updates and unwritable logs. Please move the whole folder to a local fixed disk.',
+    removableTitle: 'The program is on a removable drive',
+    removableDescription:
+      'Removable drives can disconnect at any time, which may corrupt configs or break updates. Please move the whole folder to a local fixed disk.',
+    readonlyTitle: 'The program folder is not writable',
+    readonlyDescription:
+      'Configs, logs and updates all need to write to the program folder. Please move the whole folder to a local folder you can write to.',
+    cloudTitle: 'The program is in a OneDrive synced folder',
+    cloudDescription:
+      'Cloud sync locks files in use, which often breaks updates and logging. Please move the whole folder to a local folder that is not synced.',
+    continue: 'Continue anyway',
   },
   // Proxy Settings
   proxy: {
     title: 'Network Proxy',
     url: 'Proxy URL',
     urlPlaceholder: 'e.g., http://127.0.0.1:7890',
-    urlHint: 'Supports HTTP/SOCKS5, leave empty to disable proxy',
+    urlHint: 'Supports HTTP/SOCKS5, leave empty to use the system proxy',
+    systemDetected: 'Using system proxy: {{url}}',
+    systemNone: 'No system proxy detected, connecting directly',
     urlHintDisabled: 'MirrorChyan CDK filled, proxy is disabled',
     invalid: 'Invalid proxy URL format',
     examples: 'Example Formats',
   },
+  cpuUnsupported: {
+    title: 'Unsupported CPU',
+    message: 'Your CPU does not support {{features}} and cannot run this program',
+    description:
+      'The libraries used for recognition and automation require these instruction sets and would crash on this computer, so MaaFramework will not be loaded.',
+    hint:
+      'Most Intel / AMD processors made after 2011 support AVX. If your CPU does, check whether a virtual machine or the BIOS is hiding AVX.',
+    exit: 'Exit',
+    continue: 'Open anyway (tasks cannot run)',
+  },
 };
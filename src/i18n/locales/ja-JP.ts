@@ -99,8 +99,34 @@ export default {
     hotkeysGlobalOnlyStart: 'グローバルモードでは開始のみ有効',
     minimizeToTray: '閉じる時にトレイに最小化',
     minimizeToTrayHint: '閉じるボタンをクリックすると、終了せずにシステムトレイに隠れます',
+    autoShowOverlay: '実行中にログオーバーレイを表示',
+    autoShowOverlayHint:
+      'タスク開始時にログオーバーレイを自動表示し、全タスク終了の数秒後に自動で閉じます（手動で開いたものは対象外）',
+    programTrust: '外部プログラムの信頼',
+    programTrustHint:
+      'リソース設定の前処理プログラム、pretask、MXU_LAUNCH の実行前にプログラムパスを検証',
+    programTrustAll: 'すべてのプログラムを信頼',
+    programTrustConfirm: '未登録時に確認',
+    programTrustStrict: '信頼済みのプログラムのみ実行',
+    programTrustList: '信頼済みのプログラム',
+    programTrustListEmpty: '信頼済みのプログラムはありません',
+    programTrustRemove: '信頼リストから削除',
+    taskReport: 'タスクレポート',
+    taskReportHint: 'キュー終了後、所要時間・結果・スクリーンショットをまとめた画像を debug フォルダに保存します',
+    taskReportScreenshots: 'レポートのスクリーンショット',
+    taskReportScreenshotsAll: 'すべてのタスク',
+    taskReportScreenshotsFailed: '失敗したタスクのみ',
+    taskReportScreenshotsNone: '含めない',
+    completionWebhook: '完了時に Webhook を送信',
+    completionWebhookHint:
+      'タスクキューがすべて終了したとき、実行サマリー JSON（各タスクの結果・所要時間・エラー有無）を URL に POST します',
+    completionWebhookPlaceholder: '完全な URL を入力（例：https://example.com/hook）',
     autoStart: 'スタートアップ時に起動',
     autoStartHint: 'システム起動時にこのアプリケーションを自動的に起動します',
+    autoStartDelay: '起動遅延',
+    autoStartDelayHint:
+      'スタートアップ起動時、指定秒数待ってから MaaFramework の読み込みとデバイス接続を行います。0 で遅延なし',
+    autoStartDelayUnit: '秒',
     autoStartInstance: '起動後に自動実行',
     autoStartInstanceHint:
       '起動後に自動的にアクティブにしてタスクを実行する設定を選択します。他の設定のスケジュールタスクは通常通り実行されます',
@@ -158,6 +184,11 @@ export default {
         '有効にすると cmd /c でプログラムを起動し、現在のプロセスツリーから切り離します。一部のゲームはプロセスツリーを検出する場合があります（Windows のみ）',
       cmdYes: 'cmd /c で起動',
       cmdNo: 'サブプロセスとして直接起動',
+      hideLabel: 'コンソールウィンドウを隠す',
+      hideDescription:
+        '有効にするとコマンドラインプログラムの起動時に黒いコンソールウィンドウを表示しません。GUI プログラムには影響しません（Windows のみ）',
+      hideYes: 'ウィンドウを隠す',
+      hideNo: 'ウィンドウを表示',
     },
     notify: {
       label: '💬 システム通知',
@@ -274,6 +305,12 @@ export default {
     incompatibleController: '現在のコントローラーに対応していません',
     incompatibleResource: '現在のリソースに対応していません',
     supportedControllers: 'のみ対応: {{controllers}}',
+    // スキップ条件
+    skipCondition: 'スキップ条件',
+    skipConditionPlaceholder: '例: hour >= 22 || last_result == "failed"',
+    skipConditionHint:
+      'タスク開始時に式が真ならスキップします。hour、minute、weekday、day、month、round、last_result、var.変数名 が使用できます',
+    skipConditionInvalid: '式が無効です：{{error}}',
   },
 
   // オプション
@@ -301,6 +338,9 @@ export default {
     useCmd: 'cmd で起動',
     useCmdHint:
       '有効にすると cmd /c でプログラムを起動し、現在のプロセスツリーから切り離します。一部のゲームはプロセスツリーを検出する場合があります（Windows のみ）',
+    hideWindow: 'コンソールウィンドウを隠す',
+    hideWindowHint:
+      '有効にするとコマンドラインプログラムの起動時に黒いコンソールウィンドウを表示しません。出力を確認する場合は無効にしてください（Windows のみ）',
     preActionSkipped: '前処理プログラム {{name}} は実行中のためスキップしました',
     waitingForDevice: 'デバイスの準備を待機中...',
     waitingForWindow: 'ウィンドウの準備を待機中...',
@@ -325,6 +365,8 @@ export default {
     pretaskExitCode: '事前タスク終了コード: {{code}}',
     pretaskFailed: '事前タスクの実行に失敗しました: {{error}}',
     preActionConnectDelay: '{{seconds}} 秒後に接続します...',
+    resourceIntegrityWarning:
+      'リソースファイルが {{missing}} 個欠落、{{modified}} 個変更されています（{{files}}）。ウイルス対策ソフトによる削除や破損の可能性があります。リソースの再ダウンロードをおすすめします',
     autoPreActionName: '▶️ {{name}} を起動',
     autoPreActionAdded: '前処理プログラムを自動追加しました: {{name}}（デフォルトでは無効）',
     removeConfirmTitle: '前処理プログラムを削除',
@@ -365,6 +407,13 @@ export default {
     exportShareHint: '{{projectName}} の「{{tabName}}」設定をシェアするよ～',
     exportShareFooter:
       '👆 このメッセージをコピーして、{{projectName}} で新しいタブを開き「設定をインポート」を押すだけでOK',
+    shareCodeCopied: '共有コードをクリップボードにコピーしました',
+    shareCodeExportFailed: '共有コードの生成に失敗しました：{{error}}',
+    shareCodeImported: '共有コードから {{count}} 個のタスクを追加しました',
+    shareCodeImportedPartial:
+      '共有コードから {{count}} 個のタスクを追加しました。このプロジェクトに存在しない {{skipped}} 個のタスクはスキップしました',
+    shareCodeNoTasks: '共有コードにこのプロジェクトで使えるタスクがありません',
+    shareCodeImportFailed: '共有コードのインポートに失敗しました：{{error}}',
   },
 
   // コントローラー
@@ -396,6 +445,26 @@ export default {
     savedWindowNotFound:
       '前回のウィンドウが見つかりません。接続を確認するか、別のウィンドウを選択してください',
     connectedLog: 'コントローラーに接続しました [{{name}}]',
+    resolutionMismatch:
+      'スクリーンショットの縦横比（{{actual}}）がリソースの想定と一致しないため、認識が失敗する可能性があります。エミュレーターの解像度を {{expected}} に設定してください',
+    usbDevice: 'USB 実機',
+    usbUnauthorized: '未承認',
+    usbUnauthorizedHint:
+      'USB デバッグが承認されていません。スマートフォンで「許可」をタップしてからデバイス一覧を更新してください',
+    usbOfflineHint:
+      'デバイスの状態は {{state}} です。USB ケーブルを挿し直してからデバイス一覧を更新してください',
+    usbDeviceHint:
+      'USB 実機に接続しました：実機のスクリーンショットは遅めです。失敗する場合は別のスクリーンショット方式を試し、解像度がリソースと合わない場合はスマートフォンの表示設定を調整してください',
+    emulatorLaunch: '接続前にエミュレーターを起動',
+    emulatorLaunchHint:
+      '接続時に対象デバイスやウィンドウがない場合、このプログラムを起動して準備完了まで待機します',
+    emulatorLaunchProgram: 'エミュレーターのプログラム',
+    emulatorLaunchArgs: '起動引数',
+    emulatorLaunchAdbAddress: 'ADB アドレス',
+    emulatorLaunchAdbAddressHint: 'エミュレーター起動後に待機するデバイスのアドレス',
+    emulatorLaunchTimeout: '待機タイムアウト',
+    emulatorLaunchSettle: '準備完了後の待機',
+    emulatorLaunchSeconds: '秒',
   },
 
   // リソース
@@ -403,6 +472,7 @@ export default {
     title: 'リソースパック',
     selectResource: 'リソースパックを選択',
     loading: 'リソースを読み込み中...',
+    loadingProgress: 'リソースを読み込み中（{{done}}/{{total}}）...',
     loaded: 'リソースを読み込みました',
     loadFailed: 'リソースの読み込みに失敗しました',
     loadResource: 'リソースを読み込む',
@@ -413,6 +483,13 @@ export default {
     incompatibleController: '現在のコントローラーに対応していません',
   },
 
+  // バックエンドのヘルスチェック
+  health: {
+    backendUnresponsive:
+      'バックエンドが長時間応答していません。停止している可能性があります。ログをエクスポートしてからアプリを再起動してください',
+    backendRecovered: 'バックエンドの応答が回復しました',
+  },
+
   // MaaFramework
   maa: {
     notInitialized: 'MaaFramework が初期化されていません',
@@ -433,6 +510,12 @@ export default {
     fullscreen: '全画面表示',
     exitFullscreen: '全画面を終了',
     clickHint: '画面をクリックするとデバイスにタップを送信します',
+    noLastFrame: 'タスク終了時のスクリーンショットはまだありません',
+    lastFrameLabel: '{{status}} · {{time}}',
+    lastFrameStatus: {
+      succeeded: 'タスク成功',
+      failed: 'タスク失敗',
+    },
     // フレームレート設定
     frameRate: {
       title: 'スクリーンショットのフレームレート',
@@ -447,11 +530,25 @@ export default {
 
   // ログ
   logs: {
+    section: {
+      succeeded: '成功 · {{count}} 件',
+      failed: '失敗 · {{count}} 件',
+      running: '実行中 · {{count}} 件',
+    },
     title: '実行ログ',
     clear: 'クリア',
+    openOverlay: 'ログオーバーレイを開く',
+    closeOverlay: 'オーバーレイを閉じる',
+    overlayPreview: '画面プレビュー',
+    overlayLogs: 'ログ',
+    overlayNoInstance: 'プレビューできるインスタンスがありません',
+    overlayWaitingFrame: '画面を待機中…',
+    openOverlayFailed: 'ログオーバーレイを開けませんでした: {{error}}',
     autoscroll: '自動スクロール',
     noLogs: 'ログがありません',
     copyAll: 'すべてコピー',
+    exportInstanceLogs: 'このインスタンスのログをエクスポート',
+    copyFailed: 'コピーに失敗しました。テキストを選択して手動でコピーしてください',
     showMoreLogs: 'さらにログを表示',
     expand: '上部パネルを展開',
     collapse: '上部パネルを折りたたむ',
@@ -474,6 +571,8 @@ export default {
       taskStarting: 'タスクを開始: {{name}}',
       taskSucceeded: 'タスクが完了しました: {{name}}',
       taskFailed: 'タスクが失敗しました: {{name}}',
+      taskInterrupted: 'タスクを中断しました。後で再実行します: {{name}}',
+      taskReportGenerated: 'タスクレポートを生成しました: {{path}}',
       stopTask: 'タスクを停止',
       // スケジュールメッセージ
       scheduleStarting: 'スケジュール実行を開始 [{{policy}}] {{time}}',
@@ -487,6 +586,58 @@ export default {
       agentLogFloodWarning:
         'Agent がログスパム状態です。性能問題を避けるためログ表示を一時停止しました。完全なログはローカルのログファイルで確認できます。',
       agentLogFloodRecovered: 'Agent のログスパムが緩和されました',
+      agentRestarting:
+        'Agent が予期せず終了しました（終了コード {{code}}）。自動再起動しています（{{attempt}}/{{max}}）',
+      agentRestarted: 'Agent を再起動しました。残り {{remaining}} 件のタスクを続行します',
+      agentRestartFailed: 'Agent の再起動に失敗しました：{{error}}',
+      agentRestartGaveUp: 'Agent が {{max}} 回クラッシュしたため、自動再起動を停止しました',
+      taskPreempted:
+        '優先度の高いタスク {{name}} が割り込みました。{{count}} 件のタスクは完了後に再開されます',
+      taskRetrying: 'タスク {{name}} が失敗しました。リトライ中（{{attempt}}/{{max}}）',
+      taskRetryFailed: 'タスク {{name}} のリトライに失敗しました：{{error}}',
+      taskSkippedByCondition:
+        'タスク {{name}} はスキップ条件 {{expression}} を満たしたためスキップしました',
+      taskConditionInvalid:
+        'タスク {{name}} のスキップ条件が無効です。通常どおり実行します：{{error}}',
+      taskValidationPassed:
+        'タスク {{name}} の検証に合格しました：到達可能なノード {{nodes}} 個、警告 {{warnings}} 件',
+      taskValidationFailed: 'タスク {{name}} の検証に失敗しました：エラー {{errors}} 件',
+      taskValidationIssue: 'タスク {{name}} の検証：{{location}} {{message}}',
+      taskValidationError: 'タスク {{name}} を検証できませんでした：{{error}}',
+      roundCompleted: '第 {{round}}/{{total}} ラウンドが完了しました',
+      sectionStart: '── {{name}} 開始 ──',
+      sectionEnd: '── {{name}} 終了 ──',
+      sectionRound: '{{name}}（第 {{round}} ラウンド）',
+      pipelineHotUpdated: 'タスク「{{name}}」の設定を更新しました。次のノードから反映されます',
+      pipelineHotDeferred: '{{fields}} のノードは実行中です。次回の実行時に反映されます',
+      pipelineHotRestart: '{{fields}} は今回の実行には反映されません。タスクを再開始してください',
+      pipelineHotNextRound: '{{fields}} はこのラウンドには反映されず、次のラウンドから反映されます',
+      roundCompletedNext:
+        '第 {{round}}/{{total}} ラウンドが完了しました。{{seconds}} 秒後に次のラウンドを開始します',
+      watchdogTimeout: '実行時間が上限の {{minutes}} 分を超えたため、ウォッチドッグがタスクを停止しました',
+      windowMinimized:
+        '対象ウィンドウが最小化されました。スクリーンショットが失敗または黒画面になります。ウィンドウを復元してください',
+      windowMinimizedPaused:
+        '対象ウィンドウが最小化されました。次の認識前にタスクを一時停止し、復元後に自動で再開します',
+      windowMinimizedRestore: '対象ウィンドウが最小化されました。次の認識前に自動で復元します',
+      windowRestored: '対象ウィンドウが復元されました',
+      emulatorLaunching: 'エミュレータ {{name}} を起動しました。デバイスの準備を待っています...',
+      emulatorAlreadyRunning:
+        'エミュレータ {{name}} は起動済みです。デバイスの準備を待っています...',
+      emulatorReady: 'エミュレータの準備ができました。接続します',
+      emulatorTimeout: 'エミュレータ {{name}} の準備待ちがタイムアウトしました',
+      adbUnauthorized:
+        'デバイス {{address}} のデバッグが許可されていません。デバイスで「USB デバッグを許可」をタップしてください',
+      adbReauthorized: 'デバイス {{address}} が許可されました',
+      adbReauthorizedReconnected:
+        'デバイス {{address}} が再度許可されたため、自動的に再接続しました',
+      adbAuthTimeout:
+        'デバイス {{address}} の許可待ちがタイムアウトしました。デバイスでデバッグを許可してから再接続してください',
+      programUntrusted:
+        '信頼リストにないプログラムの実行をブロックしました（{{source}}）：{{program}}',
+      programRejected: 'プログラムの実行を拒否しました（{{source}}）：{{program}}',
+      dryRunAction: '[dry-run] {{node}} で {{action}} を実行予定（認識位置）',
+      dryRunActionAt: '[dry-run] {{node}} で {{action}} を実行予定 ({{x}}, {{y}})',
       // ショートカットキー
       hotkeyDetected: 'ショートカットキーを検出: {{combo}}（{{action}}）',
       hotkeyActionStart: 'タスク開始',
@@ -529,6 +680,9 @@ export default {
     interfaceVersion: '{{name}} バージョン',
     maafwVersion: 'maafw バージョン',
     mxuVersion: 'mxu バージョン',
+    agentVersion: 'agent バージョン',
+    buildCommit: 'ビルドコミット',
+    buildTime: 'ビルド日時',
     environment: '実行環境',
     envTauri: 'Tauri デスクトップ',
     envBrowser: 'ブラウザ',
@@ -536,11 +690,68 @@ export default {
     operatingSystem: 'オペレーティングシステム',
     architecture: 'アーキテクチャ',
     tauriVersion: 'Tauri バージョン',
+    exeVolume: 'プログラムのボリューム',
+    exeVolumeReadonly: '読み取り専用',
+    controllerInfo: 'コントローラーの詳細',
+    controllerInfoRefresh: '更新',
+    controllerStatus: '状態',
+    controllerStatusValue: {
+      connected: '接続済み',
+      disconnected: '未接続',
+      none: 'コントローラー未作成',
+    },
+    controllerType: '種類',
+    controllerTarget: '接続先',
+    controllerScreencap: 'スクリーンショット方式',
+    controllerInput: '入力方式',
+    controllerResolution: '解像度',
+    controllerShortSide: 'スクリーンショットの短辺',
+    cpuFeatures: 'CPU 命令セット',
+    cpuFeaturesMissing: '（{{features}} がないためタスクを実行できません）',
     pathInfo: 'パス情報',
     cwd: '現在の作業ディレクトリ',
     exeDir: '実行ファイルのディレクトリ',
     webview2Dir: 'WebView2 ディレクトリ',
     webview2System: 'システム',
+    webview2Shared: 'WebView2 ランタイムを共有',
+    webview2SharedHint:
+      '独立ランタイムを %LOCALAPPDATA%\\MXU にインストールし、複数の MXU で共有して容量を節約します。再起動後に反映されます',
+    webviewGpuDisabled: 'WebView2 の GPU アクセラレーションを無効化',
+    webviewGpuDisabledHint:
+      '画面の乱れ・白画面・ちらつきが起きる場合に有効にするとソフトウェア描画に切り替わります。再起動後に反映されます',
+    adbPath: 'ADB パス',
+    adbPathHint:
+      'エミュレーター付属の adb とシステムの adb が競合して接続できない場合に、使用する adb を指定します',
+    adbPathPlaceholder: '空欄の場合は自動検出',
+    adbPathSaved: '指定した adb を使用します（{{count}} 台のデバイスを検出）',
+    adbPathInvalid: '指定した adb は使用できません：{{error}}',
+    toolkitConfig: 'デバイス検索ディレクトリ',
+    toolkitConfigHint:
+      'マイナーなエミュレーターやカスタムインストール先でデバイスが見つからない場合、adb のあるディレクトリまたはエミュレーターのインストール先を追加します（1 行に 1 つ）',
+    extraAdbDirs: '追加の adb ディレクトリ',
+    extraAdbDirsPlaceholder: 'adb のあるディレクトリ、または adb 実行ファイルのパス',
+    emulatorDirs: 'エミュレーターのインストール先',
+    emulatorDirsPlaceholder: 'サブディレクトリから adb を検索します',
+    toolkitConfigSaved: '保存しました。使用可能な adb が {{count}} 個見つかりました',
+    toolkitConfigFailed: 'デバイス検索ディレクトリの保存に失敗しました：{{error}}',
+    processMonitor: 'リソースモニター',
+    processMonitorHint:
+      'MXU と agent プロセスの CPU・メモリ使用量を表示します。動作が重いときの調査やパフォーマンス問題の報告に使用します',
+    processMonitorProcess: 'プロセス',
+    processMonitorMemory: 'メモリ',
+    processMonitorThreads: 'スレッド',
+    processMonitorUptime: '稼働時間',
+    processMonitorAgent: 'Agent（{{name}}）',
+    processMonitorCopy: 'データをコピー',
+    processMonitorCopied: 'リソース使用量をコピーしました',
+    agentResourceLimits: 'Agent のリソース制限',
+    agentResourceLimitsHint:
+      'Job Object で agent プロセスを制限します（0 は無制限）。MXU 終了時にすべての agent プロセスを終了します',
+    agentMemoryLimit: 'agent ごとのメモリ上限（MB）',
+    agentCpuLimit: '全 agent 合計の CPU 割り当て（%）',
+    updateCopyConcurrency: '更新時の並列コピー数',
+    updateCopyConcurrencyHint:
+      '差分更新でファイルを同時にコピーするスレッド数。0 は CPU コア数に合わせます。HDD 上では自動的に逐次コピーになります',
     resetWindowSize: 'ウィンドウサイズをリセット',
     openConfigDir: '設定フォルダを開く',
     openLogDir: 'ログフォルダを開く',
@@ -555,6 +766,106 @@ export default {
     saveDraw: 'デバッグ画像を保存',
     saveDrawHint:
       '認識と操作のデバッグ画像をログフォルダに保存します（再起動後は自動的にオフになります）',
+    verboseLog: '詳細ログ',
+    verboseLogHint: 'デバッグレベルの詳細ログを記録します。オフにすると情報レベル以上のみ記録されます（即時反映）',
+    eventLog: '構造化イベントログ',
+    eventLogHint:
+      'タスクの開始/終了、認識結果、エラーを JSONL 形式で debug/logs/events.jsonl にも書き込み、スクリプトでの分析に利用できます',
+    failureScreenshot: 'タスク失敗時のスクリーンショット',
+    failureScreenshotHint:
+      'タスク失敗時にその時点のスクリーンショット（大きい場合は縮小）をログに添付し、止まった画面の特定や問題報告に役立てます',
+    actionTrace: 'クリック軌跡の可視化',
+    actionTraceHint:
+      'タスクがクリック・スワイプするたびに座標イベントを送信し、スクリーンショットのプレビュー上に実際のタップ位置と軌跡を表示します',
+    breakpoints: 'Pipeline ブレークポイント',
+    breakpointsHint:
+      '現在のインスタンスがこれらのノードを実行する前に一時停止し、画面を確認できます',
+    breakpointsPlaceholder: 'ノード名（カンマ区切り）',
+    dryRun: 'Dry-run モード',
+    dryRunHint:
+      '現在のインスタンスで開始するタスクはスクリーンショットと認識のみ行い、アクションはスキップしてログに記録します',
+    speed: '操作速度',
+    speedHint:
+      '現在のインスタンスで以降に開始するタスクの各アクション前後の待機時間を調整します（0.25～4、既定 1）。エミュレーターの反応が遅くタップ漏れがある場合は下げ、高性能な端末では上げると時間を短縮できます',
+    captureMode: 'キャプチャ遮蔽対策',
+    captureModeHint:
+      'Win32 コントローラーのみ：前面モードは認識のたびにウィンドウを前面に出します。PrintWindow モードは再接続後に有効になります',
+    captureModeDefault: 'デフォルト',
+    captureModeForeground: '前面に表示',
+    captureModePrintWindow: 'PrintWindow',
+    minimizedPolicy: '最小化時の処理',
+    minimizedPolicyHint:
+      'Win32 コントローラーのみ：対象ウィンドウが最小化されるとスクリーンショットが失敗または黒画面になります。ウィンドウが復元されるまでタスクを一時停止するか、自動で復元できます（前面には出しません）',
+    minimizedPolicyNotify: '通知のみ',
+    minimizedPolicyPause: 'タスクを一時停止',
+    minimizedPolicyRestore: 'ウィンドウを自動復元',
+    frameDedup: 'スクリーンショットの重複排除',
+    frameDedupHint:
+      '画面が変化しない間は認識頻度を徐々に下げて CPU を節約し、画面が変わるとすぐに元に戻します（現在のインスタンス）',
+    frameDedupSimilarity: '類似度のしきい値',
+    unknownScreens: '未知の画面の収集',
+    unknownScreensHint:
+      '認識が複数ラウンド連続ですべて失敗したとき、現在の画面のスクリーンショットとコンテキストを unknown_screens フォルダに保存します（似た画面は 1 枚のみ、最大 200 枚）。まとめてリソース開発者に送ると認識の追加に役立ちます',
+    unknownScreensThreshold: '連続失敗回数',
+    unknownScreensCount: '{{count}} 枚収集済み',
+    unknownScreensOpen: 'フォルダを開く',
+    unknownScreensExport: 'まとめる',
+    unknownScreensExported: '未知の画面をまとめました',
+    unknownScreensExportFailed: '未知の画面をまとめられませんでした：{{error}}',
+    preprocess: 'スクリーンショットの前処理',
+    preprocessHint:
+      '認識前にスクリーンショットの明るさ・コントラスト・ガンマを調整し、暗い画面や色かぶりした画面の認識を安定させます（現在のインスタンス）',
+    preprocessBrightness: '明るさ',
+    preprocessContrast: 'コントラスト',
+    preprocessGamma: 'ガンマ',
+    preprocessNeedsReconnect:
+      'スクリーンショットの前処理はコントローラーの再接続後に有効になります',
+    taskRetry: 'タスク失敗時のリトライ',
+    taskRetryHint:
+      'このインスタンスのタスクが失敗したとき自動でリトライし、回数を使い切っても失敗した場合のみ終了します',
+    taskRetryMaxRetries: 'リトライ回数',
+    taskRetryInterval: 'リトライ間隔（秒）',
+    taskRetryReconnect: 'リトライ前にコントローラーを再接続',
+    taskRetryRestartApp: 'リトライ前にアプリを再起動',
+    taskRetryRestartAppPlaceholder: 'パッケージ名または Activity、空欄で再起動しない',
+    taskRetryFailed: 'リトライ設定に失敗しました：{{error}}',
+    watchdog: '最大実行時間ウォッチドッグ',
+    watchdogHint: '現在のインスタンスのタスクが上限を超えて実行されると自動停止します',
+    watchdogMaxMinutes: '最大実行時間（分）',
+    watchdogCountPaused: 'ブレークポイントで停止中の時間も計上',
+    watchdogFailed: 'ウォッチドッグの設定に失敗しました：{{error}}',
+    benchmark: 'パフォーマンステスト',
+    benchmarkHint:
+      '現在のインスタンスのスクリーンショットと認識の所要時間を測定し、結果をログフォルダに出力します',
+    benchmarkScreencap: 'スクリーンショット',
+    benchmarkFailed: '失敗',
+    benchmarkOpenResult: '結果ファイルを開く',
+    recordClip: '録画',
+    annotatedScreenshot: '注釈付きスクリーンショット',
+    annotatedScreenshotHint:
+      '現在のスクリーンショットに直近の認識枠とスコアを描画して保存します（不具合報告に便利です）',
+    annotatedScreenshotSaved: '注釈付きスクリーンショットを保存しました（認識結果 {{count}} 件）',
+    annotatedScreenshotFailed: '注釈付きスクリーンショットの保存に失敗しました：{{error}}',
+    issueBundle: '問題パッケージ',
+    issueBundleHint:
+      '認識失敗時のスクリーンショット、認識詳細、ノード設定、override、ログ、システム情報を説明ファイル付きでエクスポート',
+    issueBundleExported: '問題パッケージをエクスポートしました',
+    issueBundleFailed: '問題パッケージのエクスポートに失敗しました：{{error}}',
+    resourceManifestResetButton: 'リソース検証リストを再構築',
+    resourceManifestResetHint:
+      'リソース変更後、現在のファイルを基準に検証リストを再生成します（リソースパッケージ同梱のリストは対象外）',
+    resourceManifestReset:
+      'リソース検証リストを削除しました。次回実行時に現在のファイルから再生成されます',
+    resourceManifestResetFailed: 'リソース検証リストの再構築に失敗しました：{{error}}',
+    recordClipHint:
+      '現在のインスタンスのスクリーンショットを 2 FPS で録画し、停止時にログディレクトリの clips フォルダへ書き出します',
+    clipExportGif: '停止して GIF を書き出す',
+    clipExportMp4: '停止して MP4 を書き出す',
+    clipSummary: '{{frames}} フレーム · {{seconds}} 秒',
+    clipDropped: '録画の上限を超えたため、古い {{count}} フレームを破棄しました',
+    clipMp4Fallback: '使用可能な ffmpeg が見つからないため、GIF で書き出しました',
+    clipOpenResult: '録画ファイルを開く',
+    openFileFailed: '開けませんでした：{{error}}',
     tcpCompatMode: '通信互換モード',
     tcpCompatModeHint:
       'タスク開始後にアプリがすぐにクラッシュする場合は有効にしてください。この場合のみ使用し、それ以外は性能に影響します',
@@ -598,6 +909,8 @@ export default {
   // インスタンス
   instance: {
     defaultName: '設定',
+    duplicateName:
+      '「{{name}}」という名前の設定がすでにあります。区別しやすいよう別の名前をおすすめします',
   },
 
   // 接続パネル
@@ -609,6 +922,7 @@ export default {
   dashboard: {
     title: 'ダッシュボード',
     toggle: 'ダッシュボード表示',
+    stopAll: 'すべて停止',
     exit: 'ダッシュボードを終了',
     instances: '件のインスタンス',
     noInstances: 'インスタンスがありません',
@@ -673,6 +987,10 @@ export default {
     viewDetails: '詳細を表示',
     noDownloadUrl:
       'ダウンロード URL がありません。CDK を入力するか、ネットワーク環境を確認してください',
+    networkUnavailable: 'ネットワークに接続できません',
+    networkUnavailableHint:
+      'ダウンロードサーバーに接続できません。ネットワークやプロキシ設定を確認するか、ブラウザで手動ダウンロードしてください',
+    manualDownload: '手動ダウンロード',
     openFolder: 'フォルダを開く',
     retry: '再試行',
     preparingDownload: 'ダウンロードを準備中...',
@@ -751,6 +1069,13 @@ export default {
     startedAt: '開始時刻: {{time}}',
     deletePolicyTitle: 'スケジュールを削除',
     deletePolicyConfirm: 'スケジュール「{{name}}」を削除してもよろしいですか？',
+    queueLoop: 'ループ実行',
+    queueLoopRounds: 'ラウンド数（0 で無限）',
+    queueLoopInterval: 'ラウンド間隔（秒）',
+    queueLoopHint:
+      'タスクキューを指定したラウンド数だけ繰り返します。停止すると現在のタスクでループを終了します',
+    queueLoopSkipped:
+      'タスクリストに終了時の特殊タスクが含まれるため、今回は 1 ラウンドのみ実行します',
     // Date.getDay() に対応: 0=日, 1=月, ..., 6=土
     weekdays: ['日', '月', '火', '水', '木', '金', '土'],
   },
@@ -763,6 +1088,20 @@ export default {
     taskNotFound: 'タスクが見つかりません',
     controllerNotFound: 'コントローラーが見つかりません',
     resourceNotFound: 'リソースパックが見つかりません',
+    codes: {
+      INSTANCE_NOT_FOUND: 'インスタンスが見つかりません',
+      CONTROLLER_NOT_CONNECTED: 'コントローラーが未接続です',
+      CONTROLLER_CONNECT_FAILED: 'コントローラーの接続に失敗しました',
+      RESOURCE_NOT_LOADED: 'リソースが未読み込みです',
+      RESOURCE_LOAD_FAILED: 'リソースの読み込みに失敗しました',
+      TASK_RUN_FAILED: 'タスクの送信に失敗しました',
+      TASK_PREEMPTING: '別のタスクを割り込み実行中です。しばらくしてから再試行してください',
+      DOWNLOAD_FAILED: 'ダウンロードに失敗しました',
+      DOWNLOAD_CANCELLED: 'ダウンロードがキャンセルされました',
+      UPDATE_FAILED: 'アップデートのインストールに失敗しました',
+      INVALID_ARGUMENT: '無効な引数です',
+      INTERNAL: '内部エラー',
+    },
   },
 
   // コンテキストメニュー
@@ -782,9 +1121,11 @@ export default {
     exportConfig: '設定をエクスポート',
     exportToClipboard: 'クリップボードへエクスポート',
     exportToTxt: 'txt ファイルとしてエクスポート',
+    exportToShareCode: '共有コードをコピー',
     importConfig: '設定をインポート',
     importFromClipboard: 'クリップボードからインポート',
     importFromTxt: 'txt ファイルからインポート',
+    importFromShareCode: '共有コードからインポート',
 
     // 前処理プログラムのコンテキストメニュー
     duplicateAction: '複製',
@@ -808,6 +1149,8 @@ export default {
     moveToBottom: '最下部に移動',
     expandOptions: 'オプションを展開',
     collapseOptions: 'オプションを折りたたむ',
+    editSkipCondition: 'スキップ条件を設定',
+    validateTask: 'タスク設定を検証',
     selectAll: 'すべて選択',
     deselectAll: 'すべて解除',
     expandAllTasks: 'すべて展開',
@@ -821,6 +1164,7 @@ export default {
     fullscreen: '全画面表示',
     saveScreenshot: 'スクリーンショットを保存',
     copyScreenshot: 'スクリーンショットをコピー',
+    viewLastFrame: '最後のフレームを表示',
 
     // 接続パネルのコンテキストメニュー
     refreshDevices: 'デバイス一覧を更新',
@@ -888,6 +1232,16 @@ export default {
     desktopHint: 'より安定した環境には、デスクトップ版をご利用ください',
   },
 
+  // ブレークポイントデバッグ
+  breakpoint: {
+    title: 'ブレークポイントに到達 - {{instance}}',
+    pausedAt: 'タスクを一時停止しました。次に実行するノード：',
+    noScreenshot: 'スクリーンショットがありません',
+    recognition: '認識結果',
+    clearAndContinue: 'すべてのブレークポイントを削除して続行',
+    continue: '続行',
+  },
+
   // パス警告
   badPath: {
     title: 'プログラムの場所が正しくありません',
@@ -899,15 +1253,38 @@ export default {
       'プログラムは一時フォルダから実行されています。閉じると消える可能性があります。まずアーカイブをフォルダに解凍してから、そこからプログラムを実行してください。',
     hint: 'ヒント：「D:\\MaaXXX」のような専用フォルダに解凍することをお勧めします。管理しやすくするため、デスクトップやダウンロードフォルダは避けてください。',
     exit: '終了',
+    networkTitle: 'プログラムがネットワークドライブ上にあります',
+    networkDescription:
+      'ネットワークドライブではファイルロック、更新失敗、ログの書き込み失敗などの問題が起きやすくなります。フォルダごとローカルの固定ディスクに移動してください。',
+    removableTitle: 'プログラムがリムーバブルドライブ上にあります',
+    removableDescription:
+      'リムーバブルドライブはいつでも切断される可能性があり、設定の破損や更新失敗の原因になります。フォルダごとローカルの固定ディスクに移動してください。',
+    readonlyTitle: 'プログラムのフォルダに書き込めません',
+    readonlyDescription: '設定・ログ・更新はすべてプログラムフォルダへの書き込みが必要です。フォルダごと書き込み可能なローカルフォルダに移動してください。',
+    cloudTitle: 'プログラムが OneDrive の同期フォルダにあります',
+    cloudDescription: 'クラウド同期は使用中のファイルをロックするため、更新やログの書き込みに失敗しやすくなります。同期されないローカルフォルダに移動してください。',
+    continue: 'このまま続ける',
   },
   // プロキシ設定
   proxy: {
     title: 'ネットワークプロキシ',
     url: 'プロキシ URL',
     urlPlaceholder: '例：http://127.0.0.1:7890',
-    urlHint: 'HTTP/SOCKS5 をサポート、空欄でプロキシを無効化',
+    urlHint: 'HTTP/SOCKS5 をサポート、空欄でシステムプロキシを使用',
+    systemDetected: 'システムプロキシを使用中：{{url}}',
+    systemNone: 'システムプロキシが検出されないため、直接接続します',
     urlHintDisabled: 'Mirror醤 CDK が入力されているため、プロキシは無効です',
     invalid: 'プロキシ URL の形式が正しくありません',
     examples: '形式の例',
   },
+  cpuUnsupported: {
+    title: 'サポートされていない CPU',
+    message: 'お使いの CPU は {{features}} に対応していないため実行できません',
+    description:
+      '認識と自動操作に使う計算ライブラリは上記の命令セットを必要とし、このコンピューターでは実行時にクラッシュします。そのため今回は MaaFramework を読み込みません。',
+    hint:
+      '2011 年以降のほとんどの Intel / AMD プロセッサは AVX に対応しています。CPU が対応している場合は、仮想マシンや BIOS で AVX が無効になっていないか確認してください。',
+    exit: '終了',
+    continue: 'このまま開く（タスクは実行できません）',
+  },
 };
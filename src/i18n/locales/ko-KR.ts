@@ -98,8 +98,34 @@ export default {
     hotkeysGlobalOnlyStart: '전역 모드에서는 시작만 작동',
     minimizeToTray: '닫을 때 트레이로 최소화',
     minimizeToTrayHint: '닫기 버튼을 클릭하면 종료하지 않고 시스템 트레이에 숨깁니다',
+    autoShowOverlay: '실행 중 로그 오버레이 표시',
+    autoShowOverlayHint:
+      '작업 시작 시 로그 오버레이를 자동으로 표시하고, 모든 작업이 끝나고 몇 초 후 자동으로 닫습니다(수동으로 연 오버레이는 제외)',
+    programTrust: '외부 프로그램 신뢰',
+    programTrustHint:
+      '리소스 설정의 사전 프로그램, pretask, MXU_LAUNCH 실행 전에 프로그램 경로를 검증',
+    programTrustAll: '모든 프로그램 신뢰',
+    programTrustConfirm: '목록에 없으면 확인',
+    programTrustStrict: '신뢰 목록의 프로그램만 실행',
+    programTrustList: '신뢰하는 프로그램',
+    programTrustListEmpty: '신뢰하는 프로그램이 없습니다',
+    programTrustRemove: '신뢰 목록에서 제거',
+    taskReport: '작업 보고서',
+    taskReportHint: '대기열이 끝나면 소요 시간, 결과, 스크린샷을 요약한 이미지를 debug 폴더에 저장합니다',
+    taskReportScreenshots: '보고서 스크린샷',
+    taskReportScreenshotsAll: '모든 작업',
+    taskReportScreenshotsFailed: '실패한 작업만',
+    taskReportScreenshotsNone: '포함 안 함',
+    completionWebhook: '완료 시 Webhook 전송',
+    completionWebhookHint:
+      '작업 대기열이 모두 끝나면 실행 요약 JSON(작업별 결과, 소요 시간, 오류 여부)을 URL로 POST합니다',
+    completionWebhookPlaceholder: '전체 URL을 입력하세요 (예: https://example.com/hook)',
     autoStart: '시작 시 자동 실행',
     autoStartHint: '시스템 부팅 시 이 애플리케이션을 자동으로 시작합니다',
+    autoStartDelay: '시작 지연',
+    autoStartDelayHint:
+      '시스템 시작 시 자동 실행되면 지정한 초만큼 기다린 후 MaaFramework를 불러오고 기기에 연결합니다. 0이면 지연하지 않습니다',
+    autoStartDelayUnit: '초',
     autoStartInstance: '시작 후 자동 실행',
     autoStartInstanceHint:
       '시작 후 자동으로 활성화하고 작업을 실행할 구성을 선택합니다. 다른 구성의 예약 작업은 정상적으로 실행됩니다',
@@ -157,6 +183,11 @@ export default {
         '활성화하면 cmd /c로 프로그램을 실행하여 현재 프로세스 트리에서 분리합니다. 일부 게임은 프로세스 트리를 감지할 수 있습니다 (Windows 전용)',
       cmdYes: 'cmd /c로 실행',
       cmdNo: '직접 서브프로세스로 실행',
+      hideLabel: '콘솔 창 숨기기',
+      hideDescription:
+        '활성화하면 명령줄 프로그램 실행 시 검은 콘솔 창이 표시되지 않습니다. GUI 프로그램은 영향을 받지 않습니다 (Windows 전용)',
+      hideYes: '창 숨기기',
+      hideNo: '창 표시',
     },
     notify: {
       label: '💬 시스템 알림',
@@ -271,6 +302,12 @@ export default {
     incompatibleController: '현재 컨트롤러에서 지원되지 않음',
     incompatibleResource: '현재 리소스에서 지원되지 않음',
     supportedControllers: '지원 대상: {{controllers}}',
+    // 건너뛰기 조건
+    skipCondition: '건너뛰기 조건',
+    skipConditionPlaceholder: '예: hour >= 22 || last_result == "failed"',
+    skipConditionHint:
+      '작업 시작 시 식이 참이면 건너뜁니다. hour, minute, weekday, day, month, round, last_result, var.변수명 사용 가능',
+    skipConditionInvalid: '잘못된 식: {{error}}',
   },
 
   // 옵션
@@ -298,6 +335,9 @@ export default {
     useCmd: 'cmd로 실행',
     useCmdHint:
       '활성화하면 cmd /c로 프로그램을 실행하여 현재 프로세스 트리에서 분리합니다. 일부 게임은 프로세스 트리를 감지할 수 있습니다 (Windows 전용)',
+    hideWindow: '콘솔 창 숨기기',
+    hideWindowHint:
+      '활성화하면 명령줄 프로그램 실행 시 검은 콘솔 창이 표시되지 않습니다. 출력을 확인해야 하면 비활성화하세요 (Windows 전용)',
     preActionSkipped: '전처리 프로그램 {{name}} 이(가) 실행 중이므로 건너뜁니다',
     waitingForDevice: '장치 준비 대기 중...',
     waitingForWindow: '윈도우 준비 대기 중...',
@@ -322,6 +362,8 @@ export default {
     pretaskExitCode: '사전 작업 종료 코드: {{code}}',
     pretaskFailed: '사전 작업 실행 실패: {{error}}',
     preActionConnectDelay: '{{seconds}}초 후 연결합니다...',
+    resourceIntegrityWarning:
+      '리소스 파일 {{missing}}개 누락, {{modified}}개 변경됨({{files}}). 백신 프로그램에 의해 삭제되었거나 손상되었을 수 있으니 리소스를 다시 다운로드하세요',
     autoPreActionName: '▶️ {{name}} 실행',
     autoPreActionAdded: '전처리 프로그램 자동 추가: {{name}} (기본적으로 비활성화)',
     removeConfirmTitle: '전처리 프로그램 삭제',
@@ -362,6 +404,13 @@ export default {
     exportShareHint: '{{projectName}} 의 「{{tabName}}」 설정 공유해요~',
     exportShareFooter:
       '👆 이 메시지를 복사해서 {{projectName}} 에서 새 탭을 만들고 「설정 가져오기」를 누르면 바로 사용할 수 있어요',
+    shareCodeCopied: '공유 코드가 클립보드에 복사되었습니다',
+    shareCodeExportFailed: '공유 코드 생성 실패: {{error}}',
+    shareCodeImported: '공유 코드에서 작업 {{count}}개를 추가했습니다',
+    shareCodeImportedPartial:
+      '공유 코드에서 작업 {{count}}개를 추가했습니다. 현재 프로젝트에 없는 작업 {{skipped}}개는 건너뛰었습니다',
+    shareCodeNoTasks: '공유 코드에 현재 프로젝트에서 사용할 수 있는 작업이 없습니다',
+    shareCodeImportFailed: '공유 코드 가져오기 실패: {{error}}',
   },
 
   // 컨트롤러
@@ -392,6 +441,26 @@ export default {
     savedWindowNotFound:
       '이전 윈도우를 찾을 수 없습니다. 연결을 확인하거나 다른 윈도우를 선택하세요',
     connectedLog: '컨트롤러에 연결되었습니다 [{{name}}]',
+    resolutionMismatch:
+      '스크린샷 화면 비율({{actual}})이 리소스가 기대하는 값과 달라 인식이 실패할 수 있습니다. 에뮬레이터 해상도를 {{expected}}(으)로 설정하세요',
+    usbDevice: 'USB 실기기',
+    usbUnauthorized: '미승인',
+    usbUnauthorizedHint:
+      'USB 디버깅이 승인되지 않았습니다. 휴대폰에서 "허용"을 누른 후 기기 목록을 새로고침하세요',
+    usbOfflineHint:
+      '기기 상태가 {{state}}입니다. USB 케이블을 다시 연결한 후 기기 목록을 새로고침하세요',
+    usbDeviceHint:
+      'USB 실기기에 연결됨: 실기기 스크린샷은 느린 편입니다. 캡처가 실패하면 다른 스크린샷 방식을 시도하고, 해상도가 리소스와 맞지 않으면 휴대폰의 디스플레이 설정을 조정하세요',
+    emulatorLaunch: '연결 전 에뮬레이터 실행',
+    emulatorLaunchHint:
+      '연결 시 대상 기기나 창이 없으면 이 프로그램을 실행하고 준비될 때까지 기다립니다',
+    emulatorLaunchProgram: '에뮬레이터 프로그램',
+    emulatorLaunchArgs: '실행 인수',
+    emulatorLaunchAdbAddress: 'ADB 주소',
+    emulatorLaunchAdbAddressHint: '에뮬레이터 실행 후 기다릴 기기 주소',
+    emulatorLaunchTimeout: '대기 시간 초과',
+    emulatorLaunchSettle: '준비 후 대기',
+    emulatorLaunchSeconds: '초',
   },
 
   // 리소스
@@ -399,6 +468,7 @@ export default {
     title: '리소스 팩',
     selectResource: '리소스 팩 선택',
     loading: '리소스 로딩 중...',
+    loadingProgress: '리소스 로드 중 ({{done}}/{{total}})...',
     loaded: '리소스 로드됨',
     loadFailed: '리소스 로딩에 실패했습니다',
     loadResource: '리소스 로드',
@@ -409,6 +479,13 @@ export default {
     incompatibleController: '현재 컨트롤러에서 지원되지 않음',
   },
 
+  // 백엔드 상태 확인
+  health: {
+    backendUnresponsive:
+      '백엔드가 오랫동안 응답하지 않아 멈췄을 수 있습니다. 로그를 내보낸 후 프로그램을 다시 시작해 보세요',
+    backendRecovered: '백엔드 응답이 복구되었습니다',
+  },
+
   // MaaFramework
   maa: {
     notInitialized: 'MaaFramework가 초기화되지 않았습니다',
@@ -429,6 +506,12 @@ export default {
     fullscreen: '전체 화면',
     exitFullscreen: '전체 화면 종료',
     clickHint: '화면을 클릭하면 기기에 탭을 전송합니다',
+    noLastFrame: '아직 작업 종료 시 스크린샷이 없습니다',
+    lastFrameLabel: '{{status}} · {{time}}',
+    lastFrameStatus: {
+      succeeded: '작업 성공',
+      failed: '작업 실패',
+    },
     // 프레임률 설정
     frameRate: {
       title: '스크린샷 프레임률',
@@ -443,11 +526,25 @@ export default {
 
   // 로그
   logs: {
+    section: {
+      succeeded: '성공 · {{count}}줄',
+      failed: '실패 · {{count}}줄',
+      running: '실행 중 · {{count}}줄',
+    },
     title: '실행 로그',
     clear: '지우기',
+    openOverlay: '로그 오버레이 열기',
+    closeOverlay: '오버레이 닫기',
+    overlayPreview: '화면 미리보기',
+    overlayLogs: '로그',
+    overlayNoInstance: '미리 볼 인스턴스가 없습니다',
+    overlayWaitingFrame: '화면 대기 중…',
+    openOverlayFailed: '로그 오버레이를 열지 못했습니다: {{error}}',
     autoscroll: '자동 스크롤',
     noLogs: '로그가 없습니다',
     copyAll: '모두 복사',
+    exportInstanceLogs: '현재 인스턴스 로그 내보내기',
+    copyFailed: '복사에 실패했습니다. 텍스트를 선택하여 직접 복사하세요',
     showMoreLogs: '로그 더 보기',
     expand: '상단 패널 펼치기',
     collapse: '상단 패널 접기',
@@ -469,6 +566,8 @@ export default {
       taskStarting: '작업 시작: {{name}}',
       taskSucceeded: '작업 완료: {{name}}',
       taskFailed: '작업 실패: {{name}}',
+      taskInterrupted: '작업 중단됨, 이후 다시 실행: {{name}}',
+      taskReportGenerated: '작업 보고서 생성됨: {{path}}',
       stopTask: '작업 중지',
       // 예약 메시지
       scheduleStarting: '예약 실행 시작 [{{policy}}] {{time}}',
@@ -482,6 +581,56 @@ export default {
       agentLogFloodWarning:
         'Agent가 로그 폭주 상태입니다. 성능 문제를 방지하기 위해 로그 표시를 일시 중지했습니다. 전체 로그는 로컬 로그 파일에서 확인할 수 있습니다.',
       agentLogFloodRecovered: 'Agent 로그 폭주가 완화되었습니다',
+      agentRestarting:
+        'Agent가 예기치 않게 종료되었습니다(종료 코드 {{code}}). 자동 재시작 중({{attempt}}/{{max}})',
+      agentRestarted: 'Agent가 재시작되었습니다. 남은 작업 {{remaining}}개를 계속 실행합니다',
+      agentRestartFailed: 'Agent 재시작 실패: {{error}}',
+      agentRestartGaveUp: 'Agent가 {{max}}번 충돌하여 자동 재시작을 중단했습니다',
+      taskPreempted:
+        '우선순위가 높은 작업 {{name}}이(가) 선점 실행되었습니다. {{count}}개 작업은 완료 후 재개됩니다',
+      taskRetrying: '작업 {{name}} 실패, 재시도 중 ({{attempt}}/{{max}})',
+      taskRetryFailed: '작업 {{name}} 재시도 실패: {{error}}',
+      taskSkippedByCondition:
+        '작업 {{name}}이(가) 건너뛰기 조건 {{expression}}을(를) 만족하여 건너뛰었습니다',
+      taskConditionInvalid: '작업 {{name}}의 건너뛰기 조건이 잘못되어 그대로 실행합니다: {{error}}',
+      taskValidationPassed:
+        '작업 {{name}} 검증 통과: 도달 가능한 노드 {{nodes}}개, 경고 {{warnings}}개',
+      taskValidationFailed: '작업 {{name}} 검증 실패: 오류 {{errors}}개',
+      taskValidationIssue: '작업 {{name}} 검증: {{location}} {{message}}',
+      taskValidationError: '작업 {{name}}을(를) 검증하지 못했습니다: {{error}}',
+      roundCompleted: '{{round}}/{{total}} 라운드 완료',
+      sectionStart: '── {{name}} 시작 ──',
+      sectionEnd: '── {{name}} 종료 ──',
+      sectionRound: '{{name}} ({{round}}라운드)',
+      pipelineHotUpdated: '작업 "{{name}}"의 설정이 업데이트되어 다음 노드부터 적용됩니다',
+      pipelineHotDeferred: '{{fields}}의 노드가 실행 중이며, 다음 실행 시 적용됩니다',
+      pipelineHotRestart:
+        '{{fields}}은(는) 이번 실행에 적용되지 않으므로 작업을 다시 시작해야 합니다',
+      pipelineHotNextRound:
+        '{{fields}}은(는) 이번 라운드에 적용되지 않고 다음 라운드부터 적용됩니다',
+      roundCompletedNext: '{{round}}/{{total}} 라운드 완료, {{seconds}}초 후 다음 라운드 시작',
+      watchdogTimeout: '실행 시간이 {{minutes}}분 제한을 초과하여 워치독이 작업을 중지했습니다',
+      windowMinimized:
+        '대상 창이 최소화되었습니다. 스크린샷이 실패하거나 검은 화면이 됩니다. 창을 복원하세요',
+      windowMinimizedPaused:
+        '대상 창이 최소화되었습니다. 다음 인식 전에 작업을 일시 정지하고 복원되면 자동으로 계속합니다',
+      windowMinimizedRestore: '대상 창이 최소화되었습니다. 다음 인식 전에 자동으로 복원합니다',
+      windowRestored: '대상 창이 복원되었습니다',
+      emulatorLaunching: '에뮬레이터 {{name}}을(를) 실행했습니다. 장치 준비를 기다리는 중...',
+      emulatorAlreadyRunning:
+        '에뮬레이터 {{name}}이(가) 이미 실행 중입니다. 장치 준비를 기다리는 중...',
+      emulatorReady: '에뮬레이터 준비 완료, 연결합니다',
+      emulatorTimeout: '에뮬레이터 {{name}} 준비 대기 시간이 초과되었습니다',
+      adbUnauthorized:
+        '기기 {{address}}의 디버깅이 승인되지 않았습니다. 기기에서 "USB 디버깅 허용"을 눌러 주세요',
+      adbReauthorized: '기기 {{address}}가 승인되었습니다',
+      adbReauthorizedReconnected: '기기 {{address}}가 다시 승인되어 자동으로 재연결했습니다',
+      adbAuthTimeout:
+        '기기 {{address}} 승인 대기 시간이 초과되었습니다. 기기에서 디버깅을 허용한 후 다시 연결해 주세요',
+      programUntrusted: '신뢰 목록에 없는 프로그램 실행을 차단했습니다 ({{source}}): {{program}}',
+      programRejected: '프로그램 실행을 거부했습니다 ({{source}}): {{program}}',
+      dryRunAction: '[dry-run] {{node}}에서 {{action}} 실행 예정 (인식 위치)',
+      dryRunActionAt: '[dry-run] {{node}}에서 {{action}} 실행 예정 ({{x}}, {{y}})',
       // 단축키
       hotkeyDetected: '단축키 감지: {{combo}} ({{action}})',
       hotkeyActionStart: '작업 시작',
@@ -524,6 +673,9 @@ export default {
     interfaceVersion: '{{name}} 버전',
     maafwVersion: 'maafw 버전',
     mxuVersion: 'mxu 버전',
+    agentVersion: 'agent 버전',
+    buildCommit: '빌드 커밋',
+    buildTime: '빌드 시간',
     environment: '실행 환경',
     envTauri: 'Tauri 데스크톱',
     envBrowser: '브라우저',
@@ -531,11 +683,68 @@ export default {
     operatingSystem: '운영 체제',
     architecture: '시스템 아키텍처',
     tauriVersion: 'Tauri 버전',
+    exeVolume: '프로그램 볼륨',
+    exeVolumeReadonly: '읽기 전용',
+    controllerInfo: '컨트롤러 상세',
+    controllerInfoRefresh: '새로고침',
+    controllerStatus: '상태',
+    controllerStatusValue: {
+      connected: '연결됨',
+      disconnected: '연결 안 됨',
+      none: '컨트롤러가 아직 생성되지 않음',
+    },
+    controllerType: '유형',
+    controllerTarget: '연결 대상',
+    controllerScreencap: '스크린샷 방식',
+    controllerInput: '입력 방식',
+    controllerResolution: '해상도',
+    controllerShortSide: '스크린샷 짧은 변',
+    cpuFeatures: 'CPU 명령어 세트',
+    cpuFeaturesMissing: '({{features}} 미지원으로 작업을 실행할 수 없음)',
     pathInfo: '경로 정보',
     cwd: '현재 작업 디렉토리',
     exeDir: '실행 파일 디렉토리',
     webview2Dir: 'WebView2 디렉토리',
     webview2System: '시스템',
+    webview2Shared: 'WebView2 런타임 공유',
+    webview2SharedHint:
+      '독립 런타임을 %LOCALAPPDATA%\\MXU에 설치하여 여러 MXU에서 공유해 공간을 절약합니다. 재시작 후 적용됩니다',
+    webviewGpuDisabled: 'WebView2 GPU 가속 비활성화',
+    webviewGpuDisabledHint:
+      '화면 깨짐, 흰 화면 또는 깜빡임이 발생하면 켜서 소프트웨어 렌더링을 사용합니다. 재시작 후 적용됩니다',
+    adbPath: 'ADB 경로',
+    adbPathHint:
+      '에뮬레이터 내장 adb와 시스템 adb가 충돌해 연결에 실패할 때 사용할 adb 실행 파일을 지정합니다',
+    adbPathPlaceholder: '비워 두면 자동으로 찾습니다',
+    adbPathSaved: '지정한 adb를 사용합니다({{count}}개 기기 발견)',
+    adbPathInvalid: '지정한 adb를 사용할 수 없습니다: {{error}}',
+    toolkitConfig: '장치 검색 디렉터리',
+    toolkitConfigHint:
+      '잘 알려지지 않은 에뮬레이터나 사용자 지정 설치 위치에서 장치를 찾지 못할 때 adb 디렉터리 또는 에뮬레이터 설치 디렉터리를 추가하세요 (한 줄에 하나)',
+    extraAdbDirs: '추가 adb 디렉터리',
+    extraAdbDirsPlaceholder: 'adb가 있는 디렉터리 또는 adb 실행 파일 경로',
+    emulatorDirs: '에뮬레이터 설치 디렉터리',
+    emulatorDirsPlaceholder: '하위 디렉터리에서 adb를 검색합니다',
+    toolkitConfigSaved: '저장됨, 사용 가능한 adb {{count}}개를 찾았습니다',
+    toolkitConfigFailed: '장치 검색 디렉터리 저장 실패: {{error}}',
+    processMonitor: '리소스 모니터',
+    processMonitorHint:
+      'MXU와 agent 프로세스의 CPU, 메모리 사용량을 표시합니다. 느려짐 원인 조사나 성능 문제 보고 시 사용하세요',
+    processMonitorProcess: '프로세스',
+    processMonitorMemory: '메모리',
+    processMonitorThreads: '스레드',
+    processMonitorUptime: '실행 시간',
+    processMonitorAgent: 'Agent ({{name}})',
+    processMonitorCopy: '데이터 복사',
+    processMonitorCopied: '리소스 사용량을 복사했습니다',
+    agentResourceLimits: 'Agent 리소스 제한',
+    agentResourceLimitsHint:
+      'Job Object로 agent 프로세스를 제한합니다(0은 무제한). MXU 종료 시 모든 agent 프로세스가 종료됩니다',
+    agentMemoryLimit: 'agent당 메모리 상한(MB)',
+    agentCpuLimit: '전체 agent CPU 할당량(%)',
+    updateCopyConcurrency: '업데이트 병렬 복사 수',
+    updateCopyConcurrencyHint:
+      '증분 업데이트 시 동시에 파일을 복사할 스레드 수입니다. 0은 CPU 코어 수를 사용하며, HDD에서는 자동으로 순차 복사합니다',
     resetWindowSize: '창 크기 초기화',
     openConfigDir: '설정 폴더 열기',
     openLogDir: '로그 폴더 열기',
@@ -550,6 +759,105 @@ export default {
     saveDraw: '디버그 이미지 저장',
     saveDrawHint:
       '인식 및 작업의 디버그 이미지를 로그 폴더에 저장합니다 (재시작 후 자동으로 비활성화됨)',
+    verboseLog: '상세 로그',
+    verboseLogHint: '디버그 수준의 상세 로그를 기록합니다. 끄면 정보 수준 이상만 기록됩니다 (즉시 적용)',
+    eventLog: '구조화된 이벤트 로그',
+    eventLogHint:
+      '작업 시작/종료, 인식 결과 및 오류를 JSONL 형식으로 debug/logs/events.jsonl에도 기록하여 스크립트로 분석할 수 있습니다',
+    failureScreenshot: '작업 실패 시 스크린샷',
+    failureScreenshotHint:
+      '작업이 실패하면 당시 스크린샷(큰 경우 축소)을 로그에 첨부하여 멈춘 화면을 확인하고 문제를 보고하는 데 도움을 줍니다',
+    actionTrace: '클릭 궤적 시각화',
+    actionTraceHint:
+      '작업이 클릭·스와이프할 때마다 좌표 이벤트를 전송하여 스크린샷 미리보기에 실제 탭 위치와 궤적을 표시합니다',
+    breakpoints: 'Pipeline 중단점',
+    breakpointsHint:
+      '현재 인스턴스가 이 노드를 실행하기 전에 일시 정지하여 화면을 확인할 수 있습니다',
+    breakpointsPlaceholder: '노드 이름, 쉼표로 구분',
+    dryRun: 'Dry-run 모드',
+    dryRunHint:
+      '현재 인스턴스에서 시작하는 작업은 스크린샷과 인식만 수행하고, 동작은 건너뛰고 로그에 기록합니다',
+    speed: '작업 속도',
+    speedHint:
+      '현재 인스턴스에서 이후 시작하는 작업의 각 동작 전후 대기 시간을 조절합니다(0.25~4, 기본값 1). 에뮬레이터 반응이 느려 탭이 누락되면 낮추고, 성능이 좋은 기기에서는 높여 시간을 절약하세요',
+    captureMode: '캡처 가림 방지',
+    captureModeHint:
+      'Win32 컨트롤러 전용: 전경 모드는 인식할 때마다 창을 앞으로 가져오며, PrintWindow 모드는 다시 연결한 후 적용됩니다',
+    captureModeDefault: '기본',
+    captureModeForeground: '전경으로 가져오기',
+    captureModePrintWindow: 'PrintWindow',
+    minimizedPolicy: '최소화 시 처리',
+    minimizedPolicyHint:
+      'Win32 컨트롤러 전용: 대상 창이 최소화되면 스크린샷이 실패하거나 검은 화면이 됩니다. 창이 복원될 때까지 작업을 일시 정지하거나 자동으로 복원할 수 있습니다(포커스를 가져오지 않음)',
+    minimizedPolicyNotify: '알림만',
+    minimizedPolicyPause: '작업 일시 정지',
+    minimizedPolicyRestore: '창 자동 복원',
+    frameDedup: '스크린샷 프레임 중복 제거',
+    frameDedupHint:
+      '화면이 계속 변하지 않으면 인식 빈도를 점차 낮춰 CPU를 절약하고, 화면이 바뀌면 즉시 복구합니다 (현재 인스턴스)',
+    frameDedupSimilarity: '유사도 임계값',
+    unknownScreens: '알 수 없는 화면 수집',
+    unknownScreensHint:
+      '여러 라운드 연속으로 인식이 모두 실패하면 현재 화면 스크린샷과 컨텍스트를 unknown_screens 폴더에 보관합니다(비슷한 화면은 한 장만, 최대 200장). 묶어서 리소스 개발자에게 보내면 인식 추가에 도움이 됩니다',
+    unknownScreensThreshold: '연속 실패 횟수',
+    unknownScreensCount: '{{count}}장 수집됨',
+    unknownScreensOpen: '폴더 열기',
+    unknownScreensExport: '압축',
+    unknownScreensExported: '알 수 없는 화면을 압축했습니다',
+    unknownScreensExportFailed: '알 수 없는 화면 압축 실패: {{error}}',
+    preprocess: '스크린샷 전처리',
+    preprocessHint:
+      '인식 전에 스크린샷의 밝기, 대비, 감마를 조정하여 어둡거나 색이 틀어진 화면의 인식을 안정화합니다 (현재 인스턴스)',
+    preprocessBrightness: '밝기',
+    preprocessContrast: '대비',
+    preprocessGamma: '감마',
+    preprocessNeedsReconnect: '스크린샷 전처리는 컨트롤러를 다시 연결한 후 적용됩니다',
+    taskRetry: '작업 실패 시 재시도',
+    taskRetryHint:
+      '현재 인스턴스의 작업이 실패하면 정책에 따라 자동으로 재시도하며, 재시도를 모두 소진해도 실패할 때만 종료합니다',
+    taskRetryMaxRetries: '재시도 횟수',
+    taskRetryInterval: '재시도 간격(초)',
+    taskRetryReconnect: '재시도 전 컨트롤러 재연결',
+    taskRetryRestartApp: '재시도 전 앱 재시작',
+    taskRetryRestartAppPlaceholder: '패키지명 또는 Activity, 비우면 재시작 안 함',
+    taskRetryFailed: '재시도 정책 설정 실패: {{error}}',
+    watchdog: '최대 실행 시간 감시',
+    watchdogHint: '현재 인스턴스의 작업이 제한 시간을 넘기면 자동으로 중지합니다',
+    watchdogMaxMinutes: '최대 실행 시간(분)',
+    watchdogCountPaused: '중단점에서 일시 정지된 시간 포함',
+    watchdogFailed: '감시 설정 실패: {{error}}',
+    benchmark: '성능 테스트',
+    benchmarkHint:
+      '현재 인스턴스의 스크린샷 및 인식 소요 시간을 측정하고 결과를 로그 폴더로 내보냅니다',
+    benchmarkScreencap: '스크린샷',
+    benchmarkFailed: '실패',
+    benchmarkOpenResult: '결과 파일 열기',
+    recordClip: '녹화',
+    annotatedScreenshot: '주석 스크린샷',
+    annotatedScreenshotHint:
+      '현재 스크린샷에 최근 인식 영역과 점수를 그려 저장합니다(문제 보고에 유용)',
+    annotatedScreenshotSaved: '주석 스크린샷을 저장했습니다(인식 결과 {{count}}개)',
+    annotatedScreenshotFailed: '주석 스크린샷 저장 실패: {{error}}',
+    issueBundle: '문제 패키지',
+    issueBundleHint:
+      '인식 실패 시의 스크린샷, 인식 상세, 노드 설정, override, 로그, 시스템 정보를 설명 파일과 함께 내보내기',
+    issueBundleExported: '문제 패키지를 내보냈습니다',
+    issueBundleFailed: '문제 패키지 내보내기 실패: {{error}}',
+    resourceManifestResetButton: '리소스 검증 목록 재생성',
+    resourceManifestResetHint:
+      '리소스를 변경한 후 현재 파일을 기준으로 검증 목록을 다시 생성합니다(리소스 패키지에 포함된 목록은 영향 없음)',
+    resourceManifestReset:
+      '리소스 검증 목록을 삭제했습니다. 다음 실행 시 현재 파일로 다시 생성됩니다',
+    resourceManifestResetFailed: '리소스 검증 목록 재생성 실패: {{error}}',
+    recordClipHint:
+      '현재 인스턴스의 스크린샷을 2 FPS로 녹화하고, 중지하면 로그 디렉터리의 clips 폴더로 내보냅니다',
+    clipExportGif: '중지 후 GIF 내보내기',
+    clipExportMp4: '중지 후 MP4 내보내기',
+    clipSummary: '{{frames}}프레임 · {{seconds}}초',
+    clipDropped: '녹화 한도를 초과하여 가장 오래된 {{count}}프레임을 버렸습니다',
+    clipMp4Fallback: '사용 가능한 ffmpeg가 없어 GIF로 내보냈습니다',
+    clipOpenResult: '녹화 파일 열기',
+    openFileFailed: '열기 실패: {{error}}',
     tcpCompatMode: '통신 호환 모드',
     tcpCompatModeHint:
       '작업 시작 후 앱이 즉시 충돌하면 활성화해 보세요. 이 경우에만 사용하세요, 성능에 영향을 줄 수 있습니다',
@@ -591,6 +899,8 @@ export default {
   // 인스턴스
   instance: {
     defaultName: '설정',
+    duplicateName:
+      '이미 「{{name}}」 이름의 설정이 있습니다. 구분하기 쉽도록 다른 이름을 사용하는 것을 권장합니다',
   },
 
   // 연결 패널
@@ -602,6 +912,7 @@ export default {
   dashboard: {
     title: '대시보드',
     toggle: '대시보드 보기',
+    stopAll: '모두 중지',
     exit: '대시보드 나가기',
     instances: '개의 인스턴스',
     noInstances: '인스턴스가 없습니다',
@@ -665,6 +976,10 @@ export default {
     downloadFailed: '다운로드 실패',
     viewDetails: '상세 보기',
     noDownloadUrl: '다운로드 URL이 없습니다. CDK를 입력하거나 네트워크 환경을 확인하세요',
+    networkUnavailable: '네트워크를 사용할 수 없습니다',
+    networkUnavailableHint:
+      '다운로드 서버에 연결할 수 없습니다. 네트워크 또는 프록시 설정을 확인하거나 브라우저에서 직접 다운로드하세요',
+    manualDownload: '직접 다운로드',
     openFolder: '폴더 열기',
     retry: '재시도',
     preparingDownload: '다운로드 준비 중...',
@@ -742,6 +1057,12 @@ export default {
     startedAt: '시작 시간: {{time}}',
     deletePolicyTitle: '예약 삭제',
     deletePolicyConfirm: '예약 "{{name}}"을(를) 삭제하시겠습니까?',
+    queueLoop: '반복 실행',
+    queueLoopRounds: '라운드 수 (0 = 무한)',
+    queueLoopInterval: '라운드 간격 (초)',
+    queueLoopHint:
+      '작업 대기열을 지정한 라운드 수만큼 반복합니다. 중지하면 현재 작업에서 반복을 종료합니다',
+    queueLoopSkipped: '작업 목록에 마무리 특수 작업이 포함되어 이번에는 한 라운드만 실행합니다',
     // Date.getDay()에 대응: 0=일, 1=월, ..., 6=토
     weekdays: ['일', '월', '화', '수', '목', '금', '토'],
   },
@@ -754,6 +1075,20 @@ export default {
     taskNotFound: '작업을 찾을 수 없습니다',
     controllerNotFound: '컨트롤러를 찾을 수 없습니다',
     resourceNotFound: '리소스 팩을 찾을 수 없습니다',
+    codes: {
+      INSTANCE_NOT_FOUND: '인스턴스를 찾을 수 없습니다',
+      CONTROLLER_NOT_CONNECTED: '컨트롤러가 연결되지 않았습니다',
+      CONTROLLER_CONNECT_FAILED: '컨트롤러 연결에 실패했습니다',
+      RESOURCE_NOT_LOADED: '리소스가 로드되지 않았습니다',
+      RESOURCE_LOAD_FAILED: '리소스 로딩에 실패했습니다',
+      TASK_RUN_FAILED: '작업 제출에 실패했습니다',
+      TASK_PREEMPTING: '다른 작업을 선점 실행 중입니다. 잠시 후 다시 시도하세요',
+      DOWNLOAD_FAILED: '다운로드에 실패했습니다',
+      DOWNLOAD_CANCELLED: '다운로드가 취소되었습니다',
+      UPDATE_FAILED: '업데이트 설치에 실패했습니다',
+      INVALID_ARGUMENT: '잘못된 인수입니다',
+      INTERNAL: '내부 오류',
+    },
   },
 
   // 컨텍스트 메뉴
@@ -773,9 +1108,11 @@ export default {
     exportConfig: '설정 내보내기',
     exportToClipboard: '클립보드로 내보내기',
     exportToTxt: 'txt 파일로 내보내기',
+    exportToShareCode: '공유 코드 복사',
     importConfig: '설정 가져오기',
     importFromClipboard: '클립보드에서 가져오기',
     importFromTxt: 'txt 파일에서 가져오기',
+    importFromShareCode: '공유 코드에서 가져오기',
 
     // 전처리 프로그램 컨텍스트 메뉴
     duplicateAction: '복제',
@@ -799,6 +1136,8 @@ export default {
     moveToBottom: '맨 아래로 이동',
     expandOptions: '옵션 펼치기',
     collapseOptions: '옵션 접기',
+    editSkipCondition: '건너뛰기 조건 설정',
+    validateTask: '작업 설정 검증',
     selectAll: '모두 선택',
     deselectAll: '모두 선택 해제',
     expandAllTasks: '모두 펼치기',
@@ -812,6 +1151,7 @@ export default {
     fullscreen: '전체 화면',
     saveScreenshot: '스크린샷 저장',
     copyScreenshot: '스크린샷 복사',
+    viewLastFrame: '마지막 프레임 보기',
 
     // 연결 패널 컨텍스트 메뉴
     refreshDevices: '기기 목록 새로고침',
@@ -877,6 +1217,16 @@ export default {
     desktopHint: '보다 안정적인 환경을 원하시면 데스크톱 클라이언트를 사용해 주세요',
   },
 
+  // 중단점 디버깅
+  breakpoint: {
+    title: '중단점 도달 - {{instance}}',
+    pausedAt: '작업이 일시 정지되었습니다. 실행할 노드:',
+    noScreenshot: '스크린샷 없음',
+    recognition: '인식 결과',
+    clearAndContinue: '모든 중단점 제거 후 계속',
+    continue: '계속',
+  },
+
   // 경로 경고
   badPath: {
     title: '프로그램 위치가 잘못되었습니다',
@@ -888,15 +1238,38 @@ export default {
       '프로그램이 임시 폴더에서 실행 중입니다. 닫으면 사라질 수 있습니다. 먼저 압축 파일을 폴더에 풀고 거기서 프로그램을 실행하세요.',
     hint: '팁: "D:\\MaaXXX"와 같은 전용 폴더에 압축을 푸는 것이 좋습니다. 관리하기 쉽도록 바탕화면이나 다운로드 폴더는 피하세요.',
     exit: '종료',
+    networkTitle: '프로그램이 네트워크 드라이브에 있습니다',
+    networkDescription:
+      '네트워크 드라이브에서는 파일 잠금, 업데이트 실패, 로그 기록 실패 등의 문제가 자주 발생합니다. 폴더 전체를 로컬 고정 디스크로 옮겨 주세요.',
+    removableTitle: '프로그램이 이동식 드라이브에 있습니다',
+    removableDescription:
+      '이동식 드라이브는 언제든 연결이 끊길 수 있어 설정 손상이나 업데이트 실패의 원인이 됩니다. 폴더 전체를 로컬 고정 디스크로 옮겨 주세요.',
+    readonlyTitle: '프로그램 폴더에 쓸 수 없습니다',
+    readonlyDescription: '설정, 로그, 업데이트 모두 프로그램 폴더에 기록해야 합니다. 폴더 전체를 쓰기 권한이 있는 로컬 폴더로 옮겨 주세요.',
+    cloudTitle: '프로그램이 OneDrive 동기화 폴더에 있습니다',
+    cloudDescription: '클라우드 동기화는 사용 중인 파일을 잠가 업데이트와 로그 기록이 실패하기 쉽습니다. 동기화되지 않는 로컬 폴더로 옮겨 주세요.',
+    continue: '그래도 계속',
   },
   // 프록시 설정
   proxy: {
     title: '네트워크 프록시',
     url: '프록시 주소',
     urlPlaceholder: '예: http://127.0.0.1:7890',
-    urlHint: 'HTTP/SOCKS5 지원, 비워두면 프록시를 사용하지 않음',
+    urlHint: 'HTTP/SOCKS5 지원, 비워두면 시스템 프록시 사용',
+    systemDetected: '시스템 프록시 사용 중: {{url}}',
+    systemNone: '시스템 프록시가 감지되지 않아 직접 연결합니다',
     urlHintDisabled: 'Mirror짱 CDK가 입력되어 프록시 기능이 비활성화되었습니다',
     invalid: '프록시 주소 형식이 올바르지 않습니다',
     examples: '예시 형식',
   },
+  cpuUnsupported: {
+    title: '지원되지 않는 CPU',
+    message: '사용 중인 CPU가 {{features}}를 지원하지 않아 실행할 수 없습니다',
+    description:
+      '인식과 자동 조작에 쓰이는 연산 라이브러리는 위 명령어 세트가 필요하며, 이 컴퓨터에서는 실행 중 충돌합니다. 따라서 이번에는 MaaFramework를 불러오지 않습니다.',
+    hint:
+      '2011년 이후 출시된 대부분의 Intel / AMD 프로세서는 AVX를 지원합니다. CPU가 지원한다면 가상 머신이나 BIOS에서 AVX가 차단되어 있지 않은지 확인하세요.',
+    exit: '종료',
+    continue: '그래도 열기 (작업 실행 불가)',
+  },
 };
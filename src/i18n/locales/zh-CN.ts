@@ -99,8 +99,32 @@ export default {
     hotkeysGlobalOnlyStart: '全局模式下仅开始生效',
     minimizeToTray: '关闭时最小化到托盘',
     minimizeToTrayHint: '点击关闭按钮时隐藏到系统托盘而非退出程序',
+    autoShowOverlay: '任务运行时显示日志悬浮窗',
+    autoShowOverlayHint:
+      '任务开始时自动显示日志悬浮窗，全部任务结束几秒后自动关闭（手动打开的不受影响）',
+    programTrust: '外部程序信任',
+    programTrustHint: '执行资源配置中的前置程序、pretask 和 MXU_LAUNCH 前校验程序路径',
+    programTrustAll: '信任所有程序',
+    programTrustConfirm: '不在列表中时确认',
+    programTrustStrict: '只运行信任列表中的程序',
+    programTrustList: '已信任的程序',
+    programTrustListEmpty: '暂无信任的程序',
+    programTrustRemove: '移出信任列表',
+    taskReport: '任务报告',
+    taskReportHint: '任务队列结束后生成汇总图片（耗时、结果、截图），保存到 debug 目录',
+    taskReportScreenshots: '报告截图',
+    taskReportScreenshotsAll: '全部任务',
+    taskReportScreenshotsFailed: '仅失败任务',
+    taskReportScreenshotsNone: '不含截图',
+    completionWebhook: '完成后推送 Webhook',
+    completionWebhookHint:
+      '任务队列全部结束时向指定 URL POST 运行摘要 JSON（各任务结果、耗时、是否有错误）',
+    completionWebhookPlaceholder: '输入完整的 URL（如 https://example.com/hook）',
     autoStart: '开机自启动',
     autoStartHint: '系统启动时自动运行本程序',
+    autoStartDelay: '开机启动延迟',
+    autoStartDelayHint: '开机自启动时等待指定秒数后再加载 MaaFramework 并连接设备，0 表示不延迟',
+    autoStartDelayUnit: '秒',
     autoStartInstance: '启动后自动执行',
     autoStartInstanceHint:
       '选择程序启动后自动激活并执行任务的配置，未选择的配置其定时任务仍会正常运行',
@@ -154,6 +178,11 @@ export default {
         '启用时通过 cmd /c 启动程序，使其脱离当前进程树。一些游戏可能会检测进程树（仅 Windows 生效）',
       cmdYes: '通过 cmd /c 启动',
       cmdNo: '直接启动子进程',
+      hideLabel: '隐藏控制台窗口',
+      hideDescription:
+        '启用时启动命令行程序不再弹出黑色控制台窗口，GUI 程序不受影响（仅 Windows 生效）',
+      hideYes: '隐藏窗口',
+      hideNo: '显示窗口',
     },
     notify: {
       label: '💬 系统通知',
@@ -270,6 +299,12 @@ export default {
     incompatibleController: '不支持当前控制器',
     incompatibleResource: '不支持当前资源',
     supportedControllers: '仅支持: {{controllers}}',
+    // 跳过条件
+    skipCondition: '跳过条件',
+    skipConditionPlaceholder: '例如 hour >= 22 || last_result == "failed"',
+    skipConditionHint:
+      '任务开始时表达式为真则跳过，可用 hour、minute、weekday、day、month、round、last_result 与 var.变量名',
+    skipConditionInvalid: '表达式无效：{{error}}',
   },
 
   // 选项
@@ -296,6 +331,9 @@ export default {
     useCmd: '通过 cmd 启动',
     useCmdHint:
       '启用时通过 cmd /c 启动程序，使其脱离当前进程树。一些游戏可能会检测进程树（仅 Windows 生效）',
+    hideWindow: '隐藏控制台窗口',
+    hideWindowHint:
+      '启用时启动命令行程序不再弹出黑色控制台窗口，需要查看输出时请关闭（仅 Windows 生效）',
     preActionSkipped: '前置程序 {{name}} 已在运行，跳过执行',
     waitingForDevice: '正在等待设备就绪...',
     waitingForWindow: '正在等待窗口就绪...',
@@ -320,6 +358,8 @@ export default {
     pretaskExitCode: '预任务退出码: {{code}}',
     pretaskFailed: '预任务执行失败: {{error}}',
     preActionConnectDelay: '等待 {{seconds}} 秒后连接...',
+    resourceIntegrityWarning:
+      '资源文件缺失 {{missing}} 个、被改动 {{modified}} 个（{{files}}），可能被杀毒软件删除或已损坏，建议重新下载资源',
     autoPreActionName: '▶️ 启动 {{name}}',
     autoPreActionAdded: '已自动添加前置程序: {{name}}（默认未启用）',
     removeConfirmTitle: '删除前置程序',
@@ -359,6 +399,13 @@ export default {
     exportFileFailed: '导出失败：无法写入文件',
     exportShareHint: '「{{tabName}}」的 {{projectName}} 配置，发给你啦~',
     exportShareFooter: '👆 复制这段文字，在 {{projectName}} 里新建标签页，点「导入配置」就能直接用',
+    shareCodeCopied: '分享码已复制到剪贴板',
+    shareCodeExportFailed: '生成分享码失败：{{error}}',
+    shareCodeImported: '已从分享码追加 {{count}} 个任务',
+    shareCodeImportedPartial:
+      '已从分享码追加 {{count}} 个任务，{{skipped}} 个当前项目中不存在的任务已跳过',
+    shareCodeNoTasks: '分享码中没有当前项目可用的任务',
+    shareCodeImportFailed: '导入分享码失败：{{error}}',
   },
 
   // 控制器
@@ -388,6 +435,23 @@ export default {
     savedDeviceNotFound: '未找到上次的设备，请检查连接或重新选择',
     savedWindowNotFound: '未找到上次的窗口，请检查连接或重新选择',
     connectedLog: '已连接控制器 [{{name}}]',
+    resolutionMismatch:
+      '截图宽高比（{{actual}}）与资源期望不符，识别可能全部失败，建议将模拟器分辨率设为 {{expected}}',
+    usbDevice: 'USB 真机',
+    usbUnauthorized: '未授权',
+    usbUnauthorizedHint: '手机未授权 USB 调试，请在手机上点击「允许」后刷新设备列表',
+    usbOfflineHint: '设备当前状态为 {{state}}，请重新插拔数据线后刷新设备列表',
+    usbDeviceHint:
+      '已连接 USB 真机：真机截图较慢，截图失败时可尝试其他截图方式；分辨率与资源要求不符时请在手机上调整显示设置',
+    emulatorLaunch: '连接前启动模拟器',
+    emulatorLaunchHint: '连接时若目标设备或窗口不存在，先启动该程序并等待就绪',
+    emulatorLaunchProgram: '模拟器程序',
+    emulatorLaunchArgs: '启动参数',
+    emulatorLaunchAdbAddress: 'ADB 地址',
+    emulatorLaunchAdbAddressHint: '模拟器启动后等待出现的设备地址',
+    emulatorLaunchTimeout: '等待超时',
+    emulatorLaunchSettle: '就绪后等待',
+    emulatorLaunchSeconds: '秒',
   },
 
   // 资源
@@ -395,6 +459,7 @@ export default {
     title: '资源包',
     selectResource: '选择资源包',
     loading: '加载资源中...',
+    loadingProgress: '加载资源中（{{done}}/{{total}}）...',
     loaded: '资源已加载',
     loadFailed: '资源加载失败',
     loadResource: '加载资源',
@@ -404,6 +469,12 @@ export default {
     incompatibleController: '不支持当前控制器',
   },
 
+  // 后端健康检查
+  health: {
+    backendUnresponsive: '后端长时间无响应，可能已卡住。可尝试导出日志后重启程序',
+    backendRecovered: '后端已恢复响应',
+  },
+
   // MaaFramework
   maa: {
     notInitialized: 'MaaFramework 未初始化',
@@ -424,6 +495,12 @@ export default {
     fullscreen: '全屏显示',
     exitFullscreen: '退出全屏',
     clickHint: '点击画面可向设备发送点击',
+    noLastFrame: '暂无任务结束时的截图',
+    lastFrameLabel: '{{status}} · {{time}}',
+    lastFrameStatus: {
+      succeeded: '任务完成',
+      failed: '任务失败',
+    },
     // 帧率设置
     frameRate: {
       title: '实时截图帧率',
@@ -438,11 +515,25 @@ export default {
 
   // 日志/信息流
   logs: {
+    section: {
+      succeeded: '完成 · {{count}} 条',
+      failed: '失败 · {{count}} 条',
+      running: '运行中 · {{count}} 条',
+    },
     title: '运行日志',
     clear: '清空',
+    openOverlay: '打开日志悬浮窗',
+    closeOverlay: '关闭悬浮窗',
+    overlayPreview: '画面预览',
+    overlayLogs: '日志',
+    overlayNoInstance: '没有可预览的实例',
+    overlayWaitingFrame: '等待画面…',
+    openOverlayFailed: '打开日志悬浮窗失败: {{error}}',
     autoscroll: '自动滚动',
     noLogs: '暂无日志',
     copyAll: '复制全部',
+    exportInstanceLogs: '导出当前实例日志',
+    copyFailed: '复制失败，请手动选中文本复制',
     showMoreLogs: '展示更多日志',
     expand: '展开上方面板',
     collapse: '折叠上方面板',
@@ -464,6 +555,8 @@ export default {
       taskStarting: '任务开始: {{name}}',
       taskSucceeded: '任务完成: {{name}}',
       taskFailed: '任务失败: {{name}}',
+      taskInterrupted: '任务已中断，稍后重新执行: {{name}}',
+      taskReportGenerated: '任务报告已生成: {{path}}',
       stopTask: '停止任务',
       // 定时任务消息
       scheduleStarting: '定时执行开始 [{{policy}}] {{time}}',
@@ -477,6 +570,46 @@ export default {
       agentLogFloodWarning:
         'Agent 处于日志风暴状态，为避免性能问题已暂停显示日志，完整日志可在本地日志文件中查看',
       agentLogFloodRecovered: 'Agent 日志风暴状态已缓解',
+      agentRestarting:
+        'Agent 进程意外退出（退出码 {{code}}），正在自动重启（第 {{attempt}}/{{max}} 次）',
+      agentRestarted: 'Agent 已重启，继续执行剩余 {{remaining}} 个任务',
+      agentRestartFailed: 'Agent 重启失败：{{error}}',
+      agentRestartGaveUp: 'Agent 已连续崩溃 {{max}} 次，停止自动重启',
+      taskPreempted: '高优先级任务 {{name}} 抢占执行，{{count}} 个任务将在其完成后恢复',
+      taskRetrying: '任务 {{name}} 失败，正在进行第 {{attempt}}/{{max}} 次重试',
+      taskRetryFailed: '任务 {{name}} 重试失败：{{error}}',
+      taskSkippedByCondition: '任务 {{name}} 满足跳过条件 {{expression}}，已跳过',
+      taskConditionInvalid: '任务 {{name}} 的跳过条件无效，照常执行：{{error}}',
+      taskValidationPassed: '任务 {{name}} 校验通过：{{nodes}} 个可达节点，{{warnings}} 个警告',
+      taskValidationFailed: '任务 {{name}} 校验未通过：{{errors}} 个错误',
+      taskValidationIssue: '任务 {{name}} 校验：{{location}} {{message}}',
+      taskValidationError: '任务 {{name}} 校验失败：{{error}}',
+      roundCompleted: '第 {{round}}/{{total}} 轮执行完毕',
+      sectionStart: '── {{name}} 开始 ──',
+      sectionEnd: '── {{name}} 结束 ──',
+      sectionRound: '{{name}}（第 {{round}} 轮）',
+      pipelineHotUpdated: '任务「{{name}}」的配置已更新，从下一个节点开始生效',
+      pipelineHotDeferred: '{{fields}} 所在节点正在执行，待其下次执行时生效',
+      pipelineHotRestart: '{{fields}} 在本次运行中不再生效，需重新开始任务',
+      pipelineHotNextRound: '{{fields}} 在本轮中不再生效，将从下一轮开始生效',
+      roundCompletedNext: '第 {{round}}/{{total}} 轮执行完毕，{{seconds}} 秒后开始下一轮',
+      watchdogTimeout: '运行超过 {{minutes}} 分钟上限，看门狗已停止任务',
+      windowMinimized: '目标窗口已最小化，截图会失败或黑屏，请恢复窗口',
+      windowMinimizedPaused: '目标窗口已最小化，任务将在下一轮识别前暂停，恢复窗口后自动继续',
+      windowMinimizedRestore: '目标窗口已最小化，将在下一轮识别前自动恢复窗口',
+      windowRestored: '目标窗口已恢复',
+      emulatorLaunching: '已启动模拟器 {{name}}，等待设备就绪...',
+      emulatorAlreadyRunning: '模拟器 {{name}} 已在运行，等待设备就绪...',
+      emulatorReady: '模拟器已就绪，开始连接',
+      emulatorTimeout: '等待模拟器 {{name}} 就绪超时',
+      adbUnauthorized: '设备 {{address}} 未授权调试，请在设备上点击「允许 USB 调试」',
+      adbReauthorized: '设备 {{address}} 已授权',
+      adbReauthorizedReconnected: '设备 {{address}} 已重新授权，已自动重连',
+      adbAuthTimeout: '等待设备 {{address}} 授权超时，请在设备上允许调试后重新连接',
+      programUntrusted: '程序不在信任列表中，已拒绝执行（{{source}}）：{{program}}',
+      programRejected: '已拒绝执行程序（{{source}}）：{{program}}',
+      dryRunAction: '[dry-run] {{node}} 将执行 {{action}}（识别命中位置）',
+      dryRunActionAt: '[dry-run] {{node}} 将执行 {{action}} ({{x}}, {{y}})',
       // 快捷键
       hotkeyDetected: '检测到快捷键: {{combo}}（{{action}}）',
       hotkeyActionStart: '开始任务',
@@ -519,6 +652,9 @@ export default {
     interfaceVersion: '{{name}} 版本',
     maafwVersion: 'maafw 版本',
     mxuVersion: 'mxu 版本',
+    agentVersion: 'agent 版本',
+    buildCommit: '编译提交',
+    buildTime: '编译时间',
     environment: '运行环境',
     envTauri: 'Tauri 桌面端',
     envBrowser: '浏览器',
@@ -526,11 +662,65 @@ export default {
     operatingSystem: '操作系统',
     architecture: '系统架构',
     tauriVersion: 'Tauri 版本',
+    exeVolume: '程序所在卷',
+    exeVolumeReadonly: '只读',
+    controllerInfo: '控制器详情',
+    controllerInfoRefresh: '刷新',
+    controllerStatus: '状态',
+    controllerStatusValue: {
+      connected: '已连接',
+      disconnected: '未连接',
+      none: '尚未创建控制器',
+    },
+    controllerType: '类型',
+    controllerTarget: '连接目标',
+    controllerScreencap: '截图方式',
+    controllerInput: '输入方式',
+    controllerResolution: '分辨率',
+    controllerShortSide: '截图短边',
+    cpuFeatures: 'CPU 指令集',
+    cpuFeaturesMissing: '（缺少 {{features}}，无法运行任务）',
     pathInfo: '路径信息',
     cwd: '当前工作目录',
     exeDir: '程序所在目录',
     webview2Dir: 'WebView2 目录',
     webview2System: '系统',
+    webview2Shared: '共享 WebView2 运行时',
+    webview2SharedHint:
+      '独立运行时安装到 %LOCALAPPDATA%\\MXU，供多个 MXU 共用以节省空间，重启后生效',
+    webviewGpuDisabled: '禁用 WebView2 GPU 加速',
+    webviewGpuDisabledHint: '界面出现花屏、白屏或闪烁时开启，改用软件渲染，重启后生效',
+    adbPath: 'ADB 路径',
+    adbPathHint: '模拟器自带 adb 与系统 adb 冲突导致连接失败时，指定要使用的 adb 可执行文件',
+    adbPathPlaceholder: '留空则自动查找',
+    adbPathSaved: '已使用指定的 adb，找到 {{count}} 个设备',
+    adbPathInvalid: '指定的 adb 不可用：{{error}}',
+    toolkitConfig: '设备搜索目录',
+    toolkitConfigHint:
+      '冷门模拟器或自定义安装位置搜不到设备时，补充 adb 所在目录或模拟器安装目录（每行一个）',
+    extraAdbDirs: '额外 adb 目录',
+    extraAdbDirsPlaceholder: 'adb 所在目录或 adb 可执行文件路径',
+    emulatorDirs: '模拟器安装目录',
+    emulatorDirsPlaceholder: '会在其子目录中查找 adb',
+    toolkitConfigSaved: '已保存，找到 {{count}} 个可用的 adb',
+    toolkitConfigFailed: '保存设备搜索目录失败：{{error}}',
+    processMonitor: '资源监视',
+    processMonitorHint: '查看 MXU 和 agent 进程的 CPU、内存占用，排查卡顿或反馈性能问题时使用',
+    processMonitorProcess: '进程',
+    processMonitorMemory: '内存',
+    processMonitorThreads: '线程',
+    processMonitorUptime: '运行时长',
+    processMonitorAgent: 'Agent（{{name}}）',
+    processMonitorCopy: '复制数据',
+    processMonitorCopied: '已复制资源占用数据',
+    agentResourceLimits: 'Agent 资源限制',
+    agentResourceLimitsHint:
+      '通过 Job Object 限制 agent 子进程，0 表示不限制；MXU 退出时自动结束所有 agent 进程',
+    agentMemoryLimit: '单个 agent 内存上限（MB）',
+    agentCpuLimit: '所有 agent 合计 CPU 配额（%）',
+    updateCopyConcurrency: '更新并行复制数',
+    updateCopyConcurrencyHint:
+      '增量更新时同时复制文件的线程数，0 表示按 CPU 核数；目标在机械硬盘上时自动改为串行',
     resetWindowLayout: '重置窗口布局',
     openConfigDir: '打开配置目录',
     openLogDir: '打开日志目录',
@@ -544,6 +734,97 @@ export default {
     devModeHint: '启用后允许按 F5 刷新 UI',
     saveDraw: '保存调试图像',
     saveDrawHint: '保存识别和操作的调试图像到日志目录（重启软件后自动关闭）',
+    verboseLog: '详细日志',
+    verboseLogHint: '记录调试级别的详细日志，关闭后仅记录信息及以上级别，立即生效',
+    eventLog: '结构化事件日志',
+    eventLogHint:
+      '将任务开始/结束、识别结果和错误以 JSONL 格式额外写入 debug/logs/events.jsonl，便于脚本分析',
+    failureScreenshot: '任务失败截图',
+    failureScreenshotHint:
+      '任务失败时在日志中附带当时的截图（过大时自动缩小），便于定位卡住的界面和上报问题',
+    actionTrace: '点击轨迹可视化',
+    actionTraceHint:
+      '任务执行点击、滑动时推送坐标事件，可在截图预览上标出点击点和滑动轨迹，便于确认到底点了哪里',
+    breakpoints: 'Pipeline 断点',
+    breakpointsHint: '当前实例执行到这些节点前暂停，可查看画面后继续',
+    breakpointsPlaceholder: '节点名，多个用逗号分隔',
+    dryRun: 'Dry-run 模式',
+    dryRunHint: '当前实例之后启动的任务只截图和识别，跳过所有动作并在日志中记录本该执行的操作',
+    speed: '操作速度',
+    speedHint:
+      '缩放当前实例之后启动任务中每个动作前后的等待（0.25 ~ 4，默认 1）。模拟器响应慢、容易漏点时调低，设备性能好时调高以节省时间',
+    captureMode: '截图防遮挡',
+    captureModeHint:
+      '仅 Win32 控制器：前台模式在每轮识别前把窗口提到前台；PrintWindow 模式在重新连接后生效',
+    captureModeDefault: '默认',
+    captureModeForeground: '截图前置前台',
+    captureModePrintWindow: 'PrintWindow',
+    minimizedPolicy: '最小化处理',
+    minimizedPolicyHint:
+      '仅 Win32 控制器：目标窗口最小化时截图会失败或黑屏，可选择暂停任务直到窗口恢复，或自动恢复窗口（不抢占前台）',
+    minimizedPolicyNotify: '仅提示',
+    minimizedPolicyPause: '暂停任务',
+    minimizedPolicyRestore: '自动恢复窗口',
+    frameDedup: '截图帧去重',
+    frameDedupHint: '画面连续未变化时逐步降低识别频率以节省 CPU，画面变化后立即恢复（当前实例）',
+    frameDedupSimilarity: '相似度阈值',
+    unknownScreens: '未知界面收集',
+    unknownScreensHint:
+      '连续多轮识别全部未命中时，自动把当前界面截图和上下文归档到 unknown_screens 目录（相似截图只保留一张，最多 200 张），可打包反馈给资源开发者补充识别',
+    unknownScreensThreshold: '连续失败次数',
+    unknownScreensCount: '已收集 {{count}} 张',
+    unknownScreensOpen: '打开目录',
+    unknownScreensExport: '打包',
+    unknownScreensExported: '已打包未知界面',
+    unknownScreensExportFailed: '打包未知界面失败：{{error}}',
+    preprocess: '截图预处理',
+    preprocessHint:
+      '送去识别前统一调整截图的亮度、对比度和 gamma，改善偏暗或偏色画面的识别（当前实例）',
+    preprocessBrightness: '亮度',
+    preprocessContrast: '对比度',
+    preprocessGamma: 'Gamma',
+    preprocessNeedsReconnect: '截图预处理将在重新连接控制器后生效',
+    taskRetry: '任务失败重试',
+    taskRetryHint: '当前实例的任务失败后按策略自动重试，重试次数用尽仍失败才结束',
+    taskRetryMaxRetries: '重试次数',
+    taskRetryInterval: '重试间隔（秒）',
+    taskRetryReconnect: '重试前重连控制器',
+    taskRetryRestartApp: '重试前重启应用',
+    taskRetryRestartAppPlaceholder: '包名或 Activity，留空不重启',
+    taskRetryFailed: '设置失败重试策略失败：{{error}}',
+    watchdog: '运行时长看门狗',
+    watchdogHint: '当前实例的任务运行超过上限时自动停止',
+    watchdogMaxMinutes: '最大运行时长（分钟）',
+    watchdogCountPaused: '计入断点暂停时间',
+    watchdogFailed: '设置看门狗失败：{{error}}',
+    benchmark: '性能测试',
+    benchmarkHint: '测试当前实例的截图与识别耗时，结果导出到日志目录',
+    benchmarkScreencap: '截图',
+    benchmarkFailed: '失败',
+    benchmarkOpenResult: '打开结果文件',
+    recordClip: '录制录像',
+    annotatedScreenshot: '标注截图',
+    annotatedScreenshotHint: '保存当前截图并画出最近一轮的识别框与分数，便于反馈问题',
+    annotatedScreenshotSaved: '已保存标注截图（{{count}} 个识别结果）',
+    annotatedScreenshotFailed: '保存标注截图失败：{{error}}',
+    issueBundle: '问题包',
+    issueBundleHint:
+      '导出识别失败时的截图、识别详情、节点配置、override、日志和系统信息，附说明文件',
+    issueBundleExported: '已导出问题包',
+    issueBundleFailed: '导出问题包失败：{{error}}',
+    resourceManifestResetButton: '重建资源校验清单',
+    resourceManifestResetHint:
+      '资源改动后以当前文件为准重新生成校验清单（随资源包分发的清单不受影响）',
+    resourceManifestReset: '已清除资源校验清单，下次运行时按当前文件重新生成',
+    resourceManifestResetFailed: '重建资源校验清单失败：{{error}}',
+    recordClipHint: '按 2 FPS 录制当前实例的截图序列，停止时导出到日志目录的 clips 文件夹',
+    clipExportGif: '停止并导出 GIF',
+    clipExportMp4: '停止并导出 MP4',
+    clipSummary: '{{frames}} 帧 · {{seconds}} 秒',
+    clipDropped: '超出录制上限，已丢弃最早的 {{count}} 帧',
+    clipMp4Fallback: '未找到可用的 ffmpeg，已改为导出 GIF',
+    clipOpenResult: '打开录像文件',
+    openFileFailed: '打开失败：{{error}}',
     tcpCompatMode: '通信兼容模式',
     tcpCompatModeHint: '若启动任务后软件立即闪退，可尝试开启。仅限此情况使用，否则会影响运行效率',
     webServerEnabled: '启用 Web 服务',
@@ -581,6 +862,7 @@ export default {
   // 实例
   instance: {
     defaultName: '配置',
+    duplicateName: '已有同名配置「{{name}}」，建议使用不同名称以便区分',
   },
 
   // 连接面板
@@ -592,6 +874,7 @@ export default {
   dashboard: {
     title: '中控台',
     toggle: '中控台视图',
+    stopAll: '全部停止',
     exit: '退出中控台',
     instances: '个实例',
     noInstances: '暂无实例',
@@ -655,6 +938,9 @@ export default {
     downloadFailed: '下载失败',
     viewDetails: '查看详情',
     noDownloadUrl: '无可用下载链接，请填写 CDK 或检查网络环境',
+    networkUnavailable: '网络不可用',
+    networkUnavailableHint: '无法连接到下载服务器，请检查网络或代理设置，也可以在浏览器中手动下载',
+    manualDownload: '手动下载',
     openFolder: '打开目录',
     retry: '重试',
     preparingDownload: '准备下载...',
@@ -709,7 +995,9 @@ export default {
     title: '网络代理',
     url: '代理地址',
     urlPlaceholder: '例如：http://127.0.0.1:7890',
-    urlHint: '支持 HTTP/SOCKS5，留空则不使用代理',
+    urlHint: '支持 HTTP/SOCKS5，留空则使用系统代理',
+    systemDetected: '当前使用系统代理：{{url}}',
+    systemNone: '未检测到系统代理，将直接连接',
     urlHintDisabled: '已填写 Mirror酱 CDK，代理功能已禁用',
     invalid: '代理地址格式不正确',
     examples: '示例格式',
@@ -743,6 +1031,11 @@ export default {
     startedAt: '开始时间: {{time}}',
     deletePolicyTitle: '删除策略',
     deletePolicyConfirm: '确定要删除策略「{{name}}」吗？',
+    queueLoop: '循环执行',
+    queueLoopRounds: '轮数（0 为无限）',
+    queueLoopInterval: '每轮间隔（秒）',
+    queueLoopHint: '任务队列执行完后按设定轮数重复执行，停止任务时在当前任务处结束循环',
+    queueLoopSkipped: '任务列表含收尾特殊任务，本次只执行一轮',
     // 索引对应 Date.getDay()：0=周日, 1=周一, ..., 6=周六
     weekdays: ['周日', '周一', '周二', '周三', '周四', '周五', '周六'],
   },
@@ -755,6 +1048,20 @@ export default {
     taskNotFound: '任务不存在',
     controllerNotFound: '控制器不存在',
     resourceNotFound: '资源包不存在',
+    codes: {
+      INSTANCE_NOT_FOUND: '实例不存在',
+      CONTROLLER_NOT_CONNECTED: '控制器未连接',
+      CONTROLLER_CONNECT_FAILED: '控制器连接失败',
+      RESOURCE_NOT_LOADED: '资源未加载',
+      RESOURCE_LOAD_FAILED: '资源加载失败',
+      TASK_RUN_FAILED: '任务提交失败',
+      TASK_PREEMPTING: '正在抢占执行其他任务，请稍后重试',
+      DOWNLOAD_FAILED: '下载失败',
+      DOWNLOAD_CANCELLED: '下载已取消',
+      UPDATE_FAILED: '更新安装失败',
+      INVALID_ARGUMENT: '参数无效',
+      INTERNAL: '内部错误',
+    },
   },
 
   // 右键菜单
@@ -774,9 +1081,11 @@ export default {
     exportConfig: '导出配置',
     exportToClipboard: '导出到剪贴板',
     exportToTxt: '导出为 txt 文件',
+    exportToShareCode: '复制分享码',
     importConfig: '导入配置',
     importFromClipboard: '从剪贴板导入',
     importFromTxt: '从 txt 文件导入',
+    importFromShareCode: '从分享码导入',
 
     // 前置程序右键菜单
     duplicateAction: '复制',
@@ -800,6 +1109,8 @@ export default {
     moveToBottom: '置底',
     expandOptions: '展开选项',
     collapseOptions: '折叠选项',
+    editSkipCondition: '设置跳过条件',
+    validateTask: '校验任务配置',
     selectAll: '全选任务',
     deselectAll: '取消全选',
     expandAllTasks: '展开全部',
@@ -813,6 +1124,7 @@ export default {
     fullscreen: '全屏显示',
     saveScreenshot: '保存截图',
     copyScreenshot: '复制截图',
+    viewLastFrame: '查看最后一帧',
 
     // 连接面板右键菜单
     refreshDevices: '刷新设备列表',
@@ -875,6 +1187,16 @@ export default {
     desktopHint: '如需更稳定的体验，建议使用桌面客户端',
   },
 
+  // 断点调试
+  breakpoint: {
+    title: '断点已命中 - {{instance}}',
+    pausedAt: '任务已暂停，即将执行节点：',
+    noScreenshot: '暂无截图',
+    recognition: '识别结果',
+    clearAndContinue: '移除所有断点并继续',
+    continue: '继续',
+  },
+
   // 程序路径问题提示
   badPath: {
     title: '程序位置不对',
@@ -886,5 +1208,24 @@ export default {
       '程序现在在临时目录里跑着呢，一关掉可能就没了。先把压缩包解压到一个文件夹里，再打开里面的程序吧。',
     hint: '小提示：建议解压到一个专门的文件夹，比如「D:\\MaaXXX」，别放桌面或者下载文件夹，那样更方便管理。',
     exit: '退出程序',
+    networkTitle: '程序放在网络盘上了',
+    networkDescription: '网络盘上容易出现文件被锁、更新失败、日志写不进去等奇怪问题。建议把整个文件夹移到本机的固定硬盘上再用。',
+    removableTitle: '程序放在 U 盘/移动硬盘上了',
+    removableDescription: '可移动磁盘随时可能断开，运行中拔出会导致配置损坏或更新失败。建议把整个文件夹移到本机的固定硬盘上再用。',
+    readonlyTitle: '程序所在的文件夹没法写入',
+    readonlyDescription: '配置、日志和更新都需要写入程序目录，现在写不进去会导致各种问题。建议把整个文件夹移到有写入权限的本地目录。',
+    cloudTitle: '程序放在 OneDrive 同步目录里了',
+    cloudDescription: '云同步会锁定正在使用的文件，容易导致更新失败、日志写不了。建议把整个文件夹移到不同步的本地目录。',
+    continue: '仍然继续',
+  },
+  cpuUnsupported: {
+    title: 'CPU 不受支持',
+    message: '您的 CPU 不支持 {{features}}，无法运行',
+    description:
+      '识别与自动操作依赖的计算库需要 CPU 支持上述指令集，在这台电脑上运行会直接崩溃，因此本次不会加载 MaaFramework。',
+    hint:
+      '2011 年之后的大多数 Intel / AMD 处理器都支持 AVX。如果 CPU 本身支持，请检查虚拟机或 BIOS 是否屏蔽了 AVX。',
+    exit: '退出程序',
+    continue: '仍然打开（无法运行任务）',
   },
 };
@@ -98,8 +98,32 @@ export default {
     hotkeysGlobalOnlyStart: '全域模式下僅開始生效',
     minimizeToTray: '關閉時最小化到托盤',
     minimizeToTrayHint: '點選關閉按鈕時隱藏到系統托盤而非退出程式',
+    autoShowOverlay: '任務執行時顯示日誌懸浮窗',
+    autoShowOverlayHint:
+      '任務開始時自動顯示日誌懸浮窗，全部任務結束數秒後自動關閉（手動開啟的不受影響）',
+    programTrust: '外部程式信任',
+    programTrustHint: '執行資源設定中的前置程式、pretask 和 MXU_LAUNCH 前校驗程式路徑',
+    programTrustAll: '信任所有程式',
+    programTrustConfirm: '不在清單中時確認',
+    programTrustStrict: '只執行信任清單中的程式',
+    programTrustList: '已信任的程式',
+    programTrustListEmpty: '暫無信任的程式',
+    programTrustRemove: '移出信任清單',
+    taskReport: '任務報告',
+    taskReportHint: '任務佇列結束後產生彙總圖片（耗時、結果、截圖），儲存到 debug 目錄',
+    taskReportScreenshots: '報告截圖',
+    taskReportScreenshotsAll: '全部任務',
+    taskReportScreenshotsFailed: '僅失敗任務',
+    taskReportScreenshotsNone: '不含截圖',
+    completionWebhook: '完成後推送 Webhook',
+    completionWebhookHint:
+      '任務佇列全部結束時向指定 URL POST 執行摘要 JSON（各任務結果、耗時、是否有錯誤）',
+    completionWebhookPlaceholder: '輸入完整的 URL（如 https://example.com/hook）',
     autoStart: '開機自啟動',
     autoStartHint: '系統啟動時自動執行本程式',
+    autoStartDelay: '開機啟動延遲',
+    autoStartDelayHint: '開機自啟動時等待指定秒數後再載入 MaaFramework 並連線裝置，0 表示不延遲',
+    autoStartDelayUnit: '秒',
     autoStartInstance: '啟動後自動執行',
     autoStartInstanceHint:
       '選擇程式啟動後自動啟用並執行任務的配置，未選擇的配置其定時任務仍會正常運行',
@@ -154,6 +178,11 @@ export default {
         '啟用時透過 cmd /c 啟動程式，使其脫離當前進程樹。一些遊戲可能會檢測進程樹（僅 Windows 生效）',
       cmdYes: '透過 cmd /c 啟動',
       cmdNo: '直接啟動子行程',
+      hideLabel: '隱藏主控台視窗',
+      hideDescription:
+        '啟用時啟動命令列程式不再彈出黑色主控台視窗，GUI 程式不受影響（僅 Windows 生效）',
+      hideYes: '隱藏視窗',
+      hideNo: '顯示視窗',
     },
     notify: {
       label: '💬 系統通知',
@@ -266,6 +295,12 @@ export default {
     incompatibleController: '不支援目前控制器',
     incompatibleResource: '不支援目前資源',
     supportedControllers: '僅支援: {{controllers}}',
+    // 跳過條件
+    skipCondition: '跳過條件',
+    skipConditionPlaceholder: '例如 hour >= 22 || last_result == "failed"',
+    skipConditionHint:
+      '任務開始時運算式為真則跳過，可用 hour、minute、weekday、day、month、round、last_result 與 var.變數名',
+    skipConditionInvalid: '運算式無效：{{error}}',
   },
 
   // 選項
@@ -292,6 +327,9 @@ export default {
     useCmd: '透過 cmd 啟動',
     useCmdHint:
       '啟用時透過 cmd /c 啟動程式，使其脫離當前進程樹。一些遊戲可能會檢測進程樹（僅 Windows 生效）',
+    hideWindow: '隱藏主控台視窗',
+    hideWindowHint:
+      '啟用時啟動命令列程式不再彈出黑色主控台視窗，需要查看輸出時請關閉（僅 Windows 生效）',
     preActionSkipped: '前置程式 {{name}} 已在運行，跳過執行',
     waitingForDevice: '正在等待裝置就緒...',
     waitingForWindow: '正在等待視窗就緒...',
@@ -316,6 +354,8 @@ export default {
     pretaskExitCode: '預任務結束碼: {{code}}',
     pretaskFailed: '預任務執行失敗: {{error}}',
     preActionConnectDelay: '等待 {{seconds}} 秒後連線...',
+    resourceIntegrityWarning:
+      '資源檔案缺失 {{missing}} 個、被改動 {{modified}} 個（{{files}}），可能被防毒軟體刪除或已損壞，建議重新下載資源',
     autoPreActionName: '▶️ 啟動 {{name}}',
     autoPreActionAdded: '已自動新增前置程式: {{name}}（預設未啟用）',
     removeConfirmTitle: '刪除前置程式',
@@ -355,6 +395,13 @@ export default {
     exportFileFailed: '匯出失敗：無法寫入檔案',
     exportShareHint: '「{{tabName}}」的 {{projectName}} 設定，分享給你囉~',
     exportShareFooter: '👆 複製這段文字，在 {{projectName}} 裡新建標籤頁，點「匯入設定」就能直接用',
+    shareCodeCopied: '分享碼已複製到剪貼簿',
+    shareCodeExportFailed: '產生分享碼失敗：{{error}}',
+    shareCodeImported: '已從分享碼追加 {{count}} 個任務',
+    shareCodeImportedPartial:
+      '已從分享碼追加 {{count}} 個任務，{{skipped}} 個目前專案中不存在的任務已略過',
+    shareCodeNoTasks: '分享碼中沒有目前專案可用的任務',
+    shareCodeImportFailed: '匯入分享碼失敗：{{error}}',
   },
 
   // 控制器
@@ -384,6 +431,23 @@ export default {
     savedDeviceNotFound: '未找到上次的裝置，請檢查連接或重新選擇',
     savedWindowNotFound: '未找到上次的視窗，請檢查連接或重新選擇',
     connectedLog: '已連接控制器 [{{name}}]',
+    resolutionMismatch:
+      '截圖寬高比（{{actual}}）與資源預期不符，辨識可能全部失敗，建議將模擬器解析度設為 {{expected}}',
+    usbDevice: 'USB 實機',
+    usbUnauthorized: '未授權',
+    usbUnauthorizedHint: '手機未授權 USB 偵錯，請在手機上點選「允許」後重新整理裝置列表',
+    usbOfflineHint: '裝置目前狀態為 {{state}}，請重新插拔傳輸線後重新整理裝置列表',
+    usbDeviceHint:
+      '已連線 USB 實機：實機截圖較慢，截圖失敗時可嘗試其他截圖方式；解析度與資源要求不符時請在手機上調整顯示設定',
+    emulatorLaunch: '連線前啟動模擬器',
+    emulatorLaunchHint: '連線時若目標裝置或視窗不存在，先啟動該程式並等待就緒',
+    emulatorLaunchProgram: '模擬器程式',
+    emulatorLaunchArgs: '啟動參數',
+    emulatorLaunchAdbAddress: 'ADB 位址',
+    emulatorLaunchAdbAddressHint: '模擬器啟動後等待出現的裝置位址',
+    emulatorLaunchTimeout: '等待逾時',
+    emulatorLaunchSettle: '就緒後等待',
+    emulatorLaunchSeconds: '秒',
   },
 
   // 資源
@@ -391,6 +455,7 @@ export default {
     title: '資源包',
     selectResource: '選擇資源包',
     loading: '載入資源中...',
+    loadingProgress: '載入資源中（{{done}}/{{total}}）...',
     loaded: '資源已載入',
     loadFailed: '資源載入失敗',
     loadResource: '載入資源',
@@ -400,6 +465,12 @@ export default {
     incompatibleController: '不支援目前控制器',
   },
 
+  // 後端健康檢查
+  health: {
+    backendUnresponsive: '後端長時間無回應，可能已卡住。可嘗試匯出日誌後重新啟動程式',
+    backendRecovered: '後端已恢復回應',
+  },
+
   // MaaFramework
   maa: {
     notInitialized: 'MaaFramework 未初始化',
@@ -420,6 +491,12 @@ export default {
     fullscreen: '全螢幕顯示',
     exitFullscreen: '退出全螢幕',
     clickHint: '點擊畫面可向裝置發送點擊',
+    noLastFrame: '暫無任務結束時的截圖',
+    lastFrameLabel: '{{status}} · {{time}}',
+    lastFrameStatus: {
+      succeeded: '任務完成',
+      failed: '任務失敗',
+    },
     // 幀率設定
     frameRate: {
       title: '即時截圖幀率',
@@ -434,11 +511,25 @@ export default {
 
   // 日誌/資訊流
   logs: {
+    section: {
+      succeeded: '完成 · {{count}} 筆',
+      failed: '失敗 · {{count}} 筆',
+      running: '執行中 · {{count}} 筆',
+    },
     title: '執行日誌',
     clear: '清空',
+    openOverlay: '開啟日誌懸浮窗',
+    closeOverlay: '關閉懸浮窗',
+    overlayPreview: '畫面預覽',
+    overlayLogs: '日誌',
+    overlayNoInstance: '沒有可預覽的實例',
+    overlayWaitingFrame: '等待畫面…',
+    openOverlayFailed: '開啟日誌懸浮窗失敗: {{error}}',
     autoscroll: '自動捲動',
     noLogs: '暫無日誌',
     copyAll: '複製全部',
+    exportInstanceLogs: '匯出目前實例日誌',
+    copyFailed: '複製失敗，請手動選取文字複製',
     showMoreLogs: '顯示更多日誌',
     expand: '展開上方面板',
     collapse: '摺疊上方面板',
@@ -460,6 +551,8 @@ export default {
       taskStarting: '任務開始: {{name}}',
       taskSucceeded: '任務完成: {{name}}',
       taskFailed: '任務失敗: {{name}}',
+      taskInterrupted: '任務已中斷，稍後重新執行: {{name}}',
+      taskReportGenerated: '任務報告已產生: {{path}}',
       stopTask: '停止任務',
       // 定時任務訊息
       scheduleStarting: '定時執行開始 [{{policy}}] {{time}}',
@@ -473,6 +566,46 @@ export default {
       agentLogFloodWarning:
         'Agent 處於日誌風暴狀態，為避免效能問題已暫停顯示日誌，完整日誌可在本機日誌檔案中查看',
       agentLogFloodRecovered: 'Agent 日誌風暴狀態已緩解',
+      agentRestarting:
+        'Agent 程序意外結束（結束碼 {{code}}），正在自動重啟（第 {{attempt}}/{{max}} 次）',
+      agentRestarted: 'Agent 已重啟，繼續執行剩餘 {{remaining}} 個任務',
+      agentRestartFailed: 'Agent 重啟失敗：{{error}}',
+      agentRestartGaveUp: 'Agent 已連續崩潰 {{max}} 次，停止自動重啟',
+      taskPreempted: '高優先級任務 {{name}} 搶佔執行，{{count}} 個任務將在其完成後恢復',
+      taskRetrying: '任務 {{name}} 失敗，正在進行第 {{attempt}}/{{max}} 次重試',
+      taskRetryFailed: '任務 {{name}} 重試失敗：{{error}}',
+      taskSkippedByCondition: '任務 {{name}} 滿足跳過條件 {{expression}}，已跳過',
+      taskConditionInvalid: '任務 {{name}} 的跳過條件無效，照常執行：{{error}}',
+      taskValidationPassed: '任務 {{name}} 校驗通過：{{nodes}} 個可達節點，{{warnings}} 個警告',
+      taskValidationFailed: '任務 {{name}} 校驗未通過：{{errors}} 個錯誤',
+      taskValidationIssue: '任務 {{name}} 校驗：{{location}} {{message}}',
+      taskValidationError: '任務 {{name}} 校驗失敗：{{error}}',
+      roundCompleted: '第 {{round}}/{{total}} 輪執行完畢',
+      sectionStart: '── {{name}} 開始 ──',
+      sectionEnd: '── {{name}} 結束 ──',
+      sectionRound: '{{name}}（第 {{round}} 輪）',
+      pipelineHotUpdated: '任務「{{name}}」的設定已更新，從下一個節點開始生效',
+      pipelineHotDeferred: '{{fields}} 所在節點正在執行，待其下次執行時生效',
+      pipelineHotRestart: '{{fields}} 在本次執行中不再生效，需重新開始任務',
+      pipelineHotNextRound: '{{fields}} 在本輪中不再生效，將從下一輪開始生效',
+      roundCompletedNext: '第 {{round}}/{{total}} 輪執行完畢，{{seconds}} 秒後開始下一輪',
+      watchdogTimeout: '執行超過 {{minutes}} 分鐘上限，看門狗已停止任務',
+      windowMinimized: '目標視窗已最小化，截圖會失敗或黑屏，請恢復視窗',
+      windowMinimizedPaused: '目標視窗已最小化，任務將在下一輪辨識前暫停，恢復視窗後自動繼續',
+      windowMinimizedRestore: '目標視窗已最小化，將在下一輪辨識前自動恢復視窗',
+      windowRestored: '目標視窗已恢復',
+      emulatorLaunching: '已啟動模擬器 {{name}}，等待裝置就緒...',
+      emulatorAlreadyRunning: '模擬器 {{name}} 已在執行，等待裝置就緒...',
+      emulatorReady: '模擬器已就緒，開始連線',
+      emulatorTimeout: '等待模擬器 {{name}} 就緒逾時',
+      adbUnauthorized: '裝置 {{address}} 未授權偵錯，請在裝置上點擊「允許 USB 偵錯」',
+      adbReauthorized: '裝置 {{address}} 已授權',
+      adbReauthorizedReconnected: '裝置 {{address}} 已重新授權，已自動重新連線',
+      adbAuthTimeout: '等待裝置 {{address}} 授權逾時，請在裝置上允許偵錯後重新連線',
+      programUntrusted: '程式不在信任清單中，已拒絕執行（{{source}}）：{{program}}',
+      programRejected: '已拒絕執行程式（{{source}}）：{{program}}',
+      dryRunAction: '[dry-run] {{node}} 將執行 {{action}}（辨識命中位置）',
+      dryRunActionAt: '[dry-run] {{node}} 將執行 {{action}} ({{x}}, {{y}})',
       // 快捷鍵
       hotkeyDetected: '偵測到快捷鍵: {{combo}}（{{action}}）',
       hotkeyActionStart: '開始任務',
@@ -515,6 +648,9 @@ export default {
     interfaceVersion: '{{name}} 版本',
     maafwVersion: 'maafw 版本',
     mxuVersion: 'mxu 版本',
+    agentVersion: 'agent 版本',
+    buildCommit: '編譯提交',
+    buildTime: '編譯時間',
     environment: '執行環境',
     envTauri: 'Tauri 桌面端',
     envBrowser: '瀏覽器',
@@ -522,11 +658,65 @@ export default {
     operatingSystem: '作業系統',
     architecture: '系統架構',
     tauriVersion: 'Tauri 版本',
+    exeVolume: '程式所在磁碟區',
+    exeVolumeReadonly: '唯讀',
+    controllerInfo: '控制器詳情',
+    controllerInfoRefresh: '重新整理',
+    controllerStatus: '狀態',
+    controllerStatusValue: {
+      connected: '已連線',
+      disconnected: '未連線',
+      none: '尚未建立控制器',
+    },
+    controllerType: '類型',
+    controllerTarget: '連線目標',
+    controllerScreencap: '截圖方式',
+    controllerInput: '輸入方式',
+    controllerResolution: '解析度',
+    controllerShortSide: '截圖短邊',
+    cpuFeatures: 'CPU 指令集',
+    cpuFeaturesMissing: '（缺少 {{features}}，無法執行任務）',
     pathInfo: '路徑資訊',
     cwd: '目前工作目錄',
     exeDir: '程式所在目錄',
     webview2Dir: 'WebView2 目錄',
     webview2System: '系統',
+    webview2Shared: '共用 WebView2 執行環境',
+    webview2SharedHint:
+      '獨立執行環境安裝到 %LOCALAPPDATA%\\MXU，供多個 MXU 共用以節省空間，重啟後生效',
+    webviewGpuDisabled: '停用 WebView2 GPU 加速',
+    webviewGpuDisabledHint: '介面出現花屏、白屏或閃爍時開啟，改用軟體算繪，重啟後生效',
+    adbPath: 'ADB 路徑',
+    adbPathHint: '模擬器內建 adb 與系統 adb 衝突導致連線失敗時，指定要使用的 adb 執行檔',
+    adbPathPlaceholder: '留空則自動尋找',
+    adbPathSaved: '已使用指定的 adb，找到 {{count}} 個裝置',
+    adbPathInvalid: '指定的 adb 無法使用：{{error}}',
+    toolkitConfig: '裝置搜尋目錄',
+    toolkitConfigHint:
+      '冷門模擬器或自訂安裝位置搜不到裝置時，補充 adb 所在目錄或模擬器安裝目錄（每行一個）',
+    extraAdbDirs: '額外 adb 目錄',
+    extraAdbDirsPlaceholder: 'adb 所在目錄或 adb 執行檔路徑',
+    emulatorDirs: '模擬器安裝目錄',
+    emulatorDirsPlaceholder: '會在其子目錄中尋找 adb',
+    toolkitConfigSaved: '已儲存，找到 {{count}} 個可用的 adb',
+    toolkitConfigFailed: '儲存裝置搜尋目錄失敗：{{error}}',
+    processMonitor: '資源監視',
+    processMonitorHint: '查看 MXU 和 agent 行程的 CPU、記憶體佔用，排查卡頓或回報效能問題時使用',
+    processMonitorProcess: '行程',
+    processMonitorMemory: '記憶體',
+    processMonitorThreads: '執行緒',
+    processMonitorUptime: '執行時長',
+    processMonitorAgent: 'Agent（{{name}}）',
+    processMonitorCopy: '複製資料',
+    processMonitorCopied: '已複製資源佔用資料',
+    agentResourceLimits: 'Agent 資源限制',
+    agentResourceLimitsHint:
+      '透過 Job Object 限制 agent 子程序，0 表示不限制；MXU 結束時自動結束所有 agent 程序',
+    agentMemoryLimit: '單一 agent 記憶體上限（MB）',
+    agentCpuLimit: '所有 agent 合計 CPU 配額（%）',
+    updateCopyConcurrency: '更新平行複製數',
+    updateCopyConcurrencyHint:
+      '增量更新時同時複製檔案的執行緒數，0 表示依 CPU 核心數；目標在機械硬碟上時自動改為序列',
     resetWindowSize: '重設視窗尺寸',
     openConfigDir: '開啟設定目錄',
     openLogDir: '開啟日誌目錄',
@@ -540,6 +730,97 @@ export default {
     devModeHint: '啟用後允許按 F5 重新整理 UI',
     saveDraw: '儲存除錯圖像',
     saveDrawHint: '儲存識別和操作的除錯圖像到日誌目錄（重啟軟體後自動關閉）',
+    verboseLog: '詳細日誌',
+    verboseLogHint: '記錄除錯級別的詳細日誌，關閉後僅記錄資訊及以上級別，立即生效',
+    eventLog: '結構化事件日誌',
+    eventLogHint:
+      '將任務開始/結束、辨識結果和錯誤以 JSONL 格式額外寫入 debug/logs/events.jsonl，便於腳本分析',
+    failureScreenshot: '任務失敗截圖',
+    failureScreenshotHint:
+      '任務失敗時在日誌中附帶當時的截圖（過大時自動縮小），便於定位卡住的畫面和回報問題',
+    actionTrace: '點擊軌跡可視化',
+    actionTraceHint:
+      '任務執行點擊、滑動時推送座標事件，可在截圖預覽上標出點擊點和滑動軌跡，便於確認到底點了哪裡',
+    breakpoints: 'Pipeline 中斷點',
+    breakpointsHint: '目前實例執行到這些節點前暫停，可查看畫面後繼續',
+    breakpointsPlaceholder: '節點名稱，多個以逗號分隔',
+    dryRun: 'Dry-run 模式',
+    dryRunHint: '目前實例之後啟動的任務只截圖和辨識，跳過所有動作並在日誌中記錄本該執行的操作',
+    speed: '操作速度',
+    speedHint:
+      '縮放目前實例之後啟動任務中每個動作前後的等待（0.25 ~ 4，預設 1）。模擬器反應慢、容易漏點時調低，裝置效能好時調高以節省時間',
+    captureMode: '截圖防遮擋',
+    captureModeHint:
+      '僅 Win32 控制器：前景模式在每輪辨識前把視窗提到前景；PrintWindow 模式在重新連線後生效',
+    captureModeDefault: '預設',
+    captureModeForeground: '截圖前置前景',
+    captureModePrintWindow: 'PrintWindow',
+    minimizedPolicy: '最小化處理',
+    minimizedPolicyHint:
+      '僅 Win32 控制器：目標視窗最小化時截圖會失敗或黑屏，可選擇暫停任務直到視窗恢復，或自動恢復視窗（不搶佔前景）',
+    minimizedPolicyNotify: '僅提示',
+    minimizedPolicyPause: '暫停任務',
+    minimizedPolicyRestore: '自動恢復視窗',
+    frameDedup: '截圖影格去重',
+    frameDedupHint: '畫面連續未變化時逐步降低辨識頻率以節省 CPU，畫面變化後立即恢復（目前實例）',
+    frameDedupSimilarity: '相似度閾值',
+    unknownScreens: '未知介面收集',
+    unknownScreensHint:
+      '連續多輪辨識全部未命中時，自動把目前介面截圖和上下文歸檔到 unknown_screens 目錄（相似截圖只保留一張，最多 200 張），可打包回饋給資源開發者補充辨識',
+    unknownScreensThreshold: '連續失敗次數',
+    unknownScreensCount: '已收集 {{count}} 張',
+    unknownScreensOpen: '開啟目錄',
+    unknownScreensExport: '打包',
+    unknownScreensExported: '已打包未知介面',
+    unknownScreensExportFailed: '打包未知介面失敗：{{error}}',
+    preprocess: '截圖預處理',
+    preprocessHint:
+      '送去辨識前統一調整截圖的亮度、對比度和 gamma，改善偏暗或偏色畫面的辨識（目前實例）',
+    preprocessBrightness: '亮度',
+    preprocessContrast: '對比度',
+    preprocessGamma: 'Gamma',
+    preprocessNeedsReconnect: '截圖預處理將在重新連接控制器後生效',
+    taskRetry: '任務失敗重試',
+    taskRetryHint: '目前實例的任務失敗後依策略自動重試，重試次數用盡仍失敗才結束',
+    taskRetryMaxRetries: '重試次數',
+    taskRetryInterval: '重試間隔（秒）',
+    taskRetryReconnect: '重試前重新連線控制器',
+    taskRetryRestartApp: '重試前重新啟動應用程式',
+    taskRetryRestartAppPlaceholder: '套件名稱或 Activity，留空不重新啟動',
+    taskRetryFailed: '設定失敗重試策略失敗：{{error}}',
+    watchdog: '執行時長看門狗',
+    watchdogHint: '目前實例的任務執行超過上限時自動停止',
+    watchdogMaxMinutes: '最大執行時長（分鐘）',
+    watchdogCountPaused: '計入中斷點暫停時間',
+    watchdogFailed: '設定看門狗失敗：{{error}}',
+    benchmark: '效能測試',
+    benchmarkHint: '測試目前實例的截圖與辨識耗時，結果匯出到日誌目錄',
+    benchmarkScreencap: '截圖',
+    benchmarkFailed: '失敗',
+    benchmarkOpenResult: '開啟結果檔案',
+    recordClip: '錄製錄影',
+    annotatedScreenshot: '標註截圖',
+    annotatedScreenshotHint: '儲存目前截圖並畫出最近一輪的辨識框與分數，方便回報問題',
+    annotatedScreenshotSaved: '已儲存標註截圖（{{count}} 個辨識結果）',
+    annotatedScreenshotFailed: '儲存標註截圖失敗：{{error}}',
+    issueBundle: '問題包',
+    issueBundleHint:
+      '匯出辨識失敗時的截圖、辨識詳情、節點設定、override、日誌和系統資訊，附說明檔案',
+    issueBundleExported: '已匯出問題包',
+    issueBundleFailed: '匯出問題包失敗：{{error}}',
+    resourceManifestResetButton: '重建資源校驗清單',
+    resourceManifestResetHint:
+      '資源改動後以目前檔案為準重新產生校驗清單（隨資源包發佈的清單不受影響）',
+    resourceManifestReset: '已清除資源校驗清單，下次執行時依目前檔案重新產生',
+    resourceManifestResetFailed: '重建資源校驗清單失敗：{{error}}',
+    recordClipHint: '以 2 FPS 錄製目前實例的截圖序列，停止時匯出到日誌目錄的 clips 資料夾',
+    clipExportGif: '停止並匯出 GIF',
+    clipExportMp4: '停止並匯出 MP4',
+    clipSummary: '{{frames}} 幀 · {{seconds}} 秒',
+    clipDropped: '超出錄製上限，已捨棄最早的 {{count}} 幀',
+    clipMp4Fallback: '找不到可用的 ffmpeg，已改為匯出 GIF',
+    clipOpenResult: '開啟錄影檔案',
+    openFileFailed: '開啟失敗：{{error}}',
     tcpCompatMode: '通訊相容模式',
     tcpCompatModeHint: '若啟動任務後軟體立即閃退，可嘗試開啟。僅限此情況使用，否則會影響運行效率',
     webServerEnabled: '啟用 Web 服務',
@@ -577,6 +858,7 @@ export default {
   // 執行個體
   instance: {
     defaultName: '設定',
+    duplicateName: '已有同名設定「{{name}}」，建議使用不同名稱以便區分',
   },
 
   // 連接面板
@@ -588,6 +870,7 @@ export default {
   dashboard: {
     title: '中控台',
     toggle: '中控台檢視',
+    stopAll: '全部停止',
     exit: '退出中控台',
     instances: '個執行個體',
     noInstances: '暫無執行個體',
@@ -651,6 +934,9 @@ export default {
     downloadFailed: '下載失敗',
     viewDetails: '查看詳情',
     noDownloadUrl: '無可用下載連結，請填寫 CDK 或檢查網路環境',
+    networkUnavailable: '網路無法使用',
+    networkUnavailableHint: '無法連線到下載伺服器，請檢查網路或代理設定，也可以在瀏覽器中手動下載',
+    manualDownload: '手動下載',
     openFolder: '開啟目錄',
     retry: '重試',
     preparingDownload: '準備下載...',
@@ -728,6 +1014,11 @@ export default {
     startedAt: '開始時間: {{time}}',
     deletePolicyTitle: '刪除策略',
     deletePolicyConfirm: '確定要刪除策略「{{name}}」嗎？',
+    queueLoop: '循環執行',
+    queueLoopRounds: '輪數（0 為無限）',
+    queueLoopInterval: '每輪間隔（秒）',
+    queueLoopHint: '任務佇列執行完後按設定輪數重複執行，停止任務時在目前任務處結束循環',
+    queueLoopSkipped: '任務列表含收尾特殊任務，本次只執行一輪',
     // 索引對應 Date.getDay()：0=週日, 1=週一, ..., 6=週六
     weekdays: ['週日', '週一', '週二', '週三', '週四', '週五', '週六'],
   },
@@ -740,6 +1031,20 @@ export default {
     taskNotFound: '任務不存在',
     controllerNotFound: '控制器不存在',
     resourceNotFound: '資源包不存在',
+    codes: {
+      INSTANCE_NOT_FOUND: '實例不存在',
+      CONTROLLER_NOT_CONNECTED: '控制器未連線',
+      CONTROLLER_CONNECT_FAILED: '控制器連線失敗',
+      RESOURCE_NOT_LOADED: '資源未載入',
+      RESOURCE_LOAD_FAILED: '資源載入失敗',
+      TASK_RUN_FAILED: '任務提交失敗',
+      TASK_PREEMPTING: '正在搶佔執行其他任務，請稍後重試',
+      DOWNLOAD_FAILED: '下載失敗',
+      DOWNLOAD_CANCELLED: '下載已取消',
+      UPDATE_FAILED: '更新安裝失敗',
+      INVALID_ARGUMENT: '參數無效',
+      INTERNAL: '內部錯誤',
+    },
   },
 
   // 右鍵選單
@@ -759,9 +1064,11 @@ export default {
     exportConfig: '匯出設定',
     exportToClipboard: '匯出到剪貼簿',
     exportToTxt: '匯出為 txt 檔案',
+    exportToShareCode: '複製分享碼',
     importConfig: '匯入設定',
     importFromClipboard: '從剪貼簿匯入',
     importFromTxt: '從 txt 檔案匯入',
+    importFromShareCode: '從分享碼匯入',
 
     // 前置程式右鍵選單
     duplicateAction: '複製',
@@ -785,6 +1092,8 @@ export default {
     moveToBottom: '置底',
     expandOptions: '展開選項',
     collapseOptions: '摺疊選項',
+    editSkipCondition: '設定跳過條件',
+    validateTask: '校驗任務設定',
     selectAll: '全選任務',
     deselectAll: '取消全選',
     expandAllTasks: '展開全部',
@@ -798,6 +1107,7 @@ export default {
     fullscreen: '全螢幕顯示',
     saveScreenshot: '儲存截圖',
     copyScreenshot: '複製截圖',
+    viewLastFrame: '檢視最後一幀',
 
     // 連接面板右鍵選單
     refreshDevices: '重新整理裝置列表',
@@ -860,6 +1170,16 @@ export default {
     desktopHint: '如需更穩定的體驗，建議使用桌面用戶端',
   },
 
+  // 中斷點除錯
+  breakpoint: {
+    title: '已命中中斷點 - {{instance}}',
+    pausedAt: '任務已暫停，即將執行節點：',
+    noScreenshot: '暫無截圖',
+    recognition: '辨識結果',
+    clearAndContinue: '移除所有中斷點並繼續',
+    continue: '繼續',
+  },
+
   // 程式路徑問題提示
   badPath: {
     title: '程式位置不對',
@@ -871,15 +1191,36 @@ export default {
       '程式現在在暫存目錄裡跑著呢，一關掉可能就沒了。先把壓縮檔解壓到一個資料夾裡，再開啟裡面的程式吧。',
     hint: '小提示：建議解壓到一個專門的資料夾，比如「D:\\MaaXXX」，别放桌面或者下載資料夾，那樣更方便管理。',
     exit: '退出程式',
+    networkTitle: '程式放在網路磁碟上了',
+    networkDescription: '網路磁碟上容易出現檔案被鎖定、更新失敗、日誌寫不進去等奇怪問題。建議把整個資料夾移到本機的固定硬碟上再用。',
+    removableTitle: '程式放在隨身碟/外接硬碟上了',
+    removableDescription: '可移動磁碟隨時可能中斷連線，執行中拔出會導致設定損壞或更新失敗。建議把整個資料夾移到本機的固定硬碟上再用。',
+    readonlyTitle: '程式所在的資料夾無法寫入',
+    readonlyDescription: '設定、日誌和更新都需要寫入程式目錄，現在寫不進去會導致各種問題。建議把整個資料夾移到有寫入權限的本機目錄。',
+    cloudTitle: '程式放在 OneDrive 同步目錄裡了',
+    cloudDescription: '雲端同步會鎖定正在使用的檔案，容易導致更新失敗、日誌寫不了。建議把整個資料夾移到不同步的本機目錄。',
+    continue: '仍然繼續',
   },
   // 代理設定
   proxy: {
     title: '網路代理',
     url: '代理位址',
     urlPlaceholder: '例如：http://127.0.0.1:7890',
-    urlHint: '支援 HTTP/SOCKS5，留空則不使用代理',
+    urlHint: '支援 HTTP/SOCKS5，留空則使用系統代理',
+    systemDetected: '目前使用系統代理：{{url}}',
+    systemNone: '未偵測到系統代理，將直接連線',
     urlHintDisabled: '已填寫 Mirror酱 CDK，代理功能已停用',
     invalid: '代理位址格式不正確',
     examples: '示例格式',
   },
+  cpuUnsupported: {
+    title: 'CPU 不受支援',
+    message: '您的 CPU 不支援 {{features}}，無法執行',
+    description:
+      '辨識與自動操作依賴的運算函式庫需要 CPU 支援上述指令集，在這台電腦上執行會直接當機，因此本次不會載入 MaaFramework。',
+    hint:
+      '2011 年之後的大多數 Intel / AMD 處理器都支援 AVX。如果 CPU 本身支援，請檢查虛擬機或 BIOS 是否遮蔽了 AVX。',
+    exit: '結束程式',
+    continue: '仍然開啟（無法執行任務）',
+  },
 };
@@ -2,13 +2,17 @@ import React from 'react';
 import ReactDOM from 'react-dom/client';
 import { Provider as TooltipProvider } from '@radix-ui/react-tooltip';
 import App from './App';
+import { LogOverlay } from './components/LogOverlay';
 import './i18n';
 import './index.css';
 
+// 日志悬浮窗与主窗口共用同一页面，以查询参数区分
+const isLogOverlay = new URLSearchParams(window.location.search).get('overlay') === 'log';
+
 ReactDOM.createRoot(document.getElementById('root')!).render(
   <React.StrictMode>
     <TooltipProvider delayDuration={300}>
-      <App />
+      {isLogOverlay ? <LogOverlay /> : <App />}
     </TooltipProvider>
   </React.StrictMode>,
 );
@@ -5,21 +5,85 @@ import { invoke } from '@tauri-apps/api/core';
 import { listen, UnlistenFn } from '@tauri-apps/api/event';
 import type {
   AdbDevice,
+  AnnotatedScreenshotResult,
+  AppPaths,
   Win32Window,
   ControllerConfig,
   ConnectionStatus,
+  ControllerInfo,
+  ResourceLoadedInfo,
   TaskStatus,
   AgentConfig,
   TaskConfig,
   InstanceRuntimeInfo,
+  CachedImageFrame,
+  ImageRoi,
+  ScreencapTicket,
+  SharePayload,
+  PipelineOverrideInfo,
+  BenchmarkResult,
+  RecoNodeStats,
+  NodeTimingStats,
+  InstanceSummary,
+  OverlayPreviewFrame,
+  LogJumpEvent,
+  CaptureMode,
+  MinimizedPolicy,
+  FrameDedupConfig,
+  UnknownScreenConfig,
+  UnknownScreensInfo,
+  ScreenshotPreprocess,
+  WindowStateEvent,
+  EmulatorLaunchConfig,
+  EmulatorLaunchEvent,
+  AdbAuthEvent,
+  ProgramTrustConfig,
+  ProgramTrustMode,
+  ProgramBlockedEvent,
+  VersionInfo,
+  ClipFormat,
+  ClipExportResult,
+  AgentStopOutcome,
+  InstanceStopResult,
+  ProcessInfo,
+  ResolutionCheck,
+  ResourceIntegrityReport,
+  TemplatePreview,
+  TaskPreemptedEvent,
+  TaskRetryEvent,
+  TaskConditionEvent,
+  TaskValidation,
+  LastFrame,
+  LogSectionEvent,
+  TaskQueueLoop,
+  TaskRoundCompletedEvent,
+  PipelineOverrideResult,
+  PipelineHotUpdatedEvent,
+  TaskRetryPolicy,
+  WatchdogConfig,
+  WatchdogTimeoutEvent,
 } from '@/types/maa';
+import type { ExpectedResolution } from '@/types/interface';
 import { loggers } from '@/utils/logger';
+import type {
+  AgentResourceLimits,
+  CompletionWebhookSettings,
+  ToolkitConfig,
+  LogLevel,
+  TaskReportScreenshots,
+  TaskReportSettings,
+} from '@/types/config';
 import { isTauri } from '@/utils/paths';
+import { toMxuError } from '@/utils/mxuError';
+import type { TaggedLogEntryDto } from '@/utils/logStdout';
 import { apiDelete, apiGet, apiPost, apiPut, getApiBase } from '@/utils/backendApi';
 import * as wsService from '@/services/wsService';
 
 const log = loggers.maa;
 
+/** 各实例最近一帧截图（帧未变化时后端返回 not_modified，直接复用） */
+const lastCachedFrames = new Map<string, { seq: number; dataUrl: string }>();
+
 /**
  * 从后端获取最新缓存截图，转换为 base64 data URL（浏览器专用）
  *
@@ -52,6 +116,18 @@ async function syncMaaVersionToStore(version: string): Promise<void> {
   }
 }
 
+/**
+ * 连接前下发实例保存的运行时设置（动态导入避免与 store 循环依赖）
+ */
+async function applySavedRuntimeSettings(instanceId: string): Promise<void> {
+  try {
+    const { applyInstanceRuntimeSettings } = await import('@/utils/instanceRuntimeSettings');
+    await applyInstanceRuntimeSettings(instanceId);
+  } catch (err) {
+    log.warn('恢复实例运行时设置失败:', err);
+  }
+}
+
 /** MaaFramework 回调事件载荷 */
 export interface MaaCallbackEvent {
   /** 消息类型，如 "Resource.Loading.Succeeded", "Controller.Action.Succeeded", "Tasker.Task.Succeeded" */
@@ -73,12 +149,68 @@ export interface MaaCallbackDetails {
   param?: unknown;
   entry?: string;
   name?: string;
+  /** 任务失败时附带的截图（PNG data URL，需开启任务失败截图） */
+  screenshot?: string;
+  /** 被高优先级任务抢占或因失败重试而中断的旧任务（已用新 ID 重新提交） */
+  preempted?: boolean;
 }
 
 export interface SelfStopRequestedEvent {
   instanceId: string;
 }
 
+/** 断点命中事件 */
+export interface BreakpointHitEvent {
+  instance_id: string;
+  node: string;
+  task_id: number | null;
+  /** 命中时的截图（PNG data URL） */
+  screenshot: string | null;
+  /** 命中节点的识别回调详情 */
+  recognition: Record<string, unknown> | null;
+}
+
+/** dry-run 模式下被跳过的动作 */
+export interface DryRunActionEvent {
+  instance_id: string;
+  node: string;
+  /** 原本要执行的动作类型 */
+  action: string;
+  /** 固定坐标目标的点击点，为空表示作用于识别命中位置 */
+  target: [number, number] | null;
+  /** 原动作的关键参数 */
+  detail: Record<string, unknown>;
+}
+
+/** ADB 文件传输进度事件 */
+export interface AdbTransferProgressEvent {
+  instance_id: string;
+  direction: 'push' | 'pull';
+  local: string;
+  remote: string;
+  transferred: number;
+  total: number;
+}
+
+/** 资源加载进度事件 */
+export interface ResourceLoadProgressEvent {
+  instance_id: string;
+  stage: 'started' | 'loading' | 'succeeded' | 'failed' | 'completed';
+  /** 当前资源包目录（started / completed 时为 null） */
+  path: string | null;
+  loaded: number;
+  failed: number;
+  total: number;
+}
+
+/** 后端健康检查结果（各字段为 null 表示对应子系统正忙） */
+export interface PingResult {
+  timestamp_ms: number;
+  maa_initialized: boolean | null;
+  instance_count: number | null;
+  running_instances: number | null;
+}
+
 /** MaaFramework 服务 */
 export const maaService = {
   /**
@@ -110,14 +242,34 @@ export const maaService = {
     return version;
   },
 
+  /**
+   * 重置后端全部运行时状态（销毁所有实例、清空缓存），之后需重新调用 init
+   * 有任务运行时会失败
+   */
+  async reset(): Promise<void> {
+    if (!isTauri()) return;
+    log.info('重置 MaaFramework 运行时状态');
+    await invoke('maa_reset');
+  },
+
+  /**
+   * 后端健康检查（不会阻塞，仅桌面端）
+   * @returns 非 Tauri 环境返回 null
+   */
+  async ping(): Promise<PingResult | null> {
+    if (!isTauri()) return null;
+    return await invoke<PingResult>('maa_ping');
+  },
+
   /**
    * 设置资源目录
    * @param resourceDir 资源目录路径
+   * @param instanceId 实例 ID（可选，传入时仅对该实例生效，否则设置全局默认目录）
    */
-  async setResourceDir(resourceDir: string): Promise<void> {
+  async setResourceDir(resourceDir: string, instanceId?: string): Promise<void> {
     if (!isTauri()) return;
-    log.info('设置资源目录:', resourceDir);
-    await invoke('maa_set_resource_dir', { resourceDir });
+    log.info('设置资源目录:', resourceDir, instanceId ? `, 实例: ${instanceId}` : '');
+    await invoke('maa_set_resource_dir', { resourceDir, instanceId: instanceId ?? null });
     log.info('设置资源目录成功');
   },
 
@@ -134,12 +286,35 @@ export const maaService = {
       if (version) await syncMaaVersionToStore(version);
       return version;
     }
-    const version = await invoke<string>('maa_get_version');
+    const { framework: version } = await this.getVersionInfo();
+    // 库未加载时视为未初始化，调用方据此决定是否调用 init
+    if (version === 'unknown') {
+      throw new Error('MaaFramework library not loaded');
+    }
     await syncMaaVersionToStore(version);
     log.info('MaaFramework 版本:', version);
     return version;
   },
 
+  /**
+   * 获取各组件版本（MXU、MaaFramework、Agent、编译提交和时间），用于反馈问题
+   */
+  async getVersionInfo(): Promise<VersionInfo> {
+    if (!isTauri()) {
+      const result = await apiGet<{ initialized: boolean; version: string | null }>(
+        '/maa/initialized',
+      );
+      return {
+        mxu: __MXU_VERSION__ || 'unknown',
+        framework: result.version || 'unknown',
+        agent: 'unknown',
+        commit: 'unknown',
+        build_time: 'unknown',
+      };
+    }
+    return await invoke<VersionInfo>('maa_get_version');
+  },
+
   /**
    * 检查 MaaFramework 版本是否满足最小要求
    */
@@ -165,12 +340,15 @@ export const maaService = {
 
   /**
    * 查找 ADB 设备
+   * @param adbPath 指定 adb 可执行文件路径（可选，不传时由框架自行查找）
    */
-  async findAdbDevices(): Promise<AdbDevice[]> {
-    log.info('搜索 ADB 设备...');
+  async findAdbDevices(adbPath?: string): Promise<AdbDevice[]> {
+    log.info('搜索 ADB 设备...', adbPath ? `adb: ${adbPath}` : '');
     const devices = isTauri()
-      ? await invoke<AdbDevice[]>('maa_find_adb_devices')
-      : await apiGet<AdbDevice[]>('/maa/devices');
+      ? await invoke<AdbDevice[]>('maa_find_adb_devices', { adbPath: adbPath || null })
+      : await apiGet<AdbDevice[]>(
+          adbPath ? `/maa/devices?adb_path=${encodeURIComponent(adbPath)}` : '/maa/devices',
+        );
     log.info('找到 ADB 设备:', devices.length, '个');
     devices.forEach((device, i) => {
       log.debug(
@@ -180,6 +358,707 @@ export const maaService = {
     return devices;
   },
 
+  /**
+   * 扫描指定主机上常见模拟器 ADB 端口（5555、7555、16384 等），返回能连上的设备
+   * @param adbPath adb 可执行文件路径
+   * @param host 主机地址（如 127.0.0.1）
+   */
+  async probeAdbPorts(adbPath: string, host: string): Promise<AdbDevice[]> {
+    log.info('探测 ADB 端口, host:', host);
+    const devices = isTauri()
+      ? await invoke<AdbDevice[]>('maa_probe_adb_ports', { adbPath, host })
+      : await apiGet<AdbDevice[]>(
+          `/maa/devices/probe?adb_path=${encodeURIComponent(adbPath)}&host=${encodeURIComponent(host)}`,
+        );
+    log.info('探测到 ADB 设备:', devices.length, '个');
+    return devices;
+  },
+
+  /**
+   * 生成本轮任务队列的汇总图片报告（仅桌面端）
+   * @param instanceId 实例 ID
+   * @param options 报告标题、任务显示名（selectedTaskId → 名称）、截图范围
+   * @returns 报告文件路径
+   */
+  async generateTaskReport(
+    instanceId: string,
+    options: {
+      title?: string;
+      task_names: Record<string, string>;
+      screenshots: TaskReportScreenshots;
+    },
+  ): Promise<string> {
+    return await invoke<string>('generate_task_report', { instanceId, options });
+  },
+
+  /**
+   * 设置任务报告是否启用及截图范围（仅桌面端），后端据此决定任务结束时是否保留截图
+   * @param settings 任务报告设置
+   */
+  async setTaskReportCapture(settings: TaskReportSettings): Promise<void> {
+    if (!isTauri()) return;
+    await invoke('set_task_report_capture', {
+      enabled: settings.enabled,
+      screenshots: settings.screenshots,
+    });
+  },
+
+  /**
+   * 设置任务队列完成 webhook（仅桌面端），队列全部结束时后端向 url POST 运行摘要
+   * @param settings 是否启用与 webhook URL
+   */
+  async setCompletionWebhook(settings: CompletionWebhookSettings): Promise<void> {
+    if (!isTauri()) return;
+    log.info('设置任务完成 webhook, 启用:', settings.enabled);
+    await invoke('set_completion_webhook', { settings });
+  },
+
+  /**
+   * 设置 Agent 子进程资源限制（仅桌面端 Windows 生效），立即对运行中的 agent 生效
+   * @param limits 内存上限（MB）与 CPU 配额（%），0 表示不限制
+   */
+  async setAgentResourceLimits(limits: AgentResourceLimits): Promise<void> {
+    if (!isTauri()) return;
+    log.info('设置 Agent 资源限制:', limits);
+    await invoke('set_agent_resource_limits', { limits });
+  },
+
+  /**
+   * 设置设备搜索的 Toolkit 配置（额外 adb 目录 / 模拟器安装目录，仅桌面端）
+   * @returns 按当前配置能找到的额外 adb 路径
+   */
+  async setToolkitConfig(config: ToolkitConfig): Promise<string[]> {
+    if (!isTauri()) return [];
+    log.info('设置 Toolkit 配置:', config);
+    return await invoke<string[]>('set_toolkit_config', { config });
+  },
+
+  /**
+   * 查询指定进程的资源占用（仅桌面端）
+   * @param pid 进程 ID，不传时查询 MXU 自身
+   */
+  async getProcessInfo(pid?: number): Promise<ProcessInfo> {
+    return await invoke<ProcessInfo>('get_process_info', { pid: pid ?? null });
+  },
+
+  /**
+   * 查询 MXU 自身和所有 agent 进程的资源占用（仅桌面端）
+   */
+  async getMonitoredProcesses(): Promise<ProcessInfo[]> {
+    if (!isTauri()) return [];
+    return await invoke<ProcessInfo[]>('get_monitored_processes');
+  },
+
+  /**
+   * 设置实例的 Pipeline 断点节点（覆盖原有断点，传空数组移除所有断点，仅桌面端）
+   * @param instanceId 实例 ID
+   * @param nodeNames 断点节点名列表
+   */
+  async setBreakpoints(instanceId: string, nodeNames: string[]): Promise<void> {
+    log.info('设置断点, 实例:', instanceId, ', 节点:', nodeNames);
+    await invoke('set_breakpoints', { instanceId, nodeNames });
+  },
+
+  /**
+   * 获取实例当前设置的断点节点（仅桌面端）
+   * @param instanceId 实例 ID
+   */
+  async getBreakpoints(instanceId: string): Promise<string[]> {
+    if (!isTauri()) {
+      return [];
+    }
+    return await invoke<string[]>('get_breakpoints', { instanceId });
+  },
+
+  /**
+   * 从当前断点继续执行
+   * @param instanceId 实例 ID
+   * @returns 是否有处于暂停状态的任务
+   */
+  async continueFromBreakpoint(instanceId: string): Promise<boolean> {
+    log.info('从断点继续, 实例:', instanceId);
+    return await invoke<boolean>('continue_from_breakpoint', { instanceId });
+  },
+
+  /**
+   * 监听断点命中（任务已暂停，等待继续）
+   */
+  async onBreakpointHit(callback: (payload: BreakpointHitEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<BreakpointHitEvent>('maa-breakpoint-hit', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听断点继续（继续、断点被清除或任务停止）
+   */
+  async onBreakpointResumed(
+    callback: (payload: { instance_id: string; node: string }) => void,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<{ instance_id: string; node: string }>(
+      'maa-breakpoint-resumed',
+      (event) => {
+        callback(event.payload);
+      },
+    );
+  },
+
+  /**
+   * 设置实例的最大运行时长看门狗（超时自动停止任务，仅桌面端）
+   * @param instanceId 实例 ID
+   * @param maxRuntimeSecs 最大运行时长（秒），null 或 0 表示不限制
+   * @param countPaused 停在断点上的时间是否计入（默认不计入）
+   */
+  async setWatchdog(
+    instanceId: string,
+    maxRuntimeSecs: number | null,
+    countPaused: boolean = false,
+  ): Promise<void> {
+    if (!isTauri()) return;
+    log.info('设置看门狗, 实例:', instanceId, maxRuntimeSecs, countPaused);
+    await invoke('set_watchdog', { instanceId, maxRuntimeSecs, countPaused });
+  },
+
+  /**
+   * 获取实例的看门狗配置
+   * @param instanceId 实例 ID
+   * @returns 未设置时返回 null
+   */
+  async getWatchdog(instanceId: string): Promise<WatchdogConfig | null> {
+    if (!isTauri()) return null;
+    return await invoke<WatchdogConfig | null>('get_watchdog', { instanceId });
+  },
+
+  /**
+   * 监听看门狗超时（后端已停止任务）
+   */
+  async onWatchdogTimeout(callback: (payload: WatchdogTimeoutEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<WatchdogTimeoutEvent>('watchdog-timeout', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 请求主窗口日志面板跳转到指定序号的日志（仅桌面端）
+   * @param seq 后端分配的日志序号
+   */
+  async jumpToLog(seq: number): Promise<void> {
+    if (!isTauri()) return;
+    await invoke('jump_to_log', { seq });
+  },
+
+  /**
+   * 监听日志跳转请求
+   */
+  async onLogJump(callback: (payload: LogJumpEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<LogJumpEvent>('maa-log-jump', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 打开日志悬浮窗（手动打开的悬浮窗不会在任务结束后自动关闭）
+   */
+  async openLogOverlay(): Promise<void> {
+    if (!isTauri()) return;
+    await invoke('open_log_overlay');
+  },
+
+  /**
+   * 关闭日志悬浮窗
+   */
+  async closeLogOverlay(): Promise<void> {
+    if (!isTauri()) return;
+    await invoke('close_log_overlay');
+  },
+
+  /**
+   * 监听转发给日志悬浮窗的新日志（仅悬浮窗中使用）
+   */
+  async onOverlayLog(callback: (entry: TaggedLogEntryDto) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<TaggedLogEntryDto>('maa-overlay-log', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 设置悬浮窗画面预览的实例（仅悬浮窗中使用），null 表示切回日志模式
+   * @param instanceId 实例 ID
+   */
+  async setOverlayPreview(instanceId: string | null): Promise<void> {
+    if (!isTauri()) return;
+    await invoke('set_overlay_preview', { instanceId });
+  },
+
+  /**
+   * 监听推送给悬浮窗的画面预览帧（仅悬浮窗中使用）
+   */
+  async onOverlayFrame(callback: (frame: OverlayPreviewFrame) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<OverlayPreviewFrame>('maa-overlay-frame', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听高优先级任务抢占（被中断的任务已用新 ID 重新提交）
+   */
+  async onTaskPreempted(callback: (payload: TaskPreemptedEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<TaskPreemptedEvent>('maa-task-preempted', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 设置实例的任务失败重试策略（仅桌面端，需实例已创建）
+   * @param instanceId 实例 ID
+   * @param policy 重试策略，null 表示关闭重试
+   */
+  async setTaskRetryPolicy(instanceId: string, policy: TaskRetryPolicy | null): Promise<void> {
+    log.info('设置任务失败重试策略, 实例:', instanceId, ', 策略:', policy);
+    await invoke('set_task_retry_policy', { instanceId, policy });
+  },
+
+  /**
+   * 查询实例的任务失败重试策略（仅桌面端）
+   * @param instanceId 实例 ID
+   * @returns 未设置时返回 null
+   */
+  async getTaskRetryPolicy(instanceId: string): Promise<TaskRetryPolicy | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    return await invoke<TaskRetryPolicy | null>('get_task_retry_policy', { instanceId });
+  },
+
+  /**
+   * 监听任务失败重试（重新提交的任务以新 ID 运行）
+   */
+  async onTaskRetry(callback: (payload: TaskRetryEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<TaskRetryEvent>('maa-task-retry', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听任务因跳过条件被跳过，或跳过条件有误而照常执行
+   */
+  async onTaskCondition(callback: (payload: TaskConditionEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<TaskConditionEvent>('maa-task-condition', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 检查跳过条件表达式的语法，有误时返回错误说明（非 Tauri 环境不检查）
+   * @param expression 跳过条件表达式
+   */
+  async checkSkipCondition(expression: string): Promise<string | null> {
+    if (!isTauri()) return null;
+    try {
+      await invoke('check_skip_condition', { expression });
+      return null;
+    } catch (err) {
+      return String(err);
+    }
+  },
+
+  /**
+   * 监听日志分段标记（每个任务开始与结束时各一次）
+   */
+  async onLogSection(callback: (payload: LogSectionEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<LogSectionEvent>('maa-log-section', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听任务队列循环执行时每轮结束
+   */
+  async onRoundCompleted(
+    callback: (payload: TaskRoundCompletedEvent) => void,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<TaskRoundCompletedEvent>('maa-round-completed', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听运行中热更新 Pipeline 的确认
+   */
+  async onPipelineHotUpdated(
+    callback: (payload: PipelineHotUpdatedEvent) => void,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<PipelineHotUpdatedEvent>('maa-pipeline-hot-updated', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 开启或关闭实例的 dry-run 模式（只识别不操作，对之后提交的任务生效，仅桌面端）
+   * @param instanceId 实例 ID
+   * @param enabled 是否开启
+   */
+  async setDryRun(instanceId: string, enabled: boolean): Promise<void> {
+    log.info('设置 dry-run, 实例:', instanceId, ', 开启:', enabled);
+    await invoke('set_dry_run', { instanceId, enabled });
+  },
+
+  /**
+   * 查询实例是否开启了 dry-run 模式（仅桌面端）
+   * @param instanceId 实例 ID
+   */
+  async getDryRun(instanceId: string): Promise<boolean> {
+    if (!isTauri()) {
+      return false;
+    }
+    return await invoke<boolean>('get_dry_run', { instanceId });
+  },
+
+  /**
+   * 设置实例的操作速度系数（缩放动作前后的等待，对之后提交的任务生效，仅桌面端）
+   * @param instanceId 实例 ID
+   * @param speed 速度系数，0.25 ~ 4，小于 1 放慢、大于 1 加快
+   */
+  async setSpeed(instanceId: string, speed: number): Promise<void> {
+    log.info('设置速度系数, 实例:', instanceId, ', 系数:', speed);
+    await invoke('maa_set_speed', { instanceId, speed });
+  },
+
+  /**
+   * 查询实例的操作速度系数（仅桌面端）
+   * @param instanceId 实例 ID
+   */
+  async getSpeed(instanceId: string): Promise<number> {
+    if (!isTauri()) {
+      return 1;
+    }
+    return await invoke<number>('maa_get_speed', { instanceId });
+  },
+
+  /**
+   * 设置实例的 Win32 截图防遮挡模式（仅桌面端）
+   * 前台模式立即生效，PrintWindow 模式下次连接时生效
+   * @param instanceId 实例 ID
+   * @param mode 截图防遮挡模式
+   */
+  async setCaptureMode(instanceId: string, mode: CaptureMode): Promise<void> {
+    log.info('设置截图防遮挡模式, 实例:', instanceId, ', 模式:', mode);
+    await invoke('set_capture_mode', { instanceId, mode });
+  },
+
+  /**
+   * 查询实例的 Win32 截图防遮挡模式（仅桌面端）
+   * @param instanceId 实例 ID
+   */
+  async getCaptureMode(instanceId: string): Promise<CaptureMode> {
+    if (!isTauri()) {
+      return 'default';
+    }
+    return await invoke<CaptureMode>('get_capture_mode', { instanceId });
+  },
+
+  /**
+   * 设置实例的 Win32 目标窗口最小化处理方式（仅桌面端，立即生效）
+   * @param instanceId 实例 ID
+   * @param policy 处理方式
+   */
+  async setMinimizedPolicy(instanceId: string, policy: MinimizedPolicy): Promise<void> {
+    log.info('设置窗口最小化处理方式, 实例:', instanceId, ', 方式:', policy);
+    await invoke('set_minimized_policy', { instanceId, policy });
+  },
+
+  /**
+   * 查询实例的 Win32 目标窗口最小化处理方式（仅桌面端）
+   * @param instanceId 实例 ID
+   */
+  async getMinimizedPolicy(instanceId: string): Promise<MinimizedPolicy> {
+    if (!isTauri()) {
+      return 'notify';
+    }
+    return await invoke<MinimizedPolicy>('get_minimized_policy', { instanceId });
+  },
+
+  /**
+   * 设置实例的截图帧去重（仅桌面端，立即生效）
+   * @param instanceId 实例 ID
+   * @param config 去重配置，null 表示关闭
+   */
+  async setFrameDedup(instanceId: string, config: FrameDedupConfig | null): Promise<void> {
+    log.info('设置截图帧去重, 实例:', instanceId, ', 配置:', config);
+    await invoke('set_frame_dedup', {
+      instanceId,
+      enabled: config !== null,
+      similarity: config?.similarity ?? null,
+      maxIntervalMs: config?.max_interval_ms ?? null,
+    });
+  },
+
+  /**
+   * 查询实例的截图帧去重配置（仅桌面端）
+   * @param instanceId 实例 ID
+   * @returns 未开启时返回 null
+   */
+  async getFrameDedup(instanceId: string): Promise<FrameDedupConfig | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    return await invoke<FrameDedupConfig | null>('get_frame_dedup', { instanceId });
+  },
+
+  /**
+   * 设置实例的未知界面收集（仅桌面端，立即生效）
+   * @param instanceId 实例 ID
+   * @param config 收集配置，null 表示关闭
+   */
+  async setUnknownScreenCapture(
+    instanceId: string,
+    config: UnknownScreenConfig | null,
+  ): Promise<void> {
+    log.info('设置未知界面收集, 实例:', instanceId, ', 配置:', config);
+    await invoke('set_unknown_screen_capture', {
+      instanceId,
+      enabled: config !== null,
+      threshold: config?.threshold ?? null,
+    });
+  },
+
+  /**
+   * 查询实例的未知界面收集配置（仅桌面端）
+   * @param instanceId 实例 ID
+   * @returns 未开启时返回 null
+   */
+  async getUnknownScreenCapture(instanceId: string): Promise<UnknownScreenConfig | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    return await invoke<UnknownScreenConfig | null>('get_unknown_screen_capture', { instanceId });
+  },
+
+  /**
+   * 查询已收集的未知界面（归档目录与截图数量，仅桌面端）
+   */
+  async getUnknownScreensInfo(): Promise<UnknownScreensInfo | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    return await invoke<UnknownScreensInfo>('get_unknown_screens_info');
+  },
+
+  /**
+   * 打包已收集的未知界面截图及上下文
+   * @param projectName 项目名称，用作压缩包文件名前缀
+   * @returns 压缩包路径
+   */
+  async exportUnknownScreens(projectName?: string): Promise<string> {
+    log.info('打包未知界面');
+    return await invoke<string>('export_unknown_screens', {
+      projectName: projectName ?? null,
+    });
+  },
+
+  /**
+   * 设置实例的截图预处理参数（仅桌面端）
+   * @param instanceId 实例 ID
+   * @param config 预处理参数，null 表示关闭
+   * @returns 是否需要重新连接控制器才能生效
+   */
+  async setScreenshotPreprocess(
+    instanceId: string,
+    config: ScreenshotPreprocess | null,
+  ): Promise<boolean> {
+    log.info('设置截图预处理, 实例:', instanceId, ', 参数:', config);
+    return await invoke<boolean>('set_screenshot_preprocess', { instanceId, config });
+  },
+
+  /**
+   * 查询实例的截图预处理参数（仅桌面端）
+   * @param instanceId 实例 ID
+   * @returns 未开启时返回 null
+   */
+  async getScreenshotPreprocess(instanceId: string): Promise<ScreenshotPreprocess | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    return await invoke<ScreenshotPreprocess | null>('get_screenshot_preprocess', { instanceId });
+  },
+
+  /**
+   * 监听 Win32 目标窗口最小化/恢复
+   */
+  async onWindowStateChanged(callback: (payload: WindowStateEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<WindowStateEvent>('maa-window-state-changed', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听连接前自动启动模拟器的进度
+   */
+  async onEmulatorLaunch(callback: (payload: EmulatorLaunchEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<EmulatorLaunchEvent>('maa-emulator-launch', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听 ADB 设备授权丢失与恢复
+   */
+  async onAdbAuth(callback: (payload: AdbAuthEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<AdbAuthEvent>('maa-adb-auth', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听外部程序因不在信任列表中被拒绝执行
+   */
+  async onProgramBlocked(callback: (payload: ProgramBlockedEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<ProgramBlockedEvent>('maa-program-blocked', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 获取外部程序信任策略与信任列表
+   */
+  async getProgramTrust(): Promise<ProgramTrustConfig> {
+    return await invoke<ProgramTrustConfig>('get_program_trust');
+  },
+
+  /**
+   * 设置外部程序信任策略
+   * @param mode trust_all 信任所有 / confirm 弹窗确认 / strict 只执行信任列表中的程序
+   */
+  async setProgramTrustMode(mode: ProgramTrustMode): Promise<void> {
+    log.info('设置外部程序信任策略:', mode);
+    await invoke('set_program_trust_mode', { mode });
+  },
+
+  /**
+   * 将程序移出信任列表
+   * @param program 信任列表中保存的路径
+   */
+  async untrustProgram(program: string): Promise<void> {
+    log.info('移出信任程序:', program);
+    await invoke('untrust_program', { program });
+  },
+
+  /**
+   * 监听 dry-run 模式下被跳过的动作
+   */
+  async onDryRunAction(callback: (payload: DryRunActionEvent) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<DryRunActionEvent>('maa-dry-run-action', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 通过实例已连接设备的 adb 推送本地文件到设备（仅 ADB 控制器、仅桌面端）
+   * @param instanceId 实例 ID
+   * @param local 本地文件路径
+   * @param remote 设备端绝对路径，以 / 结尾时视为目录
+   * @returns 设备端目标路径
+   */
+  async adbPush(instanceId: string, local: string, remote: string): Promise<string> {
+    log.info('ADB 推送文件:', local, '->', remote);
+    return await invoke<string>('maa_adb_push', { instanceId, local, remote });
+  },
+
+  /**
+   * 通过实例已连接设备的 adb 从设备拉取文件到本地（仅 ADB 控制器、仅桌面端）
+   * @param instanceId 实例 ID
+   * @param remote 设备端文件绝对路径
+   * @param local 本地路径，为已存在的目录时沿用设备端文件名
+   * @returns 本地目标路径
+   */
+  async adbPull(instanceId: string, remote: string, local: string): Promise<string> {
+    log.info('ADB 拉取文件:', remote, '->', local);
+    return await invoke<string>('maa_adb_pull', { instanceId, remote, local });
+  },
+
+  /**
+   * 监听 ADB 文件传输进度
+   */
+  async onAdbTransferProgress(
+    callback: (payload: AdbTransferProgressEvent) => void,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<AdbTransferProgressEvent>('maa-adb-transfer-progress', (event) => {
+      callback(event.payload);
+    });
+  },
+
   /**
    * 查找 Win32 窗口
    * @param classRegex 窗口类名正则表达式（可选）
@@ -232,16 +1111,44 @@ export const maaService = {
   /**
    * 创建实例
    * @param instanceId 实例 ID
+   * @param name 实例名称（可选，用于 listInstances 展示）
    */
-  async createInstance(instanceId: string): Promise<void> {
+  async createInstance(instanceId: string, name?: string): Promise<string> {
     log.info('创建实例:', instanceId);
     if (!isTauri()) {
-      await apiPut(`/maa/instances/${instanceId}`, {});
+      const result = await apiPut<{ name?: string }>(`/maa/instances/${instanceId}`, { name });
       log.info('创建实例成功 (HTTP):', instanceId);
-      return;
+      return result.name ?? instanceId;
     }
-    await invoke('maa_create_instance', { instanceId });
-    log.info('创建实例成功:', instanceId);
+    const displayName = await invoke<string>('maa_create_instance', { instanceId, name });
+    log.info('创建实例成功:', instanceId, displayName);
+    return displayName;
+  },
+
+  /**
+   * 设置实例显示名（用于托盘菜单、后端日志前缀和实例列表），名称允许重复
+   * @param instanceId 实例 ID
+   * @param name 显示名，空字符串表示清除
+   * @returns 新的显示名（清除后为实例 ID）
+   */
+  async setInstanceName(instanceId: string, name: string): Promise<string> {
+    if (!isTauri()) {
+      const result = await apiPut<{ name?: string }>(`/maa/instances/${instanceId}/name`, {
+        name,
+      });
+      return result.name ?? instanceId;
+    }
+    return await invoke<string>('maa_set_instance_name', { instanceId, name });
+  },
+
+  /**
+   * 列举所有实例的精简信息（按创建顺序）
+   */
+  async listInstances(): Promise<InstanceSummary[]> {
+    if (!isTauri()) {
+      return apiGet<InstanceSummary[]>('/maa/instances');
+    }
+    return invoke<InstanceSummary[]>('maa_list_instances');
   },
 
   /**
@@ -263,32 +1170,77 @@ export const maaService = {
    * 连接控制器（异步，通过回调通知完成状态）
    * @param instanceId 实例 ID
    * @param config 控制器配置
+   * @param launch 连接前自动启动模拟器的配置（见 getEmulatorLaunch），目标不存在时先拉起模拟器
    * @returns 连接请求 ID，通过监听 maa-callback 事件获取完成状态
    */
-  async connectController(instanceId: string, config: ControllerConfig): Promise<number> {
+  async connectController(
+    instanceId: string,
+    config: ControllerConfig,
+    launch?: EmulatorLaunchConfig,
+  ): Promise<number> {
     log.info('连接控制器, 实例:', instanceId, '类型:', config.type);
     log.debug('控制器配置:', config);
 
     if (!isTauri()) {
       log.info('浏览器环境，调用 HTTP API 连接控制器');
-      const result = await apiPost<{ connId: number }>(
-        `/maa/instances/${instanceId}/connect`,
-        config,
-      );
+      const result = await apiPost<{ connId: number }>(`/maa/instances/${instanceId}/connect`, {
+        ...config,
+        launch: launch ?? null,
+      });
       return result.connId;
     }
 
+    await applySavedRuntimeSettings(instanceId);
+
     try {
       const ctrlId = await invoke<number>('maa_connect_controller', {
         instanceId,
         config,
+        launch: launch ?? null,
       });
       log.info('控制器连接请求已发送, ctrlId:', ctrlId);
       return ctrlId;
     } catch (err) {
       log.error('控制器连接请求失败:', err);
-      throw err;
+      throw toMxuError(err);
+    }
+  },
+
+  /**
+   * 确保模拟器已启动且目标设备/窗口已就绪，不连接（仅桌面端）
+   * 自动连接时保存的设备要等模拟器启动后才能搜索到，先调用此方法再按设备名搜索
+   * @param instanceId 实例 ID
+   * @param target 等待的目标（见 buildEmulatorTargetConfig）
+   * @param launch 模拟器启动配置
+   */
+  async prepareEmulator(
+    instanceId: string,
+    target: ControllerConfig,
+    launch: EmulatorLaunchConfig,
+  ): Promise<void> {
+    if (!isTauri()) return;
+    log.info('确保模拟器已就绪, 实例:', instanceId, '程序:', launch.program);
+    try {
+      await invoke('maa_prepare_emulator', { instanceId, config: target, launch });
+    } catch (err) {
+      throw toMxuError(err);
+    }
+  },
+
+  /**
+   * 预检截图分辨率是否符合资源期望（仅桌面端）
+   * 不符时后端同时发出 maa-resolution-mismatch 事件
+   * @param instanceId 实例 ID
+   * @param expected interface.json 控制器声明的期望分辨率
+   */
+  async checkResolution(
+    instanceId: string,
+    expected: ExpectedResolution,
+  ): Promise<ResolutionCheck | null> {
+    if (!isTauri()) {
+      return null;
     }
+    return await invoke<ResolutionCheck>('maa_check_resolution', { instanceId, expected });
   },
 
   /**
@@ -306,6 +1258,18 @@ export const maaService = {
     return status;
   },
 
+  /**
+   * 获取当前控制器详情（类型、连接目标、截图/输入方式、分辨率、UUID，仅桌面端）
+   * @param instanceId 实例 ID
+   * @returns 未创建控制器时 status 为 none
+   */
+  async getControllerInfo(instanceId: string): Promise<ControllerInfo | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    return await invoke<ControllerInfo>('maa_get_controller_info', { instanceId });
+  },
+
   /**
    * 加载资源（异步，通过回调通知完成状态）
    * @param instanceId 实例 ID
@@ -326,11 +1290,31 @@ export const maaService = {
       log.info('资源加载请求已发送 (HTTP), resIds:', resIds);
       return resIds;
     }
-    const resIds = await invoke<number[]>('maa_load_resource', { instanceId, paths });
+    const resIds = await invoke<number[]>('maa_load_resource', { instanceId, paths }).catch(
+      (err) => {
+        throw toMxuError(err);
+      },
+    );
     log.info('资源加载请求已发送, resIds:', resIds);
     return resIds;
   },
 
+  /**
+   * 监听资源加载进度（仅桌面端）
+   * 提交时发出 started，每个资源包开始/结束时发出 loading/succeeded/failed，全部结束后发出 completed
+   */
+  async onResourceLoadProgress(
+    callback: (payload: ResourceLoadProgressEvent) => void,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<ResourceLoadProgressEvent>('maa-resource-load-progress', (event) => {
+      callback(event.payload);
+    });
+  },
+
   /**
    * 检查资源是否已加载
    * @param instanceId 实例 ID
@@ -341,11 +1325,23 @@ export const maaService = {
       return state?.resourceLoaded ?? false;
     }
     log.debug('检查资源是否已加载, 实例:', instanceId);
-    const loaded = await invoke<boolean>('maa_is_resource_loaded', { instanceId });
+    const info = await this.getResourceLoadedInfo(instanceId);
+    const loaded = info?.loaded ?? false;
     log.debug('资源加载状态:', instanceId, '->', loaded);
     return loaded;
   },
 
+  /**
+   * 获取已加载资源详情（是否已加载、资源目录、版本、hash、加载耗时，仅桌面端）
+   * @param instanceId 实例 ID
+   */
+  async getResourceLoadedInfo(instanceId: string): Promise<ResourceLoadedInfo | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    return await invoke<ResourceLoadedInfo>('maa_is_resource_loaded', { instanceId });
+  },
+
   /**
    * 获取已加载资源的 hash（用于完整性校验）
    * @param instanceId 实例 ID
@@ -359,6 +1355,17 @@ export const maaService = {
     return hash;
   },
 
+  /**
+   * 获取节点引用的模板图（仅桌面端）
+   * @param instanceId 实例 ID
+   * @param nodeName pipeline 节点名
+   * @returns 模板图列表（多模板节点或模板目录返回多张）
+   */
+  async getTemplatePreview(instanceId: string, nodeName: string): Promise<TemplatePreview[]> {
+    if (!isTauri()) return [];
+    return await invoke<TemplatePreview[]>('maa_get_template_preview', { instanceId, nodeName });
+  },
+
   /**
    * 销毁资源（用于切换资源时重新创建）
    * @param instanceId 实例 ID
@@ -370,12 +1377,50 @@ export const maaService = {
     log.info('销毁资源成功:', instanceId);
   },
 
+  /**
+   * 重新加载资源（按原加载顺序重建资源，使磁盘上的资源修改生效）
+   * @param instanceId 实例 ID
+   * @returns 资源加载请求 ID 列表
+   */
+  async reloadResource(instanceId: string): Promise<number[]> {
+    if (!isTauri()) return [];
+    log.info('重新加载资源, 实例:', instanceId);
+    return await invoke<number[]>('maa_reload_resource', { instanceId });
+  },
+
+  /**
+   * 按清单快速校验资源目录，发现缺失或被改动的文件（仅桌面端）
+   * 没有随资源包分发的清单时，首次校验按当前文件生成清单
+   * @param paths 资源目录列表
+   * @param rebuild 丢弃生成的清单并按当前文件重新生成
+   */
+  async verifyResourceIntegrity(
+    paths: string[],
+    rebuild?: boolean,
+  ): Promise<ResourceIntegrityReport[]> {
+    if (!isTauri()) return [];
+    return await invoke<ResourceIntegrityReport[]>('verify_resource_integrity', {
+      paths,
+      rebuild: rebuild ?? null,
+    });
+  },
+
+  /**
+   * 清除生成的资源校验清单，之后的校验以当前文件为准（仅桌面端）
+   */
+  async resetResourceManifests(): Promise<void> {
+    if (!isTauri()) return;
+    await invoke('reset_resource_manifests');
+  },
+
   /**
    * 运行任务
    * @param instanceId 实例 ID
    * @param entry 任务入口
    * @param pipelineOverride Pipeline 覆盖 JSON
    * @param selectedTaskId 对应的前端任务 ID（用于后端跟踪任务状态）
+   * @param priority 任务优先级（默认 0），高于当前执行中任务时抢占执行，被中断的任务在其完成后恢复
+   * @param skipIf 跳过条件表达式，任务开始时为真则跳过
    * @returns 任务 ID
    */
   async runTask(
@@ -383,6 +1428,8 @@ export const maaService = {
     entry: string,
     pipelineOverride: string = '{}',
     selectedTaskId?: string,
+    priority?: number,
+    skipIf?: string,
   ): Promise<number> {
     log.info(
       '运行任务, 实例:',
@@ -391,11 +1438,21 @@ export const maaService = {
       entry,
       ', pipelineOverride:',
       pipelineOverride,
+      ', 优先级:',
+      priority ?? 0,
     );
     if (!isTauri()) {
       const result = await apiPost<{ taskIds: number[] }>(
         `/maa/instances/${instanceId}/tasks/run`,
-        [{ entry, pipeline_override: pipelineOverride, selected_task_id: selectedTaskId }],
+        [
+          {
+            entry,
+            pipeline_override: pipelineOverride,
+            selected_task_id: selectedTaskId,
+            priority: priority ?? 0,
+            skip_if: skipIf,
+          },
+        ],
       );
       const taskId = result.taskIds[0] ?? 0;
       log.info('任务已提交 (HTTP), taskId:', taskId);
@@ -406,11 +1463,49 @@ export const maaService = {
       entry,
       pipelineOverride,
       selectedTaskId: selectedTaskId ?? null,
+      priority: priority ?? null,
+      skipIf: skipIf ?? null,
+    }).catch((err) => {
+      throw toMxuError(err);
     });
     log.info('任务已提交, taskId:', taskId);
     return taskId;
   },
 
+  /**
+   * 校验任务的入口节点与 pipeline override，不实际运行
+   * 使用实例已加载的资源，检查从入口可达节点的引用、锚点与模板图，以及 override 是否会生效
+   * @param instanceId 实例 ID
+   * @param entry 任务入口
+   * @param pipelineOverride Pipeline 覆盖 JSON
+   */
+  async validateTask(
+    instanceId: string,
+    entry: string,
+    pipelineOverride: string = '{}',
+  ): Promise<TaskValidation> {
+    log.info('校验任务, 实例:', instanceId, ', 入口:', entry);
+    if (!isTauri()) {
+      return apiPost<TaskValidation>(`/maa/instances/${instanceId}/tasks/validate`, {
+        entry,
+        pipeline_override: pipelineOverride,
+      });
+    }
+    const validation = await invoke<TaskValidation>('maa_run_task', {
+      instanceId,
+      entry,
+      pipelineOverride,
+      selectedTaskId: null,
+      priority: null,
+      skipIf: null,
+      validateOnly: true,
+    }).catch((err) => {
+      throw toMxuError(err);
+    });
+    log.info('任务校验完成:', entry, ', 问题数:', validation.issues.length);
+    return validation;
+  },
+
   /**
    * 获取任务状态
    * @param instanceId 实例 ID
@@ -440,17 +1535,29 @@ export const maaService = {
   },
 
   /**
-   * 覆盖已提交任务的 Pipeline 配置（用于运行中修改尚未执行的任务选项）
+   * 停止所有实例的任务，全部停妥（或各自超时）后返回
+   * @returns 每个运行中实例的停止结果
+   */
+  async stopAll(): Promise<InstanceStopResult[]> {
+    log.info('停止所有实例的任务');
+    if (!isTauri()) {
+      return await apiPost<InstanceStopResult[]>('/maa/tasks/stop-all');
+    }
+    return await invoke<InstanceStopResult[]>('maa_stop_all');
+  },
+
+  /**
+   * 覆盖已提交任务的 Pipeline 配置（排队中的任务开始时生效，运行中的任务从下一个节点开始生效）
    * @param instanceId 实例 ID
    * @param taskId MAA 任务 ID
    * @param pipelineOverride Pipeline 覆盖 JSON
-   * @returns 是否成功
+   * @returns 各字段的生效方式
    */
   async overridePipeline(
     instanceId: string,
     taskId: number,
     pipelineOverride: string,
-  ): Promise<boolean> {
+  ): Promise<PipelineOverrideResult> {
     log.info(
       '覆盖 Pipeline, 实例:',
       instanceId,
@@ -459,22 +1566,151 @@ export const maaService = {
       ', override:',
       pipelineOverride,
     );
-    const success = isTauri()
-      ? await invoke<boolean>('maa_override_pipeline', {
+    const result = isTauri()
+      ? await invoke<PipelineOverrideResult>('maa_override_pipeline', {
           instanceId,
           taskId,
           pipelineOverride,
         })
-      : (
-          await apiPost<{ success: boolean }>(
-            `/maa/instances/${instanceId}/tasks/${taskId}/pipeline`,
-            {
-              pipelineOverride,
-            },
-          )
-        ).success;
-    log.info('覆盖 Pipeline 结果:', success);
-    return success;
+      : await apiPost<PipelineOverrideResult>(
+          `/maa/instances/${instanceId}/tasks/${taskId}/pipeline`,
+          {
+            pipelineOverride,
+          },
+        );
+    log.info('覆盖 Pipeline 结果:', result);
+    return result;
+  },
+
+  /**
+   * 查询实例各任务当前生效的 Pipeline override
+   * @param instanceId 实例 ID
+   * @returns 各任务的原始 override、运行中追加的 override 及叠加后的生效内容
+   */
+  async getPipelineOverrides(instanceId: string): Promise<PipelineOverrideInfo[]> {
+    return isTauri()
+      ? await invoke<PipelineOverrideInfo[]>('maa_get_pipeline_overrides', { instanceId })
+      : await apiGet<PipelineOverrideInfo[]>(`/maa/instances/${instanceId}/pipeline/overrides`);
+  },
+
+  /**
+   * 撤销运行中对 Pipeline 的所有修改，还原到资源原始状态
+   * @param instanceId 实例 ID
+   * @returns 被还原的任务数
+   */
+  async resetPipeline(instanceId: string): Promise<number> {
+    log.info('重置 Pipeline, 实例:', instanceId);
+    return isTauri()
+      ? await invoke<number>('maa_reset_pipeline', { instanceId })
+      : (await apiPost<{ reset: number }>(`/maa/instances/${instanceId}/pipeline/reset`, {}))
+          .reset;
+  },
+
+  /**
+   * 测试截图与识别性能（仅桌面端），结果同时导出到日志目录
+   * @param instanceId 实例 ID
+   * @param rounds 截图次数（默认 20）
+   */
+  async benchmark(instanceId: string, rounds?: number): Promise<BenchmarkResult> {
+    log.info('性能测试, 实例:', instanceId, ', 截图次数:', rounds ?? '(默认)');
+    return await invoke<BenchmarkResult>('maa_benchmark', { instanceId, rounds: rounds ?? null });
+  },
+
+  /**
+   * 查询识别命中率统计（仅桌面端），按识别次数从多到少排序
+   * @param instanceId 实例 ID（可选，不传时汇总所有实例）
+   */
+  async getRecoStats(instanceId?: string): Promise<RecoNodeStats[]> {
+    if (!isTauri()) return [];
+    return await invoke<RecoNodeStats[]>('maa_get_reco_stats', { instanceId: instanceId ?? null });
+  },
+
+  /**
+   * 重置识别命中率统计（仅桌面端）
+   * @param instanceId 实例 ID（可选，不传时重置所有实例）
+   */
+  async resetRecoStats(instanceId?: string): Promise<void> {
+    if (!isTauri()) return;
+    log.info('重置识别命中率统计, 实例:', instanceId ?? '(全部)');
+    await invoke('maa_reset_reco_stats', { instanceId: instanceId ?? null });
+  },
+
+  /**
+   * 查询节点执行耗时统计（仅桌面端），按节点累计耗时从多到少排序
+   * @param instanceId 实例 ID（可选，不传时汇总所有实例）
+   */
+  async getNodeTiming(instanceId?: string): Promise<NodeTimingStats[]> {
+    if (!isTauri()) return [];
+    return await invoke<NodeTimingStats[]>('maa_get_node_timing', {
+      instanceId: instanceId ?? null,
+    });
+  },
+
+  /**
+   * 重置节点执行耗时统计（仅桌面端）
+   * @param instanceId 实例 ID（可选，不传时重置所有实例）
+   */
+  async resetNodeTiming(instanceId?: string): Promise<void> {
+    if (!isTauri()) return;
+    log.info('重置节点执行耗时统计, 实例:', instanceId ?? '(全部)');
+    await invoke('maa_reset_node_timing', { instanceId: instanceId ?? null });
+  },
+
+  /**
+   * 保存带识别标注的截图（仅桌面端），在当前截图上画出最近一轮的识别框与分数
+   * @param instanceId 实例 ID
+   */
+  async saveAnnotatedScreenshot(instanceId: string): Promise<AnnotatedScreenshotResult> {
+    log.info('保存标注截图, 实例:', instanceId);
+    return await invoke<AnnotatedScreenshotResult>('maa_save_annotated_screenshot', { instanceId });
+  },
+
+  /**
+   * 导出识别失败问题包（截图、识别详情、节点定义、override、日志、系统信息）
+   * @param instanceId 实例 ID
+   * @param projectName 项目名称，用作压缩包文件名前缀
+   * @returns 压缩包路径
+   */
+  async exportIssueBundle(instanceId: string, projectName?: string): Promise<string> {
+    log.info('导出问题包, 实例:', instanceId);
+    return await invoke<string>('export_issue_bundle', {
+      instanceId,
+      projectName: projectName ?? null,
+    });
+  },
+
+  /**
+   * 开始录制实例的运行录像（截图序列，仅桌面端）
+   * @param instanceId 实例 ID
+   * @param fps 采样帧率（默认 2，最大 10）
+   */
+  async startRecordingClip(instanceId: string, fps?: number): Promise<void> {
+    log.info('开始录制录像, 实例:', instanceId, ', 帧率:', fps ?? '(默认)');
+    await invoke('start_recording_clip', { instanceId, fps: fps ?? null });
+  },
+
+  /**
+   * 停止录制并导出录像到日志目录的 clips 文件夹（仅桌面端）
+   * @param instanceId 实例 ID
+   * @param format 导出格式（默认 gif，mp4 需要 ffmpeg）
+   */
+  async stopAndExportClip(instanceId: string, format?: ClipFormat): Promise<ClipExportResult> {
+    log.info('停止录制并导出, 实例:', instanceId, ', 格式:', format ?? 'gif');
+    return await invoke<ClipExportResult>('stop_and_export', {
+      instanceId,
+      format: format ?? null,
+    });
+  },
+
+  /**
+   * 查询实例是否正在录制录像（仅桌面端）
+   * @param instanceId 实例 ID
+   */
+  async isRecordingClip(instanceId: string): Promise<boolean> {
+    if (!isTauri()) {
+      return false;
+    }
+    return await invoke<boolean>('is_recording_clip', { instanceId });
   },
 
   /**
@@ -508,31 +1744,77 @@ export const maaService = {
     return await invoke<number>('maa_post_click', { instanceId, x, y });
   },
 
+  /**
+   * 获取最近一次任务结束时保留的截图
+   * @param instanceId 实例 ID
+   * @returns 最后一帧，尚无任务结束或非 Tauri 环境时返回 null
+   */
+  async getLastFrame(instanceId: string): Promise<LastFrame | null> {
+    if (!isTauri()) return null;
+    return await invoke<LastFrame | null>('maa_get_last_frame', { instanceId });
+  },
+
   /**
    * 发起截图请求（异步，通过回调通知完成状态）
    * @param instanceId 实例 ID
-   * @returns 截图请求 ID（Tauri）或 0（浏览器，实际截图异步进行并写入缓存）
+   * @returns 本次截图的序号与发起时间（Tauri），浏览器模式返回 null（截图由后端循环驱动）
    */
-  async postScreencap(instanceId: string): Promise<number> {
+  async postScreencap(instanceId: string): Promise<ScreencapTicket | null> {
     if (!isTauri()) {
       // 浏览器模式：截图由后端 ScreenshotService 统一驱动，此处无需额外触发
-      return 0;
+      return null;
     }
-    const screencapId = await invoke<number>('maa_post_screencap', { instanceId });
-    return screencapId;
+    return await invoke<ScreencapTicket>('maa_post_screencap', { instanceId });
   },
 
   /**
    * 获取缓存的截图
    * @param instanceId 实例 ID
+   * @param minCaptureSeq postScreencap 返回的序号，传入时等待该次截图完成再取图，避免拿到旧帧
+   * @param timeoutMs 等待截图完成的超时（毫秒），默认 3000
    * @returns base64 编码的图像 data URL
    */
-  async getCachedImage(instanceId: string): Promise<string> {
+  async getCachedImage(
+    instanceId: string,
+    minCaptureSeq?: number,
+    timeoutMs?: number,
+  ): Promise<string> {
     if (!isTauri()) {
       // 浏览器模式：后端截图循环已在运行，直接读取最新缓存
       return fetchScreenshotDataUrl(instanceId).catch(() => '');
     }
-    return await invoke<string>('maa_get_cached_image', { instanceId });
+    const last = lastCachedFrames.get(instanceId);
+    const frame = await invoke<CachedImageFrame>('maa_get_cached_image', {
+      instanceId,
+      knownSeq: last?.seq ?? null,
+      minCaptureSeq: minCaptureSeq ?? null,
+      timeoutMs: timeoutMs ?? null,
+    });
+    if (frame.not_modified && last) {
+      return last.dataUrl;
+    }
+    const dataUrl = frame.data_url ?? '';
+    lastCachedFrames.set(instanceId, { seq: frame.seq, dataUrl });
+    return dataUrl;
+  },
+
+  /**
+   * 立即截一张新图并返回（仅桌面端等待本次截图完成，浏览器端返回最新缓存）
+   * @param instanceId 实例 ID
+   */
+  async captureImage(instanceId: string): Promise<string> {
+    const ticket = await this.postScreencap(instanceId);
+    return await this.getCachedImage(instanceId, ticket?.seq);
+  },
+
+  /**
+   * 从当前缓存截图中裁剪指定区域（仅桌面端），用于调 ROI 时预览裁剪结果
+   * @param instanceId 实例 ID
+   * @param roi 裁剪区域 [x, y, w, h]，越界部分会截到图像范围内
+   */
+  async getImageRoi(instanceId: string, roi: [number, number, number, number]): Promise<ImageRoi> {
+    const [x, y, w, h] = roi;
+    return await invoke<ImageRoi>('maa_get_image_roi', { instanceId, x, y, w, h });
   },
 
   /**
@@ -586,6 +1868,7 @@ export const maaService = {
    * @param piEnvs PI v2.5.0 环境变量（Agent 子进程注入）
    * @param resetState 是否重置后端任务运行状态（默认 true）。分段运行时，仅首段为 true，
    *                   后续段传 false 以追加任务、保留已完成段的状态。
+   * @param queueLoop 循环执行配置（仅首段生效），不传时只执行一轮
    * @returns 任务 ID 列表
    */
   async startTasks(
@@ -596,6 +1879,7 @@ export const maaService = {
     tcpCompatMode?: boolean,
     piEnvs?: Record<string, string>,
     resetState: boolean = true,
+    queueLoop?: TaskQueueLoop | null,
   ): Promise<number[]> {
     log.info('启动任务, 实例:', instanceId, ', 任务数:', tasks.length, ', cwd:', cwd || '.');
     tasks.forEach((task, i) => {
@@ -621,6 +1905,7 @@ export const maaService = {
           tcp_compat_mode: tcpCompatMode || false,
           pi_envs: agentConfigs && agentConfigs.length > 0 && piEnvs ? piEnvs : null,
           reset_state: resetState,
+          loop_config: queueLoop ?? null,
         },
       );
       log.info('任务已提交 (HTTP), taskIds:', result.taskIds);
@@ -635,24 +1920,30 @@ export const maaService = {
       tcpCompatMode: tcpCompatMode || false,
       piEnvs: hasAgent && piEnvs ? piEnvs : null,
       resetState,
+      loopConfig: queueLoop ?? null,
     });
     log.info('任务已提交, taskIds:', taskIds);
     return taskIds;
   },
 
   /**
-   * 停止 Agent 并断开连接
+   * 停止 Agent 并断开连接（Agent 超时未退出时后端会强制结束其进程树）
    * @param instanceId 实例 ID
+   * @returns Agent 是自行退出还是被强制结束
    */
-  async stopAgent(instanceId: string): Promise<void> {
+  async stopAgent(instanceId: string): Promise<AgentStopOutcome> {
     log.info('停止 Agent, 实例:', instanceId);
     if (!isTauri()) {
-      await apiPost(`/maa/instances/${instanceId}/agent/stop`, {});
-      log.info('停止 Agent 成功 (HTTP)');
-      return;
+      const { outcome } = await apiPost<{ outcome: AgentStopOutcome }>(
+        `/maa/instances/${instanceId}/agent/stop`,
+        {},
+      );
+      log.info('停止 Agent 成功 (HTTP):', outcome);
+      return outcome;
     }
-    await invoke('maa_stop_agent', { instanceId });
-    log.info('停止 Agent 成功');
+    const outcome = await invoke<AgentStopOutcome>('maa_stop_agent', { instanceId });
+    log.info('停止 Agent 成功:', outcome);
+    return outcome;
   },
 
   /**
@@ -1074,6 +2365,59 @@ export const maaService = {
     await invoke('restart_as_admin');
   },
 
+  /**
+   * 以当前权限重启应用（保留命令行参数）
+   * 后端先停止任务、结束 agent 子进程再启动新进程，成功后当前进程退出
+   */
+  async restartApp(): Promise<void> {
+    log.info('重启应用');
+    if (!isTauri()) {
+      await apiPost('/system/restart');
+      return;
+    }
+    await invoke('restart_app');
+  },
+
+  /**
+   * 设置后端与 MaaFramework 的日志级别（立即生效）
+   * @param level 日志级别
+   */
+  async setLogLevel(level: LogLevel): Promise<void> {
+    if (!isTauri()) return;
+    log.info('设置日志级别:', level);
+    await invoke('set_log_level', { level });
+  },
+
+  /**
+   * 开启或关闭结构化事件日志（debug/logs/events.jsonl，立即生效）
+   * @param enabled 是否启用
+   */
+  async setEventLogEnabled(enabled: boolean): Promise<void> {
+    if (!isTauri()) return;
+    log.info('设置事件日志:', enabled);
+    await invoke('set_event_log_enabled', { enabled });
+  },
+
+  /**
+   * 开启或关闭任务失败自动截图（失败事件附带截图，立即生效）
+   * @param enabled 是否启用
+   */
+  async setFailureScreenshotEnabled(enabled: boolean): Promise<void> {
+    if (!isTauri()) return;
+    log.info('设置任务失败截图:', enabled);
+    await invoke('set_failure_screenshot_enabled', { enabled });
+  },
+
+  /**
+   * 开启或关闭点击/滑动轨迹事件（MXU.Action.Trace 回调，立即生效）
+   * @param enabled 是否启用
+   */
+  async setActionTraceEnabled(enabled: boolean): Promise<void> {
+    if (!isTauri()) return;
+    log.info('设置点击轨迹事件:', enabled);
+    await invoke('set_action_trace_enabled', { enabled });
+  },
+
   /**
    * 设置保存调试图像
    * @param enabled 是否启用
@@ -1098,6 +2442,7 @@ export const maaService = {
    * @param cwd 工作目录（可选）
    * @param waitForExit 是否等待进程退出（默认 true）
    * @param useCmd 是否通过 cmd /c 启动（仅 Windows，默认 false）
+   * @param hideWindow 是否隐藏控制台窗口（仅 Windows，默认 false）
    * @returns 程序退出码（不等待时返回 0）
    */
   async runAction(
@@ -1107,11 +2452,22 @@ export const maaService = {
     cwd?: string,
     waitForExit: boolean = true,
     useCmd: boolean = false,
+    hideWindow: boolean = false,
   ): Promise<number> {
     if (!isTauri()) {
       throw new Error('此功能仅在 Tauri 环境中可用');
     }
-    log.info('执行动作:', program, args, '等待:', waitForExit, '使用cmd:', useCmd);
+    log.info(
+      '执行动作:',
+      program,
+      args,
+      '等待:',
+      waitForExit,
+      '使用cmd:',
+      useCmd,
+      '隐藏窗口:',
+      hideWindow,
+    );
     try {
       const exitCode = await invoke<number>('run_action', {
         instanceId,
@@ -1120,6 +2476,7 @@ export const maaService = {
         cwd: cwd || null,
         waitForExit,
         useCmd,
+        hideWindow,
       });
       log.info('动作执行完成, 退出码:', exitCode);
       return exitCode;
@@ -1158,6 +2515,64 @@ export const maaService = {
     return exitCode;
   },
 
+  /**
+   * 在文件管理器中定位文件（打开所在文件夹并选中该文件）
+   * @param path 文件路径
+   * @returns 是否定位到文件本身；文件不存在时打开其父目录并返回 false
+   */
+  async openFolderAndSelect(path: string): Promise<boolean> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    const located = await invoke<boolean>('open_folder_and_select', { path });
+    if (!located) {
+      log.warn('文件不存在，已打开其父目录:', path);
+    }
+    return located;
+  },
+
+  /**
+   * 复制文本到剪贴板
+   * 桌面端走后端系统剪贴板（不受 WebView 剪贴板权限影响），浏览器端使用 Clipboard API
+   * @param text 要复制的文本
+   * @throws 复制失败时抛出，调用方应提示用户手动复制
+   */
+  async copyText(text: string): Promise<void> {
+    if (!isTauri()) {
+      await navigator.clipboard.writeText(text);
+      return;
+    }
+    await invoke('copy_text_to_clipboard', { text });
+  },
+
+  /**
+   * 把任务队列配置编码为分享码（仅桌面端）
+   * @param payload 任务及其选项值
+   * @throws 配置过大时抛出，提示改用文件分享
+   */
+  async exportShareCode(payload: SharePayload): Promise<string> {
+    return await invoke<string>('export_share_code', { payload });
+  },
+
+  /**
+   * 解码分享码（仅桌面端），后端校验版本、长度和结构
+   * @param code 分享码
+   */
+  async importShareCode(code: string): Promise<SharePayload> {
+    return await invoke<SharePayload>('import_share_code', { code });
+  },
+
+  /**
+   * 一次获取所有关键目录及其是否存在
+   * @returns 非 Tauri 环境返回 null
+   */
+  async getAppPaths(): Promise<AppPaths | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    return await invoke<AppPaths>('get_app_paths');
+  },
+
   /**
    * 检查指定程序是否正在运行（通过完整路径比较）
    * @param program 程序的绝对路径
@@ -4,6 +4,8 @@
 import type { ProxySettings } from '@/types/config';
 import { invoke } from '@tauri-apps/api/core';
 import { loggers } from '@/utils/logger';
+import { isTauri } from '@/utils/paths';
+import { toMxuError } from '@/utils/mxuError';
 
 const log = loggers.app;
 
@@ -182,7 +184,7 @@ export async function downloadWithProxy(
       log.info(`[下载] 目标: ${url}`);
     }
   } else {
-    log.info(`[下载] 直连（无代理）: ${url}`);
+    log.info(`[下载] 未设置代理，使用系统代理或直连: ${url}`);
   }
 
   return invoke<DownloadResult>('download_file', {
@@ -190,5 +192,43 @@ export async function downloadWithProxy(
     savePath,
     totalSize: options?.totalSize || null,
     proxyUrl: options?.proxyUrl || null,
+  }).catch((err) => {
+    throw toMxuError(err);
   });
 }
+
+/**
+ * 系统代理（与 Rust 端 SystemProxy 对应）
+ */
+export interface SystemProxy {
+  url: string;
+  no_proxy: string | null;
+  source: 'env' | 'wininet' | 'winhttp';
+}
+
+/**
+ * 查询探测到的系统代理，未设置手动代理时下载会使用它
+ * @returns 系统代理，探测不到或非 Tauri 环境时返回 null
+ */
+export async function getSystemProxy(): Promise<SystemProxy | null> {
+  if (!isTauri()) return null;
+  try {
+    return await invoke<SystemProxy | null>('get_system_proxy');
+  } catch (err) {
+    log.warn('查询系统代理失败:', err);
+    return null;
+  }
+}
+
+/**
+ * 将手动代理同步到后端，供下次启动时下载 WebView2 运行时使用
+ * @param proxySettings 代理设置，为空时清除
+ */
+export async function syncSavedProxy(proxySettings: ProxySettings | undefined): Promise<void> {
+  if (!isTauri()) return;
+  try {
+    await invoke('set_saved_proxy', { url: proxySettings?.url || null });
+  } catch (err) {
+    log.warn('保存代理设置失败:', err);
+  }
+}
@@ -1,4 +1,5 @@
 import { normalizeAgentConfigs } from '@/types/interface';
+import type { InstanceStopResult } from '@/types/maa';
 import { loggers } from '@/utils/logger';
 import { isTauri } from '@/utils/paths';
 import { useAppStore } from '@/stores/appStore';
@@ -47,6 +48,21 @@ function cleanupTaskState(instanceId: string) {
   state.clearScheduleExecution(instanceId);
 }
 
+/** 任务停下后的收尾：结束队列监视、停止 Agent、清理前端运行状态 */
+async function finishInstanceStop(instanceId: string) {
+  cancelTaskQueueMonitor(instanceId);
+
+  const agentConfigs = normalizeAgentConfigs(useAppStore.getState().projectInterface?.agent);
+  if (agentConfigs && agentConfigs.length > 0) {
+    const outcome = await maaService.stopAgent(instanceId);
+    if (outcome === 'killed') {
+      log.warn(`[task-stop#${instanceId}] Agent 未在超时内退出，已强制结束`);
+    }
+  }
+
+  cleanupTaskState(instanceId);
+}
+
 export async function stopInstanceTasks(instanceId: string): Promise<boolean> {
   const existing = stopPromises.get(instanceId);
   if (existing) {
@@ -72,14 +88,7 @@ export async function stopInstanceTasks(instanceId: string): Promise<boolean> {
       return false;
     }
 
-    cancelTaskQueueMonitor(instanceId);
-
-    const agentConfigs = normalizeAgentConfigs(useAppStore.getState().projectInterface?.agent);
-    if (agentConfigs && agentConfigs.length > 0) {
-      await maaService.stopAgent(instanceId);
-    }
-
-    cleanupTaskState(instanceId);
+    await finishInstanceStop(instanceId);
     return true;
   })().finally(() => {
     stopPromises.delete(instanceId);
@@ -89,6 +98,38 @@ export async function stopInstanceTasks(instanceId: string): Promise<boolean> {
   return stopPromise;
 }
 
+/**
+ * 处理后端一键停止的结果：对已停下的实例做收尾，超时或失败的保留运行状态
+ * @returns 已停下的实例数
+ */
+export async function finishStopAll(results: InstanceStopResult[]): Promise<number> {
+  let stopped = 0;
+  for (const result of results) {
+    if (result.outcome !== 'stopped') {
+      log.warn(`[task-stop#${result.instance_id}] 一键停止未完成: ${result.outcome}`, result.error);
+      continue;
+    }
+    try {
+      await finishInstanceStop(result.instance_id);
+      stopped++;
+    } catch (error) {
+      log.warn(`[task-stop#${result.instance_id}] 停止后收尾失败:`, error);
+    }
+  }
+  return stopped;
+}
+
+/**
+ * 停止所有实例的任务，各实例独立等待，个别卡住不影响其他实例
+ * @returns 后端返回的各实例停止结果
+ */
+export async function stopAllInstanceTasks(): Promise<InstanceStopResult[]> {
+  log.info('[task-stop] 停止所有实例的任务');
+  const results = await maaService.stopAll();
+  await finishStopAll(results);
+  return results;
+}
+
 export async function stopInstanceTasksAndExitApp(instanceId: string): Promise<boolean> {
   const stopped = await stopInstanceTasks(instanceId);
   if (!stopped) {
@@ -5,7 +5,8 @@ import type { DownloadProgress, UpdateInfo } from '@/stores/appStore';
 import { useAppStore } from '@/stores/appStore';
 import type { ProxySettings, UpdateChannel } from '@/types/config';
 import { loggers } from '@/utils/logger';
-import { getCacheDir, joinPath } from '@/utils/paths';
+import { isMxuError, toMxuError } from '@/utils/mxuError';
+import { getCacheDir, isTauri, joinPath } from '@/utils/paths';
 import { invoke } from '@tauri-apps/api/core';
 import { dirname } from '@tauri-apps/api/path';
 import { exists } from '@tauri-apps/plugin-fs';
@@ -76,6 +77,42 @@ const MIRRORCHYAN_API_BASES = [
   'https://mirrorchyan.net/api/resources',
 ];
 
+/** 单个目标的连通性探测结果 */
+export interface NetworkProbeResult {
+  url: string;
+  /** 是否收到 HTTP 响应（任何状态码都算可达） */
+  reachable: boolean;
+  latency_ms: number | null;
+  status: number | null;
+  error: string | null;
+}
+
+/**
+ * 快速探测更新服务器/CDN 的连通性（短超时 HEAD 请求，仅桌面端）
+ * 结果按可达优先、延迟从低到高排序；未指定目标时探测默认更新服务器
+ */
+export async function getNetworkStatus(
+  targets?: string[],
+  proxyUrl?: string,
+): Promise<NetworkProbeResult[]> {
+  if (!isTauri()) return [];
+  return invoke<NetworkProbeResult[]>('get_network_status', { targets, proxyUrl });
+}
+
+/**
+ * 按连通性探测结果排序 Mirror酱 API 站点（最快的在前），探测失败时保持原顺序
+ */
+async function sortApiBasesByLatency(): Promise<string[]> {
+  try {
+    const results = await getNetworkStatus(MIRRORCHYAN_API_BASES);
+    if (!results.some((r) => r.reachable)) return MIRRORCHYAN_API_BASES;
+    return results.map((r) => r.url);
+  } catch (error) {
+    log.warn('探测 Mirror酱 站点连通性失败:', error);
+    return MIRRORCHYAN_API_BASES;
+  }
+}
+
 // MirrorChyan API 错误码定义
 // 参考: https://github.com/MirrorChyan/docs/blob/main/ErrorCode.md
 export const MIRRORCHYAN_ERROR_CODES = {
@@ -150,14 +187,16 @@ let cachedArchPromise: Promise<string> | null = null;
  * 后端返回值通常是 `x86_64` / `aarch64`，这里统一映射为更新逻辑使用的
  * `amd64` / `arm64`，避免 Apple Silicon 被误判为 x86。
  *
- * 优先读 store 中已缓存的后端架构（interfaceLoader 在 Tauri/HTTP 两条路径均会填充，
+ * 桌面端优先使用系统原生架构（get_native_arch），避免 ARM64 上以 x64 仿真运行时下载到 x64 包；
+ * 否则读 store 中已缓存的后端架构（interfaceLoader 在 Tauri/HTTP 两条路径均会填充，
  * 使 WebUI 远程也能拿到后端真实架构）；store 尚未填充时回退到直接 invoke（仅 Tauri 可用），
  * 再失败则回退 amd64。
  */
 async function getArch(): Promise<string> {
   if (!cachedArchPromise) {
     cachedArchPromise = (async () => {
-      let raw = useAppStore.getState().backendArch;
+      let raw = isTauri() ? await invoke<string>('get_native_arch').catch(() => '') : '';
+      if (!raw) raw = useAppStore.getState().backendArch;
       if (!raw) raw = await invoke<string>('get_arch');
       const normalized = raw.toLowerCase();
       if (normalized === 'x86_64' || normalized === 'x64' || normalized === 'amd64') {
@@ -303,9 +342,10 @@ export async function checkUpdate(options: CheckUpdateOptions): Promise<UpdateIn
   let data: MirrorChyanApiResponse | null = null;
   let lastError: unknown = null;
 
-  // 依次尝试主站和备用站
-  for (let i = 0; i < MIRRORCHYAN_API_BASES.length; i++) {
-    const apiBase = MIRRORCHYAN_API_BASES[i];
+  // 按连通性依次尝试主站和备用站
+  const apiBases = await sortApiBasesByLatency();
+  for (let i = 0; i < apiBases.length; i++) {
+    const apiBase = apiBases[i];
     try {
       data = await fetchUpdateFromBase(apiBase, resourceId, params);
       // 请求成功且 code 为 0，直接使用结果
@@ -474,6 +514,15 @@ export function openMirrorChyanWebsite(source?: string) {
   });
 }
 
+/**
+ * 在系统默认浏览器中打开下载链接，供网络探测失败时手动下载
+ */
+export function openManualDownload(url: string) {
+  openUrl(url).catch((err) => {
+    log.error('Failed to open URL:', err);
+  });
+}
+
 /**
  * 从 GitHub URL 提取 owner 和 repo
  * 支持格式: https://github.com/owner/repo 或 https://github.com/owner/repo.git
@@ -751,7 +800,11 @@ export type DownloadUpdateResult =
       /** 检测到的文件名（如果有） */
       detectedFilename?: string;
     }
-  | { success: false };
+  | {
+      success: false;
+      /** 下载前连通性探测失败（网络不可用），可引导用户手动下载 */
+      networkUnavailable?: boolean;
+    };
 
 /**
  * 下载更新包（使用 Rust 后端流式下载）
@@ -778,6 +831,17 @@ export async function downloadUpdate(
   log.info(`开始下载更新: ${url}`);
   log.info(`保存路径: ${savePath}`);
 
+  // 先快速探测下载服务器，网络不通时立即返回，避免等到下载超时才报错
+  try {
+    const [probe] = await getNetworkStatus([url], proxySettings?.url);
+    if (probe && !probe.reachable) {
+      log.warn(`下载服务器不可达: ${probe.error ?? 'unknown'}`);
+      return { success: false, networkUnavailable: true };
+    }
+  } catch (error) {
+    log.warn('下载前连通性探测失败，继续下载:', error);
+  }
+
   isDownloading = true;
   downloadCancelled = false;
   currentDownloadPath = savePath;
@@ -834,7 +898,7 @@ export async function downloadUpdate(
     };
   } catch (error) {
     // 如果是用户主动取消，不记录为错误
-    if (downloadCancelled) {
+    if (downloadCancelled || isMxuError(error, 'DOWNLOAD_CANCELLED')) {
       log.info('下载已被用户取消');
     } else {
       log.error('下载失败:', error);
@@ -1045,6 +1109,9 @@ export async function installUpdate(options: InstallUpdateOptions): Promise<bool
           extractDir,
           targetDir,
           deletedFiles: changesJson.deleted,
+          concurrency: useAppStore.getState().updateCopyConcurrency || null,
+        }).catch((err) => {
+          throw toMxuError(err);
         });
       } else {
         // 全量更新
@@ -1054,6 +1121,8 @@ export async function installUpdate(options: InstallUpdateOptions): Promise<bool
         await invoke('apply_full_update', {
           extractDir,
           targetDir,
+        }).catch((err) => {
+          throw toMxuError(err);
         });
       }
 
@@ -1268,6 +1337,83 @@ export function clearPendingUpdateInfo(): void {
   }
 }
 
+// ============================================================================
+// 资源更新相关
+// ============================================================================
+
+export interface ResourceUpdateInfo {
+  has_update: boolean;
+  current_version: string | null;
+  version_name: string;
+  release_note: string;
+  download_url: string | null;
+  sha256: string | null;
+  update_type: 'incremental' | 'full' | null;
+  channel: string | null;
+  file_size: number | null;
+  error_code: number | null;
+  error_message: string | null;
+}
+
+export interface ResourceUpdateResult {
+  version_name: string;
+  incremental: boolean;
+  reloaded_instances: string[];
+}
+
+export interface CheckResourceUpdateOptions {
+  resourceId: string; // 资源在 MirrorChyan 上的 ID（与程序更新分开）
+  targetDir: string; // 资源所在的项目目录
+  currentVersion?: string; // 当前资源版本，为空时使用上次资源更新记录的版本
+  cdk?: string;
+  channel?: UpdateChannel;
+  proxyUrl?: string;
+}
+
+/**
+ * 检查资源更新（资源包独立于程序本体更新）
+ */
+export async function checkResourceUpdate(
+  options: CheckResourceUpdateOptions,
+): Promise<ResourceUpdateInfo> {
+  const { resourceId, targetDir, currentVersion, cdk, channel, proxyUrl } = options;
+  log.info(`检查资源更新: ${resourceId}, 当前版本: ${currentVersion ?? '(记录值)'}`);
+  return await invoke<ResourceUpdateInfo>('check_resource_update', {
+    resourceId,
+    targetDir,
+    currentVersion: currentVersion ?? null,
+    cdk: cdk ?? null,
+    channel: channel ?? null,
+    proxyUrl: proxyUrl ?? null,
+  });
+}
+
+/**
+ * 下载并安装资源更新，完成后后端会重新加载各实例的资源
+ * 下载进度通过 download-progress 事件上报
+ */
+export async function downloadResourceUpdate(
+  info: ResourceUpdateInfo,
+  targetDir: string,
+  proxyUrl?: string,
+): Promise<ResourceUpdateResult> {
+  if (!info.download_url) {
+    throw new Error('资源更新缺少下载地址');
+  }
+  log.info(`下载资源更新: ${info.version_name} -> ${targetDir}`);
+  const result = await invoke<ResourceUpdateResult>('download_resource_update', {
+    url: info.download_url,
+    versionName: info.version_name,
+    targetDir,
+    sha256: info.sha256,
+    proxyUrl: proxyUrl ?? null,
+  });
+  log.info(
+    `资源更新完成: ${result.version_name}, 增量=${result.incremental}, 已重载实例: ${result.reloaded_instances.join(', ')}`,
+  );
+  return result;
+}
+
 /**
  * 重启应用
  * 使用 Tauri 的 relaunch API 重启应用
@@ -21,6 +21,8 @@ export interface WsMaaCallbackPayload {
 
 export interface WsAgentOutputPayload {
   instance_id: string;
+  /** 实例显示名（用于多实例日志标签） */
+  instance_name?: string | null;
   stream: string;
   line: string;
 }
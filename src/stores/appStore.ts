@@ -17,6 +17,10 @@ import {
   defaultAddTaskPanelHeight,
   defaultMirrorChyanSettings,
   defaultScreenshotFrameRate,
+  defaultTaskReportSettings,
+  defaultCompletionWebhookSettings,
+  defaultAgentResourceLimits,
+  defaultToolkitConfig,
   defaultWindowSize,
   normalizeAddTaskPanelHeight,
 } from '@/types/config';
@@ -29,7 +33,7 @@ import type {
   SchedulePolicy,
   SelectedTask,
 } from '@/types/interface';
-import type { ConnectionStatus, TaskStatus } from '@/types/maa';
+import type { ConnectionStatus, ResolutionCheck, TaskStatus } from '@/types/maa';
 import { getMxuSpecialTask, isMxuSpecialTask, MXU_SPECIAL_TASKS } from '@/types/specialTasks';
 import {
   getPretaskItems,
@@ -161,10 +165,10 @@ function resolveTaskEnabledForController(
   return task.enabled;
 }
 
-function forwardLogToStdout(message: string) {
+function forwardLogToStdout(message: string, instanceId?: string) {
   const plain = message.replace(/<[^>]*>/g, '').trim();
   if (!plain) return;
-  logToStdout(plain);
+  logToStdout(plain, instanceId);
 }
 
 // 重新导出类型供外部使用
@@ -452,6 +456,7 @@ export const useAppStore = create<AppState>()(
               id: t.id,
               taskName: t.taskName,
               customName: t.customName,
+              skipIf: t.skipIf,
               enabled: t.enabled,
               enabledByController: cacheTaskEnabledForController(
                 t.enabledByController,
@@ -462,6 +467,9 @@ export const useAppStore = create<AppState>()(
             })),
             schedulePolicies: instanceToClose.schedulePolicies,
             preActions: instanceToClose.preActions,
+            queueLoop: instanceToClose.queueLoop,
+            emulatorLaunch: instanceToClose.emulatorLaunch,
+            runtimeSettings: instanceToClose.runtimeSettings,
           };
           // 添加到列表头部，并限制最大条目数
           newRecentlyClosed = [closedRecord, ...state.recentlyClosed].slice(0, MAX_RECENTLY_CLOSED);
@@ -501,10 +509,13 @@ export const useAppStore = create<AppState>()(
         }),
       })),
 
-    renameInstance: (id, newName) =>
+    renameInstance: (id, newName) => {
       set((state) => ({
         instances: state.instances.map((i) => (i.id === id ? { ...i, name: newName } : i)),
-      })),
+      }));
+      // 同步到后端，用于日志中的实例标签（实例尚未创建时忽略）
+      maaService.setInstanceName(id, newName).catch(() => {});
+    },
 
     reorderInstances: (oldIndex, newIndex) =>
       set((state) => {
@@ -894,6 +905,20 @@ export const useAppStore = create<AppState>()(
         ),
       })),
 
+    setTaskSkipIf: (instanceId, taskId, skipIf) =>
+      set((state) => ({
+        instances: state.instances.map((i) =>
+          i.id === instanceId
+            ? {
+                ...i,
+                selectedTasks: i.selectedTasks.map((t) =>
+                  t.id === taskId ? { ...t, skipIf: skipIf.trim() || undefined } : t,
+                ),
+              }
+            : i,
+        ),
+      })),
+
     // 复制任务
     duplicateTask: (instanceId, taskId) => {
       const state = get();
@@ -1190,6 +1215,7 @@ export const useAppStore = create<AppState>()(
                 id: t.id,
                 taskName: t.taskName,
                 customName: t.customName,
+                skipIf: t.skipIf,
                 enabled: t.enabled,
                 enabledByController: t.enabledByController,
                 optionValues: t.optionValues,
@@ -1213,6 +1239,7 @@ export const useAppStore = create<AppState>()(
                 id: t.id,
                 taskName: t.taskName,
                 customName: t.customName,
+                skipIf: t.skipIf,
                 enabled: t.enabled,
                 enabledByController: t.enabledByController,
                 optionValues: mergedValues,
@@ -1236,6 +1263,7 @@ export const useAppStore = create<AppState>()(
               id: t.id,
               taskName: t.taskName,
               customName: t.customName,
+              skipIf: t.skipIf,
               enabled: t.enabled,
               enabledByController: t.enabledByController,
               optionValues: mergedValues,
@@ -1255,6 +1283,9 @@ export const useAppStore = create<AppState>()(
           isRunning: prevRunningByInstance.get(inst.id) ?? false,
           schedulePolicies: normalizeSchedulePolicies(inst),
           preActions: migratePreActions(inst),
+          queueLoop: inst.queueLoop,
+          emulatorLaunch: inst.emulatorLaunch,
+          runtimeSettings: inst.runtimeSettings,
         };
       });
 
@@ -1384,6 +1415,10 @@ export const useAppStore = create<AppState>()(
         welcomeShownHash: config.settings.welcomeShownHash ?? '',
         devMode: config.settings.devMode ?? false,
         tcpCompatMode: config.settings.tcpCompatMode ?? false,
+        logLevel: config.settings.logLevel ?? 'debug',
+        eventLogEnabled: config.settings.eventLogEnabled ?? false,
+        failureScreenshotEnabled: config.settings.failureScreenshotEnabled ?? false,
+        actionTraceEnabled: config.settings.actionTraceEnabled ?? false,
         allowLanAccess: config.settings.allowLanAccess ?? false,
         webServerEnabled: config.settings.webServerEnabled ?? true,
         webServerPort: config.settings.webServerPort ?? 12701,
@@ -1391,8 +1426,22 @@ export const useAppStore = create<AppState>()(
         autoRunOnLaunch: config.settings.autoRunOnLaunch ?? false,
         autoStartRemovedInstanceName: config.settings.autoStartRemovedInstanceName,
         minimizeToTray: config.settings.minimizeToTray ?? false,
+        autoShowOverlay: config.settings.autoShowOverlay ?? false,
+        adbPath: config.settings.adbPath ?? '',
+        updateCopyConcurrency: config.settings.updateCopyConcurrency ?? 0,
+        taskReport: { ...defaultTaskReportSettings, ...config.settings.taskReport },
+        completionWebhook: {
+          ...defaultCompletionWebhookSettings,
+          ...config.settings.completionWebhook,
+        },
+        agentResourceLimits: {
+          ...defaultAgentResourceLimits,
+          ...config.settings.agentResourceLimits,
+        },
+        toolkitConfig: { ...defaultToolkitConfig, ...config.settings.toolkitConfig },
         onboardingCompleted: config.settings.onboardingCompleted ?? false,
         preActionConnectDelaySec: config.settings.preActionConnectDelaySec ?? 5,
+        autoStartDelaySec: config.settings.autoStartDelaySec ?? 0,
         hotkeys: config.settings.hotkeys ?? {
           startTasks: 'F10',
           stopTasks: 'F11',
@@ -1435,6 +1484,21 @@ export const useAppStore = create<AppState>()(
           });
         });
       }
+      // 同步手动代理到后端（供 WebView2 运行时下载使用）
+      import('@/services/proxyService').then(({ syncSavedProxy }) =>
+        syncSavedProxy(config.settings.proxy),
+      );
+      const autoShowOverlay = config.settings.autoShowOverlay ?? false;
+      if (autoShowOverlay) {
+        import('@/utils/paths').then(({ isTauri }) => {
+          if (!isTauri()) return;
+          import('@tauri-apps/api/core').then(({ invoke }) => {
+            invoke('set_auto_show_overlay', { enabled: autoShowOverlay }).catch((err) => {
+              loggers.app.error('同步日志悬浮窗设置失败:', err);
+            });
+          });
+        });
+      }
     },
 
     // MaaFramework 状态
@@ -1451,6 +1515,7 @@ export const useAppStore = create<AppState>()(
     instanceResourceLoaded: {},
     instanceCurrentTaskId: {},
     instanceTaskStatus: {},
+    instanceResolutionCheck: {},
 
     setInstanceConnectionStatus: (instanceId, status) => {
       const previousStatus = get().instanceConnectionStatus[instanceId];
@@ -1464,6 +1529,26 @@ export const useAppStore = create<AppState>()(
           [instanceId]: status,
         },
       }));
+
+      // 连接成功后按控制器声明的期望分辨率做一次预检
+      if (status === 'Connected' && previousStatus !== 'Connected') {
+        const { projectInterface, selectedController } = get();
+        const controllers = projectInterface?.controller ?? [];
+        const controller =
+          controllers.find((c) => c.name === selectedController[instanceId]) ?? controllers[0];
+        const expected = controller?.expected_resolution;
+        if (expected) {
+          maaService
+            .checkResolution(instanceId, expected)
+            .then((result) => {
+              if (!result || get().instanceConnectionStatus[instanceId] !== 'Connected') return;
+              set((state) => ({
+                instanceResolutionCheck: { ...state.instanceResolutionCheck, [instanceId]: result },
+              }));
+            })
+            .catch((err) => loggers.maa.warn('分辨率预检失败:', err));
+        }
+      }
     },
 
     setInstanceResourceLoaded: (instanceId, loaded) =>
@@ -1849,7 +1934,10 @@ export const useAppStore = create<AppState>()(
 
     // 代理设置
     proxySettings: undefined,
-    setProxySettings: (settings) => set({ proxySettings: settings }),
+    setProxySettings: (settings) => {
+      set({ proxySettings: settings });
+      import('@/services/proxyService').then(({ syncSavedProxy }) => syncSavedProxy(settings));
+    },
 
     // 任务选项预览显示设置
     showOptionPreview: true,
@@ -1877,6 +1965,42 @@ export const useAppStore = create<AppState>()(
     tcpCompatMode: false,
     setTcpCompatMode: (enabled) => set({ tcpCompatMode: enabled }),
 
+    // 日志级别
+    logLevel: 'debug',
+    setLogLevel: (level) => {
+      set({ logLevel: level });
+      maaService.setLogLevel(level).catch((err) => {
+        loggers.app.error('设置日志级别失败:', err);
+      });
+    },
+
+    // 结构化事件日志
+    eventLogEnabled: false,
+    setEventLogEnabled: (enabled) => {
+      set({ eventLogEnabled: enabled });
+      maaService.setEventLogEnabled(enabled).catch((err) => {
+        loggers.app.error('设置事件日志失败:', err);
+      });
+    },
+
+    // 任务失败自动截图
+    failureScreenshotEnabled: false,
+    setFailureScreenshotEnabled: (enabled) => {
+      set({ failureScreenshotEnabled: enabled });
+      maaService.setFailureScreenshotEnabled(enabled).catch((err) => {
+        loggers.app.error('设置失败截图失败:', err);
+      });
+    },
+
+    // 点击/滑动轨迹事件
+    actionTraceEnabled: false,
+    setActionTraceEnabled: (enabled) => {
+      set({ actionTraceEnabled: enabled });
+      maaService.setActionTraceEnabled(enabled).catch((err) => {
+        loggers.app.error('设置点击轨迹事件失败:', err);
+      });
+    },
+
     // 局域网访问（Web UI 绑定 0.0.0.0，需重启生效）
     allowLanAccess: false,
     setAllowLanAccess: (enabled) => set({ allowLanAccess: enabled }),
@@ -1928,6 +2052,65 @@ export const useAppStore = create<AppState>()(
       }
     },
 
+    // 日志悬浮窗
+    autoShowOverlay: false,
+    setAutoShowOverlay: async (enabled) => {
+      set({ autoShowOverlay: enabled });
+      if (!isTauri()) return;
+      try {
+        const { invoke } = await import('@tauri-apps/api/core');
+        await invoke('set_auto_show_overlay', { enabled });
+      } catch (err) {
+        loggers.app.error('设置日志悬浮窗自动显示失败:', err);
+      }
+    },
+
+    // 任务报告
+    taskReport: defaultTaskReportSettings,
+    setTaskReport: (settings) => {
+      const next = { ...get().taskReport, ...settings };
+      set({ taskReport: next });
+      maaService.setTaskReportCapture(next).catch((err) => {
+        loggers.app.error('设置任务报告失败:', err);
+      });
+    },
+
+    // 任务队列完成 webhook
+    completionWebhook: defaultCompletionWebhookSettings,
+    setCompletionWebhook: (settings) => {
+      const next = { ...get().completionWebhook, ...settings };
+      set({ completionWebhook: next });
+      maaService.setCompletionWebhook(next).catch((err) => {
+        loggers.app.error('设置任务完成 webhook 失败:', err);
+      });
+    },
+
+    // 指定 adb 路径（为空由框架自行查找）
+    adbPath: '',
+    setAdbPath: (path) => set({ adbPath: path }),
+
+    // 增量更新并行复制线程数（0 表示按 CPU 核数）
+    updateCopyConcurrency: 0,
+    setUpdateCopyConcurrency: (count) => set({ updateCopyConcurrency: count }),
+
+    // Agent 子进程资源限制
+    agentResourceLimits: defaultAgentResourceLimits,
+    setAgentResourceLimits: (limits) => {
+      const next = { ...get().agentResourceLimits, ...limits };
+      set({ agentResourceLimits: next });
+      maaService.setAgentResourceLimits(next).catch((err) => {
+        loggers.app.error('设置 Agent 资源限制失败:', err);
+      });
+    },
+
+    // 设备搜索的 Toolkit 配置，返回当前能找到的额外 adb
+    toolkitConfig: defaultToolkitConfig,
+    setToolkitConfig: async (config) => {
+      const next = { ...get().toolkitConfig, ...config };
+      set({ toolkitConfig: next });
+      return maaService.setToolkitConfig(next);
+    },
+
     // 新用户引导
     onboardingCompleted: false,
     setOnboardingCompleted: (completed) => set({ onboardingCompleted: completed }),
@@ -1935,6 +2118,10 @@ export const useAppStore = create<AppState>()(
     // 前置动作连接延迟（默认 5 秒）
     preActionConnectDelaySec: 5,
 
+    // 开机自启动延迟（默认不延迟）
+    autoStartDelaySec: 0,
+    setAutoStartDelaySec: (seconds) => set({ autoStartDelaySec: Math.max(0, seconds) }),
+
     // 更新检查状态
     updateInfo: null,
     updateCheckLoading: false,
@@ -2003,6 +2190,7 @@ export const useAppStore = create<AppState>()(
           id: generateId(),
           taskName: t.taskName,
           customName: t.customName,
+          skipIf: t.skipIf,
           enabled: t.enabled,
           enabledByController: t.enabledByController ? { ...t.enabledByController } : undefined,
           optionValues: cleanOptionValues(t.optionValues, pi),
@@ -2011,6 +2199,9 @@ export const useAppStore = create<AppState>()(
         isRunning: false,
         schedulePolicies: normalizeSchedulePolicies(closedInstance),
         preActions: migratePreActions(closedInstance),
+        queueLoop: closedInstance.queueLoop,
+        emulatorLaunch: closedInstance.emulatorLaunch,
+        runtimeSettings: closedInstance.runtimeSettings,
       };
 
       // 恢复选中的控制器和资源状态
@@ -2103,6 +2294,8 @@ export const useAppStore = create<AppState>()(
         const { [instanceId]: _removedCurrentTaskId, ...restCurrentTaskId } =
           state.instanceCurrentTaskId;
         const { [instanceId]: _removedTaskStatus, ...restTaskStatus } = state.instanceTaskStatus;
+        const { [instanceId]: _removedResolutionCheck, ...restResolutionCheck } =
+          state.instanceResolutionCheck;
 
         return {
           ctrlIdToName: nextCtrlIdToName,
@@ -2112,6 +2305,7 @@ export const useAppStore = create<AppState>()(
           instanceResourceLoaded: restResourceLoaded,
           instanceCurrentTaskId: restCurrentTaskId,
           instanceTaskStatus: restTaskStatus,
+          instanceResolutionCheck: restResolutionCheck,
           instanceScreenshotStreaming: Object.fromEntries(
             Object.entries(state.instanceScreenshotStreaming).filter(([id]) => id !== instanceId),
           ),
@@ -2154,7 +2348,7 @@ export const useAppStore = create<AppState>()(
           ...log,
         };
 
-        forwardLogToStdout(log.message);
+        forwardLogToStdout(log.message, instanceId);
 
         pushLogToBackend(instanceId, {
           id: newLog.id,
@@ -2267,6 +2461,7 @@ function generateConfig(): MxuConfig {
         id: t.id,
         taskName: t.taskName,
         customName: t.customName,
+        skipIf: t.skipIf,
         enabled: t.enabled,
         enabledByController: cacheTaskEnabledForController(
           t.enabledByController,
@@ -2277,6 +2472,9 @@ function generateConfig(): MxuConfig {
       })),
       schedulePolicies: inst.schedulePolicies,
       preActions: inst.preActions,
+      queueLoop: inst.queueLoop,
+      emulatorLaunch: inst.emulatorLaunch,
+      runtimeSettings: inst.runtimeSettings,
     })),
     // WebUI 模式下保留后端原始的外观 & 布局设置，避免覆盖桌面端偏好
     ...(() => {
@@ -2311,6 +2509,10 @@ function generateConfig(): MxuConfig {
           welcomeShownHash: state.welcomeShownHash,
           devMode: state.devMode,
           tcpCompatMode: state.tcpCompatMode,
+          logLevel: state.logLevel,
+          eventLogEnabled: state.eventLogEnabled,
+          failureScreenshotEnabled: state.failureScreenshotEnabled,
+          actionTraceEnabled: state.actionTraceEnabled,
           allowLanAccess: state.allowLanAccess,
           webServerEnabled: state.webServerEnabled,
           webServerPort: state.webServerPort,
@@ -2318,8 +2520,16 @@ function generateConfig(): MxuConfig {
           autoRunOnLaunch: state.autoRunOnLaunch,
           autoStartRemovedInstanceName: state.autoStartRemovedInstanceName,
           minimizeToTray: state.minimizeToTray,
+          autoShowOverlay: state.autoShowOverlay,
+          adbPath: state.adbPath || undefined,
+          updateCopyConcurrency: state.updateCopyConcurrency || undefined,
+          taskReport: state.taskReport,
+          completionWebhook: state.completionWebhook,
+          agentResourceLimits: state.agentResourceLimits,
+          toolkitConfig: state.toolkitConfig,
           onboardingCompleted: state.onboardingCompleted,
           preActionConnectDelaySec: state.preActionConnectDelaySec,
+          autoStartDelaySec: state.autoStartDelaySec,
           hotkeys: state.hotkeys,
         },
         customAccents: ba?.customAccents ?? state.customAccents,
@@ -2386,6 +2596,10 @@ useAppStore.subscribe(
     welcomeShownHash: state.welcomeShownHash,
     devMode: state.devMode,
     tcpCompatMode: state.tcpCompatMode,
+    logLevel: state.logLevel,
+    eventLogEnabled: state.eventLogEnabled,
+    failureScreenshotEnabled: state.failureScreenshotEnabled,
+    actionTraceEnabled: state.actionTraceEnabled,
     allowLanAccess: state.allowLanAccess,
     webServerEnabled: state.webServerEnabled,
     webServerPort: state.webServerPort,
@@ -2393,7 +2607,15 @@ useAppStore.subscribe(
     autoRunOnLaunch: state.autoRunOnLaunch,
     autoStartRemovedInstanceName: state.autoStartRemovedInstanceName,
     minimizeToTray: state.minimizeToTray,
+    autoShowOverlay: state.autoShowOverlay,
+    adbPath: state.adbPath,
+    updateCopyConcurrency: state.updateCopyConcurrency,
+    agentResourceLimits: state.agentResourceLimits,
+    taskReport: state.taskReport,
+    completionWebhook: state.completionWebhook,
+    toolkitConfig: state.toolkitConfig,
     onboardingCompleted: state.onboardingCompleted,
+    autoStartDelaySec: state.autoStartDelaySec,
     hotkeys: state.hotkeys,
     recentlyClosed: state.recentlyClosed,
     newTaskNames: state.newTaskNames,
@@ -2433,3 +2655,10 @@ export function flushSaveConfig(): void {
   const projectName = state.projectInterface.name;
   saveConfig(state.dataPath, config, projectName);
 }
+
+/**
+ * 实例在配置中的显示名，创建后端实例时一并传入，使托盘菜单和日志前缀显示名称而不是 ID
+ */
+export function getInstanceName(instanceId: string): string | undefined {
+  return useAppStore.getState().instances.find((i) => i.id === instanceId)?.name;
+}
@@ -15,8 +15,19 @@ import type {
   RecentlyClosedInstance,
   ScreenshotFrameRate,
   HotkeySettings,
+  LogLevel,
+  TaskReportSettings,
+  CompletionWebhookSettings,
+  AgentResourceLimits,
+  ToolkitConfig,
 } from '@/types/config';
-import type { ConnectionStatus, TaskStatus, AdbDevice, Win32Window } from '@/types/maa';
+import type {
+  ConnectionStatus,
+  TaskStatus,
+  AdbDevice,
+  Win32Window,
+  ResolutionCheck,
+} from '@/types/maa';
 import type { AccentColor, CustomAccent } from '@/themes';
 
 /** 单个任务的运行状态 */
@@ -33,6 +44,23 @@ export interface LogEntry {
   message: string;
   /** 可选的富文本 HTML 内容（用于 focus 消息） */
   html?: string;
+  /** 可选的截图（PNG data URL，任务失败时附带，仅保存在内存中） */
+  image?: string;
+  /** 可选的任务分段标记（任务开始/结束时插入，用于按任务折叠日志，仅保存在内存中） */
+  section?: LogSection;
+}
+
+/** 日志中的任务分段标记 */
+export interface LogSection {
+  /** MaaFramework 任务 ID */
+  taskId: number;
+  phase: 'start' | 'end';
+  /** 任务显示名 */
+  name: string;
+  /** 结束时的结果 */
+  status?: 'succeeded' | 'failed';
+  /** 循环执行时的轮次，未循环时为 0 */
+  round: number;
 }
 
 export type Theme = 'light' | 'dark' | 'system';
@@ -191,6 +219,8 @@ export interface AppState {
   selectAllTasks: (instanceId: string, enabled: boolean) => void;
   collapseAllTasks: (instanceId: string, expanded: boolean) => void;
   renameTask: (instanceId: string, taskId: string, newName: string) => void;
+  /** 设置任务的跳过条件表达式，空字符串表示清除 */
+  setTaskSkipIf: (instanceId: string, taskId: string, skipIf: string) => void;
 
   // 任务右键菜单操作
   duplicateTask: (instanceId: string, taskId: string) => void;
@@ -242,6 +272,8 @@ export interface AppState {
   instanceResourceLoaded: Record<string, boolean>;
   instanceCurrentTaskId: Record<string, number | null>;
   instanceTaskStatus: Record<string, TaskStatus | null>;
+  /** 连接后的分辨率预检结果（控制器未声明 expected_resolution 时不检查） */
+  instanceResolutionCheck: Record<string, ResolutionCheck>;
 
   setInstanceConnectionStatus: (instanceId: string, status: ConnectionStatus) => void;
   setInstanceResourceLoaded: (instanceId: string, loaded: boolean) => void;
@@ -373,6 +405,22 @@ export interface AppState {
   tcpCompatMode: boolean;
   setTcpCompatMode: (enabled: boolean) => void;
 
+  // 日志级别（后端与 MaaFramework，立即生效）
+  logLevel: LogLevel;
+  setLogLevel: (level: LogLevel) => void;
+
+  // 结构化事件日志（JSONL，立即生效）
+  eventLogEnabled: boolean;
+  setEventLogEnabled: (enabled: boolean) => void;
+
+  // 任务失败时自动截图并附到日志（立即生效）
+  failureScreenshotEnabled: boolean;
+  setFailureScreenshotEnabled: (enabled: boolean) => void;
+
+  // 点击/滑动坐标事件流（用于预览叠加，立即生效）
+  actionTraceEnabled: boolean;
+  setActionTraceEnabled: (enabled: boolean) => void;
+
   /** Web 服务器是否启用（默认 true，重启生效） */
   webServerEnabled: boolean;
   setWebServerEnabled: (enabled: boolean) => void;
@@ -394,6 +442,34 @@ export interface AppState {
   minimizeToTray: boolean;
   setMinimizeToTray: (enabled: boolean) => void;
 
+  // 日志悬浮窗
+  autoShowOverlay: boolean;
+  setAutoShowOverlay: (enabled: boolean) => void;
+
+  // 任务报告
+  taskReport: TaskReportSettings;
+  setTaskReport: (settings: Partial<TaskReportSettings>) => void;
+
+  // 任务队列完成 webhook
+  completionWebhook: CompletionWebhookSettings;
+  setCompletionWebhook: (settings: Partial<CompletionWebhookSettings>) => void;
+
+  // 搜索 ADB 设备时使用的 adb 可执行文件路径（为空由框架自行查找）
+  adbPath: string;
+  setAdbPath: (path: string) => void;
+
+  // 增量更新并行复制线程数（0 表示按 CPU 核数）
+  updateCopyConcurrency: number;
+  setUpdateCopyConcurrency: (count: number) => void;
+
+  // Agent 子进程资源限制（仅 Windows）
+  agentResourceLimits: AgentResourceLimits;
+  setAgentResourceLimits: (limits: Partial<AgentResourceLimits>) => void;
+
+  // 设备搜索的 Toolkit 配置（额外 adb 目录 / 模拟器安装目录）
+  toolkitConfig: ToolkitConfig;
+  setToolkitConfig: (config: Partial<ToolkitConfig>) => Promise<string[]>;
+
   // 是否为开机自启动模式（无人值守，跳过阻塞式弹窗）
   isAutoStartMode: boolean;
   setIsAutoStartMode: (mode: boolean) => void;
@@ -417,6 +493,10 @@ export interface AppState {
   /** 前置动作轮询设备就绪后、连接前的额外延迟秒数（默认 5，仅通过编辑 mxu.json 修改） */
   preActionConnectDelaySec: number;
 
+  /** 开机自启动时延迟初始化的秒数（默认 0 不延迟，下次开机自启动时生效） */
+  autoStartDelaySec: number;
+  setAutoStartDelaySec: (seconds: number) => void;
+
   // 更新检查状态
   updateInfo: UpdateInfo | null;
   updateCheckLoading: boolean;
@@ -1,6 +1,12 @@
 // MXU 配置文件结构 (mxu.json)
 
-import type { ActionConfig, OptionValue } from './interface';
+import type {
+  ActionConfig,
+  EmulatorLaunchSettings,
+  InstanceRuntimeSettings,
+  OptionValue,
+  QueueLoopConfig,
+} from './interface';
 import type { AccentColor, CustomAccent } from '@/themes/types';
 
 export const DEFAULT_MAX_LOGS_PER_INSTANCE = 500;
@@ -19,6 +25,7 @@ export interface SavedTask {
   id: string;
   taskName: string; // 对应 interface 中的 task.name
   customName?: string; // 用户自定义名称
+  skipIf?: string; // 跳过条件表达式（任务开始时为真则跳过）
   enabled: boolean;
   /** 各控制器独立的勾选状态（旧配置中不存在时按 enabled 初始化） */
   enabledByController?: Record<string, boolean>;
@@ -66,6 +73,12 @@ export interface SavedInstance {
   preActions?: ActionConfig[];
   /** @deprecated 旧版单前置程序字段，仅用于向后兼容读取 */
   preAction?: LegacyActionConfig;
+  // 任务队列循环执行设置
+  queueLoop?: QueueLoopConfig;
+  // 连接前自动启动模拟器
+  emulatorLaunch?: EmulatorLaunchSettings;
+  // 实例运行时设置（失败重试策略等）
+  runtimeSettings?: InstanceRuntimeSettings;
 }
 
 // 窗口大小配置
@@ -95,6 +108,9 @@ export interface RecentlyClosedInstance {
   preActions?: ActionConfig[];
   /** @deprecated 旧版单前置程序字段，仅用于向后兼容读取 */
   preAction?: LegacyActionConfig;
+  queueLoop?: QueueLoopConfig; // 任务队列循环执行设置
+  emulatorLaunch?: EmulatorLaunchSettings; // 连接前自动启动模拟器
+  runtimeSettings?: InstanceRuntimeSettings; // 实例运行时设置
 }
 
 // MirrorChyan 更新频道
@@ -155,17 +171,64 @@ export interface AppSettings {
   onboardingCompleted?: boolean; // 新用户引导是否已完成
   hotkeys?: HotkeySettings; // 快捷键设置
   tcpCompatMode?: boolean; // 通信兼容模式，强制使用 TCP 而非 IPC
+  logLevel?: LogLevel; // 后端与 MaaFramework 日志级别（默认 debug）
+  eventLogEnabled?: boolean; // 关键事件额外写入 debug/logs/events.jsonl（默认 false）
+  failureScreenshotEnabled?: boolean; // 任务失败事件附带当时截图（默认 false）
+  actionTraceEnabled?: boolean; // 转发每次点击/滑动的坐标事件用于预览叠加（默认 false）
   webServerEnabled?: boolean; // Web 服务器是否启用（默认 true，重启生效）
   allowLanAccess?: boolean; // Web UI 允许局域网访问（绑定 0.0.0.0，重启生效）
   webServerPort?: number; // Web 服务器监听端口（默认 12701，重启生效）
   minimizeToTray?: boolean; // 关闭时最小化到托盘（默认 false）
+  autoShowOverlay?: boolean; // 任务运行时自动显示日志悬浮窗，全部结束后自动关闭（默认 false）
+  adbPath?: string; // 搜索设备时使用的 adb 可执行文件路径（为空由框架自行查找）
+  updateCopyConcurrency?: number; // 增量更新并行复制的线程数（0 或 undefined 表示按 CPU 核数）
+  taskReport?: TaskReportSettings; // 任务队列结束后生成图片报告
+  completionWebhook?: CompletionWebhookSettings; // 任务队列结束后 POST 运行摘要
+  agentResourceLimits?: AgentResourceLimits; // Agent 子进程内存上限与 CPU 配额（仅 Windows）
+  toolkitConfig?: ToolkitConfig; // 搜索设备时额外使用的 adb 目录 / 模拟器安装目录
   autoStartInstanceId?: string; // 启动后自动执行的实例 ID（为空或 undefined 表示不自动执行）
   autoRunOnLaunch?: boolean; // 非开机自启动的手动启动场景下，是否也自动执行选定的实例（默认 false）
   autoStartRemovedInstanceName?: string; // 被删除的自动执行配置名称（用于提示用户）
   /** 前置动作轮询设备就绪后、连接前的额外延迟秒数（默认 5，仅通过编辑 mxu.json 修改） */
   preActionConnectDelaySec?: number;
+  /** 开机自启动时延迟初始化的秒数（默认 0 不延迟，在设置 - 常规中修改） */
+  autoStartDelaySec?: number;
 }
 
+/** 任务报告中包含截图的范围 */
+export type TaskReportScreenshots = 'all' | 'failed' | 'none';
+
+/** 任务报告设置 */
+export interface TaskReportSettings {
+  enabled: boolean;
+  screenshots: TaskReportScreenshots;
+}
+
+/** 任务队列完成 webhook 设置（队列全部结束时向 url POST 运行摘要 JSON） */
+export interface CompletionWebhookSettings {
+  enabled: boolean;
+  url: string;
+}
+
+/** Agent 子进程资源限制（仅 Windows，通过 Job Object 实现，0 表示不限制） */
+export interface AgentResourceLimits {
+  /** 单个 agent 进程的内存上限（MB） */
+  memoryLimitMb: number;
+  /** 所有 agent 合计的 CPU 配额（占整机 CPU 的百分比） */
+  cpuRatePercent: number;
+}
+
+/** 设备搜索的 Toolkit 配置（补充框架默认搜不到的模拟器 adb） */
+export interface ToolkitConfig {
+  /** 额外的 adb 搜索目录（也可直接填 adb 可执行文件路径） */
+  extraAdbDirs: string[];
+  /** 模拟器安装目录，会在其子目录中查找 adb */
+  emulatorDirs: string[];
+}
+
+/** 后端日志级别 */
+export type LogLevel = 'error' | 'warn' | 'info' | 'debug';
+
 // MXU 配置文件完整结构
 export interface MxuConfig {
   version: string;
@@ -196,6 +259,27 @@ export const defaultMirrorChyanSettings: MirrorChyanSettings = {
   channel: 'stable',
 };
 
+// 默认任务报告设置
+export const defaultTaskReportSettings: TaskReportSettings = {
+  enabled: false,
+  screenshots: 'all',
+};
+
+export const defaultCompletionWebhookSettings: CompletionWebhookSettings = {
+  enabled: false,
+  url: '',
+};
+
+export const defaultAgentResourceLimits: AgentResourceLimits = {
+  memoryLimitMb: 0,
+  cpuRatePercent: 0,
+};
+
+export const defaultToolkitConfig: ToolkitConfig = {
+  extraAdbDirs: [],
+  emulatorDirs: [],
+};
+
 // 默认截图帧率
 export const defaultScreenshotFrameRate: ScreenshotFrameRate = '1';
 
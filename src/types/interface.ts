@@ -1,5 +1,12 @@
 // MaaFramework ProjectInterface V2 协议类型定义
 
+import type {
+  ScreenshotPreprocess,
+  TaskRetryPolicy,
+  UnknownScreenConfig,
+  WatchdogConfig,
+} from './maa';
+
 export interface ProjectInterface {
   interface_version: 2;
   languages?: Record<string, string>;
@@ -11,6 +18,8 @@ export interface ProjectInterface {
   mirrorchyan_multiplatform?: boolean;
   github?: string;
   version?: string;
+  /** MXU 扩展：资源要求的最低 MaaFramework 版本（语义化版本），不满足时拒绝加载资源 */
+  min_maa_version?: string;
   contact?: string;
   license?: string;
   welcome?: string;
@@ -127,6 +136,8 @@ export interface ControllerItem {
   attach_resource_path?: string[];
   /** v2.3.0: 控制器级的选项配置 */
   option?: string[];
+  /** MXU 扩展：资源期望的分辨率，连接成功后按宽高比预检，不符时提示用户 */
+  expected_resolution?: ExpectedResolution;
   adb?: Record<string, unknown>;
   win32?: Win32Config;
   wlroots?: WlRootsConfig;
@@ -134,6 +145,13 @@ export interface ControllerItem {
   gamepad?: GamepadConfig;
 }
 
+export interface ExpectedResolution {
+  width: number;
+  height: number;
+  /** 宽高比允许的相对误差，默认 0.02 */
+  tolerance?: number;
+}
+
 export interface Win32Config {
   class_regex?: string;
   window_regex?: string;
@@ -283,6 +301,7 @@ export interface SelectedTask {
   id: string;
   taskName: string;
   customName?: string; // 用户自定义名称
+  skipIf?: string; // 跳过条件表达式（任务开始时为真则跳过）
   enabled: boolean;
   /** 各控制器独立的勾选状态；enabled 始终表示当前控制器的状态 */
   enabledByController?: Record<string, boolean>;
@@ -331,6 +350,33 @@ export interface SchedulePolicy {
   times: string[]; // 开始时间点 ("HH:mm"，已排序去重)
 }
 
+// 任务队列循环执行设置
+export interface QueueLoopConfig {
+  enabled: boolean; // 是否循环执行
+  rounds: number; // 总轮数，0 表示无限循环
+  intervalSec: number; // 每轮之间的间隔（秒）
+}
+
+// 连接前自动启动模拟器设置（对应实例所绑定的设备）
+export interface EmulatorLaunchSettings {
+  enabled: boolean; // 是否在连接前启动模拟器
+  program: string; // 模拟器启动程序
+  args: string; // 启动参数
+  adbAddress?: string; // ADB 设备地址（设备未出现时按此地址等待，如 127.0.0.1:16384）
+  timeoutSecs: number; // 等待设备/窗口就绪的超时（秒）
+  settleSecs: number; // 拉起后设备/窗口出现再等待的秒数
+}
+
+// 实例运行时设置（保存到配置，连接控制器前下发给后端）
+export interface InstanceRuntimeSettings {
+  taskRetry?: TaskRetryPolicy | null; // 任务失败重试策略
+  speed?: number; // 操作速度系数（1 为原速）
+  watchdog?: WatchdogConfig | null; // 最大运行时长看门狗
+  dryRun?: boolean; // 只识别不操作
+  screenshotPreprocess?: ScreenshotPreprocess | null; // 截图预处理
+  unknownScreens?: UnknownScreenConfig | null; // 未知界面收集
+}
+
 // pre-action config
 export interface ActionConfig {
   id: string; // 唯一标识（用于排序和识别）
@@ -341,6 +387,7 @@ export interface ActionConfig {
   waitForExit: boolean; // 是否等待进程退出（默认 true）
   skipIfRunning: boolean; // 程序已运行时跳过执行（默认 true）
   useCmd: boolean; // 通过 cmd /c 启动（仅 Windows，默认 false）
+  hideWindow?: boolean; // 隐藏控制台窗口（仅 Windows，默认 false）
 }
 
 // 多开实例状态
@@ -359,6 +406,12 @@ export interface Instance {
   // 定时执行策略列表
   schedulePolicies?: SchedulePolicy[];
   preActions?: ActionConfig[];
+  // 任务队列循环执行设置
+  queueLoop?: QueueLoopConfig;
+  // 连接前自动启动模拟器
+  emulatorLaunch?: EmulatorLaunchSettings;
+  // 实例运行时设置（失败重试策略等）
+  runtimeSettings?: InstanceRuntimeSettings;
 }
 
 /** v2.3.0: 预设中的任务配置 */
@@ -1,5 +1,7 @@
 // MaaFramework 类型定义
 
+import type { OptionValue } from './interface';
+
 /** ADB 设备信息 */
 export interface AdbDevice {
   name: string;
@@ -8,6 +10,10 @@ export interface AdbDevice {
   screencap_methods: string; // u64 作为字符串传递，避免 JS 精度丢失
   input_methods: string; // u64 作为字符串传递
   config: string;
+  /** 是否为 USB 连接的真机（非模拟器） */
+  physical?: boolean;
+  /** 真机的 adb 授权状态（device / unauthorized / offline），模拟器为空 */
+  auth_state?: string | null;
 }
 
 /** Win32 窗口信息 */
@@ -15,6 +21,8 @@ export interface Win32Window {
   handle: number;
   class_name: string;
   window_name: string;
+  /** 窗口所属程序图标（PNG data URL） */
+  icon?: string | null;
 }
 
 /** ADB 控制器配置 */
@@ -81,6 +89,46 @@ export type ControllerConfig =
 /** 连接状态 */
 export type ConnectionStatus = 'Disconnected' | 'Connecting' | 'Connected' | { Failed: string };
 
+/** 当前控制器详情（maa_get_controller_info 返回） */
+export interface ControllerInfo {
+  /** connected：已连接；disconnected：已创建但未连接或已断开；none：尚未创建控制器 */
+  status: 'connected' | 'disconnected' | 'none';
+  /** 控制器类型（Adb / Win32 / WlRoots / Gamepad / PlayCover / Dummy） */
+  controller_type: string | null;
+  /** 连接目标（ADB 地址、窗口句柄、socket 路径等） */
+  target: string | null;
+  /** ADB 路径（仅 ADB 控制器） */
+  adb_path: string | null;
+  /** 截图方式 */
+  screencap_methods: string[];
+  /** 输入方式（Win32 为鼠标输入方式） */
+  input_methods: string[];
+  /** 键盘输入方式（仅 Win32 控制器） */
+  keyboard_methods: string[];
+  /** 手柄类型（仅 Gamepad 控制器） */
+  gamepad_type: string | null;
+  /** 设备原始分辨率 [宽, 高] */
+  resolution: [number, number] | null;
+  /** 截图缩放的目标短边 */
+  display_short_side: number | null;
+  /** 控制器 UUID */
+  uuid: string | null;
+}
+
+/** 已加载资源详情（maa_is_resource_loaded 返回） */
+export interface ResourceLoadedInfo {
+  /** 资源是否已加载 */
+  loaded: boolean;
+  /** 已加载的资源目录列表 */
+  paths: string[];
+  /** interface.json 中声明的版本号 */
+  version: string | null;
+  /** 资源 hash */
+  hash: string | null;
+  /** 加载耗时（毫秒） */
+  load_duration_ms: number | null;
+}
+
 /** 任务状态 */
 export type TaskStatus = 'Pending' | 'Running' | 'Succeeded' | 'Failed';
 
@@ -91,6 +139,47 @@ export interface MaaInitState {
   error: string | null;
 }
 
+/** 缓存截图获取结果 */
+export interface CachedImageFrame {
+  /** 帧序号，帧内容变化时自增 */
+  seq: number;
+  /** 传入的帧序号与当前一致时为 true，此时不携带图像数据 */
+  not_modified: boolean;
+  data_url: string | null;
+  /** 已完成的最近一次主动截图的序号（尚无时为 0） */
+  capture_seq: number;
+  /** 该次截图完成的时间（Unix 毫秒时间戳） */
+  captured_at: number | null;
+}
+
+/** maa_post_screencap 的返回结果 */
+export interface ScreencapTicket {
+  /** 控制器截图请求 ID */
+  job_id: number;
+  /** 本次截图的序号，传给 getCachedImage 可等待该帧完成 */
+  seq: number;
+  /** 发起截图的时间（Unix 毫秒时间戳） */
+  requested_at: number;
+}
+
+/** 目录路径及其是否存在 */
+export interface AppPathEntry {
+  path: string;
+  exists: boolean;
+}
+
+/** 应用关键目录（get_app_paths） */
+export interface AppPaths {
+  exe_dir: AppPathEntry;
+  data_dir: AppPathEntry;
+  logs_dir: AppPathEntry;
+  cache_dir: AppPathEntry;
+  maafw_dir: AppPathEntry;
+  cwd: AppPathEntry;
+  /** 使用系统 WebView2 或非 Windows 平台时为 null */
+  webview2_runtime_dir: AppPathEntry | null;
+}
+
 /** 实例运行时信息 */
 export interface InstanceRuntimeInfo {
   connectionStatus: ConnectionStatus;
@@ -193,4 +282,561 @@ export interface TaskConfig {
   pipeline_override: string;
   /** 对应的前端选中任务 ID（用于后端跟踪 per-task 状态） */
   selected_task_id?: string;
+  /** 任务优先级，越大越优先（默认 0），高于当前执行中任务时抢占执行 */
+  priority?: number;
+  /** 跳过条件表达式，任务开始时为真则跳过 */
+  skip_if?: string;
+}
+
+/** 任务因跳过条件被跳过，或跳过条件有误而照常执行的事件 */
+export interface TaskConditionEvent {
+  instance_id: string;
+  task_id: number;
+  entry: string;
+  expression: string;
+  /** 是否已跳过 */
+  skipped: boolean;
+  /** 表达式解析或求值失败的原因（此时任务照常执行） */
+  error: string | null;
+}
+
+/** 任务校验发现的问题（与 Rust 端 ResourceIssue 对应） */
+export interface TaskValidationIssue {
+  level: 'error' | 'warning';
+  /** 节点来源：仅由 override 新增的节点为 pipeline_override，资源中的节点为空 */
+  file: string;
+  /** 问题位置（"节点名.字段"） */
+  location: string;
+  message: string;
+}
+
+/** 任务提交前的校验结果（maa_run_task 的 validate_only 模式） */
+export interface TaskValidation {
+  entry: string;
+  /** 没有 error 级别的问题 */
+  valid: boolean;
+  /** 从入口节点可达的节点数 */
+  reachable_nodes: number;
+  /** 本次 override 涉及的节点 */
+  overridden_nodes: string[];
+  /** 问题清单（先 error 后 warning） */
+  issues: TaskValidationIssue[];
+}
+
+/** 任务结束时保留的最后一帧截图 */
+export interface LastFrame {
+  task_id: number;
+  entry: string;
+  /** 任务终态 */
+  status: 'succeeded' | 'failed';
+  /** 截图时间（Unix 毫秒时间戳） */
+  captured_at: number;
+  /** base64 编码的 PNG data URL */
+  data_url: string;
+}
+
+/** 高优先级任务抢占事件 */
+export interface TaskPreemptedEvent {
+  instance_id: string;
+  /** 抢占执行的高优先级任务 ID */
+  task_id: number;
+  priority: number;
+  /** 被中断及排在其后的任务，旧 ID -> 重新提交后的新 ID */
+  resubmitted: { old_task_id: number; new_task_id: number }[];
+}
+
+/** 任务失败重试策略 */
+export interface TaskRetryPolicy {
+  /** 单个任务最多重试的次数（最多 10） */
+  max_retries: number;
+  /** 失败后等待多久再重试（毫秒） */
+  interval_ms: number;
+  /** 重试前是否重连控制器 */
+  reconnect: boolean;
+  /** 重试前重启的应用（ADB 为包名或 Activity），为空时不重启 */
+  restart_app?: string | null;
+}
+
+export type TaskRetryStatus = 'retrying' | 'resubmitted' | 'failed';
+
+/** 任务失败重试事件 */
+export interface TaskRetryEvent {
+  instance_id: string;
+  /** 失败的任务 ID */
+  task_id: number;
+  entry: string;
+  /** 第几次重试（从 1 开始） */
+  attempt: number;
+  max_retries: number;
+  status: TaskRetryStatus;
+  /** 重新提交的任务，旧 ID -> 新 ID */
+  resubmitted: { old_task_id: number; new_task_id: number }[];
+  error: string | null;
+}
+
+/** 任务队列循环执行配置 */
+export interface TaskQueueLoop {
+  /** 总轮数，0 表示无限循环 */
+  rounds: number;
+  /** 每轮结束后等待多久再开始下一轮（毫秒） */
+  interval_ms: number;
+}
+
+/** 任务队列一轮执行结束事件 */
+export interface TaskRoundCompletedEvent {
+  instance_id: string;
+  /** 刚结束的是第几轮（从 1 开始） */
+  round: number;
+  /** 总轮数，0 表示无限循环 */
+  max_rounds: number;
+  has_failed: boolean;
+  /** 是否继续执行下一轮 */
+  continues: boolean;
+  /** 距下一轮开始的等待时间（毫秒） */
+  interval_ms: number;
+}
+
+/** 日志按任务分段的标记事件（任务开始与结束时各一次） */
+export interface LogSectionEvent {
+  instance_id: string;
+  task_id: number;
+  entry: string;
+  /** 对应的前端任务 ID（通过任务列表提交的任务才有） */
+  selected_task_id: string | null;
+  phase: 'start' | 'end';
+  /** 结束时的结果，开始时为 null */
+  status: 'succeeded' | 'failed' | null;
+  /** 循环执行时的当前轮次（从 1 开始），未循环时为 0 */
+  round: number;
+}
+
+/** 覆盖 Pipeline 的结果，字段名形如 `节点名.字段名` */
+export interface PipelineOverrideResult {
+  /** 是否已应用到该任务（任务已结束时为 false） */
+  applied: boolean;
+  /** 从下一个节点开始生效（排队中的任务则在开始时生效） */
+  hot_fields: string[];
+  /** 所在节点正在执行，待该节点下次执行时生效 */
+  deferred_fields: string[];
+  /** 本次运行中不再生效，需重新开始任务 */
+  restart_fields: string[];
+  /** 是否已同步到循环执行的后续轮次 */
+  next_round: boolean;
+}
+
+/** 运行中热更新 Pipeline 后的确认事件 */
+export interface PipelineHotUpdatedEvent extends PipelineOverrideResult {
+  instance_id: string;
+  task_id: number;
+}
+
+/** 单个任务当前生效的 Pipeline override */
+export interface PipelineOverrideInfo {
+  task_id: number;
+  /** 提交任务时携带的原始 override */
+  original: Record<string, unknown>;
+  /** 运行中追加的各次 override（按应用顺序） */
+  applied: Record<string, unknown>[];
+  /** 叠加后当前生效的 override */
+  effective: Record<string, unknown>;
+}
+
+/** 性能测试单项耗时统计（毫秒） */
+export interface BenchmarkStat {
+  rounds: number;
+  failures: number;
+  avg_ms: number;
+  min_ms: number;
+  max_ms: number;
+}
+
+/** 截图与识别性能测试结果 */
+export interface BenchmarkResult {
+  instance_id: string;
+  controller_config: ControllerConfig | null;
+  /** 截图分辨率 [宽, 高] */
+  resolution: [number, number] | null;
+  screencap: BenchmarkStat;
+  fps: number;
+  recognitions: {
+    name: string;
+    /** 单次任务（截图 + 识别）的总耗时 */
+    task: BenchmarkStat;
+    /** 估算的识别耗时 */
+    recognition_avg_ms: number;
+  }[];
+  /** 测试时生效的截图预处理参数（未开启时为 null） */
+  preprocess: ScreenshotPreprocess | null;
+  /** 导出的 JSON 文件路径 */
+  report_path: string | null;
+  timestamp: string;
+}
+
+/** 实例精简信息（maa_list_instances 返回，按创建顺序） */
+export interface InstanceSummary {
+  id: string;
+  name: string | null;
+  /** 创建时间（RFC 3339） */
+  created_at: string | null;
+  /** 已连接设备的标识，未连接时为 null */
+  device: string | null;
+}
+
+/** Win32 截图防遮挡模式：默认 / 截图前置前台 / PrintWindow 截图 */
+export type CaptureMode = 'default' | 'foreground' | 'print_window';
+
+/** Win32 目标窗口最小化时的处理方式 */
+export type MinimizedPolicy = 'notify' | 'pause' | 'restore';
+
+/** 目标窗口最小化/可见状态变化事件 */
+export interface WindowStateEvent {
+  instance_id: string;
+  handle: number;
+  minimized: boolean;
+  visible: boolean;
+  policy: MinimizedPolicy;
+}
+
+/** 连接前自动启动模拟器的配置（目标设备/窗口不存在时先拉起模拟器再连接） */
+export interface EmulatorLaunchConfig {
+  program: string;
+  args?: string;
+  cwd?: string;
+  use_cmd?: boolean;
+  hide_window?: boolean;
+  /** 等待设备/窗口就绪的超时（秒），默认 120 */
+  timeout_secs?: number;
+  /** 拉起模拟器后设备/窗口出现再等待的秒数 */
+  settle_secs?: number;
+  /** Win32 / Gamepad 原窗口句柄失效时用于查找新窗口的正则 */
+  class_regex?: string;
+  window_regex?: string;
+}
+
+export type EmulatorLaunchStage = 'launching' | 'already_running' | 'ready' | 'timeout';
+
+/** 连接前自动启动模拟器的进度事件 */
+export interface EmulatorLaunchEvent {
+  instance_id: string;
+  stage: EmulatorLaunchStage;
+  program: string;
+}
+
+/** ADB 授权状态变化阶段 */
+export type AdbAuthStage = 'unauthorized' | 'authorized' | 'timeout';
+
+/** ADB 设备授权状态变化事件 */
+export interface AdbAuthEvent {
+  instance_id: string;
+  /** 设备序列号或地址 */
+  address: string;
+  stage: AdbAuthStage;
+  /** 授权恢复后是否已自动重连（连接前等待授权时恒为 false） */
+  reconnected: boolean;
+}
+
+/** 分享码中的单个任务 */
+export interface SharedTask {
+  /** interface 中的任务名 */
+  name: string;
+  customName?: string;
+  enabled: boolean;
+  /** 选项 key -> 选项值 */
+  options: Record<string, OptionValue>;
+}
+
+/** 分享码承载的任务队列配置 */
+export interface SharePayload {
+  /** 导出时的项目名 */
+  project?: string;
+  tasks: SharedTask[];
+}
+
+/** 截图区域裁剪结果 */
+export interface ImageRoi {
+  /** 实际裁剪的区域 [x, y, w, h]（越界部分已截到图像范围内） */
+  roi: [number, number, number, number];
+  /** 请求的区域发生了越界截断 */
+  clamped: boolean;
+  image_width: number;
+  image_height: number;
+  /** 裁剪结果，base64 编码的 PNG data URL */
+  data_url: string;
+}
+
+/** 截图帧去重配置 */
+export interface FrameDedupConfig {
+  /** 相邻两帧哈希相似度达到该值（0.5~1）时视为画面未变化 */
+  similarity: number;
+  /** 画面持续未变化时每轮识别前最多等待的时间（毫秒） */
+  max_interval_ms: number;
+}
+
+/** 未知界面收集配置（按实例，未设置时不收集） */
+export interface UnknownScreenConfig {
+  /** 连续多少轮识别全部未命中时视为遇到未知界面（1~100） */
+  threshold: number;
+}
+
+/** 已收集的未知界面概况 */
+export interface UnknownScreensInfo {
+  /** 归档目录 */
+  dir: string;
+  /** 已归档的截图数量 */
+  count: number;
+}
+
+/** 截图预处理参数（送去识别前统一调整） */
+export interface ScreenshotPreprocess {
+  /** 亮度偏移（-100 ~ 100，0 为不调整） */
+  brightness: number;
+  /** 对比度倍数（0.5 ~ 2，1 为不调整） */
+  contrast: number;
+  /** gamma（0.2 ~ 5，1 为不调整，大于 1 时提亮暗部） */
+  gamma: number;
+}
+
+/** 执行外部程序前的信任策略 */
+export type ProgramTrustMode = 'trust_all' | 'confirm' | 'strict';
+
+/** 外部程序信任策略与信任列表 */
+export interface ProgramTrustConfig {
+  mode: ProgramTrustMode;
+  /** 已信任的程序路径（规范化后） */
+  programs: string[];
+}
+
+/** 外部程序被拒绝执行的事件 */
+export interface ProgramBlockedEvent {
+  instance_id: string | null;
+  program: string;
+  /** run_action / run_pretask / MXU_LAUNCH */
+  source: string;
+  /** 用户在确认框中拒绝为 true，严格模式下不在信任列表中为 false */
+  rejected_by_user: boolean;
+}
+
+/** 各组件版本信息，无法获取的字段为 'unknown' */
+export interface VersionInfo {
+  mxu: string;
+  framework: string;
+  agent: string;
+  commit: string;
+  /** 编译时间（RFC 3339） */
+  build_time: string;
+}
+
+/** 连接后的分辨率预检结果 */
+export interface ResolutionCheck {
+  instance_id: string;
+  /** 截图尺寸（已按 display_short_side 缩放，宽高比与设备一致） */
+  actual: [number, number];
+  /** 资源期望的分辨率，不符时作为建议分辨率 */
+  expected: [number, number];
+  matched: boolean;
+}
+
+/** 资源完整性校验所用清单的来源：随资源包分发 / 之前生成 / 本次首次生成（无从比对） */
+export type ResourceManifestSource = 'bundled' | 'generated' | 'created';
+
+/** 单个资源目录的完整性校验结果 */
+export interface ResourceIntegrityReport {
+  path: string;
+  source: ResourceManifestSource;
+  total_files: number;
+  /** 本次实际计算哈希的文件数（其余文件未变化，沿用上次结果） */
+  hashed_files: number;
+  /** 缺失的文件（相对资源目录） */
+  missing: string[];
+  /** 与清单不符的文件（相对资源目录） */
+  modified: string[];
+  elapsed_ms: number;
+}
+
+/** 进程资源占用 */
+export interface ProcessInfo {
+  pid: number;
+  name: string;
+  /** mxu: 本程序；agent: 已登记的 agent 子进程；other: 其他进程 */
+  role: 'mxu' | 'agent' | 'other';
+  /** agent 所属实例的显示名称 */
+  instanceName?: string | null;
+  /** CPU 使用率（占整机的百分比） */
+  cpuUsage: number;
+  memoryBytes: number;
+  /** 线程数（平台不支持时为空） */
+  threadCount?: number | null;
+  runTimeSecs: number;
+}
+
+/** 实例运行时长看门狗配置 */
+export interface WatchdogConfig {
+  /** 单轮任务允许的最大运行时长（秒） */
+  max_runtime_secs: number;
+  /** 停在断点上的时间是否计入运行时长 */
+  count_paused: boolean;
+}
+
+/** 看门狗超时事件（后端已停止任务） */
+export interface WatchdogTimeoutEvent {
+  instance_id: string;
+  max_runtime_secs: number;
+  /** 超时时已计入的运行时长（秒） */
+  elapsed_secs: number;
+}
+
+/** 点击/滑动轨迹事件（maa-callback 中 message 为 MXU.Action.Trace 的 details） */
+export interface ActionTraceEvent {
+  instance_id: string;
+  /** 控制器动作类型（click / long_press / swipe 等） */
+  action: string;
+  /** 起点（点击类动作即点击点），设备坐标 */
+  begin: [number, number];
+  /** 终点（仅滑动类动作），设备坐标 */
+  end: [number, number] | null;
+  /** 所属 pipeline 节点（手动点击等非任务动作为空） */
+  node: string | null;
+  /** 当前截图尺寸，用于换算到预览坐标 */
+  resolution: [number, number] | null;
+}
+
+/** 跳转到指定日志的事件（主窗口日志面板据此滚动到对应位置） */
+export interface LogJumpEvent {
+  instance_id: string;
+  seq: number;
+  /** 前端日志 ID */
+  log_id: string;
+  timestamp: string;
+}
+
+/** 标注截图上画出的一次识别结果 */
+export interface AnnotatedRecognition {
+  node: string;
+  hit: boolean;
+  /** 识别框 [x, y, w, h]（未命中时为分数最高的候选框） */
+  rect: [number, number, number, number] | null;
+  score: number | null;
+}
+
+/** 悬浮窗画面预览的一帧 */
+export interface OverlayPreviewFrame {
+  instance_id: string;
+  /** 缩略图（PNG data URL） */
+  image: string;
+  /** 原始截图尺寸，识别框按此坐标系换算到缩略图 */
+  width: number;
+  height: number;
+  /** 当前一轮的识别结果 */
+  recognitions: AnnotatedRecognition[];
+}
+
+/** 带标注截图的保存结果 */
+export interface AnnotatedScreenshotResult {
+  path: string;
+  width: number;
+  height: number;
+  /** 画上的识别结果（为空时保存的是原图） */
+  recognitions: AnnotatedRecognition[];
+}
+
+/** 单个识别节点的命中率统计 */
+export interface RecoNodeStats {
+  node: string;
+  /** 识别次数 */
+  attempts: number;
+  /** 命中次数 */
+  hits: number;
+  /** 带匹配分数的识别次数（ColorMatch 等无分数的算法不计入） */
+  scored: number;
+  /** 平均匹配分数（取每次识别的最高分） */
+  avg_score: number | null;
+  /** 命中时的平均匹配分数 */
+  avg_hit_score: number | null;
+  min_score: number | null;
+  max_score: number | null;
+  /** 分数分布：将 [0, 1] 等分为 10 段，依次为各段的识别次数 */
+  score_histogram: number[];
+}
+
+/** 单个 pipeline 节点的执行耗时统计（耗时单位均为毫秒） */
+export interface NodeTimingStats {
+  node: string;
+  /** 节点执行次数（命中后执行的次数） */
+  runs: number;
+  /** 节点累计/平均/最长耗时 */
+  total_ms: number;
+  avg_ms: number;
+  max_ms: number;
+  /** 识别次数（含作为 next 候选未命中的识别）与累计/平均耗时 */
+  recognitions: number;
+  recognition_total_ms: number;
+  recognition_avg_ms: number;
+  /** 动作执行次数与累计/平均耗时 */
+  actions: number;
+  action_total_ms: number;
+  action_avg_ms: number;
+}
+
+/** 节点引用的模板图预览 */
+export interface TemplatePreview {
+  /** pipeline 中声明的模板路径（相对 image 目录） */
+  template: string;
+  /** 实际读取的图片文件（模板为目录时为目录下的各图片） */
+  path: string;
+  /** 图片内容（base64） */
+  base64: string;
+}
+
+/** 停止 Agent 的结果：无运行中的 Agent / 自行退出 / 超时后被强制结束 */
+export type AgentStopOutcome = 'not_running' | 'graceful' | 'killed';
+
+/** 一键停止中单个实例的结果：已停下 / 超时仍在运行 / 停止请求失败 */
+export type InstanceStopOutcome = 'stopped' | 'timed_out' | 'failed';
+
+/** maa_stop_all 返回的单个实例结果 */
+export interface InstanceStopResult {
+  instance_id: string;
+  outcome: InstanceStopOutcome;
+  /** 停止失败时的错误信息 */
+  error: string | null;
+}
+
+/** 运行录像导出格式（mp4 需要 ffmpeg，不可用时回退为 gif） */
+export type ClipFormat = 'gif' | 'mp4';
+
+/** 运行录像导出结果 */
+export interface ClipExportResult {
+  /** 导出的文件路径 */
+  path: string;
+  /** 实际导出的格式 */
+  format: ClipFormat;
+  /** 导出的帧数（内容未变化的连续截图合并为一帧） */
+  frames: number;
+  /** 超出录制上限而丢弃的最旧帧数 */
+  dropped_frames: number;
+  duration_ms: number;
+}
+
+/** 后端结构化错误码 */
+export type MxuErrorCode =
+  | 'INSTANCE_NOT_FOUND'
+  | 'CONTROLLER_NOT_CONNECTED'
+  | 'CONTROLLER_CONNECT_FAILED'
+  | 'RESOURCE_NOT_LOADED'
+  | 'RESOURCE_LOAD_FAILED'
+  | 'TASK_RUN_FAILED'
+  | 'TASK_PREEMPTING'
+  | 'DOWNLOAD_FAILED'
+  | 'DOWNLOAD_CANCELLED'
+  | 'UPDATE_FAILED'
+  | 'INVALID_ARGUMENT'
+  | 'INTERNAL';
+
+/** 关键命令（连接、加载资源、运行任务、下载、更新）返回的结构化错误 */
+export interface MxuErrorPayload {
+  code: MxuErrorCode;
+  /** 可读描述 */
+  message: string;
+  /** 补充信息（实例 ID、URL、路径等） */
+  detail: string | null;
 }
@@ -164,6 +164,7 @@ const MXU_LAUNCH_TASK_DEF_INTERNAL: TaskItem = {
     '__MXU_LAUNCH_WAIT_OPTION__',
     '__MXU_LAUNCH_SKIP_OPTION__',
     '__MXU_LAUNCH_CMD_OPTION__',
+    '__MXU_LAUNCH_HIDE_OPTION__',
   ],
   pipeline_override: {
     [MXU_LAUNCH_ENTRY]: {
@@ -269,6 +270,38 @@ const MXU_LAUNCH_CMD_OPTION_DEF_INTERNAL: SwitchOption = {
   default_case: 'No',
 };
 
+// MXU_LAUNCH 隐藏控制台窗口选项定义（仅 Windows 生效）
+const MXU_LAUNCH_HIDE_OPTION_DEF_INTERNAL: SwitchOption = {
+  type: 'switch',
+  label: 'specialTask.launch.hideLabel',
+  description: 'specialTask.launch.hideDescription',
+  cases: [
+    {
+      name: 'Yes',
+      label: 'specialTask.launch.hideYes',
+      pipeline_override: {
+        [MXU_LAUNCH_ENTRY]: {
+          custom_action_param: {
+            hide_window: true,
+          },
+        },
+      },
+    },
+    {
+      name: 'No',
+      label: 'specialTask.launch.hideNo',
+      pipeline_override: {
+        [MXU_LAUNCH_ENTRY]: {
+          custom_action_param: {
+            hide_window: false,
+          },
+        },
+      },
+    },
+  ],
+  default_case: 'No',
+};
+
 // MXU_LAUNCH 跳过已运行选项定义
 const MXU_LAUNCH_SKIP_OPTION_DEF_INTERNAL: SwitchOption = {
   type: 'switch',
@@ -576,6 +609,7 @@ export const MXU_SPECIAL_TASKS: Record<string, MxuSpecialTaskDefinition> = {
       __MXU_LAUNCH_WAIT_OPTION__: MXU_LAUNCH_WAIT_OPTION_DEF_INTERNAL,
       __MXU_LAUNCH_SKIP_OPTION__: MXU_LAUNCH_SKIP_OPTION_DEF_INTERNAL,
       __MXU_LAUNCH_CMD_OPTION__: MXU_LAUNCH_CMD_OPTION_DEF_INTERNAL,
+      __MXU_LAUNCH_HIDE_OPTION__: MXU_LAUNCH_HIDE_OPTION_DEF_INTERNAL,
     },
     iconName: 'Play',
     iconColorClass: 'text-success/80',
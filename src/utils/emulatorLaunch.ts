@@ -0,0 +1,93 @@
+/**
+ * 连接前自动启动模拟器
+ * 实例启用后，连接控制器时把启动配置一并传给后端：目标设备/窗口不存在时后端先拉起模拟器并等待就绪
+ */
+
+import { useAppStore } from '@/stores/appStore';
+import type { ControllerItem, EmulatorLaunchSettings } from '@/types/interface';
+import type { ControllerConfig, EmulatorLaunchConfig } from '@/types/maa';
+import { parseWin32InputMethod, parseWin32ScreencapMethod } from '@/types/maa';
+
+export const DEFAULT_EMULATOR_LAUNCH: EmulatorLaunchSettings = {
+  enabled: false,
+  program: '',
+  args: '',
+  adbAddress: '',
+  timeoutSecs: 120,
+  settleSecs: 10,
+};
+
+// MaaFramework 默认截图 / 输入方式（u64 字符串），设备尚未出现、无法检测时使用
+const ADB_SCREENCAP_DEFAULT = '18446744073709551559';
+const ADB_INPUT_DEFAULT = '18446744073709551607';
+
+function getInstanceController(instanceId: string): ControllerItem | undefined {
+  const state = useAppStore.getState();
+  const controllerName =
+    state.selectedController[instanceId] || state.projectInterface?.controller?.[0]?.name;
+  return state.projectInterface?.controller?.find((c) => c.name === controllerName);
+}
+
+/**
+ * 获取实例的模拟器启动配置（传给 connectController），未启用或未填写程序时返回 undefined
+ * @param instanceId 实例 ID
+ */
+export function getEmulatorLaunch(instanceId: string): EmulatorLaunchConfig | undefined {
+  const instance = useAppStore.getState().instances.find((i) => i.id === instanceId);
+  const settings = instance?.emulatorLaunch;
+  if (!settings?.enabled || !settings.program.trim()) return undefined;
+
+  const controller = getInstanceController(instanceId);
+  const windowConfig = controller?.win32 ?? controller?.gamepad;
+  return {
+    program: settings.program.trim(),
+    args: settings.args,
+    timeout_secs: settings.timeoutSecs,
+    settle_secs: settings.settleSecs,
+    class_regex: windowConfig?.class_regex,
+    window_regex: windowConfig?.window_regex,
+  };
+}
+
+/**
+ * 保存的设备尚未出现时，按启动设置构造等待目标的控制器配置
+ * ADB 使用设置中的地址，Win32 / Gamepad 以空句柄交给后端按窗口正则查找；无法构造时返回 null
+ * @param instanceId 实例 ID
+ */
+export function buildEmulatorTargetConfig(instanceId: string): ControllerConfig | null {
+  const state = useAppStore.getState();
+  const settings = state.instances.find((i) => i.id === instanceId)?.emulatorLaunch;
+  const controller = getInstanceController(instanceId);
+  if (!settings?.enabled || !controller) return null;
+
+  switch (controller.type) {
+    case 'Adb': {
+      const address = settings.adbAddress?.trim();
+      if (!address) return null;
+      return {
+        type: 'Adb',
+        adb_path: state.adbPath || 'adb',
+        address,
+        screencap_methods: ADB_SCREENCAP_DEFAULT,
+        input_methods: ADB_INPUT_DEFAULT,
+        config: '{}',
+        display_short_side: controller.display_short_side,
+      };
+    }
+    case 'Win32':
+      if (!controller.win32?.class_regex && !controller.win32?.window_regex) return null;
+      return {
+        type: 'Win32',
+        handle: 0,
+        screencap_method: parseWin32ScreencapMethod(controller.win32.screencap || ''),
+        mouse_method: parseWin32InputMethod(controller.win32.mouse || ''),
+        keyboard_method: parseWin32InputMethod(controller.win32.keyboard || ''),
+        display_short_side: controller.display_short_side,
+      };
+    case 'Gamepad':
+      if (!controller.gamepad?.class_regex && !controller.gamepad?.window_regex) return null;
+      return { type: 'Gamepad', handle: 0, display_short_side: controller.display_short_side };
+    default:
+      return null;
+  }
+}
@@ -0,0 +1,77 @@
+/**
+ * 实例运行时设置（见 InstanceRuntimeSettings）
+ * 后端只在内存中保存这些设置，重启或重建实例后丢失；前端随实例配置保存，连接控制器前重新下发
+ */
+
+import { useAppStore } from '@/stores/appStore';
+import { maaService } from '@/services/maaService';
+import type { InstanceRuntimeSettings } from '@/types/interface';
+import { loggers } from '@/utils/logger';
+
+const log = loggers.app;
+
+/**
+ * 合并更新实例保存的运行时设置
+ * @param instanceId 实例 ID
+ * @param updates 要更新的设置项
+ */
+export function saveInstanceRuntimeSettings(
+  instanceId: string,
+  updates: Partial<InstanceRuntimeSettings>,
+): void {
+  const state = useAppStore.getState();
+  const instance = state.instances.find((i) => i.id === instanceId);
+  if (!instance) return;
+  state.updateInstance(instanceId, {
+    runtimeSettings: { ...instance.runtimeSettings, ...updates },
+  });
+}
+
+/**
+ * 把实例保存的运行时设置下发给后端（连接控制器前调用，需实例已创建）
+ * 截图预处理须在连接前设置才会包装截图；单项失败只记录日志，不影响连接
+ * @param instanceId 实例 ID
+ */
+export async function applyInstanceRuntimeSettings(instanceId: string): Promise<void> {
+  const instance = useAppStore.getState().instances.find((i) => i.id === instanceId);
+  if (!instance?.runtimeSettings) return;
+  const { taskRetry, speed, watchdog, dryRun, screenshotPreprocess, unknownScreens } =
+    instance.runtimeSettings;
+
+  const apply = async (name: string, fn: () => Promise<unknown>) => {
+    try {
+      await fn();
+    } catch (err) {
+      log.warn(`恢复实例运行时设置 ${name} 失败:`, err);
+    }
+  };
+
+  if (screenshotPreprocess !== undefined) {
+    await apply('screenshotPreprocess', () =>
+      maaService.setScreenshotPreprocess(instanceId, screenshotPreprocess),
+    );
+  }
+  if (taskRetry !== undefined) {
+    await apply('taskRetry', () => maaService.setTaskRetryPolicy(instanceId, taskRetry));
+  }
+  if (speed !== undefined) {
+    await apply('speed', () => maaService.setSpeed(instanceId, speed));
+  }
+  if (dryRun !== undefined) {
+    await apply('dryRun', () => maaService.setDryRun(instanceId, dryRun));
+  }
+  if (unknownScreens !== undefined) {
+    await apply('unknownScreens', () =>
+      maaService.setUnknownScreenCapture(instanceId, unknownScreens),
+    );
+  }
+  if (watchdog !== undefined) {
+    await apply('watchdog', () =>
+      maaService.setWatchdog(
+        instanceId,
+        watchdog?.max_runtime_secs ?? null,
+        watchdog?.count_paused ?? false,
+      ),
+    );
+  }
+}
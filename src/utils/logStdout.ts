@@ -24,10 +24,11 @@ async function getInvoke(): Promise<InvokeFn | null> {
   return _initPromise;
 }
 
-export function logToStdout(message: string) {
+/** 转发一行日志到后端 stdout，传入 instanceId 时后端会加上实例标签 */
+export function logToStdout(message: string, instanceId?: string) {
   if (!message) return;
   getInvoke().then((inv) => {
-    if (inv) inv('log_to_stdout', { message }).catch(() => {});
+    if (inv) inv('log_to_stdout', { message, instanceId }).catch(() => {});
   });
 }
 
@@ -37,6 +38,15 @@ export interface LogEntryDto {
   type: string;
   message: string;
   html?: string;
+  /** 后端分配的单调序号（跨实例唯一，用作日志锚点） */
+  seq?: number;
+}
+
+/** 带实例标签的运行日志（多实例合并视图） */
+export interface TaggedLogEntryDto extends LogEntryDto {
+  instance_id: string;
+  /** 实例显示名（未设置时为 null，显示时回退到 instance_id） */
+  instance_name: string | null;
 }
 
 /** 将一条运行日志推送到后端缓冲区（fire-and-forget） */
@@ -59,6 +69,21 @@ export async function getAllLogsFromBackend(): Promise<Record<string, LogEntryDt
   return apiGet<Record<string, LogEntryDto[]>>('/logs');
 }
 
+/**
+ * 获取多个实例合并后的运行日志（带实例标签，按时间排序）
+ * @param instanceIds 要合并的实例，省略时合并所有实例
+ */
+export async function getMergedLogsFromBackend(
+  instanceIds?: string[],
+): Promise<TaggedLogEntryDto[]> {
+  const inv = await getInvoke();
+  if (inv) {
+    return (await inv('get_merged_logs', { instanceIds })) as TaggedLogEntryDto[];
+  }
+  const query = instanceIds ? `?instances=${encodeURIComponent(instanceIds.join(','))}` : '';
+  return apiGet<TaggedLogEntryDto[]>(`/logs/merged${query}`);
+}
+
 /** 清空后端缓冲区中指定实例的运行日志（fire-and-forget） */
 export function clearLogsOnBackend(instanceId: string) {
   getInvoke().then((inv) => {
@@ -0,0 +1,55 @@
+/**
+ * 后端结构化错误
+ * 关键命令 reject 的是 `{ code, message, detail }` 对象，这里转换为 Error 子类，
+ * 既保留 `err.message` 的可读文本，又可按 `code` 做逻辑分支和本地化
+ */
+
+import type { MxuErrorCode, MxuErrorPayload } from '@/types/maa';
+
+export class MxuError extends Error {
+  readonly code: MxuErrorCode;
+  readonly detail: string | null;
+
+  constructor(payload: MxuErrorPayload) {
+    super(payload.message);
+    this.name = 'MxuError';
+    this.code = payload.code;
+    this.detail = payload.detail ?? null;
+  }
+}
+
+function isMxuErrorPayload(err: unknown): err is MxuErrorPayload {
+  return (
+    typeof err === 'object' &&
+    err !== null &&
+    typeof (err as MxuErrorPayload).code === 'string' &&
+    typeof (err as MxuErrorPayload).message === 'string'
+  );
+}
+
+/** 将 invoke 的 reject 值转换为 MxuError，非结构化错误原样返回 */
+export function toMxuError(err: unknown): unknown {
+  if (err instanceof MxuError || !isMxuErrorPayload(err)) return err;
+  return new MxuError(err);
+}
+
+/** 判断错误是否为结构化错误（可指定错误码） */
+export function isMxuError(err: unknown, code?: MxuErrorCode): err is MxuError {
+  return err instanceof MxuError && (code === undefined || err.code === code);
+}
+
+/** 前置条件错误码对应的本地化提示，显示时不附带后端文本 */
+const PRECONDITION_HINT_KEYS: Partial<Record<MxuErrorCode, string>> = {
+  CONTROLLER_NOT_CONNECTED: 'maa.needConnection',
+  RESOURCE_NOT_LOADED: 'maa.needResource',
+};
+
+/** 获取错误的显示文本，结构化错误加上本地化的错误类别 */
+export function formatError(err: unknown, t: (key: string) => string): string {
+  if (err instanceof MxuError) {
+    const hintKey = PRECONDITION_HINT_KEYS[err.code];
+    if (hintKey) return t(hintKey);
+    return `${t(`errors.codes.${err.code}`)}: ${err.message}`;
+  }
+  return err instanceof Error ? err.message : String(err);
+}
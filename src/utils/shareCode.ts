@@ -0,0 +1,100 @@
+import type { SavedTask } from '@/types/config';
+import type { Instance, OptionValue, ProjectInterface } from '@/types/interface';
+import type { SharePayload, SharedTask } from '@/types/maa';
+import { generateId, initializeAllOptionValues, sanitizeOptionValue } from '@/stores/helpers';
+
+export interface SharedTasksResolution {
+  tasks: SavedTask[];
+  /** 当前 interface 中不存在、已跳过的任务名 */
+  skipped: string[];
+}
+
+const isStringRecord = (value: unknown): value is Record<string, string> =>
+  typeof value === 'object' &&
+  value !== null &&
+  !Array.isArray(value) &&
+  Object.values(value).every((v) => typeof v === 'string');
+
+/** 结构校验分享码中的选项值，格式不对时返回 null */
+function parseSharedOptionValue(value: unknown): OptionValue | null {
+  if (typeof value !== 'object' || value === null) return null;
+  const v = value as Record<string, unknown>;
+  switch (v.type) {
+    case 'select':
+      return typeof v.caseName === 'string' ? { type: 'select', caseName: v.caseName } : null;
+    case 'checkbox':
+      return Array.isArray(v.caseNames) && v.caseNames.every((c) => typeof c === 'string')
+        ? { type: 'checkbox', caseNames: v.caseNames as string[] }
+        : null;
+    case 'switch':
+      return typeof v.value === 'boolean' ? { type: 'switch', value: v.value } : null;
+    case 'input':
+    case 'hotkey':
+      return isStringRecord(v.values) ? { type: v.type, values: v.values } : null;
+    default:
+      return null;
+  }
+}
+
+/** 从实例任务列表构建分享内容，只包含当前 interface 中定义的任务 */
+export function buildSharePayload(
+  instance: Instance,
+  projectInterface: ProjectInterface,
+): SharePayload {
+  const taskNames = new Set(projectInterface.task.map((t) => t.name));
+  return {
+    project: projectInterface.name,
+    tasks: instance.selectedTasks
+      .filter((task) => taskNames.has(task.taskName))
+      .map(
+        (task): SharedTask => ({
+          name: task.taskName,
+          customName: task.customName,
+          enabled: task.enabled,
+          options: task.optionValues,
+        }),
+      ),
+  };
+}
+
+/**
+ * 把分享码中的任务转换为当前 interface 下的任务：
+ * 不存在的任务跳过，选项值先按结构校验再按当前定义清洗，缺失或无效的选项回退为默认值
+ */
+export function resolveSharedTasks(
+  payload: SharePayload,
+  projectInterface: ProjectInterface,
+): SharedTasksResolution {
+  const allOptions = projectInterface.option ?? {};
+  const tasks: SavedTask[] = [];
+  const skipped: string[] = [];
+
+  for (const shared of payload.tasks) {
+    const taskDef = projectInterface.task.find((t) => t.name === shared.name);
+    if (!taskDef) {
+      skipped.push(shared.name);
+      continue;
+    }
+
+    const optionValues = taskDef.option
+      ? initializeAllOptionValues(taskDef.option, allOptions)
+      : {};
+    for (const [optionKey, raw] of Object.entries(shared.options ?? {})) {
+      const parsed = parseSharedOptionValue(raw);
+      const sanitized = parsed ? sanitizeOptionValue(optionKey, parsed, allOptions) : null;
+      if (sanitized) {
+        optionValues[optionKey] = sanitized;
+      }
+    }
+
+    tasks.push({
+      id: generateId(),
+      taskName: shared.name,
+      customName: shared.customName || undefined,
+      enabled: shared.enabled,
+      optionValues,
+    });
+  }
+
+  return { tasks, skipped };
+}
@@ -37,6 +37,7 @@ interface WireTask {
   cn?: string; // customName
   e: boolean; // enabled
   ec?: Record<string, boolean>; // enabledByController
+  si?: string; // skipIf
   ov: Record<string, WireOptionValue>; // optionValues
 }
 
@@ -49,6 +50,7 @@ interface WireAction {
   w: boolean; // waitForExit
   s: boolean; // skipIfRunning
   u: boolean; // useCmd
+  h?: boolean; // hideWindow
 }
 
 interface WirePayload {
@@ -88,6 +90,7 @@ function encodeTask(task: SavedTask): WireTask {
   };
   if (task.customName !== undefined) wire.cn = task.customName;
   if (task.enabledByController !== undefined) wire.ec = task.enabledByController;
+  if (task.skipIf !== undefined) wire.si = task.skipIf;
   return wire;
 }
 
@@ -102,6 +105,7 @@ function encodeAction(action: ActionConfig): WireAction {
     u: action.useCmd,
   };
   if (action.customName !== undefined) wire.cn = action.customName;
+  if (action.hideWindow) wire.h = true;
   return wire;
 }
 
@@ -141,6 +145,7 @@ function decodeTask(w: WireTask): SavedTask {
     customName: w.cn,
     enabled: w.e,
     enabledByController: w.ec,
+    skipIf: w.si,
     optionValues: Object.fromEntries(
       Object.entries(w.ov).map(([k, v]) => [k, decodeOptionValue(v)]),
     ),
@@ -157,6 +162,7 @@ function decodeAction(w: WireAction): ActionConfig {
     waitForExit: w.w,
     skipIfRunning: w.s,
     useCmd: w.u,
+    hideWindow: w.h ?? false,
   };
 }
 
@@ -265,6 +271,7 @@ export async function buildTabConfigExportText(
         instance.controllerName,
         t.enabled,
       ),
+      skipIf: t.skipIf,
       optionValues: t.optionValues,
     })),
     preActions: instance.preActions,
@@ -19,35 +19,40 @@ export function useExportLogs() {
     status: 'idle',
   });
 
-  const handleExportLogs = useCallback(async () => {
-    if (!isTauri()) {
-      loggers.ui.warn('仅 Tauri 环境支持导出日志');
-      return;
-    }
+  /** 导出日志，传入 instanceId 时只导出该实例的日志文件 */
+  const handleExportLogs = useCallback(
+    async (instanceId?: string) => {
+      if (!isTauri()) {
+        loggers.ui.warn('仅 Tauri 环境支持导出日志');
+        return;
+      }
 
-    setExportModal({ show: true, status: 'exporting' });
-    try {
-      const { invoke } = await import('@tauri-apps/api/core');
-      const zipPath = await invoke<string>('export_logs', {
-        projectName: projectInterface?.name,
-        projectVersion: projectInterface?.version,
-      });
-      loggers.ui.info('日志已导出:', zipPath);
+      setExportModal({ show: true, status: 'exporting' });
+      try {
+        const { invoke } = await import('@tauri-apps/api/core');
+        const zipPath = await invoke<string>('export_logs', {
+          projectName: projectInterface?.name,
+          projectVersion: projectInterface?.version,
+          instanceId: instanceId ?? null,
+        });
+        loggers.ui.info('日志已导出:', zipPath);
 
-      setExportModal({ show: true, status: 'success', zipPath });
+        setExportModal({ show: true, status: 'success', zipPath });
 
-      // 打开所在目录并选中文件
-      const { revealItemInDir } = await import('@tauri-apps/plugin-opener');
-      await revealItemInDir(zipPath);
-    } catch (err) {
-      loggers.ui.error('导出日志失败:', err);
-      setExportModal({
-        show: true,
-        status: 'error',
-        error: err instanceof Error ? err.message : String(err),
-      });
-    }
-  }, [projectInterface?.name, projectInterface?.version]);
+        // 打开所在目录并选中文件
+        const { revealItemInDir } = await import('@tauri-apps/plugin-opener');
+        await revealItemInDir(zipPath);
+      } catch (err) {
+        loggers.ui.error('导出日志失败:', err);
+        setExportModal({
+          show: true,
+          status: 'error',
+          error: err instanceof Error ? err.message : String(err),
+        });
+      }
+    },
+    [projectInterface?.name, projectInterface?.version],
+  );
 
   const closeExportModal = useCallback(() => {
     setExportModal({ show: false, status: 'idle' });
@@ -192,6 +192,7 @@ export function useMaaCallbackLogger() {
   const { t } = useTranslation();
   const { addLog } = useAppStore();
   const unlistenRef = useRef<(() => void) | null>(null);
+  const unlistenDryRunRef = useRef<(() => void) | null>(null);
 
   useEffect(() => {
     let cancelled = false;
@@ -223,6 +224,24 @@ export function useMaaCallbackLogger() {
         } else {
           unlistenRef.current = unlisten;
         }
+
+        // dry-run 模式下被跳过的动作
+        const unlistenDryRun = await maaService.onDryRunAction(
+          ({ instance_id, node, action, target }) => {
+            if (cancelled) return;
+            addLog(instance_id, {
+              type: 'info',
+              message: target
+                ? t('logs.messages.dryRunActionAt', { node, action, x: target[0], y: target[1] })
+                : t('logs.messages.dryRunAction', { node, action }),
+            });
+          },
+        );
+        if (cancelled) {
+          unlistenDryRun();
+        } else {
+          unlistenDryRunRef.current = unlistenDryRun;
+        }
       } catch (err) {
         log.error('Failed to setup maa callback listener:', err);
       }
@@ -236,10 +255,40 @@ export function useMaaCallbackLogger() {
         unlistenRef.current();
         unlistenRef.current = null;
       }
+      if (unlistenDryRunRef.current) {
+        unlistenDryRunRef.current();
+        unlistenDryRunRef.current = null;
+      }
     };
   }, [t, addLog]);
 }
 
+/**
+ * 根据 selectedTaskId 获取任务显示名（自定义名称 > 特殊任务翻译 > 项目接口翻译 > 任务名）
+ */
+export function getSelectedTaskDisplayName(
+  instanceId: string,
+  selectedTaskId: string,
+): string | undefined {
+  const state = useAppStore.getState();
+  const instance = state.instances.find((i) => i.id === instanceId);
+  const selectedTask = instance?.selectedTasks.find((t) => t.id === selectedTaskId);
+  if (!selectedTask) return undefined;
+  if (selectedTask.customName) return selectedTask.customName;
+
+  // 检查是否为 MXU 特殊任务（使用 i18n.t 翻译 label）
+  const specialTask = getMxuSpecialTask(selectedTask.taskName);
+  if (specialTask?.taskDef.label) {
+    return i18n.t(specialTask.taskDef.label);
+  }
+
+  // 普通任务：使用项目接口翻译
+  const taskDef = state.projectInterface?.task.find((t) => t.name === selectedTask.taskName);
+  const langKey = getInterfaceLangKey(state.language);
+  const translations = state.interfaceTranslations[langKey];
+  return resolveI18nText(taskDef?.label, translations) || selectedTask.taskName;
+}
+
 /**
  * 根据 task_id 获取任务显示名
  * 优先使用 maaTaskIdMapping 查找 selectedTaskId，然后从实例任务列表获取显示名
@@ -261,23 +310,8 @@ function getTaskDisplayName(
     // 通过 maaTaskIdMapping 查找 selectedTaskId，然后从实例任务列表获取
     const selectedTaskId = state.maaTaskIdMapping[instanceId]?.[taskId];
     if (selectedTaskId) {
-      const instance = state.instances.find((i) => i.id === instanceId);
-      const selectedTask = instance?.selectedTasks.find((t) => t.id === selectedTaskId);
-      if (selectedTask) {
-        if (selectedTask.customName) return selectedTask.customName;
-
-        // 检查是否为 MXU 特殊任务（使用 i18n.t 翻译 label）
-        const specialTask = getMxuSpecialTask(selectedTask.taskName);
-        if (specialTask?.taskDef.label) {
-          return i18n.t(specialTask.taskDef.label);
-        }
-
-        // 普通任务：使用项目接口翻译
-        const taskDef = state.projectInterface?.task.find((t) => t.name === selectedTask.taskName);
-        const langKey = getInterfaceLangKey(state.language);
-        const translations = state.interfaceTranslations[langKey];
-        return resolveI18nText(taskDef?.label, translations) || selectedTask.taskName;
-      }
+      const name = getSelectedTaskDisplayName(instanceId, selectedTaskId);
+      if (name) return name;
     }
   }
 
@@ -513,11 +547,22 @@ function handleCallback(
         break;
       }
       const taskName = getTaskDisplayName(instanceId, details.task_id, details.entry);
+      // 被抢占或重试中断的旧任务会以新 ID 重新执行，不算失败
+      if (details.preempted) {
+        addLog(instanceId, {
+          type: 'info',
+          message: t('logs.messages.taskInterrupted', {
+            name: taskName || details.entry || '',
+          }),
+        });
+        break;
+      }
       addLog(instanceId, {
         type: 'error',
         message: t('logs.messages.taskFailed', {
           name: taskName || details.entry || '',
         }),
+        image: details.screenshot,
       });
       break;
     }
@@ -546,6 +591,17 @@ function handleCallback(
   }
 }
 
+/** Agent 崩溃自动重启事件载荷 */
+interface AgentRestartPayload {
+  instance_id: string;
+  status: 'restarting' | 'restarted' | 'failed' | 'gave_up';
+  attempt: number;
+  max_attempts: number;
+  exit_code: number | null;
+  remaining: number;
+  error: string | null;
+}
+
 /**
  * 监听 Agent 输出事件
  */
@@ -553,6 +609,18 @@ export function useMaaAgentLogger() {
   const { t } = useTranslation();
   const { addLog } = useAppStore();
   const unlistenRef = useRef<(() => void) | null>(null);
+  const unlistenRestartRef = useRef<(() => void) | null>(null);
+  const unlistenWatchdogRef = useRef<(() => void) | null>(null);
+  const unlistenPreemptRef = useRef<(() => void) | null>(null);
+  const unlistenRetryRef = useRef<(() => void) | null>(null);
+  const unlistenConditionRef = useRef<(() => void) | null>(null);
+  const unlistenRoundRef = useRef<(() => void) | null>(null);
+  const unlistenSectionRef = useRef<(() => void) | null>(null);
+  const unlistenHotUpdateRef = useRef<(() => void) | null>(null);
+  const unlistenWindowStateRef = useRef<(() => void) | null>(null);
+  const unlistenEmulatorLaunchRef = useRef<(() => void) | null>(null);
+  const unlistenAdbAuthRef = useRef<(() => void) | null>(null);
+  const unlistenProgramBlockedRef = useRef<(() => void) | null>(null);
   const agentFloodStateRef = useRef<
     Map<
       string,
@@ -669,10 +737,322 @@ export function useMaaAgentLogger() {
       }
     };
 
+    const handleAgentRestart = (payload: AgentRestartPayload) => {
+      if (cancelled) return;
+
+      const { instance_id, status, attempt, max_attempts, exit_code, remaining, error } = payload;
+      const params = {
+        attempt,
+        max: max_attempts,
+        code: exit_code ?? '-',
+        remaining,
+        error: error ?? '',
+      };
+      switch (status) {
+        case 'restarting':
+          addLog(instance_id, {
+            type: 'warning',
+            message: t('logs.messages.agentRestarting', params),
+          });
+          break;
+        case 'restarted':
+          addLog(instance_id, {
+            type: 'success',
+            message: t('logs.messages.agentRestarted', params),
+          });
+          break;
+        case 'failed':
+          addLog(instance_id, {
+            type: 'error',
+            message: t('logs.messages.agentRestartFailed', params),
+          });
+          break;
+        case 'gave_up':
+          addLog(instance_id, {
+            type: 'error',
+            message: t('logs.messages.agentRestartGaveUp', params),
+          });
+          break;
+      }
+    };
+
     const setupListener = async () => {
       try {
         if (isTauri()) {
           const { listen } = await import('@tauri-apps/api/event');
+          // Agent 崩溃自动重启仅桌面端后端会触发
+          const unlistenRestart = await listen<AgentRestartPayload>('maa-agent-restart', (event) =>
+            handleAgentRestart(event.payload),
+          );
+          if (cancelled) {
+            unlistenRestart();
+          } else {
+            unlistenRestartRef.current = unlistenRestart;
+          }
+
+          // 实例运行时长看门狗超时（后端已停止任务）
+          const unlistenWatchdog = await maaService.onWatchdogTimeout((payload) => {
+            if (cancelled) return;
+            addLog(payload.instance_id, {
+              type: 'error',
+              message: t('logs.messages.watchdogTimeout', {
+                minutes: Math.max(1, Math.round(payload.max_runtime_secs / 60)),
+              }),
+            });
+          });
+          if (cancelled) {
+            unlistenWatchdog();
+          } else {
+            unlistenWatchdogRef.current = unlistenWatchdog;
+          }
+
+          // 高优先级任务抢占：被中断的任务以新 ID 重新提交，沿用原任务名
+          const unlistenPreempt = await maaService.onTaskPreempted((payload) => {
+            if (cancelled) return;
+            const { registerTaskIdName, getTaskName } = useAppStore.getState();
+            for (const { old_task_id, new_task_id } of payload.resubmitted) {
+              const name = getTaskName(old_task_id);
+              if (name) registerTaskIdName(new_task_id, name);
+            }
+            addLog(payload.instance_id, {
+              type: 'warning',
+              message: t('logs.messages.taskPreempted', {
+                name: getTaskName(payload.task_id) ?? payload.task_id,
+                count: payload.resubmitted.length,
+              }),
+            });
+          });
+          if (cancelled) {
+            unlistenPreempt();
+          } else {
+            unlistenPreemptRef.current = unlistenPreempt;
+          }
+
+          // 任务失败自动重试：重新提交的任务沿用原任务名
+          const unlistenRetry = await maaService.onTaskRetry((payload) => {
+            if (cancelled) return;
+            const { registerTaskIdName, getTaskName } = useAppStore.getState();
+            for (const { old_task_id, new_task_id } of payload.resubmitted) {
+              const name = getTaskName(old_task_id);
+              if (name) registerTaskIdName(new_task_id, name);
+            }
+            const name = getTaskName(payload.task_id) ?? payload.entry;
+            if (payload.status === 'retrying') {
+              addLog(payload.instance_id, {
+                type: 'warning',
+                message: t('logs.messages.taskRetrying', {
+                  name,
+                  attempt: payload.attempt,
+                  max: payload.max_retries,
+                }),
+              });
+            } else if (payload.status === 'failed') {
+              addLog(payload.instance_id, {
+                type: 'error',
+                message: t('logs.messages.taskRetryFailed', { name, error: payload.error ?? '' }),
+              });
+            }
+          });
+          if (cancelled) {
+            unlistenRetry();
+          } else {
+            unlistenRetryRef.current = unlistenRetry;
+          }
+
+          // 任务跳过条件：条件为真跳过的任务与表达式求值出错
+          const unlistenCondition = await maaService.onTaskCondition((payload) => {
+            if (cancelled) return;
+            const name = useAppStore.getState().getTaskName(payload.task_id) ?? payload.entry;
+            if (payload.error) {
+              addLog(payload.instance_id, {
+                type: 'warning',
+                message: t('logs.messages.taskConditionInvalid', { name, error: payload.error }),
+              });
+            } else if (payload.skipped) {
+              addLog(payload.instance_id, {
+                type: 'info',
+                message: t('logs.messages.taskSkippedByCondition', {
+                  name,
+                  expression: payload.expression,
+                }),
+              });
+            }
+          });
+          if (cancelled) {
+            unlistenCondition();
+          } else {
+            unlistenConditionRef.current = unlistenCondition;
+          }
+
+          // 按任务分段：在任务开始/结束处插入分段标记，日志面板据此折叠
+          const unlistenSection = await maaService.onLogSection((payload) => {
+            if (cancelled) return;
+            const name =
+              getTaskDisplayName(payload.instance_id, payload.task_id, payload.entry) ||
+              payload.entry;
+            const label =
+              payload.round > 0
+                ? t('logs.messages.sectionRound', { name, round: payload.round })
+                : name;
+            const isStart = payload.phase === 'start';
+            const key = isStart ? 'logs.messages.sectionStart' : 'logs.messages.sectionEnd';
+            addLog(payload.instance_id, {
+              type: isStart ? 'info' : payload.status === 'succeeded' ? 'success' : 'error',
+              message: t(key, { name: label }),
+              section: {
+                taskId: payload.task_id,
+                phase: payload.phase,
+                name: label,
+                status: payload.status ?? undefined,
+                round: payload.round,
+              },
+            });
+          });
+          if (cancelled) {
+            unlistenSection();
+          } else {
+            unlistenSectionRef.current = unlistenSection;
+          }
+
+          // 任务队列循环执行：每轮结束记录轮次
+          const unlistenRound = await maaService.onRoundCompleted((payload) => {
+            if (cancelled) return;
+            const total = payload.max_rounds > 0 ? payload.max_rounds : '∞';
+            addLog(payload.instance_id, {
+              type: payload.has_failed ? 'warning' : 'success',
+              message: payload.continues
+                ? t('logs.messages.roundCompletedNext', {
+                    round: payload.round,
+                    total,
+                    seconds: Math.round(payload.interval_ms / 1000),
+                  })
+                : t('logs.messages.roundCompleted', { round: payload.round, total }),
+            });
+          });
+          if (cancelled) {
+            unlistenRound();
+          } else {
+            unlistenRoundRef.current = unlistenRound;
+          }
+
+          // 运行中热更新 Pipeline：说明各字段何时生效
+          const unlistenHotUpdate = await maaService.onPipelineHotUpdated((payload) => {
+            if (cancelled) return;
+            const name = useAppStore.getState().getTaskName(payload.task_id) ?? payload.task_id;
+            if (payload.applied) {
+              addLog(payload.instance_id, {
+                type: 'info',
+                message: t('logs.messages.pipelineHotUpdated', { name }),
+              });
+            }
+            if (payload.deferred_fields.length > 0) {
+              addLog(payload.instance_id, {
+                type: 'info',
+                message: t('logs.messages.pipelineHotDeferred', {
+                  fields: payload.deferred_fields.join(', '),
+                }),
+              });
+            }
+            if (payload.restart_fields.length > 0) {
+              addLog(payload.instance_id, {
+                type: 'warning',
+                message: t(
+                  payload.next_round
+                    ? 'logs.messages.pipelineHotNextRound'
+                    : 'logs.messages.pipelineHotRestart',
+                  { fields: payload.restart_fields.join(', ') },
+                ),
+              });
+            }
+          });
+          if (cancelled) {
+            unlistenHotUpdate();
+          } else {
+            unlistenHotUpdateRef.current = unlistenHotUpdate;
+          }
+
+          // Win32 目标窗口最小化/恢复（最小化时截图会失败或黑屏）
+          const unlistenWindowState = await maaService.onWindowStateChanged((payload) => {
+            if (cancelled) return;
+            if (payload.minimized) {
+              addLog(payload.instance_id, {
+                type: 'warning',
+                message: t(
+                  payload.policy === 'pause'
+                    ? 'logs.messages.windowMinimizedPaused'
+                    : payload.policy === 'restore'
+                      ? 'logs.messages.windowMinimizedRestore'
+                      : 'logs.messages.windowMinimized',
+                ),
+              });
+            } else {
+              addLog(payload.instance_id, {
+                type: 'info',
+                message: t('logs.messages.windowRestored'),
+              });
+            }
+          });
+          if (cancelled) {
+            unlistenWindowState();
+          } else {
+            unlistenWindowStateRef.current = unlistenWindowState;
+          }
+
+          // 连接前自动启动模拟器
+          const unlistenEmulatorLaunch = await maaService.onEmulatorLaunch((payload) => {
+            if (cancelled) return;
+            const name = payload.program.split(/[/\\]/).pop() || payload.program;
+            const messages = {
+              launching: ['info', 'logs.messages.emulatorLaunching'],
+              already_running: ['info', 'logs.messages.emulatorAlreadyRunning'],
+              ready: ['success', 'logs.messages.emulatorReady'],
+              timeout: ['error', 'logs.messages.emulatorTimeout'],
+            } as const;
+            const [type, key] = messages[payload.stage];
+            addLog(payload.instance_id, { type, message: t(key, { name }) });
+          });
+          if (cancelled) {
+            unlistenEmulatorLaunch();
+          } else {
+            unlistenEmulatorLaunchRef.current = unlistenEmulatorLaunch;
+          }
+
+          // ADB 设备授权丢失：引导用户在设备上允许调试，恢复后自动重连
+          const unlistenAdbAuth = await maaService.onAdbAuth((payload) => {
+            if (cancelled) return;
+            const messages = {
+              unauthorized: ['warning', 'logs.messages.adbUnauthorized'],
+              authorized: payload.reconnected
+                ? ['success', 'logs.messages.adbReauthorizedReconnected']
+                : ['success', 'logs.messages.adbReauthorized'],
+              timeout: ['error', 'logs.messages.adbAuthTimeout'],
+            } as const;
+            const [type, key] = messages[payload.stage];
+            addLog(payload.instance_id, { type, message: t(key, { address: payload.address }) });
+          });
+          if (cancelled) {
+            unlistenAdbAuth();
+          } else {
+            unlistenAdbAuthRef.current = unlistenAdbAuth;
+          }
+
+          // 外部程序不在信任列表中被拒绝执行
+          const unlistenProgramBlocked = await maaService.onProgramBlocked((payload) => {
+            if (cancelled || !payload.instance_id) return;
+            const key = payload.rejected_by_user
+              ? 'logs.messages.programRejected'
+              : 'logs.messages.programUntrusted';
+            addLog(payload.instance_id, {
+              type: 'error',
+              message: t(key, { program: payload.program, source: payload.source }),
+            });
+          });
+          if (cancelled) {
+            unlistenProgramBlocked();
+          } else {
+            unlistenProgramBlockedRef.current = unlistenProgramBlocked;
+          }
+
           const unlisten = await listen<{ instance_id: string; stream: string; line: string }>(
             'maa-agent-output',
             (event) => {
@@ -707,6 +1087,54 @@ export function useMaaAgentLogger() {
         unlistenRef.current();
         unlistenRef.current = null;
       }
+      if (unlistenRestartRef.current) {
+        unlistenRestartRef.current();
+        unlistenRestartRef.current = null;
+      }
+      if (unlistenWatchdogRef.current) {
+        unlistenWatchdogRef.current();
+        unlistenWatchdogRef.current = null;
+      }
+      if (unlistenPreemptRef.current) {
+        unlistenPreemptRef.current();
+        unlistenPreemptRef.current = null;
+      }
+      if (unlistenRetryRef.current) {
+        unlistenRetryRef.current();
+        unlistenRetryRef.current = null;
+      }
+      if (unlistenConditionRef.current) {
+        unlistenConditionRef.current();
+        unlistenConditionRef.current = null;
+      }
+      if (unlistenSectionRef.current) {
+        unlistenSectionRef.current();
+        unlistenSectionRef.current = null;
+      }
+      if (unlistenRoundRef.current) {
+        unlistenRoundRef.current();
+        unlistenRoundRef.current = null;
+      }
+      if (unlistenHotUpdateRef.current) {
+        unlistenHotUpdateRef.current();
+        unlistenHotUpdateRef.current = null;
+      }
+      if (unlistenWindowStateRef.current) {
+        unlistenWindowStateRef.current();
+        unlistenWindowStateRef.current = null;
+      }
+      if (unlistenEmulatorLaunchRef.current) {
+        unlistenEmulatorLaunchRef.current();
+        unlistenEmulatorLaunchRef.current = null;
+      }
+      if (unlistenAdbAuthRef.current) {
+        unlistenAdbAuthRef.current();
+        unlistenAdbAuthRef.current = null;
+      }
+      if (unlistenProgramBlockedRef.current) {
+        unlistenProgramBlockedRef.current();
+        unlistenProgramBlockedRef.current = null;
+      }
 
       for (const batch of agentFloodStateRef.current.values()) {
         clearAgentRecoveryTimer(batch);